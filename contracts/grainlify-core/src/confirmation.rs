@@ -0,0 +1,134 @@
+//! # Irreversible Action Confirmation
+//!
+//! Actions that cannot be undone take two governance calls, so no single
+//! mis-signed transaction can brick the contract:
+//!
+//! 1. `request_irreversible(action)` records the SHA-256 of the action's
+//!    XDR and the ledger time.
+//! 2. `confirm_irreversible(action)` resubmits the identical action at
+//!    least [`COOL_OFF`] and less than [`EXPIRY`] seconds after the request.
+//!    The contract hashes it again and performs it only on a match.
+//!
+//! | Failure                        | `ConfirmationError` |
+//! |--------------------------------|---------------------|
+//! | nothing requested              | `NoPendingAction`   |
+//! | different action or arguments  | `ActionMismatch`    |
+//! | before the cool-off has passed | `NotReady`          |
+//! | at or after the expiry         | `Expired`           |
+//!
+//! One request is pending at a time; a new request replaces it.
+
+use soroban_sdk::{contracterror, contracttype, xdr::ToXdr, Address, BytesN, Env};
+
+/// Seconds between a request and the earliest confirmation (24 hours).
+pub const COOL_OFF: u64 = 24 * 60 * 60;
+/// Seconds after a request at which it can no longer be confirmed (7 days).
+pub const EXPIRY: u64 = 7 * 24 * 60 * 60;
+
+/// Codes continue after `ProposalCheckError`; `ContractError` is at the
+/// spec's 50-variant limit.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ConfirmationError {
+    /// `confirm_irreversible` or `cancel_irreversible` with nothing requested
+    NoPendingAction = 153,
+    /// Confirmed action or arguments differ from the request
+    ActionMismatch = 154,
+    /// Confirmed before the cool-off elapsed
+    NotReady = 155,
+    /// Confirmed after the request expired
+    Expired = 156,
+    /// The contract was finalized and accepts no further upgrades
+    Finalized = 157,
+}
+
+/// Action guarded by the two-step confirmation.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum IrreversibleAction {
+    /// Refuse every further upgrade, permanently
+    Finalize,
+    /// Remove this admin, leaving the contract without one
+    RenounceAdmin(Address),
+}
+
+/// Request awaiting `confirm_irreversible`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingConfirmation {
+    /// SHA-256 of the requested action's XDR.
+    pub action_hash: BytesN<32>,
+    pub requested_at: u64,
+    /// Earliest ledger timestamp the confirmation is accepted at.
+    pub confirm_after: u64,
+    /// Ledger timestamp from which the confirmation is refused.
+    pub expires_at: u64,
+}
+
+/// Confirmation state (instance).
+#[contracttype]
+#[derive(Clone)]
+pub enum ConfirmationKey {
+    /// PendingConfirmation of the latest request
+    PendingIrreversible,
+    /// Set once `IrreversibleAction::Finalize` is confirmed
+    Finalized,
+}
+
+pub fn action_hash(env: &Env, action: &IrreversibleAction) -> BytesN<32> {
+    env.crypto().sha256(&action.clone().to_xdr(env)).into()
+}
+
+pub fn get_pending(env: &Env) -> Option<PendingConfirmation> {
+    env.storage().instance().get(&ConfirmationKey::PendingIrreversible)
+}
+
+/// Records `action` as pending, replacing any earlier request. The caller
+/// is responsible for authorization.
+pub fn request(env: &Env, action: &IrreversibleAction) -> PendingConfirmation {
+    let now = env.ledger().timestamp();
+    let pending = PendingConfirmation {
+        action_hash: action_hash(env, action),
+        requested_at: now,
+        confirm_after: now.saturating_add(COOL_OFF),
+        expires_at: now.saturating_add(EXPIRY),
+    };
+    env.storage().instance().set(&ConfirmationKey::PendingIrreversible, &pending);
+    pending
+}
+
+/// Checks `action` against the pending request and consumes it. An
+/// expired request is left in place so `get_pending` still shows it. The
+/// caller is responsible for authorization.
+pub fn take(env: &Env, action: &IrreversibleAction) -> Result<PendingConfirmation, ConfirmationError> {
+    let pending = get_pending(env).ok_or(ConfirmationError::NoPendingAction)?;
+    if pending.action_hash != action_hash(env, action) {
+        return Err(ConfirmationError::ActionMismatch);
+    }
+    let now = env.ledger().timestamp();
+    if now < pending.confirm_after {
+        return Err(ConfirmationError::NotReady);
+    }
+    if now >= pending.expires_at {
+        return Err(ConfirmationError::Expired);
+    }
+    env.storage().instance().remove(&ConfirmationKey::PendingIrreversible);
+    Ok(pending)
+}
+
+/// Drops the pending request. Fails with `NoPendingAction` without
+/// one.
+pub fn cancel(env: &Env) -> Result<PendingConfirmation, ConfirmationError> {
+    let pending = get_pending(env).ok_or(ConfirmationError::NoPendingAction)?;
+    env.storage().instance().remove(&ConfirmationKey::PendingIrreversible);
+    Ok(pending)
+}
+
+pub fn is_finalized(env: &Env) -> bool {
+    env.storage().instance().has(&ConfirmationKey::Finalized)
+}
+
+pub fn set_finalized(env: &Env) {
+    env.storage().instance().set(&ConfirmationKey::Finalized, &true);
+}
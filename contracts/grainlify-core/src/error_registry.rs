@@ -44,6 +44,7 @@ pub const GRAINLIFY_CORE_REGISTRY: &[RegistryEntry] = &[
     (1, "AlreadyInitialized"),
     (2, "NotInitialized"),
     (3, "NotAdmin"),
+    (10, "Paused"),
     // ── Governance / upgrade (100-199) ───────────────────────────────────────
     (101, "ThresholdNotMet"),
     (102, "ProposalNotFound"),
@@ -52,6 +53,45 @@ pub const GRAINLIFY_CORE_REGISTRY: &[RegistryEntry] = &[
     (105, "TimelockDelayTooHigh"),
     (106, "SnapshotRestoreAdminPending"),
     (107, "SnapshotPruned"),
+    (108, "FunctionDisabled"),
+    (109, "AttestationMissing"),
+    (110, "SchemaOutdated"),
+    (111, "FeatureDisabled"),
+    (112, "RevealMismatch"),
+    (113, "UpgradeNotRevealed"),
+    (114, "InvalidExecutionWindow"),
+    (115, "OutsideExecutionWindow"),
+    (116, "AmendmentLocked"),
+    (117, "NotProposer"),
+    (118, "ProposalNotExpired"),
+    (119, "MetadataTooLong"),
+    (120, "UnknownMetadataField"),
+    (121, "InvalidInitialVersion"),
+    (122, "InvalidActionBundle"),
+    (123, "InvalidAdminNonce"),
+    (124, "NonceRequired"),
+    (125, "PostponementExceeded"),
+    (126, "MaintenanceMode"),
+    (127, "InvalidMaintenanceDeadline"),
+    (128, "NetworkMismatch"),
+    (129, "OutOfOrderUpgrade"),
+    (130, "StagingRequired"),
+    (131, "StagingDisabled"),
+    (132, "UpgradeAlreadyStaged"),
+    (133, "NoStagedUpgrade"),
+    (134, "StagedHashMismatch"),
+    (135, "StagedUpgradeNotReady"),
+    (136, "ApprovalMismatch"),
+    (137, "LegacyApprovalDisabled"),
+    (138, "NotRelayer"),
+    (139, "DescriptionTooLong"),
+    (140, "UrlTooLong"),
+    (141, "LabelTooLong"),
+    (142, "InvalidStringLimit"),
+    (143, "DuplicateExternalId"),
+    (144, "WrongGovernanceMode"),
+    (145, "SelfReference"),
+    (146, "AlreadyApproved"),
 ];
 
 /// Returns `true` if any two entries in `registry` share the same numeric code.
@@ -151,7 +191,7 @@ pub const fn no_cross_contract_conflicts(other_registry: &[RegistryEntry]) -> bo
 ///
 /// # Returns
 /// An array of conflicting error codes (empty if no conflicts)
-pub const fn find_conflicts(other_registry: &[RegistryEntry]) -> &[u32] {
+pub const fn find_conflicts(_other_registry: &[RegistryEntry]) -> &[u32] {
     // Note: This is a simplified implementation. In a real implementation,
     // you might want to use a more sophisticated approach to collect conflicts.
     // For now, this serves as a placeholder for the validation logic.
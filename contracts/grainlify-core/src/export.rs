@@ -0,0 +1,109 @@
+//! # State Export
+//!
+//! `export_state(section, page)` returns one page of a governance section
+//! as the XDR of its struct, so a backup taken before a migration can be
+//! decoded with the contract's own types. `export_manifest` lists the
+//! sections and how many pages each has right now:
+//!
+//! | Section     | Struct                    | Pages                          |
+//! |-------------|---------------------------|--------------------------------|
+//! | `governance`| [`GovernanceExport`]      | 1                              |
+//! | `config`    | [`ConfigExport`]          | 1                              |
+//! | `proposals` | [`ProposalsExport`]       | proposal IDs / `MAX_PAGE_SIZE` |
+//! | `history`   | [`HistoryExport`]         | longest retained history / `MAX_PAGE_SIZE` |
+//! | `metrics`   | [`crate::paging::MetricsPage`] | metric rows / `MAX_PAGE_SIZE` |
+//!
+//! `metrics` is listed only when monitoring is compiled in. A page past the
+//! last one is empty, as in `paging`.
+
+use soroban_sdk::{contracterror, contracttype, Address, Symbol, Vec};
+
+use grainlify_upgradeable::multisig::ApprovalRecord;
+
+use crate::paging::MAX_PAGE_SIZE;
+use crate::{ArchivedUpgrade, ThresholdChange, TunableConfig, UpgradeProposalRecord, VersionChange};
+
+/// Section names accepted by `export_state`, in manifest order.
+pub const SECTIONS: [&str; 5] = ["governance", "config", "proposals", "history", "metrics"];
+
+/// Codes continue after `ExecutionError`; `ContractError` is at the spec's
+/// 50-variant limit.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ExportError {
+    /// `export_state` was asked for a section not in `SECTIONS`
+    UnknownSection = 160,
+}
+
+/// One entry of `export_manifest`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ExportSection {
+    pub name: Symbol,
+    /// Pages `0..pages` hold the whole section; 0 when it is empty.
+    pub pages: u32,
+}
+
+/// Section `governance`: who governs and at which version.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GovernanceExport {
+    pub admin: Option<Address>,
+    /// Multisig signers in signer order; empty without multisig.
+    pub signers: Vec<Address>,
+    /// Multisig threshold; 0 without multisig.
+    pub threshold: u32,
+    pub signer_epoch: u64,
+    pub version: u32,
+    pub previous_version: Option<u32>,
+    pub schema_version: u32,
+    pub paused: bool,
+    pub read_only: bool,
+    pub finalized: bool,
+}
+
+/// Section `config`: the tunables and operational switches.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ConfigExport {
+    pub config: TunableConfig,
+    pub disabled_functions: Vec<Symbol>,
+    pub guardian: Option<Address>,
+    pub relayer: Option<Address>,
+    pub environment: Option<Symbol>,
+}
+
+/// A pending upgrade proposal with its approvals.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProposalExport {
+    pub record: UpgradeProposalRecord,
+    pub approvals: Vec<ApprovalRecord>,
+}
+
+/// Section `proposals`: page `n` covers proposal IDs
+/// `n * MAX_PAGE_SIZE + 1` through `(n + 1) * MAX_PAGE_SIZE` and lists those
+/// still pending; executed proposals are in `history`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProposalsExport {
+    pub items: Vec<ProposalExport>,
+    pub has_more: bool,
+}
+
+/// Section `history`: page `n` holds entries `n * MAX_PAGE_SIZE` onwards of
+/// each retained history, oldest first.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HistoryExport {
+    pub upgrades: Vec<ArchivedUpgrade>,
+    pub thresholds: Vec<ThresholdChange>,
+    pub versions: Vec<VersionChange>,
+    pub has_more: bool,
+}
+
+/// Pages needed for `total` entries.
+pub fn page_count(total: u64) -> u32 {
+    total.div_ceil(MAX_PAGE_SIZE as u64) as u32
+}
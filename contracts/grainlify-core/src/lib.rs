@@ -1,18 +1,29 @@
 #![no_std]
 use soroban_sdk::{
-    contract, contracterror, contractimpl, contracttype, symbol_short, Address, BytesN, Env,
-    String, Symbol, Vec,
+    contract, contracterror, contractimpl, contracttype, symbol_short, token, Address, Bytes,
+    BytesN, Env, IntoVal, InvokeError, Map, String, Symbol, TryFromVal, Val, Vec, xdr::ToXdr,
 };
 pub mod asset;
 pub mod commit_reveal;
+pub mod confirmation;
 pub mod error_registry;
 pub mod errors;
+pub mod export;
+pub mod limits;
+#[allow(dead_code)]
 mod governance;
-mod multisig;
 pub mod nonce;
+pub mod paging;
 pub mod pseudo_randomness;
 pub mod strict_mode;
-use multisig::MultiSig;
+#[cfg(any(all(test, feature = "contract"), feature = "testutils"))]
+pub mod testutils;
+mod token_voting;
+use grainlify_upgradeable::multisig::{self, MultiSig};
+pub use token_voting::{SnapshotTokenInterface, TokenProposal, TokenVotingConfig};
+pub use confirmation::{ConfirmationError, IrreversibleAction, PendingConfirmation};
+pub use export::ExportError;
+use token_voting::TokenVoting;
 
 #[cfg(test)]
 mod test_error_registry;
@@ -30,6 +41,8 @@ pub enum ContractError {
     AlreadyInitialized = 1,
     NotInitialized = 2,
     NotAdmin = 3,
+    /// Contract is paused — privileged operations are blocked until unpaused
+    Paused = 10,
     ThresholdNotMet = 101,
     ProposalNotFound = 102,
     /// [FIX-C01] Migration hash commitment not found — must call commit_migration first
@@ -42,26 +55,175 @@ pub enum ContractError {
     SnapshotRestoreAdminPending = 106,
     /// Snapshot was pruned and is no longer available
     SnapshotPruned = 107,
+    /// Entrypoint has been switched off via `disable_function`
+    FunctionDisabled = 108,
+    /// Target WASM hash lacks the required auditor attestations
+    AttestationMissing = 109,
+    /// Storage schema is older than the function requires; run migrate_schema
+    SchemaOutdated = 110,
+    /// Function is compiled out of this build (e.g. without the monitoring feature)
+    FeatureDisabled = 111,
+    /// Revealed hash and salt do not match the upgrade commitment
+    RevealMismatch = 112,
+    /// Committed upgrade must be revealed before this step
+    UpgradeNotRevealed = 113,
+    /// Execution window bounds are out of order, in the past, or past the expiry
+    InvalidExecutionWindow = 114,
+    /// Proposal's execution window has not opened yet
+    OutsideExecutionWindow = 115,
+    /// Another signer approved the proposal, so its hash can no longer be amended
+    AmendmentLocked = 116,
+    /// Caller is not the proposal's original proposer
+    NotProposer = 117,
+    /// Proposal must be expired (not cancelled or executed) to be re-proposed
+    ProposalNotExpired = 118,
+    /// Metadata value exceeds its length limit
+    MetadataTooLong = 119,
+    /// Metadata field name is not one of name, description, url, contact
+    UnknownMetadataField = 120,
+    /// Initial version passed to an init function must be at least 1
+    InvalidInitialVersion = 121,
+    /// Action bundle is empty or longer than MAX_PROPOSAL_ACTIONS
+    InvalidActionBundle = 122,
+    /// Supplied admin nonce does not match get_admin_nonce
+    InvalidAdminNonce = 123,
+    /// Nonce-less admin entrypoint called while require_nonce is on
+    NonceRequired = 124,
+    /// Postponement would exceed the configured maximum total postponement
+    PostponementExceeded = 125,
+    /// Contract is in maintenance; only governance calls and reads are allowed
+    MaintenanceMode = 126,
+    /// Maintenance deadline must be in the future
+    InvalidMaintenanceDeadline = 127,
+    /// Expected network label does not match the one the proposal recorded
+    NetworkMismatch = 128,
+    /// A newer upgrade proposal is already approved or executed
+    OutOfOrderUpgrade = 129,
+    /// One-shot admin upgrade attempted while staged upgrades are required
+    StagingRequired = 130,
+    /// Staged upgrade entrypoint called without a staging delay configured
+    StagingDisabled = 131,
+    /// An upgrade is already staged; unstage it first
+    UpgradeAlreadyStaged = 132,
+    /// No upgrade is staged
+    NoStagedUpgrade = 133,
+    /// Confirmed hash differs from the staged hash
+    StagedHashMismatch = 134,
+    /// Staged upgrade confirmed before its delay elapsed
+    StagedUpgradeNotReady = 135,
+    /// Wasm hash or description passed to `approve_upgrade_v2` differs from the proposal
+    ApprovalMismatch = 136,
+    /// `approve_upgrade` and `approve_upgrade_on_network` are turned off; use `approve_upgrade_v2`
+    LegacyApprovalDisabled = 137,
+    /// `execute_upgrade_relayed` called by an address other than the configured relayer
+    NotRelayer = 138,
+    /// Description longer than the `desc` string limit
+    DescriptionTooLong = 139,
+    /// URL longer than the `url` string limit
+    UrlTooLong = 140,
+    /// Name or identifier longer than the `label` string limit
+    LabelTooLong = 141,
+    /// Unknown string limit field, or a limit outside its floor and ceiling
+    InvalidStringLimit = 142,
+    /// External ID already linked to a pending upgrade proposal
+    DuplicateExternalId = 143,
+    /// Entrypoint belongs to the other governance mode (admin or multisig)
+    WrongGovernanceMode = 144,
+    /// The contract's own address was given as an admin, signer, or relayer
+    SelfReference = 145,
+    /// Signer already approved the proposal (strict duplicate-approval mode)
+    AlreadyApproved = 146,
 }
-pub const STORAGE_SCHEMA_VERSION: u32 = 1;
+/// Layout version of contract storage, recorded under `DataKey::SchemaVersion`
+/// and advanced by `migrate_schema`. Deployments that predate the key are at 1.
+/// - 2: monitoring entries moved from bare `Symbol` keys to
+///   `monitoring::MetricsKey`
+pub const STORAGE_SCHEMA_VERSION: u32 = 2;
 pub const LIVENESS_SCHEMA_VERSION: u32 = 1;
 /// Version stamp embedded in every event struct for cross-version compatibility checks.
 pub const EVENT_SCHEMA_VERSION: u32 = 1;
+/// Version of the external interface reported by `get_abi_version`; bump
+/// it whenever an entrypoint, argument, or returned type changes.
+pub const ABI_VERSION: u32 = 1;
 const CONFIG_SNAPSHOT_LIMIT: u32 = 20;
 
 /// Maximum number of deployed contracts that can be registered.
 /// Prevents unbounded storage growth and ensures predictable gas costs.
 const MAX_DEPLOYED_CONTRACTS: u32 = 200;
 
+/// Entrypoints that can never be switched off by `disable_function`, so a
+/// kill switch can always be reverted and approved upgrades can always land.
+/// The action proposal entrypoints are how a multisig reverts one.
+const NON_DISABLEABLE_FUNCTIONS: [&str; 5] =
+    ["enable_function", "execute_upgrade", "propose_actions", "approve_actions", "execute_proposal"];
+
+/// Maximum number of contracts notified after each upgrade.
+/// Keeps the post-upgrade fan-out within a predictable budget.
+const MAX_UPGRADE_SUBSCRIBERS: u32 = 10;
+
+/// Maximum number of child contracts governed by this contract, and therefore
+/// the largest fan-out of a single fleet upgrade.
+const MAX_CHILD_CONTRACTS: u32 = 20;
+
+/// Most proposal IDs a single `sweep_proposals` call examines.
+const MAX_SWEEP_BATCH: u32 = 50;
+
+/// Most upgrade proposals open at once. Proposals without an expiry stay
+/// open until executed or cancelled, so this bounds the pending set.
+pub const MAX_PENDING_UPGRADES: u32 = 50;
+
 /// Default timelock delay for upgrade execution (24 hours in seconds)
 const DEFAULT_TIMELOCK_DELAY: u64 = 86_400;
 
+/// Ledgers of TTL `extend_instance_ttl` extends the instance to (~30 days).
+pub const INSTANCE_TTL_EXTEND_TO: u32 = 17_280 * 30;
+/// Default `TtlWarningThreshold`: warn with about a week of TTL left.
+pub const DEFAULT_TTL_WARNING_LEDGERS: u32 = 17_280 * 7;
+
+/// Most actions a single `propose_actions` bundle may carry.
+pub const MAX_PROPOSAL_ACTIONS: u32 = 5;
+/// Most rejections `get_rejections` keeps per proposal; older ones are
+/// dropped first.
+pub const MAX_REJECTIONS: u32 = 20;
+/// Most proposal templates `create_template` keeps at once.
+pub const MAX_TEMPLATES: u32 = 20;
+/// Seconds an added signer has to call `accept_signership` (7 days) unless
+/// governance sets another window.
+pub const DEFAULT_SIGNER_ACCEPTANCE_WINDOW: u64 = 7 * 24 * 60 * 60;
+/// Most signer invitations awaiting acceptance at once.
+pub const MAX_PENDING_SIGNERS: u32 = 10;
+/// Longest label `set_version_label` accepts, in bytes.
+pub const MAX_VERSION_LABEL_LEN: u32 = 20;
+/// Seconds a `guardian_pause` lasts (72 hours) unless governance extends it.
+pub const DEFAULT_EMERGENCY_PAUSE_DURATION: u64 = 72 * 60 * 60;
+/// Seconds between two `guardian_pause` calls (7 days), counted from the
+/// start of the earlier one.
+pub const DEFAULT_EMERGENCY_PAUSE_COOLDOWN: u64 = 7 * 24 * 60 * 60;
+/// Entries each history keeps until `set_history_retention` changes it.
+pub const DEFAULT_HISTORY_RETENTION: u64 = 500;
+/// Most old entries one append deletes, so lowering a retention trims the
+/// history over several appends instead of in one oversized write.
+pub const MAX_HISTORY_PRUNE: u64 = 2;
+
+/// Default byte limits for `ContractMetadata` fields; see `limits`.
+pub use limits::{
+    MAX_CONTACT_LEN as MAX_METADATA_CONTACT_LEN, MAX_DESCRIPTION_LEN as MAX_METADATA_DESCRIPTION_LEN,
+    MAX_NAME_LEN as MAX_METADATA_NAME_LEN, MAX_URL_LEN as MAX_METADATA_URL_LEN,
+};
+
+/// Bytes of the description a signer passes to `approve_upgrade_v2`, short
+/// enough for a hardware wallet screen.
+pub const APPROVAL_DESCRIPTION_LEN: u32 = 64;
+
 /// [FIX-H02] Maximum allowed timelock delay (30 days) — prevents bricking upgrades
 const MAX_TIMELOCK_DELAY: u64 = 2_592_000;
 
 /// [FIX-H02] Minimum allowed timelock delay (1 hour)
 const MIN_TIMELOCK_DELAY: u64 = 3_600;
 
+/// Default cap on the total delay `postpone` can add to one proposal (30 days).
+const DEFAULT_MAX_POSTPONEMENT: u64 = 2_592_000;
+
 /// Default delay for config-change execution (6 hours in seconds).
 const DEFAULT_CONFIG_CHANGE_DELAY: u64 = 21_600;
 
@@ -72,6 +234,275 @@ const VERSION: u32 = 2;
 // Data Structures
 // ============================================================================
 
+/// Returned by `execute_upgrade_v2` and `upgrade_v2`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UpgradeReceipt {
+    /// The WASM hash now installed.
+    pub wasm_hash: BytesN<32>,
+    /// Stored version after the swap; unchanged until the new code migrates.
+    pub new_version: u32,
+    /// Executed proposal, or 0 for a single-admin `upgrade_v2`.
+    pub proposal_id: u64,
+    /// Ledger timestamp of the swap.
+    pub executed_at: u64,
+}
+
+/// Returned by `approve_and_execute`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ApproveExecuteOutcome {
+    pub proposal_id: u64,
+    /// Whether the upgrade was installed in the same call.
+    pub executed: bool,
+    /// `preview_execution` checks that kept the proposal from executing;
+    /// empty when `executed`.
+    pub failing: Vec<ExecutionCheck>,
+}
+
+/// Returned by `attempt_propose_upgrade`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProposeAttempt {
+    /// The opened proposal, or 0 when refused.
+    pub proposal_id: u64,
+    /// Error code `propose_upgrade` would have failed with, or 0 where it
+    /// fails with a message; `None` when the proposal opened.
+    pub refused: Option<u32>,
+}
+
+/// Compact permanent entry appended when a multisig upgrade proposal
+/// executes; returned by `get_archived_upgrade`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ArchivedUpgrade {
+    pub proposal_id: u64,
+    pub wasm_hash: BytesN<32>,
+    /// Stored version when the upgrade executed.
+    pub version: u32,
+    pub executed_at: u64,
+    pub approver_count: u32,
+    /// sha256 over the XDR of each approver address, sorted ascending.
+    pub approvers_digest: BytesN<32>,
+    /// Authenticated executor; `None` for `execute_upgrade_v3` and older.
+    pub executor: Option<Address>,
+    pub ledger_sequence: u32,
+    /// Whether `executor` was the configured relayer.
+    pub relayed: bool,
+    /// Whether the upgrade skipped the timelock through `expedite`.
+    pub expedited: bool,
+}
+
+/// Entry in the threshold history returned by `get_threshold_history`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ThresholdChange {
+    pub threshold: u32,
+    pub signer_count: u32,
+    pub changed_at: u64,
+    /// Action proposal that made the change; `None` for initialization and
+    /// snapshot restores.
+    pub proposal_id: Option<u64>,
+}
+
+/// Entry in the version history returned by `get_version_history`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VersionChange {
+    pub version: u32,
+    pub changed_at: u64,
+}
+
+/// An append-only history whose retention governance can cap.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum HistoryKind {
+    /// The executed-upgrade archive read by `get_archived_upgrade`.
+    Upgrades,
+    /// Read by `get_threshold_history`.
+    Thresholds,
+    /// Read by `get_version_history`.
+    Versions,
+}
+
+/// Returned by `get_history_range`. Entries `first..total_count` are
+/// retained; older ones were deleted to respect `retention`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HistoryRange {
+    /// Index of the oldest retained entry.
+    pub first: u64,
+    /// Entries ever recorded, including deleted ones.
+    pub total_count: u64,
+    /// Most entries kept.
+    pub retention: u64,
+}
+
+/// Emitted as `("invariant", "violated")` when `check_consistency` finds
+/// violations.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ConsistencyViolationEvent {
+    pub violations: Vec<Symbol>,
+    pub timestamp: u64,
+    /// Event schema version for cross-version compatibility checks.
+    pub event_version: u32,
+}
+
+/// Emitted as `("upgrade", "executed")` when a multisig upgrade proposal
+/// executes.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UpgradeExecutedEvent {
+    pub proposal_id: u64,
+    pub wasm_hash: BytesN<32>,
+    pub executor: Option<Address>,
+    /// Whether `executor` was the configured relayer.
+    pub relayed: bool,
+    /// Whether the upgrade skipped the timelock through `expedite`.
+    pub expedited: bool,
+    pub executed_at: u64,
+    pub ledger_sequence: u32,
+    /// Release-pipeline ID the proposal was linked to, if any.
+    pub external_id: Option<u64>,
+    /// Event schema version for cross-version compatibility checks.
+    pub event_version: u32,
+}
+
+/// A condition `execute_upgrade` enforces, as reported by `preview_execution`.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ExecutionCheck {
+    /// Read-only mode is on.
+    ReadOnly,
+    /// The contract is paused.
+    Paused,
+    /// Multisig state is inconsistent.
+    StateInconsistent,
+    /// The proposal's window has not opened yet.
+    OutsideWindow,
+    /// No approval has started the timelock.
+    TimelockNotStarted,
+    /// The timelock delay has not elapsed.
+    TimelockPending,
+    /// The proposal is still committed and has no revealed hash.
+    NotRevealed,
+    /// Below threshold, expired, cancelled, or already executed; also
+    /// reported alongside `Paused` and `StateInconsistent`.
+    NotExecutable,
+    /// The hash lacks the attestations the config requires.
+    AttestationMissing,
+    /// Token-voting mode: the vote is open, failed, or already executed.
+    VoteNotPassed,
+    /// A newer upgrade proposal is approved or executed; needs
+    /// `execute_upgrade_v3` with `allow_out_of_order`.
+    OutOfOrder,
+    /// The hash is on the blocklist.
+    WasmBlocked,
+    /// A guardian emergency pause is active.
+    EmergencyPaused,
+    /// The network protocol is older than the proposal's `min_protocol`.
+    ProtocolTooOld,
+}
+
+/// Returned by `preview_execution`: what executing a proposal right now
+/// would install and which checks would stop it.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ExecutionPreview {
+    pub proposal_id: u64,
+    /// Hash that would be installed, or the commitment while `revealed`
+    /// is false.
+    pub wasm_hash: BytesN<32>,
+    /// Whether the proposal's hash is known.
+    pub revealed: bool,
+    /// Whether the hash satisfies the attestation config (true when none
+    /// is enforced, false while unrevealed).
+    pub attested: bool,
+    /// Version `execute_upgrade_v2` would report in its receipt.
+    pub new_version: u32,
+    /// Seconds left on the timelock; `None` until it starts.
+    pub timelock_remaining: Option<u64>,
+    /// Failing checks in the order `execute_upgrade` evaluates them.
+    pub failing: Vec<ExecutionCheck>,
+    /// `true` when `failing` is empty.
+    pub executable: bool,
+}
+
+/// A precondition checked by `validate_proposal`.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ValidationCheck {
+    /// Multisig governance is configured and token voting is off.
+    GovernanceMode,
+    /// Read-only mode is off.
+    NotReadOnly,
+    /// The contract is not paused.
+    NotPaused,
+    /// Multisig state is consistent.
+    StateConsistent,
+    /// Not executed, cancelled, or lapsed.
+    Open,
+    /// Not past its expiry.
+    NotExpired,
+    /// Enough valid approvals to meet the threshold.
+    Threshold,
+    /// The timelock has elapsed.
+    Timelock,
+    /// The execution window is open.
+    Window,
+    /// The hash is revealed.
+    Revealed,
+    /// No newer upgrade proposal is approved or executed.
+    InOrder,
+    /// The hash satisfies the attestation config.
+    Attested,
+    /// The hash is not on the blocklist.
+    NotBlocked,
+    /// The hash differs from the tracked current hash; passes when no hash
+    /// is tracked.
+    HashChanged,
+}
+
+/// One row of a `ValidationReport`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ValidationResult {
+    pub check: ValidationCheck,
+    pub passed: bool,
+}
+
+/// Returned by `validate_proposal`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ValidationReport {
+    pub proposal_id: u64,
+    /// Hash the proposal would install, or its commitment while the
+    /// `Revealed` check fails.
+    pub wasm_hash: BytesN<32>,
+    /// Every check in `ValidationCheck` order, passing or not.
+    pub results: Vec<ValidationResult>,
+    /// `true` when every check passed.
+    pub valid: bool,
+}
+
+/// Returned by `can_execute_detailed`: everything currently stopping a
+/// multisig proposal from executing.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ExecutabilityReport {
+    pub proposal_id: u64,
+    /// Same value as `approvals_needed`.
+    pub approvals_needed: u32,
+    /// One symbol per failing condition: `executed`, `cancelled`,
+    /// `expired`, `lapsed`, `insufficient_approvals`, `timelock_pending`,
+    /// `outside_window`, `not_revealed`, `out_of_order`,
+    /// `attestation_missing`, `paused`, `read_only`, `state_inconsistent`.
+    pub blockers: Vec<Symbol>,
+    /// `true` when `blockers` is empty.
+    pub executable: bool,
+}
+
 #[contracttype]
 #[derive(Clone, Debug)]
 pub struct UpgradeEvent {
@@ -85,6 +516,170 @@ pub struct UpgradeEvent {
     pub event_version: u32,
 }
 
+/// Descriptive contract metadata set by `set_metadata`. Unset fields are
+/// empty strings.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContractMetadata {
+    /// At most `MAX_METADATA_NAME_LEN` bytes.
+    pub name: String,
+    /// At most `MAX_METADATA_DESCRIPTION_LEN` bytes.
+    pub description: String,
+    /// At most `MAX_METADATA_URL_LEN` bytes.
+    pub url: String,
+    /// Maintainer contact, at most `MAX_METADATA_CONTACT_LEN` bytes.
+    pub contact: String,
+}
+
+/// Emitted under `("meta", "updated")` with the full metadata after a change.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MetadataUpdatedEvent {
+    pub metadata: ContractMetadata,
+    pub timestamp: u64,
+    /// Event schema version for cross-version compatibility checks.
+    pub event_version: u32,
+}
+
+/// Identity summary returned by `get_contract_info`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContractInfo {
+    /// Metadata name; empty until set.
+    pub name: String,
+    pub version: u32,
+    /// `version` rendered as `major.minor.patch`.
+    pub version_string: String,
+    /// Upgrades performed since the counter was introduced.
+    pub upgrade_count: u64,
+    /// Same as `get_abi_version`.
+    pub abi_version: u32,
+    /// Same as `get_features`.
+    pub features: Vec<Symbol>,
+    /// Same as `get_version_label`.
+    pub version_label: Option<String>,
+    /// Expiry of the active guardian emergency pause, if any.
+    pub emergency_paused_until: Option<u64>,
+}
+
+/// Emitted as `("network", "proposed")` and `("network", "approved")` for
+/// upgrade proposals that recorded a network label.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NetworkLabelEvent {
+    pub proposal_id: u64,
+    pub network: Symbol,
+    /// Proposer or approving signer.
+    pub signer: Address,
+    pub timestamp: u64,
+    /// Release-pipeline ID the proposal was linked to, if any.
+    pub external_id: Option<u64>,
+    /// Event schema version for cross-version compatibility checks.
+    pub event_version: u32,
+}
+
+/// Published under `("upgrade", "rejected")` when a signer revokes an
+/// approval or cancels a proposal.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProposalRejectedEvent {
+    pub proposal_id: u64,
+    pub signer: Address,
+    /// `revoke` or `cancel`.
+    pub action: Symbol,
+    /// Possibly empty; see `set_rejection_reason_required`.
+    pub reason: String,
+    pub timestamp: u64,
+    /// Release-pipeline ID the proposal was linked to, if any.
+    pub external_id: Option<u64>,
+    /// Event schema version for cross-version compatibility checks.
+    pub event_version: u32,
+}
+
+/// Returned by `get_init_state`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum InitState {
+    Uninitialized,
+    /// Governed by a single admin.
+    AdminMode(Address),
+    /// Governed by a multisig: signer count and threshold.
+    MultisigMode(u32, u32),
+}
+
+/// Governance mode an init path chose, stored under `DataKey::InitMode`.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum InitMode {
+    Admin,
+    Multisig,
+}
+
+/// Returned by `maintenance_status`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MaintenanceStatus {
+    pub active: bool,
+    /// Deadline set by `enter_maintenance`; 0 when never entered or exited.
+    pub until: u64,
+}
+
+/// Authorizations the current admin issued that an admin handover
+/// revokes; returned by `get_pending_admin_actions`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingAdminActions {
+    /// The staged upgrade, if any, as zero or one entry (contract types
+    /// cannot be optional fields).
+    pub staged_upgrade: Vec<StagedUpgrade>,
+    /// Ticket from `authorize_upgrade`, including an expired one, as zero
+    /// or one entry.
+    pub upgrade_ticket: Vec<UpgradeTicket>,
+    /// Deadline of a running `enter_maintenance` window.
+    pub maintenance_until: Option<u64>,
+}
+
+/// Lifecycle timestamps of a multisig proposal, returned by
+/// `get_proposal_timings`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProposalTimings {
+    pub created_at: u64,
+    /// First time the proposal reached its approval threshold.
+    pub threshold_met_at: Option<u64>,
+    pub executed_at: Option<u64>,
+}
+
+/// Aggregate proposal figures returned by `get_governance_stats`.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct GovernanceStats {
+    pub created: u64,
+    pub reached_threshold: u64,
+    pub executed: u64,
+    /// Expired proposals counted when pruned or swept.
+    pub expired: u64,
+    pub cancelled: u64,
+    /// Mean seconds from creation to the threshold being met.
+    pub avg_time_to_threshold: u64,
+    /// Mean seconds from the threshold being met to execution.
+    pub avg_time_to_execution: u64,
+}
+
+/// Running totals behind `GovernanceStats`, updated as proposals move
+/// between states.
+#[contracttype]
+#[derive(Clone, Default)]
+struct GovernanceTotals {
+    created: u64,
+    reached_threshold: u64,
+    executed: u64,
+    expired: u64,
+    cancelled: u64,
+    threshold_secs: u64,
+    execution_secs: u64,
+}
+
 /// Emitted when read-only mode is toggled.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -96,6 +691,76 @@ pub struct ReadOnlyModeEvent {
     pub event_version: u32,
 }
 
+/// Lifecycle event published under `("grainlify", "init")` by every init path.
+///
+/// `admin` is `None` and `signers` / `threshold` are populated for multisig
+/// initialization; admin-based paths carry an empty signer list.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InitializedEvent {
+    pub admin: Option<Address>,
+    pub signers: Vec<Address>,
+    pub threshold: u32,
+    pub version: u32,
+    pub timestamp: u64,
+    /// Event schema version for cross-version compatibility checks.
+    pub event_version: u32,
+}
+
+/// Lifecycle event published under `("grainlify", "upgraded")` after a WASM swap.
+///
+/// `old_wasm_hash` is all zeroes until the contract has recorded an installed
+/// hash, i.e. for the first upgrade after deployment.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UpgradedEvent {
+    pub old_wasm_hash: BytesN<32>,
+    pub new_wasm_hash: BytesN<32>,
+    pub new_version: u32,
+    pub timestamp: u64,
+    /// Event schema version for cross-version compatibility checks.
+    pub event_version: u32,
+}
+
+/// Published under `("wasm", "corrected")` when governance overwrites the
+/// tracked WASM hash. `previous_wasm_hash` is all zeroes if none was tracked.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WasmHashCorrectedEvent {
+    pub previous_wasm_hash: BytesN<32>,
+    pub wasm_hash: BytesN<32>,
+    pub timestamp: u64,
+    /// Event schema version for cross-version compatibility checks.
+    pub event_version: u32,
+}
+
+/// Lifecycle event published under `("grainlify", "version")` by `set_version`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VersionChangedEvent {
+    pub old_version: u32,
+    pub new_version: u32,
+    pub caller: Address,
+    pub timestamp: u64,
+    /// Event schema version for cross-version compatibility checks.
+    pub event_version: u32,
+}
+
+/// Emitted when the pause flag is toggled.
+///
+/// Published under `("paused",)` when pausing and `("unpause",)` when resuming.
+/// `proposal_id` is set when a multisig unpause proposal reached its threshold.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PauseEvent {
+    pub paused: bool,
+    pub caller: Address,
+    pub proposal_id: Option<u64>,
+    pub timestamp: u64,
+    /// Event schema version for cross-version compatibility checks.
+    pub event_version: u32,
+}
+
 /// Emitted during contract initialization to record build and deployment information.
 ///
 /// This event provides crucial metadata for auditing and monitoring contract deployments:
@@ -193,67 +858,505 @@ pub struct MigrationEvent {
     pub event_version: u32,
 }
 
+/// Emitted by `migrate_schema` after each step it applies.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
-pub struct MigrationCommittedEvent {
-    pub target_version: u32,
-    pub hash: BytesN<32>,
-    pub committed_at: u64,
-    pub expires_at: u64,
+pub struct SchemaMigratedEvent {
+    pub from_version: u32,
+    pub to_version: u32,
+    pub timestamp: u64,
     /// Event schema version for cross-version compatibility checks.
     pub event_version: u32,
 }
 
-/// Canonical read model for a multisig upgrade proposal.
-///
-/// Approval and execution status remain in [`MultiSig`], while upgrade-specific
-/// metadata is stored in instance storage under the same stable `proposal_id`.
-/// `proposer` is optional to preserve compatibility with older proposal rows
-/// that predate explicit proposer storage.
-///
-/// # Expiry Semantics
-/// `expiry == 0` means the proposal never expires. When `expiry > 0` and the
-/// current ledger timestamp is at or past that value, the proposal is considered
-/// expired and can no longer be approved or executed.
-///
-/// # Cancellation Semantics
-/// `cancelled == true` means a signer has explicitly revoked the proposal.
-/// Cancelled proposals can never be re-activated or executed.
+/// Fields hashed, as XDR, into `proposal_digest`.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
-pub struct UpgradeProposalRecord {
-    /// Stable multisig proposal identifier returned by `propose_upgrade`.
+pub struct ProposalDigestInput {
+    pub contract: Address,
     pub proposal_id: u64,
-    /// Address that created the proposal, when explicitly recorded.
-    pub proposer: Option<Address>,
-    /// WASM hash that will be installed if the proposal executes.
+    /// Revealed WASM hash, or the commitment while unrevealed.
     pub wasm_hash: BytesN<32>,
-    /// Expiry ledger timestamp (seconds). `0` means no expiry.
+    /// `sha256` of the proposal description; of the empty string when it
+    /// has none.
+    pub metadata_hash: BytesN<32>,
     pub expiry: u64,
-    /// Whether the proposal was explicitly cancelled by a signer.
-    pub cancelled: bool,
+    /// Multisig threshold when the proposal was created; 0 if unrecorded.
+    pub threshold: u32,
 }
 
+/// Emitted as `("upgrade", "proposed")` when an upgrade proposal is
+/// opened, so signers can record its digest before approving.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UpgradeProposedEvent {
+    pub proposal_id: u64,
+    pub proposer: Address,
+    pub wasm_hash: BytesN<32>,
+    pub digest: BytesN<32>,
+    pub timestamp: u64,
+    /// Event schema version for cross-version compatibility checks.
+    pub event_version: u32,
+}
 
-/// [FIX-C01] Pre-committed migration hash for replay protection.
-///
-/// Admin must call `commit_migration(target_version, hash)` before calling
-/// `migrate(target_version, hash)`. The commitment is verified during migration
-/// to ensure the exact hash was pre-authorized by the admin in a separate tx.
-///
-/// # Replay Protection Flow
-/// 1. Admin calls `commit_migration(3, hash_of_migration_data)` → stored on-chain
-/// 2. Anyone can verify the commitment is live on-chain before execution
-/// 3. Admin calls `migrate(3, hash_of_migration_data)` → hash verified against commitment
-/// 4. Commitment is consumed (deleted) — cannot be replayed
+/// Emitted as `("upgrade", "ready")` when a proposal's valid approvals
+/// reach the threshold and start its timelock, and as `("upgrade",
+/// "unready")` when a revocation drops them below it again. Each crossing
+/// is published once.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
-pub struct MigrationCommitment {
-    /// Target version this commitment authorizes
-    pub target_version: u32,
-    /// Hash committed by admin — must match hash passed to migrate()
-    pub hash: BytesN<32>,
-    /// Ledger timestamp when commitment was made
+pub struct ProposalReadinessEvent {
+    pub proposal_id: u64,
+    pub tally: u32,
+    pub threshold: u32,
+    pub timestamp: u64,
+    /// Event schema version for cross-version compatibility checks.
+    pub event_version: u32,
+}
+
+/// Duration and rate limit of `guardian_pause`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EmergencyPauseSettings {
+    /// Seconds until a guardian pause expires on its own.
+    pub duration: u64,
+    /// Seconds after a guardian pause starts before the next may start.
+    pub cooldown: u64,
+}
+
+/// A guardian-initiated emergency pause; see `guardian_pause`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EmergencyPause {
+    pub guardian: Address,
+    pub started_at: u64,
+    /// Ledger time at which the pause lifts; governance may move it later.
+    pub expires_at: u64,
+}
+
+/// Published under `("emergency", "paused")`, `("emergency", "extended")`
+/// and `("emergency", "ended")`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EmergencyPauseEvent {
+    pub guardian: Address,
+    pub started_at: u64,
+    pub expires_at: u64,
+    pub timestamp: u64,
+    /// Event schema version for cross-version compatibility checks.
+    pub event_version: u32,
+}
+
+/// Emitted as `("upgrade", "linked")` when a proposal is created with an
+/// external ID.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProposalLinkedEvent {
+    pub proposal_id: u64,
+    pub external_id: u64,
+    pub proposer: Address,
+    pub timestamp: u64,
+    /// Event schema version for cross-version compatibility checks.
+    pub event_version: u32,
+}
+
+/// Emitted by `amend_proposal` when the proposer swaps the WASM hash.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProposalAmendedEvent {
+    pub proposal_id: u64,
+    pub proposer: Address,
+    pub old_wasm_hash: BytesN<32>,
+    pub new_wasm_hash: BytesN<32>,
+    pub timestamp: u64,
+    /// Release-pipeline ID the proposal was linked to, if any.
+    pub external_id: Option<u64>,
+    /// Event schema version for cross-version compatibility checks.
+    pub event_version: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MigrationCommittedEvent {
+    pub target_version: u32,
+    pub hash: BytesN<32>,
+    pub committed_at: u64,
+    pub expires_at: u64,
+    /// Event schema version for cross-version compatibility checks.
+    pub event_version: u32,
+}
+
+/// Canonical read model for a multisig upgrade proposal.
+///
+/// Approval and execution status remain in [`MultiSig`], while upgrade-specific
+/// metadata is stored in instance storage under the same stable `proposal_id`.
+/// `proposer` is optional to preserve compatibility with older proposal rows
+/// that predate explicit proposer storage.
+///
+/// # Expiry Semantics
+/// `expiry == 0` means the proposal never expires. When `expiry > 0` and the
+/// current ledger timestamp is at or past that value, the proposal is considered
+/// expired and can no longer be approved or executed.
+///
+/// # Cancellation Semantics
+/// `cancelled == true` means a signer has explicitly revoked the proposal.
+/// Cancelled proposals can never be re-activated or executed.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UpgradeProposalRecord {
+    /// Stable multisig proposal identifier returned by `propose_upgrade`.
+    pub proposal_id: u64,
+    /// Address that created the proposal, when explicitly recorded.
+    pub proposer: Option<Address>,
+    /// WASM hash that will be installed if the proposal executes.
+    pub wasm_hash: BytesN<32>,
+    /// Expiry ledger timestamp (seconds). `0` means no expiry.
+    pub expiry: u64,
+    /// Whether the proposal was explicitly cancelled by a signer.
+    pub cancelled: bool,
+    /// Earliest ledger timestamp execution is allowed, if bounded.
+    pub not_before: Option<u64>,
+    /// Execution must happen before this timestamp, if bounded; the
+    /// proposal expires once it passes.
+    pub not_after: Option<u64>,
+    /// Ledger timestamp the proposal was created or last amended; 0 for
+    /// proposals that predate the record.
+    pub proposed_at: u64,
+    /// Proposal that replaced this one through `repropose`, if any.
+    pub superseded_by: Option<u64>,
+    /// Multisig threshold in force when the proposal was created; 0 for
+    /// proposals that predate the stamp.
+    pub threshold: u32,
+    /// Release-pipeline ID set by `propose_upgrade_with_external_id`.
+    pub external_id: Option<u64>,
+    /// Oldest network protocol the WASM supports, set by
+    /// `propose_upgrade_min_protocol`.
+    pub min_protocol: Option<u32>,
+    /// Failed `attempt_execute_upgrade` calls: empty if there were none,
+    /// otherwise one entry (contract types cannot be optional fields).
+    pub execution_attempts: Vec<ExecutionAttempts>,
+}
+
+/// Failed execution attempts recorded by `attempt_execute_upgrade`. Only
+/// the latest attempt is kept, so the entry stays the same size however
+/// often a proposal is probed.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ExecutionAttempts {
+    /// Failed attempts so far, saturating at `u32::MAX`.
+    pub count: u32,
+    pub last_attempt_at: u64,
+    /// First check that stopped the latest attempt.
+    pub last_failure: ExecutionCheck,
+    pub last_caller: Address,
+}
+
+/// Execution window stored for a proposal created by `propose_upgrade_in_window`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ExecutionWindow {
+    pub not_before: Option<u64>,
+    pub not_after: Option<u64>,
+}
+
+/// Errors returned by the proposal countdown views. Unknown, executed and
+/// cancelled proposals have no countdown, so these fail instead of
+/// reporting a zero that reads as "ready" or "expired".
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum CountdownError {
+    /// Same code as `ContractError::ProposalNotFound`.
+    ProposalNotFound = 102,
+    ProposalExecuted = 147,
+    ProposalCancelled = 148,
+}
+
+/// Why `propose_upgrade` refused to open a proposal that could never
+/// execute; see `validate_proposal` for the matching checks.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ProposalCheckError {
+    /// Same code as `ContractError::Paused`.
+    Paused = 10,
+    /// Same code as `ContractError::AttestationMissing`.
+    AttestationMissing = 109,
+    ReadOnly = 149,
+    StateInconsistent = 150,
+    WasmBlocked = 151,
+    /// The hash is the code already installed.
+    HashUnchanged = 152,
+}
+
+/// Raised by an entrypoint called while a multi-step operation such as
+/// `execute_upgrade` is still running, e.g. from a subscriber it notifies.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum TransitionError {
+    InProgress = 158,
+}
+
+/// Why `execute_upgrade` refused a proposal, for checks added after
+/// `ContractError` reached the spec's 50-variant limit.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ExecutionError {
+    /// The network runs an older protocol than the proposal's
+    /// `min_protocol`.
+    ProtocolTooOld = 159,
+    /// `expedite` on a proposal not approved by every current signer, or
+    /// while a signer invitation is pending.
+    NotUnanimous = 161,
+}
+
+/// Codes continue after `ExecutionError`; `ContractError` is at the spec's
+/// 50-variant limit.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ProposalStorageError {
+    /// More legacy proposals remain in instance storage than one call may
+    /// move; drain them with `relocate_upgrade_proposals` first.
+    RelocationPending = 162,
+    /// `MAX_PENDING_UPGRADES` upgrade proposals are already open; execute
+    /// or cancel one first.
+    TooManyPending = 163,
+}
+
+/// Published under `("upgrade", "postpone")` by `postpone`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProposalPostponedEvent {
+    pub proposal_id: u64,
+    pub signer: Address,
+    /// New earliest execution timestamp.
+    pub not_before: u64,
+    /// Seconds of postponement still available for this proposal.
+    pub remaining_budget: u64,
+    pub timestamp: u64,
+    /// Release-pipeline ID the proposal was linked to, if any.
+    pub external_id: Option<u64>,
+    /// Event schema version for cross-version compatibility checks.
+    pub event_version: u32,
+}
+
+/// Stored payload of a fleet upgrade proposal created by `propose_fleet_upgrade`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FleetUpgradeProposal {
+    /// WASM hash passed to every child's `upgrade` entrypoint.
+    pub wasm_hash: BytesN<32>,
+    /// Registered children targeted by this proposal, in execution order.
+    pub children: Vec<Address>,
+}
+
+/// Outcome of upgrading a single child during `execute_fleet_upgrade`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FleetChildResult {
+    pub child: Address,
+    pub success: bool,
+}
+
+/// Published under `("fleet", "done")` once every child of a fleet upgrade
+/// has been attempted. Per-child outcomes are published under
+/// `("fleet", "child")` as `(child, success)`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FleetUpgradeEvent {
+    pub proposal_id: u64,
+    pub wasm_hash: BytesN<32>,
+    pub succeeded: u32,
+    pub failed: u32,
+    pub timestamp: u64,
+    /// Event schema version for cross-version compatibility checks.
+    pub event_version: u32,
+}
+
+/// A governance change carried by a `propose_actions` bundle.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ProposalAction {
+    /// Install this WASM hash, subject to attestation requirements.
+    Upgrade(BytesN<32>),
+    /// Store this contract version.
+    SetVersion(u32),
+    /// Set the upgrade timelock delay, within the usual bounds.
+    SetTimelockDelay(u64),
+    /// Set the multisig threshold, between 1 and the signer count.
+    SetThreshold(u32),
+    /// Apply a `ConfigUpdate`, as `set_config` does.
+    UpdateConfig(ConfigUpdate),
+    /// Invite a signer, as `add_signer` does.
+    AddSigner(Address),
+    /// Add a fleet child, as `register_child` does.
+    RegisterChild(Address),
+    /// Remove a fleet child, as `unregister_child` does.
+    UnregisterChild(Address),
+    /// Whitelist a relayer, as `set_relayer` does.
+    SetRelayer(Address),
+    /// Remove the relayer, as `clear_relayer` does.
+    ClearRelayer,
+    /// Block a WASM hash with a reason, as `block_wasm_hash` does.
+    BlockWasm(BytesN<32>, String),
+    /// Lift a WASM block, as `unblock_wasm_hash` does.
+    UnblockWasm(BytesN<32>),
+    /// Appoint a guardian, as `set_guardian` does.
+    SetGuardian(Address),
+    /// Remove the guardian, as `clear_guardian` does.
+    ClearGuardian,
+    /// Set guardian pause duration and cooldown, as
+    /// `set_emergency_pause_settings` does.
+    SetEmergencyPauseSettings(EmergencyPauseSettings),
+    /// Extend the active emergency pause, as `extend_emergency_pause` does.
+    ExtendEmergencyPause(u64),
+    /// End the active emergency pause, as `end_emergency_pause` does.
+    EndEmergencyPause,
+    /// Switch off an entrypoint, as `disable_function` does.
+    DisableFunction(Symbol),
+    /// Switch an entrypoint back on, as `enable_function` does.
+    EnableFunction(Symbol),
+    /// Store a proposal template, as `create_template` does.
+    CreateTemplate(Symbol, Vec<ProposalAction>),
+    /// Remove a proposal template, as `delete_template` does.
+    DeleteTemplate(Symbol),
+    /// Switch to token-holder voting, as `set_token_voting_config` does.
+    SetTokenVotingConfig(TokenVotingConfig),
+    /// Choose the repeated-approval mode, as `set_duplicate_approval` does.
+    SetDuplicateApproval(DuplicateApproval),
+    /// Set the network label, as `set_network_label` does.
+    SetNetworkLabel(Symbol),
+    /// Remove the network label, as `set_network_label(None)` does.
+    ClearNetworkLabel,
+    /// Require rejection reasons or not, as
+    /// `set_rejection_reason_required` does.
+    SetRejectionReasonRequired(bool),
+    /// Turn the legacy approval forms on or off, as
+    /// `set_legacy_approval_enabled` does.
+    SetLegacyApprovalEnabled(bool),
+    /// Configure auditors and enforcement, as `set_attestation_config`
+    /// does.
+    SetAttestationConfig(AttestationConfig),
+    /// Configure keeper rewards, as `set_reward_config` does.
+    SetRewardConfig(KeeperRewardConfig),
+    /// Turn keeper rewards off, as `set_reward_config(None)` does.
+    ClearRewardConfig,
+    /// Pay out of the reward pool, as `withdraw_reward_pool` does.
+    WithdrawRewardPool(Address, i128),
+    /// Map a deployment name, as `register_deployment` does.
+    RegisterDeployment(Symbol, Address, bool),
+    /// Remove a deployment name, as `unregister_deployment` does.
+    UnregisterDeployment(Symbol),
+    /// Record a deployment's version, as `set_deployment_version` does.
+    SetDeploymentVersion(Symbol, u32),
+    /// Configure the circuit breaker, as `set_circuit_breaker_config` does.
+    SetCircuitBreakerConfig(monitoring::CircuitBreakerConfig),
+    /// Announce maintenance until a deadline, as `enter_maintenance` does.
+    EnterMaintenance(u64),
+    /// End maintenance early, as `exit_maintenance` does.
+    ExitMaintenance,
+    /// Restore exported monitoring rows, as `import_metrics` does.
+    ImportMetrics(Vec<(Symbol, u64)>),
+    /// Turn tracking of an operation on or off, as `set_tracking` does.
+    SetTracking(Symbol, bool),
+    /// Set a string field's byte limit, as `set_string_limit` does.
+    SetStringLimit(Symbol, u32),
+    /// Set how many entries a history keeps, as `set_history_retention`
+    /// does.
+    SetHistoryRetention(HistoryKind, u64),
+    /// Attach a metrics contract, as `set_metrics_contract` does.
+    SetMetricsContract(Address),
+    /// Keep metrics internally again, as `set_metrics_contract(None)` does.
+    ClearMetricsContract,
+}
+
+/// Every tunable governance parameter with its effective value, defaults
+/// filled in. Returned by `get_config`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TunableConfig {
+    pub timelock_delay: u64,
+    pub config_change_delay: u64,
+    pub max_postponement: u64,
+    pub stale_proposal_age: u64,
+    pub ttl_warning_threshold: u32,
+    pub require_nonce: bool,
+    /// Set through `set_reveal_deadline`.
+    pub reveal_deadline: RevealDeadline,
+    /// Multisig threshold; 0 without multisig governance.
+    pub threshold: u32,
+    /// Set through `set_staging_delay`.
+    pub staging_delay: Option<u64>,
+    /// Set through `set_network_label`.
+    pub network_label: Option<Symbol>,
+    pub signer_acceptance_window: u64,
+    pub signer_inactivity_period: u64,
+}
+
+/// Partial `TunableConfig` for `set_config`: `Some` fields change, `None`
+/// fields keep their value. Each field is validated as by its own setter.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq, Default)]
+pub struct ConfigUpdate {
+    pub timelock_delay: Option<u64>,
+    pub config_change_delay: Option<u64>,
+    pub max_postponement: Option<u64>,
+    pub stale_proposal_age: Option<u64>,
+    pub ttl_warning_threshold: Option<u32>,
+    pub require_nonce: Option<bool>,
+    pub threshold: Option<u32>,
+    pub signer_acceptance_window: Option<u64>,
+    pub signer_inactivity_period: Option<u64>,
+}
+
+/// Published under `("config", "updated")` when a `ConfigUpdate` applies.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ConfigUpdatedEvent {
+    pub update: ConfigUpdate,
+    /// Action proposal that applied it; `None` for `set_config`.
+    pub proposal_id: Option<u64>,
+    pub timestamp: u64,
+    /// Event schema version for cross-version compatibility checks.
+    pub event_version: u32,
+}
+
+/// Published under `("action", "applied")` for each action, in order, when
+/// `execute_proposal` runs a bundle.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ActionAppliedEvent {
+    pub proposal_id: u64,
+    /// Position of the action in its bundle.
+    pub index: u32,
+    pub action: ProposalAction,
+    pub timestamp: u64,
+    /// Event schema version for cross-version compatibility checks.
+    pub event_version: u32,
+}
+
+/// [FIX-C01] Pre-committed migration hash for replay protection.
+///
+/// Admin must call `commit_migration(target_version, hash)` before calling
+/// `migrate(target_version, hash)`. The commitment is verified during migration
+/// to ensure the exact hash was pre-authorized by the admin in a separate tx.
+///
+/// # Replay Protection Flow
+/// 1. Admin calls `commit_migration(3, hash_of_migration_data)` → stored on-chain
+/// 2. Anyone can verify the commitment is live on-chain before execution
+/// 3. Admin calls `migrate(3, hash_of_migration_data)` → hash verified against commitment
+/// 4. Commitment is consumed (deleted) — cannot be replayed
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MigrationCommitment {
+    /// Target version this commitment authorizes
+    pub target_version: u32,
+    /// Hash committed by admin — must match hash passed to migrate()
+    pub hash: BytesN<32>,
+    /// Ledger timestamp when commitment was made
     pub committed_at: u64,
     /// Commitment expires after this timestamp (0 = no expiry)
     pub expires_at: u64,
@@ -320,6 +1423,63 @@ pub struct DeployedContract {
     pub deployed_at: u64,
 }
 
+/// Reward paid to callers of maintenance entrypoints that did useful work.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct KeeperRewardConfig {
+    /// Token the reward is paid in.
+    pub token: Address,
+    /// Amount paid per useful maintenance call.
+    pub reward_per_call: i128,
+}
+
+/// Auditor attestation policy checked by `upgrade` / `execute_upgrade`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AttestationConfig {
+    /// When `false`, attestations are recorded but not enforced.
+    pub require_attestation: bool,
+    /// Addresses allowed to attest; removing one voids their attestations.
+    pub auditors: Vec<Address>,
+    /// Minimum distinct configured auditors that must attest a hash (K).
+    pub min_attestations: u32,
+}
+
+/// An auditor's sign-off on a specific WASM hash.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Attestation {
+    pub auditor: Address,
+    /// Digest of the audit report covering this WASM.
+    pub report_digest: BytesN<32>,
+    pub attested_at: u64,
+}
+
+/// Name-keyed deployment record resolved by off-chain services.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DeploymentInfo {
+    /// Stable service-facing name (e.g. `bounty_escrow`).
+    pub name: Symbol,
+    /// Address the name currently resolves to.
+    pub address: Address,
+    /// Last version reported via `set_deployment_version` (`0` = unknown).
+    pub version: u32,
+    /// Ledger timestamp of the last registration or version update.
+    pub updated_at: u64,
+}
+
+/// Kind of address holding the admin role.
+///
+/// Contract admins (DAOs, multisig controllers) authorize by invoking this
+/// contract directly; account admins sign the transaction.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AdminType {
+    Account,
+    Contract,
+}
+
 /// Liveness watchdog status — a single read-only view of the contract's
 /// operational health, pause state, and maintenance mode.
 ///
@@ -374,6 +1534,11 @@ pub struct LivenessStatus {
 /// Instance storage - Persists across contract upgrades. This is critical for maintaining
 /// state continuity when upgrading contract WASM.
 ///
+/// Per-proposal keys (`UpgradeProposal`, `UpgradeProposalProposer`,
+/// `UpgradeTimelock`, `FleetUpgradeProposal`, `UnpauseProposal`) live in
+/// persistent storage instead, so historical proposals do not grow the
+/// instance entry every call loads.
+///
 /// # Storage Key Stability
 ///
 /// **IMPORTANT**: Storage keys must NEVER change between contract versions, as changing
@@ -404,13 +1569,14 @@ pub enum DataKey {
     /// - Used to determine which migration functions to execute
     /// - Persists across all WASM upgrades
     Version,
-  /// WASM hash stored per proposal (for multisig upgrades)
+  /// WASM hash stored per proposal (for multisig upgrades). Persistent.
     UpgradeProposal(u64),
 
     /// Proposer recorded per upgrade proposal.
     /// - Added as a separate key to preserve compatibility with older
     ///   deployments that already store `UpgradeProposal(u64)` as a raw hash.
     /// - Uses the same stable proposal identifier returned by `propose_upgrade`.
+    /// - Persistent, like every per-proposal key.
     UpgradeProposalProposer(u64),
 
     /// Migration state tracking - prevents double migration
@@ -494,1499 +1660,8844 @@ pub enum DataKey {
     LivenessSchemaVersion,
     /// Timestamp of the last successful ping_watchdog call.
     WatchdogLastPing,
-}
 
-// ============================================================================
-// Monitoring Module
-// ============================================================================
+    /// Circuit breaker thresholds evaluated by monitoring::track.
+    /// - Absent means the breaker is disabled
+    /// - Admin configurable via set_circuit_breaker_config()
+    CircuitBreakerConfig,
 
-mod monitoring {
-    use super::DataKey;
-    use soroban_sdk::{contracttype, symbol_short, Address, Env, String, Symbol, Vec};
+    /// Entrypoint symbols switched off by disable_function()
+    /// - Persistent storage; checked at the top of every mutating entrypoint
+    /// - `enable_function` and `execute_upgrade` can never appear here
+    DisabledFunctions,
 
-    const OPERATION_COUNT: &str = "op_count";
-    const USER_COUNT: &str = "usr_count";
-    const ERROR_COUNT: &str = "err_count";
-    const USER_INDEX: &str = "usr_index";
-    const LAST_OPERATION_TS: &str = "last_op_ts";
+    /// WASM hash of the installed code, as far as the contract knows
+    /// - Set by the `_with_wasm` init paths, every upgrade, and
+    ///   `attest_current_wasm`; used as `old_wasm_hash` in events
+    CurrentWasmHash,
 
-    #[contracttype]
-    #[derive(Clone, Debug)]
-    pub struct OperationMetric {
-        pub operation: Symbol,
-        pub caller: Address,
-        pub timestamp: u64,
-        pub success: bool,
-    }
+    /// Contracts notified via `on_dependency_upgraded` after each upgrade
+    /// - Bounded by MAX_UPGRADE_SUBSCRIBERS
+    /// - Managed by register_subscriber() / unregister_subscriber()
+    UpgradeSubscribers,
 
-    #[contracttype]
-    #[derive(Clone, Debug)]
-    pub struct PerformanceMetric {
-        pub function: Symbol,
-        pub duration: u64,
-        pub timestamp: u64,
-    }
+    /// Marks a multisig proposal as an unpause request.
+    /// - Set by propose_unpause(); executing it clears the pause flag
+    /// - Shares the proposal id space with upgrade proposals
+    UnpauseProposal(u64),
 
-    #[contracttype]
-    #[derive(Clone, Debug)]
-    pub struct HealthStatus {
-        pub is_healthy: bool,
-        pub last_operation: u64,
-        pub total_operations: u64,
-        pub contract_version: String,
-    }
+    /// Child contracts whose admin is this contract, upgradable as a fleet
+    /// - Bounded by MAX_CHILD_CONTRACTS
+    /// - Managed by register_child() / unregister_child()
+    ChildContracts,
 
-    #[contracttype]
-    #[derive(Clone, Debug)]
-    pub struct Analytics {
-        pub operation_count: u64,
-        pub unique_users: u64,
-        pub error_count: u64,
-        pub error_rate: u32,
-    }
+    /// FleetUpgradeProposal payload for a multisig proposal id
+    /// - Set by propose_fleet_upgrade(); shares the proposal id space
+    FleetUpgradeProposal(u64),
 
-    #[contracttype]
-    #[derive(Clone, Debug)]
-    pub struct StateSnapshot {
-        pub timestamp: u64,
-        pub total_operations: u64,
-        pub total_users: u64,
-        pub total_errors: u64,
+    /// Token and per-call amount for maintenance keeper rewards
+    /// - Absent means maintenance calls are unrewarded
+    KeeperRewardConfig,
+
+    /// Reward tokens funded via fund_rewards() and not yet paid out
+    /// or withdrawn via withdraw_reward_pool()
+    /// - Tracked separately from the contract's token balance
+    KeeperRewardPool,
+
+    /// Auditor list and enforcement flag for upgrade attestations
+    /// - Absent means attestations are not enforced
+    AttestationConfig,
+
+    /// Attestations recorded for a WASM hash (persistent storage)
+    /// - Written by attest_wasm(); entries removed by revoke_attestation()
+    WasmAttestations(BytesN<32>),
+
+    /// DeploymentInfo keyed by deployment name (persistent)
+    /// - Written by register_deployment() / set_deployment_version()
+    /// - Removed by unregister_deployment()
+    DeploymentByName(Symbol),
+
+    /// Registered deployment names in registration order (persistent)
+    /// - Bounded by MAX_DEPLOYED_CONTRACTS; backs list_deployments()
+    DeploymentNames,
+
+    /// Storage layout version, distinct from the contract `Version`
+    /// - Written at init; advanced only by migrate_schema()
+    /// - Absent means 1, the layout before the key existed
+    SchemaVersion,
+
+    /// Next proposal ID sweep_proposals() examines; absent means 1
+    SweepCursor,
+
+    /// RevealDeadline for commit-reveal proposals; absent means BeforeApproval
+    RevealDeadline,
+
+    /// Descriptive `ContractMetadata` shown by explorers
+    Metadata,
+
+    /// u64 nonce expected by the next `_with_nonce` admin call
+    AdminNonce,
+
+    /// bool; when true the nonce-less admin entrypoints are refused
+    RequireNonce,
+
+    /// Maximum total seconds `postpone` may delay one proposal
+    MaxPostponement,
+
+    /// Timestamp at which maintenance mode ends on its own; absent when off
+    MaintenanceUntil,
+
+    /// GovernanceTotals behind get_governance_stats()
+    GovernanceTotals,
+
+    /// Symbol naming this deployment's network, e.g. "mainnet"; absent
+    /// when the safeguard is off
+    NetworkLabel,
+
+    /// InitMode written by every init path and by snapshot restores
+    InitMode,
+}
+
+/// Per-proposal entries for multisig proposals, all in persistent storage
+/// and removed with the proposal. Split from `DataKey`, which is at
+/// the 50-variant limit of a contract type; keys encode by variant name, so
+/// these entries are unchanged from when they lived in `DataKey`.
+#[contracttype]
+#[derive(Clone)]
+pub enum ProposalKey {
+    /// Unrevealed commitment for a commit-reveal upgrade proposal
+    /// - Written by propose_upgrade_commit(); removed by reveal_upgrade()
+    UpgradeCommitment(u64),
+
+    /// ExecutionWindow of an upgrade proposal; absent means unbounded
+    /// - `postpone` moves its `not_before` later
+    UpgradeWindow(u64),
+
+    /// Timestamp an upgrade proposal was created or last amended
+    UpgradeProposedAt(u64),
+
+    /// Set once a signer other than the proposer approves
+    /// - Blocks amend_proposal() for good, even if that approval is revoked
+    UpgradeAmendLocked(u64),
+
+    /// ID of the proposal `repropose` created from this expired one
+    UpgradeSupersededBy(u64),
+
+    /// Seconds of `postpone` budget a proposal has used
+    UpgradePostponed(u64),
+
+    /// Vec<ProposalAction> bundle for a multisig proposal id
+    /// - Set by propose_actions(); shares the proposal id space
+    ActionProposal(u64),
+
+    /// ProposalTimings, written at creation and kept as history after
+    /// execution
+    ProposalTimings(u64),
+
+    /// Network label in force when an upgrade proposal was created
+    UpgradeNetwork(u64),
+
+    /// u32 multisig threshold in force when a proposal was created; kept
+    /// as history after execution
+    ThresholdAtCreation(u64),
+
+    /// String description of an upgrade proposal; absent means empty
+    /// - Set by propose_upgrade_with_description(); kept as history
+    UpgradeDescription(u64),
+
+    /// u64 release-pipeline ID of an upgrade proposal; kept as history
+    UpgradeExternalId(u64),
+
+    /// Latest proposal ID linked to an external ID
+    /// - Repointed when a new proposal reuses the ID after the old one closed
+    ExternalIdIndex(u64),
+
+    /// Content key `sha256(wasm_hash || nonce || proposer)` of a proposal
+    /// made by propose_upgrade_idempotent(); kept as history
+    UpgradeContentKey(u64),
+
+    /// Latest proposal ID created under a content key
+    /// - Repointed when the content is proposed again after the old one closed
+    ContentKeyIndex(BytesN<32>),
+
+    /// ExecutionAttempts of a proposal; kept as history
+    ExecutionAttempts(u64),
+
+    /// Vec<(Address, String, u64)> of revocations and cancellations with
+    /// their reasons, oldest first; kept as history
+    Rejections(u64),
+
+    /// u32 oldest network protocol an upgrade proposal's WASM supports
+    /// - Set by propose_upgrade_min_protocol(); kept as history
+    UpgradeMinProtocol(u64),
+}
+
+/// Append-only archives of executed upgrades and threshold changes, and the
+/// overall upgrade counter. Never pruned.
+#[contracttype]
+#[derive(Clone)]
+pub enum ArchiveKey {
+    /// u64 number of archived upgrades (instance)
+    UpgradeArchiveCount,
+
+    /// ArchivedUpgrade at a zero-based index (persistent)
+    UpgradeArchive(u64),
+
+    /// u64 number of threshold history entries (instance)
+    ThresholdHistoryCount,
+
+    /// ThresholdChange at a zero-based index (persistent)
+    ThresholdHistory(u64),
+
+    /// u64 number of WASM swaps through any upgrade path (persistent)
+    UpgradeCount,
+
+    /// u64 archive index of an executed upgrade proposal (persistent)
+    ArchiveIndex(u64),
+
+    /// u64 highest ID of an executed multisig upgrade proposal (instance)
+    /// - Absent until the first upgrade executes after it was introduced
+    HighestExecutedUpgrade,
+
+    /// u64 number of version history entries (instance)
+    VersionHistoryCount,
+
+    /// VersionChange at a zero-based index (persistent)
+    VersionHistory(u64),
+
+    /// u64 entries a history keeps; absent means DEFAULT_HISTORY_RETENTION
+    /// (instance)
+    HistoryRetention(HistoryKind),
+
+    /// u64 index of a history's oldest retained entry; absent means 0
+    /// (instance)
+    HistoryFirst(HistoryKind),
+}
+
+/// Inputs to the stale-proposal and signer-inactivity health checks
+/// (instance).
+#[contracttype]
+#[derive(Clone)]
+pub enum HealthKey {
+    /// Vec<u64> of upgrade proposal IDs that may still be open, at most
+    /// `MAX_PENDING_UPGRADES`. Entries that closed are dropped when the
+    /// next proposal is added (persistent; older deployments kept it in
+    /// instance storage until that next proposal)
+    PendingUpgrades,
+
+    /// u64 seconds after which an open upgrade proposal counts as stale
+    StaleProposalAge,
+
+    /// u64 seconds without activity after which `health_check` reports a
+    /// signer as inactive; absent or 0 turns the report off
+    SignerInactivityPeriod,
+
+    /// Next proposal ID check_consistency() examines for its `archive`
+    /// check; absent means 1
+    ConsistencyCursor,
+}
+
+/// Relayed execution settings (instance).
+#[contracttype]
+#[derive(Clone)]
+pub enum RelayerKey {
+    /// Address governance allows to execute approved upgrades on the
+    /// signers' behalf through `execute_upgrade_relayed`
+    Relayer,
+}
+
+/// Human-readable version (instance).
+#[contracttype]
+#[derive(Clone)]
+pub enum VersionKey {
+    /// String set by `set_version_label`
+    Label,
+}
+
+/// Multi-step operation guard (instance).
+#[contracttype]
+#[derive(Clone)]
+pub enum TransitionKey {
+    /// Symbol of the operation running; entrypoints fail with
+    /// `TransitionError::InProgress` while it is set
+    InProgress,
+}
+
+/// Deployment fingerprint (instance). The environment label itself is
+/// `DataKey::NetworkLabel`.
+#[contracttype]
+#[derive(Clone)]
+pub enum FingerprintKey {
+    /// BytesN<32> network passphrase hash recorded with the label; named
+    /// apart from `DataKey::NetworkId`, which holds the init-time string
+    NetworkHash,
+}
+
+/// Proposal templates (persistent).
+#[contracttype]
+#[derive(Clone)]
+pub enum TemplateKey {
+    /// Vec<ProposalAction> stored under a template name
+    Template(Symbol),
+    /// Vec<Symbol> of template names in creation order
+    Names,
+}
+
+/// Approval settings (instance).
+#[contracttype]
+#[derive(Clone)]
+pub enum ApprovalKey {
+    /// Set when governance turned off `approve_upgrade` and
+    /// `approve_upgrade_on_network` in favour of `approve_upgrade_v2`
+    LegacyApprovalDisabled,
+    /// `DuplicateApproval` mode; absent means `Strict`
+    DuplicateApproval,
+    /// Set when governance requires a non-empty reason to revoke an
+    /// approval or cancel a proposal
+    RejectionReasonRequired,
+}
+
+/// Two-phase admin upgrade state (instance).
+#[contracttype]
+#[derive(Clone)]
+pub enum StagingKey {
+    /// u64 seconds between `stage_upgrade` and `confirm_upgrade`; its
+    /// presence turns staging on and the one-shot `upgrade` off
+    StagingDelay,
+
+    /// StagedUpgrade awaiting confirmation
+    StagedUpgrade,
+
+    /// UpgradeTicket issued by authorize_upgrade
+    UpgradeTicket,
+}
+
+/// WASM hashes that must never be installed (persistent).
+#[contracttype]
+#[derive(Clone)]
+pub enum BlocklistKey {
+    /// BlockedWasm for a blocked hash
+    Blocked(BytesN<32>),
+}
+
+/// Signer onboarding state (instance).
+#[contracttype]
+#[derive(Clone)]
+pub enum OnboardingKey {
+    /// Vec<PendingSigner> added by `add_signer` and not yet accepted
+    PendingSigners,
+    /// u64 seconds a pending signer has to accept; absent means
+    /// `DEFAULT_SIGNER_ACCEPTANCE_WINDOW`
+    AcceptanceWindow,
+}
+
+/// Guardian emergency pause state (instance).
+#[contracttype]
+#[derive(Clone)]
+pub enum GuardianKey {
+    /// Address allowed to call `guardian_pause`
+    Guardian,
+    /// EmergencyPauseSettings; absent means the defaults
+    Settings,
+    /// EmergencyPause of the latest incident, kept after it expires so the
+    /// cooldown can be enforced
+    LastPause,
+}
+
+/// Instance TTL bookkeeping (instance). The contract cannot read its own
+/// TTL, so the ledger each extension reached is recorded instead.
+#[contracttype]
+#[derive(Clone)]
+pub enum TtlKey {
+    /// u32 ledger sequence the last `extend_instance_ttl` extended to
+    InstanceExtendedTo,
+
+    /// u32 ledgers remaining below which calls publish `("ttl", "low")`
+    TtlWarningThreshold,
+}
+
+/// Governance configuration bookkeeping (instance).
+#[contracttype]
+#[derive(Clone)]
+pub enum ConfigKey {
+    /// `ConfigProvenance` of the `TunableConfig` field with this name
+    Provenance(Symbol),
+}
+
+/// Who made a configuration change.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ConfigSource {
+    /// A direct call to the setter by the admin.
+    Admin,
+    /// The executed multisig proposal with this ID.
+    Proposal(u64),
+}
+
+/// Last change of one tunable, returned by `get_config_provenance`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ConfigProvenance {
+    pub source: ConfigSource,
+    pub changed_at: u64,
+}
+
+/// Published under `("ttl", "low")` by any call made while the recorded
+/// instance TTL is below the warning threshold.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TtlLowEvent {
+    /// Estimated ledgers until the instance entry expires.
+    pub ledgers_remaining: u32,
+    pub extended_to: u32,
+    pub threshold: u32,
+    /// Event schema version for cross-version compatibility checks.
+    pub event_version: u32,
+}
+
+/// Upgrade recorded by `stage_upgrade`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StagedUpgrade {
+    pub wasm_hash: BytesN<32>,
+    pub staged_at: u64,
+    /// Earliest ledger timestamp `confirm_upgrade` accepts.
+    pub confirm_after: u64,
+}
+
+/// Published under `("upgrade", <step>)` for the `staged`, `confirmed`,
+/// `unstaged`, and `revoked` steps of a two-phase admin upgrade.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StagedUpgradeEvent {
+    pub wasm_hash: BytesN<32>,
+    pub confirm_after: u64,
+    pub timestamp: u64,
+    /// Event schema version for cross-version compatibility checks.
+    pub event_version: u32,
+}
+
+/// Published under `("irrev", <step>)` for the `requested`, `confirmed`,
+/// and `cancelled` steps of an irreversible action.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct IrreversibleActionEvent {
+    pub action_hash: BytesN<32>,
+    pub confirm_after: u64,
+    pub expires_at: u64,
+    pub timestamp: u64,
+    /// Event schema version for cross-version compatibility checks.
+    pub event_version: u32,
+}
+
+/// Entry of `list_authorities`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Authority {
+    pub address: Address,
+    /// `admin`, `signer`, `relayer`, or `auditor`.
+    pub role: Symbol,
+}
+
+/// Why and when governance blocked a WASM hash with `block_wasm_hash`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BlockedWasm {
+    pub reason: String,
+    pub blocked_at: u64,
+}
+
+/// Emitted as `("wasm", "blocked")` and `("wasm", "unblocked")`. An
+/// unblock carries the reason the hash was blocked for.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WasmBlockEvent {
+    pub wasm_hash: BytesN<32>,
+    pub reason: String,
+    /// The admin; `None` when an action proposal applied the change.
+    pub caller: Option<Address>,
+    /// Action proposal that applied it; `None` for the admin entrypoints.
+    pub proposal_id: Option<u64>,
+    pub timestamp: u64,
+    /// Event schema version for cross-version compatibility checks.
+    pub event_version: u32,
+}
+
+/// Signer added by `add_signer` who has not yet called
+/// `accept_signership`. Holds no signer rights until then.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingSigner {
+    pub address: Address,
+    pub added_at: u64,
+    /// Last timestamp at which `accept_signership` succeeds.
+    pub expires_at: u64,
+    /// Action proposal that added the signer; 0 when the admin did.
+    pub proposal_id: u64,
+}
+
+/// One-shot upgrade the admin pre-authorized with `authorize_upgrade`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UpgradeTicket {
+    pub wasm_hash: BytesN<32>,
+    /// First ledger timestamp `execute_authorized_upgrade` accepts.
+    pub not_before: u64,
+    /// Ledger timestamp from which the ticket is expired.
+    pub not_after: u64,
+    pub authorized_by: Address,
+    pub authorized_at: u64,
+}
+
+/// Published under `("ticket", <step>)` for the `issued`, `used`,
+/// `cancelled`, and `revoked` steps of a pre-authorized upgrade.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UpgradeTicketEvent {
+    pub wasm_hash: BytesN<32>,
+    pub not_before: u64,
+    pub not_after: u64,
+    pub timestamp: u64,
+    /// Event schema version for cross-version compatibility checks.
+    pub event_version: u32,
+}
+
+/// Default `StaleProposalAge`: 30 days.
+pub const DEFAULT_STALE_PROPOSAL_AGE: u64 = 30 * 24 * 60 * 60;
+
+/// When a commit-reveal upgrade proposal must be revealed.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RevealDeadline {
+    /// Approvals are rejected until the reveal, so signers always see the
+    /// hash they approve on-chain.
+    BeforeApproval,
+    /// Signers approve the commitment, having checked it against the hash
+    /// and salt shared privately; only execution waits for the reveal. Keeps
+    /// the hash private through the timelock.
+    BeforeExecution,
+}
+
+/// How the approval entrypoints treat a signer approving a proposal they
+/// already approved.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DuplicateApproval {
+    /// Fail with `AlreadyApproved`.
+    Strict,
+    /// Succeed as a no-op without an event, so retried transactions do not
+    /// raise alerts.
+    Idempotent,
+}
+
+// ============================================================================
+// Monitoring Module
+// ============================================================================
+
+mod monitoring {
+    use super::{
+        ArchiveKey, DataKey, HealthKey, InitMode, MultiSig, ProposalKey, ProposalTimings, DEFAULT_STALE_PROPOSAL_AGE,
+    };
+    use crate::paging::{MetricsPage, MAX_PAGE_SIZE};
+    use grainlify_upgradeable::MetricsSinkClient;
+    use soroban_sdk::{
+        contracttype, symbol_short, Address, Env, IntoVal, Map, String, Symbol, TryFromVal, Val, Vec,
+    };
+
+    // Metric names under the schema 1 layout, read by the migration; the
+    // totals' names double as row names of `export_metrics`. Several exceed
+    // `symbol_short!`'s nine characters, so they stay `&str`.
+    const OPERATION_COUNT: &str = "op_count";
+    const USER_COUNT: &str = "usr_count";
+    const ERROR_COUNT: &str = "err_count";
+    const USER_INDEX: &str = "usr_index";
+    const LAST_OPERATION_TS: &str = "last_op_ts";
+    const CB_FAILURE_STREAK: &str = "cb_streak";
+    const CB_WINDOW_OPS: &str = "cb_win_ops";
+    const CB_WINDOW_ERRORS: &str = "cb_win_err";
+    const TTL_BUMPED_AT: &str = "ttl_bumped";
+    const RECORDED_SNAPSHOT: &str = "snap_rec";
+    const PERF_COUNT: &str = "perf_cnt";
+    const PERF_TIME: &str = "perf_time";
+    const PERF_LAST: &str = "perf_last";
+    const PERF_INDEX: &str = "perf_index";
+
+    /// Storage schema that introduced the `MetricsKey` layout; readers that
+    /// would misread an older store require it.
+    pub const SCHEMA: u32 = 2;
+    /// False in builds without the `monitoring` feature: tracking becomes a
+    /// no-op and the metric entrypoints fail with `FeatureDisabled`.
+    pub const ENABLED: bool = cfg!(feature = "monitoring");
+
+    /// Monitoring storage keys (persistent). Counters that change together
+    /// share one entry, so each tracked call is a single read-modify-write.
+    #[contracttype]
+    #[derive(Clone)]
+    pub enum MetricsKey {
+        /// `MetricTotals` across all tracked operations
+        Totals,
+        /// Distinct callers seen so far, capped at `MAX_TRACKED_USERS`
+        TrackedUsers,
+        /// `BreakerCounters` for the circuit breaker's current window
+        BreakerWindow,
+        /// Ledger sequence of the last `bump_metrics_ttl` extension
+        TtlBumpedAt,
+        /// Last `StateSnapshot` stored by `record_snapshot`
+        RecordedSnapshot,
+        /// Functions with performance counters, oldest first
+        PerfIndex,
+        /// `PerfCounters` for one function
+        FunctionPerf(Symbol),
+        /// `FailureCounts` by operation and error code
+        Failures,
+        /// `GovernanceFailureStats` by governance stage
+        GovernanceFailures,
+        /// `ModeStats` of one governance mode (`admin` or `multisig`)
+        ModeStats(Symbol),
+        /// Ledger timestamp the current governance mode began, once it
+        /// changed after initialization
+        ModeSwitchedAt,
+        /// `VersionStats` of one stored contract version
+        VersionStats(u32),
+        /// u32 version `VersionStats` are currently accumulated under
+        ActiveVersion,
+    }
+
+    /// Monitoring settings (instance), read by every tracked call.
+    #[contracttype]
+    #[derive(Clone)]
+    pub enum MonitoringKey {
+        /// Map<Symbol, bool> of operations whose tracking governance turned
+        /// on or off; operations not listed are tracked
+        TrackingOverrides,
+        /// Address of the companion `MetricsSink` contract tracked calls are
+        /// forwarded to; absent means metrics are kept internally
+        MetricsContract,
     }
 
+    /// Counters of `track_failure` calls, stored under `MetricsKey::Failures`.
+    /// Each map holds at most `MAX_TRACKED_FUNCTIONS` /
+    /// `MAX_TRACKED_ERROR_CODES` keys; failures under further keys only
+    /// reach the global `error_count`.
     #[contracttype]
     #[derive(Clone, Debug)]
-    pub struct PerformanceStats {
-        pub function_name: Symbol,
-        pub call_count: u64,
-        pub total_time: u64,
-        pub avg_time: u64,
-        pub last_called: u64,
+    pub struct FailureCounts {
+        pub by_function: Map<Symbol, u64>,
+        pub by_code: Map<u32, u64>,
+    }
+
+    /// Governance step a failure belongs to. Only failures absorbed by an
+    /// `attempt_*` entry point or a fleet child are counted; a failed
+    /// `propose_upgrade`, `approve_upgrade` or `cancel_upgrade` reverts and
+    /// leaves nothing to count.
+    #[contracttype]
+    #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+    pub enum GovernanceStage {
+        /// `attempt_propose_upgrade` refusals.
+        Propose,
+        /// `attempt_approve_upgrade` refusals.
+        Approve,
+        /// `attempt_execute_upgrade` checks and fleet children.
+        Execute,
+        /// `attempt_cancel_upgrade` refusals.
+        Cancel,
+    }
+
+    /// `track_governance_failure` calls per stage, stored under
+    /// `MetricsKey::GovernanceFailures`.
+    #[contracttype]
+    #[derive(Clone, Debug, Default, Eq, PartialEq)]
+    pub struct GovernanceFailureStats {
+        pub propose: u64,
+        pub approve: u64,
+        pub execute: u64,
+        pub cancel: u64,
+    }
+
+    /// Published as `("metric", "gov_fail")` by `track_governance_failure`.
+    #[contracttype]
+    #[derive(Clone, Debug)]
+    pub struct GovernanceFailureMetric {
+        pub operation: Symbol,
+        pub stage: GovernanceStage,
+        pub code: u32,
+        pub timestamp: u64,
     }
 
+    /// Tracked operations while the contract was in one governance mode,
+    /// stored under `MetricsKey::ModeStats`. `by_operation` holds at most
+    /// `MAX_TRACKED_FUNCTIONS` keys.
     #[contracttype]
     #[derive(Clone, Debug, Eq, PartialEq)]
-    pub struct InvariantReport {
-        pub healthy: bool,
-        pub config_sane: bool,
-        pub metrics_sane: bool,
-        pub admin_set: bool,
-        pub version_set: bool,
-        pub version: u32,
+    pub struct ModeStats {
         pub operation_count: u64,
-        pub unique_users: u64,
         pub error_count: u64,
-        pub violation_count: u32,
+        pub by_operation: Map<Symbol, u64>,
+        /// Seconds spent in the mode. Stored for closed periods only;
+        /// `get_mode_stats` adds the running one.
+        pub duration: u64,
     }
 
-    pub const MAX_TRACKED_FUNCTIONS: u32 = 50;
-    pub const MAX_TRACKED_USERS: u32 = 64;
+    /// Tracked operations while one contract version was stored, under
+    /// `MetricsKey::VersionStats`. Counted like `ModeStats`.
+    #[contracttype]
+    #[derive(Clone, Debug, Default, Eq, PartialEq)]
+    pub struct VersionStats {
+        pub operation_count: u64,
+        pub error_count: u64,
+        /// Ledger timestamp the version was first stored; 0 when that
+        /// predates per-version stats.
+        pub active_from: u64,
+        /// Ledger timestamp a different version replaced it; `None` while
+        /// it is current.
+        pub active_until: Option<u64>,
+    }
 
-    fn get_counter(env: &Env, key: &str) -> u64 {
-        env.storage()
-            .persistent()
-            .get(&Symbol::new(env, key))
-            .unwrap_or(0)
+    pub const ADMIN_MODE: Symbol = symbol_short!("admin");
+    pub const MULTISIG_MODE: Symbol = symbol_short!("multisig");
+
+    /// Operation totals, stored under `MetricsKey::Totals`. Rewritten on
+    /// every tracked call, so the field names fit `symbol_short!`'s nine
+    /// characters and converting the entry allocates no symbols.
+    #[contracttype]
+    #[derive(Clone, Debug, Default)]
+    pub struct MetricTotals {
+        pub op_count: u64,
+        pub err_count: u64,
+        /// Saturates at `MAX_TRACKED_USERS`
+        pub usr_count: u64,
+        pub last_op_ts: u64,
+    }
+
+    #[contracttype]
+    #[derive(Clone, Debug, Default)]
+    pub struct BreakerCounters {
+        pub failure_streak: u64,
+        pub window_operations: u64,
+        pub window_errors: u64,
+    }
+
+    #[contracttype]
+    #[derive(Clone, Debug, Default)]
+    pub struct PerfCounters {
+        pub call_count: u64,
+        pub total_time: u64,
+        pub last_called: u64,
+    }
+
+    /// Metric entries are extended once their TTL would drop below this many ledgers.
+    pub const METRICS_TTL_THRESHOLD: u32 = 17_280 * 7;
+    /// Ledgers of TTL a bump extends metric entries to (~30 days).
+    pub const METRICS_TTL_EXTEND_TO: u32 = 17_280 * 30;
+    /// Minimum seconds between two recorded state snapshots.
+    pub const SNAPSHOT_INTERVAL: u64 = 3_600;
+
+    #[contracttype]
+    #[derive(Clone, Debug)]
+    pub struct OperationMetric {
+        pub operation: Symbol,
+        pub caller: Address,
+        pub timestamp: u64,
+        pub success: bool,
+        /// `direct` or `contract`; see `call_kind`.
+        pub call_kind: Symbol,
+        /// Stored contract version when the operation was recorded.
+        pub version: u32,
+    }
+
+    /// Published as `("metric", "version")` with each tracked `set_version`,
+    /// so metric consumers see the transition and not just the call.
+    #[contracttype]
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    pub struct VersionMetric {
+        pub old_version: u32,
+        pub new_version: u32,
+        pub caller: Address,
+        pub timestamp: u64,
+    }
+
+    #[contracttype]
+    #[derive(Clone, Debug)]
+    pub struct PerformanceMetric {
+        pub function: Symbol,
+        pub duration: u64,
+        pub timestamp: u64,
+    }
+
+    #[contracttype]
+    #[derive(Clone, Debug)]
+    pub struct HealthStatus {
+        pub is_healthy: bool,
+        pub last_operation: u64,
+        pub total_operations: u64,
+        pub contract_version: String,
+        /// Label set with `set_version_label`, if any.
+        pub version_label: Option<String>,
+        pub is_paused: bool,
+        /// `invariants` or `stale_proposal` when unhealthy.
+        pub unhealthy_reason: Option<Symbol>,
+        /// Oldest open upgrade proposal past the stale-proposal age.
+        pub stale_proposal_id: Option<u64>,
+        /// Signers idle for longer than the signer inactivity period.
+        /// Informational: does not affect `is_healthy`.
+        pub inactive_signers: Vec<Address>,
+        /// Expiry of the active guardian emergency pause, if any.
+        pub emergency_paused_until: Option<u64>,
+    }
+
+    /// Returned by `get_caller_stats`.
+    #[contracttype]
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    pub struct CallerStats {
+        /// Same as `Analytics::unique_users`.
+        pub unique_callers: u64,
+        /// `unique_callers` split by `OperationMetric::call_kind`.
+        pub by_call_kind: Map<Symbol, u64>,
+    }
+
+    /// Returned by `get_failure_breakdown`.
+    #[contracttype]
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    pub struct FailureBreakdown {
+        /// Global `error_count`, including failures the maps do not cover.
+        pub total_errors: u64,
+        pub by_function: Map<Symbol, u64>,
+        pub by_code: Map<u32, u64>,
+    }
+
+    #[contracttype]
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    pub struct Analytics {
+        pub operation_count: u64,
+        pub unique_users: u64,
+        pub error_count: u64,
+        pub error_rate: u32,
+        pub upgrade_count: u64,
+    }
+
+    #[contracttype]
+    #[derive(Clone, Debug)]
+    pub struct StateSnapshot {
+        pub timestamp: u64,
+        pub total_operations: u64,
+        pub total_users: u64,
+        pub total_errors: u64,
+    }
+
+    #[contracttype]
+    #[derive(Clone, Debug)]
+    pub struct PerformanceStats {
+        pub function_name: Symbol,
+        pub call_count: u64,
+        pub total_time: u64,
+        pub avg_time: u64,
+        pub last_called: u64,
+    }
+
+    #[contracttype]
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    pub struct InvariantReport {
+        pub healthy: bool,
+        pub config_sane: bool,
+        pub metrics_sane: bool,
+        pub admin_set: bool,
+        pub version_set: bool,
+        pub version: u32,
+        pub operation_count: u64,
+        pub unique_users: u64,
+        pub error_count: u64,
+        pub violation_count: u32,
+    }
+
+    /// Thresholds that automatically pause the contract when monitoring sees
+    /// too many `attempt_*` failures from the admin or signers; see
+    /// `set_circuit_breaker_config`. Either check can be switched off by
+    /// setting it to 0.
+    #[contracttype]
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    pub struct CircuitBreakerConfig {
+        /// Master switch; when false no tracked outcome trips the breaker.
+        pub enabled: bool,
+        /// Trip after this many back-to-back failures (0 = streak check off).
+        pub max_consecutive_failures: u32,
+        /// Trip when window errors / window operations exceed this, in basis
+        /// points (0 = rate check off).
+        pub max_error_rate_bps: u32,
+        /// Operations per evaluation window; the rate is only checked once a
+        /// window is full, then the window restarts.
+        pub window_size: u32,
+    }
+
+    /// Emitted when the circuit breaker pauses the contract.
+    #[contracttype]
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    pub struct CircuitBreakerEvent {
+        /// `streak` or `rate`, depending on which limit was crossed.
+        pub reason: Symbol,
+        pub operation: Symbol,
+        pub failure_streak: u64,
+        pub window_operations: u64,
+        pub window_errors: u64,
+        pub timestamp: u64,
+        /// Event schema version for cross-version compatibility checks.
+        pub event_version: u32,
+    }
+
+    pub const MAX_TRACKED_FUNCTIONS: u32 = 50;
+    pub const MAX_TRACKED_USERS: u32 = 64;
+    pub const MAX_TRACKED_ERROR_CODES: u32 = 50;
+
+    fn get_totals(env: &Env) -> MetricTotals {
+        env.storage()
+            .persistent()
+            .get(&MetricsKey::Totals)
+            .unwrap_or_default()
+    }
+
+    fn get_tracked_users(env: &Env) -> Vec<Address> {
+        env.storage()
+            .persistent()
+            .get(&MetricsKey::TrackedUsers)
+            .unwrap_or(Vec::new(env))
+    }
+
+    /// Adds `caller` to the tracked users unless already present or the
+    /// index is full, and returns the number of tracked users.
+    fn track_unique_user(env: &Env, caller: &Address) -> u64 {
+        let mut users = get_tracked_users(env);
+        if users.len() < MAX_TRACKED_USERS && !users.contains(caller) {
+            users.push_back(caller.clone());
+            env.storage().persistent().set(&MetricsKey::TrackedUsers, &users);
+        }
+        users.len().into()
+    }
+
+    /// Removes and returns the entry stored under an older layout's key.
+    fn take_legacy<V: TryFromVal<Env, Val>>(env: &Env, key: Val) -> Option<V> {
+        let value = env.storage().persistent().get(&key)?;
+        env.storage().persistent().remove(&key);
+        Some(value)
+    }
+
+    /// Schema 1 → 2: folds the metric entries under bare `Symbol` keys and
+    /// the per-function `(name, function)` performance tuples into the
+    /// `MetricsKey` entries and removes them. Upgrade paths that ran between
+    /// the code swap and the migration already wrote under the new keys, so
+    /// the two are merged: counters add up, the user index is unioned, the
+    /// last-operation time and recorded snapshot keep the newer value, and
+    /// circuit-breaker window state and the TTL bump marker restart.
+    /// Functions that no longer fit under `MAX_TRACKED_FUNCTIONS` are
+    /// dropped.
+    pub fn consolidate_metric_keys(env: &Env) {
+        let legacy_key = |name: &str| Symbol::new(env, name).to_val();
+        let mut totals = get_totals(env);
+        if let Some(legacy) = take_legacy::<u64>(env, legacy_key(OPERATION_COUNT)) {
+            totals.op_count = totals.op_count.saturating_add(legacy);
+        }
+        if let Some(legacy) = take_legacy::<u64>(env, legacy_key(ERROR_COUNT)) {
+            totals.err_count = totals.err_count.saturating_add(legacy);
+        }
+        if let Some(legacy) = take_legacy::<u64>(env, legacy_key(LAST_OPERATION_TS)) {
+            totals.last_op_ts = totals.last_op_ts.max(legacy);
+        }
+        if let Some(legacy) = take_legacy::<Vec<Address>>(env, legacy_key(USER_INDEX)) {
+            for user in legacy.iter() {
+                totals.usr_count = totals.usr_count.max(track_unique_user(env, &user));
+            }
+        }
+        // The index is capped, so the stored count can exceed its length.
+        if let Some(legacy) = take_legacy::<u64>(env, legacy_key(USER_COUNT)) {
+            totals.usr_count = totals.usr_count.max(legacy);
+        }
+        if totals.op_count > 0 {
+            env.storage().persistent().set(&MetricsKey::Totals, &totals);
+        }
+        if let Some(legacy) = take_legacy::<StateSnapshot>(env, legacy_key(RECORDED_SNAPSHOT)) {
+            if get_recorded_snapshot(env).is_none_or(|current| current.timestamp < legacy.timestamp) {
+                env.storage().persistent().set(&MetricsKey::RecordedSnapshot, &legacy);
+            }
+        }
+        for name in [CB_FAILURE_STREAK, CB_WINDOW_OPS, CB_WINDOW_ERRORS, TTL_BUMPED_AT] {
+            take_legacy::<u64>(env, legacy_key(name));
+        }
+
+        let Some(legacy) = take_legacy::<Vec<Symbol>>(env, legacy_key(PERF_INDEX)) else {
+            return;
+        };
+        let (count_name, time_name, last_name) =
+            (Symbol::new(env, PERF_COUNT), Symbol::new(env, PERF_TIME), Symbol::new(env, PERF_LAST));
+        let mut index = get_perf_index(env);
+        for function in legacy.iter() {
+            let count = take_legacy::<u64>(env, (count_name.clone(), function.clone()).into_val(env));
+            let time = take_legacy::<u64>(env, (time_name.clone(), function.clone()).into_val(env));
+            let last = take_legacy::<u64>(env, (last_name.clone(), function.clone()).into_val(env));
+            if !index.contains(&function) {
+                if index.len() >= MAX_TRACKED_FUNCTIONS {
+                    continue;
+                }
+                index.push_back(function.clone());
+            }
+            let key = MetricsKey::FunctionPerf(function);
+            let mut perf: PerfCounters = env.storage().persistent().get(&key).unwrap_or_default();
+            perf.call_count = perf.call_count.saturating_add(count.unwrap_or(0));
+            perf.total_time = perf.total_time.saturating_add(time.unwrap_or(0));
+            perf.last_called = perf.last_called.max(last.unwrap_or(0));
+            env.storage().persistent().set(&key, &perf);
+        }
+        env.storage().persistent().set(&MetricsKey::PerfIndex, &index);
+    }
+
+    /// [FIX-H03] Dynamic semver decoding — handles any version, not just hardcoded ones
+    fn version_semver_string(env: &Env) -> String {
+        let raw: u32 = env.storage().instance().get(&DataKey::Version).unwrap_or(0);
+        // Promote legacy single-digit versions (1,2,...) to major.0.0 encoding
+        let encoded = if raw >= 10_000 { raw } else { raw.saturating_mul(10_000) };
+        let major = encoded / 10_000;
+        let minor = (encoded % 10_000) / 100;
+        let patch = encoded % 100;
+
+        // Build semver string without heap alloc
+        let mut buf = [0u8; 12];
+        let mut pos = 0usize;
+
+        macro_rules! write_u32 {
+            ($n:expr) => {
+                let n: u32 = $n;
+                if n >= 100 { buf[pos] = b'0' + (n / 100) as u8; pos += 1; }
+                if n >= 10 { buf[pos] = b'0' + ((n % 100) / 10) as u8; pos += 1; }
+                buf[pos] = b'0' + (n % 10) as u8; pos += 1;
+            };
+        }
+
+        write_u32!(major);
+        buf[pos] = b'.'; pos += 1;
+        write_u32!(minor);
+        buf[pos] = b'.'; pos += 1;
+        write_u32!(patch);
+
+        let s = core::str::from_utf8(&buf[..pos]).unwrap_or("0.0.0");
+        String::from_str(env, s)
+    }
+
+    /// An operation started by `track_start`.
+    #[must_use = "call finish() once the operation's fallible work is done"]
+    pub struct TrackedOperation {
+        operation: Symbol,
+        started_at: u64,
+    }
+
+    /// Starts timing `operation`. Nothing is recorded until `finish`, so call
+    /// it first and `finish` last: an invocation that traps in between is
+    /// reverted along with its metrics instead of counting as a success.
+    ///
+    /// It is not counted as an error either: the host rolls back every
+    /// write of a failed invocation, so a trapped or erroring call such as
+    /// a second `init_admin` leaves no trace. Failures are counted when the
+    /// contract absorbs them and reports them through `finish` or through
+    /// an `attempt_*` entry point such as `attempt_init_admin`.
+    pub fn track_start(env: &Env, operation: Symbol) -> TrackedOperation {
+        TrackedOperation { operation, started_at: env.ledger().timestamp() }
+    }
+
+    impl TrackedOperation {
+        /// Records the outcome through `track` and the duration
+        /// through `emit_performance`. Failures can only be recorded by
+        /// calls that complete: a panic or error return reverts every write
+        /// of the invocation, this one included, so such failures are never
+        /// counted (see `track_start`).
+        ///
+        /// Records nothing for an operation whose tracking is turned off.
+        ///
+        /// With a metrics contract attached the call is forwarded there
+        /// instead, in builds with or without the `monitoring` feature; if
+        /// the metrics contract fails, it is recorded internally as if none
+        /// were attached.
+        pub fn finish(self, env: &Env, caller: Address, success: bool) {
+            let duration = env.ledger().timestamp().saturating_sub(self.started_at);
+            if let Some(sink) = get_metrics_contract(env) {
+                if !is_tracking_on(env, &self.operation)
+                    || forward(env, &sink, &self.operation, &caller, success, duration)
+                {
+                    return;
+                }
+            }
+            if !ENABLED || !is_tracked(env, &self.operation) {
+                return;
+            }
+            track(env, self.operation.clone(), caller, success, None, true);
+            track_mode(env, &self.operation, success);
+            track_version(env, success);
+            emit_performance(env, self.operation, duration);
+        }
+    }
+
+    pub fn get_metrics_contract(env: &Env) -> Option<Address> {
+        env.storage().instance().get(&MonitoringKey::MetricsContract)
+    }
+
+    pub fn set_metrics_contract(env: &Env, sink: &Option<Address>) {
+        match sink {
+            Some(sink) => env.storage().instance().set(&MonitoringKey::MetricsContract, sink),
+            None => env.storage().instance().remove(&MonitoringKey::MetricsContract),
+        }
+    }
+
+    /// Sends one finished operation to the metrics contract `sink` and
+    /// returns whether it was recorded. A failure, trap included, is
+    /// contained and published as `("metric", "sink_err")` with
+    /// `(sink, operation)`.
+    fn forward(env: &Env, sink: &Address, operation: &Symbol, caller: &Address, success: bool, duration: u64) -> bool {
+        let result = MetricsSinkClient::new(env, sink).try_record_call(operation, caller, &success, &duration);
+        if matches!(result, Ok(Ok(()))) {
+            return true;
+        }
+        env.events().publish(
+            (symbol_short!("metric"), symbol_short!("sink_err")),
+            (sink.clone(), operation.clone()),
+        );
+        false
+    }
+
+    /// `admin` or `multisig`, from the mode the contract was initialized or
+    /// migrated into.
+    pub fn current_mode(env: &Env) -> Symbol {
+        let mode = env.storage().instance().get(&DataKey::InitMode).unwrap_or(
+            if env.storage().instance().has(&DataKey::Admin) { InitMode::Admin } else { InitMode::Multisig },
+        );
+        match mode {
+            InitMode::Admin => ADMIN_MODE,
+            InitMode::Multisig => MULTISIG_MODE,
+        }
+    }
+
+    fn load_mode_stats(env: &Env, mode: &Symbol) -> ModeStats {
+        env.storage()
+            .persistent()
+            .get(&MetricsKey::ModeStats(mode.clone()))
+            .unwrap_or(ModeStats { operation_count: 0, error_count: 0, by_operation: Map::new(env), duration: 0 })
+    }
+
+    /// Counts `operation` under the current governance mode. Called by
+    /// `TrackedOperation::finish` and entrypoints that only report
+    /// performance, not by `track`, so the per-call cost measured
+    /// against schema 1 is unchanged.
+    pub fn track_mode(env: &Env, operation: &Symbol, success: bool) {
+        if !ENABLED {
+            return;
+        }
+        let mode = current_mode(env);
+        let mut stats = load_mode_stats(env, &mode);
+        stats.operation_count = stats.operation_count.saturating_add(1);
+        if !success {
+            stats.error_count = stats.error_count.saturating_add(1);
+        }
+        if stats.by_operation.contains_key(operation.clone()) || stats.by_operation.len() < MAX_TRACKED_FUNCTIONS {
+            let count = stats.by_operation.get(operation.clone()).unwrap_or(0);
+            stats.by_operation.set(operation.clone(), count.saturating_add(1));
+        }
+        env.storage().persistent().set(&MetricsKey::ModeStats(mode), &stats);
+    }
+
+    /// Start of the current mode: the last switchover, else the first
+    /// recorded version change (initialization), else 0.
+    fn mode_started_at(env: &Env) -> u64 {
+        env.storage().persistent().get(&MetricsKey::ModeSwitchedAt).unwrap_or_else(|| {
+            env.storage()
+                .persistent()
+                .get::<_, super::VersionChange>(&ArchiveKey::VersionHistory(0))
+                .map_or(0, |entry| entry.changed_at)
+        })
+    }
+
+    /// Closes the period spent in `from` and starts the current mode's at
+    /// the current ledger timestamp. Call after the mode changed.
+    pub fn record_mode_switch(env: &Env, from: &Symbol) {
+        if !ENABLED {
+            return;
+        }
+        let now = env.ledger().timestamp();
+        let mut stats = load_mode_stats(env, from);
+        stats.duration = stats.duration.saturating_add(now.saturating_sub(mode_started_at(env)));
+        env.storage().persistent().set(&MetricsKey::ModeStats(from.clone()), &stats);
+        env.storage().persistent().set(&MetricsKey::ModeSwitchedAt, &now);
+    }
+
+    /// Stats of `mode`, with the running period added to `duration` when
+    /// it is the current mode. Zeroed for a mode never entered.
+    pub fn get_mode_stats(env: &Env, mode: &Symbol) -> ModeStats {
+        let mut stats = load_mode_stats(env, mode);
+        if *mode == current_mode(env) {
+            let running = env.ledger().timestamp().saturating_sub(mode_started_at(env));
+            stats.duration = stats.duration.saturating_add(running);
+        }
+        stats
+    }
+
+    fn stored_version(env: &Env) -> u32 {
+        env.storage().instance().get(&DataKey::Version).unwrap_or(0)
+    }
+
+    /// Counts an operation under the stored contract version. Called
+    /// alongside `track_mode`, for the same reason.
+    pub fn track_version(env: &Env, success: bool) {
+        if !ENABLED {
+            return;
+        }
+        let version = stored_version(env);
+        let mut stats = get_version_stats(env, version);
+        stats.operation_count = stats.operation_count.saturating_add(1);
+        if !success {
+            stats.error_count = stats.error_count.saturating_add(1);
+        }
+        env.storage().persistent().set(&MetricsKey::VersionStats(version), &stats);
+    }
+
+    /// Closes the stats of the version accumulated so far and opens the
+    /// stored version's at the current ledger timestamp. A version stored
+    /// again keeps its earlier counts and `active_from`. No-op while the
+    /// version is unchanged. Call after the version changed.
+    pub fn record_version_switch(env: &Env) {
+        if !ENABLED {
+            return;
+        }
+        let version = stored_version(env);
+        let previous: Option<u32> = env.storage().persistent().get(&MetricsKey::ActiveVersion);
+        if previous == Some(version) {
+            return;
+        }
+        let now = env.ledger().timestamp();
+        if let Some(previous) = previous {
+            let mut stats = get_version_stats(env, previous);
+            stats.active_until = Some(now);
+            env.storage().persistent().set(&MetricsKey::VersionStats(previous), &stats);
+        }
+        let mut stats = get_version_stats(env, version);
+        if stats.active_from == 0 && stats.operation_count == 0 {
+            stats.active_from = now;
+        }
+        stats.active_until = None;
+        env.storage().persistent().set(&MetricsKey::VersionStats(version), &stats);
+        env.storage().persistent().set(&MetricsKey::ActiveVersion, &version);
+    }
+
+    /// Stats of `version`; zeroed for a version never stored.
+    pub fn get_version_stats(env: &Env, version: u32) -> VersionStats {
+        env.storage()
+            .persistent()
+            .get(&MetricsKey::VersionStats(version))
+            .unwrap_or_default()
+    }
+
+    /// Records a failure with a known error code, counted per operation and
+    /// per code in `get_failure_breakdown`. Like any metric it only persists
+    /// if the invocation completes, so it suits failures the contract
+    /// absorbs, such as a failing cross-contract call or a rejected
+    /// `attempt_execute_upgrade`.
+    ///
+    /// The failure reaches the circuit breaker only with `trips_breaker`
+    /// set. The `attempt_*` entry points decide it with
+    /// `GrainlifyContract::trips_breaker`, so a failure provoked by an
+    /// address that could not pause the contract itself never pauses it.
+    /// Failed subscriber and fleet-child callbacks never set it: a
+    /// third-party hook must not be able to pause this contract.
+    pub fn track_failure(env: &Env, operation: Symbol, caller: Address, code: u32, trips_breaker: bool) {
+        if is_tracked(env, &operation) {
+            track(env, operation, caller, false, Some(code), trips_breaker);
+        }
+    }
+
+    /// `track_failure` for a governance operation, also counted under
+    /// `stage` in `get_governance_failure_stats` and published with the
+    /// stage as `("metric", "gov_fail")`. The same caveats apply: only
+    /// failures the contract absorbs persist, and only those with
+    /// `trips_breaker` set reach the breaker.
+    pub fn track_governance_failure(
+        env: &Env,
+        operation: Symbol,
+        stage: GovernanceStage,
+        caller: Address,
+        code: u32,
+        trips_breaker: bool,
+    ) {
+        if !is_tracked(env, &operation) {
+            return;
+        }
+        track(env, operation.clone(), caller, false, Some(code), trips_breaker);
+        let mut stats = get_governance_failure_stats(env);
+        let counter = match stage {
+            GovernanceStage::Propose => &mut stats.propose,
+            GovernanceStage::Approve => &mut stats.approve,
+            GovernanceStage::Execute => &mut stats.execute,
+            GovernanceStage::Cancel => &mut stats.cancel,
+        };
+        *counter = counter.saturating_add(1);
+        env.storage().persistent().set(&MetricsKey::GovernanceFailures, &stats);
+        env.events().publish(
+            (symbol_short!("metric"), symbol_short!("gov_fail")),
+            GovernanceFailureMetric { operation, stage, code, timestamp: env.ledger().timestamp() },
+        );
+    }
+
+    pub fn get_governance_failure_stats(env: &Env) -> GovernanceFailureStats {
+        env.storage()
+            .persistent()
+            .get(&MetricsKey::GovernanceFailures)
+            .unwrap_or_default()
+    }
+
+    /// Records one outcome: the global totals, the caller, the failure
+    /// breakdown for a coded failure, and the circuit breaker when
+    /// `trips_breaker` is set.
+    pub fn track(
+        env: &Env,
+        operation: Symbol,
+        caller: Address,
+        success: bool,
+        code: Option<u32>,
+        trips_breaker: bool,
+    ) {
+        if !ENABLED {
+            return;
+        }
+        let timestamp = env.ledger().timestamp();
+        let mut totals = get_totals(env);
+        totals.op_count = totals.op_count.saturating_add(1);
+        totals.last_op_ts = timestamp;
+        if !success {
+            totals.err_count = totals.err_count.saturating_add(1);
+        }
+        // Once the index is full no caller can be added, so skip reading it.
+        if totals.usr_count < MAX_TRACKED_USERS as u64 {
+            totals.usr_count = track_unique_user(env, &caller);
+        }
+        env.storage().persistent().set(&MetricsKey::Totals, &totals);
+        if let Some(code) = code {
+            record_failure(env, &operation, code);
+        }
+        if trips_breaker {
+            evaluate_circuit_breaker(env, &operation, success);
+        }
+        let call_kind = call_kind(&caller);
+        let version = stored_version(env);
+        env.events().publish(
+            (symbol_short!("metric"), symbol_short!("op")),
+            OperationMetric { operation, caller, timestamp, success, call_kind, version },
+        );
+    }
+
+    /// Whether `address` is a contract rather than an account.
+    pub fn is_contract(address: &Address) -> bool {
+        // Strkeys are 56 characters: `G...` for accounts, `C...` for contracts.
+        let mut strkey = [0u8; 56];
+        address.to_string().copy_into_slice(&mut strkey);
+        strkey[0] == b'C'
+    }
+
+    /// How a call authorized by `caller` reached this contract: `contract`
+    /// when `caller` is a contract, whose authorization only comes from
+    /// invoking this contract itself, else `direct`. Soroban does not expose
+    /// the transaction source, so a fee-sponsored account call is `direct`
+    /// and attributed to the account that authorized it.
+    pub fn call_kind(caller: &Address) -> Symbol {
+        if is_contract(caller) {
+            symbol_short!("contract")
+        } else {
+            symbol_short!("direct")
+        }
+    }
+
+    /// Splits the tracked callers by `call_kind` when read, so `track` pays
+    /// nothing beyond the event field.
+    pub fn get_caller_stats(env: &Env) -> CallerStats {
+        let mut contract = 0u64;
+        for user in get_tracked_users(env).iter() {
+            if is_contract(&user) {
+                contract += 1;
+            }
+        }
+        let unique_callers = get_totals(env).usr_count;
+        let mut by_call_kind = Map::new(env);
+        by_call_kind.set(symbol_short!("direct"), unique_callers.saturating_sub(contract));
+        by_call_kind.set(symbol_short!("contract"), contract);
+        CallerStats { unique_callers, by_call_kind }
+    }
+
+    pub fn get_tracking_overrides(env: &Env) -> Map<Symbol, bool> {
+        env.storage()
+            .instance()
+            .get(&MonitoringKey::TrackingOverrides)
+            .unwrap_or(Map::new(env))
+    }
+
+    /// Turns tracking of `operation` on or off. At most
+    /// `MAX_TRACKED_FUNCTIONS` operations can be overridden.
+    pub fn set_tracking(env: &Env, operation: Symbol, enabled: bool) {
+        let mut overrides = get_tracking_overrides(env);
+        if !overrides.contains_key(operation.clone()) && overrides.len() >= MAX_TRACKED_FUNCTIONS {
+            panic!("Too many tracking overrides");
+        }
+        overrides.set(operation, enabled);
+        env.storage().instance().set(&MonitoringKey::TrackingOverrides, &overrides);
+    }
+
+    /// Whether governance left tracking of `operation` on. `track_failure`,
+    /// `track_governance_failure` and `TrackedOperation::finish` check it
+    /// themselves;
+    /// direct `emit_performance` callers check it first.
+    pub fn is_tracked(env: &Env, operation: &Symbol) -> bool {
+        ENABLED && is_tracking_on(env, operation)
+    }
+
+    /// The override lookup behind `is_tracked`, also consulted for calls
+    /// forwarded to a metrics contract.
+    fn is_tracking_on(env: &Env, operation: &Symbol) -> bool {
+        env.storage()
+            .instance()
+            .get::<_, Map<Symbol, bool>>(&MonitoringKey::TrackingOverrides)
+            .and_then(|overrides| overrides.get(operation.clone()))
+            .unwrap_or(true)
+    }
+
+    fn record_failure(env: &Env, operation: &Symbol, code: u32) {
+        let mut counts = get_failure_counts(env);
+        if counts.by_function.contains_key(operation.clone()) || counts.by_function.len() < MAX_TRACKED_FUNCTIONS {
+            let count = counts.by_function.get(operation.clone()).unwrap_or(0);
+            counts.by_function.set(operation.clone(), count.saturating_add(1));
+        }
+        if counts.by_code.contains_key(code) || counts.by_code.len() < MAX_TRACKED_ERROR_CODES {
+            let count = counts.by_code.get(code).unwrap_or(0);
+            counts.by_code.set(code, count.saturating_add(1));
+        }
+        env.storage().persistent().set(&MetricsKey::Failures, &counts);
+    }
+
+    fn get_failure_counts(env: &Env) -> FailureCounts {
+        env.storage()
+            .persistent()
+            .get(&MetricsKey::Failures)
+            .unwrap_or(FailureCounts { by_function: Map::new(env), by_code: Map::new(env) })
+    }
+
+    pub fn get_failure_breakdown(env: &Env) -> FailureBreakdown {
+        let counts = get_failure_counts(env);
+        FailureBreakdown {
+            total_errors: get_totals(env).err_count,
+            by_function: counts.by_function,
+            by_code: counts.by_code,
+        }
+    }
+
+    pub fn get_circuit_breaker_config(env: &Env) -> Option<CircuitBreakerConfig> {
+        env.storage().instance().get(&DataKey::CircuitBreakerConfig)
+    }
+
+    /// Updates the breaker's streak and window counters and pauses the
+    /// contract when a configured limit is crossed. Counters restart after a
+    /// trip so a governance unpause starts from a clean slate.
+    ///
+    /// Successes reach it through `TrackedOperation::finish`. A failing
+    /// entrypoint reverts along with its metrics, so the only failures it
+    /// sees are refusals of the `attempt_*` entry points by the admin or a
+    /// signer. It is not an error-rate breaker over all traffic.
+    fn evaluate_circuit_breaker(env: &Env, operation: &Symbol, success: bool) {
+        let config = match get_circuit_breaker_config(env) {
+            Some(config) if config.enabled => config,
+            _ => return,
+        };
+
+        let counters: BreakerCounters = env
+            .storage()
+            .persistent()
+            .get(&MetricsKey::BreakerWindow)
+            .unwrap_or_default();
+        let streak = if success { 0 } else { counters.failure_streak.saturating_add(1) };
+        let window_ops = counters.window_operations.saturating_add(1);
+        let window_errors = counters.window_errors + if success { 0 } else { 1 };
+
+        let streak_tripped = config.max_consecutive_failures > 0
+            && streak >= config.max_consecutive_failures as u64;
+        let window_full = config.window_size > 0 && window_ops >= config.window_size as u64;
+        let rate_tripped = config.max_error_rate_bps > 0
+            && window_full
+            && (window_errors as u128 * 10_000) / window_ops as u128 > config.max_error_rate_bps as u128;
+
+        if (streak_tripped || rate_tripped) && !MultiSig::is_contract_paused(env) {
+            MultiSig::set_paused(env, true);
+            env.events().publish(
+                (symbol_short!("cbreaker"), symbol_short!("tripped")),
+                CircuitBreakerEvent {
+                    reason: if streak_tripped { symbol_short!("streak") } else { symbol_short!("rate") },
+                    operation: operation.clone(),
+                    failure_streak: streak,
+                    window_operations: window_ops,
+                    window_errors,
+                    timestamp: env.ledger().timestamp(),
+                    event_version: super::EVENT_SCHEMA_VERSION,
+                },
+            );
+            env.storage()
+                .persistent()
+                .set(&MetricsKey::BreakerWindow, &BreakerCounters::default());
+            return;
+        }
+
+        let counters = if window_full {
+            BreakerCounters { failure_streak: streak, ..Default::default() }
+        } else {
+            BreakerCounters { failure_streak: streak, window_operations: window_ops, window_errors }
+        };
+        env.storage().persistent().set(&MetricsKey::BreakerWindow, &counters);
+    }
+
+    fn get_perf_index(env: &Env) -> Vec<Symbol> {
+        env.storage()
+            .persistent()
+            .get(&MetricsKey::PerfIndex)
+            .unwrap_or(Vec::new(env))
+    }
+
+    /// Publishes a `VersionMetric` unless tracking of `set_ver` is off.
+    /// Stores nothing; the counters come from the `set_ver` operation.
+    pub fn emit_version(env: &Env, old_version: u32, new_version: u32, caller: Address) {
+        if !is_tracked(env, &symbol_short!("set_ver")) {
+            return;
+        }
+        env.events().publish(
+            (symbol_short!("metric"), symbol_short!("version")),
+            VersionMetric { old_version, new_version, caller, timestamp: env.ledger().timestamp() },
+        );
+    }
+
+    pub fn emit_performance(env: &Env, function: Symbol, duration: u64) {
+        if !ENABLED {
+            return;
+        }
+        let mut index = get_perf_index(env);
+        if !index.contains(&function) {
+            if index.len() >= MAX_TRACKED_FUNCTIONS {
+                let oldest = index.pop_front_unchecked();
+                env.storage().persistent().remove(&MetricsKey::FunctionPerf(oldest));
+            }
+            index.push_back(function.clone());
+            env.storage().persistent().set(&MetricsKey::PerfIndex, &index);
+        }
+
+        let key = MetricsKey::FunctionPerf(function.clone());
+        let mut perf: PerfCounters = env.storage().persistent().get(&key).unwrap_or_default();
+        let timestamp = env.ledger().timestamp();
+        perf.call_count = perf.call_count.saturating_add(1);
+        perf.total_time = perf.total_time.saturating_add(duration);
+        perf.last_called = timestamp;
+        env.storage().persistent().set(&key, &perf);
+        env.events().publish(
+            (symbol_short!("metric"), symbol_short!("perf")),
+            PerformanceMetric { function, duration, timestamp },
+        );
+    }
+
+    pub fn health_check(env: &Env) -> HealthStatus {
+        let report = check_invariants(env);
+        let stale_proposal_id = stale_proposal(env);
+        let unhealthy_reason = if !report.healthy {
+            Some(Symbol::new(env, "invariants"))
+        } else if stale_proposal_id.is_some() {
+            Some(Symbol::new(env, "stale_proposal"))
+        } else {
+            None
+        };
+        HealthStatus {
+            is_healthy: unhealthy_reason.is_none(),
+            last_operation: get_totals(env).last_op_ts,
+            total_operations: report.operation_count,
+            contract_version: version_semver_string(env), // [FIX-H03] now dynamic
+            version_label: env.storage().instance().get(&crate::VersionKey::Label),
+            is_paused: MultiSig::is_contract_paused(env),
+            unhealthy_reason,
+            stale_proposal_id,
+            inactive_signers: inactive_signers(env),
+            emergency_paused_until: env
+                .storage()
+                .instance()
+                .get::<_, crate::EmergencyPause>(&crate::GuardianKey::LastPause)
+                .map(|pause| pause.expires_at)
+                .filter(|&expires_at| env.ledger().timestamp() < expires_at),
+        }
+    }
+
+    /// Current signers whose last activity is older than the signer
+    /// inactivity period; empty while the period is off.
+    fn inactive_signers(env: &Env) -> Vec<Address> {
+        let period: u64 = env
+            .storage()
+            .instance()
+            .get(&HealthKey::SignerInactivityPeriod)
+            .unwrap_or(0);
+        let mut out = Vec::new(env);
+        if period == 0 {
+            return out;
+        }
+        let now = env.ledger().timestamp();
+        for stats in MultiSig::list_signer_stats(env).iter() {
+            if now.saturating_sub(stats.last_active_at) > period {
+                out.push_back(stats.signer);
+            }
+        }
+        out
+    }
+
+    /// Oldest pending upgrade proposal created more than the stale-proposal
+    /// age ago. An age of 0 turns the check off.
+    fn stale_proposal(env: &Env) -> Option<u64> {
+        let max_age: u64 = env
+            .storage()
+            .instance()
+            .get(&HealthKey::StaleProposalAge)
+            .unwrap_or(DEFAULT_STALE_PROPOSAL_AGE);
+        if max_age == 0 {
+            return None;
+        }
+        let pending = pending_upgrades(env);
+        let now = env.ledger().timestamp();
+        pending.iter().find(|&proposal_id| {
+            let timings: Option<ProposalTimings> =
+                env.storage().persistent().get(&ProposalKey::ProposalTimings(proposal_id));
+            is_pending(env, proposal_id)
+                && timings.is_some_and(|t| now.saturating_sub(t.created_at) > max_age)
+        })
+    }
+
+    /// The tracked upgrade proposal IDs, read from instance storage until
+    /// the first write moves them to persistent storage.
+    pub fn pending_upgrades(env: &Env) -> Vec<u64> {
+        env.storage()
+            .persistent()
+            .get(&HealthKey::PendingUpgrades)
+            .or_else(|| env.storage().instance().get(&HealthKey::PendingUpgrades))
+            .unwrap_or(Vec::new(env))
+    }
+
+    pub fn store_pending_upgrades(env: &Env, pending: &Vec<u64>) {
+        let key = HealthKey::PendingUpgrades;
+        env.storage().instance().remove(&key);
+        env.storage().persistent().set(&key, pending);
+        env.storage().persistent().extend_ttl(&key, METRICS_TTL_THRESHOLD, METRICS_TTL_EXTEND_TO);
+    }
+
+    /// Not executed, cancelled, or expired.
+    pub fn is_pending(env: &Env, proposal_id: u64) -> bool {
+        MultiSig::get_proposal_opt(env, proposal_id)
+            .is_some_and(|p| !p.executed && !p.cancelled && !MultiSig::is_expired(env, proposal_id))
+    }
+
+    pub fn get_analytics(env: &Env) -> Analytics {
+        let totals = get_totals(env);
+        let error_rate = if totals.op_count > 0 {
+            ((totals.err_count as u128 * 10000) / totals.op_count as u128) as u32
+        } else { 0 };
+        Analytics {
+            operation_count: totals.op_count,
+            unique_users: totals.usr_count,
+            error_count: totals.err_count,
+            error_rate,
+            upgrade_count: env.storage().persistent().get(&ArchiveKey::UpgradeCount).unwrap_or(0),
+        }
+    }
+
+    pub fn get_state_snapshot(env: &Env) -> StateSnapshot {
+        let totals = get_totals(env);
+        StateSnapshot {
+            timestamp: env.ledger().timestamp(),
+            total_operations: totals.op_count,
+            total_users: totals.usr_count,
+            total_errors: totals.err_count,
+        }
+    }
+
+    /// Extends the TTL of the metric counters when it is due. Returns `false`
+    /// without touching storage when the previous bump still keeps every
+    /// entry above `METRICS_TTL_THRESHOLD`, or when no metrics exist yet.
+    pub fn bump_metrics_ttl(env: &Env) -> bool {
+        let now = env.ledger().sequence();
+        let bumped_key = MetricsKey::TtlBumpedAt;
+        if let Some(bumped_at) = env.storage().persistent().get::<_, u32>(&bumped_key) {
+            if now.saturating_sub(bumped_at) <= METRICS_TTL_EXTEND_TO - METRICS_TTL_THRESHOLD {
+                return false;
+            }
+        }
+
+        let mut bumped = false;
+        for key in [MetricsKey::Totals, MetricsKey::TrackedUsers] {
+            if env.storage().persistent().has(&key) {
+                env.storage()
+                    .persistent()
+                    .extend_ttl(&key, METRICS_TTL_THRESHOLD, METRICS_TTL_EXTEND_TO);
+                bumped = true;
+            }
+        }
+        if bumped {
+            env.storage().persistent().set(&bumped_key, &now);
+            env.storage()
+                .persistent()
+                .extend_ttl(&bumped_key, METRICS_TTL_THRESHOLD, METRICS_TTL_EXTEND_TO);
+        }
+        bumped
+    }
+
+    /// Stores the current state snapshot if `SNAPSHOT_INTERVAL` has elapsed
+    /// since the last recorded one. Returns whether a snapshot was recorded.
+    pub fn record_snapshot(env: &Env) -> bool {
+        if let Some(last) = get_recorded_snapshot(env) {
+            if env.ledger().timestamp().saturating_sub(last.timestamp) < SNAPSHOT_INTERVAL {
+                return false;
+            }
+        }
+        env.storage()
+            .persistent()
+            .set(&MetricsKey::RecordedSnapshot, &get_state_snapshot(env));
+        true
+    }
+
+    pub fn get_recorded_snapshot(env: &Env) -> Option<StateSnapshot> {
+        env.storage()
+            .persistent()
+            .get(&MetricsKey::RecordedSnapshot)
+    }
+
+    pub fn get_performance_stats(env: &Env, function_name: Symbol) -> PerformanceStats {
+        let perf: PerfCounters = env
+            .storage()
+            .persistent()
+            .get(&MetricsKey::FunctionPerf(function_name.clone()))
+            .unwrap_or_default();
+        PerformanceStats {
+            function_name,
+            call_count: perf.call_count,
+            total_time: perf.total_time,
+            avg_time: perf.total_time.checked_div(perf.call_count).unwrap_or(0),
+            last_called: perf.last_called,
+        }
+    }
+
+    /// Totals rows of `export_metrics`, in export order.
+    const EXPORTED_TOTALS: [&str; 3] = [OPERATION_COUNT, ERROR_COUNT, LAST_OPERATION_TS];
+
+    /// Page `page` of the exported metric rows: the totals named in
+    /// `EXPORTED_TOTALS`, then one `(function, call_count)` row per function
+    /// in the performance index, oldest first. `page_size` is capped at
+    /// `MAX_PAGE_SIZE`, and only the functions on the page are read.
+    pub fn export_metrics(env: &Env, page: u32, page_size: u32) -> MetricsPage {
+        let page_size = page_size.min(MAX_PAGE_SIZE);
+        let index = get_perf_index(env);
+        let rows_total = export_row_count(env);
+        let range = crate::paging::range(page as u64 * page_size as u64, page_size, rows_total);
+
+        let totals = get_totals(env);
+        let mut rows = Vec::new(env);
+        for row in range.start as u32..range.end as u32 {
+            let entry = match row {
+                0 => (Symbol::new(env, OPERATION_COUNT), totals.op_count),
+                1 => (Symbol::new(env, ERROR_COUNT), totals.err_count),
+                2 => (Symbol::new(env, LAST_OPERATION_TS), totals.last_op_ts),
+                _ => {
+                    let function = index.get_unchecked(row - EXPORTED_TOTALS.len() as u32);
+                    let perf: PerfCounters = env
+                        .storage()
+                        .persistent()
+                        .get(&MetricsKey::FunctionPerf(function.clone()))
+                        .unwrap_or_default();
+                    (function, perf.call_count)
+                }
+            };
+            rows.push_back(entry);
+        }
+        MetricsPage { items: rows, has_more: range.has_more }
+    }
+
+    /// Number of rows `export_metrics` pages through.
+    pub fn export_row_count(env: &Env) -> u64 {
+        EXPORTED_TOTALS.len() as u64 + get_perf_index(env).len() as u64
+    }
+
+    /// Writes rows produced by `export_metrics` into a store that has
+    /// recorded nothing yet, every total included, and returns the number
+    /// of rows written. Panics if the rows give more errors than operations. Rows
+    /// not named in `EXPORTED_TOTALS` restore a function's call count;
+    /// durations and the caller index are not exported and start over.
+    pub fn import_metrics(env: &Env, entries: &Vec<(Symbol, u64)>) -> u32 {
+        let mut totals = get_totals(env);
+        let mut index = get_perf_index(env);
+        let recorded = totals.op_count > 0 || totals.err_count > 0 || totals.usr_count > 0 || totals.last_op_ts > 0;
+        if recorded || !index.is_empty() {
+            panic!("Metrics must be empty to import");
+        }
+        let names = EXPORTED_TOTALS.map(|name| Symbol::new(env, name));
+        for (name, value) in entries.iter() {
+            if name == names[0] {
+                totals.op_count = value;
+            } else if name == names[1] {
+                totals.err_count = value;
+            } else if name == names[2] {
+                totals.last_op_ts = value;
+            } else {
+                if !index.contains(&name) {
+                    if index.len() >= MAX_TRACKED_FUNCTIONS {
+                        panic!("Too many functions to import");
+                    }
+                    index.push_back(name.clone());
+                }
+                let perf = PerfCounters { call_count: value, ..Default::default() };
+                env.storage().persistent().set(&MetricsKey::FunctionPerf(name), &perf);
+            }
+        }
+        if totals.err_count > totals.op_count {
+            panic!("Imported errors exceed operations");
+        }
+        env.storage().persistent().set(&MetricsKey::Totals, &totals);
+        env.storage().persistent().set(&MetricsKey::PerfIndex, &index);
+        entries.len()
+    }
+
+    pub fn check_invariants(env: &Env) -> InvariantReport {
+        let MetricTotals { op_count: operation_count, err_count: error_count, usr_count: unique_users, .. } =
+            get_totals(env);
+
+        let metrics_sane = error_count <= operation_count
+            && unique_users <= operation_count
+            && (operation_count > 0 || (unique_users == 0 && error_count == 0));
+
+        let admin_set = env.storage().instance().has(&DataKey::Admin);
+        let version_opt: Option<u32> = env.storage().instance().get(&DataKey::Version);
+        let version_set = version_opt.is_some();
+        let version = version_opt.unwrap_or(0);
+        let version_sane = version > 0;
+
+        let previous_version_opt: Option<u32> = env.storage().instance().get(&DataKey::PreviousVersion);
+        let previous_version_sane = match (previous_version_opt, version_opt) {
+            (Some(prev), Some(curr)) => prev <= curr,
+            (Some(_), None) => false,
+            (None, _) => true,
+        };
+
+        let chain_id: Option<String> = env.storage().instance().get(&DataKey::ChainId);
+        let network_id: Option<String> = env.storage().instance().get(&DataKey::NetworkId);
+        let network_pair_sane = match (chain_id, network_id) {
+            (Some(chain), Some(network)) => !chain.is_empty() && !network.is_empty(),
+            (None, None) => true,
+            _ => false,
+        };
+
+        let config_sane = admin_set && version_set && version_sane && previous_version_sane && network_pair_sane;
+        let mut violation_count: u32 = 0;
+        if !admin_set { violation_count += 1; }
+        if !version_set || !version_sane { violation_count += 1; }
+        if !previous_version_sane { violation_count += 1; }
+        if !network_pair_sane { violation_count += 1; }
+        if error_count > operation_count { violation_count += 1; }
+        if unique_users > operation_count { violation_count += 1; }
+        if operation_count == 0 && (unique_users > 0 || error_count > 0) { violation_count += 1; }
+
+        InvariantReport {
+            healthy: config_sane && metrics_sane,
+            config_sane, metrics_sane, admin_set, version_set, version,
+            operation_count, unique_users, error_count, violation_count,
+        }
+    }
+
+    pub fn verify_invariants(env: &Env) -> bool {
+        let report = check_invariants(env);
+        #[cfg(feature = "strict-mode")]
+        {
+            if !report.healthy {
+                env.events().publish(
+                    (symbol_short!("strict"), symbol_short!("inv_fail")),
+                    report.violation_count,
+                );
+            }
+        }
+        report.healthy
+    }
+}
+
+#[cfg(all(test, feature = "wasm_tests"))]
+mod test_core_monitoring;
+#[cfg(all(test, feature = "wasm_tests"))]
+mod test_pseudo_randomness;
+#[cfg(all(test, feature = "wasm_tests"))]
+mod test_serialization_compatibility;
+#[cfg(all(test, feature = "contract"))]
+mod test_storage_layout;
+#[cfg(all(test, feature = "wasm_tests"))]
+mod test_version_helpers;
+#[cfg(all(test, feature = "contract"))]
+mod test_strict_mode;
+#[cfg(all(test, feature = "contract"))]
+mod test_timelock_boundary;
+#[cfg(all(test, feature = "contract"))]
+mod test_contract_registry;
+#[cfg(all(test, feature = "contract"))]
+mod test_config_change_timelock;
+#[cfg(all(test, feature = "contract"))]
+mod test_build_info_init_event;
+#[cfg(all(test, feature = "contract"))]
+mod test_pausable;
+#[cfg(all(test, feature = "contract", feature = "monitoring"))]
+mod test_circuit_breaker;
+#[cfg(all(test, feature = "contract"))]
+mod test_kill_switches;
+#[cfg(all(test, feature = "contract"))]
+mod test_lifecycle_events;
+#[cfg(all(test, feature = "contract"))]
+mod test_upgrade_subscribers;
+#[cfg(all(test, feature = "contract"))]
+mod test_fleet_upgrade;
+#[cfg(all(test, feature = "contract"))]
+mod test_deployment_names;
+#[cfg(all(test, feature = "contract"))]
+mod test_token_voting;
+#[cfg(all(test, feature = "contract"))]
+mod test_attestations;
+#[cfg(all(test, feature = "contract", feature = "monitoring"))]
+mod test_keeper_rewards;
+#[cfg(all(test, feature = "contract"))]
+mod test_admin_contract;
+#[cfg(all(test, feature = "contract"))]
+mod test_wasm_swap;
+#[cfg(all(test, feature = "contract"))]
+mod test_upgrade_simulation;
+#[cfg(all(test, feature = "contract"))]
+mod test_proposal_revoke;
+#[cfg(all(test, feature = "contract"))]
+mod test_governance_budget;
+#[cfg(all(test, feature = "contract"))]
+mod test_proposal_storage;
+#[cfg(all(test, feature = "contract", feature = "monitoring"))]
+mod test_schema_migration;
+#[cfg(all(test, feature = "contract"))]
+mod test_proposal_sweep;
+#[cfg(all(test, feature = "contract", feature = "monitoring"))]
+mod test_monitoring_budget;
+#[cfg(all(test, feature = "contract", not(feature = "monitoring")))]
+mod test_monitoring_disabled;
+#[cfg(all(test, feature = "contract"))]
+mod test_upgrade_receipt;
+#[cfg(all(test, feature = "contract"))]
+mod test_commit_reveal_upgrade;
+#[cfg(all(test, feature = "contract"))]
+mod test_execution_window;
+#[cfg(all(test, feature = "contract"))]
+mod test_amend_proposal;
+#[cfg(all(test, feature = "contract"))]
+mod test_repropose;
+#[cfg(all(test, feature = "contract"))]
+mod test_execution_preview;
+#[cfg(all(test, feature = "contract"))]
+mod test_contract_metadata;
+#[cfg(all(test, feature = "contract"))]
+mod test_initial_version;
+#[cfg(all(test, feature = "contract"))]
+mod test_current_wasm;
+#[cfg(all(test, feature = "contract"))]
+mod test_action_proposals;
+#[cfg(all(test, feature = "contract"))]
+mod test_admin_nonce;
+#[cfg(all(test, feature = "contract"))]
+mod test_postpone;
+#[cfg(all(test, feature = "contract"))]
+mod test_maintenance_mode;
+#[cfg(all(test, feature = "contract"))]
+mod test_proposal_timings;
+#[cfg(all(test, feature = "contract"))]
+mod test_executability;
+#[cfg(all(test, feature = "contract"))]
+mod test_approver_records;
+#[cfg(all(test, feature = "contract"))]
+mod test_network_label;
+#[cfg(all(test, feature = "contract"))]
+mod test_upgrade_archive;
+#[cfg(all(test, feature = "contract", feature = "monitoring"))]
+mod test_operation_tracking;
+#[cfg(all(test, feature = "contract"))]
+mod test_init_state;
+#[cfg(all(test, feature = "contract"))]
+mod test_upgrade_ordering;
+#[cfg(all(test, feature = "contract"))]
+mod test_executor_record;
+#[cfg(all(test, feature = "contract"))]
+mod test_stale_proposals;
+#[cfg(all(test, feature = "contract"))]
+mod test_threshold_history;
+#[cfg(all(test, feature = "contract"))]
+mod test_staged_upgrade;
+#[cfg(all(test, feature = "contract"))]
+mod test_ttl_warning;
+#[cfg(all(test, feature = "contract"))]
+mod test_upgrade_count;
+#[cfg(all(test, feature = "contract"))]
+mod test_tunable_config;
+#[cfg(all(test, feature = "contract", feature = "monitoring"))]
+mod test_failure_breakdown;
+#[cfg(all(test, feature = "contract", feature = "monitoring"))]
+mod test_tracking_filter;
+#[cfg(all(test, feature = "contract"))]
+mod test_approval_payload;
+#[cfg(all(test, feature = "contract"))]
+mod test_consistency_check;
+#[cfg(all(test, feature = "contract"))]
+mod test_signer_stats;
+#[cfg(all(test, feature = "contract"))]
+mod test_relayer;
+#[cfg(all(test, feature = "contract", feature = "monitoring"))]
+mod test_governance_failures;
+#[cfg(all(test, feature = "contract"))]
+mod test_string_limits;
+#[cfg(all(test, feature = "contract"))]
+mod test_external_id;
+#[cfg(all(test, feature = "contract"))]
+mod test_governance_mode;
+#[cfg(all(test, feature = "contract"))]
+mod test_bootstrap_upgrade;
+#[cfg(all(test, feature = "contract"))]
+mod test_signer_epoch;
+#[cfg(all(test, feature = "contract", feature = "monitoring"))]
+mod test_mode_stats;
+#[cfg(all(test, feature = "contract"))]
+mod test_self_reference;
+#[cfg(all(test, feature = "contract", feature = "monitoring"))]
+mod test_metrics_export;
+#[cfg(all(test, feature = "contract"))]
+mod test_duplicate_approval;
+#[cfg(all(test, feature = "contract"))]
+mod test_config_provenance;
+#[cfg(all(test, feature = "contract", feature = "monitoring"))]
+mod test_metrics_sink;
+#[cfg(all(test, feature = "contract"))]
+mod test_idempotent_proposal;
+#[cfg(all(test, feature = "contract"))]
+mod test_countdown;
+#[cfg(all(test, feature = "contract"))]
+mod test_execution_attempts;
+#[cfg(all(test, feature = "contract"))]
+mod test_proposal_templates;
+#[cfg(all(test, feature = "contract"))]
+mod test_history_retention;
+#[cfg(all(test, feature = "contract"))]
+mod test_rejection_reasons;
+#[cfg(all(test, feature = "contract"))]
+mod test_validate_proposal;
+#[cfg(all(test, feature = "contract"))]
+mod test_upgrade_ticket;
+#[cfg(all(test, feature = "contract"))]
+mod test_authorities;
+#[cfg(all(test, feature = "contract"))]
+mod test_features;
+#[cfg(all(test, feature = "contract"))]
+mod test_signer_onboarding;
+#[cfg(all(test, feature = "contract"))]
+mod test_proposal_digest;
+#[cfg(all(test, feature = "contract", feature = "monitoring"))]
+mod test_version_metrics;
+#[cfg(all(test, feature = "contract"))]
+mod test_paging;
+#[cfg(all(test, feature = "contract"))]
+mod test_wasm_blocklist;
+#[cfg(all(test, feature = "contract"))]
+mod test_approval_readiness;
+#[cfg(all(test, feature = "contract"))]
+mod test_guardian_pause;
+#[cfg(all(test, feature = "contract"))]
+mod test_creation_checks;
+#[cfg(all(test, feature = "contract"))]
+mod test_environment;
+#[cfg(all(test, feature = "contract"))]
+mod test_approve_and_execute;
+#[cfg(all(test, feature = "contract"))]
+mod test_version_label;
+#[cfg(all(test, feature = "contract"))]
+mod test_approval_bitmap;
+#[cfg(all(test, feature = "contract", feature = "monitoring"))]
+mod test_caller_kind;
+#[cfg(all(test, feature = "contract"))]
+mod test_irreversible_actions;
+#[cfg(all(test, feature = "contract", feature = "monitoring"))]
+mod test_version_stats;
+#[cfg(all(test, feature = "contract"))]
+mod test_transition_guard;
+#[cfg(all(test, feature = "contract"))]
+mod test_min_protocol;
+#[cfg(all(test, feature = "contract"))]
+mod test_state_export;
+#[cfg(all(test, feature = "contract"))]
+mod test_expedite;
+#[cfg(all(test, feature = "contract"))]
+mod test_admin_handover;
+// ==================== END MONITORING MODULE ====================
+
+#[cfg_attr(feature = "contract", contract)]
+pub struct GrainlifyContract;
+#[cfg(feature = "contract")]
+#[contractimpl]
+impl GrainlifyContract {
+    fn emit_build_info_event(
+        env: &Env,
+        init_path: Symbol,
+        admin: Option<Address>,
+        signer_count: u32,
+        threshold: u32,
+    ) {
+        env.events().publish(
+            (symbol_short!("init"), symbol_short!("build")),
+            BuildInfoEvent {
+                init_path,
+                admin,
+                signer_count,
+                threshold,
+                version: grainlify_upgradeable::get_version(env),
+                timestamp: env.ledger().timestamp(),
+                event_version: EVENT_SCHEMA_VERSION,
+            },
+        );
+    }
+
+    /// Resolves an init path's optional starting version.
+    fn initial_version(version: Option<u32>) -> u32 {
+        match version {
+            None => VERSION,
+            Some(0) => panic!("{}", ContractError::InvalidInitialVersion as u32),
+            Some(version) => version,
+        }
+    }
+
+    fn emit_init_event(env: &Env, admin: Option<Address>, signers: Vec<Address>, threshold: u32) {
+        env.events().publish(
+            (symbol_short!("grainlify"), symbol_short!("init")),
+            InitializedEvent {
+                admin,
+                signers,
+                threshold,
+                version: grainlify_upgradeable::get_version(env),
+                timestamp: env.ledger().timestamp(),
+                event_version: EVENT_SCHEMA_VERSION,
+            },
+        );
+    }
+
+    /// Records `new_wasm_hash` as the installed code and publishes the
+    /// `("grainlify", "upgraded")` lifecycle event.
+    fn emit_upgraded_event(env: &Env, new_wasm_hash: &BytesN<32>) {
+        let old_wasm_hash: BytesN<32> = env
+            .storage()
+            .instance()
+            .get(&DataKey::CurrentWasmHash)
+            .unwrap_or(BytesN::from_array(env, &[0; 32]));
+        env.storage().instance().set(&DataKey::CurrentWasmHash, new_wasm_hash);
+        env.events().publish(
+            (symbol_short!("grainlify"), symbol_short!("upgraded")),
+            UpgradedEvent {
+                old_wasm_hash,
+                new_wasm_hash: new_wasm_hash.clone(),
+                new_version: env.storage().instance().get(&DataKey::Version).unwrap_or(0),
+                timestamp: env.ledger().timestamp(),
+                event_version: EVENT_SCHEMA_VERSION,
+            },
+        );
+    }
+
+    /// Moves per-proposal entries written by versions that kept them in
+    /// instance storage into persistent storage. Called at the top of every
+    /// proposal entrypoint; afterwards it costs one instance read. A backlog
+    /// of up to `RELOCATION_BATCH` IDs is moved inline by the first call
+    /// after an upgrade; a larger one must first be drained through
+    /// `relocate_upgrade_proposals`, and until then proposal entrypoints fail
+    /// with `ProposalStorageError::RelocationPending`.
+    fn ensure_proposal_storage(env: &Env) {
+        match grainlify_upgradeable::legacy_proposals_remaining(env) {
+            None => {}
+            Some(remaining) if remaining <= grainlify_upgradeable::RELOCATION_BATCH => {
+                Self::relocate_proposal_batch(env);
+            }
+            Some(_) => panic!("{}", ProposalStorageError::RelocationPending as u32),
+        }
+    }
+
+    /// Relocates the next `RELOCATION_BATCH` proposal IDs, including the
+    /// contract's own per-proposal entries. Returns whether a batch ran.
+    fn relocate_proposal_batch(env: &Env) -> bool {
+        let Some((first, last)) =
+            grainlify_upgradeable::relocate_legacy_proposals(env, grainlify_upgradeable::RELOCATION_BATCH)
+        else {
+            return false;
+        };
+        for proposal_id in first..=last {
+            Self::relocate_to_persistent::<Address>(env, DataKey::UpgradeProposalProposer(proposal_id));
+            Self::relocate_to_persistent::<u64>(env, DataKey::UpgradeTimelock(proposal_id));
+            Self::relocate_to_persistent::<FleetUpgradeProposal>(env, DataKey::FleetUpgradeProposal(proposal_id));
+            Self::relocate_to_persistent::<bool>(env, DataKey::UnpauseProposal(proposal_id));
+        }
+        true
+    }
+
+    /// Prunes or archives `proposal_id` if it is finished. Returns whether
+    /// anything was removed.
+    fn sweep_one(env: &Env, proposal_id: u64) -> bool {
+        let Some(proposal) = MultiSig::get_proposal_opt(env, proposal_id) else {
+            return false;
+        };
+        if proposal.executed {
+            MultiSig::archive(env, proposal_id);
+            Self::remove_proposal_entries(env, proposal_id, false);
+            true
+        } else {
+            // Expired implies prunable here, so counting first is safe.
+            Self::record_proposal_pruned(env, proposal_id);
+            if MultiSig::try_prune(env, proposal_id).is_err() {
+                return false;
+            }
+            Self::remove_proposal_entries(env, proposal_id, true);
+            true
+        }
+    }
+
+    /// Removes the contract's per-proposal entries. `include_history` also
+    /// drops the upgrade hash and fleet payload, which an executed proposal
+    /// keeps as history.
+    fn remove_proposal_entries(env: &Env, proposal_id: u64, include_history: bool) {
+        let storage = env.storage().persistent();
+        storage.remove(&DataKey::UpgradeProposalProposer(proposal_id));
+        storage.remove(&DataKey::UpgradeTimelock(proposal_id));
+        storage.remove(&DataKey::UnpauseProposal(proposal_id));
+        storage.remove(&ProposalKey::UpgradeCommitment(proposal_id));
+        storage.remove(&ProposalKey::UpgradeWindow(proposal_id));
+        storage.remove(&ProposalKey::UpgradeProposedAt(proposal_id));
+        storage.remove(&ProposalKey::UpgradeAmendLocked(proposal_id));
+        storage.remove(&ProposalKey::UpgradeSupersededBy(proposal_id));
+        storage.remove(&ProposalKey::UpgradePostponed(proposal_id));
+        storage.remove(&ProposalKey::UpgradeNetwork(proposal_id));
+        Self::untrack_pending_upgrade(env, proposal_id);
+        if include_history {
+            storage.remove(&DataKey::UpgradeProposal(proposal_id));
+            storage.remove(&DataKey::FleetUpgradeProposal(proposal_id));
+            storage.remove(&ProposalKey::ActionProposal(proposal_id));
+            storage.remove(&ProposalKey::ProposalTimings(proposal_id));
+            storage.remove(&ProposalKey::ThresholdAtCreation(proposal_id));
+            storage.remove(&ProposalKey::UpgradeDescription(proposal_id));
+            storage.remove(&ProposalKey::ExecutionAttempts(proposal_id));
+            storage.remove(&ProposalKey::Rejections(proposal_id));
+            storage.remove(&ProposalKey::UpgradeMinProtocol(proposal_id));
+            if let Some(external_id) = storage.get::<_, u64>(&ProposalKey::UpgradeExternalId(proposal_id)) {
+                storage.remove(&ProposalKey::UpgradeExternalId(proposal_id));
+                if storage.get::<_, u64>(&ProposalKey::ExternalIdIndex(external_id)) == Some(proposal_id) {
+                    storage.remove(&ProposalKey::ExternalIdIndex(external_id));
+                }
+            }
+            if let Some(key) = storage.get::<_, BytesN<32>>(&ProposalKey::UpgradeContentKey(proposal_id)) {
+                storage.remove(&ProposalKey::UpgradeContentKey(proposal_id));
+                if storage.get::<_, u64>(&ProposalKey::ContentKeyIndex(key.clone())) == Some(proposal_id) {
+                    storage.remove(&ProposalKey::ContentKeyIndex(key));
+                }
+            }
+        }
+    }
+
+    fn relocate_to_persistent<V>(env: &Env, key: DataKey)
+    where
+        V: IntoVal<Env, Val> + TryFromVal<Env, Val>,
+    {
+        if let Some(value) = env.storage().instance().get::<_, V>(&key) {
+            env.storage().instance().remove(&key);
+            env.storage().persistent().set(&key, &value);
+            let ttl = grainlify_upgradeable::RELOCATED_TTL;
+            env.storage().persistent().extend_ttl(&key, ttl, ttl);
+        }
+    }
+
+    /// Start the upgrade timelock once `proposal_id` meets the threshold.
+    /// No-op if the threshold is not met yet or the timelock already runs.
+    fn start_timelock_if_ready(env: &Env, proposal_id: u64) {
+        let Some((tally, threshold)) = MultiSig::open_tally(env, proposal_id) else {
+            return;
+        };
+        if tally >= threshold && !env.storage().persistent().has(&DataKey::UpgradeTimelock(proposal_id)) {
+            let now = env.ledger().timestamp();
+            env.storage().persistent().set(&DataKey::UpgradeTimelock(proposal_id), &now);
+            Self::record_threshold_met(env, proposal_id);
+            env.events().publish(
+                (Symbol::new(env, "timelock"), Symbol::new(env, "started")),
+                (proposal_id, now),
+            );
+            Self::emit_readiness(env, proposal_id, symbol_short!("ready"), tally, threshold);
+        }
+    }
+
+    /// Publishes `("upgrade", action)` with a `ProposalReadinessEvent`.
+    fn emit_readiness(env: &Env, proposal_id: u64, action: Symbol, tally: u32, threshold: u32) {
+        env.events().publish(
+            (symbol_short!("upgrade"), action),
+            ProposalReadinessEvent {
+                proposal_id,
+                tally,
+                threshold,
+                timestamp: env.ledger().timestamp(),
+                event_version: EVENT_SCHEMA_VERSION,
+            },
+        );
+    }
+
+    /// Clears the timelock of a proposal whose approvals dropped below the
+    /// threshold, publishing `("upgrade", "unready")` if it had started.
+    fn stop_timelock_if_unready(env: &Env, proposal_id: u64) {
+        let key = DataKey::UpgradeTimelock(proposal_id);
+        if MultiSig::approvals_needed(env, proposal_id) != Some(0) && env.storage().persistent().has(&key) {
+            env.storage().persistent().remove(&key);
+            let tally = MultiSig::get_valid_approvals(env, proposal_id).len();
+            let threshold = MultiSig::get_config_opt(env).map_or(0, |config| config.threshold);
+            Self::emit_readiness(env, proposal_id, symbol_short!("unready"), tally, threshold);
+        }
+    }
+
+    fn governance_totals(env: &Env) -> GovernanceTotals {
+        env.storage().instance().get(&DataKey::GovernanceTotals).unwrap_or_default()
+    }
+
+    fn load_timings(env: &Env, proposal_id: u64) -> Option<ProposalTimings> {
+        env.storage().persistent().get(&ProposalKey::ProposalTimings(proposal_id))
+    }
+
+    fn record_proposal_created(env: &Env, proposal_id: u64) {
+        let timings = ProposalTimings {
+            created_at: env.ledger().timestamp(),
+            threshold_met_at: None,
+            executed_at: None,
+        };
+        env.storage().persistent().set(&ProposalKey::ProposalTimings(proposal_id), &timings);
+        if let Some(config) = MultiSig::get_config_opt(env) {
+            env.storage()
+                .persistent()
+                .set(&ProposalKey::ThresholdAtCreation(proposal_id), &config.threshold);
+        }
+        let mut totals = Self::governance_totals(env);
+        totals.created += 1;
+        env.storage().instance().set(&DataKey::GovernanceTotals, &totals);
+    }
+
+    /// Only the first time counts; a threshold lost to a revoke and met
+    /// again keeps the original timestamp. Proposals without timings
+    /// (created before they were recorded) are left out of the stats.
+    fn record_threshold_met(env: &Env, proposal_id: u64) {
+        let Some(mut timings) = Self::load_timings(env, proposal_id) else {
+            return;
+        };
+        if timings.threshold_met_at.is_some() {
+            return;
+        }
+        let now = env.ledger().timestamp();
+        timings.threshold_met_at = Some(now);
+        env.storage().persistent().set(&ProposalKey::ProposalTimings(proposal_id), &timings);
+        let mut totals = Self::governance_totals(env);
+        totals.reached_threshold += 1;
+        totals.threshold_secs += now.saturating_sub(timings.created_at);
+        env.storage().instance().set(&DataKey::GovernanceTotals, &totals);
+    }
+
+    fn record_proposal_executed(env: &Env, proposal_id: u64) {
+        let Some(mut timings) = Self::load_timings(env, proposal_id) else {
+            return;
+        };
+        let now = env.ledger().timestamp();
+        timings.executed_at = Some(now);
+        env.storage().persistent().set(&ProposalKey::ProposalTimings(proposal_id), &timings);
+        let mut totals = Self::governance_totals(env);
+        totals.executed += 1;
+        totals.execution_secs += now.saturating_sub(timings.threshold_met_at.unwrap_or(timings.created_at));
+        env.storage().instance().set(&DataKey::GovernanceTotals, &totals);
+    }
+
+    fn record_proposal_cancelled(env: &Env, proposal_id: u64) {
+        if Self::load_timings(env, proposal_id).is_none() {
+            return;
+        }
+        let mut totals = Self::governance_totals(env);
+        totals.cancelled += 1;
+        env.storage().instance().set(&DataKey::GovernanceTotals, &totals);
+    }
+
+    /// Call before pruning: counts the proposal as expired unless it was
+    /// cancelled, which `cancel_upgrade` already counted.
+    fn record_proposal_pruned(env: &Env, proposal_id: u64) {
+        if Self::load_timings(env, proposal_id).is_none() {
+            return;
+        }
+        let cancelled = MultiSig::get_proposal_opt(env, proposal_id)
+            .map(|p| p.cancelled)
+            .unwrap_or(false);
+        if cancelled || !MultiSig::is_expired(env, proposal_id) {
+            return;
+        }
+        let mut totals = Self::governance_totals(env);
+        totals.expired += 1;
+        env.storage().instance().set(&DataKey::GovernanceTotals, &totals);
+    }
+
+    /// Panics with `OutsideExecutionWindow` before `proposal_id`'s window
+    /// opens. Past the window the proposal is expired and fails the
+    /// executability check instead.
+    fn require_in_window(env: &Env, proposal_id: u64) {
+        if !Self::window_open(env, proposal_id) {
+            panic!("{}", ContractError::OutsideExecutionWindow as u32);
+        }
+    }
+
+    fn require_protocol(env: &Env, proposal_id: u64) {
+        if !Self::protocol_supported(env, proposal_id) {
+            panic!("{}", ExecutionError::ProtocolTooOld as u32);
+        }
+    }
+
+    /// Whether the network protocol is at least the proposal's
+    /// `min_protocol`; true when it has none.
+    fn protocol_supported(env: &Env, proposal_id: u64) -> bool {
+        env.storage()
+            .persistent()
+            .get::<_, u32>(&ProposalKey::UpgradeMinProtocol(proposal_id))
+            .is_none_or(|min_protocol| env.ledger().protocol_version() >= min_protocol)
+    }
+
+    fn window_open(env: &Env, proposal_id: u64) -> bool {
+        let window: Option<ExecutionWindow> =
+            env.storage().persistent().get(&ProposalKey::UpgradeWindow(proposal_id));
+        match window.and_then(|window| window.not_before) {
+            Some(not_before) => env.ledger().timestamp() >= not_before,
+            None => true,
+        }
+    }
+
+    /// Panics with `UpgradeNotRevealed` while `proposal_id` has an
+    /// outstanding upgrade commitment.
+    fn require_revealed(env: &Env, proposal_id: u64) {
+        if !Self::is_revealed(env, proposal_id) {
+            panic!("{}", ContractError::UpgradeNotRevealed as u32);
+        }
+    }
+
+    fn is_revealed(env: &Env, proposal_id: u64) -> bool {
+        !env.storage().persistent().has(&ProposalKey::UpgradeCommitment(proposal_id))
+    }
+
+    /// Panics unless `proposal_id` is approved and its timelock has elapsed.
+    fn require_timelock_elapsed(env: &Env, proposal_id: u64) {
+        let remaining = Self::timelock_remaining(env, proposal_id)
+            .unwrap_or_else(|| panic!("Timelock not started - call approve_upgrade first"));
+        if remaining > 0 {
+            panic!("Timelock delay not met: {} seconds remaining", remaining);
+        }
+
+        if !MultiSig::can_execute(env, proposal_id) {
+            panic!("Threshold not met or proposal not executable");
+        }
+    }
+
+    /// Panics with `NotUnanimous` unless every current signer holds a valid
+    /// approval of `proposal_id` and no signer invitation is pending, then
+    /// as `require_timelock_elapsed` does if the proposal cannot execute.
+    fn require_unanimous(env: &Env, proposal_id: u64) {
+        let signers = MultiSig::get_config_opt(env).map_or(Vec::new(env), |config| config.signers);
+        let approvals = MultiSig::get_valid_approvals(env, proposal_id);
+        let unanimous = !signers.is_empty()
+            && signers.iter().all(|signer| approvals.contains(&signer))
+            && Self::get_pending_signers(env.clone()).is_empty();
+        if !unanimous {
+            panic!("{}", ExecutionError::NotUnanimous as u32);
+        }
+        if !MultiSig::can_execute(env, proposal_id) {
+            panic!("Threshold not met or proposal not executable");
+        }
+    }
+
+    /// Loads `proposal_id` for the countdown views, failing if it is unknown
+    /// or already closed.
+    fn open_proposal(env: &Env, proposal_id: u64) -> Result<multisig::Proposal, CountdownError> {
+        let proposal = MultiSig::get_proposal_opt(env, proposal_id).ok_or(CountdownError::ProposalNotFound)?;
+        if proposal.executed {
+            return Err(CountdownError::ProposalExecuted);
+        }
+        if proposal.cancelled {
+            return Err(CountdownError::ProposalCancelled);
+        }
+        Ok(proposal)
+    }
+
+    /// Seconds left before `proposal_id`'s timelock elapses, or `None` if
+    /// no approval has started it.
+    fn timelock_remaining(env: &Env, proposal_id: u64) -> Option<u64> {
+        let timelock_start: u64 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::UpgradeTimelock(proposal_id))?;
+        let elapsed = env.ledger().timestamp().saturating_sub(timelock_start);
+        Some(Self::get_timelock_delay(env.clone()).saturating_sub(elapsed))
+    }
+
+    /// Seconds left before a token proposal's timelock elapses, or `None`
+    /// while its voting window is open. The timelock starts when the window
+    /// closes.
+    fn token_timelock_remaining(env: &Env, proposal: &TokenProposal) -> Option<u64> {
+        let elapsed = env.ledger().timestamp().checked_sub(proposal.voting_ends_at)?;
+        Some(Self::get_timelock_delay(env.clone()).saturating_sub(elapsed))
+    }
+
+    /// Pays the configured keeper reward to `keeper` for `task`. An exhausted
+    /// pool emits `("keeper", "empty")` instead of failing the maintenance call.
+    fn pay_keeper_reward(env: &Env, keeper: &Address, task: Symbol) {
+        let config: KeeperRewardConfig = match env.storage().instance().get(&DataKey::KeeperRewardConfig) {
+            Some(config) => config,
+            None => return,
+        };
+        let balance = Self::get_reward_pool_balance(env.clone());
+        if balance < config.reward_per_call {
+            env.events().publish(
+                (symbol_short!("keeper"), symbol_short!("empty")),
+                (task, balance),
+            );
+            return;
+        }
+        env.storage()
+            .instance()
+            .set(&DataKey::KeeperRewardPool, &(balance - config.reward_per_call));
+        token::Client::new(env, &config.token).transfer(
+            &env.current_contract_address(),
+            keeper,
+            &config.reward_per_call,
+        );
+        env.events().publish(
+            (symbol_short!("keeper"), symbol_short!("reward")),
+            (keeper.clone(), task, config.reward_per_call),
+        );
+    }
+
+    /// Attestations for `wasm_hash` excluding any made by `auditor`.
+    fn without_auditor(env: &Env, wasm_hash: &BytesN<32>, auditor: &Address) -> Vec<Attestation> {
+        let mut kept: Vec<Attestation> = Vec::new(env);
+        for attestation in Self::get_attestations(env.clone(), wasm_hash.clone()).iter() {
+            if attestation.auditor != *auditor {
+                kept.push_back(attestation);
+            }
+        }
+        kept
+    }
+
+    /// Contract error code of a failed cross-contract call, or 0 when the
+    /// callee trapped without one.
+    fn invoke_error_code<T, C>(result: Result<T, Result<InvokeError, C>>) -> u32 {
+        match result {
+            Err(Ok(InvokeError::Contract(code))) => code,
+            _ => 0,
+        }
+    }
+
+    /// Calls `on_dependency_upgraded(upgraded, new_version)` on every registered
+    /// subscriber. Failures are reported in the `("subscr", "notify")` event
+    /// and never abort the upgrade.
+    fn notify_upgrade_subscribers(env: &Env) {
+        let subscribers: Vec<Address> = env
+            .storage()
+            .instance()
+            .get(&DataKey::UpgradeSubscribers)
+            .unwrap_or(Vec::new(env));
+        if subscribers.is_empty() {
+            return;
+        }
+
+        let new_version: u32 = env.storage().instance().get(&DataKey::Version).unwrap_or(0);
+        let callback = Symbol::new(env, "on_dependency_upgraded");
+        let args: Vec<Val> = (env.current_contract_address(), new_version).into_val(env);
+        for subscriber in subscribers.iter() {
+            let result = env.try_invoke_contract::<(), InvokeError>(&subscriber, &callback, args.clone());
+            let delivered = matches!(result, Ok(Ok(())));
+            if !delivered {
+                monitoring::track_failure(
+                    env,
+                    symbol_short!("notify"),
+                    env.current_contract_address(),
+                    Self::invoke_error_code(result),
+                    false,
+                );
+            }
+            env.events().publish(
+                (symbol_short!("subscr"), symbol_short!("notify")),
+                (subscriber, new_version, delivered),
+            );
+        }
+    }
+
+    /// One-time initialization: set the admin and initial version. Requires `admin` auth.
+    /// Fails with `SelfReference` if `admin` is this contract.
+    pub fn init_admin(env: Env, admin: Address) {
+        Self::init_admin_with_version(env, admin, None);
+    }
+
+    /// `init_admin` that records a refusal instead of failing, like
+    /// `attempt_execute_upgrade`. On a contract that is already initialized
+    /// it counts an `adm_init` failure and returns `AlreadyInitialized`,
+    /// where a second plain `init_admin` reverts and is not counted.
+    /// Returns `None` after initializing.
+    pub fn attempt_init_admin(env: Env, admin: Address) -> Option<u32> {
+        Self::require_function_enabled(&env, "attempt_init_admin");
+        if grainlify_upgradeable::get_version(&env) == 0 {
+            Self::init_admin(env, admin);
+            return None;
+        }
+        let code = ContractError::AlreadyInitialized as u32;
+        admin.require_auth();
+        monitoring::track_failure(
+            &env,
+            symbol_short!("adm_init"),
+            admin.clone(),
+            code,
+            Self::trips_breaker(&env, &admin),
+        );
+        Some(code)
+    }
+
+    /// `init_admin` starting at `version` instead of `VERSION`, e.g. when
+    /// redeploying an existing system to a new contract ID. `None` uses
+    /// `VERSION`; 0 fails with `InvalidInitialVersion`.
+    pub fn init_admin_with_version(env: Env, admin: Address, version: Option<u32>) {
+        Self::initialize_admin(env, admin, version, None);
+    }
+
+    /// `init_admin` that also labels the deployment with `environment`
+    /// (e.g. `mainnet`) and records the network passphrase hash; see
+    /// `assert_environment`.
+    pub fn init_admin_with_environment(env: Env, admin: Address, environment: Symbol) {
+        Self::initialize_admin(env.clone(), admin, None, None);
+        Self::store_environment(&env, Some(environment));
+    }
+
+    /// `init_admin_with_version` that also records `wasm_hash` as the
+    /// installed code for `get_current_wasm_hash`.
+    pub fn init_admin_with_wasm(env: Env, admin: Address, wasm_hash: BytesN<32>, version: Option<u32>) {
+        Self::initialize_admin(env, admin, version, Some(wasm_hash));
+    }
+
+    fn initialize_admin(env: Env, admin: Address, version: Option<u32>, wasm_hash: Option<BytesN<32>>) {
+        Self::require_not_self(&env, &admin);
+        let version = Self::initial_version(version);
+        grainlify_upgradeable::initialize(&env, &admin, version);
+        Self::record_version(&env);
+        env.storage().instance().set(&DataKey::InitMode, &InitMode::Admin);
+        if let Some(wasm_hash) = wasm_hash {
+            env.storage().instance().set(&DataKey::CurrentWasmHash, &wasm_hash);
+        }
+        env.storage().instance().set(&DataKey::ReadOnlyMode, &false);
+        env.storage().instance().set(&DataKey::LivenessSchemaVersion, &LIVENESS_SCHEMA_VERSION);
+        env.storage().instance().set(&DataKey::SchemaVersion, &STORAGE_SCHEMA_VERSION);
+
+        Self::emit_build_info_event(
+            &env,
+            symbol_short!("adm_init"),
+            Some(admin.clone()),
+            0,
+            0,
+        );
+        Self::emit_init_event(&env, Some(admin), Vec::new(&env), 0);
+    }
+
+    // ========================================================================
+    // Timelock Execution (continued from propose/approve flow)
+    // ========================================================================
+
+    /// Execute a multisig-approved upgrade after the timelock delay has elapsed.
+    /// Fails with `WrongGovernanceMode` on an admin-only contract.
+    pub fn execute_upgrade(env: Env, proposal_id: u64) {
+        Self::execute_upgrade_v2(env, proposal_id);
+    }
+
+    /// `execute_upgrade`, returning a receipt of what was installed.
+    ///
+    /// Fails with `OutOfOrderUpgrade` while a newer upgrade proposal is
+    /// approved or executed; see `execute_upgrade_v3` to override.
+    pub fn execute_upgrade_v2(env: Env, proposal_id: u64) -> UpgradeReceipt {
+        Self::execute_upgrade_v3(env, proposal_id, false)
+    }
+
+    /// `execute_upgrade_v2` with an explicit override for the ordering
+    /// guard. Executing an upgrade proposal while one with a higher ID has
+    /// met its threshold or already executed could reinstall an older
+    /// build, so it fails with `OutOfOrderUpgrade` unless
+    /// `allow_out_of_order` is set, in which case
+    /// `("upgrade", "out_order")` records `(proposal_id, newer_id)`.
+    /// Multisig mode only; other proposal kinds are not considered.
+    pub fn execute_upgrade_v3(env: Env, proposal_id: u64, allow_out_of_order: bool) -> UpgradeReceipt {
+        Self::execute_upgrade_inner(env, proposal_id, allow_out_of_order, None)
+    }
+
+    /// `execute_upgrade_v3` by an authenticated `executor`, who is recorded
+    /// with the ledger sequence in `get_executed_upgrade`, the upgrade
+    /// archive, and the `("upgrade", "executed")` event. Anyone may execute.
+    pub fn execute_upgrade_as(
+        env: Env,
+        executor: Address,
+        proposal_id: u64,
+        allow_out_of_order: bool,
+    ) -> UpgradeReceipt {
+        Self::require_function_enabled(&env, "execute_upgrade_as");
+        executor.require_auth();
+        Self::execute_upgrade_inner(env, proposal_id, allow_out_of_order, Some(executor))
+    }
+
+    /// `execute_upgrade_as` for the configured relayer, which submits and
+    /// pays for the execution on the signers' behalf. The approvals are
+    /// already on-chain, so the relayer only triggers what the quorum
+    /// approved. Fails with `NotRelayer` for any other address or when no
+    /// relayer is set. The execution is recorded as relayed.
+    pub fn execute_upgrade_relayed(
+        env: Env,
+        relayer: Address,
+        proposal_id: u64,
+        allow_out_of_order: bool,
+    ) -> UpgradeReceipt {
+        Self::require_function_enabled(&env, "execute_upgrade_relayed");
+        relayer.require_auth();
+        if Self::get_relayer(env.clone()).as_ref() != Some(&relayer) {
+            panic!("{}", ContractError::NotRelayer as u32);
+        }
+        Self::execute_upgrade_inner(env, proposal_id, allow_out_of_order, Some(relayer))
+    }
+
+    /// Execute `proposal_id` before its timelock elapses. Allowed only when
+    /// every current signer, not just the threshold, holds a valid approval
+    /// and no `add_signer` invitation is pending; fails with
+    /// `ExecutionError::NotUnanimous` otherwise. The signer set is read at
+    /// execution, so a signer added after the approvals blocks it. All other
+    /// `execute_upgrade_v2` checks apply, and the archive entry and
+    /// `("upgrade", "executed")` event are marked `expedited`. Multisig mode
+    /// only.
+    pub fn expedite(env: Env, proposal_id: u64) -> UpgradeReceipt {
+        Self::require_function_enabled(&env, "expedite");
+        if TokenVoting::get_config(&env).is_some() {
+            panic!("{}", ContractError::WrongGovernanceMode as u32);
+        }
+        Self::in_transition(&env.clone(), symbol_short!("exec_upg"), || {
+            Self::execute_upgrade_steps(env, proposal_id, false, None, true)
+        })
+    }
+
+    /// `execute_upgrade_as` that records a failure instead of failing. A
+    /// failed `execute_upgrade` reverts everything it wrote, so it cannot
+    /// leave a trace; this entry point runs the `preview_execution` checks
+    /// first and, if any fails, stores it in the proposal's
+    /// `execution_attempts`, emits `("upgrade", "exec_fail")` with
+    /// `(proposal_id, caller, check)`, counts it as an `Execute` governance
+    /// failure, and returns the check. Returns `None` after executing.
+    /// Panics with `ProposalNotFound` for an unknown ID, so unknown IDs
+    /// cannot grow storage.
+    pub fn attempt_execute_upgrade(env: Env, caller: Address, proposal_id: u64) -> Option<ExecutionCheck> {
+        Self::require_function_enabled(&env, "attempt_execute_upgrade");
+        caller.require_auth();
+        let preview = Self::preview_execution(env.clone(), proposal_id);
+        let Some(check) = preview.failing.first() else {
+            Self::execute_upgrade_inner(env, proposal_id, false, Some(caller));
+            return None;
+        };
+
+        let key = ProposalKey::ExecutionAttempts(proposal_id);
+        let count = env
+            .storage()
+            .persistent()
+            .get::<_, ExecutionAttempts>(&key)
+            .map_or(0, |attempts| attempts.count);
+        env.storage().persistent().set(
+            &key,
+            &ExecutionAttempts {
+                count: count.saturating_add(1),
+                last_attempt_at: env.ledger().timestamp(),
+                last_failure: check,
+                last_caller: caller.clone(),
+            },
+        );
+        monitoring::track_governance_failure(
+            &env,
+            symbol_short!("exec_upg"),
+            monitoring::GovernanceStage::Execute,
+            caller.clone(),
+            Self::execution_check_code(check),
+            Self::trips_breaker(&env, &caller),
+        );
+        env.events().publish(
+            (symbol_short!("upgrade"), symbol_short!("exec_fail")),
+            (proposal_id, caller, check),
+        );
+        Some(check)
+    }
+
+    /// The `ContractError` code `execute_upgrade` fails with for `check`,
+    /// or 0, as in `invoke_error_code`, where it fails with a message.
+    fn execution_check_code(check: ExecutionCheck) -> u32 {
+        let error = match check {
+            ExecutionCheck::Paused => ContractError::Paused,
+            ExecutionCheck::OutsideWindow => ContractError::OutsideExecutionWindow,
+            ExecutionCheck::NotRevealed => ContractError::UpgradeNotRevealed,
+            ExecutionCheck::AttestationMissing => ContractError::AttestationMissing,
+            ExecutionCheck::OutOfOrder => ContractError::OutOfOrderUpgrade,
+            _ => return 0,
+        };
+        error as u32
+    }
+
+    /// Address allowed to call `execute_upgrade_relayed`, if any.
+    pub fn get_relayer(env: Env) -> Option<Address> {
+        env.storage().instance().get(&RelayerKey::Relayer)
+    }
+
+    /// Whitelist `relayer` for `execute_upgrade_relayed`, replacing any
+    /// previous relayer. Publishes `("relayer", "set")`. Governance only;
+    /// multisig deployments use a `ProposalAction::SetRelayer` bundle.
+    /// Fails with `SelfReference` for this contract's address, and flags a
+    /// relayer that is also the admin or a signer via `("role", "overlap")`.
+    pub fn set_relayer(env: Env, relayer: Address) {
+        Self::require_function_enabled(&env, "set_relayer");
+        grainlify_upgradeable::require_governance(&env);
+        Self::require_not_read_only(&env);
+        Self::store_relayer(&env, relayer);
+    }
+
+    /// Remove the relayer. Publishes `("relayer", "cleared")`. Governance
+    /// only; multisig deployments use a `ProposalAction::ClearRelayer`
+    /// bundle.
+    pub fn clear_relayer(env: Env) {
+        Self::require_function_enabled(&env, "clear_relayer");
+        grainlify_upgradeable::require_governance(&env);
+        Self::require_not_read_only(&env);
+        Self::remove_relayer(&env);
+    }
+
+    fn store_relayer(env: &Env, relayer: Address) {
+        Self::require_not_self(env, &relayer);
+        if grainlify_upgradeable::get_admin(env).as_ref() == Some(&relayer) {
+            Self::flag_role_overlap(env, symbol_short!("relayer"), symbol_short!("admin"), &relayer);
+        }
+        if MultiSig::is_signer(env, &relayer) {
+            Self::flag_role_overlap(env, symbol_short!("relayer"), symbol_short!("signer"), &relayer);
+        }
+        env.storage().instance().set(&RelayerKey::Relayer, &relayer);
+        env.events().publish((symbol_short!("relayer"), symbol_short!("set")), relayer);
+    }
+
+    fn remove_relayer(env: &Env) {
+        if let Some(relayer) = Self::get_relayer(env.clone()) {
+            env.storage().instance().remove(&RelayerKey::Relayer);
+            env.events().publish((symbol_short!("relayer"), symbol_short!("cleared")), relayer);
+        }
+    }
+
+    fn execute_upgrade_inner(
+        env: Env,
+        proposal_id: u64,
+        allow_out_of_order: bool,
+        executor: Option<Address>,
+    ) -> UpgradeReceipt {
+        Self::in_transition(&env.clone(), symbol_short!("exec_upg"), || {
+            Self::execute_upgrade_steps(env, proposal_id, allow_out_of_order, executor, false)
+        })
+    }
+
+    fn execute_upgrade_steps(
+        env: Env,
+        proposal_id: u64,
+        allow_out_of_order: bool,
+        executor: Option<Address>,
+        expedited: bool,
+    ) -> UpgradeReceipt {
+        let start = env.ledger().timestamp();
+        Self::ensure_proposal_storage(&env);
+        Self::require_not_read_only(&env);
+        Self::require_not_paused(&env);
+
+        let wasm_hash = if TokenVoting::get_config(&env).is_some() {
+            // Token-holder mode: the closed vote replaces the threshold, and
+            // the timelock runs from the close of the window.
+            let proposal = TokenVoting::get_proposal(&env, proposal_id)
+                .unwrap_or_else(|| panic!("{}", ContractError::ProposalNotFound as u32));
+            if let Err(reason) = TokenVoting::check_finalizable(&env, &proposal) {
+                panic!("{}", reason);
+            }
+            let remaining = Self::token_timelock_remaining(&env, &proposal).unwrap_or(0);
+            if remaining > 0 {
+                panic!("Timelock delay not met: {} seconds remaining", remaining);
+            }
+            Self::require_in_order(&env, proposal_id, allow_out_of_order);
+            let wasm_hash = TokenVoting::finalize(&env, proposal_id);
+            Self::record_highest_executed(&env, proposal_id);
+            wasm_hash
+        } else {
+            Self::require_governance_mode(&env, InitMode::Multisig);
+            if MultiSig::is_state_inconsistent(&env) {
+                panic!("Contract state inconsistent - upgrade blocked");
+            }
+
+            Self::require_in_window(&env, proposal_id);
+            if expedited {
+                Self::require_unanimous(&env, proposal_id);
+            } else {
+                Self::require_timelock_elapsed(&env, proposal_id);
+            }
+            Self::require_revealed(&env, proposal_id);
+            Self::require_protocol(&env, proposal_id);
+            Self::require_in_order(&env, proposal_id, allow_out_of_order);
+
+            let wasm_hash = match &executor {
+                Some(executor) => grainlify_upgradeable::take_approved_upgrade_by(&env, proposal_id, executor),
+                None => grainlify_upgradeable::take_approved_upgrade(&env, proposal_id),
+            };
+            env.storage().persistent().remove(&DataKey::UpgradeTimelock(proposal_id));
+            Self::untrack_pending_upgrade(&env, proposal_id);
+            Self::record_highest_executed(&env, proposal_id);
+            wasm_hash
+        };
+        let relayed = executor.is_some() && executor == Self::get_relayer(env.clone());
+        Self::record_proposal_executed(&env, proposal_id);
+        Self::archive_executed_upgrade(&env, proposal_id, &wasm_hash, executor.clone(), relayed, expedited);
+        env.events().publish(
+            (symbol_short!("upgrade"), symbol_short!("executed")),
+            UpgradeExecutedEvent {
+                proposal_id,
+                wasm_hash: wasm_hash.clone(),
+                executor,
+                relayed,
+                expedited,
+                executed_at: env.ledger().timestamp(),
+                ledger_sequence: env.ledger().sequence(),
+                external_id: Self::external_id_of(&env, proposal_id),
+                event_version: EVENT_SCHEMA_VERSION,
+            },
+        );
+        Self::install_upgrade(&env, &wasm_hash);
+
+        let duration = env.ledger().timestamp().saturating_sub(start);
+        if monitoring::is_tracked(&env, &symbol_short!("exec_upg")) {
+            monitoring::emit_performance(&env, symbol_short!("exec_upg"), duration);
+            monitoring::track_mode(&env, &symbol_short!("exec_upg"), true);
+            monitoring::track_version(&env, true);
+        }
+        Self::upgrade_receipt(&env, wasm_hash, proposal_id)
+    }
+
+    /// WASM hash of the installed code as tracked by init and upgrades, or
+    /// `None` if it was never recorded. The contract cannot hash its own
+    /// code, so an upgrade performed outside these paths leaves it stale
+    /// until `attest_current_wasm` corrects it.
+    pub fn get_current_wasm_hash(env: Env) -> Option<BytesN<32>> {
+        env.storage().instance().get(&DataKey::CurrentWasmHash)
+    }
+
+    /// Overwrite the tracked WASM hash with `wasm_hash`. Governance only;
+    /// emits `("wasm", "corrected")` with the previous and new hashes.
+    pub fn attest_current_wasm(env: Env, wasm_hash: BytesN<32>) {
+        Self::require_function_enabled(&env, "attest_current_wasm");
+        grainlify_upgradeable::require_governance(&env);
+        Self::require_not_read_only(&env);
+
+        let previous_wasm_hash = Self::get_current_wasm_hash(env.clone())
+            .unwrap_or(BytesN::from_array(&env, &[0; 32]));
+        env.storage().instance().set(&DataKey::CurrentWasmHash, &wasm_hash);
+        env.events().publish(
+            (symbol_short!("wasm"), symbol_short!("corrected")),
+            WasmHashCorrectedEvent {
+                previous_wasm_hash,
+                wasm_hash,
+                timestamp: env.ledger().timestamp(),
+                event_version: EVENT_SCHEMA_VERSION,
+            },
+        );
+    }
+
+    /// Single-admin upgrade path. Fails with `WrongGovernanceMode` on a
+    /// multisig-only contract.
+    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) {
+        Self::upgrade_v2(env, new_wasm_hash);
+    }
+
+    /// `upgrade`, returning a receipt with `proposal_id` 0. Shares the
+    /// `upgrade` kill switch.
+    pub fn upgrade_v2(env: Env, new_wasm_hash: BytesN<32>) -> UpgradeReceipt {
+        Self::admin_upgrade(env, new_wasm_hash, None)
+    }
+
+    /// `upgrade_v2` bound to `nonce`, which must equal `get_admin_nonce`
+    /// (else `InvalidAdminNonce`) and is consumed on success. Shares the
+    /// `upgrade` kill switch.
+    pub fn upgrade_with_nonce(env: Env, new_wasm_hash: BytesN<32>, nonce: u64) -> UpgradeReceipt {
+        Self::admin_upgrade(env, new_wasm_hash, Some(nonce))
+    }
+
+    /// Fails with `WrongGovernanceMode` when an initialized contract lacks
+    /// the governance `expected` needs: an admin for `InitMode::Admin`, a
+    /// multisig config for `InitMode::Multisig`. Contracts set up with both
+    /// pass either check; uninitialized ones are left to the caller's own
+    /// `NotInitialized` handling.
+    fn require_governance_mode(env: &Env, expected: InitMode) {
+        if !env.storage().instance().has(&DataKey::Version) {
+            return;
+        }
+        let configured = match expected {
+            InitMode::Admin => grainlify_upgradeable::get_admin(env).is_some(),
+            InitMode::Multisig => MultiSig::get_config_opt(env).is_some(),
+        };
+        if !configured {
+            panic!("{}", ContractError::WrongGovernanceMode as u32);
+        }
+    }
+
+    /// Fails with `SelfReference` when `address` is this contract: it cannot
+    /// authorize its own governance calls, so the instance would be stuck.
+    fn require_not_self(env: &Env, address: &Address) {
+        if *address == env.current_contract_address() {
+            panic!("{}", ContractError::SelfReference as u32);
+        }
+    }
+
+    /// `require_not_self` for every signer in `signers`.
+    fn require_no_self_signer(env: &Env, signers: &Vec<Address>) {
+        for signer in signers.iter() {
+            Self::require_not_self(env, &signer);
+        }
+    }
+
+    /// Emits `("role", "overlap")` with `(role, other_role, address)` when
+    /// one address holds two roles meant for different parties. Allowed, but
+    /// worth flagging to whoever watches governance events.
+    fn flag_role_overlap(env: &Env, role: Symbol, other_role: Symbol, address: &Address) {
+        env.events().publish(
+            (symbol_short!("role"), symbol_short!("overlap")),
+            (role, other_role, address.clone()),
+        );
+    }
+
+    fn admin_upgrade(env: Env, new_wasm_hash: BytesN<32>, nonce: Option<u64>) -> UpgradeReceipt {
+        Self::require_function_enabled(&env, "upgrade");
+        Self::require_governance_mode(&env, InitMode::Admin);
+        let tracked = monitoring::track_start(&env, symbol_short!("upgrade"));
+
+        #[cfg(feature = "strict-mode")]
+        {
+            let report = monitoring::check_invariants(&env);
+            strict_mode::strict_assert(report.healthy, "Strict mode: contract invariants unhealthy before upgrade");
+            strict_mode::strict_emit(&env, symbol_short!("upgrade"), symbol_short!("pre_chk"));
+        }
+
+        let admin = grainlify_upgradeable::require_governance(&env);
+        Self::consume_admin_nonce(&env, nonce);
+        Self::require_not_read_only(&env);
+        Self::require_not_paused(&env);
+        if Self::get_staging_delay(env.clone()).is_some() {
+            panic!("{}", ContractError::StagingRequired as u32);
+        }
+        Self::install_upgrade(&env, &new_wasm_hash);
+
+        tracked.finish(&env, admin, true);
+        Self::upgrade_receipt(&env, new_wasm_hash, 0)
+    }
+
+    // ========================================================================
+    // Two-Phase Admin Upgrades
+    // ========================================================================
+
+    /// Delay between `stage_upgrade` and `confirm_upgrade`, or `None` when
+    /// staging is off and the one-shot `upgrade` is used.
+    pub fn get_staging_delay(env: Env) -> Option<u64> {
+        env.storage().instance().get(&StagingKey::StagingDelay)
+    }
+
+    /// Turn two-phase admin upgrades on with `delay` seconds between staging
+    /// and confirmation, or off with `None`. While on, `upgrade` and its
+    /// variants fail with `StagingRequired`. Governance only.
+    pub fn set_staging_delay(env: Env, delay: Option<u64>) {
+        Self::require_function_enabled(&env, "set_staging_delay");
+        grainlify_upgradeable::require_governance(&env);
+        Self::require_not_read_only(&env);
+        match delay {
+            Some(delay) => env.storage().instance().set(&StagingKey::StagingDelay, &delay),
+            None => env.storage().instance().remove(&StagingKey::StagingDelay),
+        }
+        Self::record_config_change(&env, "staging_delay", None);
+    }
+
+    /// Record `wasm_hash` as the upgrade to install once the staging delay
+    /// has passed. Fails with `StagingDisabled` when staging is off and
+    /// `UpgradeAlreadyStaged` until a staged upgrade is confirmed or
+    /// unstaged. Governance only.
+    pub fn stage_upgrade(env: Env, wasm_hash: BytesN<32>) {
+        Self::require_function_enabled(&env, "stage_upgrade");
+        grainlify_upgradeable::require_governance(&env);
+        Self::require_not_read_only(&env);
+        Self::require_not_paused(&env);
+        let delay = Self::get_staging_delay(env.clone())
+            .unwrap_or_else(|| panic!("{}", ContractError::StagingDisabled as u32));
+        Self::require_not_blocked(&env, &wasm_hash);
+        if env.storage().instance().has(&StagingKey::StagedUpgrade) {
+            panic!("{}", ContractError::UpgradeAlreadyStaged as u32);
+        }
+        let now = env.ledger().timestamp();
+        let staged = StagedUpgrade {
+            wasm_hash,
+            staged_at: now,
+            confirm_after: now.saturating_add(delay),
+        };
+        env.storage().instance().set(&StagingKey::StagedUpgrade, &staged);
+        Self::emit_staging_event(&env, symbol_short!("staged"), &staged);
+    }
+
+    /// Install the staged upgrade. `wasm_hash` must repeat the staged hash
+    /// (else `StagedHashMismatch`) and the delay must have passed (else
+    /// `StagedUpgradeNotReady`). Governance only; shares the `upgrade` kill
+    /// switch.
+    pub fn confirm_upgrade(env: Env, wasm_hash: BytesN<32>) -> UpgradeReceipt {
+        Self::require_function_enabled(&env, "upgrade");
+        let tracked = monitoring::track_start(&env, symbol_short!("upgrade"));
+        let admin = grainlify_upgradeable::require_governance(&env);
+        Self::require_not_read_only(&env);
+        Self::require_not_paused(&env);
+        let staged = Self::load_staged_upgrade(&env);
+        if staged.wasm_hash != wasm_hash {
+            panic!("{}", ContractError::StagedHashMismatch as u32);
+        }
+        if env.ledger().timestamp() < staged.confirm_after {
+            panic!("{}", ContractError::StagedUpgradeNotReady as u32);
+        }
+        env.storage().instance().remove(&StagingKey::StagedUpgrade);
+        Self::emit_staging_event(&env, symbol_short!("confirmed"), &staged);
+        Self::install_upgrade(&env, &wasm_hash);
+
+        tracked.finish(&env, admin, true);
+        Self::upgrade_receipt(&env, wasm_hash, 0)
+    }
+
+    /// Drop the staged upgrade. Governance only.
+    pub fn unstage_upgrade(env: Env) {
+        Self::require_function_enabled(&env, "unstage_upgrade");
+        grainlify_upgradeable::require_governance(&env);
+        Self::require_not_read_only(&env);
+        let staged = Self::load_staged_upgrade(&env);
+        env.storage().instance().remove(&StagingKey::StagedUpgrade);
+        Self::emit_staging_event(&env, symbol_short!("unstaged"), &staged);
+    }
+
+    pub fn get_staged_upgrade_hash(env: Env) -> Option<BytesN<32>> {
+        env.storage()
+            .instance()
+            .get::<_, StagedUpgrade>(&StagingKey::StagedUpgrade)
+            .map(|staged| staged.wasm_hash)
+    }
+
+    /// Earliest ledger timestamp the staged upgrade can be confirmed at.
+    pub fn get_earliest_confirm_time(env: Env) -> Option<u64> {
+        env.storage()
+            .instance()
+            .get::<_, StagedUpgrade>(&StagingKey::StagedUpgrade)
+            .map(|staged| staged.confirm_after)
+    }
+
+    fn load_staged_upgrade(env: &Env) -> StagedUpgrade {
+        env.storage()
+            .instance()
+            .get(&StagingKey::StagedUpgrade)
+            .unwrap_or_else(|| panic!("{}", ContractError::NoStagedUpgrade as u32))
+    }
+
+    fn emit_staging_event(env: &Env, step: Symbol, staged: &StagedUpgrade) {
+        env.events().publish(
+            (symbol_short!("upgrade"), step),
+            StagedUpgradeEvent {
+                wasm_hash: staged.wasm_hash.clone(),
+                confirm_after: staged.confirm_after,
+                timestamp: env.ledger().timestamp(),
+                event_version: EVENT_SCHEMA_VERSION,
+            },
+        );
+    }
+
+    // ========================================================================
+    // Pre-authorized Admin Upgrades
+    // ========================================================================
+
+    /// Issue a one-shot ticket that lets anyone install `wasm_hash` through
+    /// `execute_authorized_upgrade` from `not_before` until just before
+    /// `not_after`. Fails with `InvalidExecutionWindow` unless
+    /// `not_before < not_after` and `not_after` is in the future, and with
+    /// `UpgradeAlreadyStaged` while an unexpired ticket exists. With staging
+    /// on, `not_before` must be at least the staging delay away (else
+    /// `StagingRequired`). Admin only.
+    pub fn authorize_upgrade(env: Env, wasm_hash: BytesN<32>, not_before: u64, not_after: u64) {
+        Self::require_function_enabled(&env, "authorize_upgrade");
+        Self::require_governance_mode(&env, InitMode::Admin);
+        let admin = grainlify_upgradeable::require_governance(&env);
+        Self::require_not_read_only(&env);
+        let now = env.ledger().timestamp();
+        if not_before >= not_after || not_after <= now {
+            panic!("{}", ContractError::InvalidExecutionWindow as u32);
+        }
+        Self::require_not_blocked(&env, &wasm_hash);
+        if Self::get_upgrade_ticket(env.clone()).is_some_and(|ticket| ticket.not_after > now) {
+            panic!("{}", ContractError::UpgradeAlreadyStaged as u32);
+        }
+        if let Some(delay) = Self::get_staging_delay(env.clone()) {
+            if not_before < now.saturating_add(delay) {
+                panic!("{}", ContractError::StagingRequired as u32);
+            }
+        }
+        let ticket = UpgradeTicket {
+            wasm_hash,
+            not_before,
+            not_after,
+            authorized_by: admin,
+            authorized_at: now,
+        };
+        env.storage().instance().set(&StagingKey::UpgradeTicket, &ticket);
+        Self::emit_ticket_event(&env, symbol_short!("issued"), &ticket);
+    }
+
+    /// Install the ticketed upgrade and consume the ticket. Anyone may call
+    /// it. `wasm_hash` must repeat the ticket's hash (else
+    /// `StagedHashMismatch`) and the ledger time must be inside its window
+    /// (else `OutsideExecutionWindow`). Fails with `NoStagedUpgrade`
+    /// without a ticket. Shares the `upgrade` kill switch.
+    pub fn execute_authorized_upgrade(env: Env, wasm_hash: BytesN<32>) -> UpgradeReceipt {
+        Self::require_function_enabled(&env, "upgrade");
+        let tracked = monitoring::track_start(&env, symbol_short!("upgrade"));
+        Self::require_not_read_only(&env);
+        Self::require_not_paused(&env);
+        let ticket = Self::get_upgrade_ticket(env.clone())
+            .unwrap_or_else(|| panic!("{}", ContractError::NoStagedUpgrade as u32));
+        if ticket.wasm_hash != wasm_hash {
+            panic!("{}", ContractError::StagedHashMismatch as u32);
+        }
+        let now = env.ledger().timestamp();
+        if now < ticket.not_before || now >= ticket.not_after {
+            panic!("{}", ContractError::OutsideExecutionWindow as u32);
+        }
+        env.storage().instance().remove(&StagingKey::UpgradeTicket);
+        Self::emit_ticket_event(&env, symbol_short!("used"), &ticket);
+        Self::install_upgrade(&env, &wasm_hash);
+
+        tracked.finish(&env, ticket.authorized_by, true);
+        Self::upgrade_receipt(&env, wasm_hash, 0)
+    }
+
+    /// Drop the upgrade ticket. Fails with `NoStagedUpgrade` without one.
+    /// Admin only.
+    pub fn cancel_upgrade_authorization(env: Env) {
+        Self::require_function_enabled(&env, "cancel_upgrade_authorization");
+        grainlify_upgradeable::require_governance(&env);
+        let ticket = Self::get_upgrade_ticket(env.clone())
+            .unwrap_or_else(|| panic!("{}", ContractError::NoStagedUpgrade as u32));
+        env.storage().instance().remove(&StagingKey::UpgradeTicket);
+        Self::emit_ticket_event(&env, symbol_short!("cancelled"), &ticket);
+    }
+
+    /// The issued upgrade ticket, including an expired one not yet replaced.
+    pub fn get_upgrade_ticket(env: Env) -> Option<UpgradeTicket> {
+        env.storage().instance().get(&StagingKey::UpgradeTicket)
+    }
+
+    fn emit_ticket_event(env: &Env, step: Symbol, ticket: &UpgradeTicket) {
+        env.events().publish(
+            (symbol_short!("ticket"), step),
+            UpgradeTicketEvent {
+                wasm_hash: ticket.wasm_hash.clone(),
+                not_before: ticket.not_before,
+                not_after: ticket.not_after,
+                timestamp: env.ledger().timestamp(),
+                event_version: EVENT_SCHEMA_VERSION,
+            },
+        );
+    }
+
+    // ========================================================================
+    // Irreversible Actions
+    // ========================================================================
+
+    /// Step one of an irreversible action: record its hash so
+    /// `confirm_irreversible` can perform it between the cool-off and the
+    /// expiry (see `confirmation`). Replaces any pending request.
+    /// `RenounceAdmin` must name the current admin (else `NotAdmin`).
+    /// Governance only.
+    pub fn request_irreversible(env: Env, action: IrreversibleAction) -> PendingConfirmation {
+        Self::require_function_enabled(&env, "request_irreversible");
+        let admin = grainlify_upgradeable::require_governance(&env);
+        Self::require_not_read_only(&env);
+        Self::require_irreversible_target(&admin, &action);
+        let pending = confirmation::request(&env, &action);
+        Self::emit_irreversible_event(&env, symbol_short!("requested"), &pending);
+        pending
+    }
+
+    /// Step two: resubmit the requested action and perform it. `Finalize`
+    /// makes every later upgrade fail with `Finalized`;
+    /// `RenounceAdmin` removes the admin and any upgrade ticket it issued.
+    /// Governance only.
+    pub fn confirm_irreversible(env: Env, action: IrreversibleAction) {
+        Self::require_function_enabled(&env, "confirm_irreversible");
+        let admin = grainlify_upgradeable::require_governance(&env);
+        Self::require_not_read_only(&env);
+        Self::require_irreversible_target(&admin, &action);
+        let pending = confirmation::take(&env, &action).unwrap_or_else(|e| panic!("{}", e as u32));
+        match action {
+            IrreversibleAction::Finalize => confirmation::set_finalized(&env),
+            IrreversibleAction::RenounceAdmin(_) => {
+                env.storage().instance().remove(&DataKey::Admin);
+                Self::revoke_admin_actions(&env);
+            }
+        }
+        Self::emit_irreversible_event(&env, symbol_short!("confirmed"), &pending);
+    }
+
+    /// Drop the pending irreversible action. Governance only.
+    pub fn cancel_irreversible(env: Env) {
+        Self::require_function_enabled(&env, "cancel_irreversible");
+        grainlify_upgradeable::require_governance(&env);
+        let pending = confirmation::cancel(&env).unwrap_or_else(|e| panic!("{}", e as u32));
+        Self::emit_irreversible_event(&env, symbol_short!("cancelled"), &pending);
+    }
+
+    /// The pending irreversible action, including an expired one not yet
+    /// replaced.
+    pub fn get_pending_irreversible(env: Env) -> Option<PendingConfirmation> {
+        confirmation::get_pending(&env)
+    }
+
+    pub fn is_finalized(env: Env) -> bool {
+        confirmation::is_finalized(&env)
+    }
+
+    fn require_irreversible_target(admin: &Address, action: &IrreversibleAction) {
+        if let IrreversibleAction::RenounceAdmin(target) = action {
+            if target != admin {
+                panic!("{}", ContractError::NotAdmin as u32);
+            }
+        }
+    }
+
+    fn emit_irreversible_event(env: &Env, step: Symbol, pending: &PendingConfirmation) {
+        env.events().publish(
+            (symbol_short!("irrev"), step),
+            IrreversibleActionEvent {
+                action_hash: pending.action_hash.clone(),
+                confirm_after: pending.confirm_after,
+                expires_at: pending.expires_at,
+                timestamp: env.ledger().timestamp(),
+                event_version: EVENT_SCHEMA_VERSION,
+            },
+        );
+    }
+
+    // ========================================================================
+    // Timelock Management
+    // ========================================================================
+
+    pub fn get_timelock_delay(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&DataKey::TimelockDelay)
+            .unwrap_or(DEFAULT_TIMELOCK_DELAY)
+    }
+
+    /// [FIX-H02] Now enforces both minimum AND maximum to prevent bricking upgrades
+    pub fn set_timelock_delay(env: Env, delay_seconds: u64) {
+        Self::require_function_enabled(&env, "set_timelock_delay");
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+        Self::require_not_read_only(&env);
+        Self::store_timelock_delay(&env, delay_seconds, None);
+    }
+
+    /// Validates and stores the timelock delay, publishing the change.
+    fn store_timelock_delay(env: &Env, delay_seconds: u64, proposal_id: Option<u64>) {
+        if delay_seconds < MIN_TIMELOCK_DELAY {
+            panic!("Timelock delay must be at least 1 hour (3600 seconds)");
+        }
+
+        // [FIX-H02] Enforce maximum — prevents admin from bricking upgrade execution
+        if delay_seconds > MAX_TIMELOCK_DELAY {
+            panic!("Timelock delay cannot exceed 30 days (2592000 seconds)");
+        }
+
+        let old_delay = Self::get_timelock_delay(env.clone());
+        env.storage().instance().set(&DataKey::TimelockDelay, &delay_seconds);
+        Self::record_config_change(env, "timelock_delay", proposal_id);
+
+        env.events().publish(
+            (symbol_short!("timelock"), symbol_short!("dly_chg")),
+            (old_delay, delay_seconds),
+        );
+    }
+
+    pub fn get_timelock_status(env: Env, proposal_id: u64) -> Option<u64> {
+        Self::ensure_proposal_storage(&env);
+        if let Some(timelock_start) = env
+            .storage()
+            .persistent()
+            .get(&DataKey::UpgradeTimelock(proposal_id))
+        {
+            let timelock_delay = Self::get_timelock_delay(env.clone());
+            let current_time = env.ledger().timestamp();
+            let elapsed = current_time.saturating_sub(timelock_start);
+            if elapsed >= timelock_delay { Some(0) } else { Some(timelock_delay.saturating_sub(elapsed)) }
+        } else {
+            None
+        }
+    }
+
+    /// Seconds until `proposal_id` clears every time gate: the timelock and
+    /// the execution window's `not_before`. A timelock that has not started
+    /// yet counts as the full delay. Zero means the time gates are open;
+    /// approvals and expiry are not considered.
+    pub fn time_until_executable(env: Env, proposal_id: u64) -> Result<u64, CountdownError> {
+        Self::ensure_proposal_storage(&env);
+        Self::open_proposal(&env, proposal_id)?;
+        let timelock = Self::timelock_remaining(&env, proposal_id)
+            .unwrap_or_else(|| Self::get_timelock_delay(env.clone()));
+        let window: Option<ExecutionWindow> =
+            env.storage().persistent().get(&ProposalKey::UpgradeWindow(proposal_id));
+        let not_before = window
+            .and_then(|window| window.not_before)
+            .map_or(0, |start| start.saturating_sub(env.ledger().timestamp()));
+        Ok(timelock.max(not_before))
+    }
+
+    /// Seconds until `proposal_id` expires; zero once it has expired and
+    /// `u64::MAX` for a proposal without an expiry. A window's `not_after`
+    /// is already folded into the expiry.
+    pub fn time_until_expiry(env: Env, proposal_id: u64) -> Result<u64, CountdownError> {
+        Self::ensure_proposal_storage(&env);
+        let proposal = Self::open_proposal(&env, proposal_id)?;
+        if proposal.expiry == 0 {
+            return Ok(u64::MAX);
+        }
+        Ok(proposal.expiry.saturating_sub(env.ledger().timestamp()))
+    }
+
+    /// Returns the config-change timelock delay in seconds.
+    pub fn get_config_change_delay(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&DataKey::ConfigChangeDelay)
+            .unwrap_or(DEFAULT_CONFIG_CHANGE_DELAY)
+    }
+
+    /// Sets the config-change timelock delay.
+    ///
+    /// Delay must remain within the same guardrails as the upgrade timelock.
+    pub fn set_config_change_delay(env: Env, delay_seconds: u64) {
+        Self::require_function_enabled(&env, "set_config_change_delay");
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+        Self::require_not_read_only(&env);
+        Self::store_config_change_delay(&env, delay_seconds, None);
+    }
+
+    /// Validates and stores the config-change delay, publishing the change.
+    fn store_config_change_delay(env: &Env, delay_seconds: u64, proposal_id: Option<u64>) {
+        if delay_seconds < MIN_TIMELOCK_DELAY {
+            panic!("Config change delay must be at least 1 hour (3600 seconds)");
+        }
+        if delay_seconds > MAX_TIMELOCK_DELAY {
+            panic!("Config change delay cannot exceed 30 days (2592000 seconds)");
+        }
+
+        let old_delay = Self::get_config_change_delay(env.clone());
+        env.storage().instance().set(&DataKey::ConfigChangeDelay, &delay_seconds);
+        Self::record_config_change(env, "config_change_delay", proposal_id);
+        env.events().publish(
+            (symbol_short!("cfg_tmlk"), symbol_short!("dly_chg")),
+            (old_delay, delay_seconds),
+        );
+    }
+
+    /// Creates a timelocked proposal to restore a configuration snapshot.
+    pub fn propose_config_snapshot_restore(env: Env, snapshot_id: u64) -> u64 {
+        Self::require_function_enabled(&env, "propose_config_snapshot_restore");
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).expect("Admin not set");
+        admin.require_auth();
+        Self::require_not_read_only(&env);
+
+        if !env.storage().instance().has(&DataKey::ConfigSnapshot(snapshot_id)) {
+            panic!("Snapshot not found or has been pruned");
+        }
+
+        let proposal_id: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey::ConfigChangeCounter)
+            .unwrap_or(0u64)
+            .saturating_add(1);
+        let now = env.ledger().timestamp();
+        let delay = Self::get_config_change_delay(env.clone());
+        let proposal = ConfigChangeProposal {
+            proposal_id,
+            snapshot_id,
+            proposer: admin,
+            created_at: now,
+            execute_after: now.saturating_add(delay),
+            cancelled: false,
+            executed: false,
+        };
+
+        env.storage().instance().set(&DataKey::ConfigChangeProposal(proposal_id), &proposal);
+        env.storage().instance().set(&DataKey::ConfigChangeCounter, &proposal_id);
+        env.events().publish(
+            (symbol_short!("cfg_tmlk"), symbol_short!("propose")),
+            (proposal_id, snapshot_id, proposal.execute_after),
+        );
+        proposal_id
+    }
+
+    /// Returns a config-change proposal by id.
+    pub fn get_config_change_proposal(env: Env, proposal_id: u64) -> Option<ConfigChangeProposal> {
+        env.storage().instance().get(&DataKey::ConfigChangeProposal(proposal_id))
+    }
+
+    /// Returns remaining delay in seconds for a config-change proposal.
+    ///
+    /// `Some(0)` means executable now. `None` means proposal does not exist.
+    pub fn get_config_change_status(env: Env, proposal_id: u64) -> Option<u64> {
+        let proposal: ConfigChangeProposal = env
+            .storage()
+            .instance()
+            .get(&DataKey::ConfigChangeProposal(proposal_id))?;
+
+        if proposal.cancelled || proposal.executed {
+            return Some(0);
+        }
+
+        let now = env.ledger().timestamp();
+        if now >= proposal.execute_after {
+            Some(0)
+        } else {
+            Some(proposal.execute_after.saturating_sub(now))
+        }
+    }
+
+    /// Cancels a pending config-change proposal.
+    pub fn cancel_config_change(env: Env, proposal_id: u64) {
+        Self::require_function_enabled(&env, "cancel_config_change");
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).expect("Admin not set");
+        admin.require_auth();
+        Self::require_not_read_only(&env);
+
+        let mut proposal: ConfigChangeProposal = env
+            .storage()
+            .instance()
+            .get(&DataKey::ConfigChangeProposal(proposal_id))
+            .unwrap_or_else(|| panic!("Config change proposal not found"));
+
+        if proposal.executed {
+            panic!("Config change proposal already executed");
+        }
+        if proposal.cancelled {
+            panic!("Config change proposal already cancelled");
+        }
+
+        proposal.cancelled = true;
+        env.storage().instance().set(&DataKey::ConfigChangeProposal(proposal_id), &proposal);
+        env.events().publish(
+            (symbol_short!("cfg_tmlk"), symbol_short!("cancel")),
+            proposal_id,
+        );
+    }
+
+    /// Executes a timelocked config-change proposal after delay expiry.
+    pub fn execute_config_snapshot_restore(env: Env, proposal_id: u64) {
+        Self::require_function_enabled(&env, "execute_config_snapshot_restore");
+        Self::require_not_read_only(&env);
+
+        let mut proposal: ConfigChangeProposal = env
+            .storage()
+            .instance()
+            .get(&DataKey::ConfigChangeProposal(proposal_id))
+            .unwrap_or_else(|| panic!("Config change proposal not found"));
+
+        if proposal.cancelled {
+            panic!("Config change proposal has been cancelled");
+        }
+        if proposal.executed {
+            panic!("Config change proposal already executed");
+        }
+        let now = env.ledger().timestamp();
+        if now < proposal.execute_after {
+            panic!(
+                "Config change timelock not met: {} seconds remaining",
+                proposal.execute_after.saturating_sub(now)
+            );
+        }
+
+        Self::restore_snapshot_with_checks(&env, proposal.snapshot_id);
+        proposal.executed = true;
+        env.storage().instance().set(&DataKey::ConfigChangeProposal(proposal_id), &proposal);
+        env.events().publish(
+            (symbol_short!("cfg_tmlk"), symbol_short!("exec")),
+            (proposal_id, proposal.snapshot_id, now),
+        );
+    }
+
+    // ========================================================================
+    // Version Management
+    // ========================================================================
+
+    pub fn get_version(env: Env) -> u32 {
+        grainlify_upgradeable::get_version(&env)
+    }
+
+    pub fn get_admin(env: Env) -> Option<Address> {
+        grainlify_upgradeable::get_admin(&env)
+    }
+
+    /// Whether the contract is initialized and how it is governed, in one
+    /// read-only call. Contracts initialized before the mode was recorded
+    /// report admin mode when an admin is set and multisig mode otherwise.
+    pub fn get_init_state(env: Env) -> InitState {
+        if !env.storage().instance().has(&DataKey::Version) {
+            return InitState::Uninitialized;
+        }
+        let admin = grainlify_upgradeable::get_admin(&env);
+        let mode = env
+            .storage()
+            .instance()
+            .get(&DataKey::InitMode)
+            .unwrap_or(if admin.is_some() { InitMode::Admin } else { InitMode::Multisig });
+        match (mode, admin) {
+            (InitMode::Admin, Some(admin)) => InitState::AdminMode(admin),
+            _ => match MultiSig::get_config_opt(&env) {
+                Some(config) => InitState::MultisigMode(config.signers.len(), config.threshold),
+                None => InitState::Uninitialized,
+            },
+        }
+    }
+
+    /// Returns whether the admin is an account or a contract, or `None`
+    /// when no admin is set (e.g. multisig-only initialization).
+    pub fn get_admin_type(env: Env) -> Option<AdminType> {
+        let admin = grainlify_upgradeable::get_admin(&env)?;
+        Some(if monitoring::is_contract(&admin) {
+            AdminType::Contract
+        } else {
+            AdminType::Account
+        })
+    }
+
+    pub fn is_strict_mode(_env: Env) -> bool {
+        strict_mode::is_enabled()
+    }
+
+    pub fn get_version_semver_string(env: Env) -> String {
+        let encoded = Self::get_version_numeric_encoded(env.clone());
+        let major = encoded / 10_000;
+        let minor = (encoded % 10_000) / 100;
+        let patch = encoded % 100;
+        let mut buf = [0u8; 12];
+        let mut pos = 0usize;
+
+        macro_rules! write_u32 {
+            ($n:expr) => {
+                let n: u32 = $n;
+                if n >= 100 { buf[pos] = b'0' + (n / 100) as u8; pos += 1; }
+                if n >= 10 { buf[pos] = b'0' + ((n % 100) / 10) as u8; pos += 1; }
+                buf[pos] = b'0' + (n % 10) as u8; pos += 1;
+            };
+        }
+        write_u32!(major); buf[pos] = b'.'; pos += 1;
+        write_u32!(minor); buf[pos] = b'.'; pos += 1;
+        write_u32!(patch);
+
+        let s = core::str::from_utf8(&buf[..pos]).unwrap_or("0.0.0");
+        String::from_str(&env, s)
+    }
+
+    pub fn get_version_numeric_encoded(env: Env) -> u32 {
+        let raw: u32 = env.storage().instance().get(&DataKey::Version).unwrap_or(0);
+        if raw >= 10_000 { raw } else { raw.saturating_mul(10_000) }
+    }
+
+    /// Label set with `set_version_label`, e.g. `2.1.0`.
+    pub fn get_version_label(env: Env) -> Option<String> {
+        env.storage().instance().get(&VersionKey::Label)
+    }
+
+    /// Attach a human-readable label to the stored version. `label` is one
+    /// to three dot-separated decimal numbers (`major[.minor[.patch]]`, the
+    /// missing parts being 0) of at most `MAX_VERSION_LABEL_LEN` bytes, and
+    /// must name the version `get_version_semver_string` reports unless
+    /// `force` is set. Later version changes leave the label as it is.
+    /// Gated like `set_version`. Publishes `("version", "label")`.
+    pub fn set_version_label(env: Env, label: String, force: bool) {
+        Self::require_function_enabled(&env, "set_version_label");
+        Self::require_governance_mode(&env, InitMode::Admin);
+        grainlify_upgradeable::require_governance(&env);
+        Self::require_not_read_only(&env);
+        Self::require_not_paused(&env);
+        let [major, minor, patch] =
+            Self::parse_version_label(&label).unwrap_or_else(|| panic!("Malformed version label"));
+        let encoded = major.checked_mul(10_000).and_then(|major| major.checked_add(minor * 100 + patch));
+        let consistent = minor < 100 && patch < 100 && encoded == Some(Self::get_version_numeric_encoded(env.clone()));
+        if !consistent && !force {
+            panic!("Version label does not match version");
+        }
+        env.storage().instance().set(&VersionKey::Label, &label);
+        env.events().publish((symbol_short!("version"), symbol_short!("label")), (label, force));
+    }
+
+    /// `[major, minor, patch]` of a well-formed version label.
+    fn parse_version_label(label: &String) -> Option<[u32; 3]> {
+        let len = label.len() as usize;
+        if len == 0 || len > MAX_VERSION_LABEL_LEN as usize {
+            return None;
+        }
+        let mut buf = [0u8; MAX_VERSION_LABEL_LEN as usize];
+        label.copy_into_slice(&mut buf[..len]);
+        let mut parts = [0u32; 3];
+        for (i, part) in buf[..len].split(|&b| b == b'.').enumerate() {
+            if i >= parts.len() || part.is_empty() || !part.iter().all(u8::is_ascii_digit) {
+                return None;
+            }
+            parts[i] = core::str::from_utf8(part).ok()?.parse().ok()?;
+        }
+        Some(parts)
+    }
+
+    pub fn require_min_version(env: Env, min_numeric: u32) {
+        let cur = Self::get_version_numeric_encoded(env.clone());
+        if cur == 0 { panic!("{}", ContractError::NotInitialized as u32); }
+        if cur < min_numeric { panic!("version_too_low"); }
+    }
+
+    /// Set the stored version. Admin governance only; fails with
+    /// `WrongGovernanceMode` on a multisig-only contract.
+    ///
+    /// Publishes `("grainlify", "version")` with a `VersionChangedEvent` and
+    /// the monitoring `("metric", "version")` with the same transition. A
+    /// rejected call, unauthorized included, reverts along with its
+    /// metrics, so only successes reach the `set_ver` counters.
+    pub fn set_version(env: Env, new_version: u32) {
+        Self::store_version(env, new_version, None);
+    }
+
+    /// `set_version` bound to `nonce`; see `upgrade_with_nonce`. Shares the
+    /// `set_version` kill switch.
+    pub fn set_version_with_nonce(env: Env, new_version: u32, nonce: u64) {
+        Self::store_version(env, new_version, Some(nonce));
+    }
+
+    fn store_version(env: Env, new_version: u32, nonce: Option<u64>) {
+        Self::require_function_enabled(&env, "set_version");
+        Self::require_governance_mode(&env, InitMode::Admin);
+        let tracked = monitoring::track_start(&env, symbol_short!("set_ver"));
+        let admin = grainlify_upgradeable::require_governance(&env);
+        Self::consume_admin_nonce(&env, nonce);
+        Self::require_not_read_only(&env);
+        Self::require_not_paused(&env);
+        let old_version = grainlify_upgradeable::set_version(&env, new_version);
+        Self::record_version(&env);
+        env.events().publish(
+            (symbol_short!("grainlify"), symbol_short!("version")),
+            VersionChangedEvent {
+                old_version,
+                new_version,
+                caller: admin.clone(),
+                timestamp: env.ledger().timestamp(),
+                event_version: EVENT_SCHEMA_VERSION,
+            },
+        );
+        monitoring::emit_version(&env, old_version, new_version, admin.clone());
+        tracked.finish(&env, admin, true);
+    }
+
+    // ========================================================================
+    // Admin Nonces
+    // ========================================================================
+
+    /// Nonce the next `_with_nonce` admin call must supply; starts at 0.
+    pub fn get_admin_nonce(env: Env) -> u64 {
+        env.storage().instance().get(&DataKey::AdminNonce).unwrap_or(0)
+    }
+
+    pub fn get_require_nonce(env: Env) -> bool {
+        env.storage().instance().get(&DataKey::RequireNonce).unwrap_or(false)
+    }
+
+    /// When `required`, `upgrade`, `upgrade_v2`, and `set_version` fail with
+    /// `NonceRequired` and only their `_with_nonce` variants work. Governance
+    /// only; publishes `("nonce", "required")`.
+    pub fn set_require_nonce(env: Env, required: bool) {
+        Self::require_function_enabled(&env, "set_require_nonce");
+        grainlify_upgradeable::require_governance(&env);
+        env.storage().instance().set(&DataKey::RequireNonce, &required);
+        Self::record_config_change(&env, "require_nonce", None);
+        env.events().publish((symbol_short!("nonce"), symbol_short!("required")), required);
+    }
+
+    /// Checks and consumes the admin nonce supplied to a `_with_nonce`
+    /// variant, or, for the nonce-less entrypoint (`None`), that nonces are
+    /// not required.
+    fn consume_admin_nonce(env: &Env, nonce: Option<u64>) {
+        let Some(nonce) = nonce else {
+            if Self::get_require_nonce(env.clone()) {
+                panic!("{}", ContractError::NonceRequired as u32);
+            }
+            return;
+        };
+        let expected = Self::get_admin_nonce(env.clone());
+        if nonce != expected {
+            panic!("{}", ContractError::InvalidAdminNonce as u32);
+        }
+        let next = expected
+            .checked_add(1)
+            .unwrap_or_else(|| panic!("{}", ContractError::InvalidAdminNonce as u32));
+        env.storage().instance().set(&DataKey::AdminNonce, &next);
+    }
+
+    // ========================================================================
+    // Maintenance Mode
+    // ========================================================================
+
+    /// Announce maintenance until `until`, ahead of an upgrade. While active,
+    /// non-governance mutating calls (`fund_rewards`, `bump_metrics_ttl`,
+    /// `record_snapshot`, `prune_upgrade_proposal`, `sweep_proposals`) fail with `MaintenanceMode`; reads, governance,
+    /// attestations, and upgrade execution keep working. Ends on its own at
+    /// `until`. Governance only; multisig deployments use a
+    /// `ProposalAction::EnterMaintenance` bundle, whose deadline must still
+    /// be ahead when it executes.
+    pub fn enter_maintenance(env: Env, until: u64) {
+        Self::require_function_enabled(&env, "enter_maintenance");
+        grainlify_upgradeable::require_governance(&env);
+        Self::require_not_read_only(&env);
+        Self::start_maintenance(&env, until);
+    }
+
+    fn start_maintenance(env: &Env, until: u64) {
+        if until <= env.ledger().timestamp() {
+            panic!("{}", ContractError::InvalidMaintenanceDeadline as u32);
+        }
+        env.storage().instance().set(&DataKey::MaintenanceUntil, &until);
+        env.events().publish((symbol_short!("maint"), symbol_short!("enter")), until);
+    }
+
+    /// End maintenance before its deadline. Governance only; multisig
+    /// deployments use a `ProposalAction::ExitMaintenance` bundle.
+    pub fn exit_maintenance(env: Env) {
+        Self::require_function_enabled(&env, "exit_maintenance");
+        grainlify_upgradeable::require_governance(&env);
+        Self::end_maintenance(&env);
+    }
+
+    fn end_maintenance(env: &Env) {
+        env.storage().instance().remove(&DataKey::MaintenanceUntil);
+        env.events().publish((symbol_short!("maint"), symbol_short!("exit")), ());
+    }
+
+    /// Staged upgrade, upgrade ticket, and running maintenance window that
+    /// an admin handover would revoke: `confirm_admin_restore`,
+    /// `migrate_to_multisig`, or a renounced admin. Lets the incoming admin
+    /// see what it will not inherit.
+    pub fn get_pending_admin_actions(env: Env) -> PendingAdminActions {
+        let until: u64 = env.storage().instance().get(&DataKey::MaintenanceUntil).unwrap_or(0);
+        let mut staged_upgrade = Vec::new(&env);
+        if let Some(staged) = env.storage().instance().get(&StagingKey::StagedUpgrade) {
+            staged_upgrade.push_back(staged);
+        }
+        let mut upgrade_ticket = Vec::new(&env);
+        if let Some(ticket) = Self::get_upgrade_ticket(env.clone()) {
+            upgrade_ticket.push_back(ticket);
+        }
+        PendingAdminActions {
+            staged_upgrade,
+            upgrade_ticket,
+            maintenance_until: (env.ledger().timestamp() < until).then_some(until),
+        }
+    }
+
+    /// Removes the `get_pending_admin_actions` of an outgoing admin,
+    /// publishing `("upgrade", "revoked")`, `("ticket", "revoked")`, and
+    /// `("maint", "revoked")` with the deadline for each item removed.
+    fn revoke_admin_actions(env: &Env) {
+        let pending = Self::get_pending_admin_actions(env.clone());
+        for staged in pending.staged_upgrade.iter() {
+            env.storage().instance().remove(&StagingKey::StagedUpgrade);
+            Self::emit_staging_event(env, symbol_short!("revoked"), &staged);
+        }
+        for ticket in pending.upgrade_ticket.iter() {
+            env.storage().instance().remove(&StagingKey::UpgradeTicket);
+            Self::emit_ticket_event(env, symbol_short!("revoked"), &ticket);
+        }
+        env.storage().instance().remove(&DataKey::MaintenanceUntil);
+        if let Some(until) = pending.maintenance_until {
+            env.events().publish((symbol_short!("maint"), symbol_short!("revoked")), until);
+        }
+    }
+
+    pub fn maintenance_status(env: Env) -> MaintenanceStatus {
+        let until: u64 = env.storage().instance().get(&DataKey::MaintenanceUntil).unwrap_or(0);
+        MaintenanceStatus {
+            active: env.ledger().timestamp() < until,
+            until,
+        }
+    }
+
+    /// Creation, threshold, and execution times of multisig proposal
+    /// `proposal_id`; `None` for proposals created before timings were
+    /// recorded or pruned since.
+    pub fn get_proposal_timings(env: Env, proposal_id: u64) -> Option<ProposalTimings> {
+        Self::load_timings(&env, proposal_id)
+    }
+
+    /// Aggregate figures over multisig proposals with recorded timings.
+    /// Averages are 0 until a proposal reaches the respective step; an
+    /// expired proposal is counted once it is pruned or swept.
+    pub fn get_governance_stats(env: Env) -> GovernanceStats {
+        let totals = Self::governance_totals(&env);
+        GovernanceStats {
+            created: totals.created,
+            reached_threshold: totals.reached_threshold,
+            executed: totals.executed,
+            expired: totals.expired,
+            cancelled: totals.cancelled,
+            avg_time_to_threshold: totals.threshold_secs.checked_div(totals.reached_threshold).unwrap_or(0),
+            avg_time_to_execution: totals.execution_secs.checked_div(totals.executed).unwrap_or(0),
+        }
+    }
+
+    fn require_not_in_maintenance(env: &Env) {
+        if Self::maintenance_status(env.clone()).active {
+            panic!("{}", ContractError::MaintenanceMode as u32);
+        }
+    }
+
+    // ========================================================================
+    // Read-Only Mode
+    // ========================================================================
+
+    pub fn is_read_only(env: Env) -> bool {
+        env.storage().instance().get(&DataKey::ReadOnlyMode).unwrap_or(false)
+    }
+
+    pub fn set_read_only_mode(env: Env, enabled: bool) {
+        Self::require_function_enabled(&env, "set_read_only_mode");
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+        env.storage().instance().set(&DataKey::ReadOnlyMode, &enabled);
+        env.events().publish(
+            (symbol_short!("ROModeChg"),),
+            ReadOnlyModeEvent { enabled, admin, timestamp: env.ledger().timestamp(), event_version: EVENT_SCHEMA_VERSION },
+        );
+    }
+
+    fn require_not_read_only(env: &Env) {
+        if Self::is_read_only(env.clone()) { panic!("Read-only mode"); }
+    }
+
+    /// Panics with `FunctionDisabled` when `name` has been switched off,
+    /// and with `TransitionError::InProgress` during a multi-step operation.
+    fn require_function_enabled(env: &Env, name: &str) {
+        if env.storage().instance().has(&TransitionKey::InProgress) {
+            panic!("{}", TransitionError::InProgress as u32);
+        }
+        let disabled: Vec<Symbol> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::DisabledFunctions)
+            .unwrap_or(Vec::new(env));
+        if !disabled.is_empty() && disabled.contains(Symbol::new(env, name)) {
+            panic!("{}", ContractError::FunctionDisabled as u32);
+        }
+        Self::warn_if_ttl_low(env);
+    }
+
+    /// Publishes `("ttl", "low")` when the instance TTL recorded by the last
+    /// `extend_instance_ttl` is below the warning threshold. Never fails;
+    /// contracts that were never extended through it are not checked.
+    fn warn_if_ttl_low(env: &Env) {
+        let Some(extended_to) = env.storage().instance().get::<_, u32>(&TtlKey::InstanceExtendedTo) else {
+            return;
+        };
+        let threshold = Self::get_ttl_warning_threshold(env.clone());
+        let ledgers_remaining = extended_to.saturating_sub(env.ledger().sequence());
+        if ledgers_remaining < threshold {
+            env.events().publish(
+                (symbol_short!("ttl"), symbol_short!("low")),
+                TtlLowEvent {
+                    ledgers_remaining,
+                    extended_to,
+                    threshold,
+                    event_version: EVENT_SCHEMA_VERSION,
+                },
+            );
+        }
+    }
+
+    /// Runs `f` with `TransitionKey::InProgress` set to `operation`, so
+    /// contracts it calls out to cannot re-enter governance entrypoints.
+    /// The flag is cleared on every return path, including `f` returning
+    /// an error; a panic reverts it along with everything else. Nested
+    /// calls leave it to the outermost one.
+    fn in_transition<T>(env: &Env, operation: Symbol, f: impl FnOnce() -> T) -> T {
+        let outermost = !env.storage().instance().has(&TransitionKey::InProgress);
+        if outermost {
+            env.storage().instance().set(&TransitionKey::InProgress, &operation);
+        }
+        let result = f();
+        if outermost {
+            env.storage().instance().remove(&TransitionKey::InProgress);
+        }
+        result
+    }
+
+    fn require_not_paused(env: &Env) {
+        if MultiSig::is_contract_paused(env) {
+            panic!("{}", ContractError::Paused as u32);
+        }
+    }
+
+    /// Checks attestations, swaps in `wasm_hash`, and publishes the upgrade
+    /// events shared by every upgrade path.
+    fn install_upgrade(env: &Env, wasm_hash: &BytesN<32>) {
+        Self::in_transition(env, symbol_short!("upgrade"), || {
+            if confirmation::is_finalized(env) {
+                panic!("{}", ConfirmationError::Finalized as u32);
+            }
+            Self::require_no_emergency_pause(env);
+            Self::require_not_blocked(env, wasm_hash);
+            Self::require_attested(env, wasm_hash);
+            let current_version = grainlify_upgradeable::perform_upgrade(env, wasm_hash);
+            let upgrade_count = Self::get_upgrade_count(env.clone()).saturating_add(1);
+            env.storage().persistent().set(&ArchiveKey::UpgradeCount, &upgrade_count);
+            Self::emit_upgraded_event(env, wasm_hash);
+            Self::notify_upgrade_subscribers(env);
+
+            // [FIX-L02] Same event shape on every upgrade path
+            env.events().publish(
+                (symbol_short!("upgrade"), symbol_short!("wasm")),
+                UpgradeEvent {
+                    new_wasm_hash: wasm_hash.clone(),
+                    previous_version: current_version,
+                    timestamp: env.ledger().timestamp(),
+                    event_version: EVENT_SCHEMA_VERSION,
+                },
+            );
+        })
+    }
+
+    /// Panics with `OutOfOrderUpgrade` when `newer_upgrade` finds one,
+    /// unless `allow_out_of_order`, which publishes `("upgrade",
+    /// "out_order")` instead. Applies to multisig and token proposals alike.
+    fn require_in_order(env: &Env, proposal_id: u64, allow_out_of_order: bool) {
+        if let Some(newer_id) = Self::newer_upgrade(env, proposal_id) {
+            if !allow_out_of_order {
+                panic!("{}", ContractError::OutOfOrderUpgrade as u32);
+            }
+            env.events().publish(
+                (symbol_short!("upgrade"), symbol_short!("out_order")),
+                (proposal_id, newer_id),
+            );
+        }
+    }
+
+    fn record_highest_executed(env: &Env, proposal_id: u64) {
+        let highest: u64 = env.storage().instance().get(&ArchiveKey::HighestExecutedUpgrade).unwrap_or(0);
+        env.storage().instance().set(&ArchiveKey::HighestExecutedUpgrade, &highest.max(proposal_id));
+    }
+
+    /// An upgrade proposal with an ID above `proposal_id` that executed or
+    /// is open with its threshold met: the lowest such open one, else the
+    /// highest executed one. Reads `HighestExecutedUpgrade` and the pending
+    /// set, so the cost is bounded by `MAX_PENDING_UPGRADES`.
+    fn newer_upgrade(env: &Env, proposal_id: u64) -> Option<u64> {
+        let approved = monitoring::pending_upgrades(env).iter().filter(|&id| {
+            id > proposal_id && monitoring::is_pending(env, id) && MultiSig::approvals_needed(env, id) == Some(0)
+        });
+        let executed = env
+            .storage()
+            .instance()
+            .get::<_, u64>(&ArchiveKey::HighestExecutedUpgrade)
+            .filter(|&id| id > proposal_id);
+        approved.min().or(executed)
+    }
+
+    fn archive_executed_upgrade(
+        env: &Env,
+        proposal_id: u64,
+        wasm_hash: &BytesN<32>,
+        executor: Option<Address>,
+        relayed: bool,
+        expedited: bool,
+    ) {
+        let approvals = MultiSig::get_execution(env, proposal_id)
+            .map(|record| record.approvals)
+            .unwrap_or(Vec::new(env));
+        // Binary insertion: each comparison is a host call.
+        let mut sorted: Vec<Address> = Vec::new(env);
+        for approver in approvals.iter() {
+            let (mut low, mut high) = (0, sorted.len());
+            while low < high {
+                let mid = (low + high) / 2;
+                if sorted.get_unchecked(mid) > approver {
+                    high = mid;
+                } else {
+                    low = mid + 1;
+                }
+            }
+            sorted.insert(low, approver);
+        }
+        let mut data = Bytes::new(env);
+        for approver in sorted.iter() {
+            data.append(&approver.to_xdr(env));
+        }
+
+        let index = Self::executed_upgrade_count(env.clone());
+        let entry = ArchivedUpgrade {
+            proposal_id,
+            wasm_hash: wasm_hash.clone(),
+            version: grainlify_upgradeable::get_version(env),
+            executed_at: env.ledger().timestamp(),
+            approver_count: sorted.len(),
+            approvers_digest: env.crypto().sha256(&data).into(),
+            executor,
+            ledger_sequence: env.ledger().sequence(),
+            relayed,
+            expedited,
+        };
+        env.storage().persistent().set(&ArchiveKey::UpgradeArchive(index), &entry);
+        env.storage().persistent().set(&ArchiveKey::ArchiveIndex(proposal_id), &index);
+        env.storage().instance().set(&ArchiveKey::UpgradeArchiveCount, &(index + 1));
+        Self::prune_history(env, HistoryKind::Upgrades, index + 1);
+    }
+
+    fn upgrade_receipt(env: &Env, wasm_hash: BytesN<32>, proposal_id: u64) -> UpgradeReceipt {
+        UpgradeReceipt {
+            wasm_hash,
+            new_version: grainlify_upgradeable::get_version(env),
+            proposal_id,
+            executed_at: env.ledger().timestamp(),
+        }
+    }
+
+    /// Panics with `FeatureDisabled` in builds without the `monitoring`
+    /// feature, where no metrics are recorded.
+    fn require_monitoring() {
+        if !monitoring::ENABLED {
+            panic!("{}", ContractError::FeatureDisabled as u32);
+        }
+    }
+
+    /// Panics with `SchemaOutdated` unless storage is at schema `min_version`
+    /// or later. Use it in functions that would misread an unmigrated store.
+    fn require_schema(env: &Env, min_version: u32) {
+        if Self::get_schema_version(env.clone()) < min_version {
+            panic!("{}", ContractError::SchemaOutdated as u32);
+        }
+    }
+
+    /// Panics with `AttestationMissing` when attestations are enforced and
+    /// fewer than `min_attestations` configured auditors attested `wasm_hash`.
+    fn require_attested(env: &Env, wasm_hash: &BytesN<32>) {
+        if !Self::is_attested(env, wasm_hash) {
+            panic!("{}", ContractError::AttestationMissing as u32);
+        }
+    }
+
+    fn is_attested(env: &Env, wasm_hash: &BytesN<32>) -> bool {
+        let config: AttestationConfig = match env.storage().instance().get(&DataKey::AttestationConfig) {
+            Some(config) => config,
+            None => return true,
+        };
+        if !config.require_attestation {
+            return true;
+        }
+        let mut valid = 0u32;
+        for attestation in Self::get_attestations(env.clone(), wasm_hash.clone()).iter() {
+            if config.auditors.contains(&attestation.auditor) {
+                valid += 1;
+            }
+        }
+        valid >= config.min_attestations
+    }
+
+    // ========================================================================
+    // WASM Blocklist
+    // ========================================================================
+
+    /// Block `wasm_hash` for good: every install path refuses it, including
+    /// proposals opened before the block, and no new proposal, staged
+    /// upgrade or ticket may name it. `reason` must fit the `reason` string
+    /// limit. Publishes `("wasm", "blocked")`. Governance only; multisig
+    /// deployments use a `ProposalAction::BlockWasm` bundle.
+    pub fn block_wasm_hash(env: Env, wasm_hash: BytesN<32>, reason: String) {
+        Self::require_function_enabled(&env, "block_wasm_hash");
+        let admin = grainlify_upgradeable::require_governance(&env);
+        Self::require_not_read_only(&env);
+        Self::store_wasm_block(&env, wasm_hash, reason, Some(admin), None);
+    }
+
+    /// Lift the block on `wasm_hash`. Panics unless it is blocked.
+    /// Publishes `("wasm", "unblocked")` with the original reason, so
+    /// monitors can flag a known-bad build becoming installable again.
+    /// Governance only; multisig deployments use a
+    /// `ProposalAction::UnblockWasm` bundle.
+    pub fn unblock_wasm_hash(env: Env, wasm_hash: BytesN<32>) {
+        Self::require_function_enabled(&env, "unblock_wasm_hash");
+        let admin = grainlify_upgradeable::require_governance(&env);
+        Self::require_not_read_only(&env);
+        Self::remove_wasm_block(&env, wasm_hash, Some(admin), None);
+    }
+
+    fn store_wasm_block(
+        env: &Env,
+        wasm_hash: BytesN<32>,
+        reason: String,
+        caller: Option<Address>,
+        proposal_id: Option<u64>,
+    ) {
+        limits::require_len(env, limits::REASON, &reason);
+        let now = env.ledger().timestamp();
+        env.storage().persistent().set(
+            &BlocklistKey::Blocked(wasm_hash.clone()),
+            &BlockedWasm { reason: reason.clone(), blocked_at: now },
+        );
+        env.events().publish(
+            (symbol_short!("wasm"), symbol_short!("blocked")),
+            WasmBlockEvent {
+                wasm_hash,
+                reason,
+                caller,
+                proposal_id,
+                timestamp: now,
+                event_version: EVENT_SCHEMA_VERSION,
+            },
+        );
+    }
+
+    fn remove_wasm_block(env: &Env, wasm_hash: BytesN<32>, caller: Option<Address>, proposal_id: Option<u64>) {
+        let key = BlocklistKey::Blocked(wasm_hash.clone());
+        let blocked: BlockedWasm =
+            env.storage().persistent().get(&key).unwrap_or_else(|| panic!("Wasm hash is not blocked"));
+        env.storage().persistent().remove(&key);
+        env.events().publish(
+            (symbol_short!("wasm"), symbol_short!("unblocked")),
+            WasmBlockEvent {
+                wasm_hash,
+                reason: blocked.reason,
+                caller,
+                proposal_id,
+                timestamp: env.ledger().timestamp(),
+                event_version: EVENT_SCHEMA_VERSION,
+            },
+        );
+    }
+
+    pub fn is_wasm_blocked(env: Env, wasm_hash: BytesN<32>) -> bool {
+        env.storage().persistent().has(&BlocklistKey::Blocked(wasm_hash))
+    }
+
+    pub fn get_wasm_block(env: Env, wasm_hash: BytesN<32>) -> Option<BlockedWasm> {
+        env.storage().persistent().get(&BlocklistKey::Blocked(wasm_hash))
+    }
+
+    fn require_not_blocked(env: &Env, wasm_hash: &BytesN<32>) {
+        if Self::is_wasm_blocked(env.clone(), wasm_hash.clone()) {
+            panic!("Wasm hash is blocked");
+        }
+    }
+
+    /// Requires `caller` auth and that `caller` is the admin or a signer.
+    fn require_admin_or_signer(env: &Env, caller: &Address) {
+        caller.require_auth();
+        if !Self::is_admin_or_signer(env, caller) {
+            panic!("{}", ContractError::NotAdmin as u32);
+        }
+    }
+
+    fn is_admin_or_signer(env: &Env, caller: &Address) -> bool {
+        let admin: Option<Address> = env.storage().instance().get(&DataKey::Admin);
+        admin.as_ref() == Some(caller) || MultiSig::is_signer(env, caller)
+    }
+
+    /// Whether an `attempt_*` failure by `caller` may count toward the
+    /// circuit breaker: only when `caller` could `pause` the contract anyway.
+    /// A failure any other address provokes is still recorded, but cannot
+    /// trip the pause.
+    fn trips_breaker(env: &Env, caller: &Address) -> bool {
+        Self::is_admin_or_signer(env, caller)
+    }
+
+    // ========================================================================
+    // Upgrade Subscribers
+    // ========================================================================
+
+    /// Register a contract to receive `on_dependency_upgraded` after each
+    /// upgrade. Admin only; at most `MAX_UPGRADE_SUBSCRIBERS` entries.
+    pub fn register_subscriber(env: Env, contract: Address) {
+        Self::require_function_enabled(&env, "register_subscriber");
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic!("{}", ContractError::NotInitialized as u32));
+        admin.require_auth();
+        Self::require_not_read_only(&env);
+
+        let mut subscribers = Self::get_subscribers(env.clone());
+        if subscribers.contains(&contract) {
+            return;
+        }
+        if subscribers.len() >= MAX_UPGRADE_SUBSCRIBERS {
+            panic!("Subscriber list full");
+        }
+        subscribers.push_back(contract.clone());
+        env.storage().instance().set(&DataKey::UpgradeSubscribers, &subscribers);
+        env.events().publish((symbol_short!("subscr"), symbol_short!("add")), contract);
+    }
+
+    /// Remove a contract from the upgrade subscriber list. Admin only.
+    pub fn unregister_subscriber(env: Env, contract: Address) {
+        Self::require_function_enabled(&env, "unregister_subscriber");
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic!("{}", ContractError::NotInitialized as u32));
+        admin.require_auth();
+        Self::require_not_read_only(&env);
+
+        let subscribers = Self::get_subscribers(env.clone());
+        let mut remaining: Vec<Address> = Vec::new(&env);
+        for entry in subscribers.iter() {
+            if entry != contract {
+                remaining.push_back(entry);
+            }
+        }
+        if remaining.len() == subscribers.len() {
+            return;
+        }
+        env.storage().instance().set(&DataKey::UpgradeSubscribers, &remaining);
+        env.events().publish((symbol_short!("subscr"), symbol_short!("remove")), contract);
+    }
+
+    /// Returns the contracts notified after each upgrade.
+    pub fn get_subscribers(env: Env) -> Vec<Address> {
+        env.storage()
+            .instance()
+            .get(&DataKey::UpgradeSubscribers)
+            .unwrap_or(Vec::new(&env))
+    }
+
+    // ========================================================================
+    // Fleet Upgrades
+    // ========================================================================
+
+    /// Register a child contract whose admin is this contract, making it
+    /// eligible for fleet upgrades. Governance only; multisig deployments
+    /// use a `ProposalAction::RegisterChild` bundle. The upgrade itself
+    /// still needs a threshold-approved proposal.
+    pub fn register_child(env: Env, child: Address) {
+        Self::require_function_enabled(&env, "register_child");
+        grainlify_upgradeable::require_governance(&env);
+        Self::require_not_read_only(&env);
+        Self::store_child(&env, child);
+    }
+
+    /// Remove a child contract from the fleet registry. Governance only;
+    /// multisig deployments use a `ProposalAction::UnregisterChild` bundle.
+    pub fn unregister_child(env: Env, child: Address) {
+        Self::require_function_enabled(&env, "unregister_child");
+        grainlify_upgradeable::require_governance(&env);
+        Self::require_not_read_only(&env);
+        Self::remove_child(&env, child);
+    }
+
+    fn store_child(env: &Env, child: Address) {
+        let mut children = Self::get_children(env.clone());
+        if children.contains(&child) {
+            return;
+        }
+        if children.len() >= MAX_CHILD_CONTRACTS {
+            panic!("Child registry full");
+        }
+        children.push_back(child.clone());
+        env.storage().instance().set(&DataKey::ChildContracts, &children);
+        env.events().publish((symbol_short!("fleet"), symbol_short!("add")), child);
+    }
+
+    fn remove_child(env: &Env, child: Address) {
+        let children = Self::get_children(env.clone());
+        let mut remaining: Vec<Address> = Vec::new(env);
+        for entry in children.iter() {
+            if entry != child {
+                remaining.push_back(entry);
+            }
+        }
+        if remaining.len() == children.len() {
+            return;
+        }
+        env.storage().instance().set(&DataKey::ChildContracts, &remaining);
+        env.events().publish((symbol_short!("fleet"), symbol_short!("remove")), child);
+    }
+
+    /// Returns the registered child contracts.
+    pub fn get_children(env: Env) -> Vec<Address> {
+        env.storage()
+            .instance()
+            .get(&DataKey::ChildContracts)
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Propose upgrading `children` to `wasm_hash` in one multisig proposal.
+    /// Every child must be registered and listed at most once.
+    pub fn propose_fleet_upgrade(
+        env: Env,
+        proposer: Address,
+        wasm_hash: BytesN<32>,
+        children: Vec<Address>,
+        expiry: u64,
+    ) -> u64 {
+        Self::require_function_enabled(&env, "propose_fleet_upgrade");
+        Self::ensure_proposal_storage(&env);
+        Self::require_not_read_only(&env);
+        Self::require_not_paused(&env);
+        if children.is_empty() {
+            panic!("Fleet upgrade needs at least one child");
+        }
+        Self::require_not_blocked(&env, &wasm_hash);
+        let registered = Self::get_children(env.clone());
+        let mut seen: Vec<Address> = Vec::new(&env);
+        for child in children.iter() {
+            if !registered.contains(&child) {
+                panic!("Child not registered");
+            }
+            if seen.contains(&child) {
+                panic!("Duplicate child in fleet upgrade");
+            }
+            seen.push_back(child);
+        }
+
+        let proposal_id = MultiSig::propose(&env, proposer, expiry);
+        Self::record_proposal_created(&env, proposal_id);
+        env.storage().persistent().set(
+            &DataKey::FleetUpgradeProposal(proposal_id),
+            &FleetUpgradeProposal { wasm_hash, children },
+        );
+        proposal_id
+    }
+
+    /// Approve a fleet upgrade proposal. Starts the shared upgrade timelock
+    /// when the threshold is met.
+    pub fn approve_fleet_upgrade(env: Env, proposal_id: u64, signer: Address) {
+        Self::require_function_enabled(&env, "approve_fleet_upgrade");
+        Self::ensure_proposal_storage(&env);
+        Self::require_not_paused(&env);
+        if !env.storage().persistent().has(&DataKey::FleetUpgradeProposal(proposal_id)) {
+            panic!("{}", ContractError::ProposalNotFound as u32);
+        }
+        MultiSig::approve(&env, proposal_id, signer);
+        Self::start_timelock_if_ready(&env, proposal_id);
+    }
+
+    /// Execute an approved fleet upgrade after the timelock delay.
+    ///
+    /// Calls `upgrade(wasm_hash)` on each listed child with this contract as
+    /// the authorizing admin. A failing child is recorded and skipped; the
+    /// remaining children are still upgraded and the proposal is consumed.
+    /// Like a local upgrade, fails for a blocked or unattested hash.
+    pub fn execute_fleet_upgrade(env: Env, proposal_id: u64) -> Vec<FleetChildResult> {
+        Self::require_function_enabled(&env, "execute_fleet_upgrade");
+        Self::in_transition(&env.clone(), symbol_short!("fleet_upg"), || Self::run_fleet_upgrade(env, proposal_id))
+    }
+
+    fn run_fleet_upgrade(env: Env, proposal_id: u64) -> Vec<FleetChildResult> {
+        Self::ensure_proposal_storage(&env);
+        Self::require_not_read_only(&env);
+        Self::require_not_paused(&env);
+
+        let proposal: FleetUpgradeProposal = env
+            .storage()
+            .persistent()
+            .get(&DataKey::FleetUpgradeProposal(proposal_id))
+            .unwrap_or_else(|| panic!("{}", ContractError::ProposalNotFound as u32));
+        Self::require_timelock_elapsed(&env, proposal_id);
+        Self::require_not_blocked(&env, &proposal.wasm_hash);
+        Self::require_attested(&env, &proposal.wasm_hash);
+
+        MultiSig::mark_executed(&env, proposal_id);
+        env.storage().persistent().remove(&DataKey::UpgradeTimelock(proposal_id));
+        Self::record_proposal_executed(&env, proposal_id);
+
+        let entrypoint = symbol_short!("upgrade");
+        let args: Vec<Val> = (proposal.wasm_hash.clone(),).into_val(&env);
+        let mut results: Vec<FleetChildResult> = Vec::new(&env);
+        let mut succeeded = 0u32;
+        for child in proposal.children.iter() {
+            let result = env.try_invoke_contract::<(), InvokeError>(&child, &entrypoint, args.clone());
+            let success = matches!(result, Ok(Ok(())));
+            if success {
+                succeeded += 1;
+            } else {
+                monitoring::track_governance_failure(
+                    &env,
+                    symbol_short!("fleet_upg"),
+                    monitoring::GovernanceStage::Execute,
+                    env.current_contract_address(),
+                    Self::invoke_error_code(result),
+                    false,
+                );
+            }
+            env.events().publish(
+                (symbol_short!("fleet"), symbol_short!("child")),
+                (child.clone(), success),
+            );
+            results.push_back(FleetChildResult { child, success });
+        }
+
+        env.events().publish(
+            (symbol_short!("fleet"), symbol_short!("done")),
+            FleetUpgradeEvent {
+                proposal_id,
+                wasm_hash: proposal.wasm_hash,
+                succeeded,
+                failed: results.len() - succeeded,
+                timestamp: env.ledger().timestamp(),
+                event_version: EVENT_SCHEMA_VERSION,
+            },
+        );
+        results
+    }
+
+    /// Return the fleet upgrade payload for a proposal ID, or None.
+    pub fn get_fleet_upgrade_proposal(env: Env, proposal_id: u64) -> Option<FleetUpgradeProposal> {
+        Self::ensure_proposal_storage(&env);
+        env.storage()
+            .persistent()
+            .get(&DataKey::FleetUpgradeProposal(proposal_id))
+    }
+
+    // ========================================================================
+    // Action Bundle Proposals
+    // ========================================================================
+
+    /// Open a multisig proposal that applies `actions` in order as one unit.
+    /// Fails with `InvalidActionBundle` unless it holds 1 to
+    /// `MAX_PROPOSAL_ACTIONS` actions. Multisig mode only.
+    pub fn propose_actions(env: Env, proposer: Address, actions: Vec<ProposalAction>, expiry: u64) -> u64 {
+        Self::require_function_enabled(&env, "propose_actions");
+        Self::ensure_proposal_storage(&env);
+        Self::require_not_read_only(&env);
+        Self::require_not_paused(&env);
+        if TokenVoting::get_config(&env).is_some() {
+            panic!("Token voting active - action bundles need multisig");
+        }
+        if actions.is_empty() || actions.len() > MAX_PROPOSAL_ACTIONS {
+            panic!("{}", ContractError::InvalidActionBundle as u32);
+        }
+        for action in actions.iter() {
+            if let ProposalAction::Upgrade(wasm_hash) = action {
+                Self::require_not_blocked(&env, &wasm_hash);
+            }
+        }
+
+        let proposal_id = MultiSig::propose(&env, proposer, expiry);
+        Self::record_proposal_created(&env, proposal_id);
+        env.storage().persistent().set(&ProposalKey::ActionProposal(proposal_id), &actions);
+        proposal_id
+    }
+
+    /// Approve an action bundle. Starts the shared upgrade timelock when the
+    /// threshold is met.
+    pub fn approve_actions(env: Env, proposal_id: u64, signer: Address) {
+        Self::require_function_enabled(&env, "approve_actions");
+        Self::ensure_proposal_storage(&env);
+        Self::require_not_paused(&env);
+        if !env.storage().persistent().has(&ProposalKey::ActionProposal(proposal_id)) {
+            panic!("{}", ContractError::ProposalNotFound as u32);
+        }
+        MultiSig::approve(&env, proposal_id, signer);
+        Self::start_timelock_if_ready(&env, proposal_id);
+    }
+
+    /// Apply an approved action bundle after the timelock delay. Actions run
+    /// in order with the same validation as their standalone entrypoints;
+    /// if any fails, the whole invocation reverts and nothing is applied.
+    /// Publishes `("action", "applied")` per action.
+    pub fn execute_proposal(env: Env, proposal_id: u64) {
+        Self::require_function_enabled(&env, "execute_proposal");
+        Self::ensure_proposal_storage(&env);
+        Self::require_not_read_only(&env);
+        Self::require_not_paused(&env);
+
+        let actions: Vec<ProposalAction> = env
+            .storage()
+            .persistent()
+            .get(&ProposalKey::ActionProposal(proposal_id))
+            .unwrap_or_else(|| panic!("{}", ContractError::ProposalNotFound as u32));
+        Self::require_timelock_elapsed(&env, proposal_id);
+
+        MultiSig::mark_executed(&env, proposal_id);
+        env.storage().persistent().remove(&DataKey::UpgradeTimelock(proposal_id));
+        Self::record_proposal_executed(&env, proposal_id);
+
+        for (index, action) in actions.iter().enumerate() {
+            match action.clone() {
+                ProposalAction::Upgrade(wasm_hash) => Self::install_upgrade(&env, &wasm_hash),
+                ProposalAction::SetVersion(version) => {
+                    grainlify_upgradeable::set_version(&env, version);
+                    Self::record_version(&env);
+                }
+                ProposalAction::SetTimelockDelay(delay) => {
+                    Self::store_timelock_delay(&env, delay, Some(proposal_id));
+                }
+                ProposalAction::SetThreshold(threshold) => {
+                    Self::store_threshold(&env, threshold, Some(proposal_id));
+                }
+                ProposalAction::UpdateConfig(update) => {
+                    Self::apply_config_update(&env, update, Some(proposal_id));
+                }
+                ProposalAction::AddSigner(signer) => Self::invite_signer(&env, signer, proposal_id),
+                ProposalAction::RegisterChild(child) => Self::store_child(&env, child),
+                ProposalAction::UnregisterChild(child) => Self::remove_child(&env, child),
+                ProposalAction::SetRelayer(relayer) => Self::store_relayer(&env, relayer),
+                ProposalAction::ClearRelayer => Self::remove_relayer(&env),
+                ProposalAction::BlockWasm(wasm_hash, reason) => {
+                    Self::store_wasm_block(&env, wasm_hash, reason, None, Some(proposal_id));
+                }
+                ProposalAction::UnblockWasm(wasm_hash) => {
+                    Self::remove_wasm_block(&env, wasm_hash, None, Some(proposal_id));
+                }
+                ProposalAction::SetGuardian(guardian) => Self::store_guardian(&env, guardian),
+                ProposalAction::ClearGuardian => Self::remove_guardian(&env),
+                ProposalAction::SetEmergencyPauseSettings(settings) => {
+                    Self::store_emergency_pause_settings(&env, settings);
+                }
+                ProposalAction::ExtendEmergencyPause(seconds) => Self::push_back_emergency_pause(&env, seconds),
+                ProposalAction::EndEmergencyPause => Self::stop_emergency_pause(&env),
+                ProposalAction::DisableFunction(name) => Self::switch_off(&env, name, None),
+                ProposalAction::EnableFunction(name) => Self::switch_on(&env, name, None),
+                ProposalAction::CreateTemplate(name, actions) => Self::store_template(&env, name, actions),
+                ProposalAction::DeleteTemplate(name) => Self::remove_template(&env, name),
+                ProposalAction::SetTokenVotingConfig(config) => Self::store_token_voting_config(&env, Some(config)),
+                ProposalAction::SetDuplicateApproval(mode) => Self::store_duplicate_approval(&env, mode),
+                ProposalAction::SetNetworkLabel(label) => {
+                    Self::store_network_label(&env, Some(label), Some(proposal_id));
+                }
+                ProposalAction::ClearNetworkLabel => Self::store_network_label(&env, None, Some(proposal_id)),
+                ProposalAction::SetRejectionReasonRequired(required) => {
+                    Self::store_rejection_reason_required(&env, required);
+                }
+                ProposalAction::SetLegacyApprovalEnabled(enabled) => {
+                    Self::store_legacy_approval_enabled(&env, enabled);
+                }
+                ProposalAction::SetAttestationConfig(config) => Self::store_attestation_config(&env, config),
+                ProposalAction::SetRewardConfig(config) => Self::store_reward_config(&env, Some(config)),
+                ProposalAction::ClearRewardConfig => Self::store_reward_config(&env, None),
+                ProposalAction::WithdrawRewardPool(to, amount) => Self::pay_out_reward_pool(&env, to, amount),
+                ProposalAction::RegisterDeployment(name, address, overwrite) => {
+                    Self::store_deployment(&env, name, address, overwrite);
+                }
+                ProposalAction::UnregisterDeployment(name) => Self::remove_deployment(&env, name),
+                ProposalAction::SetDeploymentVersion(name, version) => {
+                    Self::store_deployment_version(&env, name, version);
+                }
+                ProposalAction::SetCircuitBreakerConfig(config) => {
+                    Self::store_circuit_breaker_config(&env, config);
+                }
+                ProposalAction::EnterMaintenance(until) => Self::start_maintenance(&env, until),
+                ProposalAction::ExitMaintenance => Self::end_maintenance(&env),
+                ProposalAction::ImportMetrics(entries) => Self::store_imported_metrics(&env, entries),
+                ProposalAction::SetTracking(operation, enabled) => monitoring::set_tracking(&env, operation, enabled),
+                ProposalAction::SetStringLimit(field, max) => limits::set_limit(&env, &field, max),
+                ProposalAction::SetHistoryRetention(kind, retention) => {
+                    Self::store_history_retention(&env, kind, retention);
+                }
+                ProposalAction::SetMetricsContract(sink) => Self::store_metrics_contract(&env, Some(sink)),
+                ProposalAction::ClearMetricsContract => Self::store_metrics_contract(&env, None),
+            }
+            env.events().publish(
+                (symbol_short!("action"), symbol_short!("applied")),
+                ActionAppliedEvent {
+                    proposal_id,
+                    index: index as u32,
+                    action,
+                    timestamp: env.ledger().timestamp(),
+                    event_version: EVENT_SCHEMA_VERSION,
+                },
+            );
+        }
+    }
+
+    /// Return the action bundle for a proposal ID, or None.
+    pub fn get_proposal_actions(env: Env, proposal_id: u64) -> Option<Vec<ProposalAction>> {
+        Self::ensure_proposal_storage(&env);
+        env.storage().persistent().get(&ProposalKey::ActionProposal(proposal_id))
+    }
+
+    /// Store `actions` as template `name`, replacing any template of that
+    /// name. Fails with `InvalidActionBundle` as `propose_actions` would.
+    /// Keeps at most `MAX_TEMPLATES`. A template cannot hold template
+    /// actions. Publishes `("template", "set")`. Governance only; multisig
+    /// deployments use a `ProposalAction::CreateTemplate` bundle.
+    pub fn create_template(env: Env, name: Symbol, actions: Vec<ProposalAction>) {
+        Self::require_function_enabled(&env, "create_template");
+        grainlify_upgradeable::require_governance(&env);
+        Self::require_not_read_only(&env);
+        Self::store_template(&env, name, actions);
+    }
+
+    /// Remove template `name`. Publishes `("template", "deleted")`.
+    /// Governance only; multisig deployments use a
+    /// `ProposalAction::DeleteTemplate` bundle.
+    pub fn delete_template(env: Env, name: Symbol) {
+        Self::require_function_enabled(&env, "delete_template");
+        grainlify_upgradeable::require_governance(&env);
+        Self::require_not_read_only(&env);
+        Self::remove_template(&env, name);
+    }
+
+    fn store_template(env: &Env, name: Symbol, actions: Vec<ProposalAction>) {
+        if actions.is_empty() || actions.len() > MAX_PROPOSAL_ACTIONS {
+            panic!("{}", ContractError::InvalidActionBundle as u32);
+        }
+        for action in actions.iter() {
+            if matches!(action, ProposalAction::CreateTemplate(..) | ProposalAction::DeleteTemplate(_)) {
+                panic!("{}", ContractError::InvalidActionBundle as u32);
+            }
+        }
+
+        let mut names = Self::list_templates(env.clone());
+        if !names.contains(&name) {
+            if names.len() >= MAX_TEMPLATES {
+                panic!("Too many templates");
+            }
+            names.push_back(name.clone());
+            env.storage().persistent().set(&TemplateKey::Names, &names);
+        }
+        env.storage().persistent().set(&TemplateKey::Template(name.clone()), &actions);
+        env.events().publish((symbol_short!("template"), symbol_short!("set")), (name, actions));
+    }
+
+    fn remove_template(env: &Env, name: Symbol) {
+        let mut names = Self::list_templates(env.clone());
+        let index = names.first_index_of(&name).unwrap_or_else(|| panic!("Unknown template"));
+        names.remove(index);
+        env.storage().persistent().set(&TemplateKey::Names, &names);
+        env.storage().persistent().remove(&TemplateKey::Template(name.clone()));
+        env.events().publish((symbol_short!("template"), symbol_short!("deleted")), name);
+    }
+
+    /// Names of the stored templates, oldest first.
+    pub fn list_templates(env: Env) -> Vec<Symbol> {
+        env.storage().persistent().get(&TemplateKey::Names).unwrap_or(Vec::new(&env))
+    }
+
+    /// Actions of template `name`, or None.
+    pub fn get_template(env: Env, name: Symbol) -> Option<Vec<ProposalAction>> {
+        env.storage().persistent().get(&TemplateKey::Template(name))
+    }
+
+    /// `propose_actions` with template `name`'s actions and no expiry.
+    /// `params` fills in the template's placeholders: each entry replaces
+    /// the action at its index with an action of the same kind, such as a
+    /// `SetVersion` carrying this month's version. Fails with
+    /// `InvalidActionBundle` for an index past the end or a different
+    /// kind. Publishes `("template", "used")` with the name and proposal ID.
+    pub fn propose_from_template(
+        env: Env,
+        name: Symbol,
+        proposer: Address,
+        params: Map<u32, ProposalAction>,
+    ) -> u64 {
+        Self::require_function_enabled(&env, "propose_from_template");
+        let mut actions =
+            Self::get_template(env.clone(), name.clone()).unwrap_or_else(|| panic!("Unknown template"));
+        for (index, param) in params.iter() {
+            let placeholder = actions
+                .get(index)
+                .unwrap_or_else(|| panic!("{}", ContractError::InvalidActionBundle as u32));
+            if core::mem::discriminant(&placeholder) != core::mem::discriminant(&param) {
+                panic!("{}", ContractError::InvalidActionBundle as u32);
+            }
+            actions.set(index, param);
+        }
+
+        let proposal_id = Self::propose_actions(env.clone(), proposer, actions, 0);
+        env.events().publish((symbol_short!("template"), symbol_short!("used")), (name, proposal_id));
+        proposal_id
+    }
+
+    // ========================================================================
+    // Function Kill Switches
+    // ========================================================================
+
+    /// Switch off a single entrypoint by name. Governance only; multisig
+    /// deployments use a `ProposalAction::DisableFunction` bundle.
+    /// Publishes `("killsw", "disable")` with `(name, admin)`, the admin
+    /// being `None` when an action proposal applied it.
+    ///
+    /// `NON_DISABLEABLE_FUNCTIONS` are protected, so a kill switch can
+    /// never lock either governance mode out.
+    pub fn disable_function(env: Env, name: Symbol) {
+        Self::require_function_enabled(&env, "disable_function");
+        let admin = grainlify_upgradeable::require_governance(&env);
+        Self::switch_off(&env, name, Some(admin));
+    }
+
+    /// Re-enable a previously disabled entrypoint. Governance only;
+    /// multisig deployments use a `ProposalAction::EnableFunction` bundle.
+    pub fn enable_function(env: Env, name: Symbol) {
+        let admin = grainlify_upgradeable::require_governance(&env);
+        Self::switch_on(&env, name, Some(admin));
+    }
+
+    fn switch_off(env: &Env, name: Symbol, admin: Option<Address>) {
+        for protected in NON_DISABLEABLE_FUNCTIONS {
+            if name == Symbol::new(env, protected) {
+                panic!("Function cannot be disabled");
+            }
+        }
+
+        let mut disabled = Self::list_disabled_functions(env.clone());
+        if disabled.contains(&name) {
+            return;
+        }
+        disabled.push_back(name.clone());
+        env.storage().persistent().set(&DataKey::DisabledFunctions, &disabled);
+        env.events().publish(
+            (symbol_short!("killsw"), symbol_short!("disable")),
+            (name, admin),
+        );
+    }
+
+    fn switch_on(env: &Env, name: Symbol, admin: Option<Address>) {
+        let disabled = Self::list_disabled_functions(env.clone());
+        let mut remaining: Vec<Symbol> = Vec::new(env);
+        for entry in disabled.iter() {
+            if entry != name {
+                remaining.push_back(entry);
+            }
+        }
+        if remaining.len() == disabled.len() {
+            return;
+        }
+        env.storage().persistent().set(&DataKey::DisabledFunctions, &remaining);
+        env.events().publish(
+            (symbol_short!("killsw"), symbol_short!("enable")),
+            (name, admin),
+        );
+    }
+
+    /// Returns the entrypoint names currently switched off.
+    pub fn list_disabled_functions(env: Env) -> Vec<Symbol> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::DisabledFunctions)
+            .unwrap_or(Vec::new(&env))
+    }
+
+    // ========================================================================
+    // Config Snapshots
+    // ========================================================================
+
+    pub fn create_config_snapshot(env: Env) -> u64 {
+        Self::require_function_enabled(&env, "create_config_snapshot");
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).expect("Admin not set");
+        admin.require_auth();
+        // [GUARDRAIL] Snapshots are state mutations — blocked in read-only mode
+        Self::require_not_read_only(&env);
+
+        let next_id: u64 = env.storage().instance()
+            .get(&DataKey::SnapshotCounter).unwrap_or(0) + 1;
+
+        let (multisig_threshold, multisig_signers) = match MultiSig::get_config_opt(&env) {
+            Some(cfg) => (cfg.threshold, cfg.signers),
+            None => (0u32, Vec::new(&env)),
+        };
+
+        let snapshot = CoreConfigSnapshot {
+            id: next_id,
+            timestamp: env.ledger().timestamp(),
+            admin: env.storage().instance().get(&DataKey::Admin),
+            version: env.storage().instance().get(&DataKey::Version).unwrap_or(0),
+            previous_version: env.storage().instance().get(&DataKey::PreviousVersion),
+            multisig_threshold,
+            multisig_signers,
+        };
+
+        env.storage().instance().set(&DataKey::ConfigSnapshot(next_id), &snapshot);
+
+        let mut index: Vec<u64> = env.storage().instance()
+            .get(&DataKey::SnapshotIndex).unwrap_or(Vec::new(&env));
+        index.push_back(next_id);
+
+        if index.len() > CONFIG_SNAPSHOT_LIMIT {
+            let oldest_snapshot_id = index.get(0).unwrap();
+            env.storage().instance().remove(&DataKey::ConfigSnapshot(oldest_snapshot_id));
+            let mut trimmed = Vec::new(&env);
+            for i in 1..index.len() { trimmed.push_back(index.get(i).unwrap()); }
+            index = trimmed;
+        }
+
+        env.storage().instance().set(&DataKey::SnapshotIndex, &index);
+        env.storage().instance().set(&DataKey::SnapshotCounter, &next_id);
+
+        env.events().publish(
+            (symbol_short!("cfg_snap"), symbol_short!("create")),
+            (next_id, snapshot.timestamp),
+        );
+        next_id
+    }
+
+    pub fn list_config_snapshots(env: Env) -> Vec<CoreConfigSnapshot> {
+        let index: Vec<u64> = env.storage().instance()
+            .get(&DataKey::SnapshotIndex).unwrap_or(Vec::new(&env));
+        let mut snapshots: Vec<CoreConfigSnapshot> = Vec::new(&env);
+        for i in 0..index.len() {
+            let snapshot_id = index.get(i).unwrap();
+            if let Some(snapshot) = env.storage().instance()
+                .get::<DataKey, CoreConfigSnapshot>(&DataKey::ConfigSnapshot(snapshot_id))
+            {
+                snapshots.push_back(snapshot);
+            }
+        }
+        snapshots
+    }
+
+    pub fn get_config_snapshot(env: Env, snapshot_id: u64) -> Option<CoreConfigSnapshot> {
+        env.storage().instance().get(&DataKey::ConfigSnapshot(snapshot_id))
+    }
+
+    pub fn get_latest_config_snapshot(env: Env) -> Option<CoreConfigSnapshot> {
+        let index: Vec<u64> = env.storage().instance()
+            .get(&DataKey::SnapshotIndex).unwrap_or(Vec::new(&env));
+        if index.is_empty() { return None; }
+        let latest_id = index.get(index.len() - 1).unwrap();
+        env.storage().instance().get(&DataKey::ConfigSnapshot(latest_id))
+    }
+
+    pub fn get_snapshot_count(env: Env) -> u32 {
+        let index: Vec<u64> = env.storage().instance()
+            .get(&DataKey::SnapshotIndex).unwrap_or(Vec::new(&env));
+        index.len()
+    }
+
+    pub fn compare_snapshots(env: Env, from_id: u64, to_id: u64) -> SnapshotDiff {
+        let from: CoreConfigSnapshot = env.storage().instance()
+            .get(&DataKey::ConfigSnapshot(from_id))
+            .unwrap_or_else(|| panic!("Snapshot not found: from_id"));
+        let to: CoreConfigSnapshot = env.storage().instance()
+            .get(&DataKey::ConfigSnapshot(to_id))
+            .unwrap_or_else(|| panic!("Snapshot not found: to_id"));
+        SnapshotDiff {
+            from_id, to_id,
+            admin_changed: from.admin != to.admin,
+            version_changed: from.version != to.version,
+            previous_version_changed: from.previous_version != to.previous_version,
+            multisig_threshold_changed: from.multisig_threshold != to.multisig_threshold,
+            multisig_signers_changed: from.multisig_signers != to.multisig_signers,
+            from_version: from.version,
+            to_version: to.version,
+        }
+    }
+
+    /// [FIX-C02] Restore now uses two-step process when admin address changes.
+    ///
+    /// If the snapshot would change the admin address, a `PendingAdminRestore`
+    /// is created instead of applying immediately. The new admin address must
+    /// call `confirm_admin_restore()` to finalize.
+    ///
+    /// If the snapshot does NOT change the admin, restore applies immediately
+    /// (same behavior as before).
+    pub fn restore_config_snapshot(env: Env, snapshot_id: u64) {
+        Self::require_function_enabled(&env, "restore_config_snapshot");
+        let admin: Address = env.storage().instance()
+            .get(&DataKey::Admin).expect("Admin not set");
+        admin.require_auth();
+        // [GUARDRAIL] Restores mutate state — blocked in read-only mode
+        Self::require_not_read_only(&env);
+
+        Self::restore_snapshot_with_checks(&env, snapshot_id);
+
+        env.events().publish(
+            (symbol_short!("cfg_snap"), symbol_short!("restore")),
+            (snapshot_id, env.ledger().timestamp()),
+        );
+    }
+
+    fn restore_snapshot_with_checks(env: &Env, snapshot_id: u64) {
+
+        // [FIX-M02] Explicit error when snapshot is pruned
+        let snapshot: CoreConfigSnapshot = env.storage().instance()
+            .get(&DataKey::ConfigSnapshot(snapshot_id))
+            .unwrap_or_else(|| panic!("{}", ContractError::SnapshotPruned as u32));
+
+        let current_admin: Option<Address> = env.storage().instance().get(&DataKey::Admin);
+
+        // [GUARDRAIL] Prevent no-op restore to save gas
+        let current_version: u32 = env.storage().instance().get(&DataKey::Version).unwrap_or(0);
+        let multisig_opt = MultiSig::get_config_opt(env);
+        let current_threshold = multisig_opt.as_ref().map(|c| c.threshold).unwrap_or(0);
+        let current_signers = multisig_opt.as_ref().map(|c| c.signers.clone()).unwrap_or(Vec::new(env));
+
+        if snapshot.version == current_version 
+            && snapshot.admin == current_admin 
+            && snapshot.multisig_threshold == current_threshold 
+            && snapshot.multisig_signers == current_signers 
+        {
+            return;
+        }
+
+        if let Some(ref snapshot_admin) = snapshot.admin {
+            Self::require_not_self(env, snapshot_admin);
+        }
+        Self::require_no_self_signer(env, &snapshot.multisig_signers);
+
+        // [FIX-C02] Detect if restore would change admin — if so, require two-step confirmation
+        let admin_would_change = snapshot.admin != current_admin;
+
+        if admin_would_change {
+            // Create pending restore — new admin must confirm
+            let pending = PendingAdminRestore {
+                snapshot_id,
+                proposed_admin: snapshot.admin.clone().expect("Snapshot has no admin to restore"),
+                initiated_at: env.ledger().timestamp(),
+                expires_at: env.ledger().timestamp().saturating_add(DEFAULT_TIMELOCK_DELAY),
+            };
+            env.storage().instance().set(&DataKey::PendingAdminRestore, &pending);
+
+            env.events().publish(
+                (symbol_short!("cfg_snap"), symbol_short!("adm_pnd")),
+                (snapshot_id, pending.proposed_admin, pending.expires_at),
+            );
+            // Return early — do not apply yet
+            return;
+        }
+
+        // Admin unchanged — apply restore immediately
+        Self::apply_snapshot_restore(env, &snapshot);
+    }
+
+    /// [FIX-C02] The proposed new admin confirms an admin-changing snapshot restore.
+    ///
+    /// Only the address that would BECOME the new admin can confirm this.
+    /// This ensures a compromised old key cannot silently transfer control.
+    /// The outgoing admin's `get_pending_admin_actions` are revoked, so the
+    /// new admin should review them first.
+    pub fn confirm_admin_restore(env: Env, snapshot_id: u64) {
+        Self::require_function_enabled(&env, "confirm_admin_restore");
+        let pending: PendingAdminRestore = env.storage().instance()
+            .get(&DataKey::PendingAdminRestore)
+            .unwrap_or_else(|| panic!("No pending admin restore found"));
+
+        if pending.snapshot_id != snapshot_id {
+            panic!("Snapshot ID does not match pending restore");
+        }
+
+        // The proposed new admin must authorize this
+        pending.proposed_admin.require_auth();
+
+        // [GUARDRAIL] Confirm is a state mutation — blocked in read-only mode
+        Self::require_not_read_only(&env);
+
+        // Check expiry
+        if env.ledger().timestamp() > pending.expires_at {
+            env.storage().instance().remove(&DataKey::PendingAdminRestore);
+            panic!("Pending admin restore has expired");
+        }
+
+        let snapshot: CoreConfigSnapshot = env.storage().instance()
+            .get(&DataKey::ConfigSnapshot(snapshot_id))
+            .unwrap_or_else(|| panic!("Snapshot not found"));
+
+        Self::apply_snapshot_restore(&env, &snapshot);
+
+        env.storage().instance().remove(&DataKey::PendingAdminRestore);
+
+        env.events().publish(
+            (symbol_short!("cfg_snap"), symbol_short!("adm_conf")),
+            (snapshot_id, env.ledger().timestamp()),
+        );
+    }
+
+    /// Internal: applies snapshot state to storage
+    fn apply_snapshot_restore(env: &Env, snapshot: &CoreConfigSnapshot) {
+        if snapshot.admin != grainlify_upgradeable::get_admin(env) {
+            Self::require_no_emergency_pause(env);
+            Self::revoke_admin_actions(env);
+        }
+        let previous_mode = monitoring::current_mode(env);
+        if let Some(ref snapshot_admin) = snapshot.admin {
+            env.storage().instance().set(&DataKey::Admin, snapshot_admin);
+            env.storage().instance().set(&DataKey::InitMode, &InitMode::Admin);
+        } else {
+            env.storage().instance().remove(&DataKey::Admin);
+            env.storage().instance().set(&DataKey::InitMode, &InitMode::Multisig);
+        }
+
+        env.storage().instance().set(&DataKey::Version, &snapshot.version);
+        Self::record_version(env);
+
+        match snapshot.previous_version {
+            Some(prev) => env.storage().instance().set(&DataKey::PreviousVersion, &prev),
+            None => env.storage().instance().remove(&DataKey::PreviousVersion),
+        }
+
+        if snapshot.multisig_threshold > 0 {
+            let config = multisig::MultiSigConfig {
+                signers: snapshot.multisig_signers.clone(),
+                threshold: snapshot.multisig_threshold,
+            };
+            MultiSig::set_config(env, config);
+            Self::record_threshold_change(env, None);
+        } else {
+            MultiSig::clear_config(env);
+        }
+        if monitoring::current_mode(env) != previous_mode {
+            monitoring::record_mode_switch(env, &previous_mode);
+        }
+    }
+
+    /// [FIX-L04] Returns None on inconsistency instead of panicking — view fn safety
+    pub fn get_rollback_info(env: Env) -> RollbackInfo {
+        let current_version: u32 = env.storage().instance().get(&DataKey::Version).unwrap_or(0);
+        let previous_version: u32 = env.storage().instance().get(&DataKey::PreviousVersion).unwrap_or(0);
+        let rollback_available = previous_version > 0;
+
+        let migration_state: Option<MigrationState> = env.storage().instance().get(&DataKey::MigrationState);
+        let has_migration = migration_state.is_some();
+        let migration_from_version = migration_state.as_ref().map(|m| m.from_version).unwrap_or(0);
+        let migration_to_version = migration_state.as_ref().map(|m| m.to_version).unwrap_or(0);
+        let migration_timestamp = migration_state.as_ref().map(|m| m.migrated_at).unwrap_or(0);
+
+        let index: Vec<u64> = env.storage().instance()
+            .get(&DataKey::SnapshotIndex).unwrap_or(Vec::new(&env));
+        let snapshot_count = index.len();
+        let has_snapshot = snapshot_count > 0;
+
+        // [FIX-L04] Use Option pattern instead of panic on inconsistency
+        let (latest_snapshot_id, latest_snapshot_version) = if has_snapshot {
+            let latest_id = index.get(snapshot_count - 1).unwrap();
+            let snap: Option<CoreConfigSnapshot> = env.storage().instance()
+                .get(&DataKey::ConfigSnapshot(latest_id));
+            match snap {
+                Some(s) => (latest_id, s.version),
+                None => (0u64, 0u32), // Inconsistency: return safe defaults
+            }
+        } else {
+            (0u64, 0u32)
+        };
+
+        RollbackInfo {
+            current_version, previous_version, rollback_available,
+            has_migration, migration_from_version, migration_to_version,
+            migration_timestamp, snapshot_count, has_snapshot,
+            latest_snapshot_id, latest_snapshot_version,
+        }
+    }
+
+    // ========================================================================
+    // Network Configuration
+    // ========================================================================
+
+    pub fn get_chain_id(env: Env) -> Option<String> {
+        env.storage().instance().get(&DataKey::ChainId)
+    }
+
+    pub fn get_network_id(env: Env) -> Option<String> {
+        env.storage().instance().get(&DataKey::NetworkId)
+    }
+
+    pub fn get_network_info(env: Env) -> (Option<String>, Option<String>) {
+        let chain_id = env.storage().instance().get(&DataKey::ChainId);
+        let network_id = env.storage().instance().get(&DataKey::NetworkId);
+        (chain_id, network_id)
+    }
+
+    /// Environment this deployment was labelled for, e.g. `mainnet`. Same
+    /// as `get_network_label`.
+    pub fn get_environment(env: Env) -> Option<Symbol> {
+        Self::get_network_label(env)
+    }
+
+    /// Network passphrase hash recorded when the environment label was
+    /// last set.
+    pub fn get_environment_network_id(env: Env) -> Option<BytesN<32>> {
+        env.storage().instance().get(&FingerprintKey::NetworkHash)
+    }
+
+    /// Fails with `NetworkMismatch` unless this deployment is labelled
+    /// `expected` and runs on the network the label was set on. Meant as
+    /// the first call of ops scripts, so one pointed at the wrong contract
+    /// stops before doing anything.
+    pub fn assert_environment(env: Env, expected: Symbol) {
+        let recorded = Self::get_environment_network_id(env.clone());
+        if Self::get_environment(env.clone()) != Some(expected)
+            || recorded.is_some_and(|network_id| network_id != env.ledger().network_id())
+        {
+            panic!("{}", ContractError::NetworkMismatch as u32);
+        }
+    }
+
+    /// Stores the environment label with the current network passphrase
+    /// hash, or clears both, and publishes `("env", "set")`.
+    fn store_environment(env: &Env, label: Option<Symbol>) {
+        match &label {
+            Some(label) => {
+                env.storage().instance().set(&DataKey::NetworkLabel, label);
+                env.storage().instance().set(&FingerprintKey::NetworkHash, &env.ledger().network_id());
+            }
+            None => {
+                env.storage().instance().remove(&DataKey::NetworkLabel);
+                env.storage().instance().remove(&FingerprintKey::NetworkHash);
+            }
+        }
+        env.events().publish((symbol_short!("env"), symbol_short!("set")), label);
+    }
+
+    // ========================================================================
+    // Contract Metadata
+    // ========================================================================
+
+    /// Replace all metadata fields. Governance only; each field must fit its
+    /// string limit (`name`, `desc`, `url`, `contact`) and all four together
+    /// the `meta_tot` limit, or the call fails with that field's error.
+    pub fn set_metadata(env: Env, name: String, description: String, url: String, contact: String) {
+        Self::require_function_enabled(&env, "set_metadata");
+        grainlify_upgradeable::require_governance(&env);
+        Self::require_not_read_only(&env);
+        Self::store_metadata(&env, ContractMetadata { name, description, url, contact });
+    }
+
+    /// Update one metadata field: `name`, `description`, `url`, or
+    /// `contact`. Other keys fail with `UnknownMetadataField`. Shares the
+    /// `set_metadata` kill switch.
+    pub fn set_metadata_field(env: Env, key: Symbol, value: String) {
+        Self::require_function_enabled(&env, "set_metadata");
+        grainlify_upgradeable::require_governance(&env);
+        Self::require_not_read_only(&env);
+
+        let mut metadata = Self::get_metadata(env.clone());
+        if key == Symbol::new(&env, "name") {
+            metadata.name = value;
+        } else if key == Symbol::new(&env, "description") {
+            metadata.description = value;
+        } else if key == Symbol::new(&env, "url") {
+            metadata.url = value;
+        } else if key == Symbol::new(&env, "contact") {
+            metadata.contact = value;
+        } else {
+            panic!("{}", ContractError::UnknownMetadataField as u32);
+        }
+        Self::store_metadata(&env, metadata);
+    }
+
+    pub fn get_metadata(env: Env) -> ContractMetadata {
+        env.storage().instance().get(&DataKey::Metadata).unwrap_or_else(|| {
+            let empty = String::from_str(&env, "");
+            ContractMetadata {
+                name: empty.clone(),
+                description: empty.clone(),
+                url: empty.clone(),
+                contact: empty,
+            }
+        })
+    }
+
+    pub fn get_contract_info(env: Env) -> ContractInfo {
+        ContractInfo {
+            name: Self::get_metadata(env.clone()).name,
+            version: grainlify_upgradeable::get_version(&env),
+            version_string: Self::get_version_semver_string(env.clone()),
+            upgrade_count: Self::get_upgrade_count(env.clone()),
+            abi_version: ABI_VERSION,
+            features: Self::get_features(env.clone()),
+            version_label: Self::get_version_label(env.clone()),
+            emergency_paused_until: Self::active_emergency_pause(&env).map(|pause| pause.expires_at),
+        }
+    }
+
+    /// `ABI_VERSION` of the running code, for clients to check before
+    /// calling entrypoints that older builds lack.
+    pub fn get_abi_version(_env: Env) -> u32 {
+        ABI_VERSION
+    }
+
+    /// Optional features active on this deployment, read from the current
+    /// configuration so the list follows every governance change:
+    ///
+    /// - `multisig`: multisig governance is configured
+    /// - `timelock`: multisig with a nonzero upgrade timelock delay
+    /// - `tok_vote`: token-holder voting replaces the multisig flow
+    /// - `attest`: upgrades need auditor attestations
+    /// - `staging`: admin upgrades go through `stage_upgrade`
+    /// - `nonce`: admin calls need a nonce
+    /// - `relayer`: a relayer may execute approved upgrades
+    /// - `guardian`: a guardian may call `guardian_pause`
+    /// - `keeper`: maintenance calls pay keeper rewards
+    /// - `monitor`: built with the monitoring feature
+    /// - `metrics`: tracked calls go to a metrics contract
+    pub fn get_features(env: Env) -> Vec<Symbol> {
+        let mut features = Vec::new(&env);
+        let multisig = MultiSig::get_config_opt(&env).is_some();
+        let enabled = [
+            (symbol_short!("multisig"), multisig),
+            (symbol_short!("timelock"), multisig && Self::get_timelock_delay(env.clone()) > 0),
+            (symbol_short!("tok_vote"), TokenVoting::get_config(&env).is_some()),
+            (
+                symbol_short!("attest"),
+                Self::get_attestation_config(env.clone()).is_some_and(|config| config.require_attestation),
+            ),
+            (symbol_short!("staging"), Self::get_staging_delay(env.clone()).is_some()),
+            (symbol_short!("nonce"), Self::get_require_nonce(env.clone())),
+            (symbol_short!("relayer"), Self::get_relayer(env.clone()).is_some()),
+            (symbol_short!("guardian"), Self::get_guardian(env.clone()).is_some()),
+            (symbol_short!("keeper"), Self::get_reward_config(env.clone()).is_some()),
+            (symbol_short!("monitor"), monitoring::ENABLED),
+            (symbol_short!("metrics"), monitoring::get_metrics_contract(&env).is_some()),
+        ];
+        for (feature, on) in enabled {
+            if on {
+                features.push_back(feature);
+            }
+        }
+        features
+    }
+
+    /// Byte limit currently enforced for string field `field` (`name`,
+    /// `desc`, `url`, `contact`, `label`, `meta_tot` or `reason`). Fails
+    /// with `InvalidStringLimit` for other fields.
+    pub fn get_string_limit(env: Env, field: Symbol) -> u32 {
+        limits::get_limit(&env, &field)
+    }
+
+    /// Set the byte limit for `field` within its hard floor and ceiling;
+    /// see the `limits` module. Existing values are not re-checked.
+    /// Governance only; multisig deployments use a
+    /// `ProposalAction::SetStringLimit` bundle.
+    pub fn set_string_limit(env: Env, field: Symbol, max: u32) {
+        Self::require_function_enabled(&env, "set_string_limit");
+        grainlify_upgradeable::require_governance(&env);
+        Self::require_not_read_only(&env);
+        limits::set_limit(&env, &field, max);
+    }
+
+    fn store_metadata(env: &Env, metadata: ContractMetadata) {
+        limits::require_len(env, limits::NAME, &metadata.name);
+        limits::require_len(env, limits::DESCRIPTION, &metadata.description);
+        limits::require_len(env, limits::URL, &metadata.url);
+        limits::require_len(env, limits::CONTACT, &metadata.contact);
+        let total = metadata.name.len() + metadata.description.len() + metadata.url.len() + metadata.contact.len();
+        if let Err(error) = limits::validate_byte_len(env, limits::METADATA_TOTAL, total) {
+            panic!("{}", error as u32);
+        }
+        env.storage().instance().set(&DataKey::Metadata, &metadata);
+        env.events().publish(
+            (symbol_short!("meta"), symbol_short!("updated")),
+            MetadataUpdatedEvent {
+                metadata,
+                timestamp: env.ledger().timestamp(),
+                event_version: EVENT_SCHEMA_VERSION,
+            },
+        );
+    }
+
+    // ========================================================================
+    // Storage Layout Verification
+    // ========================================================================
+
+    /// Storage schema version; 1 on deployments that predate the marker.
+    pub fn get_schema_version(env: Env) -> u32 {
+        env.storage().instance().get(&DataKey::SchemaVersion).unwrap_or(1)
+    }
+
+    /// Bring storage up to `STORAGE_SCHEMA_VERSION`, applying each pending
+    /// step in order and recording the version after each. `caller` must
+    /// be the admin or a signer: the steps are fixed by the code, so any
+    /// one of them can run them. Idempotent: on a current store it changes
+    /// nothing. Returns the resulting schema version.
+    pub fn migrate_schema(env: Env, caller: Address) -> u32 {
+        Self::require_function_enabled(&env, "migrate_schema");
+        Self::require_admin_or_signer(&env, &caller);
+        Self::require_not_read_only(&env);
+
+        let mut version = Self::get_schema_version(env.clone());
+        while version < STORAGE_SCHEMA_VERSION {
+            match version {
+                1 => monitoring::consolidate_metric_keys(&env),
+                _ => panic!("No migration path available"),
+            }
+            version += 1;
+            env.storage().instance().set(&DataKey::SchemaVersion, &version);
+            env.events().publish(
+                (symbol_short!("schema"), symbol_short!("migrated")),
+                SchemaMigratedEvent {
+                    from_version: version - 1,
+                    to_version: version,
+                    timestamp: env.ledger().timestamp(),
+                    event_version: EVENT_SCHEMA_VERSION,
+                },
+            );
+        }
+        version
+    }
+
+    pub fn verify_storage_layout(env: Env) -> bool {
+        let admin_ok = env.storage().instance().has(&DataKey::Admin)
+            && env.storage().instance().get::<_, Address>(&DataKey::Admin).is_some();
+
+        let version_ok = env.storage().instance().has(&DataKey::Version)
+            && env.storage().instance().get::<_, u32>(&DataKey::Version).is_some();
+
+        let migration_ok = if env.storage().instance().has(&DataKey::MigrationState) {
+            // [FIX-L03] Also verify MigrationState schema is readable
+            env.storage().instance()
+                .get::<_, crate::MigrationState>(&DataKey::MigrationState)
+                .is_some()
+        } else {
+            true
+        };
+
+        admin_ok && version_ok && migration_ok
+    }
+
+    // ========================================================================
+    // Monitoring & Analytics
+    // ========================================================================
+
+    pub fn health_check(env: Env) -> monitoring::HealthStatus {
+        monitoring::health_check(&env)
+    }
+
+    pub fn get_analytics(env: Env) -> monitoring::Analytics {
+        Self::require_monitoring();
+        Self::require_schema(&env, monitoring::SCHEMA);
+        monitoring::get_analytics(&env)
+    }
+
+    /// Operations whose tracking governance has overridden, with whether
+    /// each is tracked. Operations not listed are tracked.
+    pub fn get_tracking_config(env: Env) -> Map<Symbol, bool> {
+        monitoring::get_tracking_overrides(&env)
+    }
+
+    /// Turn metric and performance tracking of `operation` (e.g. `set_ver`)
+    /// on or off. Governance only; multisig deployments use a
+    /// `ProposalAction::SetTracking` bundle.
+    pub fn set_tracking(env: Env, operation: Symbol, enabled: bool) {
+        Self::require_function_enabled(&env, "set_tracking");
+        grainlify_upgradeable::require_governance(&env);
+        Self::require_not_read_only(&env);
+        monitoring::set_tracking(&env, operation, enabled);
+    }
+
+    /// Companion metrics contract tracked calls are forwarded to, if any.
+    pub fn get_metrics_contract(env: Env) -> Option<Address> {
+        monitoring::get_metrics_contract(&env)
+    }
+
+    /// Forward tracked calls to the `MetricsSink` contract `sink`, or keep
+    /// metrics internally again with `None`. A failing metrics contract
+    /// never fails the tracked call: it is recorded internally instead and
+    /// `("metric", "sink_err")` is published. Works in builds without the
+    /// `monitoring` feature. Publishes `("metric", "sink")`. Governance
+    /// only; multisig deployments use a `ProposalAction::SetMetricsContract`
+    /// or `ProposalAction::ClearMetricsContract` bundle.
+    pub fn set_metrics_contract(env: Env, sink: Option<Address>) {
+        Self::require_function_enabled(&env, "set_metrics_contract");
+        grainlify_upgradeable::require_governance(&env);
+        Self::require_not_read_only(&env);
+        Self::store_metrics_contract(&env, sink);
+    }
+
+    fn store_metrics_contract(env: &Env, sink: Option<Address>) {
+        if let Some(sink) = &sink {
+            Self::require_not_self(env, sink);
+        }
+        monitoring::set_metrics_contract(env, &sink);
+        env.events().publish((symbol_short!("metric"), symbol_short!("sink")), sink);
+    }
+
+    /// Distinct callers, split into accounts calling directly and contracts
+    /// relaying calls.
+    pub fn get_caller_stats(env: Env) -> monitoring::CallerStats {
+        Self::require_monitoring();
+        Self::require_schema(&env, monitoring::SCHEMA);
+        monitoring::get_caller_stats(&env)
+    }
+
+    /// Failures recorded with an error code, by operation and by code, next
+    /// to the global error count, for alerting on one function's failures.
+    pub fn get_failure_breakdown(env: Env) -> monitoring::FailureBreakdown {
+        Self::require_monitoring();
+        Self::require_schema(&env, monitoring::SCHEMA);
+        monitoring::get_failure_breakdown(&env)
+    }
+
+    /// Tracked operations while the contract was in governance mode `mode`
+    /// (`admin` or `multisig`), and the seconds spent in it. Lifetime
+    /// totals stay in `get_analytics`. Zeroed for any other symbol.
+    pub fn get_mode_stats(env: Env, mode: Symbol) -> monitoring::ModeStats {
+        Self::require_monitoring();
+        Self::require_schema(&env, monitoring::SCHEMA);
+        monitoring::get_mode_stats(&env, &mode)
+    }
+
+    /// Tracked operations and errors while `version` was the stored
+    /// version, and when it was. Zeroed for a version never stored.
+    pub fn get_version_stats(env: Env, version: u32) -> monitoring::VersionStats {
+        Self::require_monitoring();
+        Self::require_schema(&env, monitoring::SCHEMA);
+        monitoring::get_version_stats(&env, version)
+    }
+
+    /// Governance failures recorded per stage. A failing call reverts its
+    /// own writes, so these count the failures the contract absorbs: checks
+    /// that stop `attempt_propose_upgrade`, `attempt_approve_upgrade`,
+    /// `attempt_execute_upgrade` and `attempt_cancel_upgrade`, and fleet
+    /// children that reject an upgrade. Rejected transactions are only
+    /// visible off-chain.
+    pub fn get_governance_failure_stats(env: Env) -> monitoring::GovernanceFailureStats {
+        Self::require_monitoring();
+        Self::require_schema(&env, monitoring::SCHEMA);
+        monitoring::get_governance_failure_stats(&env)
+    }
+
+    pub fn get_state_snapshot(env: Env) -> monitoring::StateSnapshot {
+        Self::require_monitoring();
+        Self::require_schema(&env, monitoring::SCHEMA);
+        monitoring::get_state_snapshot(&env)
+    }
+
+    pub fn get_performance_stats(env: Env, function_name: Symbol) -> monitoring::PerformanceStats {
+        Self::require_monitoring();
+        monitoring::get_performance_stats(&env, function_name)
+    }
+
+    /// Page `page` (from 0) of up to `page_size` monitoring rows for
+    /// off-chain backups: `op_count`, `err_count` and `last_op_ts`, then
+    /// each tracked function's call count under its name. `page_size` is
+    /// capped at `MAX_PAGE_SIZE`; the export is complete at the first page
+    /// without `has_more`.
+    pub fn export_metrics(env: Env, page: u32, page_size: u32) -> paging::MetricsPage {
+        Self::require_monitoring();
+        Self::require_schema(&env, monitoring::SCHEMA);
+        monitoring::export_metrics(&env, page, page_size)
+    }
+
+    /// Restore rows from `export_metrics` for disaster recovery, e.g. into
+    /// a freshly deployed instance. Governance only, and only while no
+    /// total or function has been recorded; rows with more errors than
+    /// operations are rejected. Publishes `("metric", "import")` with the
+    /// number of rows. Multisig deployments use a
+    /// `ProposalAction::ImportMetrics` bundle.
+    pub fn import_metrics(env: Env, entries: Vec<(Symbol, u64)>) {
+        Self::require_function_enabled(&env, "import_metrics");
+        grainlify_upgradeable::require_governance(&env);
+        Self::require_not_read_only(&env);
+        Self::store_imported_metrics(&env, entries);
+    }
+
+    fn store_imported_metrics(env: &Env, entries: Vec<(Symbol, u64)>) {
+        Self::require_monitoring();
+        Self::require_schema(env, monitoring::SCHEMA);
+        let imported = monitoring::import_metrics(env, &entries);
+        env.events().publish((symbol_short!("metric"), symbol_short!("import")), imported);
+    }
+
+    pub fn check_invariants(env: Env) -> monitoring::InvariantReport {
+        monitoring::check_invariants(&env)
+    }
+
+    pub fn verify_invariants(env: Env) -> bool {
+        monitoring::verify_invariants(&env)
+    }
+
+    /// Cross-checks the contract's records against each other and returns
+    /// the name of every check that fails; empty means consistent. Anyone
+    /// may call it. Publishes `("invariant", "violated")` on failures.
+    ///
+    /// - `version`: the last version history entry is the stored version
+    /// - `upg_count`: the upgrade count covers every archived upgrade
+    ///   (admin upgrades are counted but not archived)
+    /// - `archive`: every executed upgrade proposal has an archive entry,
+    ///   unless the archive's retention deleted it. Each call examines up
+    ///   to `MAX_SWEEP_BATCH` proposal IDs, resuming after the last ID the
+    ///   previous call examined and wrapping back to 1 after the newest
+    /// - `pending`: no executed proposal is tracked as pending
+    /// - `threshold`: the multisig has at least `threshold` signers
+    /// - `metrics`: no monitoring counter is below the recorded snapshot
+    pub fn check_consistency(env: Env) -> Vec<Symbol> {
+        let mut violations = Vec::new(&env);
+
+        let history_len: u64 = env.storage().instance().get(&ArchiveKey::VersionHistoryCount).unwrap_or(0);
+        if history_len > 0 {
+            let last: Option<VersionChange> =
+                env.storage().persistent().get(&ArchiveKey::VersionHistory(history_len - 1));
+            if last.map(|entry| entry.version) != Some(grainlify_upgradeable::get_version(&env)) {
+                violations.push_back(symbol_short!("version"));
+            }
+        }
+
+        let archived = Self::executed_upgrade_count(env.clone());
+        if Self::get_upgrade_count(env.clone()) < archived {
+            violations.push_back(symbol_short!("upg_count"));
+        }
+
+        let newest = MultiSig::get_proposal_count(&env);
+        let mut cursor: u64 = env.storage().instance().get(&HealthKey::ConsistencyCursor).unwrap_or(1);
+        for _ in 0..(MAX_SWEEP_BATCH as u64).min(newest) {
+            if cursor > newest {
+                cursor = 1;
+            }
+            let proposal_id = cursor;
+            cursor += 1;
+            if grainlify_upgradeable::get_upgrade_hash(&env, proposal_id).is_none()
+                || MultiSig::get_execution(&env, proposal_id).is_none()
+            {
+                continue;
+            }
+            let index = env.storage().persistent().get::<_, u64>(&ArchiveKey::ArchiveIndex(proposal_id));
+            if index.is_some_and(|index| index < Self::history_first(&env, HistoryKind::Upgrades)) {
+                continue;
+            }
+            let entry = index.and_then(|index| Self::get_archived_upgrade(env.clone(), index));
+            if entry.map(|entry| entry.proposal_id) != Some(proposal_id) {
+                violations.push_back(symbol_short!("archive"));
+                break;
+            }
+        }
+        if newest > 0 {
+            env.storage().instance().set(&HealthKey::ConsistencyCursor, &cursor);
+        }
+
+        let pending = monitoring::pending_upgrades(&env);
+        if pending.iter().any(|id| MultiSig::get_execution(&env, id).is_some()) {
+            violations.push_back(symbol_short!("pending"));
+        }
+
+        if MultiSig::get_config_opt(&env).is_some_and(|config| config.signers.len() < config.threshold) {
+            violations.push_back(symbol_short!("threshold"));
+        }
+
+        if let Some(recorded) = monitoring::get_recorded_snapshot(&env) {
+            let current = monitoring::get_state_snapshot(&env);
+            if current.total_operations < recorded.total_operations
+                || current.total_users < recorded.total_users
+                || current.total_errors < recorded.total_errors
+            {
+                violations.push_back(symbol_short!("metrics"));
+            }
+        }
+
+        if !violations.is_empty() {
+            env.events().publish(
+                (symbol_short!("invariant"), symbol_short!("violated")),
+                ConsistencyViolationEvent {
+                    violations: violations.clone(),
+                    timestamp: env.ledger().timestamp(),
+                    event_version: EVENT_SCHEMA_VERSION,
+                },
+            );
+        }
+        violations
+    }
+
+    /// Returns the circuit breaker configuration, or `None` when it has never
+    /// been configured (breaker disabled).
+    pub fn get_circuit_breaker_config(env: Env) -> Option<monitoring::CircuitBreakerConfig> {
+        monitoring::get_circuit_breaker_config(&env)
+    }
+
+    /// Configure the monitoring circuit breaker. Governance only; multisig
+    /// deployments use a `ProposalAction::SetCircuitBreakerConfig` bundle.
+    ///
+    /// When enabled, the contract pauses itself once a limit is crossed.
+    /// Only failures of `attempt_propose_upgrade`, `attempt_approve_upgrade`,
+    /// `attempt_execute_upgrade` and `attempt_cancel_upgrade` called by the
+    /// admin or a signer count. Any other failing call reverts before it can
+    /// be counted, so spikes of ordinary errors never trip the breaker.
+    /// Attempts by other addresses, and failed subscriber or fleet-child
+    /// callbacks, are recorded but never counted, so they cannot pause the
+    /// contract. Successful upgrades, version changes and migrations reset
+    /// the failure streak. Resuming requires
+    /// an explicit `unpause` (or an approved unpause proposal). Pass
+    /// `enabled: false` to switch the breaker off.
+    pub fn set_circuit_breaker_config(env: Env, config: monitoring::CircuitBreakerConfig) {
+        Self::require_function_enabled(&env, "set_circuit_breaker_config");
+        Self::require_monitoring();
+        grainlify_upgradeable::require_governance(&env);
+        Self::require_not_read_only(&env);
+        Self::store_circuit_breaker_config(&env, config);
+    }
+
+    /// Validates and stores the circuit breaker config, publishing the change.
+    fn store_circuit_breaker_config(env: &Env, config: monitoring::CircuitBreakerConfig) {
+        Self::require_monitoring();
+        if config.max_error_rate_bps > 10_000 {
+            panic!("Circuit breaker error rate cannot exceed 10000 bps");
+        }
+        if config.max_error_rate_bps > 0 && config.window_size == 0 {
+            panic!("Circuit breaker window size must be non-zero when rate check is on");
+        }
+
+        env.storage().instance().set(&DataKey::CircuitBreakerConfig, &config);
+        env.events().publish(
+            (symbol_short!("cbreaker"), symbol_short!("config")),
+            config,
+        );
+    }
+
+    // ========================================================================
+    // Maintenance & Keeper Rewards
+    // ========================================================================
+
+    /// Extend the TTL of the monitoring counters. Pays `caller` the keeper
+    /// reward only when the bump was due; returns whether it was.
+    pub fn bump_metrics_ttl(env: Env, caller: Address) -> bool {
+        Self::require_function_enabled(&env, "bump_metrics_ttl");
+        Self::require_not_in_maintenance(&env);
+        Self::require_monitoring();
+        Self::require_schema(&env, monitoring::SCHEMA);
+        caller.require_auth();
+        let did_work = monitoring::bump_metrics_ttl(&env);
+        if did_work {
+            Self::pay_keeper_reward(&env, &caller, symbol_short!("ttl"));
+        }
+        did_work
+    }
+
+    /// Extend the contract instance and code TTL to `INSTANCE_TTL_EXTEND_TO`
+    /// ledgers and record the ledger it now lasts until, which calls compare
+    /// against the warning threshold. Anyone may call. Returns that ledger.
+    pub fn extend_instance_ttl(env: Env) -> u32 {
+        Self::require_function_enabled(&env, "extend_instance_ttl");
+        env.storage()
+            .instance()
+            .extend_ttl(INSTANCE_TTL_EXTEND_TO, INSTANCE_TTL_EXTEND_TO);
+        let extended_to = env.ledger().sequence().saturating_add(INSTANCE_TTL_EXTEND_TO);
+        env.storage().instance().set(&TtlKey::InstanceExtendedTo, &extended_to);
+        extended_to
+    }
+
+    /// Ledger the instance was last extended to by `extend_instance_ttl`.
+    pub fn get_instance_extended_to(env: Env) -> Option<u32> {
+        env.storage().instance().get(&TtlKey::InstanceExtendedTo)
+    }
+
+    pub fn get_ttl_warning_threshold(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&TtlKey::TtlWarningThreshold)
+            .unwrap_or(DEFAULT_TTL_WARNING_LEDGERS)
+    }
+
+    /// Set the remaining-ledger count below which calls publish
+    /// `("ttl", "low")`; 0 turns the warning off. Governance only.
+    pub fn set_ttl_warning_threshold(env: Env, ledgers: u32) {
+        Self::require_function_enabled(&env, "set_ttl_warning_threshold");
+        grainlify_upgradeable::require_governance(&env);
+        Self::require_not_read_only(&env);
+        env.storage().instance().set(&TtlKey::TtlWarningThreshold, &ledgers);
+        Self::record_config_change(&env, "ttl_warning_threshold", None);
+    }
+
+    /// Record a state snapshot once `SNAPSHOT_INTERVAL` has elapsed. Pays
+    /// `caller` the keeper reward only when a snapshot was recorded.
+    pub fn record_snapshot(env: Env, caller: Address) -> bool {
+        Self::require_function_enabled(&env, "record_snapshot");
+        Self::require_not_in_maintenance(&env);
+        Self::require_monitoring();
+        Self::require_schema(&env, monitoring::SCHEMA);
+        caller.require_auth();
+        let did_work = monitoring::record_snapshot(&env);
+        if did_work {
+            Self::pay_keeper_reward(&env, &caller, symbol_short!("snapshot"));
+        }
+        did_work
+    }
+
+    pub fn get_recorded_snapshot(env: Env) -> Option<monitoring::StateSnapshot> {
+        Self::require_monitoring();
+        Self::require_schema(&env, monitoring::SCHEMA);
+        monitoring::get_recorded_snapshot(&env)
+    }
+
+    /// Configure or clear keeper rewards. Governance only; multisig
+    /// deployments use a `ProposalAction::SetRewardConfig` or
+    /// `ClearRewardConfig` bundle.
+    ///
+    /// Clearing, or switching tokens, requires an empty pool; drain it
+    /// first with `withdraw_reward_pool`.
+    pub fn set_reward_config(env: Env, config: Option<KeeperRewardConfig>) {
+        Self::require_function_enabled(&env, "set_reward_config");
+        grainlify_upgradeable::require_governance(&env);
+        Self::require_not_read_only(&env);
+        Self::store_reward_config(&env, config);
+    }
+
+    /// Validates and stores the keeper reward config, publishing the change.
+    fn store_reward_config(env: &Env, config: Option<KeeperRewardConfig>) {
+        match &config {
+            Some(config) => {
+                if config.reward_per_call <= 0 {
+                    panic!("Reward per call must be positive");
+                }
+                let current = Self::get_reward_config(env.clone());
+                if current.is_some_and(|c| c.token != config.token)
+                    && Self::get_reward_pool_balance(env.clone()) > 0
+                {
+                    panic!("Reward pool must be empty to change token");
+                }
+                env.storage().instance().set(&DataKey::KeeperRewardConfig, config);
+            }
+            None => {
+                if Self::get_reward_pool_balance(env.clone()) > 0 {
+                    panic!("Reward pool must be empty to disable rewards");
+                }
+                env.storage().instance().remove(&DataKey::KeeperRewardConfig);
+            }
+        }
+        env.events().publish((symbol_short!("keeper"), symbol_short!("config")), config);
+    }
+
+    pub fn get_reward_config(env: Env) -> Option<KeeperRewardConfig> {
+        env.storage().instance().get(&DataKey::KeeperRewardConfig)
+    }
+
+    /// Transfer `amount` of the reward token from `funder` into the pool.
+    pub fn fund_rewards(env: Env, funder: Address, amount: i128) {
+        Self::require_function_enabled(&env, "fund_rewards");
+        Self::require_not_in_maintenance(&env);
+        funder.require_auth();
+        if amount <= 0 {
+            panic!("Funding amount must be positive");
+        }
+        let config = Self::get_reward_config(env.clone())
+            .unwrap_or_else(|| panic!("Keeper rewards not configured"));
+        token::Client::new(&env, &config.token).transfer(
+            &funder,
+            &env.current_contract_address(),
+            &amount,
+        );
+        let balance = Self::get_reward_pool_balance(env.clone()) + amount;
+        env.storage().instance().set(&DataKey::KeeperRewardPool, &balance);
+        env.events().publish(
+            (symbol_short!("keeper"), symbol_short!("fund")),
+            (funder, amount, balance),
+        );
+    }
+
+    /// Transfer `amount` of the reward token out of the pool to `to`.
+    /// Governance only; multisig deployments use a
+    /// `ProposalAction::WithdrawRewardPool` bundle.
+    pub fn withdraw_reward_pool(env: Env, to: Address, amount: i128) {
+        Self::require_function_enabled(&env, "withdraw_reward_pool");
+        grainlify_upgradeable::require_governance(&env);
+        Self::require_not_read_only(&env);
+        Self::pay_out_reward_pool(&env, to, amount);
+    }
+
+    fn pay_out_reward_pool(env: &Env, to: Address, amount: i128) {
+        if amount <= 0 {
+            panic!("Withdrawal amount must be positive");
+        }
+        let config = Self::get_reward_config(env.clone())
+            .unwrap_or_else(|| panic!("Keeper rewards not configured"));
+        let balance = Self::get_reward_pool_balance(env.clone());
+        if amount > balance {
+            panic!("Withdrawal exceeds reward pool");
+        }
+        let balance = balance - amount;
+        env.storage().instance().set(&DataKey::KeeperRewardPool, &balance);
+        token::Client::new(env, &config.token).transfer(
+            &env.current_contract_address(),
+            &to,
+            &amount,
+        );
+        env.events().publish(
+            (symbol_short!("keeper"), symbol_short!("withdraw")),
+            (to, amount, balance),
+        );
+    }
+
+    pub fn get_reward_pool_balance(env: Env) -> i128 {
+        env.storage()
+            .instance()
+            .get(&DataKey::KeeperRewardPool)
+            .unwrap_or(0)
+    }
+
+    // ========================================================================
+    // Token-Holder Governance
+    // ========================================================================
+
+    /// Switch upgrade approval to token-holder voting, or back to the
+    /// multisig with `None`. Governance only; multisig deployments use a
+    /// `ProposalAction::SetTokenVotingConfig` bundle. Action bundles need
+    /// multisig approval, so while token voting is active only the admin,
+    /// or an upgrade, can switch it back.
+    ///
+    /// Proposals opened under one mode cannot be executed under the other.
+    /// The token must implement `SnapshotTokenInterface`; a plain SEP-41
+    /// token such as a Stellar Asset Contract is rejected.
+    pub fn set_token_voting_config(env: Env, config: Option<TokenVotingConfig>) {
+        Self::require_function_enabled(&env, "set_token_voting_config");
+        grainlify_upgradeable::require_governance(&env);
+        Self::require_not_read_only(&env);
+        Self::store_token_voting_config(&env, config);
+    }
+
+    /// Validates and stores the token voting config, publishing the change.
+    fn store_token_voting_config(env: &Env, config: Option<TokenVotingConfig>) {
+        TokenVoting::set_config(env, config.clone());
+        env.events().publish((symbol_short!("tgov"), symbol_short!("config")), config);
+    }
+
+    pub fn get_token_voting_config(env: Env) -> Option<TokenVotingConfig> {
+        TokenVoting::get_config(&env)
+    }
+
+    /// Vote on a token-governed upgrade proposal while its window is open.
+    pub fn vote(env: Env, proposal_id: u64, voter: Address, support: bool) {
+        Self::require_function_enabled(&env, "vote");
+        Self::require_not_paused(&env);
+        TokenVoting::vote(&env, proposal_id, voter, support);
+    }
+
+    pub fn has_voted(env: Env, proposal_id: u64, voter: Address) -> bool {
+        TokenVoting::has_voted(&env, proposal_id, voter)
+    }
+
+    pub fn get_token_proposal(env: Env, proposal_id: u64) -> Option<TokenProposal> {
+        TokenVoting::get_proposal(&env, proposal_id)
+    }
+
+    // ========================================================================
+    // Audit Attestations
+    // ========================================================================
+
+    /// Configure the auditor list and whether upgrades require attestations.
+    /// Governance only; multisig deployments use a
+    /// `ProposalAction::SetAttestationConfig` bundle.
+    pub fn set_attestation_config(env: Env, config: AttestationConfig) {
+        Self::require_function_enabled(&env, "set_attestation_config");
+        grainlify_upgradeable::require_governance(&env);
+        Self::require_not_read_only(&env);
+        Self::store_attestation_config(&env, config);
+    }
+
+    /// Validates and stores the attestation config, publishing the change.
+    fn store_attestation_config(env: &Env, config: AttestationConfig) {
+        if config.min_attestations > config.auditors.len() {
+            panic!("Required attestations exceed auditor count");
+        }
+        if config.require_attestation && config.min_attestations == 0 {
+            panic!("Required attestations must be non-zero when enforced");
+        }
+
+        env.storage().instance().set(&DataKey::AttestationConfig, &config);
+        env.events().publish((symbol_short!("attest"), symbol_short!("config")), config);
+    }
+
+    pub fn get_attestation_config(env: Env) -> Option<AttestationConfig> {
+        env.storage().instance().get(&DataKey::AttestationConfig)
+    }
+
+    /// Record `auditor`'s sign-off on `wasm_hash`. Re-attesting replaces the
+    /// auditor's earlier report digest.
+    pub fn attest_wasm(env: Env, wasm_hash: BytesN<32>, auditor: Address, report_digest: BytesN<32>) {
+        Self::require_function_enabled(&env, "attest_wasm");
+        auditor.require_auth();
+        let config = Self::get_attestation_config(env.clone())
+            .unwrap_or_else(|| panic!("Attestations not configured"));
+        if !config.auditors.contains(&auditor) {
+            panic!("Not a configured auditor");
+        }
+
+        let mut attestations = Self::without_auditor(&env, &wasm_hash, &auditor);
+        attestations.push_back(Attestation {
+            auditor: auditor.clone(),
+            report_digest: report_digest.clone(),
+            attested_at: env.ledger().timestamp(),
+        });
+        env.storage()
+            .persistent()
+            .set(&DataKey::WasmAttestations(wasm_hash.clone()), &attestations);
+        env.events().publish(
+            (symbol_short!("attest"), symbol_short!("add")),
+            (wasm_hash, auditor, report_digest),
+        );
+    }
+
+    /// Withdraw `auditor`'s attestation for `wasm_hash`.
+    pub fn revoke_attestation(env: Env, wasm_hash: BytesN<32>, auditor: Address) {
+        Self::require_function_enabled(&env, "revoke_attestation");
+        auditor.require_auth();
+
+        let attestations = Self::without_auditor(&env, &wasm_hash, &auditor);
+        if attestations.len() == Self::get_attestations(env.clone(), wasm_hash.clone()).len() {
+            return;
+        }
+        env.storage()
+            .persistent()
+            .set(&DataKey::WasmAttestations(wasm_hash.clone()), &attestations);
+        env.events().publish(
+            (symbol_short!("attest"), symbol_short!("revoke")),
+            (wasm_hash, auditor),
+        );
+    }
+
+    pub fn get_attestations(env: Env, wasm_hash: BytesN<32>) -> Vec<Attestation> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::WasmAttestations(wasm_hash))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    // ========================================================================
+    // Deployed Contract Registry
+    // ========================================================================
+
+    /// Record a deployed contract. `name` must fit the `label` string limit
+    /// (`LabelTooLong`).
+    pub fn register_deployed_contract(
+        env: Env,
+        address: Address,
+        name: String,
+        kind: ContractKind,
+        version: u32,
+    ) {
+        Self::require_function_enabled(&env, "register_deployed_contract");
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+        Self::require_not_read_only(&env);
+        limits::require_len(&env, limits::LABEL, &name);
+
+        let mut index: Vec<Address> = env
+            .storage()
+            .instance()
+            .get(&DataKey::DeployedContractIndex)
+            .unwrap_or(Vec::new(&env));
+
+        let existed = env
+            .storage()
+            .instance()
+            .has(&DataKey::DeployedContractEntry(address.clone()));
+
+        if !existed {
+            if index.len() >= MAX_DEPLOYED_CONTRACTS {
+                panic!("Registry full");
+            }
+            index.push_back(address.clone());
+        }
+
+        let entry = DeployedContract {
+            address: address.clone(),
+            name,
+            kind,
+            version,
+            deployed_at: env.ledger().timestamp(),
+        };
+
+        env.storage()
+            .instance()
+            .set(&DataKey::DeployedContractEntry(address), &entry);
+        env.storage()
+            .instance()
+            .set(&DataKey::DeployedContractIndex, &index);
+    }
+
+    pub fn deregister_deployed_contract(env: Env, address: Address) {
+        Self::require_function_enabled(&env, "deregister_deployed_contract");
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+        Self::require_not_read_only(&env);
+
+        let had_entry = env
+            .storage()
+            .instance()
+            .has(&DataKey::DeployedContractEntry(address.clone()));
+        if !had_entry {
+            return;
+        }
+
+        env.storage()
+            .instance()
+            .remove(&DataKey::DeployedContractEntry(address.clone()));
+
+        let index: Vec<Address> = env
+            .storage()
+            .instance()
+            .get(&DataKey::DeployedContractIndex)
+            .unwrap_or(Vec::new(&env));
+        let mut trimmed = Vec::new(&env);
+        for i in 0..index.len() {
+            let addr = index.get(i).unwrap();
+            if addr != address {
+                trimmed.push_back(addr);
+            }
+        }
+        env.storage()
+            .instance()
+            .set(&DataKey::DeployedContractIndex, &trimmed);
+    }
+
+    pub fn get_deployed_contract(env: Env, address: Address) -> Option<DeployedContract> {
+        env.storage()
+            .instance()
+            .get(&DataKey::DeployedContractEntry(address))
+    }
+
+    pub fn deployed_contract_count(env: Env) -> u32 {
+        let index: Vec<Address> = env
+            .storage()
+            .instance()
+            .get(&DataKey::DeployedContractIndex)
+            .unwrap_or(Vec::new(&env));
+        index.len()
+    }
+
+    /// Page of registered contracts from `offset` (default 0), in
+    /// registration order; see `paging`. `limit` defaults to
+    /// `MAX_PAGE_SIZE`.
+    pub fn list_deployed_contracts(
+        env: Env,
+        offset: Option<u32>,
+        limit: Option<u32>,
+    ) -> paging::DeployedContractPage {
+        let index: Vec<Address> = env
+            .storage()
+            .instance()
+            .get(&DataKey::DeployedContractIndex)
+            .unwrap_or(Vec::new(&env));
+        let page = paging::range(
+            offset.unwrap_or(0) as u64,
+            limit.unwrap_or(paging::MAX_PAGE_SIZE),
+            index.len() as u64,
+        );
+
+        let mut items: Vec<DeployedContract> = Vec::new(&env);
+        for i in page.start as u32..page.end as u32 {
+            let addr = index.get(i).unwrap();
+            if let Some(entry) = env
+                .storage()
+                .instance()
+                .get::<_, DeployedContract>(&DataKey::DeployedContractEntry(addr))
+            {
+                items.push_back(entry);
+            }
+        }
+        paging::DeployedContractPage { items, has_more: page.has_more }
+    }
+
+    // ========================================================================
+    // Deployment Name Registry
+    // ========================================================================
+
+    /// Map `name` to `address`. Governance only; multisig deployments use a
+    /// `ProposalAction::RegisterDeployment` bundle.
+    ///
+    /// An existing name is only replaced when `overwrite` is set; replacing
+    /// resets the recorded version because it describes the old address.
+    pub fn register_deployment(env: Env, name: Symbol, address: Address, overwrite: bool) {
+        Self::require_function_enabled(&env, "register_deployment");
+        grainlify_upgradeable::require_governance(&env);
+        Self::require_not_read_only(&env);
+        Self::store_deployment(&env, name, address, overwrite);
+    }
+
+    fn store_deployment(env: &Env, name: Symbol, address: Address, overwrite: bool) {
+        let key = DataKey::DeploymentByName(name.clone());
+        if env.storage().persistent().has(&key) {
+            if !overwrite {
+                panic!("Deployment name already registered");
+            }
+        } else {
+            let mut names = Self::deployment_names(env);
+            if names.len() >= MAX_DEPLOYED_CONTRACTS {
+                panic!("Registry full");
+            }
+            names.push_back(name.clone());
+            env.storage().persistent().set(&DataKey::DeploymentNames, &names);
+        }
+
+        let info = DeploymentInfo {
+            name,
+            address,
+            version: 0,
+            updated_at: env.ledger().timestamp(),
+        };
+        env.storage().persistent().set(&key, &info);
+        env.events().publish((symbol_short!("deploy"), symbol_short!("register")), info);
+    }
+
+    /// Remove `name` from the registry. Governance only; multisig
+    /// deployments use a `ProposalAction::UnregisterDeployment` bundle.
+    /// Panics if unknown.
+    pub fn unregister_deployment(env: Env, name: Symbol) {
+        Self::require_function_enabled(&env, "unregister_deployment");
+        grainlify_upgradeable::require_governance(&env);
+        Self::require_not_read_only(&env);
+        Self::remove_deployment(&env, name);
+    }
+
+    fn remove_deployment(env: &Env, name: Symbol) {
+        let key = DataKey::DeploymentByName(name.clone());
+        let info: DeploymentInfo = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or_else(|| panic!("Deployment not found"));
+        env.storage().persistent().remove(&key);
+
+        let mut names = Self::deployment_names(env);
+        if let Some(index) = names.first_index_of(&name) {
+            names.remove(index);
+        }
+        env.storage().persistent().set(&DataKey::DeploymentNames, &names);
+        env.events().publish((symbol_short!("deploy"), symbol_short!("remove")), info);
+    }
+
+    /// Record the version currently running under `name`. Governance only;
+    /// multisig deployments use a `ProposalAction::SetDeploymentVersion`
+    /// bundle.
+    pub fn set_deployment_version(env: Env, name: Symbol, version: u32) {
+        Self::require_function_enabled(&env, "set_deployment_version");
+        grainlify_upgradeable::require_governance(&env);
+        Self::require_not_read_only(&env);
+        Self::store_deployment_version(&env, name, version);
     }
 
-    fn set_counter(env: &Env, key: &str, value: u64) {
-        env.storage()
+    fn store_deployment_version(env: &Env, name: Symbol, version: u32) {
+        let key = DataKey::DeploymentByName(name);
+        let mut info: DeploymentInfo = env
+            .storage()
             .persistent()
-            .set(&Symbol::new(env, key), &value);
+            .get(&key)
+            .unwrap_or_else(|| panic!("Deployment not found"));
+        info.version = version;
+        info.updated_at = env.ledger().timestamp();
+        env.storage().persistent().set(&key, &info);
+        env.events().publish((symbol_short!("deploy"), symbol_short!("version")), info);
     }
 
-    fn get_tracked_users(env: &Env) -> Vec<Address> {
-        env.storage()
-            .persistent()
-            .get(&Symbol::new(env, USER_INDEX))
-            .unwrap_or(Vec::new(env))
+    /// Resolve a deployment name to its address. Panics if unknown.
+    pub fn resolve(env: Env, name: Symbol) -> Address {
+        Self::get_deployment_info(env, name)
+            .map(|info| info.address)
+            .unwrap_or_else(|| panic!("Deployment not found"))
     }
 
-    fn track_unique_user(env: &Env, caller: &Address) {
-        let mut users = get_tracked_users(env);
-        for index in 0..users.len() {
-            if users.get(index).unwrap() == *caller {
-                return;
+    pub fn get_deployment_info(env: Env, name: Symbol) -> Option<DeploymentInfo> {
+        env.storage().persistent().get(&DataKey::DeploymentByName(name))
+    }
+
+    /// Page of deployments from `start`, in registration order; see `paging`.
+    pub fn list_deployments(env: Env, start: u32, limit: u32) -> paging::DeploymentPage {
+        let names = Self::deployment_names(&env);
+        let page = paging::range(start as u64, limit, names.len() as u64);
+        let mut items: Vec<DeploymentInfo> = Vec::new(&env);
+        for i in page.start as u32..page.end as u32 {
+            if let Some(info) = Self::get_deployment_info(env.clone(), names.get(i).unwrap()) {
+                items.push_back(info);
             }
         }
-        if users.len() >= MAX_TRACKED_USERS {
-            set_counter(env, USER_COUNT, MAX_TRACKED_USERS as u64);
-            return;
-        }
-        users.push_back(caller.clone());
+        paging::DeploymentPage { items, has_more: page.has_more }
+    }
+
+    fn deployment_names(env: &Env) -> Vec<Symbol> {
         env.storage()
             .persistent()
-            .set(&Symbol::new(env, USER_INDEX), &users);
-        set_counter(env, USER_COUNT, users.len().into());
+            .get(&DataKey::DeploymentNames)
+            .unwrap_or(Vec::new(env))
     }
 
-    /// [FIX-H03] Dynamic semver decoding — handles any version, not just hardcoded ones
-    fn version_semver_string(env: &Env) -> String {
-        let raw: u32 = env.storage().instance().get(&DataKey::Version).unwrap_or(0);
-        // Promote legacy single-digit versions (1,2,...) to major.0.0 encoding
-        let encoded = if raw >= 10_000 { raw } else { raw.saturating_mul(10_000) };
-        let major = encoded / 10_000;
-        let minor = (encoded % 10_000) / 100;
-        let patch = encoded % 100;
+    // ========================================================================
+    // Emergency Controls
+    // ========================================================================
 
-        // Build semver string without heap alloc
-        let mut buf = [0u8; 12];
-        let mut pos = 0usize;
+    /// Pause upgrade activity and version changes.
+    ///
+    /// Callable by the admin or by any single multisig signer so an incident can
+    /// be contained without waiting for a full approval round.
+    pub fn pause(env: Env, caller: Address) {
+        Self::require_function_enabled(&env, "pause");
+        Self::require_admin_or_signer(&env, &caller);
+        MultiSig::set_paused(&env, true);
+        Self::emit_pause_event(&env, true, caller, None);
+    }
 
-        macro_rules! write_u32 {
-            ($n:expr) => {
-                let n: u32 = $n;
-                if n >= 100 { buf[pos] = b'0' + (n / 100) as u8; pos += 1; }
-                if n >= 10 { buf[pos] = b'0' + ((n % 100) / 10) as u8; pos += 1; }
-                buf[pos] = b'0' + (n % 10) as u8; pos += 1;
-            };
+    /// Resume paused operations. Admin only.
+    ///
+    /// Multisig deployments without an admin must go through
+    /// `propose_unpause` / `approve_unpause` instead — a single signer can
+    /// pause, but resuming deliberately requires the full threshold.
+    pub fn unpause(env: Env, caller: Address) {
+        Self::require_function_enabled(&env, "unpause");
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic!("{}", ContractError::NotAdmin as u32));
+        if admin != caller {
+            panic!("{}", ContractError::NotAdmin as u32);
         }
+        caller.require_auth();
+        MultiSig::set_paused(&env, false);
+        Self::emit_pause_event(&env, false, caller, None);
+    }
 
-        write_u32!(major);
-        buf[pos] = b'.'; pos += 1;
-        write_u32!(minor);
-        buf[pos] = b'.'; pos += 1;
-        write_u32!(patch);
+    /// Propose lifting the pause via multisig. Returns the proposal ID.
+    pub fn propose_unpause(env: Env, proposer: Address, expiry: u64) -> u64 {
+        Self::require_function_enabled(&env, "propose_unpause");
+        Self::ensure_proposal_storage(&env);
+        let proposal_id = MultiSig::propose(&env, proposer, expiry);
+        Self::record_proposal_created(&env, proposal_id);
+        env.storage().persistent().set(&DataKey::UnpauseProposal(proposal_id), &true);
+        proposal_id
+    }
 
-        let s = core::str::from_utf8(&buf[..pos]).unwrap_or("0.0.0");
-        String::from_str(env, s)
+    /// Approve an unpause proposal. The pause is lifted as soon as the
+    /// threshold is met; no timelock applies.
+    pub fn approve_unpause(env: Env, proposal_id: u64, signer: Address) {
+        Self::require_function_enabled(&env, "approve_unpause");
+        Self::ensure_proposal_storage(&env);
+        if !env.storage().persistent().has(&DataKey::UnpauseProposal(proposal_id)) {
+            panic!("{}", ContractError::ProposalNotFound as u32);
+        }
+        MultiSig::approve(&env, proposal_id, signer.clone());
+
+        let threshold = MultiSig::get_config_opt(&env).map(|c| c.threshold).unwrap_or(0);
+        let approvals = MultiSig::get_proposal_opt(&env, proposal_id)
+            .map(|p| p.approvals.len())
+            .unwrap_or(0);
+        if approvals >= threshold {
+            // Clear the flag first so the execution bookkeeping is not itself blocked.
+            MultiSig::set_paused(&env, false);
+            MultiSig::mark_executed(&env, proposal_id);
+            Self::record_threshold_met(&env, proposal_id);
+            Self::record_proposal_executed(&env, proposal_id);
+            Self::emit_pause_event(&env, false, signer, Some(proposal_id));
+        }
     }
 
-    pub fn track_operation(env: &Env, operation: Symbol, caller: Address, success: bool) {
-        let count = get_counter(env, OPERATION_COUNT);
-        set_counter(env, OPERATION_COUNT, count.saturating_add(1));
-        set_counter(env, LAST_OPERATION_TS, env.ledger().timestamp());
-        track_unique_user(env, &caller);
-        if !success {
-            let err_count = get_counter(env, ERROR_COUNT);
-            set_counter(env, ERROR_COUNT, err_count.saturating_add(1));
+    pub fn is_paused(env: Env) -> bool {
+        MultiSig::is_contract_paused(&env)
+    }
+
+    /// Unified liveness watchdog view — no auth required, never panics.
+    ///
+    /// Returns a `LivenessStatus` snapshot combining pause state, read-only
+    /// mode, monitoring health, last-ping timestamp, version, and admin
+    /// presence.  Designed for polling by monitoring agents, circuit breakers,
+    /// and dashboards.
+    ///
+    /// # Upgrade Safety
+    /// `schema_version` reflects `LivenessSchemaVersion` written at `init_admin`.
+    /// Returns `0` on legacy deployments where the marker was never written.
+    pub fn liveness_watchdog(env: Env) -> LivenessStatus {
+        let is_paused = MultiSig::is_contract_paused(&env);
+        let is_read_only: bool = env
+            .storage()
+            .instance()
+            .get(&DataKey::ReadOnlyMode)
+            .unwrap_or(false);
+        let version: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::Version)
+            .unwrap_or(0);
+        let healthy = monitoring::check_invariants(&env).healthy;
+        let last_ping_ts: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey::WatchdogLastPing)
+            .unwrap_or(0);
+        LivenessStatus {
+            paused: is_paused,
+            read_only: is_read_only,
+            healthy,
+            last_ping_ts,
+            is_paused,
+            is_read_only,
+            is_operational: !is_paused && !is_read_only,
+            version,
+            admin_set: env.storage().instance().has(&DataKey::Admin),
+            schema_version: env
+                .storage()
+                .instance()
+                .get(&DataKey::LivenessSchemaVersion)
+                .unwrap_or(0),
+            timestamp: env.ledger().timestamp(),
         }
+    }
+
+    pub fn can_execute(env: Env, proposal_id: u64) -> bool {
+        Self::ensure_proposal_storage(&env);
+        MultiSig::can_execute(&env, proposal_id)
+    }
+
+    fn emit_pause_event(env: &Env, paused: bool, caller: Address, proposal_id: Option<u64>) {
+        let topic = if paused { symbol_short!("paused") } else { symbol_short!("unpause") };
         env.events().publish(
-            (symbol_short!("metric"), symbol_short!("op")),
-            OperationMetric { operation, caller, timestamp: env.ledger().timestamp(), success },
+            (topic,),
+            PauseEvent {
+                paused,
+                caller,
+                proposal_id,
+                timestamp: env.ledger().timestamp(),
+                event_version: EVENT_SCHEMA_VERSION,
+            },
         );
     }
 
-    pub fn emit_performance(env: &Env, function: Symbol, duration: u64) {
-        let index_key = Symbol::new(env, "perf_index");
-        let mut index: Vec<Symbol> = env
-            .storage().persistent().get(&index_key).unwrap_or(Vec::new(env));
+    // ========================================================================
+    // Guardian Emergency Pause
+    // ========================================================================
 
-        let mut already_tracked = false;
-        for i in 0..index.len() {
-            if index.get(i).unwrap() == function { already_tracked = true; break; }
+    /// Address allowed to call `guardian_pause`, if any.
+    pub fn get_guardian(env: Env) -> Option<Address> {
+        env.storage().instance().get(&GuardianKey::Guardian)
+    }
+
+    /// Appoint `guardian`, replacing any previous one. Publishes
+    /// `("guardian", "set")`. Governance only; multisig deployments use a
+    /// `ProposalAction::SetGuardian` bundle. Fails with `SelfReference` for
+    /// this contract's address.
+    pub fn set_guardian(env: Env, guardian: Address) {
+        Self::require_function_enabled(&env, "set_guardian");
+        grainlify_upgradeable::require_governance(&env);
+        Self::require_not_read_only(&env);
+        Self::store_guardian(&env, guardian);
+    }
+
+    /// Remove the guardian. An active emergency pause keeps running until
+    /// it expires or governance ends it. Governance only; multisig
+    /// deployments use a `ProposalAction::ClearGuardian` bundle.
+    pub fn clear_guardian(env: Env) {
+        Self::require_function_enabled(&env, "clear_guardian");
+        grainlify_upgradeable::require_governance(&env);
+        Self::require_not_read_only(&env);
+        Self::remove_guardian(&env);
+    }
+
+    fn store_guardian(env: &Env, guardian: Address) {
+        Self::require_not_self(env, &guardian);
+        env.storage().instance().set(&GuardianKey::Guardian, &guardian);
+        env.events().publish((symbol_short!("guardian"), symbol_short!("set")), guardian);
+    }
+
+    fn remove_guardian(env: &Env) {
+        if let Some(guardian) = Self::get_guardian(env.clone()) {
+            env.storage().instance().remove(&GuardianKey::Guardian);
+            env.events().publish((symbol_short!("guardian"), symbol_short!("cleared")), guardian);
         }
+    }
 
-        if !already_tracked {
-            if index.len() >= MAX_TRACKED_FUNCTIONS {
-                let oldest = index.get(0).unwrap();
-                env.storage().persistent().remove(&(Symbol::new(env, "perf_cnt"), oldest.clone()));
-                env.storage().persistent().remove(&(Symbol::new(env, "perf_time"), oldest.clone()));
-                env.storage().persistent().remove(&(Symbol::new(env, "perf_last"), oldest.clone()));
-                let mut trimmed = Vec::new(env);
-                for i in 1..index.len() { trimmed.push_back(index.get(i).unwrap()); }
-                index = trimmed;
+    pub fn get_emergency_pause_settings(env: Env) -> EmergencyPauseSettings {
+        env.storage().instance().get(&GuardianKey::Settings).unwrap_or(EmergencyPauseSettings {
+            duration: DEFAULT_EMERGENCY_PAUSE_DURATION,
+            cooldown: DEFAULT_EMERGENCY_PAUSE_COOLDOWN,
+        })
+    }
+
+    /// Set how long later guardian pauses last and how far apart they must
+    /// start. Panics for a zero duration or a cooldown shorter than the
+    /// duration. Governance only; multisig deployments use a
+    /// `ProposalAction::SetEmergencyPauseSettings` bundle.
+    pub fn set_emergency_pause_settings(env: Env, settings: EmergencyPauseSettings) {
+        Self::require_function_enabled(&env, "set_emergency_pause_settings");
+        grainlify_upgradeable::require_governance(&env);
+        Self::require_not_read_only(&env);
+        Self::store_emergency_pause_settings(&env, settings);
+    }
+
+    fn store_emergency_pause_settings(env: &Env, settings: EmergencyPauseSettings) {
+        if settings.duration == 0 || settings.cooldown < settings.duration {
+            panic!("Invalid emergency pause settings");
+        }
+        env.storage().instance().set(&GuardianKey::Settings, &settings);
+    }
+
+    /// Guardian: halt upgrade execution and admin changes for the
+    /// configured duration without any governance key. Reads, proposals
+    /// and approvals keep working. Fails while a pause is active or within
+    /// the cooldown of the previous one. Publishes `("emergency", "paused")`.
+    pub fn guardian_pause(env: Env) {
+        Self::require_function_enabled(&env, "guardian_pause");
+        let guardian = Self::get_guardian(env.clone()).unwrap_or_else(|| panic!("No guardian set"));
+        guardian.require_auth();
+        let now = env.ledger().timestamp();
+        let settings = Self::get_emergency_pause_settings(env.clone());
+        if let Some(last) = env.storage().instance().get::<_, EmergencyPause>(&GuardianKey::LastPause) {
+            if now < last.expires_at {
+                panic!("Emergency pause already active");
+            }
+            if now < last.started_at.saturating_add(settings.cooldown) {
+                panic!("Emergency pause cooldown active");
             }
-            index.push_back(function.clone());
-            env.storage().persistent().set(&index_key, &index);
         }
+        let pause = EmergencyPause {
+            guardian,
+            started_at: now,
+            expires_at: now.saturating_add(settings.duration),
+        };
+        env.storage().instance().set(&GuardianKey::LastPause, &pause);
+        Self::emit_emergency_event(&env, symbol_short!("paused"), &pause);
+    }
 
-        let count_key = (Symbol::new(env, "perf_cnt"), function.clone());
-        let time_key = (Symbol::new(env, "perf_time"), function.clone());
-        let last_key = (Symbol::new(env, "perf_last"), function.clone());
-        let count: u64 = env.storage().persistent().get(&count_key).unwrap_or(0);
-        let total: u64 = env.storage().persistent().get(&time_key).unwrap_or(0);
-        let timestamp = env.ledger().timestamp();
-        env.storage().persistent().set(&count_key, &count.saturating_add(1));
-        env.storage().persistent().set(&time_key, &total.saturating_add(duration));
-        env.storage().persistent().set(&last_key, &timestamp);
-        env.events().publish(
-            (symbol_short!("metric"), symbol_short!("perf")),
-            PerformanceMetric { function, duration, timestamp },
-        );
+    /// Push the active emergency pause's expiry back by `seconds`.
+    /// Publishes `("emergency", "extended")`. Governance only; multisig
+    /// deployments use a `ProposalAction::ExtendEmergencyPause` bundle.
+    pub fn extend_emergency_pause(env: Env, seconds: u64) {
+        Self::require_function_enabled(&env, "extend_emergency_pause");
+        grainlify_upgradeable::require_governance(&env);
+        Self::push_back_emergency_pause(&env, seconds);
     }
 
-    pub fn health_check(env: &Env) -> HealthStatus {
-        let report = check_invariants(env);
-        HealthStatus {
-            is_healthy: report.healthy,
-            last_operation: get_counter(env, LAST_OPERATION_TS),
-            total_operations: report.operation_count,
-            contract_version: version_semver_string(env), // [FIX-H03] now dynamic
-        }
+    /// End the active emergency pause early. The cooldown still runs from
+    /// its start. Publishes `("emergency", "ended")`. Governance only;
+    /// multisig deployments use a `ProposalAction::EndEmergencyPause`
+    /// bundle, which an emergency pause does not hold up.
+    pub fn end_emergency_pause(env: Env) {
+        Self::require_function_enabled(&env, "end_emergency_pause");
+        grainlify_upgradeable::require_governance(&env);
+        Self::stop_emergency_pause(&env);
     }
 
-    pub fn get_analytics(env: &Env) -> Analytics {
-        let ops = get_counter(env, OPERATION_COUNT);
-        let users = get_counter(env, USER_COUNT);
-        let errors = get_counter(env, ERROR_COUNT);
-        let error_rate = if ops > 0 {
-            ((errors as u128 * 10000) / ops as u128) as u32
-        } else { 0 };
-        Analytics { operation_count: ops, unique_users: users, error_count: errors, error_rate }
+    fn push_back_emergency_pause(env: &Env, seconds: u64) {
+        let mut pause = Self::active_emergency_pause(env).unwrap_or_else(|| panic!("No emergency pause active"));
+        pause.expires_at = pause.expires_at.saturating_add(seconds);
+        env.storage().instance().set(&GuardianKey::LastPause, &pause);
+        Self::emit_emergency_event(env, symbol_short!("extended"), &pause);
     }
 
-    pub fn get_state_snapshot(env: &Env) -> StateSnapshot {
-        StateSnapshot {
-            timestamp: env.ledger().timestamp(),
-            total_operations: get_counter(env, OPERATION_COUNT),
-            total_users: get_counter(env, USER_COUNT),
-            total_errors: get_counter(env, ERROR_COUNT),
+    fn stop_emergency_pause(env: &Env) {
+        let mut pause = Self::active_emergency_pause(env).unwrap_or_else(|| panic!("No emergency pause active"));
+        pause.expires_at = env.ledger().timestamp();
+        env.storage().instance().set(&GuardianKey::LastPause, &pause);
+        Self::emit_emergency_event(env, symbol_short!("ended"), &pause);
+    }
+
+    /// The guardian emergency pause in force, if any.
+    pub fn get_emergency_pause(env: Env) -> Option<EmergencyPause> {
+        Self::active_emergency_pause(&env)
+    }
+
+    fn active_emergency_pause(env: &Env) -> Option<EmergencyPause> {
+        env.storage()
+            .instance()
+            .get::<_, EmergencyPause>(&GuardianKey::LastPause)
+            .filter(|pause| env.ledger().timestamp() < pause.expires_at)
+    }
+
+    fn require_no_emergency_pause(env: &Env) {
+        if Self::active_emergency_pause(env).is_some() {
+            panic!("Emergency pause active");
         }
     }
 
-    pub fn get_performance_stats(env: &Env, function_name: Symbol) -> PerformanceStats {
-        let count_key = (Symbol::new(env, "perf_cnt"), function_name.clone());
-        let time_key = (Symbol::new(env, "perf_time"), function_name.clone());
-        let last_key = (Symbol::new(env, "perf_last"), function_name.clone());
-        let count: u64 = env.storage().persistent().get(&count_key).unwrap_or(0);
-        let total: u64 = env.storage().persistent().get(&time_key).unwrap_or(0);
-        let last: u64 = env.storage().persistent().get(&last_key).unwrap_or(0);
-        let avg = if count > 0 { total / count } else { 0 };
-        PerformanceStats { function_name, call_count: count, total_time: total, avg_time: avg, last_called: last }
+    fn emit_emergency_event(env: &Env, action: Symbol, pause: &EmergencyPause) {
+        env.events().publish(
+            (symbol_short!("emergency"), action),
+            EmergencyPauseEvent {
+                guardian: pause.guardian.clone(),
+                started_at: pause.started_at,
+                expires_at: pause.expires_at,
+                timestamp: env.ledger().timestamp(),
+                event_version: EVENT_SCHEMA_VERSION,
+            },
+        );
     }
 
-    pub fn check_invariants(env: &Env) -> InvariantReport {
-        let operation_count: u64 = get_counter(env, OPERATION_COUNT);
-        let unique_users: u64 = get_counter(env, USER_COUNT);
-        let error_count: u64 = get_counter(env, ERROR_COUNT);
+    // ========================================================================
+    // Liveness Watchdog
+    // ========================================================================
 
-        let metrics_sane = error_count <= operation_count
-            && unique_users <= operation_count
-            && (operation_count > 0 || (unique_users == 0 && error_count == 0));
+    /// Admin: record a liveness ping — updates `WatchdogLastPing` timestamp.
+    ///
+    /// Allows off-chain monitors to prove the contract is reachable and the
+    /// admin key is live. Blocked when read-only mode is active.
+    ///
+    /// # Authorization
+    /// Requires admin signature.
+    pub fn ping_watchdog(env: Env) {
+        Self::require_function_enabled(&env, "ping_watchdog");
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic!("{}", ContractError::NotInitialized as u32));
+        admin.require_auth();
+        Self::require_not_read_only(&env);
+        let ts = env.ledger().timestamp();
+        env.storage().instance().set(&DataKey::WatchdogLastPing, &ts);
+        env.events().publish(
+            (symbol_short!("watchdog"), symbol_short!("ping")),
+            (admin, ts),
+        );
+    }
 
-        let admin_set = env.storage().instance().has(&DataKey::Admin);
-        let version_opt: Option<u32> = env.storage().instance().get(&DataKey::Version);
-        let version_set = version_opt.is_some();
-        let version = version_opt.unwrap_or(0);
-        let version_sane = version > 0;
+    /// Returns the liveness schema version written at `init_admin`.
+    /// Returns `0` on legacy deployments where the marker was never written.
+    /// Increment `LivenessSchemaVersion` in `init_admin` whenever `WatchdogStatus` layout changes.
+    pub fn get_liveness_schema_version(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::LivenessSchemaVersion)
+            .unwrap_or(0)
+    }
 
-        let previous_version_opt: Option<u32> = env.storage().instance().get(&DataKey::PreviousVersion);
-        let previous_version_sane = match (previous_version_opt, version_opt) {
-            (Some(prev), Some(curr)) => prev <= curr,
-            (Some(_), None) => false,
-            (None, _) => true,
-        };
+    // ========================================================================
+    // Migration State Queries
+    // ========================================================================
 
-        let chain_id: Option<String> = env.storage().instance().get(&DataKey::ChainId);
-        let network_id: Option<String> = env.storage().instance().get(&DataKey::NetworkId);
-        let network_pair_sane = match (chain_id, network_id) {
-            (Some(chain), Some(network)) => chain.len() > 0 && network.len() > 0,
-            (None, None) => true,
-            _ => false,
-        };
+    pub fn get_migration_state(env: Env) -> Option<MigrationState> {
+        if env.storage().instance().has(&DataKey::MigrationState) {
+            Some(env.storage().instance().get(&DataKey::MigrationState).unwrap())
+        } else {
+            None
+        }
+    }
 
-        let config_sane = admin_set && version_set && version_sane && previous_version_sane && network_pair_sane;
-        let mut violation_count: u32 = 0;
-        if !admin_set { violation_count += 1; }
-        if !version_set || !version_sane { violation_count += 1; }
-        if !previous_version_sane { violation_count += 1; }
-        if !network_pair_sane { violation_count += 1; }
-        if error_count > operation_count { violation_count += 1; }
-        if unique_users > operation_count { violation_count += 1; }
-        if operation_count == 0 && (unique_users > 0 || error_count > 0) { violation_count += 1; }
+    pub fn get_previous_version(env: Env) -> Option<u32> {
+        grainlify_upgradeable::get_previous_version(&env)
+    }
+
+    // ========================================================================
+    // Multisig Initialization
+    // ========================================================================
 
-        InvariantReport {
-            healthy: config_sane && metrics_sane,
-            config_sane, metrics_sane, admin_set, version_set, version,
-            operation_count, unique_users, error_count, violation_count,
-        }
+    /// Initialize with multisig governance (alternative to init_admin).
+    /// Requires at least one signer and a valid threshold. Fails with
+    /// `SelfReference` if this contract is among `signers`.
+    pub fn init(env: Env, signers: Vec<Address>, threshold: u32) {
+        Self::init_with_version(env, signers, threshold, None);
     }
 
-    pub fn verify_invariants(env: &Env) -> bool {
-        let report = check_invariants(env);
-        #[cfg(feature = "strict-mode")]
-        {
-            if !report.healthy {
-                env.events().publish(
-                    (symbol_short!("strict"), symbol_short!("inv_fail")),
-                    report.violation_count,
-                );
-            }
-        }
-        report.healthy
+    /// `init` starting at `version`; see `init_admin_with_version`.
+    pub fn init_with_version(env: Env, signers: Vec<Address>, threshold: u32, version: Option<u32>) {
+        Self::initialize_multisig(env, signers, threshold, version, None);
     }
-}
 
-#[cfg(all(test, feature = "wasm_tests"))]
-mod test_core_monitoring;
-#[cfg(all(test, feature = "wasm_tests"))]
-mod test_pseudo_randomness;
-#[cfg(all(test, feature = "wasm_tests"))]
-mod test_serialization_compatibility;
-#[cfg(test)]
-mod test_storage_layout;
-#[cfg(all(test, feature = "wasm_tests"))]
-mod test_version_helpers;
-#[cfg(test)]
-mod test_strict_mode;
-#[cfg(test)]
-mod test_timelock_boundary;
-#[cfg(test)]
-mod test_contract_registry;
-#[cfg(test)]
-mod test_config_change_timelock;
-#[cfg(test)]
-mod test_build_info_init_event;
-// ==================== END MONITORING MODULE ====================
+    /// `init_with_version` that also records `wasm_hash` as the installed
+    /// code for `get_current_wasm_hash`.
+    pub fn init_with_wasm(
+        env: Env,
+        signers: Vec<Address>,
+        threshold: u32,
+        wasm_hash: BytesN<32>,
+        version: Option<u32>,
+    ) {
+        Self::initialize_multisig(env, signers, threshold, version, Some(wasm_hash));
+    }
 
-#[cfg_attr(feature = "contract", contract)]
-pub struct GrainlifyContract;
-#[cfg(feature = "contract")]
-#[contractimpl]
-impl GrainlifyContract {
-    fn emit_build_info_event(
-        env: &Env,
-        init_path: Symbol,
-        admin: Option<Address>,
-        signer_count: u32,
+    fn initialize_multisig(
+        env: Env,
+        signers: Vec<Address>,
         threshold: u32,
+        version: Option<u32>,
+        wasm_hash: Option<BytesN<32>>,
     ) {
-        env.events().publish(
-            (symbol_short!("init"), symbol_short!("build")),
-            BuildInfoEvent {
-                init_path,
-                admin,
-                signer_count,
-                threshold,
-                version: VERSION,
-                timestamp: env.ledger().timestamp(),
-                event_version: EVENT_SCHEMA_VERSION,
-            },
+        Self::require_no_self_signer(&env, &signers);
+        let version = Self::initial_version(version);
+        let signer_count = signers.len();
+        grainlify_upgradeable::initialize_multisig(&env, signers.clone(), threshold, version);
+        Self::record_version(&env);
+        env.storage().instance().set(&DataKey::InitMode, &InitMode::Multisig);
+        Self::record_threshold_change(&env, None);
+        if let Some(wasm_hash) = wasm_hash {
+            env.storage().instance().set(&DataKey::CurrentWasmHash, &wasm_hash);
+        }
+        env.storage().instance().set(&DataKey::ReadOnlyMode, &false);
+        env.storage().instance().set(&DataKey::SchemaVersion, &STORAGE_SCHEMA_VERSION);
+        Self::emit_build_info_event(
+            &env,
+            symbol_short!("msig_init"),
+            None,
+            signer_count,
+            threshold,
         );
+        Self::emit_init_event(&env, None, signers, threshold);
     }
 
-    /// One-time initialization: set the admin and initial version. Requires `admin` auth.
-    pub fn init_admin(env: Env, admin: Address) {
+    /// `init` for a staged launch: sets up multisig governance, stores
+    /// `metadata`, and opens upgrade proposal #1 for `wasm_hash` in the same
+    /// call, so signers can approve it without a separate proposer
+    /// transaction. Returns the proposal ID. `expiry` and the timelock apply
+    /// as for `propose_upgrade`. No signer authorized the proposal, so it
+    /// records no proposer and cannot be amended; signer stats credit it to
+    /// the first signer.
+    pub fn init_with_pending_upgrade(
+        env: Env,
+        signers: Vec<Address>,
+        threshold: u32,
+        wasm_hash: BytesN<32>,
+        metadata: ContractMetadata,
+        expiry: u64,
+    ) -> u64 {
+        // Rejects an empty signer set, so the first signer exists below.
+        Self::initialize_multisig(env.clone(), signers.clone(), threshold, None, None);
+        Self::store_metadata(&env, metadata);
+        let first_signer = signers.get_unchecked(0);
+
+        let proposal_id = MultiSig::try_propose(&env, &first_signer, expiry)
+            .unwrap_or_else(|e| panic!("{:?}", e));
+        env.storage().persistent().set(&DataKey::UpgradeProposal(proposal_id), &wasm_hash);
+        env.storage()
+            .persistent()
+            .set(&ProposalKey::UpgradeProposedAt(proposal_id), &env.ledger().timestamp());
+        Self::record_proposal_created(&env, proposal_id);
+        Self::track_pending_upgrade(&env, proposal_id);
+        proposal_id
+    }
+
+    /// Initialize with admin, chain_id, and network_id (network-aware init).
+    /// Both IDs must fit the `label` string limit (`LabelTooLong`).
+    pub fn init_with_network(env: Env, admin: Address, chain_id: String, network_id: String) {
         if env.storage().instance().has(&DataKey::Version) {
-            panic!("{}", ContractError::AlreadyInitialized as u32);
+            panic!("Already initialized");
         }
         admin.require_auth();
+        Self::require_not_self(&env, &admin);
+        limits::require_len(&env, limits::LABEL, &chain_id);
+        limits::require_len(&env, limits::LABEL, &network_id);
         env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage().instance().set(&DataKey::InitMode, &InitMode::Admin);
         env.storage().instance().set(&DataKey::Version, &VERSION);
+        Self::record_version(&env);
         env.storage().instance().set(&DataKey::ReadOnlyMode, &false);
-        env.storage().instance().set(&DataKey::LivenessSchemaVersion, &LIVENESS_SCHEMA_VERSION);
-        
+        env.storage().instance().set(&DataKey::ChainId, &chain_id);
+        env.storage().instance().set(&DataKey::NetworkId, &network_id);
+        env.storage().instance().set(&DataKey::SchemaVersion, &STORAGE_SCHEMA_VERSION);
         Self::emit_build_info_event(
             &env,
-            symbol_short!("adm_init"),
-            Some(admin),
+            symbol_short!("net_init"),
+            Some(admin.clone()),
             0,
             0,
         );
+        Self::emit_init_event(&env, Some(admin), Vec::new(&env), 0);
     }
 
-    // ========================================================================
-    // Timelock Execution (continued from propose/approve flow)
-    // ========================================================================
+    /// Initialize with governance configuration.
+    pub fn init_governance(env: Env, admin: Address, config: GovernanceConfig) {
+        if env.storage().instance().has(&DataKey::Version) {
+            panic!("Already initialized");
+        }
+        admin.require_auth();
+        Self::require_not_self(&env, &admin);
+        if config.quorum_percentage == 0 || config.quorum_percentage > 10000 {
+            panic!("Invalid quorum percentage");
+        }
+        if config.approval_threshold < 5000 || config.approval_threshold > 10000 {
+            panic!("Invalid approval threshold");
+        }
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage().instance().set(&DataKey::InitMode, &InitMode::Admin);
+        env.storage().instance().set(&DataKey::Version, &VERSION);
+        Self::record_version(&env);
+        env.storage().instance().set(&DataKey::ReadOnlyMode, &false);
+        env.storage().instance().set(&DataKey::SchemaVersion, &STORAGE_SCHEMA_VERSION);
+        env.storage().instance().set(&governance::GOVERNANCE_CONFIG, &config);
+        env.storage().instance().set(&governance::PROPOSAL_COUNT, &0u32);
+        Self::emit_build_info_event(
+            &env,
+            symbol_short!("gov_init"),
+            Some(admin.clone()),
+            0,
+            0,
+        );
+        Self::emit_init_event(&env, Some(admin), Vec::new(&env), 0);
+    }
 
-    /// Execute a multisig-approved upgrade after the timelock delay has elapsed.
-    pub fn execute_upgrade(env: Env, proposal_id: u64) {
-        let start = env.ledger().timestamp();
+    /// Hand governance from the admin to a multisig of `signers` with
+    /// `threshold`. Admin only, and fails with `WrongGovernanceMode` on a
+    /// multisig-only contract. Removes the admin and revokes its
+    /// `get_pending_admin_actions`, records the switchover for
+    /// `get_mode_stats`, and emits `("gov_mode", "multisig")` with
+    /// `(former_admin, signer_count, threshold)`. Fails with `SelfReference`
+    /// if this contract is among `signers`. The admin may stay on as a
+    /// signer only with `allow_admin_signer`, and is then flagged via
+    /// `("role", "overlap")`.
+    pub fn migrate_to_multisig(env: Env, signers: Vec<Address>, threshold: u32, allow_admin_signer: bool) {
+        Self::require_function_enabled(&env, "migrate_to_multisig");
+        Self::require_governance_mode(&env, InitMode::Admin);
+        let admin = grainlify_upgradeable::require_governance(&env);
         Self::require_not_read_only(&env);
-
-        if MultiSig::is_state_inconsistent(&env) {
-            panic!("Contract state inconsistent - upgrade blocked");
+        Self::require_no_emergency_pause(&env);
+        Self::require_no_self_signer(&env, &signers);
+        if signers.contains(&admin) {
+            if !allow_admin_signer {
+                panic!("Admin cannot become a signer without allow_admin_signer");
+            }
+            Self::flag_role_overlap(&env, symbol_short!("admin"), symbol_short!("signer"), &admin);
         }
 
-        let timelock_start: u64 = env
-            .storage()
-            .instance()
-            .get(&DataKey::UpgradeTimelock(proposal_id))
-            .unwrap_or_else(|| panic!("Timelock not started - call approve_upgrade first"));
-
-        let timelock_delay = Self::get_timelock_delay(env.clone());
-        let current_time = env.ledger().timestamp();
-        let elapsed = current_time.saturating_sub(timelock_start);
+        let signer_count = signers.len();
+        MultiSig::set_config(&env, multisig::MultiSigConfig { signers, threshold });
+        Self::record_threshold_change(&env, None);
+        env.storage().instance().remove(&DataKey::Admin);
+        Self::revoke_admin_actions(&env);
+        env.storage().instance().set(&DataKey::InitMode, &InitMode::Multisig);
+        monitoring::record_mode_switch(&env, &monitoring::ADMIN_MODE);
+        env.events().publish(
+            (symbol_short!("gov_mode"), symbol_short!("multisig")),
+            (admin, signer_count, threshold),
+        );
+    }
 
-        if elapsed < timelock_delay {
-            let remaining = timelock_delay.saturating_sub(elapsed);
-            panic!("Timelock delay not met: {} seconds remaining", remaining);
+    /// Every address holding a governance role, once per role it holds:
+    /// the admin, the signers in signer order, the relayer, then the
+    /// configured attestation auditors. An address listed under several
+    /// roles holds overlapping authority.
+    pub fn list_authorities(env: Env) -> Vec<Authority> {
+        let mut authorities = Vec::new(&env);
+        let mut add = |address: Address, role: Symbol| authorities.push_back(Authority { address, role });
+        if let Some(admin) = grainlify_upgradeable::get_admin(&env) {
+            add(admin, symbol_short!("admin"));
         }
-
-        if !MultiSig::can_execute(&env, proposal_id) {
-            panic!("Threshold not met or proposal not executable");
+        if let Some(config) = MultiSig::get_config_opt(&env) {
+            for signer in config.signers.iter() {
+                add(signer, symbol_short!("signer"));
+            }
         }
+        if let Some(relayer) = Self::get_relayer(env.clone()) {
+            add(relayer, symbol_short!("relayer"));
+        }
+        if let Some(config) = Self::get_attestation_config(env.clone()) {
+            for auditor in config.auditors.iter() {
+                add(auditor, symbol_short!("auditor"));
+            }
+        }
+        authorities
+    }
 
-        let wasm_hash: BytesN<32> = env
-            .storage()
-            .instance()
-            .get(&DataKey::UpgradeProposal(proposal_id))
-            .unwrap_or_else(|| panic!("Upgrade proposal not found"));
-
-        let current_version: u32 = env.storage().instance().get(&DataKey::Version).unwrap_or(1);
-        env.storage().instance().set(&DataKey::PreviousVersion, &current_version);
-
-        MultiSig::mark_executed(&env, proposal_id);
-        env.storage().instance().remove(&DataKey::UpgradeTimelock(proposal_id));
-
-        env.deployer().update_current_contract_wasm(wasm_hash.clone());
-
-        env.events().publish(
-            (symbol_short!("upgrade"), symbol_short!("wasm")),
-            UpgradeEvent {
-                new_wasm_hash: wasm_hash,
-                previous_version: current_version,
-                timestamp: env.ledger().timestamp(),
-                event_version: EVENT_SCHEMA_VERSION,
-            },
-        );
+    // ========================================================================
+    // Signer Onboarding
+    // ========================================================================
 
-        let duration = env.ledger().timestamp().saturating_sub(start);
-        monitoring::emit_performance(&env, symbol_short!("exec_upg"), duration);
+    /// Invite `signer` to the multisig. The address becomes a signer only
+    /// once it calls `accept_signership` within the acceptance window;
+    /// until then it cannot approve and does not count toward the
+    /// threshold. Re-inviting a pending address restarts its window.
+    /// Publishes `("signer", "pending")` with the `PendingSigner`. Fails
+    /// with `NotInitialized` without multisig governance, with
+    /// `SelfReference` for this contract, and panics for an existing
+    /// signer. Governance only; multisig deployments use a
+    /// `ProposalAction::AddSigner` bundle.
+    pub fn add_signer(env: Env, signer: Address) {
+        Self::require_function_enabled(&env, "add_signer");
+        grainlify_upgradeable::require_governance(&env);
+        Self::require_not_read_only(&env);
+        Self::invite_signer(&env, signer, 0);
     }
 
-    /// Single-admin upgrade path
-    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) {
-        let start = env.ledger().timestamp();
+    /// Accept a pending invitation from `add_signer`, joining the signer
+    /// set. Requires `signer`'s auth. Panics when no unexpired invitation
+    /// exists. Publishes `("signer", "accepted")` with `signer`.
+    pub fn accept_signership(env: Env, signer: Address) {
+        Self::require_function_enabled(&env, "accept_signership");
+        signer.require_auth();
+        Self::require_not_read_only(&env);
+        let mut pending = Self::get_pending_signers(env.clone());
+        let index = pending
+            .iter()
+            .position(|entry| entry.address == signer)
+            .unwrap_or_else(|| panic!("No pending signership"));
+        pending.remove(index as u32);
+        env.storage().instance().set(&OnboardingKey::PendingSigners, &pending);
+
+        let mut config =
+            MultiSig::get_config_opt(&env).unwrap_or_else(|| panic!("{}", ContractError::NotInitialized as u32));
+        config.signers.push_back(signer.clone());
+        MultiSig::set_config(&env, config);
+        Self::record_threshold_change(&env, None);
+        env.events().publish((symbol_short!("signer"), symbol_short!("accepted")), signer);
+    }
 
-        #[cfg(feature = "strict-mode")]
-        {
-            let report = monitoring::check_invariants(&env);
-            strict_mode::strict_assert(report.healthy, "Strict mode: contract invariants unhealthy before upgrade");
-            strict_mode::strict_emit(&env, symbol_short!("upgrade"), symbol_short!("pre_chk"));
+    /// Invitations awaiting `accept_signership`, oldest first. Expired
+    /// entries are left out.
+    pub fn get_pending_signers(env: Env) -> Vec<PendingSigner> {
+        let stored: Vec<PendingSigner> =
+            env.storage().instance().get(&OnboardingKey::PendingSigners).unwrap_or(Vec::new(&env));
+        let now = env.ledger().timestamp();
+        let mut pending = Vec::new(&env);
+        for entry in stored.iter() {
+            if entry.expires_at >= now {
+                pending.push_back(entry);
+            }
         }
+        pending
+    }
 
-        let admin: Address = env
-            .storage()
+    /// Seconds an invited signer has to accept.
+    pub fn get_signer_acceptance_window(env: Env) -> u64 {
+        env.storage()
             .instance()
-            .get(&DataKey::Admin)
-            .unwrap_or_else(|| panic!("{}", ContractError::NotInitialized as u32));
-        admin.require_auth();
+            .get(&OnboardingKey::AcceptanceWindow)
+            .unwrap_or(DEFAULT_SIGNER_ACCEPTANCE_WINDOW)
+    }
+
+    /// Set the acceptance window for later invitations; existing ones keep
+    /// their deadline. Panics for 0. Governance only; multisig deployments
+    /// set `signer_acceptance_window` in a `ProposalAction::UpdateConfig`
+    /// bundle.
+    pub fn set_signer_acceptance_window(env: Env, seconds: u64) {
+        Self::require_function_enabled(&env, "set_signer_acceptance_window");
+        grainlify_upgradeable::require_governance(&env);
         Self::require_not_read_only(&env);
+        Self::store_signer_acceptance_window(&env, seconds, None);
+    }
 
-        let current_version: u32 = env.storage().instance().get(&DataKey::Version).unwrap_or(1);
-        env.storage().instance().set(&DataKey::PreviousVersion, &current_version);
-        env.deployer().update_current_contract_wasm(new_wasm_hash.clone());
+    fn store_signer_acceptance_window(env: &Env, seconds: u64, proposal_id: Option<u64>) {
+        if seconds == 0 {
+            panic!("Acceptance window must be positive");
+        }
+        env.storage().instance().set(&OnboardingKey::AcceptanceWindow, &seconds);
+        Self::record_config_change(env, "signer_acceptance_window", proposal_id);
+    }
 
-        // [FIX-L02] Consistent event shape with execute_upgrade
-        env.events().publish(
-            (symbol_short!("upgrade"), symbol_short!("wasm")),
-            UpgradeEvent {
-                new_wasm_hash,
-                previous_version: current_version,
-                timestamp: env.ledger().timestamp(),
-                event_version: EVENT_SCHEMA_VERSION,
-            },
-        );
+    fn invite_signer(env: &Env, signer: Address, proposal_id: u64) {
+        Self::require_not_self(env, &signer);
+        let config =
+            MultiSig::get_config_opt(env).unwrap_or_else(|| panic!("{}", ContractError::NotInitialized as u32));
+        if config.signers.contains(&signer) {
+            panic!("Already a signer");
+        }
 
-        monitoring::track_operation(&env, symbol_short!("upgrade"), admin, true);
-        let duration = env.ledger().timestamp().saturating_sub(start);
-        monitoring::emit_performance(&env, symbol_short!("upgrade"), duration);
+        let mut pending = Self::get_pending_signers(env.clone());
+        if let Some(index) = pending.iter().position(|entry| entry.address == signer) {
+            pending.remove(index as u32);
+        }
+        if pending.len() >= MAX_PENDING_SIGNERS {
+            panic!("Too many pending signers");
+        }
+        let now = env.ledger().timestamp();
+        let entry = PendingSigner {
+            address: signer,
+            added_at: now,
+            expires_at: now.saturating_add(Self::get_signer_acceptance_window(env.clone())),
+            proposal_id,
+        };
+        pending.push_back(entry.clone());
+        env.storage().instance().set(&OnboardingKey::PendingSigners, &pending);
+        env.events().publish((symbol_short!("signer"), symbol_short!("pending")), entry);
     }
 
     // ========================================================================
-    // Timelock Management
+    // Multisig Upgrade Proposal Flow
     // ========================================================================
 
-    pub fn get_timelock_delay(env: Env) -> u64 {
-        env.storage()
-            .instance()
-            .get(&DataKey::TimelockDelay)
-            .unwrap_or(DEFAULT_TIMELOCK_DELAY)
+    /// Propose a WASM upgrade via multisig. Returns the stable proposal ID.
+    /// `expiry` is a ledger timestamp after which the proposal cannot be approved
+    /// or executed (0 = no expiry).
+    ///
+    /// With token voting configured, this opens a voting window instead; the
+    /// proposer must hold voting tokens and `expiry` is unused. Otherwise fails with
+    /// `WrongGovernanceMode` on an admin-only contract.
+    ///
+    /// Checks that do not depend on time or approvals run here as well as at
+    /// execution, failing with the matching `ProposalCheckError`: read-only
+    /// mode, pause, inconsistent state, missing attestations, a blocked hash,
+    /// or the hash already installed. The timelock and execution window are
+    /// only checked at execution.
+    pub fn propose_upgrade(env: Env, proposer: Address, wasm_hash: BytesN<32>, expiry: u64) -> u64 {
+        Self::open_upgrade_proposal(&env, proposer, wasm_hash, expiry, String::from_str(&env, ""))
     }
 
-    /// [FIX-H02] Now enforces both minimum AND maximum to prevent bricking upgrades
-    pub fn set_timelock_delay(env: Env, delay_seconds: u64) {
-        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
-        admin.require_auth();
-        Self::require_not_read_only(&env);
+    /// `propose_upgrade` that records a refusal instead of failing, like
+    /// `attempt_execute_upgrade`. Runs the creation checks, the governance
+    /// mode and the proposer's signer status first and, if one fails,
+    /// counts it as a `Propose` governance failure and returns its code in
+    /// `refused`. Multisig mode only; failures these checks miss still
+    /// revert.
+    pub fn attempt_propose_upgrade(env: Env, proposer: Address, wasm_hash: BytesN<32>, expiry: u64) -> ProposeAttempt {
+        Self::require_function_enabled(&env, "attempt_propose_upgrade");
+        let Some(code) = Self::proposal_refusal(&env, &proposer, &wasm_hash) else {
+            let proposal_id = Self::propose_upgrade(env, proposer, wasm_hash, expiry);
+            return ProposeAttempt { proposal_id, refused: None };
+        };
+        proposer.require_auth();
+        monitoring::track_governance_failure(
+            &env,
+            symbol_short!("prop_upg"),
+            monitoring::GovernanceStage::Propose,
+            proposer.clone(),
+            code,
+            Self::trips_breaker(&env, &proposer),
+        );
+        ProposeAttempt { proposal_id: 0, refused: Some(code) }
+    }
 
-        if delay_seconds < MIN_TIMELOCK_DELAY {
-            panic!("Timelock delay must be at least 1 hour (3600 seconds)");
+    /// Code `propose_upgrade` fails with for `proposer` and `wasm_hash`,
+    /// as far as it can be told without opening the proposal.
+    fn proposal_refusal(env: &Env, proposer: &Address, wasm_hash: &BytesN<32>) -> Option<u32> {
+        if let Some(error) = Self::creation_check_error(env, wasm_hash) {
+            return Some(error as u32);
         }
-
-        // [FIX-H02] Enforce maximum — prevents admin from bricking upgrade execution
-        if delay_seconds > MAX_TIMELOCK_DELAY {
-            panic!("Timelock delay cannot exceed 30 days (2592000 seconds)");
+        if TokenVoting::get_config(env).is_some() {
+            return Some(0);
         }
-
-        let old_delay = Self::get_timelock_delay(env.clone());
-        env.storage().instance().set(&DataKey::TimelockDelay, &delay_seconds);
-
-        env.events().publish(
-            (symbol_short!("timelock"), symbol_short!("dly_chg")),
-            (old_delay, delay_seconds),
-        );
+        if MultiSig::get_config_opt(env).is_none() {
+            return Some(ContractError::WrongGovernanceMode as u32);
+        }
+        (!MultiSig::is_signer(env, proposer)).then_some(0)
     }
 
-    pub fn get_timelock_status(env: Env, proposal_id: u64) -> Option<u64> {
-        if let Some(timelock_start) = env
-            .storage()
-            .instance()
-            .get(&DataKey::UpgradeTimelock(proposal_id))
-        {
-            let timelock_delay = Self::get_timelock_delay(env.clone());
-            let current_time = env.ledger().timestamp();
-            let elapsed = current_time.saturating_sub(timelock_start);
-            if elapsed >= timelock_delay { Some(0) } else { Some(timelock_delay.saturating_sub(elapsed)) }
-        } else {
-            None
+    /// `propose_upgrade` storing `description` before the
+    /// `("upgrade", "proposed")` event, so the event digest covers it.
+    fn open_upgrade_proposal(
+        env: &Env,
+        proposer: Address,
+        wasm_hash: BytesN<32>,
+        expiry: u64,
+        description: String,
+    ) -> u64 {
+        let env = env.clone();
+        Self::require_function_enabled(&env, "propose_upgrade");
+        Self::ensure_proposal_storage(&env);
+        Self::require_creation_checks(&env, &wasm_hash);
+        if TokenVoting::get_config(&env).is_some() {
+            let proposal_id = TokenVoting::propose(&env, proposer, wasm_hash);
+            Self::record_proposal_created(&env, proposal_id);
+            return proposal_id;
+        }
+        Self::require_governance_mode(&env, InitMode::Multisig);
+        let proposal_id = grainlify_upgradeable::propose_upgrade(&env, proposer.clone(), &wasm_hash, expiry);
+        Self::record_proposal_created(&env, proposal_id);
+        Self::record_network_label(&env, proposal_id, &proposer);
+        Self::track_pending_upgrade(&env, proposal_id);
+        env.storage().persistent().set(&DataKey::UpgradeProposalProposer(proposal_id), &proposer);
+        env.storage()
+            .persistent()
+            .set(&ProposalKey::UpgradeProposedAt(proposal_id), &env.ledger().timestamp());
+        if !description.is_empty() {
+            env.storage()
+                .persistent()
+                .set(&ProposalKey::UpgradeDescription(proposal_id), &description);
         }
+        // As recorded by `record_proposal_created`.
+        let threshold = MultiSig::get_config_opt(&env).map_or(0, |config| config.threshold);
+        let digest = Self::digest_of(&env, proposal_id, wasm_hash.clone(), description, expiry, threshold);
+        env.events().publish(
+            (symbol_short!("upgrade"), symbol_short!("proposed")),
+            UpgradeProposedEvent {
+                proposal_id,
+                proposer,
+                wasm_hash,
+                digest,
+                timestamp: env.ledger().timestamp(),
+                event_version: EVENT_SCHEMA_VERSION,
+            },
+        );
+        proposal_id
     }
 
-    /// Returns the config-change timelock delay in seconds.
-    pub fn get_config_change_delay(env: Env) -> u64 {
-        env.storage()
-            .instance()
-            .get(&DataKey::ConfigChangeDelay)
-            .unwrap_or(DEFAULT_CONFIG_CHANGE_DELAY)
+    /// `sha256` of the XDR encoding of this proposal's
+    /// `ProposalDigestInput`, for signers to compare across their own
+    /// tooling before approving. Stable while the proposal is unchanged;
+    /// revealing, amending or postponing it yields a new digest. Panics
+    /// with `ProposalNotFound` unless `proposal_id` is an upgrade proposal.
+    pub fn proposal_digest(env: Env, proposal_id: u64) -> BytesN<32> {
+        Self::ensure_proposal_storage(&env);
+        let proposal = MultiSig::get_proposal_opt(&env, proposal_id)
+            .unwrap_or_else(|| panic!("{}", ContractError::ProposalNotFound as u32));
+        let threshold = env
+            .storage()
+            .persistent()
+            .get(&ProposalKey::ThresholdAtCreation(proposal_id))
+            .unwrap_or(0);
+        Self::digest_of(
+            &env,
+            proposal_id,
+            Self::proposal_wasm_hash(&env, proposal_id),
+            Self::get_upgrade_description(env.clone(), proposal_id),
+            proposal.expiry,
+            threshold,
+        )
     }
 
-    /// Sets the config-change timelock delay.
-    ///
-    /// Delay must remain within the same guardrails as the upgrade timelock.
-    pub fn set_config_change_delay(env: Env, delay_seconds: u64) {
-        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
-        admin.require_auth();
-        Self::require_not_read_only(&env);
+    /// `proposal_digest` from values the caller already holds.
+    fn digest_of(
+        env: &Env,
+        proposal_id: u64,
+        wasm_hash: BytesN<32>,
+        description: String,
+        expiry: u64,
+        threshold: u32,
+    ) -> BytesN<32> {
+        let input = ProposalDigestInput {
+            contract: env.current_contract_address(),
+            proposal_id,
+            wasm_hash,
+            metadata_hash: env.crypto().sha256(&description.to_xdr(env)).into(),
+            expiry,
+            threshold,
+        };
+        env.crypto().sha256(&input.to_xdr(env)).into()
+    }
 
-        if delay_seconds < MIN_TIMELOCK_DELAY {
-            panic!("Config change delay must be at least 1 hour (3600 seconds)");
+    /// Revealed WASM hash of an upgrade proposal, or its commitment while
+    /// unrevealed. Panics with `ProposalNotFound` when it has neither.
+    fn proposal_wasm_hash(env: &Env, proposal_id: u64) -> BytesN<32> {
+        grainlify_upgradeable::get_upgrade_hash(env, proposal_id).unwrap_or_else(|| {
+            let commitment: commit_reveal::Commitment = env
+                .storage()
+                .persistent()
+                .get(&ProposalKey::UpgradeCommitment(proposal_id))
+                .unwrap_or_else(|| panic!("{}", ContractError::ProposalNotFound as u32));
+            commitment.hash
+        })
+    }
+
+    /// Open a new proposal for the hash of `expired_id`, which must have
+    /// expired without being cancelled or executed. The new proposal gets a
+    /// fresh ID, creation time, and the same lifetime (expiry minus creation
+    /// time; none for proposals that predate the recorded creation time).
+    /// Approvals never carry over. Links the old proposal to the new one and
+    /// emits `("upgrade", "repropose")` with both IDs.
+    pub fn repropose(env: Env, expired_id: u64, proposer: Address) -> u64 {
+        Self::require_function_enabled(&env, "repropose");
+        Self::ensure_proposal_storage(&env);
+        let old = Self::load_upgrade_proposal(&env, expired_id)
+            .unwrap_or_else(|| panic!("{}", ContractError::ProposalNotFound as u32));
+        if old.cancelled
+            || MultiSig::get_execution(&env, expired_id).is_some()
+            || !MultiSig::is_expired(&env, expired_id)
+        {
+            panic!("{}", ContractError::ProposalNotExpired as u32);
         }
-        if delay_seconds > MAX_TIMELOCK_DELAY {
-            panic!("Config change delay cannot exceed 30 days (2592000 seconds)");
+        if let Some(successor) = old.superseded_by {
+            panic!("Proposal already superseded by {}", successor);
+        }
+        if old.not_before.is_some() || old.not_after.is_some() {
+            panic!("Windowed proposals need a new window - use propose_upgrade_in_window");
         }
 
-        let old_delay = Self::get_config_change_delay(env.clone());
-        env.storage().instance().set(&DataKey::ConfigChangeDelay, &delay_seconds);
+        let expiry = if old.proposed_at > 0 && old.expiry > old.proposed_at {
+            env.ledger().timestamp().saturating_add(old.expiry - old.proposed_at)
+        } else {
+            0
+        };
+        let new_id = Self::propose_upgrade(env.clone(), proposer, old.wasm_hash, expiry);
+        env.storage().persistent().set(&ProposalKey::UpgradeSupersededBy(expired_id), &new_id);
         env.events().publish(
-            (symbol_short!("cfg_tmlk"), symbol_short!("dly_chg")),
-            (old_delay, delay_seconds),
+            (symbol_short!("upgrade"), symbol_short!("repropose")),
+            (expired_id, new_id),
         );
+        new_id
     }
 
-    /// Creates a timelocked proposal to restore a configuration snapshot.
-    pub fn propose_config_snapshot_restore(env: Env, snapshot_id: u64) -> u64 {
-        let admin: Address = env.storage().instance().get(&DataKey::Admin).expect("Admin not set");
-        admin.require_auth();
+    /// Push back the earliest execution time of an approved upgrade
+    /// proposal to `new_not_before`. Any signer may call it once the
+    /// threshold is met (the timelock is running). The new time must be
+    /// later than the current earliest execution time (timelock end or
+    /// window start) and before the window end and expiry, else
+    /// `InvalidExecutionWindow`. The total delay added across calls is
+    /// capped by `get_max_postponement`, else `PostponementExceeded`.
+    pub fn postpone(env: Env, proposal_id: u64, new_not_before: u64, signer: Address) {
+        Self::require_function_enabled(&env, "postpone");
+        Self::ensure_proposal_storage(&env);
         Self::require_not_read_only(&env);
+        Self::require_not_paused(&env);
+        signer.require_auth();
+        if !MultiSig::is_signer(&env, &signer) {
+            panic!("{}", ContractError::NotAdmin as u32);
+        }
 
-        if !env.storage().instance().has(&DataKey::ConfigSnapshot(snapshot_id)) {
-            panic!("Snapshot not found or has been pruned");
+        let storage = env.storage().persistent();
+        if !storage.has(&DataKey::UpgradeProposal(proposal_id))
+            && !storage.has(&ProposalKey::UpgradeCommitment(proposal_id))
+        {
+            panic!("{}", ContractError::ProposalNotFound as u32);
+        }
+        let proposal = MultiSig::get_proposal_opt(&env, proposal_id)
+            .unwrap_or_else(|| panic!("{}", ContractError::ProposalNotFound as u32));
+        if proposal.executed || proposal.cancelled || MultiSig::is_expired(&env, proposal_id) {
+            panic!("Proposal is closed");
+        }
+        let timelock_start: u64 = storage
+            .get(&DataKey::UpgradeTimelock(proposal_id))
+            .unwrap_or_else(|| panic!("{}", ContractError::ThresholdNotMet as u32));
+
+        let mut window: ExecutionWindow = storage
+            .get(&ProposalKey::UpgradeWindow(proposal_id))
+            .unwrap_or(ExecutionWindow { not_before: None, not_after: None });
+        let earliest = timelock_start
+            .saturating_add(Self::get_timelock_delay(env.clone()))
+            .max(window.not_before.unwrap_or(0));
+        if new_not_before <= earliest
+            || window.not_after.is_some_and(|end| new_not_before >= end)
+            || (proposal.expiry != 0 && new_not_before >= proposal.expiry)
+        {
+            panic!("{}", ContractError::InvalidExecutionWindow as u32);
         }
 
-        let proposal_id: u64 = env
-            .storage()
-            .instance()
-            .get(&DataKey::ConfigChangeCounter)
-            .unwrap_or(0u64)
-            .saturating_add(1);
-        let now = env.ledger().timestamp();
-        let delay = Self::get_config_change_delay(env.clone());
-        let proposal = ConfigChangeProposal {
-            proposal_id,
-            snapshot_id,
-            proposer: admin,
-            created_at: now,
-            execute_after: now.saturating_add(delay),
-            cancelled: false,
-            executed: false,
-        };
+        let used: u64 = storage.get(&ProposalKey::UpgradePostponed(proposal_id)).unwrap_or(0);
+        let used = used.saturating_add(new_not_before - earliest);
+        let max = Self::get_max_postponement(env.clone());
+        if used > max {
+            panic!("{}", ContractError::PostponementExceeded as u32);
+        }
 
-        env.storage().instance().set(&DataKey::ConfigChangeProposal(proposal_id), &proposal);
-        env.storage().instance().set(&DataKey::ConfigChangeCounter, &proposal_id);
+        window.not_before = Some(new_not_before);
+        storage.set(&ProposalKey::UpgradeWindow(proposal_id), &window);
+        storage.set(&ProposalKey::UpgradePostponed(proposal_id), &used);
         env.events().publish(
-            (symbol_short!("cfg_tmlk"), symbol_short!("propose")),
-            (proposal_id, snapshot_id, proposal.execute_after),
+            (symbol_short!("upgrade"), symbol_short!("postpone")),
+            ProposalPostponedEvent {
+                proposal_id,
+                signer,
+                not_before: new_not_before,
+                remaining_budget: max - used,
+                timestamp: env.ledger().timestamp(),
+                external_id: Self::external_id_of(&env, proposal_id),
+                event_version: EVENT_SCHEMA_VERSION,
+            },
         );
-        proposal_id
     }
 
-    /// Returns a config-change proposal by id.
-    pub fn get_config_change_proposal(env: Env, proposal_id: u64) -> Option<ConfigChangeProposal> {
-        env.storage().instance().get(&DataKey::ConfigChangeProposal(proposal_id))
+    /// Maximum total seconds `postpone` may delay one proposal; 30 days
+    /// unless configured.
+    pub fn get_max_postponement(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&DataKey::MaxPostponement)
+            .unwrap_or(DEFAULT_MAX_POSTPONEMENT)
     }
 
-    /// Returns remaining delay in seconds for a config-change proposal.
-    ///
-    /// `Some(0)` means executable now. `None` means proposal does not exist.
-    pub fn get_config_change_status(env: Env, proposal_id: u64) -> Option<u64> {
-        let proposal: ConfigChangeProposal = env
+    /// Set the `postpone` cap. Admin only. Applies to postponements made
+    /// afterwards; budget already used stays counted.
+    pub fn set_max_postponement(env: Env, max_seconds: u64) {
+        Self::require_function_enabled(&env, "set_max_postponement");
+        let admin: Address = env
             .storage()
             .instance()
-            .get(&DataKey::ConfigChangeProposal(proposal_id))?;
-
-        if proposal.cancelled || proposal.executed {
-            return Some(0);
-        }
-
-        let now = env.ledger().timestamp();
-        if now >= proposal.execute_after {
-            Some(0)
-        } else {
-            Some(proposal.execute_after.saturating_sub(now))
-        }
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic!("{}", ContractError::NotInitialized as u32));
+        admin.require_auth();
+        env.storage().instance().set(&DataKey::MaxPostponement, &max_seconds);
+        Self::record_config_change(&env, "max_postponement", None);
     }
 
-    /// Cancels a pending config-change proposal.
-    pub fn cancel_config_change(env: Env, proposal_id: u64) {
-        let admin: Address = env.storage().instance().get(&DataKey::Admin).expect("Admin not set");
-        admin.require_auth();
+    /// Replace the WASM hash of `proposal_id` before anyone else approves
+    /// it. Only the original proposer may amend; their own approval, if
+    /// any, is withdrawn along with a running timelock, and the creation
+    /// time restarts. Panics with `AmendmentLocked` once another signer has
+    /// approved, even if that approval was later revoked.
+    pub fn amend_proposal(env: Env, proposal_id: u64, new_wasm_hash: BytesN<32>, proposer: Address) {
+        Self::require_function_enabled(&env, "amend_proposal");
+        Self::ensure_proposal_storage(&env);
         Self::require_not_read_only(&env);
+        Self::require_not_paused(&env);
+        proposer.require_auth();
+        if TokenVoting::get_config(&env).is_some() {
+            panic!("Token voting active - cancel and re-propose instead");
+        }
 
-        let mut proposal: ConfigChangeProposal = env
-            .storage()
-            .instance()
-            .get(&DataKey::ConfigChangeProposal(proposal_id))
-            .unwrap_or_else(|| panic!("Config change proposal not found"));
-
-        if proposal.executed {
-            panic!("Config change proposal already executed");
+        let storage = env.storage().persistent();
+        let original: Address = storage
+            .get(&DataKey::UpgradeProposalProposer(proposal_id))
+            .unwrap_or_else(|| panic!("{}", ContractError::ProposalNotFound as u32));
+        if original != proposer {
+            panic!("{}", ContractError::NotProposer as u32);
         }
-        if proposal.cancelled {
-            panic!("Config change proposal already cancelled");
+        let old_wasm_hash: BytesN<32> = storage
+            .get(&DataKey::UpgradeProposal(proposal_id))
+            .unwrap_or_else(|| panic!("{}", ContractError::UpgradeNotRevealed as u32));
+        let proposal = MultiSig::get_proposal_opt(&env, proposal_id)
+            .unwrap_or_else(|| panic!("{}", ContractError::ProposalNotFound as u32));
+        if proposal.executed || proposal.cancelled || MultiSig::is_expired(&env, proposal_id) {
+            panic!("Proposal is closed");
+        }
+        let approved_by_other = proposal.approvals.iter().any(|approver| approver != proposer);
+        if approved_by_other || storage.has(&ProposalKey::UpgradeAmendLocked(proposal_id)) {
+            panic!("{}", ContractError::AmendmentLocked as u32);
         }
 
-        proposal.cancelled = true;
-        env.storage().instance().set(&DataKey::ConfigChangeProposal(proposal_id), &proposal);
+        if proposal.approvals.contains(&proposer) {
+            MultiSig::try_revoke(&env, proposal_id, &proposer).unwrap_or_else(|e| panic!("{:?}", e));
+            Self::stop_timelock_if_unready(&env, proposal_id);
+        }
+        Self::require_not_blocked(&env, &new_wasm_hash);
+        let now = env.ledger().timestamp();
+        storage.set(&DataKey::UpgradeProposal(proposal_id), &new_wasm_hash);
+        storage.set(&ProposalKey::UpgradeProposedAt(proposal_id), &now);
         env.events().publish(
-            (symbol_short!("cfg_tmlk"), symbol_short!("cancel")),
-            proposal_id,
+            (symbol_short!("upgrade"), symbol_short!("amended")),
+            ProposalAmendedEvent {
+                proposal_id,
+                proposer,
+                old_wasm_hash,
+                new_wasm_hash,
+                timestamp: now,
+                external_id: Self::external_id_of(&env, proposal_id),
+                event_version: EVENT_SCHEMA_VERSION,
+            },
         );
     }
 
-    /// Executes a timelocked config-change proposal after delay expiry.
-    pub fn execute_config_snapshot_restore(env: Env, proposal_id: u64) {
-        Self::require_not_read_only(&env);
-
-        let mut proposal: ConfigChangeProposal = env
-            .storage()
-            .instance()
-            .get(&DataKey::ConfigChangeProposal(proposal_id))
-            .unwrap_or_else(|| panic!("Config change proposal not found"));
-
-        if proposal.cancelled {
-            panic!("Config change proposal has been cancelled");
-        }
-        if proposal.executed {
-            panic!("Config change proposal already executed");
+    /// `propose_upgrade` restricted to a maintenance window: execution is
+    /// allowed from `not_before` until just before `not_after`. Bounds must
+    /// lie in the future and `not_before < not_after`; the proposal expires
+    /// at `not_after` (or at `expiry`, if earlier). Multisig mode only;
+    /// shares the `propose_upgrade` kill switch.
+    pub fn propose_upgrade_in_window(
+        env: Env,
+        proposer: Address,
+        wasm_hash: BytesN<32>,
+        expiry: u64,
+        not_before: Option<u64>,
+        not_after: Option<u64>,
+    ) -> u64 {
+        Self::require_function_enabled(&env, "propose_upgrade_in_window");
+        if TokenVoting::get_config(&env).is_some() {
+            panic!("Token voting active - execution windows need multisig");
         }
         let now = env.ledger().timestamp();
-        if now < proposal.execute_after {
-            panic!(
-                "Config change timelock not met: {} seconds remaining",
-                proposal.execute_after.saturating_sub(now)
+        let window_valid = not_before.is_none_or(|start| start > now)
+            && not_after.is_none_or(|end| end > now)
+            && match (not_before, not_after) {
+                (Some(start), Some(end)) => start < end,
+                _ => true,
+            }
+            && not_before.is_none_or(|start| expiry == 0 || start < expiry);
+        if !window_valid {
+            panic!("{}", ContractError::InvalidExecutionWindow as u32);
+        }
+
+        let expiry = match not_after {
+            Some(end) if expiry == 0 || end < expiry => end,
+            _ => expiry,
+        };
+        let proposal_id = Self::propose_upgrade(env.clone(), proposer, wasm_hash, expiry);
+        if not_before.is_some() || not_after.is_some() {
+            env.storage().persistent().set(
+                &ProposalKey::UpgradeWindow(proposal_id),
+                &ExecutionWindow { not_before, not_after },
             );
         }
+        proposal_id
+    }
+
+    /// `propose_upgrade` with a description within the `desc` string limit
+    /// (`DescriptionTooLong` otherwise).
+    /// Signers confirm its first `APPROVAL_DESCRIPTION_LEN` bytes in
+    /// `approve_upgrade_v2`. Multisig mode only.
+    pub fn propose_upgrade_with_description(
+        env: Env,
+        proposer: Address,
+        wasm_hash: BytesN<32>,
+        description: String,
+        expiry: u64,
+    ) -> u64 {
+        if TokenVoting::get_config(&env).is_some() {
+            panic!("Token voting active - described proposals need multisig");
+        }
+        limits::require_len(&env, limits::DESCRIPTION, &description);
+        Self::open_upgrade_proposal(&env, proposer, wasm_hash, expiry, description)
+    }
 
-        Self::restore_snapshot_with_checks(&env, proposal.snapshot_id);
-        proposal.executed = true;
-        env.storage().instance().set(&DataKey::ConfigChangeProposal(proposal_id), &proposal);
+    /// `propose_upgrade` linked to `external_id`, a release-pipeline ID
+    /// such as a build number. Fails with `DuplicateExternalId` while an
+    /// earlier proposal with the same ID is still pending; once it is
+    /// executed, cancelled or expired the ID can be reused. The ID is
+    /// returned by `get_upgrade_proposal` and carried by the proposal's
+    /// later `upgrade` and `network` events; multisig engine events only
+    /// carry the proposal ID. Emits `("upgrade", "linked")`. Multisig mode only.
+    pub fn propose_upgrade_with_external_id(
+        env: Env,
+        proposer: Address,
+        wasm_hash: BytesN<32>,
+        external_id: u64,
+        expiry: u64,
+    ) -> u64 {
+        Self::require_function_enabled(&env, "propose_upgrade_with_external_id");
+        if TokenVoting::get_config(&env).is_some() {
+            panic!("Token voting active - linked proposals need multisig");
+        }
+        let index_key = ProposalKey::ExternalIdIndex(external_id);
+        if let Some(existing) = env.storage().persistent().get::<_, u64>(&index_key) {
+            if monitoring::is_pending(&env, existing) {
+                panic!("{}", ContractError::DuplicateExternalId as u32);
+            }
+        }
+        let proposal_id = Self::propose_upgrade(env.clone(), proposer.clone(), wasm_hash, expiry);
+        let storage = env.storage().persistent();
+        storage.set(&ProposalKey::UpgradeExternalId(proposal_id), &external_id);
+        storage.set(&index_key, &proposal_id);
         env.events().publish(
-            (symbol_short!("cfg_tmlk"), symbol_short!("exec")),
-            (proposal_id, proposal.snapshot_id, now),
+            (symbol_short!("upgrade"), symbol_short!("linked")),
+            ProposalLinkedEvent {
+                proposal_id,
+                external_id,
+                proposer,
+                timestamp: env.ledger().timestamp(),
+                event_version: EVENT_SCHEMA_VERSION,
+            },
         );
+        proposal_id
     }
 
-    // ========================================================================
-    // Version Management
-    // ========================================================================
-
-    pub fn get_version(env: Env) -> u32 {
-        env.storage().instance().get(&DataKey::Version).unwrap_or(0)
+    /// `propose_upgrade` for WASM built against network protocol
+    /// `min_protocol`. Execution fails with `ExecutionError::ProtocolTooOld`
+    /// while the network runs an older protocol, so the proposal waits for
+    /// the network upgrade instead of installing code that traps. Multisig
+    /// mode only.
+    pub fn propose_upgrade_min_protocol(
+        env: Env,
+        proposer: Address,
+        wasm_hash: BytesN<32>,
+        min_protocol: u32,
+        expiry: u64,
+    ) -> u64 {
+        Self::require_function_enabled(&env, "propose_upgrade_min_protocol");
+        if TokenVoting::get_config(&env).is_some() {
+            panic!("Token voting active - protocol-gated proposals need multisig");
+        }
+        let proposal_id = Self::propose_upgrade(env.clone(), proposer, wasm_hash, expiry);
+        env.storage()
+            .persistent()
+            .set(&ProposalKey::UpgradeMinProtocol(proposal_id), &min_protocol);
+        proposal_id
     }
 
-    pub fn get_admin(env: Env) -> Option<Address> {
-        env.storage().instance().get(&DataKey::Admin)
+    /// Latest upgrade proposal linked to `external_id`, pending or not.
+    pub fn find_proposal_by_external_id(env: Env, external_id: u64) -> Option<u64> {
+        env.storage().persistent().get(&ProposalKey::ExternalIdIndex(external_id))
     }
 
-    pub fn is_strict_mode(_env: Env) -> bool {
-        strict_mode::is_enabled()
+    fn external_id_of(env: &Env, proposal_id: u64) -> Option<u64> {
+        env.storage().persistent().get(&ProposalKey::UpgradeExternalId(proposal_id))
     }
 
-    pub fn get_version_semver_string(env: Env) -> String {
-        let encoded = Self::get_version_numeric_encoded(env.clone());
-        let major = encoded / 10_000;
-        let minor = (encoded % 10_000) / 100;
-        let patch = encoded % 100;
-        let mut buf = [0u8; 12];
-        let mut pos = 0usize;
-
-        macro_rules! write_u32 {
-            ($n:expr) => {
-                let n: u32 = $n;
-                if n >= 100 { buf[pos] = b'0' + (n / 100) as u8; pos += 1; }
-                if n >= 10 { buf[pos] = b'0' + ((n % 100) / 10) as u8; pos += 1; }
-                buf[pos] = b'0' + (n % 10) as u8; pos += 1;
-            };
+    /// `propose_upgrade` that is safe to retry. The proposal is keyed by
+    /// `sha256(wasm_hash || nonce || proposer)`: while a proposal with the
+    /// same key is pending, its ID is returned and nothing new is created.
+    /// Once it is executed, cancelled or expired the same content opens a
+    /// new proposal. Proposals never expire (`expiry` 0). Multisig mode only.
+    pub fn propose_upgrade_idempotent(env: Env, proposer: Address, wasm_hash: BytesN<32>, nonce: u64) -> u64 {
+        Self::require_function_enabled(&env, "propose_upgrade_idempotent");
+        if TokenVoting::get_config(&env).is_some() {
+            panic!("Token voting active - idempotent proposals need multisig");
         }
-        write_u32!(major); buf[pos] = b'.'; pos += 1;
-        write_u32!(minor); buf[pos] = b'.'; pos += 1;
-        write_u32!(patch);
+        let key = Self::content_key(&env, &wasm_hash, nonce, &proposer);
+        let index_key = ProposalKey::ContentKeyIndex(key.clone());
+        if let Some(existing) = env.storage().persistent().get::<_, u64>(&index_key) {
+            if monitoring::is_pending(&env, existing) {
+                proposer.require_auth();
+                return existing;
+            }
+        }
+        let proposal_id = Self::propose_upgrade(env.clone(), proposer, wasm_hash, 0);
+        let storage = env.storage().persistent();
+        storage.set(&ProposalKey::UpgradeContentKey(proposal_id), &key);
+        storage.set(&index_key, &proposal_id);
+        proposal_id
+    }
 
-        let s = core::str::from_utf8(&buf[..pos]).unwrap_or("0.0.0");
-        String::from_str(&env, s)
+    /// Latest proposal `propose_upgrade_idempotent` created for this
+    /// content, pending or not.
+    pub fn find_proposal_by_content(env: Env, wasm_hash: BytesN<32>, nonce: u64, proposer: Address) -> Option<u64> {
+        let key = Self::content_key(&env, &wasm_hash, nonce, &proposer);
+        env.storage().persistent().get(&ProposalKey::ContentKeyIndex(key))
     }
 
-    pub fn get_version_numeric_encoded(env: Env) -> u32 {
-        let raw: u32 = env.storage().instance().get(&DataKey::Version).unwrap_or(0);
-        if raw >= 10_000 { raw } else { raw.saturating_mul(10_000) }
+    /// `sha256(wasm_hash || nonce as big-endian u64 || proposer XDR)`.
+    fn content_key(env: &Env, wasm_hash: &BytesN<32>, nonce: u64, proposer: &Address) -> BytesN<32> {
+        let mut data = Bytes::from(wasm_hash.clone());
+        data.extend_from_array(&nonce.to_be_bytes());
+        data.append(&proposer.to_xdr(env));
+        env.crypto().sha256(&data).into()
     }
 
-    pub fn require_min_version(env: Env, min_numeric: u32) {
-        let cur = Self::get_version_numeric_encoded(env.clone());
-        if cur == 0 { panic!("{}", ContractError::NotInitialized as u32); }
-        if cur < min_numeric { panic!("version_too_low"); }
+    /// Description of an upgrade proposal; empty when it has none.
+    pub fn get_upgrade_description(env: Env, proposal_id: u64) -> String {
+        env.storage()
+            .persistent()
+            .get(&ProposalKey::UpgradeDescription(proposal_id))
+            .unwrap_or(String::from_str(&env, ""))
     }
 
-    pub fn set_version(env: Env, new_version: u32) {
-        let start = env.ledger().timestamp();
-        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
-        admin.require_auth();
-        Self::require_not_read_only(&env);
-        env.storage().instance().set(&DataKey::Version, &new_version);
-        monitoring::track_operation(&env, symbol_short!("set_ver"), admin, true);
-        let duration = env.ledger().timestamp().saturating_sub(start);
-        monitoring::emit_performance(&env, symbol_short!("set_ver"), duration);
+    /// The description `approve_upgrade_v2` expects: the first
+    /// `APPROVAL_DESCRIPTION_LEN` bytes of the proposal's description, cut
+    /// back to a character boundary.
+    pub fn get_approval_description(env: Env, proposal_id: u64) -> String {
+        let description = Self::get_upgrade_description(env.clone(), proposal_id);
+        if description.len() <= APPROVAL_DESCRIPTION_LEN {
+            return description;
+        }
+        let mut buf = [0u8; limits::DESCRIPTION_CEILING as usize];
+        let bytes = &mut buf[..description.len() as usize];
+        description.copy_into_slice(bytes);
+        let mut end = APPROVAL_DESCRIPTION_LEN as usize;
+        while end > 0 && bytes[end] & 0xC0 == 0x80 {
+            end -= 1;
+        }
+        String::from_str(&env, core::str::from_utf8(&bytes[..end]).unwrap_or(""))
     }
 
-    // ========================================================================
-    // Read-Only Mode
-    // ========================================================================
+    /// Approve a pending upgrade proposal. Starts the timelock when threshold is met.
+    /// Fails with `WrongGovernanceMode` on an admin-only contract.
+    ///
+    /// Fails with `NetworkMismatch` for proposals that recorded a network
+    /// label; approve those with `approve_upgrade_on_network`. Fails with
+    /// `LegacyApprovalDisabled` once governance requires `approve_upgrade_v2`.
+    /// A repeated approval fails with `AlreadyApproved` or is a no-op,
+    /// depending on `set_duplicate_approval`.
+    pub fn approve_upgrade(env: Env, proposal_id: u64, signer: Address) {
+        Self::require_legacy_approval(&env);
+        Self::approve_upgrade_checked(&env, proposal_id, signer, None);
+    }
 
-    pub fn is_read_only(env: Env) -> bool {
-        env.storage().instance().get(&DataKey::ReadOnlyMode).unwrap_or(false)
+    /// `approve_upgrade` for a signer who states which network they believe
+    /// they are approving on. Fails with `NetworkMismatch` unless `network`
+    /// equals the label the proposal recorded; proposals created without a
+    /// label skip the check.
+    pub fn approve_upgrade_on_network(env: Env, proposal_id: u64, signer: Address, network: Symbol) {
+        Self::require_legacy_approval(&env);
+        Self::approve_upgrade_checked(&env, proposal_id, signer, Some(network));
     }
 
-    pub fn set_read_only_mode(env: Env, enabled: bool) {
-        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
-        admin.require_auth();
-        env.storage().instance().set(&DataKey::ReadOnlyMode, &enabled);
-        env.events().publish(
-            (symbol_short!("ROModeChg"),),
-            ReadOnlyModeEvent { enabled, admin, timestamp: env.ledger().timestamp(), event_version: EVENT_SCHEMA_VERSION },
-        );
+    /// Approve an upgrade proposal with its substance in the signed
+    /// arguments, so a wallet shows the signer what they approve.
+    /// `wasm_hash` must equal the proposal's hash and `description` the
+    /// value of `get_approval_description`, or the call fails with
+    /// `ApprovalMismatch`; a committed proposal must be revealed first.
+    /// `network` is checked as in `approve_upgrade_on_network`. A `digest`
+    /// must equal `proposal_digest`, also failing with `ApprovalMismatch`.
+    ///
+    /// Returns the proposal's approval count after the call; a duplicate
+    /// accepted under `DuplicateApproval::Idempotent` returns it unchanged.
+    pub fn approve_upgrade_v2(
+        env: Env,
+        proposal_id: u64,
+        signer: Address,
+        wasm_hash: BytesN<32>,
+        description: String,
+        network: Option<Symbol>,
+        digest: Option<BytesN<32>>,
+    ) -> u32 {
+        Self::require_function_enabled(&env, "approve_upgrade_v2");
+        Self::ensure_proposal_storage(&env);
+        if grainlify_upgradeable::get_upgrade_hash(&env, proposal_id) != Some(wasm_hash)
+            || Self::get_approval_description(env.clone(), proposal_id) != description
+            || digest.is_some_and(|digest| digest != Self::proposal_digest(env.clone(), proposal_id))
+        {
+            panic!("{}", ContractError::ApprovalMismatch as u32);
+        }
+        Self::approve_upgrade_checked(&env, proposal_id, signer, network);
+        MultiSig::get_valid_approvals(&env, proposal_id).len()
+    }
+
+    /// `approve_upgrade`, then execute the proposal as `signer` in the same
+    /// call if `preview_execution` finds nothing in the way. When the
+    /// threshold, timelock, window or a pause still gates it, only the
+    /// approval is recorded and the outcome lists the failing checks.
+    pub fn approve_and_execute(env: Env, proposal_id: u64, signer: Address) -> ApproveExecuteOutcome {
+        Self::require_function_enabled(&env, "approve_and_execute");
+        Self::approve_upgrade(env.clone(), proposal_id, signer.clone());
+        let failing = Self::preview_execution(env.clone(), proposal_id).failing;
+        let executed = failing.is_empty();
+        if executed {
+            Self::execute_upgrade_inner(env, proposal_id, false, Some(signer));
+        }
+        ApproveExecuteOutcome { proposal_id, executed, failing }
     }
 
-    fn require_not_read_only(env: &Env) {
-        let read_only: bool = env.storage().instance().get(&DataKey::ReadOnlyMode).unwrap_or(false);
-        if read_only { panic!("Read-only mode"); }
+    /// Whether `approve_upgrade` and `approve_upgrade_on_network` are
+    /// still accepted.
+    pub fn is_legacy_approval_enabled(env: Env) -> bool {
+        !env.storage().instance().has(&ApprovalKey::LegacyApprovalDisabled)
     }
 
-    // ========================================================================
-    // Config Snapshots
-    // ========================================================================
+    /// Turn `approve_upgrade` and `approve_upgrade_on_network` on or off.
+    /// With them off every approval goes through `approve_upgrade_v2`.
+    /// Governance only; multisig deployments use a
+    /// `ProposalAction::SetLegacyApprovalEnabled` bundle.
+    pub fn set_legacy_approval_enabled(env: Env, enabled: bool) {
+        Self::require_function_enabled(&env, "set_legacy_approval_enabled");
+        grainlify_upgradeable::require_governance(&env);
+        Self::require_not_read_only(&env);
+        Self::store_legacy_approval_enabled(&env, enabled);
+    }
 
-    pub fn create_config_snapshot(env: Env) -> u64 {
-        let admin: Address = env.storage().instance().get(&DataKey::Admin).expect("Admin not set");
-        admin.require_auth();
-        // [GUARDRAIL] Snapshots are state mutations — blocked in read-only mode
+    fn store_legacy_approval_enabled(env: &Env, enabled: bool) {
+        if enabled {
+            env.storage().instance().remove(&ApprovalKey::LegacyApprovalDisabled);
+        } else {
+            env.storage().instance().set(&ApprovalKey::LegacyApprovalDisabled, &true);
+        }
+    }
+
+    pub fn get_duplicate_approval(env: Env) -> DuplicateApproval {
+        env.storage()
+            .instance()
+            .get(&ApprovalKey::DuplicateApproval)
+            .unwrap_or(DuplicateApproval::Strict)
+    }
+
+    /// Choose how every approval entrypoint treats a repeated approval from
+    /// the same signer. Governance only; multisig deployments use a
+    /// `ProposalAction::SetDuplicateApproval` bundle.
+    pub fn set_duplicate_approval(env: Env, mode: DuplicateApproval) {
+        Self::require_function_enabled(&env, "set_duplicate_approval");
+        grainlify_upgradeable::require_governance(&env);
         Self::require_not_read_only(&env);
+        Self::store_duplicate_approval(&env, mode);
+    }
 
-        let next_id: u64 = env.storage().instance()
-            .get(&DataKey::SnapshotCounter).unwrap_or(0) + 1;
+    fn store_duplicate_approval(env: &Env, mode: DuplicateApproval) {
+        env.storage().instance().set(&ApprovalKey::DuplicateApproval, &mode);
+    }
 
-        let (multisig_threshold, multisig_signers) = match MultiSig::get_config_opt(&env) {
-            Some(cfg) => (cfg.threshold, cfg.signers),
-            None => (0u32, Vec::new(&env)),
+    /// `approve_upgrade` that records a refusal instead of failing, like
+    /// `attempt_execute_upgrade`. Runs the `approve_upgrade` checks that do
+    /// not write first and, if one fails, counts it as an `Approve`
+    /// governance failure and returns its code, or 0 where
+    /// `approve_upgrade` fails with a message. Returns `None` after
+    /// approving, or when a repeated approval is a no-op.
+    pub fn attempt_approve_upgrade(env: Env, signer: Address, proposal_id: u64) -> Option<u32> {
+        Self::require_function_enabled(&env, "attempt_approve_upgrade");
+        let Some(code) = Self::approval_refusal(&env, &signer, proposal_id) else {
+            Self::approve_upgrade(env, proposal_id, signer);
+            return None;
         };
+        signer.require_auth();
+        monitoring::track_governance_failure(
+            &env,
+            symbol_short!("appr_upg"),
+            monitoring::GovernanceStage::Approve,
+            signer.clone(),
+            code,
+            Self::trips_breaker(&env, &signer),
+        );
+        Some(code)
+    }
 
-        let snapshot = CoreConfigSnapshot {
-            id: next_id,
-            timestamp: env.ledger().timestamp(),
-            admin: env.storage().instance().get(&DataKey::Admin),
-            version: env.storage().instance().get(&DataKey::Version).unwrap_or(0),
-            previous_version: env.storage().instance().get(&DataKey::PreviousVersion),
-            multisig_threshold,
-            multisig_signers,
+    /// Code `approve_upgrade` fails with for `signer` on `proposal_id`, as
+    /// far as it can be told without approving.
+    fn approval_refusal(env: &Env, signer: &Address, proposal_id: u64) -> Option<u32> {
+        if !Self::is_legacy_approval_enabled(env.clone()) {
+            return Some(ContractError::LegacyApprovalDisabled as u32);
+        }
+        if MultiSig::is_contract_paused(env) {
+            return Some(ContractError::Paused as u32);
+        }
+        if TokenVoting::get_config(env).is_some() {
+            return Some(0);
+        }
+        if MultiSig::get_config_opt(env).is_none() {
+            return Some(ContractError::WrongGovernanceMode as u32);
+        }
+        if Self::get_reveal_deadline(env.clone()) == RevealDeadline::BeforeApproval
+            && !Self::is_revealed(env, proposal_id)
+        {
+            return Some(ContractError::UpgradeNotRevealed as u32);
+        }
+        if env.storage().persistent().has(&ProposalKey::UpgradeNetwork(proposal_id)) {
+            return Some(ContractError::NetworkMismatch as u32);
+        }
+        if !MultiSig::is_signer(env, signer) {
+            return Some(0);
+        }
+        let Some(proposal) = MultiSig::get_proposal_opt(env, proposal_id) else {
+            return Some(0);
         };
+        if proposal.executed
+            || proposal.cancelled
+            || MultiSig::is_expired(env, proposal_id)
+            || MultiSig::is_lapsed(env, proposal_id)
+        {
+            return Some(0);
+        }
+        let duplicate = MultiSig::get_valid_approvals(env, proposal_id).contains(signer);
+        (duplicate && Self::get_duplicate_approval(env.clone()) == DuplicateApproval::Strict)
+            .then_some(ContractError::AlreadyApproved as u32)
+    }
 
-        env.storage().instance().set(&DataKey::ConfigSnapshot(next_id), &snapshot);
+    fn require_legacy_approval(env: &Env) {
+        if !Self::is_legacy_approval_enabled(env.clone()) {
+            panic!("{}", ContractError::LegacyApprovalDisabled as u32);
+        }
+    }
 
-        let mut index: Vec<u64> = env.storage().instance()
-            .get(&DataKey::SnapshotIndex).unwrap_or(Vec::new(&env));
-        index.push_back(next_id);
+    fn approve_upgrade_checked(env: &Env, proposal_id: u64, signer: Address, network: Option<Symbol>) {
+        Self::require_function_enabled(env, "approve_upgrade");
+        Self::ensure_proposal_storage(env);
+        Self::require_not_paused(env);
+        if TokenVoting::get_config(env).is_some() {
+            panic!("Token voting active - use vote");
+        }
+        Self::require_governance_mode(env, InitMode::Multisig);
+        if Self::get_reveal_deadline(env.clone()) == RevealDeadline::BeforeApproval {
+            Self::require_revealed(env, proposal_id);
+        }
+        let recorded: Option<Symbol> =
+            env.storage().persistent().get(&ProposalKey::UpgradeNetwork(proposal_id));
+        if let Some(recorded) = &recorded {
+            if network.as_ref() != Some(recorded) {
+                panic!("{}", ContractError::NetworkMismatch as u32);
+            }
+        }
+        signer.require_auth();
+        match MultiSig::try_approve(env, proposal_id, &signer) {
+            Ok(()) => {}
+            Err(multisig::MultiSigError::AlreadyApproved) => {
+                if Self::get_duplicate_approval(env.clone()) == DuplicateApproval::Strict {
+                    panic!("{}", ContractError::AlreadyApproved as u32);
+                }
+                return;
+            }
+            Err(e) => panic!("{:?}", e),
+        }
+        if let Some(network) = recorded {
+            Self::emit_network_event(env, symbol_short!("approved"), proposal_id, network, signer.clone());
+        }
+        let proposer: Option<Address> =
+            env.storage().persistent().get(&DataKey::UpgradeProposalProposer(proposal_id));
+        if proposer.is_some_and(|proposer| proposer != signer) {
+            env.storage().persistent().set(&ProposalKey::UpgradeAmendLocked(proposal_id), &true);
+        }
+        Self::start_timelock_if_ready(env, proposal_id);
+    }
 
-        if index.len() > CONFIG_SNAPSHOT_LIMIT {
-            let oldest_snapshot_id = index.get(0).unwrap();
-            env.storage().instance().remove(&DataKey::ConfigSnapshot(oldest_snapshot_id));
-            let mut trimmed = Vec::new(&env);
-            for i in 1..index.len() { trimmed.push_back(index.get(i).unwrap()); }
-            index = trimmed;
+    /// Open an upgrade proposal that publishes only
+    /// `commitment = sha256(wasm_hash || salt)`. The proposer later calls
+    /// `reveal_upgrade`; see `RevealDeadline` for when. Multisig mode only.
+    pub fn propose_upgrade_commit(env: Env, proposer: Address, commitment: BytesN<32>, expiry: u64) -> u64 {
+        Self::require_function_enabled(&env, "propose_upgrade_commit");
+        Self::ensure_proposal_storage(&env);
+        Self::require_not_read_only(&env);
+        Self::require_not_paused(&env);
+        if TokenVoting::get_config(&env).is_some() {
+            panic!("Token voting active - commit-reveal proposals need multisig");
         }
+        let proposal_id = MultiSig::propose(&env, proposer.clone(), expiry);
+        Self::record_proposal_created(&env, proposal_id);
+        Self::record_network_label(&env, proposal_id, &proposer);
+        Self::track_pending_upgrade(&env, proposal_id);
+        let expiry = if expiry == 0 { None } else { Some(expiry) };
+        let commitment = commit_reveal::create_commitment(&env, proposer.clone(), commitment, expiry);
+        env.storage().persistent().set(&ProposalKey::UpgradeCommitment(proposal_id), &commitment);
+        env.storage().persistent().set(&DataKey::UpgradeProposalProposer(proposal_id), &proposer);
+        env.events().publish((symbol_short!("upgrade"), symbol_short!("commit")), proposal_id);
+        proposal_id
+    }
 
-        env.storage().instance().set(&DataKey::SnapshotIndex, &index);
-        env.storage().instance().set(&DataKey::SnapshotCounter, &next_id);
+    /// Reveal the WASM hash behind a committed proposal. Only the proposer
+    /// may reveal; panics with `RevealMismatch` unless
+    /// `sha256(wasm_hash || salt)` equals the commitment.
+    pub fn reveal_upgrade(env: Env, proposal_id: u64, wasm_hash: BytesN<32>, salt: Bytes) {
+        Self::require_function_enabled(&env, "reveal_upgrade");
+        Self::ensure_proposal_storage(&env);
+        Self::require_not_read_only(&env);
+        Self::require_not_paused(&env);
+        let commitment: commit_reveal::Commitment = env
+            .storage()
+            .persistent()
+            .get(&ProposalKey::UpgradeCommitment(proposal_id))
+            .unwrap_or_else(|| panic!("{}", ContractError::ProposalNotFound as u32));
+        let proposal = MultiSig::get_proposal_opt(&env, proposal_id)
+            .unwrap_or_else(|| panic!("{}", ContractError::ProposalNotFound as u32));
+        if proposal.executed || proposal.cancelled {
+            panic!("Proposal is closed");
+        }
+
+        let revealer = commitment.creator.clone();
+        match commit_reveal::verify_reveal(&env, &commitment, revealer, wasm_hash.clone().into(), salt) {
+            Ok(()) => {}
+            Err(commit_reveal::Error::CommitmentExpired) => panic!("Proposal expired"),
+            Err(_) => panic!("{}", ContractError::RevealMismatch as u32),
+        }
+        Self::require_not_blocked(&env, &wasm_hash);
 
+        env.storage().persistent().remove(&ProposalKey::UpgradeCommitment(proposal_id));
+        env.storage().persistent().set(&DataKey::UpgradeProposal(proposal_id), &wasm_hash);
         env.events().publish(
-            (symbol_short!("cfg_snap"), symbol_short!("create")),
-            (next_id, snapshot.timestamp),
+            (symbol_short!("upgrade"), symbol_short!("revealed")),
+            (proposal_id, wasm_hash),
         );
-        next_id
-    }
-
-    pub fn list_config_snapshots(env: Env) -> Vec<CoreConfigSnapshot> {
-        let index: Vec<u64> = env.storage().instance()
-            .get(&DataKey::SnapshotIndex).unwrap_or(Vec::new(&env));
-        let mut snapshots: Vec<CoreConfigSnapshot> = Vec::new(&env);
-        for i in 0..index.len() {
-            let snapshot_id = index.get(i).unwrap();
-            if let Some(snapshot) = env.storage().instance()
-                .get::<DataKey, CoreConfigSnapshot>(&DataKey::ConfigSnapshot(snapshot_id))
-            {
-                snapshots.push_back(snapshot);
-            }
-        }
-        snapshots
     }
 
-    pub fn get_config_snapshot(env: Env, snapshot_id: u64) -> Option<CoreConfigSnapshot> {
-        env.storage().instance().get(&DataKey::ConfigSnapshot(snapshot_id))
+    /// Commitment still awaiting `reveal_upgrade`, if any.
+    pub fn get_upgrade_commitment(env: Env, proposal_id: u64) -> Option<commit_reveal::Commitment> {
+        Self::ensure_proposal_storage(&env);
+        env.storage().persistent().get(&ProposalKey::UpgradeCommitment(proposal_id))
     }
 
-    pub fn get_latest_config_snapshot(env: Env) -> Option<CoreConfigSnapshot> {
-        let index: Vec<u64> = env.storage().instance()
-            .get(&DataKey::SnapshotIndex).unwrap_or(Vec::new(&env));
-        if index.is_empty() { return None; }
-        let latest_id = index.get(index.len() - 1).unwrap();
-        env.storage().instance().get(&DataKey::ConfigSnapshot(latest_id))
+    pub fn get_reveal_deadline(env: Env) -> RevealDeadline {
+        env.storage()
+            .instance()
+            .get(&DataKey::RevealDeadline)
+            .unwrap_or(RevealDeadline::BeforeApproval)
     }
 
-    pub fn get_snapshot_count(env: Env) -> u32 {
-        let index: Vec<u64> = env.storage().instance()
-            .get(&DataKey::SnapshotIndex).unwrap_or(Vec::new(&env));
-        index.len()
+    /// Choose when commit-reveal proposals must be revealed. Admin only.
+    /// Applies to proposals already open as well as new ones.
+    pub fn set_reveal_deadline(env: Env, deadline: RevealDeadline) {
+        Self::require_function_enabled(&env, "set_reveal_deadline");
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic!("{}", ContractError::NotInitialized as u32));
+        admin.require_auth();
+        Self::require_not_read_only(&env);
+        env.storage().instance().set(&DataKey::RevealDeadline, &deadline);
+        Self::record_config_change(&env, "reveal_deadline", None);
     }
 
-    pub fn compare_snapshots(env: Env, from_id: u64, to_id: u64) -> SnapshotDiff {
-        let from: CoreConfigSnapshot = env.storage().instance()
-            .get(&DataKey::ConfigSnapshot(from_id))
-            .unwrap_or_else(|| panic!("Snapshot not found: from_id"));
-        let to: CoreConfigSnapshot = env.storage().instance()
-            .get(&DataKey::ConfigSnapshot(to_id))
-            .unwrap_or_else(|| panic!("Snapshot not found: to_id"));
-        SnapshotDiff {
-            from_id, to_id,
-            admin_changed: from.admin != to.admin,
-            version_changed: from.version != to.version,
-            previous_version_changed: from.previous_version != to.previous_version,
-            multisig_threshold_changed: from.multisig_threshold != to.multisig_threshold,
-            multisig_signers_changed: from.multisig_signers != to.multisig_signers,
-            from_version: from.version,
-            to_version: to.version,
-        }
+    /// Label of the network this deployment runs on, recorded by new
+    /// upgrade proposals; `None` when the safeguard is off.
+    pub fn get_network_label(env: Env) -> Option<Symbol> {
+        env.storage().instance().get(&DataKey::NetworkLabel)
     }
 
-    /// [FIX-C02] Restore now uses two-step process when admin address changes.
-    ///
-    /// If the snapshot would change the admin address, a `PendingAdminRestore`
-    /// is created instead of applying immediately. The new admin address must
-    /// call `confirm_admin_restore()` to finalize.
-    ///
-    /// If the snapshot does NOT change the admin, restore applies immediately
-    /// (same behavior as before).
-    pub fn restore_config_snapshot(env: Env, snapshot_id: u64) {
-        let admin: Address = env.storage().instance()
-            .get(&DataKey::Admin).expect("Admin not set");
-        admin.require_auth();
-        // [GUARDRAIL] Restores mutate state — blocked in read-only mode
+    /// Set or clear the network label, which is also the environment
+    /// `assert_environment` checks, recording the current network
+    /// passphrase hash with it. Governance only; multisig deployments use a
+    /// `ProposalAction::SetNetworkLabel` or `ClearNetworkLabel` bundle.
+    /// Proposals keep the label they were created with.
+    pub fn set_network_label(env: Env, label: Option<Symbol>) {
+        Self::require_function_enabled(&env, "set_network_label");
+        grainlify_upgradeable::require_governance(&env);
         Self::require_not_read_only(&env);
+        Self::store_network_label(&env, label, None);
+    }
 
-        Self::restore_snapshot_with_checks(&env, snapshot_id);
+    fn store_network_label(env: &Env, label: Option<Symbol>, proposal_id: Option<u64>) {
+        Self::store_environment(env, label);
+        Self::record_config_change(env, "network_label", proposal_id);
+    }
 
-        env.events().publish(
-            (symbol_short!("cfg_snap"), symbol_short!("restore")),
-            (snapshot_id, env.ledger().timestamp()),
-        );
+    /// Network label upgrade proposal `proposal_id` was created under.
+    pub fn get_proposal_network(env: Env, proposal_id: u64) -> Option<Symbol> {
+        Self::ensure_proposal_storage(&env);
+        env.storage().persistent().get(&ProposalKey::UpgradeNetwork(proposal_id))
     }
 
-    fn restore_snapshot_with_checks(env: &Env, snapshot_id: u64) {
+    /// Every tunable governance parameter in one read.
+    pub fn get_config(env: Env) -> TunableConfig {
+        TunableConfig {
+            timelock_delay: Self::get_timelock_delay(env.clone()),
+            config_change_delay: Self::get_config_change_delay(env.clone()),
+            max_postponement: Self::get_max_postponement(env.clone()),
+            stale_proposal_age: Self::get_stale_proposal_age(env.clone()),
+            ttl_warning_threshold: Self::get_ttl_warning_threshold(env.clone()),
+            require_nonce: Self::get_require_nonce(env.clone()),
+            reveal_deadline: Self::get_reveal_deadline(env.clone()),
+            threshold: MultiSig::get_config_opt(&env).map_or(0, |config| config.threshold),
+            staging_delay: Self::get_staging_delay(env.clone()),
+            network_label: Self::get_network_label(env.clone()),
+            signer_acceptance_window: Self::get_signer_acceptance_window(env.clone()),
+            signer_inactivity_period: Self::get_signer_inactivity_period(env),
+        }
+    }
 
-        // [FIX-M02] Explicit error when snapshot is pruned
-        let snapshot: CoreConfigSnapshot = env.storage().instance()
-            .get(&DataKey::ConfigSnapshot(snapshot_id))
-            .unwrap_or_else(|| panic!("{}", ContractError::SnapshotPruned as u32));
+    /// Who last changed the `get_config` field named `key` (e.g.
+    /// `timelock_delay`) and when: the admin, or the proposal that applied
+    /// it through `execute_proposal`. `None` for fields still at their
+    /// initial value.
+    pub fn get_config_provenance(env: Env, key: Symbol) -> Option<ConfigProvenance> {
+        env.storage().instance().get(&ConfigKey::Provenance(key))
+    }
 
-        let current_admin: Option<Address> = env.storage().instance().get(&DataKey::Admin);
+    /// Records a change of the `TunableConfig` field `field`, made by
+    /// `proposal_id` or, for `None`, directly by the admin.
+    fn record_config_change(env: &Env, field: &str, proposal_id: Option<u64>) {
+        let provenance = ConfigProvenance {
+            source: proposal_id.map_or(ConfigSource::Admin, ConfigSource::Proposal),
+            changed_at: env.ledger().timestamp(),
+        };
+        env.storage().instance().set(&ConfigKey::Provenance(Symbol::new(env, field)), &provenance);
+    }
 
-        // [GUARDRAIL] Prevent no-op restore to save gas
-        let current_version: u32 = env.storage().instance().get(&DataKey::Version).unwrap_or(0);
-        let multisig_opt = MultiSig::get_config_opt(&env);
-        let current_threshold = multisig_opt.as_ref().map(|c| c.threshold).unwrap_or(0);
-        let current_signers = multisig_opt.as_ref().map(|c| c.signers.clone()).unwrap_or(Vec::new(&env));
+    /// Change several tunables at once; fields left `None` keep their value
+    /// and nothing changes if any field is invalid. Governance only.
+    /// Multisig deployments use a `ProposalAction::UpdateConfig` bundle.
+    pub fn set_config(env: Env, update: ConfigUpdate) {
+        Self::require_function_enabled(&env, "set_config");
+        grainlify_upgradeable::require_governance(&env);
+        Self::require_not_read_only(&env);
+        Self::apply_config_update(&env, update, None);
+    }
 
-        if snapshot.version == current_version 
-            && snapshot.admin == current_admin 
-            && snapshot.multisig_threshold == current_threshold 
-            && snapshot.multisig_signers == current_signers 
-        {
-            return;
+    fn apply_config_update(env: &Env, update: ConfigUpdate, proposal_id: Option<u64>) {
+        if let Some(delay) = update.timelock_delay {
+            Self::store_timelock_delay(env, delay, proposal_id);
         }
+        if let Some(delay) = update.config_change_delay {
+            Self::store_config_change_delay(env, delay, proposal_id);
+        }
+        if let Some(max_seconds) = update.max_postponement {
+            env.storage().instance().set(&DataKey::MaxPostponement, &max_seconds);
+            Self::record_config_change(env, "max_postponement", proposal_id);
+        }
+        if let Some(age) = update.stale_proposal_age {
+            env.storage().instance().set(&HealthKey::StaleProposalAge, &age);
+            Self::record_config_change(env, "stale_proposal_age", proposal_id);
+        }
+        if let Some(ledgers) = update.ttl_warning_threshold {
+            env.storage().instance().set(&TtlKey::TtlWarningThreshold, &ledgers);
+            Self::record_config_change(env, "ttl_warning_threshold", proposal_id);
+        }
+        if let Some(required) = update.require_nonce {
+            env.storage().instance().set(&DataKey::RequireNonce, &required);
+            Self::record_config_change(env, "require_nonce", proposal_id);
+        }
+        if let Some(threshold) = update.threshold {
+            Self::store_threshold(env, threshold, proposal_id);
+        }
+        if let Some(seconds) = update.signer_acceptance_window {
+            Self::store_signer_acceptance_window(env, seconds, proposal_id);
+        }
+        if let Some(period_secs) = update.signer_inactivity_period {
+            Self::store_signer_inactivity_period(env, period_secs, proposal_id);
+        }
+        env.events().publish(
+            (symbol_short!("config"), symbol_short!("updated")),
+            ConfigUpdatedEvent {
+                update,
+                proposal_id,
+                timestamp: env.ledger().timestamp(),
+                event_version: EVENT_SCHEMA_VERSION,
+            },
+        );
+    }
 
-        // [FIX-C02] Detect if restore would change admin — if so, require two-step confirmation
-        let admin_would_change = snapshot.admin != current_admin;
-
-        if admin_would_change {
-            // Create pending restore — new admin must confirm
-            let pending = PendingAdminRestore {
-                snapshot_id,
-                proposed_admin: snapshot.admin.clone().expect("Snapshot has no admin to restore"),
-                initiated_at: env.ledger().timestamp(),
-                expires_at: env.ledger().timestamp().saturating_add(DEFAULT_TIMELOCK_DELAY),
-            };
-            env.storage().instance().set(&DataKey::PendingAdminRestore, &pending);
+    /// Seconds after which an open upgrade proposal makes `health_check`
+    /// report `stale_proposal`.
+    pub fn get_stale_proposal_age(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&HealthKey::StaleProposalAge)
+            .unwrap_or(DEFAULT_STALE_PROPOSAL_AGE)
+    }
 
-            env.events().publish(
-                (symbol_short!("cfg_snap"), symbol_short!("adm_pnd")),
-                (snapshot_id, pending.proposed_admin, pending.expires_at),
-            );
-            // Return early — do not apply yet
-            return;
-        }
+    /// Set the stale-proposal age; 0 turns the check off. Governance only.
+    pub fn set_stale_proposal_age(env: Env, age_secs: u64) {
+        Self::require_function_enabled(&env, "set_stale_proposal_age");
+        grainlify_upgradeable::require_governance(&env);
+        Self::require_not_read_only(&env);
+        env.storage().instance().set(&HealthKey::StaleProposalAge, &age_secs);
+        Self::record_config_change(&env, "stale_proposal_age", None);
+    }
 
-        // Admin unchanged — apply restore immediately
-        Self::apply_snapshot_restore(&env, &snapshot);
+    /// Seconds without activity after which `health_check` lists a signer
+    /// as inactive; 0 when the report is off.
+    pub fn get_signer_inactivity_period(env: Env) -> u64 {
+        env.storage().instance().get(&HealthKey::SignerInactivityPeriod).unwrap_or(0)
     }
 
-    /// [FIX-C02] The proposed new admin confirms an admin-changing snapshot restore.
-    ///
-    /// Only the address that would BECOME the new admin can confirm this.
-    /// This ensures a compromised old key cannot silently transfer control.
-    pub fn confirm_admin_restore(env: Env, snapshot_id: u64) {
-        let pending: PendingAdminRestore = env.storage().instance()
-            .get(&DataKey::PendingAdminRestore)
-            .unwrap_or_else(|| panic!("No pending admin restore found"));
+    /// Set the signer inactivity period; 0 turns the report off.
+    /// Governance only; multisig deployments set `signer_inactivity_period`
+    /// in a `ProposalAction::UpdateConfig` bundle.
+    pub fn set_signer_inactivity_period(env: Env, period_secs: u64) {
+        Self::require_function_enabled(&env, "set_signer_inactivity_period");
+        grainlify_upgradeable::require_governance(&env);
+        Self::require_not_read_only(&env);
+        Self::store_signer_inactivity_period(&env, period_secs, None);
+    }
 
-        if pending.snapshot_id != snapshot_id {
-            panic!("Snapshot ID does not match pending restore");
-        }
+    fn store_signer_inactivity_period(env: &Env, period_secs: u64, proposal_id: Option<u64>) {
+        env.storage().instance().set(&HealthKey::SignerInactivityPeriod, &period_secs);
+        Self::record_config_change(env, "signer_inactivity_period", proposal_id);
+    }
 
-        // The proposed new admin must authorize this
-        pending.proposed_admin.require_auth();
+    /// Proposals created, approvals given, rejections (revoked approvals
+    /// and cancellations) and last activity of `signer`. Removed signers
+    /// keep their stats with `removed_at` set; `None` for addresses that
+    /// were never active and are not signers.
+    pub fn get_signer_stats(env: Env, signer: Address) -> Option<multisig::SignerStats> {
+        MultiSig::get_signer_stats(&env, &signer)
+    }
 
-        // [GUARDRAIL] Confirm is a state mutation — blocked in read-only mode
-        Self::require_not_read_only(&env);
+    /// `get_signer_stats` for every current signer, in signer order.
+    pub fn list_signer_stats(env: Env) -> Vec<multisig::SignerStats> {
+        MultiSig::list_signer_stats(&env)
+    }
 
-        // Check expiry
-        if env.ledger().timestamp() > pending.expires_at {
-            env.storage().instance().remove(&DataKey::PendingAdminRestore);
-            panic!("Pending admin restore has expired");
-        }
+    /// Current multisig signer-set epoch, bumped whenever a signer joins or
+    /// leaves. Carried by the multisig proposal and approval events.
+    pub fn get_signer_epoch(env: Env) -> u64 {
+        MultiSig::get_signer_epoch(&env)
+    }
 
-        let snapshot: CoreConfigSnapshot = env.storage().instance()
-            .get(&DataKey::ConfigSnapshot(snapshot_id))
-            .unwrap_or_else(|| panic!("Snapshot not found"));
+    /// Approvals of an open proposal that count toward its threshold: from
+    /// current signers, given under the current signer epoch. The raw list
+    /// stays in the proposal; see `MultiSig::get_valid_approvals`.
+    pub fn get_valid_approvals(env: Env, proposal_id: u64) -> Vec<Address> {
+        Self::ensure_proposal_storage(&env);
+        MultiSig::get_valid_approvals(&env, proposal_id)
+    }
 
-        Self::apply_snapshot_restore(&env, &snapshot);
+    /// Index of `signer` in the current signer list, or `None` for a
+    /// non-signer. Signers keep their index when others join; removing a
+    /// signer shifts those after it.
+    pub fn get_signer_index(env: Env, signer: Address) -> Option<u32> {
+        MultiSig::signer_index(&env, &signer)
+    }
 
-        env.storage().instance().remove(&DataKey::PendingAdminRestore);
+    /// Signer at `index` in the current signer list. Panics when out of
+    /// range.
+    pub fn get_signer_at(env: Env, index: u32) -> Address {
+        MultiSig::get_config_opt(&env)
+            .and_then(|config| config.signers.get(index))
+            .unwrap_or_else(|| panic!("Signer index out of range"))
+    }
 
-        env.events().publish(
-            (symbol_short!("cfg_snap"), symbol_short!("adm_conf")),
-            (snapshot_id, env.ledger().timestamp()),
-        );
+    /// `get_valid_approvals` as a bitmap: bit `i % 8` of byte `i / 8` is set
+    /// when the signer `get_signer_at(i)` approved. Valid approvals all
+    /// date from the current signer epoch, so the indexes are those of the
+    /// current list, and this plus the signer list reconstructs them.
+    /// All bits are clear for an unknown, executed or lapsed proposal.
+    pub fn get_approval_bitmap(env: Env, proposal_id: u64) -> Bytes {
+        Self::ensure_proposal_storage(&env);
+        MultiSig::approval_bitmap(&env, proposal_id)
     }
 
-    /// Internal: applies snapshot state to storage
-    fn apply_snapshot_restore(env: &Env, snapshot: &CoreConfigSnapshot) {
-        if let Some(ref snapshot_admin) = snapshot.admin {
-            env.storage().instance().set(&DataKey::Admin, snapshot_admin);
-        } else {
-            env.storage().instance().remove(&DataKey::Admin);
+    /// Adds `proposal_id` to the pending set, dropping entries that closed
+    /// so the set stays as small as the number of open proposals. Panics
+    /// with `TooManyPending` once `MAX_PENDING_UPGRADES` are open.
+    fn track_pending_upgrade(env: &Env, proposal_id: u64) {
+        let mut kept = Vec::new(env);
+        for id in monitoring::pending_upgrades(env).iter() {
+            if monitoring::is_pending(env, id) {
+                kept.push_back(id);
+            }
         }
+        if kept.len() >= MAX_PENDING_UPGRADES {
+            panic!("{}", ProposalStorageError::TooManyPending as u32);
+        }
+        kept.push_back(proposal_id);
+        monitoring::store_pending_upgrades(env, &kept);
+    }
 
-        env.storage().instance().set(&DataKey::Version, &snapshot.version);
-
-        match snapshot.previous_version {
-            Some(prev) => env.storage().instance().set(&DataKey::PreviousVersion, &prev),
-            None => env.storage().instance().remove(&DataKey::PreviousVersion),
+    fn untrack_pending_upgrade(env: &Env, proposal_id: u64) {
+        let mut pending = monitoring::pending_upgrades(env);
+        if let Some(index) = pending.first_index_of(proposal_id) {
+            pending.remove(index);
+            monitoring::store_pending_upgrades(env, &pending);
         }
+    }
 
-        if snapshot.multisig_threshold > 0 {
-            let config = multisig::MultiSigConfig {
-                signers: snapshot.multisig_signers.clone(),
-                threshold: snapshot.multisig_threshold,
-            };
-            MultiSig::set_config(env, config);
-        } else {
-            MultiSig::clear_config(env);
+    fn record_network_label(env: &Env, proposal_id: u64, proposer: &Address) {
+        if let Some(network) = Self::get_network_label(env.clone()) {
+            env.storage().persistent().set(&ProposalKey::UpgradeNetwork(proposal_id), &network);
+            Self::emit_network_event(env, symbol_short!("proposed"), proposal_id, network, proposer.clone());
         }
     }
 
-    /// [FIX-L04] Returns None on inconsistency instead of panicking — view fn safety
-    pub fn get_rollback_info(env: Env) -> RollbackInfo {
-        let current_version: u32 = env.storage().instance().get(&DataKey::Version).unwrap_or(0);
-        let previous_version: u32 = env.storage().instance().get(&DataKey::PreviousVersion).unwrap_or(0);
-        let rollback_available = previous_version > 0;
+    fn emit_network_event(env: &Env, action: Symbol, proposal_id: u64, network: Symbol, signer: Address) {
+        env.events().publish(
+            (symbol_short!("network"), action),
+            NetworkLabelEvent {
+                proposal_id,
+                network,
+                signer,
+                timestamp: env.ledger().timestamp(),
+                external_id: Self::external_id_of(env, proposal_id),
+                event_version: EVENT_SCHEMA_VERSION,
+            },
+        );
+    }
 
-        let migration_state: Option<MigrationState> = env.storage().instance().get(&DataKey::MigrationState);
-        let has_migration = migration_state.is_some();
-        let migration_from_version = migration_state.as_ref().map(|m| m.from_version).unwrap_or(0);
-        let migration_to_version = migration_state.as_ref().map(|m| m.to_version).unwrap_or(0);
-        let migration_timestamp = migration_state.as_ref().map(|m| m.migrated_at).unwrap_or(0);
+    /// Cancel a pending upgrade proposal. Any signer may cancel. Records
+    /// an empty reason, so it fails while reasons are required.
+    pub fn cancel_upgrade(env: Env, proposal_id: u64, canceller: Address) {
+        Self::cancel_upgrade_with_reason(env.clone(), proposal_id, canceller, String::from_str(&env, ""));
+    }
 
-        let index: Vec<u64> = env.storage().instance()
-            .get(&DataKey::SnapshotIndex).unwrap_or(Vec::new(&env));
-        let snapshot_count = index.len();
-        let has_snapshot = snapshot_count > 0;
+    /// `cancel_upgrade` with why, recorded in `get_rejections` and the
+    /// `("upgrade", "rejected")` event. `reason` is bounded by the `reason`
+    /// string limit. Shares the `cancel_upgrade` kill switch.
+    pub fn cancel_upgrade_with_reason(env: Env, proposal_id: u64, canceller: Address, reason: String) {
+        Self::require_function_enabled(&env, "cancel_upgrade");
+        Self::ensure_proposal_storage(&env);
+        Self::require_rejection_reason(&env, &reason);
+        MultiSig::cancel(&env, proposal_id, canceller.clone());
+        env.storage().persistent().remove(&DataKey::UpgradeTimelock(proposal_id));
+        Self::record_proposal_cancelled(&env, proposal_id);
+        Self::untrack_pending_upgrade(&env, proposal_id);
+        Self::record_rejection(&env, proposal_id, canceller, symbol_short!("cancel"), reason);
+    }
 
-        // [FIX-L04] Use Option pattern instead of panic on inconsistency
-        let (latest_snapshot_id, latest_snapshot_version) = if has_snapshot {
-            let latest_id = index.get(snapshot_count - 1).unwrap();
-            let snap: Option<CoreConfigSnapshot> = env.storage().instance()
-                .get(&DataKey::ConfigSnapshot(latest_id));
-            match snap {
-                Some(s) => (latest_id, s.version),
-                None => (0u64, 0u32), // Inconsistency: return safe defaults
-            }
-        } else {
-            (0u64, 0u32)
+    /// `cancel_upgrade` that records a refusal instead of failing, like
+    /// `attempt_execute_upgrade`. Runs the `cancel_upgrade` checks first
+    /// and, if one fails, counts it as a `Cancel` governance failure and
+    /// returns its code, or 0 where `cancel_upgrade` fails with a message.
+    /// Returns `None` after cancelling.
+    pub fn attempt_cancel_upgrade(env: Env, canceller: Address, proposal_id: u64) -> Option<u32> {
+        Self::require_function_enabled(&env, "attempt_cancel_upgrade");
+        let Some(code) = Self::cancellation_refusal(&env, &canceller, proposal_id) else {
+            Self::cancel_upgrade(env, proposal_id, canceller);
+            return None;
         };
-
-        RollbackInfo {
-            current_version, previous_version, rollback_available,
-            has_migration, migration_from_version, migration_to_version,
-            migration_timestamp, snapshot_count, has_snapshot,
-            latest_snapshot_id, latest_snapshot_version,
-        }
+        canceller.require_auth();
+        monitoring::track_governance_failure(
+            &env,
+            symbol_short!("canc_upg"),
+            monitoring::GovernanceStage::Cancel,
+            canceller.clone(),
+            code,
+            Self::trips_breaker(&env, &canceller),
+        );
+        Some(code)
     }
 
-    // ========================================================================
-    // Network Configuration
-    // ========================================================================
-
-    pub fn get_chain_id(env: Env) -> Option<String> {
-        env.storage().instance().get(&DataKey::ChainId)
+    /// Code `cancel_upgrade` fails with for `canceller` on `proposal_id`.
+    /// Its multisig failures are all messages, so every refusal is 0.
+    fn cancellation_refusal(env: &Env, canceller: &Address, proposal_id: u64) -> Option<u32> {
+        let refused = Self::is_rejection_reason_required(env.clone())
+            || !MultiSig::is_signer(env, canceller)
+            || MultiSig::get_proposal_opt(env, proposal_id).is_none_or(|p| p.executed || p.cancelled);
+        refused.then_some(0)
     }
 
-    pub fn get_network_id(env: Env) -> Option<String> {
-        env.storage().instance().get(&DataKey::NetworkId)
+    /// Withdraw `signer`'s approval before execution. A running timelock is
+    /// cleared once the proposal drops below threshold. Records an empty
+    /// reason, so it fails while reasons are required.
+    pub fn revoke_approval(env: Env, proposal_id: u64, signer: Address) {
+        Self::revoke_approval_with_reason(env.clone(), proposal_id, signer, String::from_str(&env, ""));
     }
 
-    pub fn get_network_info(env: Env) -> (Option<String>, Option<String>) {
-        let chain_id = env.storage().instance().get(&DataKey::ChainId);
-        let network_id = env.storage().instance().get(&DataKey::NetworkId);
-        (chain_id, network_id)
+    /// `revoke_approval` with why, recorded like a cancellation's reason.
+    /// Shares the `revoke_approval` kill switch.
+    pub fn revoke_approval_with_reason(env: Env, proposal_id: u64, signer: Address, reason: String) {
+        Self::require_function_enabled(&env, "revoke_approval");
+        Self::ensure_proposal_storage(&env);
+        Self::require_rejection_reason(&env, &reason);
+        MultiSig::revoke(&env, proposal_id, signer.clone());
+        Self::stop_timelock_if_unready(&env, proposal_id);
+        Self::record_rejection(&env, proposal_id, signer, symbol_short!("revoke"), reason);
     }
 
-    // ========================================================================
-    // Storage Layout Verification
-    // ========================================================================
+    /// Revocations and cancellations of `proposal_id` as `(signer, reason,
+    /// timestamp)`, oldest first. Keeps the latest `MAX_REJECTIONS`.
+    pub fn get_rejections(env: Env, proposal_id: u64) -> Vec<(Address, String, u64)> {
+        env.storage()
+            .persistent()
+            .get(&ProposalKey::Rejections(proposal_id))
+            .unwrap_or(Vec::new(&env))
+    }
 
-    pub fn verify_storage_layout(env: Env) -> bool {
-        let admin_ok = env.storage().instance().has(&DataKey::Admin)
-            && env.storage().instance().get::<_, Address>(&DataKey::Admin).is_some();
+    pub fn is_rejection_reason_required(env: Env) -> bool {
+        env.storage().instance().has(&ApprovalKey::RejectionReasonRequired)
+    }
 
-        let version_ok = env.storage().instance().has(&DataKey::Version)
-            && env.storage().instance().get::<_, u32>(&DataKey::Version).is_some();
+    /// Require a non-empty reason to revoke an approval or cancel a
+    /// proposal, which turns off `revoke_approval` and `cancel_upgrade`.
+    /// Governance only; multisig deployments use a
+    /// `ProposalAction::SetRejectionReasonRequired` bundle.
+    pub fn set_rejection_reason_required(env: Env, required: bool) {
+        Self::require_function_enabled(&env, "set_rejection_reason_required");
+        grainlify_upgradeable::require_governance(&env);
+        Self::require_not_read_only(&env);
+        Self::store_rejection_reason_required(&env, required);
+    }
 
-        let migration_ok = if env.storage().instance().has(&DataKey::MigrationState) {
-            // [FIX-L03] Also verify MigrationState schema is readable
-            env.storage().instance()
-                .get::<_, crate::MigrationState>(&DataKey::MigrationState)
-                .is_some()
+    fn store_rejection_reason_required(env: &Env, required: bool) {
+        if required {
+            env.storage().instance().set(&ApprovalKey::RejectionReasonRequired, &true);
         } else {
-            true
-        };
+            env.storage().instance().remove(&ApprovalKey::RejectionReasonRequired);
+        }
+    }
 
-        admin_ok && version_ok && migration_ok
+    /// Panics with `DescriptionTooLong` for a `reason` over its limit, and
+    /// on an empty one while reasons are required.
+    fn require_rejection_reason(env: &Env, reason: &String) {
+        limits::require_len(env, limits::REASON, reason);
+        if reason.is_empty() && Self::is_rejection_reason_required(env.clone()) {
+            panic!("Rejection reason required");
+        }
     }
 
-    // ========================================================================
-    // Monitoring & Analytics
-    // ========================================================================
+    fn record_rejection(env: &Env, proposal_id: u64, signer: Address, action: Symbol, reason: String) {
+        let mut rejections = Self::get_rejections(env.clone(), proposal_id);
+        if rejections.len() >= MAX_REJECTIONS {
+            rejections.pop_front();
+        }
+        let timestamp = env.ledger().timestamp();
+        rejections.push_back((signer.clone(), reason.clone(), timestamp));
+        env.storage().persistent().set(&ProposalKey::Rejections(proposal_id), &rejections);
+        env.events().publish(
+            (symbol_short!("upgrade"), symbol_short!("rejected")),
+            ProposalRejectedEvent {
+                proposal_id,
+                signer,
+                action,
+                reason,
+                timestamp,
+                external_id: Self::external_id_of(env, proposal_id),
+                event_version: EVENT_SCHEMA_VERSION,
+            },
+        );
+    }
 
-    pub fn health_check(env: Env) -> monitoring::HealthStatus {
-        monitoring::health_check(&env)
+    /// Delete a cancelled or expired upgrade proposal together with its
+    /// stored hash and proposer. Anyone may reclaim the storage.
+    pub fn prune_upgrade_proposal(env: Env, proposal_id: u64) {
+        Self::require_function_enabled(&env, "prune_upgrade_proposal");
+        Self::require_not_in_maintenance(&env);
+        Self::ensure_proposal_storage(&env);
+        Self::record_proposal_pruned(&env, proposal_id);
+        MultiSig::prune(&env, proposal_id);
+        Self::remove_proposal_entries(&env, proposal_id, true);
     }
 
-    pub fn get_analytics(env: Env) -> monitoring::Analytics {
-        monitoring::get_analytics(&env)
+    /// Clean up to `max` (capped at `MAX_SWEEP_BATCH`) proposal IDs, resuming
+    /// after the last ID the previous call examined and wrapping back to 1
+    /// after the newest proposal. Returns how many were cleaned.
+    ///
+    /// - Cancelled, expired, or lapsed proposals are pruned with everything
+    ///   stored for them
+    /// - Executed proposals are archived: bookkeeping goes, the execution
+    ///   record and installed hash stay as history
+    /// - Pending and executable proposals are skipped untouched
+    ///
+    /// Anyone may call it; a keeper calls it until it returns 0.
+    pub fn sweep_proposals(env: Env, max: u32) -> u32 {
+        Self::require_function_enabled(&env, "sweep_proposals");
+        Self::require_not_in_maintenance(&env);
+        Self::ensure_proposal_storage(&env);
+
+        let newest = MultiSig::get_proposal_count(&env);
+        if newest == 0 {
+            return 0;
+        }
+        let mut cursor: u64 = env.storage().instance().get(&DataKey::SweepCursor).unwrap_or(1);
+        let to_examine = (max.min(MAX_SWEEP_BATCH) as u64).min(newest);
+        let mut cleaned = 0u32;
+        for _ in 0..to_examine {
+            if cursor > newest {
+                cursor = 1;
+            }
+            if Self::sweep_one(&env, cursor) {
+                cleaned += 1;
+            }
+            cursor += 1;
+        }
+        env.storage().instance().set(&DataKey::SweepCursor, &cursor);
+        if cleaned > 0 {
+            env.events().publish((symbol_short!("sweep"),), (cleaned, cursor));
+        }
+        cleaned
     }
 
-    pub fn get_state_snapshot(env: Env) -> monitoring::StateSnapshot {
-        monitoring::get_state_snapshot(&env)
+    /// Next proposal ID `sweep_proposals` will examine.
+    pub fn get_sweep_cursor(env: Env) -> u64 {
+        env.storage().instance().get(&DataKey::SweepCursor).unwrap_or(1)
     }
 
-    pub fn get_performance_stats(env: Env, function_name: Symbol) -> monitoring::PerformanceStats {
-        monitoring::get_performance_stats(&env, function_name)
+    /// Next proposal ID `check_consistency` will examine.
+    pub fn get_consistency_cursor(env: Env) -> u64 {
+        env.storage().instance().get(&HealthKey::ConsistencyCursor).unwrap_or(1)
     }
 
-    pub fn check_invariants(env: Env) -> monitoring::InvariantReport {
-        monitoring::check_invariants(&env)
+    /// Move upgrade, fleet, and unpause proposals stored by earlier versions
+    /// from instance to persistent storage, `RELOCATION_BATCH` proposal IDs
+    /// per call from a stored cursor. Proposal entrypoints move a small
+    /// backlog on their own; a larger one needs repeated calls here until
+    /// `get_relocation_remaining` reports `None`. Anyone may call it.
+    /// Returns `false` when there was nothing left to move.
+    pub fn relocate_upgrade_proposals(env: Env) -> bool {
+        Self::relocate_proposal_batch(&env)
     }
 
-    pub fn verify_invariants(env: Env) -> bool {
-        monitoring::verify_invariants(&env)
+    /// Proposal IDs `relocate_upgrade_proposals` has yet to scan, or `None`
+    /// once every proposal lives in persistent storage.
+    pub fn get_relocation_remaining(env: Env) -> Option<u64> {
+        grainlify_upgradeable::legacy_proposals_remaining(&env)
     }
 
-    // ========================================================================
-    // Deployed Contract Registry
-    // ========================================================================
+    /// Return the hash, approvers, and execution time recorded when a
+    /// multisig upgrade proposal executed, or None if it has not.
+    pub fn get_executed_upgrade(env: Env, proposal_id: u64) -> Option<grainlify_upgradeable::ExecutedUpgrade> {
+        Self::ensure_proposal_storage(&env);
+        grainlify_upgradeable::get_executed_upgrade(&env, proposal_id)
+    }
 
-    pub fn register_deployed_contract(
-        env: Env,
-        address: Address,
-        name: String,
-        kind: ContractKind,
-        version: u32,
-    ) {
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set");
-        admin.require_auth();
-        Self::require_not_read_only(&env);
+    /// Entry `index` (zero-based, in execution order) of the archive of
+    /// executed multisig upgrade proposals. Unlike `get_executed_upgrade`,
+    /// which reads the proposal's own history, the archive is never swept;
+    /// only entries past its retention are deleted.
+    pub fn get_archived_upgrade(env: Env, index: u64) -> Option<ArchivedUpgrade> {
+        env.storage().persistent().get(&ArchiveKey::UpgradeArchive(index))
+    }
 
-        let mut index: Vec<Address> = env
-            .storage()
-            .instance()
-            .get(&DataKey::DeployedContractIndex)
-            .unwrap_or(Vec::new(&env));
+    /// Number of times the contract code was swapped, by any upgrade path.
+    /// Upgrades made before the counter existed are not included.
+    pub fn get_upgrade_count(env: Env) -> u64 {
+        env.storage().persistent().get(&ArchiveKey::UpgradeCount).unwrap_or(0)
+    }
 
-        let existed = env
-            .storage()
-            .instance()
-            .has(&DataKey::DeployedContractEntry(address.clone()));
+    /// Number of upgrades ever archived, including entries deleted by
+    /// retention.
+    pub fn executed_upgrade_count(env: Env) -> u64 {
+        env.storage().instance().get(&ArchiveKey::UpgradeArchiveCount).unwrap_or(0)
+    }
 
-        if !existed {
-            if index.len() >= MAX_DEPLOYED_CONTRACTS {
-                panic!("Registry full");
+    /// Page of multisig threshold changes from index `start`, oldest
+    /// first; see `paging`. The first entry is the configuration set at
+    /// initialization. A `start` before the retained range begins at its
+    /// oldest entry.
+    pub fn get_threshold_history(env: Env, start: u64, limit: u32) -> paging::ThresholdChangePage {
+        let count: u64 = env.storage().instance().get(&ArchiveKey::ThresholdHistoryCount).unwrap_or(0);
+        let start = start.max(Self::history_first(&env, HistoryKind::Thresholds));
+        let page = paging::range(start, limit, count);
+        let mut items = Vec::new(&env);
+        for index in page.start..page.end {
+            if let Some(entry) = env.storage().persistent().get(&ArchiveKey::ThresholdHistory(index)) {
+                items.push_back(entry);
             }
-            index.push_back(address.clone());
         }
+        paging::ThresholdChangePage { items, has_more: page.has_more }
+    }
 
-        let entry = DeployedContract {
-            address: address.clone(),
-            name,
-            kind,
-            version,
-            deployed_at: env.ledger().timestamp(),
-        };
+    /// Page of version changes from index `start`, oldest first; see
+    /// `paging`. Deployments initialized before the history existed start
+    /// it at their first later version change. A `start` before the
+    /// retained range begins at its oldest entry.
+    pub fn get_version_history(env: Env, start: u64, limit: u32) -> paging::VersionChangePage {
+        let count: u64 = env.storage().instance().get(&ArchiveKey::VersionHistoryCount).unwrap_or(0);
+        let start = start.max(Self::history_first(&env, HistoryKind::Versions));
+        let page = paging::range(start, limit, count);
+        let mut items = Vec::new(&env);
+        for index in page.start..page.end {
+            if let Some(entry) = env.storage().persistent().get(&ArchiveKey::VersionHistory(index)) {
+                items.push_back(entry);
+            }
+        }
+        paging::VersionChangePage { items, has_more: page.has_more }
+    }
 
-        env.storage()
-            .instance()
-            .set(&DataKey::DeployedContractEntry(address), &entry);
-        env.storage()
-            .instance()
-            .set(&DataKey::DeployedContractIndex, &index);
+    /// Retained range, lifetime entry count and retention of `kind`.
+    pub fn get_history_range(env: Env, kind: HistoryKind) -> HistoryRange {
+        let (count_key, _) = Self::history_keys(kind, 0);
+        HistoryRange {
+            first: Self::history_first(&env, kind),
+            total_count: env.storage().instance().get(&count_key).unwrap_or(0),
+            retention: Self::history_retention(&env, kind),
+        }
     }
 
-    pub fn deregister_deployed_contract(env: Env, address: Address) {
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set");
-        admin.require_auth();
+    /// Keep at most `retention` entries of history `kind`. Once the history
+    /// is longer, each append deletes its oldest entries, at most
+    /// `MAX_HISTORY_PRUNE` at a time. Entry indices and the lifetime count
+    /// are unaffected. Publishes `("history", "retain")`. Governance only;
+    /// multisig deployments use a `ProposalAction::SetHistoryRetention`
+    /// bundle.
+    pub fn set_history_retention(env: Env, kind: HistoryKind, retention: u64) {
+        Self::require_function_enabled(&env, "set_history_retention");
+        grainlify_upgradeable::require_governance(&env);
         Self::require_not_read_only(&env);
+        Self::store_history_retention(&env, kind, retention);
+    }
 
-        let had_entry = env
-            .storage()
-            .instance()
-            .has(&DataKey::DeployedContractEntry(address.clone()));
-        if !had_entry {
-            return;
+    fn store_history_retention(env: &Env, kind: HistoryKind, retention: u64) {
+        if retention == 0 {
+            panic!("Retention must be at least 1");
         }
+        env.storage().instance().set(&ArchiveKey::HistoryRetention(kind), &retention);
+        env.events().publish((symbol_short!("history"), symbol_short!("retain")), (kind, retention));
+    }
 
-        env.storage()
-            .instance()
-            .remove(&DataKey::DeployedContractEntry(address.clone()));
-
-        let index: Vec<Address> = env
-            .storage()
-            .instance()
-            .get(&DataKey::DeployedContractIndex)
-            .unwrap_or(Vec::new(&env));
-        let mut trimmed = Vec::new(&env);
-        for i in 0..index.len() {
-            let addr = index.get(i).unwrap();
-            if addr != address {
-                trimmed.push_back(addr);
-            }
+    /// Count key of history `kind` and the key of its entry at `index`.
+    fn history_keys(kind: HistoryKind, index: u64) -> (ArchiveKey, ArchiveKey) {
+        match kind {
+            HistoryKind::Upgrades => (ArchiveKey::UpgradeArchiveCount, ArchiveKey::UpgradeArchive(index)),
+            HistoryKind::Thresholds => (ArchiveKey::ThresholdHistoryCount, ArchiveKey::ThresholdHistory(index)),
+            HistoryKind::Versions => (ArchiveKey::VersionHistoryCount, ArchiveKey::VersionHistory(index)),
         }
-        env.storage()
-            .instance()
-            .set(&DataKey::DeployedContractIndex, &trimmed);
     }
 
-    pub fn get_deployed_contract(env: Env, address: Address) -> Option<DeployedContract> {
-        env.storage()
-            .instance()
-            .get(&DataKey::DeployedContractEntry(address))
+    fn history_first(env: &Env, kind: HistoryKind) -> u64 {
+        env.storage().instance().get(&ArchiveKey::HistoryFirst(kind)).unwrap_or(0)
     }
 
-    pub fn deployed_contract_count(env: Env) -> u32 {
-        let index: Vec<Address> = env
-            .storage()
+    fn history_retention(env: &Env, kind: HistoryKind) -> u64 {
+        env.storage()
             .instance()
-            .get(&DataKey::DeployedContractIndex)
-            .unwrap_or(Vec::new(&env));
-        index.len()
+            .get(&ArchiveKey::HistoryRetention(kind))
+            .unwrap_or(DEFAULT_HISTORY_RETENTION)
     }
 
-    pub fn list_deployed_contracts(
-        env: Env,
-        offset: Option<u32>,
-        limit: Option<u32>,
-    ) -> Vec<DeployedContract> {
-        let index: Vec<Address> = env
-            .storage()
-            .instance()
-            .get(&DataKey::DeployedContractIndex)
-            .unwrap_or(Vec::new(&env));
-        let total = index.len();
-        let off = offset.unwrap_or(0);
-        if off > total {
-            panic!("Offset exceeds registry size");
+    /// Deletes up to `MAX_HISTORY_PRUNE` of the oldest entries of `kind`
+    /// while more than its retention remain out of `total_count`.
+    fn prune_history(env: &Env, kind: HistoryKind, total_count: u64) {
+        let first = Self::history_first(env, kind);
+        let excess = total_count.saturating_sub(first).saturating_sub(Self::history_retention(env, kind));
+        if excess == 0 {
+            return;
+        }
+        let new_first = first + excess.min(MAX_HISTORY_PRUNE);
+        for index in first..new_first {
+            env.storage().persistent().remove(&Self::history_keys(kind, index).1);
         }
+        env.storage().instance().set(&ArchiveKey::HistoryFirst(kind), &new_first);
+    }
 
-        let lim = limit.unwrap_or(total.saturating_sub(off));
-        let end = if off.saturating_add(lim) > total {
-            total
-        } else {
-            off + lim
+    /// Appends the stored version to the version history.
+    fn record_version(env: &Env) {
+        let index: u64 = env.storage().instance().get(&ArchiveKey::VersionHistoryCount).unwrap_or(0);
+        let entry = VersionChange {
+            version: grainlify_upgradeable::get_version(env),
+            changed_at: env.ledger().timestamp(),
         };
-
-        let mut out: Vec<DeployedContract> = Vec::new(&env);
-        for i in off..end {
-            let addr = index.get(i).unwrap();
-            if let Some(entry) = env
-                .storage()
-                .instance()
-                .get::<_, DeployedContract>(&DataKey::DeployedContractEntry(addr))
-            {
-                out.push_back(entry);
-            }
-        }
-        out
+        env.storage().persistent().set(&ArchiveKey::VersionHistory(index), &entry);
+        env.storage().instance().set(&ArchiveKey::VersionHistoryCount, &(index + 1));
+        Self::prune_history(env, HistoryKind::Versions, index + 1);
+        monitoring::record_version_switch(env);
     }
 
-    // ========================================================================
-    // Emergency Controls
-    // ========================================================================
+    /// Sets the multisig threshold and records the change.
+    fn store_threshold(env: &Env, threshold: u32, proposal_id: Option<u64>) {
+        let mut config =
+            MultiSig::get_config_opt(env).unwrap_or_else(|| panic!("{}", ContractError::NotInitialized as u32));
+        config.threshold = threshold;
+        MultiSig::set_config(env, config);
+        Self::record_threshold_change(env, proposal_id);
+        Self::record_config_change(env, "threshold", proposal_id);
+    }
 
-    pub fn pause(env: Env, signer: Address) {
-        MultiSig::pause(&env, signer);
+    /// Appends the current multisig configuration to the threshold history.
+    fn record_threshold_change(env: &Env, proposal_id: Option<u64>) {
+        let Some(config) = MultiSig::get_config_opt(env) else {
+            return;
+        };
+        let index: u64 = env.storage().instance().get(&ArchiveKey::ThresholdHistoryCount).unwrap_or(0);
+        let entry = ThresholdChange {
+            threshold: config.threshold,
+            signer_count: config.signers.len(),
+            changed_at: env.ledger().timestamp(),
+            proposal_id,
+        };
+        env.storage().persistent().set(&ArchiveKey::ThresholdHistory(index), &entry);
+        env.storage().instance().set(&ArchiveKey::ThresholdHistoryCount, &(index + 1));
+        Self::prune_history(env, HistoryKind::Thresholds, index + 1);
     }
 
-    pub fn unpause(env: Env, signer: Address) {
-        MultiSig::unpause(&env, signer);
+    /// Who approved multisig proposal `proposal_id` and at which ledger
+    /// time and sequence, in approval order. Executed proposals keep these
+    /// records permanently, including after sweeping. Panics with
+    /// `ProposalNotFound` for an unknown ID.
+    pub fn get_approvers(env: Env, proposal_id: u64) -> Vec<multisig::ApprovalRecord> {
+        Self::ensure_proposal_storage(&env);
+        if MultiSig::get_proposal_opt(&env, proposal_id).is_none()
+            && MultiSig::get_execution(&env, proposal_id).is_none()
+        {
+            panic!("{}", ContractError::ProposalNotFound as u32);
+        }
+        MultiSig::get_approval_records(&env, proposal_id)
     }
 
-    pub fn is_paused(env: Env) -> bool {
-        MultiSig::is_contract_paused(&env)
+    /// `get_approvers` without the times.
+    pub fn get_approver_addresses(env: Env, proposal_id: u64) -> Vec<Address> {
+        let mut addresses = Vec::new(&env);
+        for record in Self::get_approvers(env.clone(), proposal_id).iter() {
+            addresses.push_back(record.signer);
+        }
+        addresses
     }
 
-    /// Unified liveness watchdog view — no auth required, never panics.
-    ///
-    /// Returns a `LivenessStatus` snapshot combining pause state, read-only
-    /// mode, monitoring health, last-ping timestamp, version, and admin
-    /// presence.  Designed for polling by monitoring agents, circuit breakers,
-    /// and dashboards.
-    ///
-    /// # Upgrade Safety
-    /// `schema_version` reflects `LivenessSchemaVersion` written at `init_admin`.
-    /// Returns `0` on legacy deployments where the marker was never written.
-    pub fn liveness_watchdog(env: Env) -> LivenessStatus {
-        let is_paused = MultiSig::is_contract_paused(&env);
-        let is_read_only: bool = env
-            .storage()
-            .instance()
-            .get(&DataKey::ReadOnlyMode)
+    /// Report what `execute_upgrade` would do for `proposal_id` right now:
+    /// the hash it would install and every check that would stop it. Uses
+    /// the same predicates as execution and writes nothing. Panics with
+    /// `ProposalNotFound` for an unknown ID.
+    pub fn preview_execution(env: Env, proposal_id: u64) -> ExecutionPreview {
+        Self::ensure_proposal_storage(&env);
+        let mut failing = Vec::new(&env);
+        if Self::is_read_only(env.clone()) {
+            failing.push_back(ExecutionCheck::ReadOnly);
+        }
+        if MultiSig::is_contract_paused(&env) {
+            failing.push_back(ExecutionCheck::Paused);
+        }
+        if Self::active_emergency_pause(&env).is_some() {
+            failing.push_back(ExecutionCheck::EmergencyPaused);
+        }
+
+        let (wasm_hash, timelock_remaining) = if TokenVoting::get_config(&env).is_some() {
+            let proposal = TokenVoting::get_proposal(&env, proposal_id)
+                .unwrap_or_else(|| panic!("{}", ContractError::ProposalNotFound as u32));
+            if TokenVoting::check_finalizable(&env, &proposal).is_err() {
+                failing.push_back(ExecutionCheck::VoteNotPassed);
+            }
+            let remaining = Self::token_timelock_remaining(&env, &proposal);
+            if remaining.is_some_and(|remaining| remaining > 0) {
+                failing.push_back(ExecutionCheck::TimelockPending);
+            }
+            if Self::newer_upgrade(&env, proposal_id).is_some() {
+                failing.push_back(ExecutionCheck::OutOfOrder);
+            }
+            (Some(proposal.wasm_hash), remaining)
+        } else {
+            if MultiSig::get_proposal_opt(&env, proposal_id).is_none() {
+                panic!("{}", ContractError::ProposalNotFound as u32);
+            }
+            if MultiSig::is_state_inconsistent(&env) {
+                failing.push_back(ExecutionCheck::StateInconsistent);
+            }
+            if !Self::window_open(&env, proposal_id) {
+                failing.push_back(ExecutionCheck::OutsideWindow);
+            }
+            let remaining = Self::timelock_remaining(&env, proposal_id);
+            match remaining {
+                None => failing.push_back(ExecutionCheck::TimelockNotStarted),
+                Some(0) => {}
+                Some(_) => failing.push_back(ExecutionCheck::TimelockPending),
+            }
+            if !MultiSig::can_execute(&env, proposal_id) {
+                failing.push_back(ExecutionCheck::NotExecutable);
+            }
+            if !Self::is_revealed(&env, proposal_id) {
+                failing.push_back(ExecutionCheck::NotRevealed);
+            }
+            if !Self::protocol_supported(&env, proposal_id) {
+                failing.push_back(ExecutionCheck::ProtocolTooOld);
+            }
+            if Self::newer_upgrade(&env, proposal_id).is_some() {
+                failing.push_back(ExecutionCheck::OutOfOrder);
+            }
+            (grainlify_upgradeable::get_upgrade_hash(&env, proposal_id), remaining)
+        };
+
+        let attested = wasm_hash
+            .as_ref()
+            .map(|hash| Self::is_attested(&env, hash))
             .unwrap_or(false);
-        let version: u32 = env
-            .storage()
-            .instance()
-            .get(&DataKey::Version)
-            .unwrap_or(0);
-        let healthy = monitoring::check_invariants(&env).healthy;
-        let last_ping_ts: u64 = env
-            .storage()
-            .instance()
-            .get(&DataKey::WatchdogLastPing)
-            .unwrap_or(0);
-        LivenessStatus {
-            paused: is_paused,
-            read_only: is_read_only,
-            healthy,
-            last_ping_ts,
-            is_paused,
-            is_read_only,
-            is_operational: !is_paused && !is_read_only,
-            version,
-            admin_set: env.storage().instance().has(&DataKey::Admin),
-            schema_version: env
+        if wasm_hash.is_some() && !attested {
+            failing.push_back(ExecutionCheck::AttestationMissing);
+        }
+        if wasm_hash.as_ref().is_some_and(|hash| Self::is_wasm_blocked(env.clone(), hash.clone())) {
+            failing.push_back(ExecutionCheck::WasmBlocked);
+        }
+        let revealed = wasm_hash.is_some();
+        let wasm_hash = wasm_hash.unwrap_or_else(|| {
+            let commitment: commit_reveal::Commitment = env
                 .storage()
-                .instance()
-                .get(&DataKey::LivenessSchemaVersion)
-                .unwrap_or(0),
-            timestamp: env.ledger().timestamp(),
+                .persistent()
+                .get(&ProposalKey::UpgradeCommitment(proposal_id))
+                .unwrap_or_else(|| panic!("{}", ContractError::ProposalNotFound as u32));
+            commitment.hash
+        });
+        ExecutionPreview {
+            proposal_id,
+            wasm_hash,
+            revealed,
+            attested,
+            new_version: grainlify_upgradeable::get_version(&env),
+            timelock_remaining,
+            executable: failing.is_empty(),
+            failing,
         }
     }
 
-    pub fn can_execute(env: Env, proposal_id: u64) -> bool {
-        MultiSig::can_execute(&env, proposal_id)
+    /// Dry run of `execute_upgrade` for multisig upgrade proposal
+    /// `proposal_id`: evaluates each of its preconditions and reports pass
+    /// or fail per check without writing anything. A valid report means
+    /// executing now would succeed, with one stricter check: `HashChanged`
+    /// also fails a proposal that would reinstall the current code. Panics
+    /// with `ProposalNotFound` for an unknown ID or one that is not an
+    /// upgrade.
+    pub fn validate_proposal(env: Env, proposal_id: u64) -> ValidationReport {
+        Self::ensure_proposal_storage(&env);
+        let proposal = MultiSig::get_proposal_opt(&env, proposal_id)
+            .unwrap_or_else(|| panic!("{}", ContractError::ProposalNotFound as u32));
+        let wasm_hash = grainlify_upgradeable::get_upgrade_hash(&env, proposal_id);
+        let [not_read_only, not_paused, state_consistent, attested, not_blocked, hash_changed] =
+            Self::static_checks(&env, wasm_hash.as_ref());
+
+        let checks = [
+            (
+                ValidationCheck::GovernanceMode,
+                TokenVoting::get_config(&env).is_none() && MultiSig::get_config_opt(&env).is_some(),
+            ),
+            not_read_only,
+            not_paused,
+            state_consistent,
+            (
+                ValidationCheck::Open,
+                !proposal.executed && !proposal.cancelled && !MultiSig::is_lapsed(&env, proposal_id),
+            ),
+            (ValidationCheck::NotExpired, !MultiSig::is_expired(&env, proposal_id)),
+            (ValidationCheck::Threshold, MultiSig::approvals_needed(&env, proposal_id) == Some(0)),
+            (ValidationCheck::Timelock, Self::timelock_remaining(&env, proposal_id) == Some(0)),
+            (ValidationCheck::Window, Self::window_open(&env, proposal_id)),
+            (ValidationCheck::Revealed, Self::is_revealed(&env, proposal_id) && wasm_hash.is_some()),
+            (ValidationCheck::InOrder, Self::newer_upgrade(&env, proposal_id).is_none()),
+            attested,
+            not_blocked,
+            hash_changed,
+        ];
+        let mut results = Vec::new(&env);
+        for (check, passed) in checks {
+            results.push_back(ValidationResult { check, passed });
+        }
+        ValidationReport {
+            proposal_id,
+            wasm_hash: Self::proposal_wasm_hash(&env, proposal_id),
+            valid: results.iter().all(|result| result.passed),
+            results,
+        }
     }
 
-    // ========================================================================
-    // Liveness Watchdog
-    // ========================================================================
+    /// `validate_proposal` checks that depend only on contract state and
+    /// the hash, never on time or approvals, so `propose_upgrade` can
+    /// enforce them at creation from the same predicates.
+    fn static_checks(env: &Env, wasm_hash: Option<&BytesN<32>>) -> [(ValidationCheck, bool); 6] {
+        let current = Self::get_current_wasm_hash(env.clone());
+        [
+            (ValidationCheck::NotReadOnly, !Self::is_read_only(env.clone())),
+            (ValidationCheck::NotPaused, !MultiSig::is_contract_paused(env)),
+            (ValidationCheck::StateConsistent, !MultiSig::is_state_inconsistent(env)),
+            (ValidationCheck::Attested, wasm_hash.is_some_and(|hash| Self::is_attested(env, hash))),
+            (
+                ValidationCheck::NotBlocked,
+                wasm_hash.is_none_or(|hash| !Self::is_wasm_blocked(env.clone(), hash.clone())),
+            ),
+            (ValidationCheck::HashChanged, current.is_none() || current.as_ref() != wasm_hash),
+        ]
+    }
 
-    /// Admin: record a liveness ping — updates `WatchdogLastPing` timestamp.
-    ///
-    /// Allows off-chain monitors to prove the contract is reachable and the
-    /// admin key is live. Blocked when read-only mode is active.
-    ///
-    /// # Authorization
-    /// Requires admin signature.
-    pub fn ping_watchdog(env: Env) {
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .unwrap_or_else(|| panic!("{}", ContractError::NotInitialized as u32));
-        admin.require_auth();
-        Self::require_not_read_only(&env);
-        let ts = env.ledger().timestamp();
-        env.storage().instance().set(&DataKey::WatchdogLastPing, &ts);
-        env.events().publish(
-            (symbol_short!("watchdog"), symbol_short!("ping")),
-            (admin, ts),
-        );
+    /// Panics with the `ProposalCheckError` of the first failing
+    /// `static_checks` entry for `wasm_hash`.
+    fn require_creation_checks(env: &Env, wasm_hash: &BytesN<32>) {
+        if let Some(error) = Self::creation_check_error(env, wasm_hash) {
+            panic!("{}", error as u32);
+        }
     }
 
-    /// Returns the liveness schema version written at `init_admin`.
-    /// Returns `0` on legacy deployments where the marker was never written.
-    /// Increment `LivenessSchemaVersion` in `init_admin` whenever `WatchdogStatus` layout changes.
-    pub fn get_liveness_schema_version(env: Env) -> u32 {
-        env.storage()
-            .instance()
-            .get(&DataKey::LivenessSchemaVersion)
-            .unwrap_or(0)
+    /// The `ProposalCheckError` of the first failing `static_checks` entry
+    /// for `wasm_hash`, if any.
+    fn creation_check_error(env: &Env, wasm_hash: &BytesN<32>) -> Option<ProposalCheckError> {
+        let (check, _) = Self::static_checks(env, Some(wasm_hash))
+            .into_iter()
+            .find(|(_, passed)| !passed)?;
+        Some(match check {
+            ValidationCheck::NotReadOnly => ProposalCheckError::ReadOnly,
+            ValidationCheck::NotPaused => ProposalCheckError::Paused,
+            ValidationCheck::StateConsistent => ProposalCheckError::StateInconsistent,
+            ValidationCheck::Attested => ProposalCheckError::AttestationMissing,
+            ValidationCheck::NotBlocked => ProposalCheckError::WasmBlocked,
+            _ => ProposalCheckError::HashUnchanged,
+        })
     }
 
-    // ========================================================================
-    // Migration State Queries
-    // ========================================================================
+    /// Live approvals multisig proposal `proposal_id` still needs to meet
+    /// the threshold, clamped at 0. Approvals from removed signers do not
+    /// count, exactly as at execution. Panics with `ProposalNotFound` for
+    /// an unknown ID.
+    pub fn approvals_needed(env: Env, proposal_id: u64) -> u32 {
+        Self::ensure_proposal_storage(&env);
+        MultiSig::approvals_needed(&env, proposal_id)
+            .unwrap_or_else(|| panic!("{}", ContractError::ProposalNotFound as u32))
+    }
 
-    pub fn get_migration_state(env: Env) -> Option<MigrationState> {
-        if env.storage().instance().has(&DataKey::MigrationState) {
-            Some(env.storage().instance().get(&DataKey::MigrationState).unwrap())
-        } else {
-            None
+    /// List every condition currently blocking execution of multisig
+    /// proposal `proposal_id`, using the predicates the execute paths
+    /// enforce. Unpause proposals execute on the approval that meets the
+    /// threshold, so only their approval state is reported.
+    pub fn can_execute_detailed(env: Env, proposal_id: u64) -> ExecutabilityReport {
+        let approvals_needed = Self::approvals_needed(env.clone(), proposal_id);
+        let proposal = MultiSig::get_proposal_opt(&env, proposal_id)
+            .unwrap_or_else(|| panic!("{}", ContractError::ProposalNotFound as u32));
+        let mut blockers = Vec::new(&env);
+        let mut block = |name: &str| blockers.push_back(Symbol::new(&env, name));
+
+        if proposal.executed {
+            block("executed");
+        }
+        if proposal.cancelled {
+            block("cancelled");
+        }
+        if MultiSig::is_expired(&env, proposal_id) {
+            block("expired");
+        }
+        if MultiSig::is_lapsed(&env, proposal_id) {
+            block("lapsed");
+        }
+        if approvals_needed > 0 {
+            block("insufficient_approvals");
+        }
+        if !env.storage().persistent().has(&DataKey::UnpauseProposal(proposal_id)) {
+            // The timelock only starts once the threshold is met.
+            if approvals_needed == 0 && Self::timelock_remaining(&env, proposal_id) != Some(0) {
+                block("timelock_pending");
+            }
+            if !Self::window_open(&env, proposal_id) {
+                block("outside_window");
+            }
+            if !Self::is_revealed(&env, proposal_id) {
+                block("not_revealed");
+            }
+            let is_upgrade = grainlify_upgradeable::get_upgrade_hash(&env, proposal_id).is_some()
+                || !Self::is_revealed(&env, proposal_id);
+            if is_upgrade && Self::newer_upgrade(&env, proposal_id).is_some() {
+                block("out_of_order");
+            }
+            if let Some(hash) = grainlify_upgradeable::get_upgrade_hash(&env, proposal_id) {
+                if !Self::is_attested(&env, &hash) {
+                    block("attestation_missing");
+                }
+            }
+            if MultiSig::is_contract_paused(&env) {
+                block("paused");
+            }
+            if Self::is_read_only(env.clone()) {
+                block("read_only");
+            }
+            if MultiSig::is_state_inconsistent(&env) {
+                block("state_inconsistent");
+            }
         }
-    }
 
-    pub fn get_previous_version(env: Env) -> Option<u32> {
-        if env.storage().instance().has(&DataKey::PreviousVersion) {
-            Some(env.storage().instance().get(&DataKey::PreviousVersion).unwrap())
-        } else {
-            None
+        ExecutabilityReport {
+            proposal_id,
+            approvals_needed,
+            executable: blockers.is_empty(),
+            blockers,
         }
     }
 
+    /// Return the upgrade proposal record for a given proposal ID, or None.
+    pub fn get_upgrade_proposal(env: Env, proposal_id: u64) -> Option<UpgradeProposalRecord> {
+        Self::ensure_proposal_storage(&env);
+        Self::load_upgrade_proposal(&env, proposal_id)
+    }
+
     // ========================================================================
-    // Multisig Initialization
+    // State Export
     // ========================================================================
 
-    /// Initialize with multisig governance (alternative to init_admin).
-    /// Requires at least one signer and a valid threshold.
-    pub fn init(env: Env, signers: Vec<Address>, threshold: u32) {
-        if env.storage().instance().has(&DataKey::Version) {
-            panic!("Already initialized");
+    /// Sections `export_state` serves and their current page counts; see
+    /// `export`. Page counts change as state does, so read the manifest
+    /// right before exporting.
+    pub fn export_manifest(env: Env) -> Vec<export::ExportSection> {
+        Self::ensure_proposal_storage(&env);
+        let mut sections = Vec::new(&env);
+        for name in export::SECTIONS {
+            let pages = match name {
+                "governance" | "config" => 1,
+                "proposals" => export::page_count(MultiSig::get_proposal_count(&env)),
+                "history" => {
+                    let mut longest = 0;
+                    for kind in [HistoryKind::Upgrades, HistoryKind::Thresholds, HistoryKind::Versions] {
+                        let range = Self::get_history_range(env.clone(), kind);
+                        longest = longest.max(range.total_count.saturating_sub(range.first));
+                    }
+                    export::page_count(longest)
+                }
+                _ if !monitoring::ENABLED => continue,
+                _ => export::page_count(monitoring::export_row_count(&env)),
+            };
+            sections.push_back(export::ExportSection { name: Symbol::new(&env, name), pages });
         }
-        let signer_count = signers.len();
-        MultiSig::init(&env, signers, threshold);
-        env.storage().instance().set(&DataKey::Version, &VERSION);
-        env.storage().instance().set(&DataKey::ReadOnlyMode, &false);
-        Self::emit_build_info_event(
-            &env,
-            symbol_short!("msig_init"),
-            None,
-            signer_count,
-            threshold,
-        );
+        sections
     }
 
-    /// Initialize with admin, chain_id, and network_id (network-aware init).
-    pub fn init_with_network(env: Env, admin: Address, chain_id: String, network_id: String) {
-        if env.storage().instance().has(&DataKey::Version) {
-            panic!("Already initialized");
+    /// Page `page` of export section `section` as the XDR of its struct,
+    /// e.g. `GovernanceExport` for `governance`; see `export`. Panics with
+    /// `ExportError::UnknownSection` for a name not in the manifest.
+    pub fn export_state(env: Env, section: Symbol, page: u32) -> Bytes {
+        Self::ensure_proposal_storage(&env);
+        let Some(name) = export::SECTIONS.into_iter().find(|name| section == Symbol::new(&env, name)) else {
+            panic!("{}", ExportError::UnknownSection as u32);
+        };
+        match name {
+            "governance" => Self::export_governance(&env).to_xdr(&env),
+            "config" => Self::export_config(&env).to_xdr(&env),
+            "proposals" => Self::export_proposals(&env, page).to_xdr(&env),
+            "history" => Self::export_history(&env, page).to_xdr(&env),
+            _ => {
+                Self::require_monitoring();
+                Self::require_schema(&env, monitoring::SCHEMA);
+                monitoring::export_metrics(&env, page, paging::MAX_PAGE_SIZE).to_xdr(&env)
+            }
         }
-        admin.require_auth();
-        env.storage().instance().set(&DataKey::Admin, &admin);
-        env.storage().instance().set(&DataKey::Version, &VERSION);
-        env.storage().instance().set(&DataKey::ReadOnlyMode, &false);
-        env.storage().instance().set(&DataKey::ChainId, &chain_id);
-        env.storage().instance().set(&DataKey::NetworkId, &network_id);
-        Self::emit_build_info_event(
-            &env,
-            symbol_short!("net_init"),
-            Some(admin),
-            0,
-            0,
-        );
     }
 
-    /// Initialize with governance configuration.
-    pub fn init_governance(env: Env, admin: Address, config: GovernanceConfig) {
-        if env.storage().instance().has(&DataKey::Version) {
-            panic!("Already initialized");
-        }
-        admin.require_auth();
-        if config.quorum_percentage == 0 || config.quorum_percentage > 10000 {
-            panic!("Invalid quorum percentage");
-        }
-        if config.approval_threshold < 5000 || config.approval_threshold > 10000 {
-            panic!("Invalid approval threshold");
+    fn export_governance(env: &Env) -> export::GovernanceExport {
+        let multisig = MultiSig::get_config_opt(env);
+        export::GovernanceExport {
+            admin: Self::get_admin(env.clone()),
+            signers: multisig.as_ref().map_or(Vec::new(env), |config| config.signers.clone()),
+            threshold: multisig.map_or(0, |config| config.threshold),
+            signer_epoch: Self::get_signer_epoch(env.clone()),
+            version: Self::get_version(env.clone()),
+            previous_version: Self::get_previous_version(env.clone()),
+            schema_version: Self::get_schema_version(env.clone()),
+            paused: Self::is_paused(env.clone()),
+            read_only: Self::is_read_only(env.clone()),
+            finalized: confirmation::is_finalized(env),
         }
-        env.storage().instance().set(&DataKey::Admin, &admin);
-        env.storage().instance().set(&DataKey::Version, &VERSION);
-        env.storage().instance().set(&DataKey::ReadOnlyMode, &false);
-        env.storage().instance().set(&governance::GOVERNANCE_CONFIG, &config);
-        env.storage().instance().set(&governance::PROPOSAL_COUNT, &0u32);
-        Self::emit_build_info_event(
-            &env,
-            symbol_short!("gov_init"),
-            Some(admin),
-            0,
-            0,
-        );
     }
 
-    // ========================================================================
-    // Multisig Upgrade Proposal Flow
-    // ========================================================================
-
-    /// Propose a WASM upgrade via multisig. Returns the stable proposal ID.
-    /// `expiry` is a ledger timestamp after which the proposal cannot be approved
-    /// or executed (0 = no expiry).
-    pub fn propose_upgrade(env: Env, proposer: Address, wasm_hash: BytesN<32>, expiry: u64) -> u64 {
-        Self::require_not_read_only(&env);
-        let proposal_id = MultiSig::propose(&env, proposer.clone(), expiry);
-        env.storage().instance().set(&DataKey::UpgradeProposal(proposal_id), &wasm_hash);
-        env.storage().instance().set(&DataKey::UpgradeProposalProposer(proposal_id), &proposer);
-        proposal_id
+    fn export_config(env: &Env) -> export::ConfigExport {
+        export::ConfigExport {
+            config: Self::get_config(env.clone()),
+            disabled_functions: Self::list_disabled_functions(env.clone()),
+            guardian: Self::get_guardian(env.clone()),
+            relayer: Self::get_relayer(env.clone()),
+            environment: Self::get_environment(env.clone()),
+        }
     }
 
-    /// Approve a pending upgrade proposal. Starts the timelock when threshold is met.
-    pub fn approve_upgrade(env: Env, proposal_id: u64, signer: Address) {
-        MultiSig::approve(&env, proposal_id, signer);
-        // Start timelock if threshold is now met and not already started
-        if MultiSig::can_execute(&env, proposal_id)
-            && !env.storage().instance().has(&DataKey::UpgradeTimelock(proposal_id))
-        {
-            let now = env.ledger().timestamp();
-            env.storage().instance().set(&DataKey::UpgradeTimelock(proposal_id), &now);
-            env.events().publish(
-                (Symbol::new(&env, "timelock"), Symbol::new(&env, "started")),
-                (proposal_id, now),
-            );
+    fn export_proposals(env: &Env, page: u32) -> export::ProposalsExport {
+        let count = MultiSig::get_proposal_count(env);
+        let range = paging::range(page as u64 * paging::MAX_PAGE_SIZE as u64, paging::MAX_PAGE_SIZE, count);
+        let mut items = Vec::new(env);
+        for proposal_id in range.start + 1..=range.end {
+            let pending = MultiSig::get_proposal_opt(env, proposal_id)
+                .is_some_and(|p| !p.executed && !p.cancelled && !MultiSig::is_expired(env, proposal_id));
+            if !pending {
+                continue;
+            }
+            if let Some(record) = Self::load_upgrade_proposal(env, proposal_id) {
+                let approvals = MultiSig::get_approval_records(env, proposal_id);
+                items.push_back(export::ProposalExport { record, approvals });
+            }
         }
+        export::ProposalsExport { items, has_more: range.has_more }
     }
 
-    /// Cancel a pending upgrade proposal. Any signer may cancel.
-    pub fn cancel_upgrade(env: Env, proposal_id: u64, canceller: Address) {
-        MultiSig::cancel(&env, proposal_id, canceller);
-        env.storage().instance().remove(&DataKey::UpgradeTimelock(proposal_id));
+    fn export_history(env: &Env, page: u32) -> export::HistoryExport {
+        let offset = page as u64 * paging::MAX_PAGE_SIZE as u64;
+        let (upgrades, more_upgrades) = Self::export_history_kind(env, HistoryKind::Upgrades, offset);
+        let (thresholds, more_thresholds) = Self::export_history_kind(env, HistoryKind::Thresholds, offset);
+        let (versions, more_versions) = Self::export_history_kind(env, HistoryKind::Versions, offset);
+        export::HistoryExport {
+            upgrades,
+            thresholds,
+            versions,
+            has_more: more_upgrades || more_thresholds || more_versions,
+        }
     }
 
-    /// Return the upgrade proposal record for a given proposal ID, or None.
-    pub fn get_upgrade_proposal(env: Env, proposal_id: u64) -> Option<UpgradeProposalRecord> {
-        Self::load_upgrade_proposal(&env, proposal_id)
+    /// Up to `MAX_PAGE_SIZE` retained entries of `kind` from `offset` past
+    /// its oldest, and whether more follow.
+    fn export_history_kind<T: IntoVal<Env, Val> + TryFromVal<Env, Val>>(env: &Env, kind: HistoryKind, offset: u64) -> (Vec<T>, bool) {
+        let (count_key, _) = Self::history_keys(kind, 0);
+        let count: u64 = env.storage().instance().get(&count_key).unwrap_or(0);
+        let first = Self::history_first(env, kind);
+        let range = paging::range(first.saturating_add(offset), paging::MAX_PAGE_SIZE, count);
+        let mut items = Vec::new(env);
+        for index in range.start..range.end {
+            if let Some(entry) = env.storage().persistent().get(&Self::history_keys(kind, index).1) {
+                items.push_back(entry);
+            }
+        }
+        (items, range.has_more)
     }
 
     // ========================================================================
@@ -1996,6 +10507,7 @@ impl GrainlifyContract {
     /// Pre-commit a migration hash for replay protection.
     /// Must be called before `migrate()` with the same target_version and hash.
     pub fn commit_migration(env: Env, target_version: u32, hash: BytesN<32>, expires_at: u64) {
+        Self::require_function_enabled(&env, "commit_migration");
         let admin: Address = env.storage().instance().get(&DataKey::Admin)
             .unwrap_or_else(|| panic!("{}", ContractError::NotInitialized as u32));
         admin.require_auth();
@@ -2018,6 +10530,14 @@ impl GrainlifyContract {
     /// Requires a prior `commit_migration` call with the same hash (replay protection).
     /// Idempotent: migrating to the same version twice is a no-op after the first call.
     pub fn migrate(env: Env, target_version: u32, migration_hash: BytesN<32>) {
+        Self::require_function_enabled(&env, "migrate");
+        Self::in_transition(&env.clone(), symbol_short!("migrate"), || {
+            Self::run_migration(env, target_version, migration_hash)
+        })
+    }
+
+    fn run_migration(env: Env, target_version: u32, migration_hash: BytesN<32>) {
+        let tracked = monitoring::track_start(&env, symbol_short!("migrate"));
         let admin: Address = env.storage().instance().get(&DataKey::Admin)
             .unwrap_or_else(|| panic!("{}", ContractError::NotInitialized as u32));
         admin.require_auth();
@@ -2069,6 +10589,7 @@ impl GrainlifyContract {
         };
         env.storage().instance().set(&DataKey::MigrationState, &state);
         env.storage().instance().set(&DataKey::Version, &target_version);
+        Self::record_version(&env);
 
         // Consume commitment (replay protection)
         env.storage().instance().remove(&DataKey::MigrationCommitment(target_version));
@@ -2078,7 +10599,7 @@ impl GrainlifyContract {
             (current_version, target_version, env.ledger().timestamp()),
         );
 
-        monitoring::track_operation(&env, symbol_short!("migrate"), admin, true);
+        tracked.finish(&env, admin, true);
     }
 
     // ========================================================================
@@ -2090,13 +10611,15 @@ impl GrainlifyContract {
     fn load_upgrade_proposal(env: &Env, proposal_id: u64) -> Option<UpgradeProposalRecord> {
         let wasm_hash: BytesN<32> = env
             .storage()
-            .instance()
+            .persistent()
             .get(&DataKey::UpgradeProposal(proposal_id))?;
         let proposer: Option<Address> = env
             .storage()
-            .instance()
+            .persistent()
             .get(&DataKey::UpgradeProposalProposer(proposal_id));
         let proposal = multisig::MultiSig::get_proposal_opt(env, proposal_id)?;
+        let window: Option<ExecutionWindow> =
+            env.storage().persistent().get(&ProposalKey::UpgradeWindow(proposal_id));
 
         Some(UpgradeProposalRecord {
             proposal_id,
@@ -2104,6 +10627,26 @@ impl GrainlifyContract {
             wasm_hash,
             expiry: proposal.expiry,
             cancelled: proposal.cancelled,
+            not_before: window.as_ref().and_then(|window| window.not_before),
+            not_after: window.and_then(|window| window.not_after),
+            proposed_at: env
+                .storage()
+                .persistent()
+                .get(&ProposalKey::UpgradeProposedAt(proposal_id))
+                .unwrap_or(0),
+            superseded_by: env.storage().persistent().get(&ProposalKey::UpgradeSupersededBy(proposal_id)),
+            threshold: env
+                .storage()
+                .persistent()
+                .get(&ProposalKey::ThresholdAtCreation(proposal_id))
+                .unwrap_or(0),
+            external_id: Self::external_id_of(env, proposal_id),
+            min_protocol: env.storage().persistent().get(&ProposalKey::UpgradeMinProtocol(proposal_id)),
+            execution_attempts: env
+                .storage()
+                .persistent()
+                .get::<_, ExecutionAttempts>(&ProposalKey::ExecutionAttempts(proposal_id))
+                .map_or(Vec::new(env), |attempts| Vec::from_array(env, [attempts])),
         })
     }
 }
@@ -2125,7 +10668,7 @@ pub fn is_compatible_event_version(version: u32) -> bool {
     version == EVENT_SCHEMA_VERSION
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "contract"))]
 mod test_event_versioning;
 
 // ============================================================================
@@ -2141,6 +10684,17 @@ pub mod traits {
     }
 }
 
+/// Reference implementation of the shared upgrade interface.
+#[cfg(feature = "contract")]
+impl grainlify_upgradeable::Upgradeable for GrainlifyContract {
+    fn upgrade(env: Env, new_wasm_hash: BytesN<32>) {
+        GrainlifyContract::upgrade(env, new_wasm_hash)
+    }
+    fn get_version(env: Env) -> u32 {
+        GrainlifyContract::get_version(env)
+    }
+}
+
 #[cfg(feature = "contract")]
 impl traits::UpgradeInterface for GrainlifyContract {
     fn get_version(env: &Env) -> u32 {
@@ -2151,4 +10705,5 @@ impl traits::UpgradeInterface for GrainlifyContract {
         Ok(())
     }
 }
+#[cfg(feature = "contract")]
 mod test;
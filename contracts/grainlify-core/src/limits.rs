@@ -0,0 +1,121 @@
+//! # String Size Limits
+//!
+//! Byte limits for every string the contract stores, checked by
+//! [`validate_len`] before anything is written. Each field has a default,
+//! and governance can move it between a hard floor and ceiling with
+//! `set_string_limit`; the ceiling also bounds what older entries can hold.
+//!
+//! | Field        | Default | Floor | Ceiling | Error                |
+//! |--------------|---------|-------|---------|----------------------|
+//! | `name`       | 64      | 8     | 128     | `MetadataTooLong`    |
+//! | `desc`       | 256     | 32    | 1024    | `DescriptionTooLong` |
+//! | `url`        | 128     | 32    | 256     | `UrlTooLong`         |
+//! | `contact`    | 128     | 16    | 256     | `MetadataTooLong`    |
+//! | `label`      | 32      | 8     | 64      | `LabelTooLong`       |
+//! | `meta_tot`   | 1024    | 256   | 2048    | `MetadataTooLong`    |
+//! | `reason`     | 200     | 32    | 200     | `DescriptionTooLong` |
+//!
+//! `label` covers short identifiers: deployed contract names and the
+//! chain and network IDs. `meta_tot` bounds the four metadata fields
+//! together. `reason` covers why a signer revoked or cancelled.
+
+use soroban_sdk::{contracttype, symbol_short, Env, String, Symbol};
+
+use crate::ContractError;
+
+pub const MAX_NAME_LEN: u32 = 64;
+pub const MAX_DESCRIPTION_LEN: u32 = 256;
+pub const MAX_URL_LEN: u32 = 128;
+pub const MAX_CONTACT_LEN: u32 = 128;
+pub const MAX_LABEL_LEN: u32 = 32;
+pub const MAX_METADATA_TOTAL_LEN: u32 = 1024;
+pub const MAX_REASON_LEN: u32 = 200;
+
+/// Largest description any limit setting allows.
+pub const DESCRIPTION_CEILING: u32 = 1024;
+
+pub const NAME: Symbol = symbol_short!("name");
+pub const DESCRIPTION: Symbol = symbol_short!("desc");
+pub const URL: Symbol = symbol_short!("url");
+pub const CONTACT: Symbol = symbol_short!("contact");
+pub const LABEL: Symbol = symbol_short!("label");
+pub const METADATA_TOTAL: Symbol = symbol_short!("meta_tot");
+pub const REASON: Symbol = symbol_short!("reason");
+
+/// Governance overrides of the defaults (instance).
+#[contracttype]
+#[derive(Clone)]
+pub enum LimitKey {
+    /// u32 byte limit set for a field
+    StringLimit(Symbol),
+}
+
+/// (default, floor, ceiling, error) for `field`, or `None` if unknown.
+fn bounds(field: &Symbol) -> Option<(u32, u32, u32, ContractError)> {
+    let bounds = if *field == NAME {
+        (MAX_NAME_LEN, 8, 128, ContractError::MetadataTooLong)
+    } else if *field == DESCRIPTION {
+        (MAX_DESCRIPTION_LEN, 32, DESCRIPTION_CEILING, ContractError::DescriptionTooLong)
+    } else if *field == URL {
+        (MAX_URL_LEN, 32, 256, ContractError::UrlTooLong)
+    } else if *field == CONTACT {
+        (MAX_CONTACT_LEN, 16, 256, ContractError::MetadataTooLong)
+    } else if *field == LABEL {
+        (MAX_LABEL_LEN, 8, 64, ContractError::LabelTooLong)
+    } else if *field == METADATA_TOTAL {
+        (MAX_METADATA_TOTAL_LEN, 256, 2048, ContractError::MetadataTooLong)
+    } else if *field == REASON {
+        (MAX_REASON_LEN, 32, MAX_REASON_LEN, ContractError::DescriptionTooLong)
+    } else {
+        return None;
+    };
+    Some(bounds)
+}
+
+/// Current byte limit for `field`. Panics with `InvalidStringLimit` for an
+/// unknown field.
+pub fn get_limit(env: &Env, field: &Symbol) -> u32 {
+    let (default, ..) =
+        bounds(field).unwrap_or_else(|| panic!("{}", ContractError::InvalidStringLimit as u32));
+    env.storage()
+        .instance()
+        .get(&LimitKey::StringLimit(field.clone()))
+        .unwrap_or(default)
+}
+
+/// Sets the limit for `field`. Fails with `InvalidStringLimit` for an
+/// unknown field or a value outside its floor and ceiling. The caller is
+/// responsible for authorization.
+pub fn set_limit(env: &Env, field: &Symbol, max: u32) {
+    match bounds(field) {
+        Some((_, floor, ceiling, _)) if (floor..=ceiling).contains(&max) => {
+            env.storage()
+                .instance()
+                .set(&LimitKey::StringLimit(field.clone()), &max);
+        }
+        _ => panic!("{}", ContractError::InvalidStringLimit as u32),
+    }
+}
+
+/// Checks `len` bytes against the limit for `field`, returning the
+/// field's error when it is over.
+pub fn validate_byte_len(env: &Env, field: Symbol, len: u32) -> Result<(), ContractError> {
+    let (_, _, _, error) =
+        bounds(&field).unwrap_or_else(|| panic!("{}", ContractError::InvalidStringLimit as u32));
+    if len > get_limit(env, &field) {
+        return Err(error);
+    }
+    Ok(())
+}
+
+/// Checks `s` against the limit for `field`.
+pub fn validate_len(env: &Env, field: Symbol, s: &String) -> Result<(), ContractError> {
+    validate_byte_len(env, field, s.len())
+}
+
+/// [`validate_len`] that panics with the field's error code.
+pub fn require_len(env: &Env, field: Symbol, s: &String) {
+    if let Err(error) = validate_len(env, field, s) {
+        panic!("{}", error as u32);
+    }
+}
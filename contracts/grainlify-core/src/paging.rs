@@ -0,0 +1,74 @@
+//! # Paged Views
+//!
+//! Every view that returns an open-ended list takes a start index and a
+//! limit and answers with one page of it, so a large history or registry
+//! never pushes a read past the ledger budget:
+//!
+//! - `limit` is clamped to [`MAX_PAGE_SIZE`]
+//! - a `start` at or past the end gives an empty page
+//! - `has_more` tells whether entries remain after the page
+//!
+//! Contract types cannot be generic, so each view has its own page struct
+//! with the same `items` and `has_more` fields. Lists bounded by a small
+//! constant, such as signers or templates, are returned whole.
+
+use soroban_sdk::{contracttype, Symbol, Vec};
+
+use crate::{DeployedContract, DeploymentInfo, ThresholdChange, VersionChange};
+
+/// Most entries any paged view returns at once.
+pub const MAX_PAGE_SIZE: u32 = 50;
+
+/// Entries `[start, end)` of a list, as picked by [`range`].
+pub struct PageRange {
+    pub start: u64,
+    pub end: u64,
+    pub has_more: bool,
+}
+
+/// The page of up to `limit` entries from `start` in a list of `total`.
+pub fn range(start: u64, limit: u32, total: u64) -> PageRange {
+    let start = start.min(total);
+    let end = start.saturating_add(limit.min(MAX_PAGE_SIZE) as u64).min(total);
+    PageRange { start, end, has_more: end < total }
+}
+
+/// Page of `get_threshold_history`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ThresholdChangePage {
+    pub items: Vec<ThresholdChange>,
+    pub has_more: bool,
+}
+
+/// Page of `get_version_history`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VersionChangePage {
+    pub items: Vec<VersionChange>,
+    pub has_more: bool,
+}
+
+/// Page of `list_deployments`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DeploymentPage {
+    pub items: Vec<DeploymentInfo>,
+    pub has_more: bool,
+}
+
+/// Page of `list_deployed_contracts`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DeployedContractPage {
+    pub items: Vec<DeployedContract>,
+    pub has_more: bool,
+}
+
+/// Page of `export_metrics`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MetricsPage {
+    pub items: Vec<(Symbol, u64)>,
+    pub has_more: bool,
+}
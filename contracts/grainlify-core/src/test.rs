@@ -1,17 +1,14 @@
 #![cfg(test)]
 use super::*;
+use crate::testutils::setup_admin;
 use soroban_sdk::{testutils::Address as _, Address, Env, String};
 
 #[test]
 fn test_registry_workflow() {
     let env = Env::default();
-    env.mock_all_auths();
-    let contract_id = env.register_contract(None, GrainlifyContract);
-    let client = GrainlifyContractClient::new(&env, &contract_id);
-    let admin = Address::generate(&env);
+    let (client, _admin) = setup_admin(&env);
     let contract_a = Address::generate(&env);
     let name = String::from_str(&env, "vault");
-    client.init_admin(&admin);
     client.register_deployed_contract(&contract_a, &name, &ContractKind::Other, &1u32);
     let retrieved = client.get_deployed_contract(&contract_a).unwrap();
     assert_eq!(retrieved.address, contract_a);
@@ -20,11 +17,7 @@ fn test_registry_workflow() {
 #[test]
 fn test_cannot_init_twice() {
     let env = Env::default();
-    env.mock_all_auths();
-    let contract_id = env.register_contract(None, GrainlifyContract);
-    let client = GrainlifyContractClient::new(&env, &contract_id);
-    let admin = Address::generate(&env);
-    client.init_admin(&admin);
+    let (client, admin) = setup_admin(&env);
     let result = client.try_init_admin(&admin);
     assert!(result.is_err());
 }
@@ -32,10 +25,6 @@ fn test_cannot_init_twice() {
 #[test]
 fn test_get_nonexistent_address() {
     let env = Env::default();
-    env.mock_all_auths();
-    let contract_id = env.register_contract(None, GrainlifyContract);
-    let client = GrainlifyContractClient::new(&env, &contract_id);
-    let admin = Address::generate(&env);
-    client.init_admin(&admin);
+    let (client, _admin) = setup_admin(&env);
     assert!(client.get_deployed_contract(&Address::generate(&env)).is_none());
 }
@@ -439,7 +439,7 @@ fn test_monitoring_unique_user_count_is_bounded() {
         for index in 0..(monitoring::MAX_TRACKED_USERS + 5) {
             let caller = Address::generate(&env);
             let operation = Symbol::new(&env, if index % 2 == 0 { "ping" } else { "pong" });
-            monitoring::track_operation(&env, operation, caller, true);
+            monitoring::track(&env, operation, caller, true, None, true);
         }
     });
 
@@ -0,0 +1,144 @@
+//! # Action Bundle Proposal Tests
+//!
+//! - `execute_proposal` applies every action in order and publishes one
+//!   `("action", "applied")` event per action
+//! - A bundle whose second action fails validation reverts as a whole:
+//!   the first action's change does not persist and the proposal stays open
+//! - An upgrade can be bundled with other actions
+//! - Bundles must hold 1 to `MAX_PROPOSAL_ACTIONS` actions, and execution
+//!   needs threshold approval and an elapsed timelock
+
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{
+    testutils::{Events as _, Ledger as _},
+    vec, Address, Env, Symbol, TryFromVal, Vec,
+};
+
+use crate::testutils::{setup_multisig, upload_dummy_wasm};
+use crate::{ActionAppliedEvent, DataKey, GrainlifyContractClient, ProposalAction, MAX_PROPOSAL_ACTIONS};
+
+fn applied_events(env: &Env) -> std::vec::Vec<ActionAppliedEvent> {
+    let mut out = std::vec::Vec::new();
+    for (_, topics, data) in env.events().all().iter() {
+        if topics.len() != 2 {
+            continue;
+        }
+        let t0 = Symbol::try_from_val(env, &topics.get(0).unwrap());
+        if t0 == Ok(Symbol::new(env, "action")) {
+            out.push(ActionAppliedEvent::try_from_val(env, &data).unwrap());
+        }
+    }
+    out
+}
+
+/// Proposes `actions`, collects two of three approvals, and waits out the
+/// timelock.
+fn approved_bundle(
+    env: &Env,
+    client: &GrainlifyContractClient,
+    signers: &Vec<Address>,
+    actions: Vec<ProposalAction>,
+) -> u64 {
+    let proposal_id = client.propose_actions(&signers.get(0).unwrap(), &actions, &0);
+    client.approve_actions(&proposal_id, &signers.get(0).unwrap());
+    client.approve_actions(&proposal_id, &signers.get(1).unwrap());
+    env.ledger()
+        .set_timestamp(env.ledger().timestamp() + client.get_timelock_delay());
+    proposal_id
+}
+
+#[test]
+fn test_bundle_applies_all_actions_in_order() {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    let actions = vec![&env, ProposalAction::SetVersion(3), ProposalAction::SetTimelockDelay(7_200)];
+    let proposal_id = approved_bundle(&env, &client, &signers, actions.clone());
+    assert_eq!(client.get_proposal_actions(&proposal_id), Some(actions.clone()));
+
+    client.execute_proposal(&proposal_id);
+    assert_eq!(client.get_version(), 3);
+    assert_eq!(client.get_timelock_delay(), 7_200);
+
+    let events = applied_events(&env);
+    assert_eq!(events.len(), 2);
+    for (index, event) in events.iter().enumerate() {
+        assert_eq!(event.proposal_id, proposal_id);
+        assert_eq!(event.index, index as u32);
+        assert_eq!(event.action, actions.get(index as u32).unwrap());
+    }
+    assert!(client.try_execute_proposal(&proposal_id).is_err(), "consumed");
+}
+
+#[test]
+fn test_invalid_second_action_reverts_whole_bundle() {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    let version_before = client.get_version();
+    let actions = vec![
+        &env,
+        ProposalAction::SetVersion(3),
+        ProposalAction::SetTimelockDelay(u64::MAX),
+    ];
+    let proposal_id = approved_bundle(&env, &client, &signers, actions);
+
+    assert!(client.try_execute_proposal(&proposal_id).is_err());
+    assert_eq!(client.get_version(), version_before);
+    assert_eq!(client.get_timelock_delay(), 86_400);
+    assert_eq!(client.get_executed_upgrade(&proposal_id), None);
+    assert!(client.try_execute_proposal(&proposal_id).is_err(), "still fails, still open");
+}
+
+#[test]
+fn test_bundle_with_upgrade() {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    let wasm = upload_dummy_wasm(&env, 1);
+    let actions = vec![&env, ProposalAction::SetVersion(5), ProposalAction::Upgrade(wasm.clone())];
+    let proposal_id = approved_bundle(&env, &client, &signers, actions);
+
+    client.execute_proposal(&proposal_id);
+    let (version, installed) = env.as_contract(&client.address, || {
+        (
+            grainlify_upgradeable::get_version(&env),
+            env.storage()
+                .instance()
+                .get::<_, soroban_sdk::BytesN<32>>(&DataKey::CurrentWasmHash),
+        )
+    });
+    assert_eq!(version, 5);
+    assert_eq!(installed, Some(wasm));
+    assert_eq!(applied_events(&env).len(), 2);
+}
+
+#[test]
+fn test_bundle_size_bounds() {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    let proposer = signers.get(0).unwrap();
+    assert!(client.try_propose_actions(&proposer, &Vec::new(&env), &0).is_err());
+
+    let mut actions = Vec::new(&env);
+    for version in 0..=MAX_PROPOSAL_ACTIONS {
+        actions.push_back(ProposalAction::SetVersion(version + 1));
+    }
+    assert!(client.try_propose_actions(&proposer, &actions, &0).is_err());
+    actions.pop_back();
+    client.propose_actions(&proposer, &actions, &0);
+}
+
+#[test]
+fn test_execution_requires_threshold_and_timelock() {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    let actions = vec![&env, ProposalAction::SetVersion(3)];
+    let proposal_id = client.propose_actions(&signers.get(0).unwrap(), &actions, &0);
+    client.approve_actions(&proposal_id, &signers.get(0).unwrap());
+    assert!(client.try_execute_proposal(&proposal_id).is_err());
+
+    client.approve_actions(&proposal_id, &signers.get(1).unwrap());
+    assert!(client.try_execute_proposal(&proposal_id).is_err(), "timelock pending");
+    assert!(client.try_approve_actions(&99, &signers.get(2).unwrap()).is_err());
+}
@@ -0,0 +1,127 @@
+//! # Contract Admin Tests
+//!
+//! The admin may be a controller contract (DAO, multisig wallet) rather than
+//! an account. Its authorization comes from invoking this contract directly,
+//! so no signatures are mocked after initialization. Covers:
+//! - A controller contract upgrading and versioning via cross-contract calls
+//! - Pausing through the admin-or-signer path with a contract caller
+//! - Calls from anyone but the controller are still rejected
+//! - `get_admin_type` for account, contract, and missing admins
+
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{testutils::Address as _, vec, Address, Env, String};
+
+use crate::testutils::{setup_multisig, upload_dummy_wasm};
+use crate::{AdminType, GrainlifyContract, GrainlifyContractClient};
+
+mod controller {
+    use soroban_sdk::{contract, contractimpl, Address, BytesN, Env};
+
+    use crate::GrainlifyContractClient;
+
+    /// Minimal governance contract standing in for a DAO. Each call forwards
+    /// to the governed contract, which sees this contract as the invoker.
+    #[contract]
+    pub struct Controller;
+
+    #[contractimpl]
+    impl Controller {
+        pub fn upgrade(env: Env, target: Address, wasm_hash: BytesN<32>) {
+            GrainlifyContractClient::new(&env, &target).upgrade(&wasm_hash);
+        }
+
+        pub fn set_version(env: Env, target: Address, version: u32) {
+            GrainlifyContractClient::new(&env, &target).set_version(&version);
+        }
+
+        pub fn pause(env: Env, target: Address) {
+            GrainlifyContractClient::new(&env, &target).pause(&env.current_contract_address());
+        }
+    }
+}
+
+use controller::{Controller, ControllerClient};
+
+/// Contract governed by a freshly registered controller. Auth mocking is
+/// switched off once the controller is installed as admin.
+fn setup(env: &Env) -> (GrainlifyContractClient<'_>, ControllerClient<'_>) {
+    let client = GrainlifyContractClient::new(env, &env.register_contract(None, GrainlifyContract));
+    let controller = ControllerClient::new(env, &env.register_contract(None, Controller));
+
+    env.mock_all_auths();
+    client.init_admin(&controller.address);
+    env.set_auths(&[]);
+    (client, controller)
+}
+
+#[test]
+fn test_contract_admin_sets_version() {
+    let env = Env::default();
+    let (client, controller) = setup(&env);
+    controller.set_version(&client.address, &5);
+    assert_eq!(client.get_version(), 5);
+}
+
+#[test]
+fn test_contract_admin_upgrades() {
+    let env = Env::default();
+    let (client, controller) = setup(&env);
+    controller.set_version(&client.address, &3);
+    controller.upgrade(&client.address, &upload_dummy_wasm(&env, 1));
+
+    // The contract now runs the uploaded module; read state directly.
+    let previous = env.as_contract(&client.address, || {
+        grainlify_upgradeable::get_previous_version(&env)
+    });
+    assert_eq!(previous, Some(3));
+}
+
+#[test]
+fn test_contract_admin_pauses() {
+    let env = Env::default();
+    let (client, controller) = setup(&env);
+    env.as_contract(&client.address, || {
+        crate::MultiSig::init(&env, vec![&env, Address::generate(&env)], 1);
+    });
+    controller.pause(&client.address);
+    assert!(client.is_paused());
+}
+
+#[test]
+fn test_direct_call_without_controller_rejected() {
+    let env = Env::default();
+    let (client, _controller) = setup(&env);
+    assert!(client.try_set_version(&5).is_err());
+    assert!(client.try_upgrade(&upload_dummy_wasm(&env, 1)).is_err());
+}
+
+#[test]
+fn test_other_controller_rejected() {
+    let env = Env::default();
+    let (client, _controller) = setup(&env);
+    let impostor = ControllerClient::new(&env, &env.register_contract(None, Controller));
+    assert!(impostor.try_set_version(&client.address, &5).is_err());
+    assert_eq!(client.get_version(), 2);
+}
+
+#[test]
+fn test_admin_type() {
+    let env = Env::default();
+    let (client, _controller) = setup(&env);
+    assert_eq!(client.get_admin_type(), Some(AdminType::Contract));
+
+    let account = Address::from_string(&String::from_str(
+        &env,
+        "GAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAWHF",
+    ));
+    let other = GrainlifyContractClient::new(&env, &env.register_contract(None, GrainlifyContract));
+    env.mock_all_auths();
+    other.init_admin(&account);
+    assert_eq!(other.get_admin_type(), Some(AdminType::Account));
+
+    let (multisig_only, _signers) = setup_multisig(&env, 1, 1);
+    assert_eq!(multisig_only.get_admin_type(), None);
+}
@@ -0,0 +1,84 @@
+//! # Admin Handover Tests
+//!
+//! - `get_pending_admin_actions` lists the staged upgrade, upgrade ticket,
+//!   and running maintenance window
+//! - Confirming an admin-changing snapshot restore revokes the staged
+//!   upgrade, which the new admin then cannot confirm
+//! - `migrate_to_multisig` revokes the ticket and maintenance window
+//! - Each revoked item publishes a `revoked` event
+
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events as _, Ledger as _},
+    vec, Address, Env, IntoVal, Symbol,
+};
+
+use crate::testutils::{setup_admin, upload_dummy_wasm};
+use crate::{DataKey, GrainlifyContractClient};
+
+fn revoked(env: &Env, topic: Symbol) -> bool {
+    let topics = (topic, symbol_short!("revoked")).into_val(env);
+    env.events().all().iter().any(|(_, t, _)| t == topics)
+}
+
+/// Snapshot naming `successor` as admin, taken while `successor` is
+/// written in directly; the admin is restored afterwards.
+fn snapshot_with_admin(env: &Env, client: &GrainlifyContractClient, admin: &Address, successor: &Address) -> u64 {
+    env.as_contract(&client.address, || env.storage().instance().set(&DataKey::Admin, successor));
+    let snapshot_id = client.create_config_snapshot();
+    env.as_contract(&client.address, || env.storage().instance().set(&DataKey::Admin, admin));
+    snapshot_id
+}
+
+#[test]
+fn test_restore_handover_revokes_staged_upgrade() {
+    let env = Env::default();
+    let (client, admin) = setup_admin(&env);
+    env.ledger().set_timestamp(1_000);
+    let successor = Address::generate(&env);
+    let snapshot_id = snapshot_with_admin(&env, &client, &admin, &successor);
+
+    client.set_staging_delay(&Some(60));
+    let wasm_hash = upload_dummy_wasm(&env, 1);
+    client.stage_upgrade(&wasm_hash);
+    client.restore_config_snapshot(&snapshot_id);
+    let pending = client.get_pending_admin_actions();
+    assert_eq!(pending.staged_upgrade.get(0).unwrap().wasm_hash, wasm_hash);
+
+    client.confirm_admin_restore(&snapshot_id);
+    assert_eq!(client.get_admin(), Some(successor));
+    let cleared = client.get_pending_admin_actions();
+    assert!(cleared.staged_upgrade.is_empty());
+    assert!(cleared.upgrade_ticket.is_empty());
+    assert!(revoked(&env, symbol_short!("upgrade")));
+
+    env.ledger().set_timestamp(2_000);
+    assert!(client.try_confirm_upgrade(&wasm_hash).is_err());
+}
+
+#[test]
+fn test_migration_revokes_ticket_and_maintenance() {
+    let env = Env::default();
+    let (client, _admin) = setup_admin(&env);
+    env.ledger().set_timestamp(1_000);
+    let wasm_hash = upload_dummy_wasm(&env, 1);
+    client.authorize_upgrade(&wasm_hash, &1_100, &5_000);
+    client.enter_maintenance(&3_000);
+    let pending = client.get_pending_admin_actions();
+    assert_eq!(pending.upgrade_ticket.get(0).unwrap().wasm_hash, wasm_hash);
+    assert_eq!(pending.maintenance_until, Some(3_000));
+
+    let signers = vec![&env, Address::generate(&env), Address::generate(&env)];
+    client.migrate_to_multisig(&signers, &2, &false);
+    assert!(revoked(&env, symbol_short!("ticket")));
+    assert!(revoked(&env, symbol_short!("maint")));
+    assert_eq!(client.get_upgrade_ticket(), None);
+    assert!(!client.maintenance_status().active);
+
+    env.ledger().set_timestamp(1_200);
+    assert!(client.try_execute_authorized_upgrade(&wasm_hash).is_err());
+}
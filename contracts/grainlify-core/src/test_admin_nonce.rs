@@ -0,0 +1,88 @@
+//! # Admin Nonce Tests
+//!
+//! - `set_version_with_nonce` / `upgrade_with_nonce` accept only the current
+//!   nonce and consume it; stale and future nonces fail with
+//!   `InvalidAdminNonce`
+//! - With `require_nonce` on, the nonce-less variants fail with
+//!   `NonceRequired`; turning it off restores them
+//! - Only governance can flip the flag
+
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::Env;
+
+use crate::testutils::{setup_admin, upload_dummy_wasm};
+use crate::DataKey;
+
+#[test]
+fn test_nonce_consumed_in_order() {
+    let env = Env::default();
+    let (client, _admin) = setup_admin(&env);
+    assert_eq!(client.get_admin_nonce(), 0);
+
+    client.set_version_with_nonce(&3, &0);
+    assert_eq!(client.get_version(), 3);
+    assert_eq!(client.get_admin_nonce(), 1);
+
+    assert!(client.try_set_version_with_nonce(&4, &0).is_err(), "stale nonce");
+    assert!(client.try_set_version_with_nonce(&4, &2).is_err(), "future nonce");
+    assert_eq!(client.get_version(), 3);
+    assert_eq!(client.get_admin_nonce(), 1);
+
+    let wasm = upload_dummy_wasm(&env, 1);
+    assert!(client.try_upgrade_with_nonce(&wasm, &0).is_err());
+    let receipt = client.upgrade_with_nonce(&wasm, &1);
+    assert_eq!(receipt.wasm_hash, wasm);
+    let nonce: u64 = env.as_contract(&client.address, || {
+        env.storage().instance().get(&DataKey::AdminNonce).unwrap()
+    });
+    assert_eq!(nonce, 2);
+}
+
+#[test]
+#[should_panic(expected = "123")]
+fn test_stale_nonce_fails_with_typed_error() {
+    let env = Env::default();
+    let (client, _admin) = setup_admin(&env);
+    client.set_version_with_nonce(&3, &0);
+    client.set_version_with_nonce(&4, &0);
+}
+
+#[test]
+fn test_require_nonce_flag() {
+    let env = Env::default();
+    let (client, _admin) = setup_admin(&env);
+    client.set_version(&3);
+    assert_eq!(client.get_admin_nonce(), 0, "nonce-less calls do not consume");
+
+    client.set_require_nonce(&true);
+    assert!(client.get_require_nonce());
+    assert!(client.try_set_version(&4).is_err());
+    assert!(client.try_upgrade(&upload_dummy_wasm(&env, 1)).is_err());
+    client.set_version_with_nonce(&4, &0);
+    assert_eq!(client.get_version(), 4);
+
+    client.set_require_nonce(&false);
+    client.set_version(&5);
+    assert_eq!(client.get_version(), 5);
+}
+
+#[test]
+#[should_panic(expected = "124")]
+fn test_nonce_less_call_fails_with_typed_error() {
+    let env = Env::default();
+    let (client, _admin) = setup_admin(&env);
+    client.set_require_nonce(&true);
+    client.set_version(&4);
+}
+
+#[test]
+fn test_require_nonce_is_governance_only() {
+    let env = Env::default();
+    let (client, _admin) = setup_admin(&env);
+    env.set_auths(&[]);
+    assert!(client.try_set_require_nonce(&true).is_err());
+    assert!(!client.get_require_nonce());
+}
@@ -0,0 +1,109 @@
+//! # Proposal Amendment Tests
+//!
+//! - The proposer can replace the hash while no one has approved; the
+//!   record shows the new hash and a fresh `proposed_at`, and an `amended`
+//!   event carries both hashes
+//! - The proposer's own approval (and a timelock it started) is reset
+//! - Once another signer approves, amendment fails for good, even after
+//!   that approval is revoked
+//! - Other signers cannot amend, and closed proposals cannot be amended
+
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{testutils::Events as _, testutils::Ledger as _, Env, Symbol, TryFromVal};
+
+use crate::testutils::{setup_multisig, upload_dummy_wasm};
+use crate::{DataKey, ProposalAmendedEvent};
+
+fn amended_event(env: &Env) -> Option<ProposalAmendedEvent> {
+    let mut found = None;
+    for (_, topics, data) in env.events().all().iter() {
+        if topics.len() != 2 {
+            continue;
+        }
+        let t1 = Symbol::try_from_val(env, &topics.get(1).unwrap());
+        if t1 == Ok(Symbol::new(env, "amended")) {
+            found = ProposalAmendedEvent::try_from_val(env, &data).ok();
+        }
+    }
+    found
+}
+
+#[test]
+fn test_amend_before_approvals() {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    let proposer = signers.get(0).unwrap();
+    let (old_hash, new_hash) = (upload_dummy_wasm(&env, 1), upload_dummy_wasm(&env, 2));
+    env.ledger().set_timestamp(100);
+    let proposal_id = client.propose_upgrade(&proposer, &old_hash, &0);
+
+    env.ledger().set_timestamp(250);
+    client.amend_proposal(&proposal_id, &new_hash, &proposer);
+
+    let record = client.get_upgrade_proposal(&proposal_id).unwrap();
+    assert_eq!(record.wasm_hash, new_hash);
+    assert_eq!(record.proposed_at, 250);
+    let event = amended_event(&env).unwrap();
+    assert_eq!((event.old_wasm_hash, event.new_wasm_hash), (old_hash, new_hash.clone()));
+    assert_eq!(event.proposal_id, proposal_id);
+
+    client.approve_upgrade(&proposal_id, &proposer);
+    client.approve_upgrade(&proposal_id, &signers.get(1).unwrap());
+    env.ledger().set_timestamp(250 + client.get_timelock_delay());
+    assert_eq!(client.execute_upgrade_v2(&proposal_id).wasm_hash, new_hash);
+}
+
+#[test]
+fn test_amend_resets_proposer_approval_and_timelock() {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 3, 1);
+    let proposer = signers.get(0).unwrap();
+    let proposal_id = client.propose_upgrade(&proposer, &upload_dummy_wasm(&env, 1), &0);
+    client.approve_upgrade(&proposal_id, &proposer);
+
+    client.amend_proposal(&proposal_id, &upload_dummy_wasm(&env, 2), &proposer);
+
+    env.as_contract(&client.address, || {
+        let proposal = crate::MultiSig::get_proposal_opt(&env, proposal_id).unwrap();
+        assert!(proposal.approvals.is_empty());
+        assert!(!env.storage().persistent().has(&DataKey::UpgradeTimelock(proposal_id)));
+    });
+}
+
+#[test]
+fn test_amend_locked_after_other_signer_approves() {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    let proposer = signers.get(0).unwrap();
+    let other = signers.get(1).unwrap();
+    let proposal_id = client.propose_upgrade(&proposer, &upload_dummy_wasm(&env, 1), &0);
+    let new_hash = upload_dummy_wasm(&env, 2);
+
+    client.approve_upgrade(&proposal_id, &other);
+    assert!(client.try_amend_proposal(&proposal_id, &new_hash, &proposer).is_err());
+
+    client.revoke_approval(&proposal_id, &other);
+    assert!(client.try_amend_proposal(&proposal_id, &new_hash, &proposer).is_err());
+    assert_eq!(client.get_upgrade_proposal(&proposal_id).unwrap().wasm_hash, upload_dummy_wasm(&env, 1));
+}
+
+#[test]
+fn test_only_proposer_amends_open_proposals() {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    let proposer = signers.get(0).unwrap();
+    let new_hash = upload_dummy_wasm(&env, 2);
+
+    let proposal_id = client.propose_upgrade(&proposer, &upload_dummy_wasm(&env, 1), &0);
+    assert!(client.try_amend_proposal(&proposal_id, &new_hash, &signers.get(1).unwrap()).is_err());
+
+    client.cancel_upgrade(&proposal_id, &proposer);
+    assert!(client.try_amend_proposal(&proposal_id, &new_hash, &proposer).is_err());
+
+    let expiring = client.propose_upgrade(&proposer, &upload_dummy_wasm(&env, 1), &100);
+    env.ledger().set_timestamp(100);
+    assert!(client.try_amend_proposal(&expiring, &new_hash, &proposer).is_err());
+}
@@ -0,0 +1,83 @@
+//! # Approval Bitmap Tests
+//!
+//! - `get_approval_bitmap` plus `get_signer_at` reconstructs the approvers
+//!   `get_approver_addresses` lists, over more than one byte of signers
+//! - `get_signer_index` and `get_signer_at` are inverse, and a joining
+//!   signer takes the next index without moving the others
+//! - Proposals without valid approvals have every bit clear
+
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{testutils::Address as _, vec, Address, Bytes, Env, Vec};
+
+use crate::testutils::{setup_admin, setup_multisig, upload_dummy_wasm};
+use crate::{GrainlifyContractClient, MultiSig};
+
+fn approvers_from_bitmap(env: &Env, client: &GrainlifyContractClient, bitmap: &Bytes) -> Vec<Address> {
+    let mut approvers = Vec::new(env);
+    for i in 0..bitmap.len() * 8 {
+        if bitmap.get(i / 8).unwrap() & (1 << (i % 8)) != 0 {
+            approvers.push_back(client.get_signer_at(&i));
+        }
+    }
+    approvers
+}
+
+#[test]
+fn test_bitmap_reconstructs_approvers() {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 10, 6);
+    let proposal_id = client.propose_upgrade(&signers.get(0).unwrap(), &upload_dummy_wasm(&env, 1), &0);
+    for i in [9, 0, 8, 3] {
+        client.approve_upgrade(&proposal_id, &signers.get(i).unwrap());
+    }
+
+    let bitmap = client.get_approval_bitmap(&proposal_id);
+    assert_eq!(bitmap.len(), 2);
+    assert_eq!((bitmap.get(0).unwrap(), bitmap.get(1).unwrap()), (0b0000_1001, 0b0000_0011));
+
+    let mut expected = std::vec::Vec::from_iter(client.get_approver_addresses(&proposal_id).iter());
+    let mut actual = std::vec::Vec::from_iter(approvers_from_bitmap(&env, &client, &bitmap).iter());
+    expected.sort_by_key(|signer| client.get_signer_index(signer));
+    actual.sort_by_key(|signer| client.get_signer_index(signer));
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn test_index_stable_when_signer_joins() {
+    let env = Env::default();
+    let (client, _) = setup_admin(&env);
+    let signers = vec![&env, Address::generate(&env), Address::generate(&env)];
+    env.as_contract(&client.address, || MultiSig::init(&env, signers.clone(), 2));
+    for (i, signer) in signers.iter().enumerate() {
+        assert_eq!(client.get_signer_index(&signer), Some(i as u32));
+        assert_eq!(client.get_signer_at(&(i as u32)), signer);
+    }
+
+    let newcomer = Address::generate(&env);
+    assert_eq!(client.get_signer_index(&newcomer), None);
+    client.add_signer(&newcomer);
+    client.accept_signership(&newcomer);
+    assert_eq!(client.get_signer_index(&signers.get(0).unwrap()), Some(0));
+    assert_eq!(client.get_signer_index(&signers.get(1).unwrap()), Some(1));
+    assert_eq!(client.get_signer_index(&newcomer), Some(2));
+}
+
+#[test]
+fn test_no_valid_approvals_clears_bitmap() {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    let proposal_id = client.propose_upgrade(&signers.get(0).unwrap(), &upload_dummy_wasm(&env, 1), &0);
+    assert_eq!(client.get_approval_bitmap(&proposal_id), Bytes::from_array(&env, &[0]));
+    assert_eq!(client.get_approval_bitmap(&99), Bytes::from_array(&env, &[0]));
+}
+
+#[test]
+#[should_panic(expected = "Signer index out of range")]
+fn test_signer_at_out_of_range() {
+    let env = Env::default();
+    let (client, _) = setup_multisig(&env, 3, 2);
+    client.get_signer_at(&3);
+}
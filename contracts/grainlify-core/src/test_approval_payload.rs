@@ -0,0 +1,155 @@
+//! # Approval Payload Tests
+//!
+//! - `approve_upgrade_v2` puts the proposal's wasm hash and description in
+//!   the arguments the signer authorizes
+//! - A hash or description that differs from storage fails with
+//!   `ApprovalMismatch`
+//! - Long descriptions are confirmed by their first
+//!   `APPROVAL_DESCRIPTION_LEN` bytes
+//! - Governance can turn off the legacy `approve_upgrade` forms
+
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{
+    testutils::{AuthorizedFunction, AuthorizedInvocation},
+    vec, Address, BytesN, Env, IntoVal, String, Symbol, Vec,
+};
+
+use crate::testutils::{setup_admin, setup_multisig, upload_dummy_wasm, GovernanceDriver};
+use crate::{GrainlifyContractClient, MultiSig, ProposalAction, APPROVAL_DESCRIPTION_LEN};
+
+fn approvals(env: &Env, client: &GrainlifyContractClient, proposal_id: u64) -> Vec<Address> {
+    env.as_contract(&client.address, || MultiSig::get_proposal_opt(env, proposal_id).unwrap().approvals)
+}
+
+fn describe(env: &Env, text: &str) -> String {
+    String::from_str(env, text)
+}
+
+#[test]
+fn test_signer_authorizes_hash_and_description() {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    let signer = signers.get(0).unwrap();
+    let wasm_hash = upload_dummy_wasm(&env, 1);
+    let description = describe(&env, "v2: fee fix");
+    let proposal_id = client.propose_upgrade_with_description(&signer, &wasm_hash, &description, &0);
+
+    client.approve_upgrade_v2(&proposal_id, &signer, &wasm_hash, &description, &None, &None);
+    assert_eq!(
+        env.auths(),
+        std::vec![(
+            signer.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    client.address.clone(),
+                    Symbol::new(&env, "approve_upgrade_v2"),
+                    (
+                        proposal_id,
+                        signer.clone(),
+                        wasm_hash.clone(),
+                        description.clone(),
+                        None::<Symbol>,
+                        None::<BytesN<32>>,
+                    )
+                        .into_val(&env),
+                )),
+                sub_invocations: std::vec![],
+            }
+        )]
+    );
+    assert!(approvals(&env, &client, proposal_id).contains(&signer));
+}
+
+#[test]
+fn test_mismatched_hash_or_description_is_rejected() {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    let signer = signers.get(0).unwrap();
+    let wasm_hash = upload_dummy_wasm(&env, 1);
+    let description = describe(&env, "v2: fee fix");
+    let proposal_id = client.propose_upgrade_with_description(&signer, &wasm_hash, &description, &0);
+
+    let other_hash = upload_dummy_wasm(&env, 2);
+    assert!(client
+        .try_approve_upgrade_v2(&proposal_id, &signer, &other_hash, &description, &None, &None)
+        .is_err());
+    assert!(client
+        .try_approve_upgrade_v2(
+            &proposal_id,
+            &signer,
+            &wasm_hash,
+            &describe(&env, "v2: fee fix!"),
+            &None,
+            &None
+        )
+        .is_err());
+    assert!(approvals(&env, &client, proposal_id).is_empty());
+}
+
+#[test]
+#[should_panic(expected = "136")]
+fn test_mismatch_fails_with_approval_mismatch() {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    let signer = signers.get(0).unwrap();
+    let wasm_hash = upload_dummy_wasm(&env, 1);
+    let proposal_id = client.propose_upgrade(&signer, &wasm_hash, &0);
+    client.approve_upgrade_v2(&proposal_id, &signer, &wasm_hash, &describe(&env, "unstated"), &None, &None);
+}
+
+#[test]
+fn test_long_description_is_confirmed_by_prefix() {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    let signer = signers.get(0).unwrap();
+    let wasm_hash = upload_dummy_wasm(&env, 1);
+    // 'é' is two bytes, so the cut at 64 would split the last one.
+    let text = std::format!("{}{}", "a".repeat(63), "é".repeat(20));
+    let proposal_id =
+        client.propose_upgrade_with_description(&signer, &wasm_hash, &describe(&env, &text), &0);
+
+    let expected = client.get_approval_description(&proposal_id);
+    assert_eq!(expected, describe(&env, &"a".repeat(63)));
+    assert!(expected.len() <= APPROVAL_DESCRIPTION_LEN);
+    assert!(client
+        .try_approve_upgrade_v2(&proposal_id, &signer, &wasm_hash, &describe(&env, &text), &None, &None)
+        .is_err());
+    client.approve_upgrade_v2(&proposal_id, &signer, &wasm_hash, &expected, &None, &None);
+}
+
+fn set_legacy(env: &Env, client: &GrainlifyContractClient, signers: &Vec<Address>, enabled: bool) {
+    let actions = vec![env, ProposalAction::SetLegacyApprovalEnabled(enabled)];
+    GovernanceDriver::apply_actions(env, client, signers, actions);
+}
+
+#[test]
+fn test_legacy_approval_can_be_turned_off() {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    let signer = signers.get(0).unwrap();
+    let wasm_hash = upload_dummy_wasm(&env, 1);
+    let proposal_id = client.propose_upgrade(&signer, &wasm_hash, &0);
+    assert!(client.is_legacy_approval_enabled());
+
+    set_legacy(&env, &client, &signers, false);
+    assert!(!client.is_legacy_approval_enabled());
+    assert!(client.try_approve_upgrade(&proposal_id, &signer).is_err());
+    client.approve_upgrade_v2(&proposal_id, &signer, &wasm_hash, &describe(&env, ""), &None, &None);
+
+    set_legacy(&env, &client, &signers, true);
+    client.approve_upgrade(&proposal_id, &signers.get(1).unwrap());
+}
+
+#[test]
+fn test_legacy_switch_is_governance_only() {
+    let env = Env::default();
+    let (client, _signers) = setup_multisig(&env, 3, 2);
+    assert!(client.try_set_legacy_approval_enabled(&false).is_err());
+
+    let (client, _admin) = setup_admin(&env);
+    client.set_legacy_approval_enabled(&false);
+    assert!(!client.is_legacy_approval_enabled());
+}
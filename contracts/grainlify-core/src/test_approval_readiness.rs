@@ -0,0 +1,107 @@
+//! # Approval Readiness Tests
+//!
+//! - Each `("approved",)` event carries the tally after the approval, the
+//!   threshold and whether it is met
+//! - Reaching the threshold publishes `("upgrade", "ready")` once; extra
+//!   approvals above it publish nothing more
+//! - A revocation that drops the tally below the threshold publishes
+//!   `("upgrade", "unready")`, and approving again publishes a new `ready`
+
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{
+    symbol_short, testutils::Events as _, Address, Env, IntoVal, Symbol, TryFromVal, Vec,
+};
+
+use crate::testutils::{setup_multisig, upload_dummy_wasm};
+use crate::{GrainlifyContractClient, ProposalReadinessEvent};
+
+fn readiness(env: &Env, action: &str) -> Vec<ProposalReadinessEvent> {
+    let topics = (symbol_short!("upgrade"), Symbol::new(env, action)).into_val(env);
+    let mut found = Vec::new(env);
+    for (_, t, data) in env.events().all().iter() {
+        if t == topics {
+            found.push_back(ProposalReadinessEvent::try_from_val(env, &data).unwrap());
+        }
+    }
+    found
+}
+
+fn last_approval(env: &Env) -> (u64, Address, u64, u32, u32, bool) {
+    let topics = (symbol_short!("approved"),).into_val(env);
+    let (_, _, data) = env.events().all().iter().filter(|(_, t, _)| *t == topics).last().unwrap();
+    <(u64, Address, u64, u32, u32, bool)>::try_from_val(env, &data).unwrap()
+}
+
+fn propose(env: &Env) -> (GrainlifyContractClient<'_>, Vec<Address>, u64) {
+    let (client, signers) = setup_multisig(env, 3, 2);
+    let proposal_id = client.propose_upgrade(&signers.get(0).unwrap(), &upload_dummy_wasm(env, 1), &0);
+    (client, signers, proposal_id)
+}
+
+#[test]
+fn test_approval_event_carries_tally() {
+    let env = Env::default();
+    let (client, signers, proposal_id) = propose(&env);
+
+    client.approve_upgrade(&proposal_id, &signers.get(0).unwrap());
+    let (_, _, _, tally, threshold, met) = last_approval(&env);
+    assert_eq!((tally, threshold, met), (1, 2, false));
+
+    client.approve_upgrade(&proposal_id, &signers.get(1).unwrap());
+    let (_, _, _, tally, threshold, met) = last_approval(&env);
+    assert_eq!((tally, threshold, met), (2, 2, true));
+}
+
+#[test]
+fn test_ready_published_once_per_crossing() {
+    let env = Env::default();
+    let (client, signers, proposal_id) = propose(&env);
+
+    client.approve_upgrade(&proposal_id, &signers.get(0).unwrap());
+    assert!(readiness(&env, "ready").is_empty());
+
+    client.approve_upgrade(&proposal_id, &signers.get(1).unwrap());
+    let ready = readiness(&env, "ready");
+    assert_eq!(ready.len(), 1);
+    let event = ready.get(0).unwrap();
+    assert_eq!((event.proposal_id, event.tally, event.threshold), (proposal_id, 2, 2));
+
+    client.approve_upgrade(&proposal_id, &signers.get(2).unwrap());
+    assert_eq!(readiness(&env, "ready").len(), 1);
+    let (_, _, _, tally, _, met) = last_approval(&env);
+    assert_eq!((tally, met), (3, true));
+}
+
+#[test]
+fn test_revoke_below_threshold_then_reapprove() {
+    let env = Env::default();
+    let (client, signers, proposal_id) = propose(&env);
+    client.approve_upgrade(&proposal_id, &signers.get(0).unwrap());
+    client.approve_upgrade(&proposal_id, &signers.get(1).unwrap());
+
+    client.revoke_approval(&proposal_id, &signers.get(1).unwrap());
+    let unready = readiness(&env, "unready");
+    assert_eq!(unready.len(), 1);
+    let event = unready.get(0).unwrap();
+    assert_eq!((event.proposal_id, event.tally, event.threshold), (proposal_id, 1, 2));
+
+    client.approve_upgrade(&proposal_id, &signers.get(2).unwrap());
+    let ready = readiness(&env, "ready");
+    assert_eq!(ready.len(), 2);
+    assert_eq!(ready.get(1).unwrap().tally, 2);
+}
+
+#[test]
+fn test_revoke_above_threshold_stays_ready() {
+    let env = Env::default();
+    let (client, signers, proposal_id) = propose(&env);
+    for i in 0..3 {
+        client.approve_upgrade(&proposal_id, &signers.get(i).unwrap());
+    }
+
+    client.revoke_approval(&proposal_id, &signers.get(2).unwrap());
+    assert!(readiness(&env, "unready").is_empty());
+}
@@ -0,0 +1,76 @@
+//! # Approve-and-Execute Tests
+//!
+//! - An approval that leaves nothing gating the proposal installs it in the
+//!   same call, with the signer recorded as executor
+//! - An approval that meets the threshold while the timelock runs is
+//!   recorded and reports `TimelockPending`
+//! - An approval short of the threshold is recorded and reports
+//!   `NotExecutable`
+
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Events as _, Ledger as _},
+    vec, Address, Env, IntoVal, TryFromVal, Vec,
+};
+
+use crate::testutils::{setup_admin_and_multisig, upload_dummy_wasm};
+use crate::{ConfigUpdate, ExecutionCheck, GrainlifyContractClient, UpgradeExecutedEvent};
+
+/// Admin-initialized contract with a 2-of-3 multisig and one open upgrade
+/// proposal.
+fn setup(env: &Env) -> (GrainlifyContractClient<'_>, Vec<Address>, u64) {
+    let (client, signers) = setup_admin_and_multisig(env);
+    let proposal_id = client.propose_upgrade(&signers.get(0).unwrap(), &upload_dummy_wasm(env, 1), &0);
+    (client, signers, proposal_id)
+}
+
+#[test]
+fn test_final_approval_executes() {
+    let env = Env::default();
+    let (client, signers, proposal_id) = setup(&env);
+    client.approve_upgrade(&proposal_id, &signers.get(0).unwrap());
+    client.approve_upgrade(&proposal_id, &signers.get(1).unwrap());
+    // The timelock started at the old threshold; the third approval is now
+    // the one that completes the new threshold.
+    client.set_config(&ConfigUpdate { threshold: Some(3), ..Default::default() });
+    env.ledger().set_timestamp(client.get_timelock_delay());
+
+    let last = signers.get(2).unwrap();
+    let outcome = client.approve_and_execute(&proposal_id, &last);
+    assert!(outcome.executed);
+    assert!(outcome.failing.is_empty());
+
+    let topics = (symbol_short!("upgrade"), symbol_short!("executed")).into_val(&env);
+    let (_, _, data) = env.events().all().iter().find(|(_, t, _)| *t == topics).unwrap();
+    assert_eq!(UpgradeExecutedEvent::try_from_val(&env, &data).unwrap().executor, Some(last));
+}
+
+#[test]
+fn test_timelock_pending_records_approval() {
+    let env = Env::default();
+    let (client, signers, proposal_id) = setup(&env);
+    client.approve_upgrade(&proposal_id, &signers.get(0).unwrap());
+
+    let outcome = client.approve_and_execute(&proposal_id, &signers.get(1).unwrap());
+    assert!(!outcome.executed);
+    assert_eq!(outcome.failing, vec![&env, ExecutionCheck::TimelockPending]);
+    assert_eq!(client.approvals_needed(&proposal_id), 0);
+
+    env.ledger().set_timestamp(client.get_timelock_delay());
+    client.execute_upgrade(&proposal_id);
+}
+
+#[test]
+fn test_not_final_approval_records_approval() {
+    let env = Env::default();
+    let (client, signers, proposal_id) = setup(&env);
+
+    let outcome = client.approve_and_execute(&proposal_id, &signers.get(0).unwrap());
+    assert!(!outcome.executed);
+    assert!(outcome.failing.contains(ExecutionCheck::NotExecutable));
+    assert_eq!(client.approvals_needed(&proposal_id), 1);
+}
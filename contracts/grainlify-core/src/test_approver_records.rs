@@ -0,0 +1,95 @@
+//! # Approver Record Tests
+//!
+//! - `get_approvers` reports each approval's ledger timestamp and sequence
+//! - Revoked approvals drop out; a re-approval gets a new time
+//! - Executed proposals keep their records after sweeping
+
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{testutils::Ledger as _, vec, Env};
+
+use crate::multisig::ApprovalRecord;
+use crate::testutils::{setup_multisig, upload_dummy_wasm};
+use crate::{GrainlifyContract, MultiSig};
+
+fn advance(env: &Env, seconds: u64, ledgers: u32) {
+    env.ledger().with_mut(|info| {
+        info.timestamp += seconds;
+        info.sequence_number += ledgers;
+    });
+}
+
+#[test]
+fn test_approvals_record_ledger_time_and_sequence() {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    let (a, b) = (signers.get(0).unwrap(), signers.get(1).unwrap());
+    let proposal_id = client.propose_upgrade(&a, &upload_dummy_wasm(&env, 1), &0);
+    advance(&env, 100, 20);
+    client.approve_upgrade(&proposal_id, &a);
+    advance(&env, 50, 10);
+    client.approve_upgrade(&proposal_id, &b);
+
+    let start = env.ledger().timestamp() - 150;
+    let seq = env.ledger().sequence() - 30;
+    assert_eq!(
+        client.get_approvers(&proposal_id),
+        vec![
+            &env,
+            ApprovalRecord { signer: a.clone(), at: start + 100, sequence: seq + 20 },
+            ApprovalRecord { signer: b.clone(), at: start + 150, sequence: seq + 30 },
+        ]
+    );
+    assert_eq!(client.get_approver_addresses(&proposal_id), vec![&env, a, b]);
+}
+
+#[test]
+fn test_revoke_drops_record_and_reapproval_restamps() {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    let a = signers.get(0).unwrap();
+    let proposal_id = client.propose_upgrade(&a, &upload_dummy_wasm(&env, 1), &1_000_000);
+
+    client.approve_upgrade(&proposal_id, &a);
+    client.revoke_approval(&proposal_id, &a);
+    assert!(client.get_approvers(&proposal_id).is_empty());
+
+    advance(&env, 70, 7);
+    client.approve_upgrade(&proposal_id, &a);
+    let records = client.get_approvers(&proposal_id);
+    assert_eq!(records.len(), 1);
+    assert_eq!(records.get(0).unwrap().at, env.ledger().timestamp());
+}
+
+#[test]
+fn test_executed_records_survive_sweep() {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    let (a, b) = (signers.get(0).unwrap(), signers.get(1).unwrap());
+    let proposal_id = client.propose_upgrade(&a, &upload_dummy_wasm(&env, 1), &0);
+    client.approve_upgrade(&proposal_id, &a);
+    advance(&env, 30, 3);
+    client.approve_upgrade(&proposal_id, &b);
+    let before = client.get_approvers(&proposal_id);
+
+    advance(&env, client.get_timelock_delay(), 1);
+    client.execute_upgrade_v2(&proposal_id);
+
+    // The dummy WASM is installed now, so drive the native contract.
+    let after = env.as_contract(&client.address, || {
+        GrainlifyContract::sweep_proposals(env.clone(), 10);
+        assert!(MultiSig::get_proposal_opt(&env, proposal_id).is_none());
+        GrainlifyContract::get_approvers(env.clone(), proposal_id)
+    });
+    assert_eq!(after, before);
+    assert_ne!(after.get(0).unwrap().at, after.get(1).unwrap().at);
+}
+
+#[test]
+fn test_unknown_proposal_rejected() {
+    let env = Env::default();
+    let (client, _) = setup_multisig(&env, 3, 2);
+    assert!(client.try_get_approvers(&5).is_err());
+}
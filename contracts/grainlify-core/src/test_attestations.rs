@@ -0,0 +1,158 @@
+//! # Audit Attestation Tests
+//!
+//! - Upgrades are blocked with `AttestationMissing` (109) when enforcement is
+//!   on and the target hash lacks K auditor attestations
+//! - Present attestations let `upgrade` and `execute_upgrade` through
+//! - Revoked attestations, and those of removed auditors, no longer count
+//! - Only configured auditors may attest
+//! - Multisig deployments configure attestations with an action bundle
+
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{testutils::Address as _, vec, Address, BytesN, Env};
+
+use crate::testutils::{setup_admin, setup_multisig, upload_dummy_wasm, GovernanceDriver};
+use crate::{AttestationConfig, GrainlifyContractClient, ProposalAction};
+
+fn setup(env: &Env, min_attestations: u32) -> (GrainlifyContractClient<'_>, [Address; 2]) {
+    let (client, _admin) = setup_admin(env);
+
+    let auditors = [Address::generate(env), Address::generate(env)];
+    client.set_attestation_config(&AttestationConfig {
+        require_attestation: true,
+        auditors: vec![env, auditors[0].clone(), auditors[1].clone()],
+        min_attestations,
+    });
+    (client, auditors)
+}
+
+fn digest(env: &Env, n: u8) -> BytesN<32> {
+    BytesN::from_array(env, &[n; 32])
+}
+
+#[test]
+#[should_panic(expected = "109")]
+fn test_upgrade_without_attestation_blocked() {
+    let env = Env::default();
+    let (client, _auditors) = setup(&env, 1);
+    client.upgrade(&upload_dummy_wasm(&env, 1));
+}
+
+#[test]
+fn test_upgrade_with_attestation_succeeds() {
+    let env = Env::default();
+    let (client, auditors) = setup(&env, 1);
+    let wasm = upload_dummy_wasm(&env, 1);
+
+    client.attest_wasm(&wasm, &auditors[0], &digest(&env, 7));
+    let recorded = client.get_attestations(&wasm);
+    assert_eq!(recorded.len(), 1);
+    assert_eq!(recorded.get(0).unwrap().report_digest, digest(&env, 7));
+
+    client.upgrade(&wasm);
+}
+
+#[test]
+#[should_panic(expected = "109")]
+fn test_fewer_than_k_attestations_blocked() {
+    let env = Env::default();
+    let (client, auditors) = setup(&env, 2);
+    let wasm = upload_dummy_wasm(&env, 1);
+    client.attest_wasm(&wasm, &auditors[0], &digest(&env, 1));
+    // Re-attesting by the same auditor does not count twice.
+    client.attest_wasm(&wasm, &auditors[0], &digest(&env, 2));
+    client.upgrade(&wasm);
+}
+
+#[test]
+#[should_panic(expected = "109")]
+fn test_revoked_attestation_blocks_upgrade() {
+    let env = Env::default();
+    let (client, auditors) = setup(&env, 1);
+    let wasm = upload_dummy_wasm(&env, 1);
+    client.attest_wasm(&wasm, &auditors[0], &digest(&env, 1));
+    client.revoke_attestation(&wasm, &auditors[0]);
+    assert_eq!(client.get_attestations(&wasm).len(), 0);
+    client.upgrade(&wasm);
+}
+
+#[test]
+#[should_panic(expected = "109")]
+fn test_removed_auditor_attestation_ignored() {
+    let env = Env::default();
+    let (client, auditors) = setup(&env, 1);
+    let wasm = upload_dummy_wasm(&env, 1);
+    client.attest_wasm(&wasm, &auditors[0], &digest(&env, 1));
+
+    client.set_attestation_config(&AttestationConfig {
+        require_attestation: true,
+        auditors: vec![&env, auditors[1].clone()],
+        min_attestations: 1,
+    });
+    client.upgrade(&wasm);
+}
+
+#[test]
+fn test_attestation_for_other_hash_does_not_count() {
+    let env = Env::default();
+    let (client, auditors) = setup(&env, 1);
+    let audited = upload_dummy_wasm(&env, 1);
+    let other = upload_dummy_wasm(&env, 2);
+    client.attest_wasm(&audited, &auditors[0], &digest(&env, 1));
+    assert!(client.try_upgrade(&other).is_err());
+}
+
+#[test]
+fn test_enforcement_off_allows_upgrade() {
+    let env = Env::default();
+    let (client, auditors) = setup(&env, 1);
+    client.set_attestation_config(&AttestationConfig {
+        require_attestation: false,
+        auditors: vec![&env, auditors[0].clone()],
+        min_attestations: 1,
+    });
+    client.upgrade(&upload_dummy_wasm(&env, 1));
+}
+
+#[test]
+#[should_panic(expected = "Not a configured auditor")]
+fn test_unknown_auditor_cannot_attest() {
+    let env = Env::default();
+    let (client, _auditors) = setup(&env, 1);
+    client.attest_wasm(&digest(&env, 9), &Address::generate(&env), &digest(&env, 1));
+}
+
+#[test]
+fn test_execute_upgrade_requires_attestation() {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 1, 1);
+    let auditors = [Address::generate(&env), Address::generate(&env)];
+    let config = AttestationConfig {
+        require_attestation: true,
+        auditors: vec![&env, auditors[0].clone(), auditors[1].clone()],
+        min_attestations: 1,
+    };
+    assert!(client.try_set_attestation_config(&config).is_err());
+    GovernanceDriver::apply_actions(&env, &client, &signers, vec![&env, ProposalAction::SetAttestationConfig(config)]);
+
+    let signer = signers.get(0).unwrap();
+    let wasm = upload_dummy_wasm(&env, 3);
+    client.attest_wasm(&wasm, &auditors[0], &digest(&env, 4));
+    let proposal_id = client.propose_upgrade(&signer, &wasm, &0u64);
+    client.approve_upgrade(&proposal_id, &signer);
+    GovernanceDriver::skip_timelock(&env, &client);
+    client.revoke_attestation(&wasm, &auditors[0]);
+    assert!(client.try_execute_upgrade(&proposal_id).is_err());
+
+    client.attest_wasm(&wasm, &auditors[1], &digest(&env, 4));
+    client.execute_upgrade(&proposal_id);
+}
+
+#[test]
+#[should_panic(expected = "Required attestations exceed auditor count")]
+fn test_config_rejects_k_above_auditor_count() {
+    let env = Env::default();
+    setup(&env, 3);
+}
@@ -0,0 +1,87 @@
+//! # Authority Overlap Tests
+//!
+//! - `migrate_to_multisig` rejects the admin among the new signers unless
+//!   `allow_admin_signer` is set
+//! - Migration deletes the admin and any admin-only pending upgrade state
+//! - `list_authorities` names every address with a governance role, once
+//!   per role, so overlaps show up
+
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{symbol_short, testutils::Address as _, vec, Address, Env, Symbol};
+
+use crate::testutils::{setup_admin, setup_multisig, upload_dummy_wasm};
+use crate::{AttestationConfig, Authority, DataKey};
+
+fn authority(address: &Address, role: Symbol) -> Authority {
+    Authority { address: address.clone(), role }
+}
+
+#[test]
+fn test_unflagged_migration_rejects_admin_signer() {
+    let env = Env::default();
+    let (client, admin) = setup_admin(&env);
+    let signers = vec![&env, admin.clone(), Address::generate(&env)];
+    assert!(client.try_migrate_to_multisig(&signers, &2, &false).is_err());
+    assert_eq!(client.list_authorities(), vec![&env, authority(&admin, symbol_short!("admin"))]);
+}
+
+#[test]
+fn test_flagged_migration_keeps_admin_as_signer_only() {
+    let env = Env::default();
+    let (client, admin) = setup_admin(&env);
+    let other = Address::generate(&env);
+    client.migrate_to_multisig(&vec![&env, admin.clone(), other.clone()], &2, &true);
+
+    env.as_contract(&client.address, || assert!(!env.storage().instance().has(&DataKey::Admin)));
+    assert_eq!(
+        client.list_authorities(),
+        vec![&env, authority(&admin, symbol_short!("signer")), authority(&other, symbol_short!("signer"))]
+    );
+}
+
+#[test]
+fn test_migration_clears_admin_upgrade_ticket() {
+    let env = Env::default();
+    let (client, _) = setup_admin(&env);
+    client.authorize_upgrade(&upload_dummy_wasm(&env, 1), &100, &200);
+    client.migrate_to_multisig(&vec![&env, Address::generate(&env), Address::generate(&env)], &2, &false);
+    assert_eq!(client.get_upgrade_ticket(), None);
+}
+
+#[test]
+fn test_authorities_show_overlaps() {
+    let env = Env::default();
+    let (client, admin) = setup_admin(&env);
+    let auditor = Address::generate(&env);
+    client.set_relayer(&admin);
+    client.set_attestation_config(&AttestationConfig {
+        require_attestation: false,
+        auditors: vec![&env, auditor.clone(), admin.clone()],
+        min_attestations: 1,
+    });
+
+    assert_eq!(
+        client.list_authorities(),
+        vec![
+            &env,
+            authority(&admin, symbol_short!("admin")),
+            authority(&admin, symbol_short!("relayer")),
+            authority(&auditor, symbol_short!("auditor")),
+            authority(&admin, symbol_short!("auditor")),
+        ]
+    );
+}
+
+#[test]
+fn test_multisig_authorities_in_signer_order() {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    let roles: std::vec::Vec<(Address, Symbol)> =
+        client.list_authorities().iter().map(|entry| (entry.address, entry.role)).collect();
+    let expected: std::vec::Vec<(Address, Symbol)> =
+        signers.iter().map(|signer| (signer, symbol_short!("signer"))).collect();
+    assert_eq!(roles, expected);
+}
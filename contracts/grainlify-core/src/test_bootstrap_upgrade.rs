@@ -0,0 +1,87 @@
+//! # Bootstrap Upgrade Tests
+//!
+//! - `init_with_pending_upgrade` sets up multisig governance, stores the
+//!   metadata, and opens proposal #1 without a proposer transaction
+//! - Proposal #1 follows the usual quorum, timelock and expiry rules and
+//!   executes end to end
+//! - The combined init cannot run twice
+
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger as _},
+    vec, Address, Env, String, Vec,
+};
+
+use crate::testutils::upload_dummy_wasm;
+use crate::{ContractMetadata, GrainlifyContract, GrainlifyContractClient, InitState};
+
+fn metadata(env: &Env) -> ContractMetadata {
+    ContractMetadata {
+        name: String::from_str(env, "grainlify"),
+        description: String::from_str(env, "bootstrap"),
+        url: String::from_str(env, "https://grainlify.io"),
+        contact: String::from_str(env, ""),
+    }
+}
+
+fn setup(env: &Env, expiry: u64) -> (GrainlifyContractClient<'_>, Vec<Address>, u64) {
+    env.mock_all_auths();
+    let client = GrainlifyContractClient::new(env, &env.register_contract(None, GrainlifyContract));
+    let signers = vec![env, Address::generate(env), Address::generate(env), Address::generate(env)];
+    let proposal_id = client.init_with_pending_upgrade(
+        &signers,
+        &2,
+        &upload_dummy_wasm(env, 1),
+        &metadata(env),
+        &expiry,
+    );
+    (client, signers, proposal_id)
+}
+
+#[test]
+fn test_bootstrap_proposal_executes_end_to_end() {
+    let env = Env::default();
+    let (client, signers, proposal_id) = setup(&env, 0);
+    assert_eq!(proposal_id, 1);
+    assert_eq!(client.get_init_state(), InitState::MultisigMode(3, 2));
+    assert_eq!(client.get_metadata(), metadata(&env));
+
+    let proposal = client.get_upgrade_proposal(&proposal_id).unwrap();
+    assert_eq!(proposal.wasm_hash, upload_dummy_wasm(&env, 1));
+    assert_eq!(proposal.proposer, None);
+
+    client.approve_upgrade(&proposal_id, &signers.get(1).unwrap());
+    client.approve_upgrade(&proposal_id, &signers.get(2).unwrap());
+    assert!(client.try_execute_upgrade(&proposal_id).is_err(), "timelock still running");
+
+    env.ledger().set_timestamp(client.get_timelock_delay());
+    let receipt = client.execute_upgrade_v2(&proposal_id);
+    assert_eq!(receipt.wasm_hash, upload_dummy_wasm(&env, 1));
+}
+
+#[test]
+fn test_bootstrap_proposal_expires() {
+    let env = Env::default();
+    let (client, signers, proposal_id) = setup(&env, 100);
+    env.ledger().set_timestamp(101);
+    assert!(client.try_approve_upgrade(&proposal_id, &signers.get(0).unwrap()).is_err());
+}
+
+#[test]
+fn test_bootstrap_proposal_cannot_be_amended() {
+    let env = Env::default();
+    let (client, signers, proposal_id) = setup(&env, 0);
+    let other = upload_dummy_wasm(&env, 2);
+    assert!(client.try_amend_proposal(&proposal_id, &other, &signers.get(0).unwrap()).is_err());
+}
+
+#[test]
+#[should_panic(expected = "Already initialized")]
+fn test_bootstrap_init_runs_once() {
+    let env = Env::default();
+    let (client, signers, _) = setup(&env, 0);
+    client.init_with_pending_upgrade(&signers, &2, &upload_dummy_wasm(&env, 2), &metadata(&env), &0);
+}
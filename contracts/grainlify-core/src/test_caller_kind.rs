@@ -0,0 +1,86 @@
+//! # Caller Kind Tests
+//!
+//! - `OperationMetric` reports `direct` for an account admin and `contract`
+//!   for a controller contract acting as admin
+//! - `get_caller_stats` splits the distinct callers by call kind
+
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{symbol_short, testutils::Events as _, Address, Env, IntoVal, String, Symbol, TryFromVal};
+
+use crate::monitoring::OperationMetric;
+use crate::{GrainlifyContract, GrainlifyContractClient};
+
+mod controller {
+    use soroban_sdk::{contract, contractimpl, Address, Env};
+
+    use crate::GrainlifyContractClient;
+
+    /// Forwards `set_version`, so the governed contract sees this contract as
+    /// the authorized caller.
+    #[contract]
+    pub struct Controller;
+
+    #[contractimpl]
+    impl Controller {
+        pub fn set_version(env: Env, target: Address, version: u32) {
+            GrainlifyContractClient::new(&env, &target).set_version(&version);
+        }
+    }
+}
+
+use controller::{Controller, ControllerClient};
+
+fn last_operation(env: &Env) -> OperationMetric {
+    let topics = (symbol_short!("metric"), symbol_short!("op")).into_val(env);
+    let (_, _, data) = env.events().all().iter().filter(|(_, t, _)| *t == topics).last().unwrap();
+    OperationMetric::try_from_val(env, &data).unwrap()
+}
+
+fn kind_count(client: &GrainlifyContractClient, kind: &Symbol) -> u64 {
+    client.get_caller_stats().by_call_kind.get(kind.clone()).unwrap_or(0)
+}
+
+#[test]
+fn test_account_admin_is_direct() {
+    let env = Env::default();
+    // `Address::generate` yields contract addresses, so name an account.
+    let admin = Address::from_string(&String::from_str(
+        &env,
+        "GAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAWHF",
+    ));
+    let client = GrainlifyContractClient::new(&env, &env.register_contract(None, GrainlifyContract));
+    env.mock_all_auths();
+    client.init_admin(&admin);
+    let direct = Symbol::new(&env, "direct");
+
+    client.set_version(&4);
+    client.set_version(&5);
+
+    let metric = last_operation(&env);
+    assert_eq!((metric.caller, metric.call_kind), (admin, direct.clone()));
+    assert_eq!(kind_count(&client, &direct), 1);
+    assert_eq!(kind_count(&client, &Symbol::new(&env, "contract")), 0);
+}
+
+#[test]
+fn test_contract_admin_is_contract() {
+    let env = Env::default();
+    let client = GrainlifyContractClient::new(&env, &env.register_contract(None, GrainlifyContract));
+    let controller = ControllerClient::new(&env, &env.register_contract(None, Controller));
+    env.mock_all_auths();
+    client.init_admin(&controller.address);
+    env.set_auths(&[]);
+
+    controller.set_version(&client.address, &4);
+    controller.set_version(&client.address, &5);
+
+    let metric = last_operation(&env);
+    let contract = Symbol::new(&env, "contract");
+    assert_eq!((metric.caller, metric.call_kind), (controller.address.clone(), contract.clone()));
+    assert_eq!(kind_count(&client, &contract), 1);
+    assert_eq!(kind_count(&client, &Symbol::new(&env, "direct")), 0);
+    assert_eq!(client.get_caller_stats().unique_callers, 1);
+}
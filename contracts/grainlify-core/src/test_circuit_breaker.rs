@@ -0,0 +1,300 @@
+//! # Circuit Breaker Tests
+//!
+//! Failures are driven through public entrypoints only: `attempt_*` calls
+//! the contract refuses, from the admin (`attempt_propose_upgrade` on an
+//! admin-only contract) or from signers (`attempt_execute_upgrade` stopped
+//! by the timelock). `set_version` supplies tracked successes.
+//! Covers:
+//! - Breaker disabled by default and when `enabled == false`
+//! - Consecutive-failure streak trips the breaker and pauses the contract
+//! - Windowed error rate trips the breaker once a window fills
+//! - A tracked success resets the streak
+//! - A tripped breaker blocks `execute_upgrade` with `Paused` until an
+//!   approved unpause proposal resumes it
+//! - Signer attempts count toward the breaker; attempts by other addresses
+//!   are recorded but cannot trip it
+//! - Failed subscriber callbacks are recorded but cannot trip it
+//! - Multisig deployments configure the breaker with an action bundle
+
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{
+    testutils::{Address as _, Events},
+    vec, Address, Env, Symbol, TryFromVal,
+};
+
+use crate::monitoring::{self, BreakerCounters, CircuitBreakerConfig, CircuitBreakerEvent, MetricsKey};
+use crate::multisig::MultiSig;
+use crate::testutils::{setup_admin, setup_multisig, upload_dummy_wasm, GovernanceDriver};
+use crate::{ContractError, ExecutionCheck, GrainlifyContractClient, ProposalAction};
+
+mod failing_subscriber {
+    use soroban_sdk::{contract, contractimpl, Address, Env};
+
+    #[contract]
+    pub struct FailingSubscriber;
+
+    #[contractimpl]
+    impl FailingSubscriber {
+        pub fn on_dependency_upgraded(_env: Env, _upgraded: Address, _new_version: u32) {
+            panic!("subscriber failure");
+        }
+    }
+}
+
+use failing_subscriber::FailingSubscriber;
+
+/// `failures` admin proposals the admin-only contract refuses.
+fn fail(env: &Env, client: &GrainlifyContractClient, admin: &Address, failures: u32) {
+    let wasm = upload_dummy_wasm(env, 1);
+    for _ in 0..failures {
+        let attempt = client.attempt_propose_upgrade(admin, &wasm, &0u64);
+        assert_eq!(attempt.refused, Some(ContractError::WrongGovernanceMode as u32));
+    }
+}
+
+fn config(max_consecutive_failures: u32, max_error_rate_bps: u32, window_size: u32) -> CircuitBreakerConfig {
+    CircuitBreakerConfig {
+        enabled: true,
+        max_consecutive_failures,
+        max_error_rate_bps,
+        window_size,
+    }
+}
+
+/// Pause flag of `client`, read from storage so it works after an upgrade.
+fn paused(env: &Env, client: &GrainlifyContractClient) -> bool {
+    env.as_contract(&client.address, || MultiSig::is_contract_paused(env))
+}
+
+fn breaker_counters(env: &Env, client: &GrainlifyContractClient) -> BreakerCounters {
+    env.as_contract(&client.address, || {
+        env.storage()
+            .persistent()
+            .get(&MetricsKey::BreakerWindow)
+            .unwrap_or_default()
+    })
+}
+
+fn tripped_event(env: &Env) -> Option<CircuitBreakerEvent> {
+    let mut found = None;
+    for (_, topics, data) in env.events().all().iter() {
+        if topics.len() < 2 {
+            continue;
+        }
+        let t1 = Symbol::try_from_val(env, &topics.get(1).unwrap());
+        if t1 == Ok(Symbol::new(env, "tripped")) {
+            found = CircuitBreakerEvent::try_from_val(env, &data).ok();
+        }
+    }
+    found
+}
+
+#[test]
+fn test_breaker_disabled_by_default() {
+    let env = Env::default();
+    let (client, admin) = setup_admin(&env);
+    assert_eq!(client.get_circuit_breaker_config(), None);
+
+    fail(&env, &client, &admin, 3);
+    assert_eq!(client.get_failure_breakdown().total_errors, 3);
+    assert!(!client.is_paused());
+}
+
+#[test]
+fn test_disabled_config_never_trips() {
+    let env = Env::default();
+    let (client, admin) = setup_admin(&env);
+    let mut cfg = config(2, 0, 0);
+    cfg.enabled = false;
+    client.set_circuit_breaker_config(&cfg);
+
+    fail(&env, &client, &admin, 3);
+    assert!(!client.is_paused());
+}
+
+#[test]
+fn test_failure_streak_trips_breaker() {
+    let env = Env::default();
+    let (client, admin) = setup_admin(&env);
+    client.set_circuit_breaker_config(&config(3, 0, 0));
+
+    fail(&env, &client, &admin, 2);
+    assert!(!client.is_paused());
+    fail(&env, &client, &admin, 1);
+    assert!(client.is_paused());
+
+    let event = tripped_event(&env).expect("tripped event");
+    assert_eq!(event.reason, Symbol::new(&env, "streak"));
+    assert_eq!(event.operation, Symbol::new(&env, "prop_upg"));
+    assert_eq!(event.failure_streak, 3);
+}
+
+#[test]
+fn test_success_resets_streak() {
+    let env = Env::default();
+    let (client, admin) = setup_admin(&env);
+    client.set_circuit_breaker_config(&config(3, 0, 0));
+
+    fail(&env, &client, &admin, 2);
+    client.set_version(&5);
+    assert_eq!(breaker_counters(&env, &client).failure_streak, 0);
+    fail(&env, &client, &admin, 2);
+    assert!(!client.is_paused());
+}
+
+#[test]
+fn test_error_rate_trips_when_window_fills() {
+    let env = Env::default();
+    let (client, admin) = setup_admin(&env);
+    // 50% limit over a window of 4 operations.
+    client.set_circuit_breaker_config(&config(0, 5_000, 4));
+
+    // Three failures leave the window one short.
+    fail(&env, &client, &admin, 3);
+    assert!(!client.is_paused());
+    client.set_version(&5);
+    assert!(client.is_paused());
+    let event = tripped_event(&env).expect("tripped event");
+    assert_eq!(event.reason, Symbol::new(&env, "rate"));
+    assert_eq!(event.operation, Symbol::new(&env, "set_ver"));
+    assert_eq!(event.window_operations, 4);
+    assert_eq!(event.window_errors, 3);
+}
+
+#[test]
+fn test_error_rate_at_limit_does_not_trip() {
+    let env = Env::default();
+    let (client, admin) = setup_admin(&env);
+    client.set_circuit_breaker_config(&config(0, 5_000, 4));
+
+    client.set_version(&5);
+    fail(&env, &client, &admin, 2);
+    client.set_version(&6);
+    assert!(!client.is_paused());
+    assert_eq!(tripped_event(&env), None);
+}
+
+#[test]
+fn test_subscriber_failures_cannot_trip_breaker() {
+    let env = Env::default();
+    let (client, _admin) = setup_admin(&env);
+    for _ in 0..3 {
+        client.register_subscriber(&env.register_contract(None, FailingSubscriber));
+    }
+    client.set_circuit_breaker_config(&config(1, 0, 0));
+
+    client.upgrade(&upload_dummy_wasm(&env, 1));
+    let errors = env.as_contract(&client.address, || monitoring::get_failure_breakdown(&env).total_errors);
+    assert_eq!(errors, 3);
+    assert!(!paused(&env, &client));
+    assert_eq!(breaker_counters(&env, &client).failure_streak, 0);
+    assert_eq!(tripped_event(&env), None);
+}
+
+#[test]
+fn test_signer_attempts_trip_breaker() {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    let actions = vec![&env, ProposalAction::SetCircuitBreakerConfig(config(3, 0, 0))];
+    GovernanceDriver::apply_actions(&env, &client, &signers, actions);
+
+    let wasm = upload_dummy_wasm(&env, 1);
+    let proposal_id = GovernanceDriver::propose(&env, &client, &signers, &wasm);
+    GovernanceDriver::approve_to_threshold(&env, &client, &signers, proposal_id);
+
+    let signer = signers.get(2).unwrap();
+    for _ in 0..3 {
+        assert_eq!(
+            client.attempt_execute_upgrade(&signer, &proposal_id),
+            Some(ExecutionCheck::TimelockPending)
+        );
+    }
+    assert!(client.is_paused());
+    assert_eq!(tripped_event(&env).expect("tripped event").reason, Symbol::new(&env, "streak"));
+
+    GovernanceDriver::skip_timelock(&env, &client);
+    assert_eq!(client.attempt_execute_upgrade(&signer, &proposal_id), Some(ExecutionCheck::Paused));
+    assert!(client.try_execute_upgrade(&proposal_id).is_err());
+
+    let unpause_id = client.propose_unpause(&signers.get(0).unwrap(), &0);
+    client.approve_unpause(&unpause_id, &signers.get(0).unwrap());
+    client.approve_unpause(&unpause_id, &signers.get(1).unwrap());
+    assert!(!client.is_paused());
+    client.execute_upgrade(&proposal_id);
+}
+
+#[test]
+#[should_panic(expected = "10")]
+fn test_tripped_breaker_blocks_execute_upgrade() {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    let actions = vec![&env, ProposalAction::SetCircuitBreakerConfig(config(2, 0, 0))];
+    GovernanceDriver::apply_actions(&env, &client, &signers, actions);
+
+    let proposal_id = GovernanceDriver::propose(&env, &client, &signers, &upload_dummy_wasm(&env, 1));
+    GovernanceDriver::approve_to_threshold(&env, &client, &signers, proposal_id);
+    client.attempt_execute_upgrade(&signers.get(0).unwrap(), &proposal_id);
+    client.attempt_execute_upgrade(&signers.get(1).unwrap(), &proposal_id);
+    assert!(client.is_paused());
+
+    GovernanceDriver::skip_timelock(&env, &client);
+    client.execute_upgrade(&proposal_id);
+}
+
+#[test]
+fn test_outsider_attempts_cannot_trip_breaker() {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    let actions = vec![&env, ProposalAction::SetCircuitBreakerConfig(config(2, 0, 0))];
+    GovernanceDriver::apply_actions(&env, &client, &signers, actions);
+
+    let wasm = upload_dummy_wasm(&env, 1);
+    let proposal_id = GovernanceDriver::propose(&env, &client, &signers, &wasm);
+    let outsider = Address::generate(&env);
+    let errors_before = client.get_failure_breakdown().total_errors;
+    for _ in 0..5 {
+        assert_eq!(
+            client.attempt_execute_upgrade(&outsider, &proposal_id),
+            Some(ExecutionCheck::TimelockNotStarted)
+        );
+    }
+    assert_eq!(client.get_failure_breakdown().total_errors, errors_before + 5);
+    assert!(!client.is_paused());
+    assert_eq!(tripped_event(&env), None);
+
+    // The outsider's failures did not start a streak for the signers.
+    let signer = signers.get(0).unwrap();
+    client.attempt_execute_upgrade(&signer, &proposal_id);
+    assert!(!client.is_paused());
+    client.attempt_execute_upgrade(&signer, &proposal_id);
+    assert!(client.is_paused());
+}
+
+#[test]
+#[should_panic(expected = "Circuit breaker error rate cannot exceed 10000 bps")]
+fn test_config_rejects_rate_above_100_percent() {
+    let env = Env::default();
+    let (client, _admin) = setup_admin(&env);
+    client.set_circuit_breaker_config(&config(0, 10_001, 4));
+}
+
+#[test]
+#[should_panic(expected = "Circuit breaker window size must be non-zero")]
+fn test_config_rejects_zero_window_with_rate_check() {
+    let env = Env::default();
+    let (client, _admin) = setup_admin(&env);
+    client.set_circuit_breaker_config(&config(0, 5_000, 0));
+}
+
+#[test]
+fn test_multisig_configures_breaker_through_actions() {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    assert!(client.try_set_circuit_breaker_config(&config(2, 0, 0)).is_err());
+    let actions = vec![&env, ProposalAction::SetCircuitBreakerConfig(config(2, 0, 0))];
+    GovernanceDriver::apply_actions(&env, &client, &signers, actions);
+    assert_eq!(client.get_circuit_breaker_config(), Some(config(2, 0, 0)));
+}
@@ -0,0 +1,126 @@
+//! # Commit-Reveal Upgrade Tests
+//!
+//! - `propose_upgrade_commit` stores only the commitment; no hash is readable
+//!   until `reveal_upgrade`
+//! - A reveal with the wrong salt or hash fails with `RevealMismatch` and
+//!   leaves the commitment in place
+//! - Only the proposer can reveal, and not after the proposal expired or
+//!   was cancelled
+//! - `BeforeApproval` (default): approvals are rejected until the reveal
+//! - `BeforeExecution`: approvals and the timelock run on the commitment,
+//!   execution waits for the reveal
+
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{testutils::Ledger as _, Bytes, BytesN, Env};
+
+use crate::testutils::{setup_admin_and_multisig, setup_multisig, upload_dummy_wasm};
+use crate::{GrainlifyContractClient, RevealDeadline};
+
+fn commitment(env: &Env, wasm_hash: &BytesN<32>, salt: &Bytes) -> BytesN<32> {
+    let mut data = Bytes::from(wasm_hash.clone());
+    data.append(salt);
+    env.crypto().sha256(&data).into()
+}
+
+fn salt(env: &Env) -> Bytes {
+    Bytes::from_array(env, &[7; 16])
+}
+
+fn stored_hash(env: &Env, client: &GrainlifyContractClient, proposal_id: u64) -> Option<BytesN<32>> {
+    env.as_contract(&client.address, || grainlify_upgradeable::get_upgrade_hash(env, proposal_id))
+}
+
+#[test]
+fn test_reveal_unlocks_approvals_and_execution() {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    let proposer = signers.get(0).unwrap();
+    let wasm_hash = upload_dummy_wasm(&env, 1);
+
+    let proposal_id =
+        client.propose_upgrade_commit(&proposer, &commitment(&env, &wasm_hash, &salt(&env)), &0);
+    assert_eq!(stored_hash(&env, &client, proposal_id), None);
+    assert!(client.try_approve_upgrade(&proposal_id, &proposer).is_err());
+
+    client.reveal_upgrade(&proposal_id, &wasm_hash, &salt(&env));
+    assert_eq!(stored_hash(&env, &client, proposal_id), Some(wasm_hash.clone()));
+    assert_eq!(client.get_upgrade_commitment(&proposal_id), None);
+
+    client.approve_upgrade(&proposal_id, &proposer);
+    client.approve_upgrade(&proposal_id, &signers.get(1).unwrap());
+    env.ledger().set_timestamp(client.get_timelock_delay());
+    assert_eq!(client.execute_upgrade_v2(&proposal_id).wasm_hash, wasm_hash);
+}
+
+#[test]
+fn test_mismatched_reveal_is_rejected() {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    let proposer = signers.get(0).unwrap();
+    let wasm_hash = upload_dummy_wasm(&env, 1);
+    let proposal_id =
+        client.propose_upgrade_commit(&proposer, &commitment(&env, &wasm_hash, &salt(&env)), &0);
+
+    let wrong_salt = Bytes::from_array(&env, &[8; 16]);
+    assert!(client.try_reveal_upgrade(&proposal_id, &wasm_hash, &wrong_salt).is_err());
+    let other_hash = upload_dummy_wasm(&env, 2);
+    assert!(client.try_reveal_upgrade(&proposal_id, &other_hash, &salt(&env)).is_err());
+
+    assert!(client.get_upgrade_commitment(&proposal_id).is_some());
+    assert_eq!(stored_hash(&env, &client, proposal_id), None);
+    assert!(client.try_approve_upgrade(&proposal_id, &proposer).is_err());
+}
+
+#[test]
+fn test_reveal_requires_proposer_auth() {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    let wasm_hash = upload_dummy_wasm(&env, 1);
+    let proposal_id = client.propose_upgrade_commit(
+        &signers.get(0).unwrap(),
+        &commitment(&env, &wasm_hash, &salt(&env)),
+        &0,
+    );
+
+    env.set_auths(&[]);
+    assert!(client.try_reveal_upgrade(&proposal_id, &wasm_hash, &salt(&env)).is_err());
+}
+
+#[test]
+fn test_reveal_rejected_after_expiry_or_cancel() {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    let proposer = signers.get(0).unwrap();
+    let wasm_hash = upload_dummy_wasm(&env, 1);
+    let commit = commitment(&env, &wasm_hash, &salt(&env));
+
+    let expiring = client.propose_upgrade_commit(&proposer, &commit, &100);
+    env.ledger().set_timestamp(101);
+    assert!(client.try_reveal_upgrade(&expiring, &wasm_hash, &salt(&env)).is_err());
+
+    let cancelled = client.propose_upgrade_commit(&proposer, &commit, &0);
+    client.cancel_upgrade(&cancelled, &proposer);
+    assert!(client.try_reveal_upgrade(&cancelled, &wasm_hash, &salt(&env)).is_err());
+}
+
+#[test]
+fn test_before_execution_mode_defers_reveal_to_execution() {
+    let env = Env::default();
+    let (client, signers) = setup_admin_and_multisig(&env);
+    client.set_reveal_deadline(&RevealDeadline::BeforeExecution);
+    let proposer = signers.get(0).unwrap();
+    let wasm_hash = upload_dummy_wasm(&env, 1);
+
+    let proposal_id =
+        client.propose_upgrade_commit(&proposer, &commitment(&env, &wasm_hash, &salt(&env)), &0);
+    client.approve_upgrade(&proposal_id, &proposer);
+    client.approve_upgrade(&proposal_id, &signers.get(1).unwrap());
+    env.ledger().set_timestamp(client.get_timelock_delay());
+    assert!(client.try_execute_upgrade(&proposal_id).is_err());
+
+    client.reveal_upgrade(&proposal_id, &wasm_hash, &salt(&env));
+    assert_eq!(client.execute_upgrade_v2(&proposal_id).wasm_hash, wasm_hash);
+}
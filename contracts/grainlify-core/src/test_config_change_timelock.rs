@@ -2,23 +2,14 @@
 
 extern crate std;
 
-use soroban_sdk::{testutils::{Address as _, Ledger as _}, Address, Env};
+use soroban_sdk::{testutils::Ledger as _, Env};
 
-use crate::{GrainlifyContract, GrainlifyContractClient};
-
-fn setup(env: &Env) -> (GrainlifyContractClient, Address) {
-    let contract_id = env.register_contract(None, GrainlifyContract);
-    let client = GrainlifyContractClient::new(env, &contract_id);
-    let admin = Address::generate(env);
-    client.init_admin(&admin);
-    (client, admin)
-}
+use crate::testutils::setup_admin;
 
 #[test]
 fn test_config_change_delay_default() {
     let env = Env::default();
-    env.mock_all_auths();
-    let (client, _admin) = setup(&env);
+    let (client, _admin) = setup_admin(&env);
 
     assert_eq!(client.get_config_change_delay(), 21_600);
 }
@@ -26,8 +17,7 @@ fn test_config_change_delay_default() {
 #[test]
 fn test_propose_and_execute_restore_after_timelock() {
     let env = Env::default();
-    env.mock_all_auths();
-    let (client, _admin) = setup(&env);
+    let (client, _admin) = setup_admin(&env);
 
     let snapshot_id = client.create_config_snapshot();
     client.set_version(&5);
@@ -52,8 +42,7 @@ fn test_propose_and_execute_restore_after_timelock() {
 #[test]
 fn test_cancelled_config_change_cannot_execute() {
     let env = Env::default();
-    env.mock_all_auths();
-    let (client, _admin) = setup(&env);
+    let (client, _admin) = setup_admin(&env);
 
     let snapshot_id = client.create_config_snapshot();
     client.set_config_change_delay(&3_600);
@@ -70,8 +59,7 @@ fn test_cancelled_config_change_cannot_execute() {
 #[should_panic(expected = "Config change delay must be at least 1 hour")]
 fn test_set_config_change_delay_rejects_too_small() {
     let env = Env::default();
-    env.mock_all_auths();
-    let (client, _admin) = setup(&env);
+    let (client, _admin) = setup_admin(&env);
 
     client.set_config_change_delay(&1_800);
 }
@@ -80,8 +68,7 @@ fn test_set_config_change_delay_rejects_too_small() {
 #[should_panic(expected = "Snapshot not found or has been pruned")]
 fn test_propose_config_restore_requires_existing_snapshot() {
     let env = Env::default();
-    env.mock_all_auths();
-    let (client, _admin) = setup(&env);
+    let (client, _admin) = setup_admin(&env);
 
     client.propose_config_snapshot_restore(&999);
 }
@@ -89,8 +76,7 @@ fn test_propose_config_restore_requires_existing_snapshot() {
 #[test]
 fn test_config_change_status_counts_down() {
     let env = Env::default();
-    env.mock_all_auths();
-    let (client, _admin) = setup(&env);
+    let (client, _admin) = setup_admin(&env);
 
     let snapshot_id = client.create_config_snapshot();
     client.set_config_change_delay(&3_600);
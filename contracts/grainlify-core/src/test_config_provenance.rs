@@ -0,0 +1,85 @@
+//! # Config Provenance Tests
+//!
+//! - A direct admin setter records `ConfigSource::Admin` and the time
+//! - `execute_proposal` records the proposal ID for every field its
+//!   actions change, including fields inside an `UpdateConfig` bundle
+//! - Fields never changed have no provenance
+
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{testutils::Ledger as _, vec, Address, Env, Symbol, Vec};
+
+use crate::testutils::{setup_admin, setup_admin_and_multisig, setup_multisig};
+use crate::{
+    ConfigProvenance, ConfigSource, ConfigUpdate, GrainlifyContractClient, ProposalAction,
+};
+
+fn key(env: &Env, field: &str) -> Symbol {
+    Symbol::new(env, field)
+}
+
+/// Approves and executes an action bundle on a 2-of-3 multisig.
+fn execute_actions(
+    env: &Env,
+    client: &GrainlifyContractClient,
+    signers: &Vec<Address>,
+    actions: Vec<ProposalAction>,
+) -> u64 {
+    let proposal_id = client.propose_actions(&signers.get(0).unwrap(), &actions, &0);
+    client.approve_actions(&proposal_id, &signers.get(0).unwrap());
+    client.approve_actions(&proposal_id, &signers.get(1).unwrap());
+    env.ledger().set_timestamp(env.ledger().timestamp() + client.get_timelock_delay());
+    client.execute_proposal(&proposal_id);
+    proposal_id
+}
+
+#[test]
+fn test_admin_setter_records_admin() {
+    let env = Env::default();
+    let (client, _admin) = setup_admin(&env);
+    assert_eq!(client.get_config_provenance(&key(&env, "timelock_delay")), None);
+
+    env.ledger().set_timestamp(500);
+    client.set_timelock_delay(&7_200);
+    client.set_stale_proposal_age(&60);
+    let expected = Some(ConfigProvenance { source: ConfigSource::Admin, changed_at: 500 });
+    assert_eq!(client.get_config_provenance(&key(&env, "timelock_delay")), expected);
+    assert_eq!(client.get_config_provenance(&key(&env, "stale_proposal_age")), expected);
+    assert_eq!(client.get_config_provenance(&key(&env, "require_nonce")), None);
+}
+
+#[test]
+fn test_proposal_records_its_id() {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    let update = ConfigUpdate { threshold: Some(3), max_postponement: Some(600), ..Default::default() };
+    let actions = vec![&env, ProposalAction::SetTimelockDelay(7_200), ProposalAction::UpdateConfig(update)];
+    let proposal_id = execute_actions(&env, &client, &signers, actions);
+
+    let expected = Some(ConfigProvenance {
+        source: ConfigSource::Proposal(proposal_id),
+        changed_at: env.ledger().timestamp(),
+    });
+    for field in ["timelock_delay", "threshold", "max_postponement"] {
+        assert_eq!(client.get_config_provenance(&key(&env, field)), expected, "{field}");
+    }
+    assert_eq!(client.get_config_provenance(&key(&env, "config_change_delay")), None);
+}
+
+#[test]
+fn test_provenance_follows_latest_change() {
+    let env = Env::default();
+    let (client, signers) = setup_admin_and_multisig(&env);
+
+    client.set_timelock_delay(&7_200);
+    let field = key(&env, "timelock_delay");
+    assert_eq!(client.get_config_provenance(&field).unwrap().source, ConfigSource::Admin);
+
+    let proposal_id = execute_actions(&env, &client, &signers, vec![&env, ProposalAction::SetTimelockDelay(3_600)]);
+    assert_eq!(client.get_config_provenance(&field).unwrap().source, ConfigSource::Proposal(proposal_id));
+
+    client.set_timelock_delay(&10_800);
+    assert_eq!(client.get_config_provenance(&field).unwrap().source, ConfigSource::Admin);
+}
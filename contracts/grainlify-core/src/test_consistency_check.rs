@@ -0,0 +1,175 @@
+//! # Consistency Check Tests
+//!
+//! - A contract that executed an upgrade through every record passes
+//! - Corrupting one relationship is reported under its own name, with an
+//!   `("invariant", "violated")` event
+//! - Version changes are appended to `get_version_history`
+//! - The archive check pages through proposal IDs from a cursor, so a
+//!   long proposal history stays within the default budget
+
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{
+    contracttype, symbol_short, testutils::Events as _, testutils::Ledger as _, vec, Env, Symbol,
+    TryFromVal, Vec,
+};
+
+use crate::monitoring::{MetricsKey, StateSnapshot};
+use crate::testutils::{setup_admin, setup_multisig, upload_dummy_wasm};
+use crate::{
+    ArchiveKey, DataKey, GrainlifyContract, GrainlifyContractClient, HealthKey, MultiSig, ProposalAction,
+};
+
+/// 2-of-3 multisig contract that executed one upgrade proposal.
+fn setup_executed(env: &Env) -> (GrainlifyContractClient<'_>, u64) {
+    let (client, signers) = setup_multisig(env, 3, 2);
+    let proposal_id = client.propose_upgrade(&signers.get(0).unwrap(), &upload_dummy_wasm(env, 1), &0);
+    client.approve_upgrade(&proposal_id, &signers.get(0).unwrap());
+    client.approve_upgrade(&proposal_id, &signers.get(1).unwrap());
+    env.ledger().set_timestamp(client.get_timelock_delay());
+    client.execute_upgrade_v2(&proposal_id);
+    (client, proposal_id)
+}
+
+/// Same encoding as the multisig module's private config key, so the
+/// config can be written without `MultiSig::set_config`'s validation.
+#[contracttype]
+enum MultiSigStorage {
+    Config,
+}
+
+/// Test-only hook: writes straight to the contract's storage.
+fn corrupt(env: &Env, client: &GrainlifyContractClient, f: impl FnOnce()) {
+    env.as_contract(&client.address, f);
+}
+
+fn check(env: &Env, client: &GrainlifyContractClient) -> Vec<Symbol> {
+    env.as_contract(&client.address, || GrainlifyContract::check_consistency(env.clone()))
+}
+
+fn violation_events(env: &Env) -> u32 {
+    let topic = symbol_short!("invariant");
+    env.events()
+        .all()
+        .iter()
+        .filter(|(_, topics, _)| {
+            topics
+                .get(0)
+                .and_then(|t| Symbol::try_from_val(env, &t).ok())
+                .is_some_and(|t| t == topic)
+        })
+        .count() as u32
+}
+
+#[test]
+fn test_consistent_contract_passes() {
+    let env = Env::default();
+    let (client, _) = setup_executed(&env);
+    assert!(check(&env, &client).is_empty());
+    assert_eq!(violation_events(&env), 0);
+}
+
+#[test]
+fn test_version_mismatch_is_detected() {
+    let env = Env::default();
+    let (client, _) = setup_admin(&env);
+    client.set_version(&3);
+    corrupt(&env, &client, || env.storage().instance().set(&DataKey::Version, &9u32));
+
+    assert_eq!(check(&env, &client), vec![&env, symbol_short!("version")]);
+    assert_eq!(violation_events(&env), 1);
+}
+
+#[test]
+fn test_missing_archive_entry_is_detected() {
+    let env = Env::default();
+    let (client, proposal_id) = setup_executed(&env);
+    corrupt(&env, &client, || {
+        env.storage().persistent().remove(&ArchiveKey::ArchiveIndex(proposal_id))
+    });
+    assert_eq!(check(&env, &client), vec![&env, symbol_short!("archive")]);
+}
+
+#[test]
+fn test_upgrade_count_below_archive_is_detected() {
+    let env = Env::default();
+    let (client, _) = setup_executed(&env);
+    corrupt(&env, &client, || env.storage().persistent().set(&ArchiveKey::UpgradeCount, &0u64));
+    assert_eq!(check(&env, &client), vec![&env, symbol_short!("upg_count")]);
+}
+
+#[test]
+fn test_executed_pending_proposal_is_detected() {
+    let env = Env::default();
+    let (client, proposal_id) = setup_executed(&env);
+    corrupt(&env, &client, || {
+        env.storage().persistent().set(&HealthKey::PendingUpgrades, &vec![&env, proposal_id])
+    });
+    assert_eq!(check(&env, &client), vec![&env, symbol_short!("pending")]);
+}
+
+#[test]
+fn test_threshold_above_signer_count_is_detected() {
+    let env = Env::default();
+    let (client, _) = setup_executed(&env);
+    corrupt(&env, &client, || {
+        let mut config = MultiSig::get_config_opt(&env).unwrap();
+        config.threshold = 4;
+        env.storage().instance().set(&MultiSigStorage::Config, &config);
+    });
+    assert_eq!(check(&env, &client), vec![&env, symbol_short!("threshold")]);
+}
+
+#[test]
+fn test_metrics_below_snapshot_are_detected() {
+    let env = Env::default();
+    let (client, _) = setup_admin(&env);
+    client.set_version(&3);
+    corrupt(&env, &client, || {
+        let snapshot = StateSnapshot { timestamp: 0, total_operations: 1_000, total_users: 0, total_errors: 0 };
+        env.storage().persistent().set(&MetricsKey::RecordedSnapshot, &snapshot);
+    });
+    assert_eq!(check(&env, &client), vec![&env, symbol_short!("metrics")]);
+}
+
+#[test]
+fn test_version_changes_are_recorded() {
+    let env = Env::default();
+    let (client, _) = setup_admin(&env);
+    let initial = client.get_version();
+    env.ledger().set_timestamp(50);
+    client.set_version(&initial.saturating_add(1));
+
+    let history = client.get_version_history(&0, &10).items;
+    assert_eq!(history.len(), 2);
+    assert_eq!(history.get(0).unwrap().version, initial);
+    assert_eq!(history.get(1).unwrap().version, initial + 1);
+    assert_eq!(history.get(1).unwrap().changed_at, 50);
+}
+
+#[test]
+fn test_archive_check_pages_through_proposals() {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    let proposer = signers.get(0).unwrap();
+    env.budget().reset_unlimited();
+    for version in 0..55 {
+        client.propose_actions(&proposer, &vec![&env, ProposalAction::SetVersion(version)], &0);
+    }
+    let proposal_id = client.propose_upgrade(&proposer, &upload_dummy_wasm(&env, 1), &0);
+    client.approve_upgrade(&proposal_id, &signers.get(0).unwrap());
+    client.approve_upgrade(&proposal_id, &signers.get(1).unwrap());
+    env.ledger().set_timestamp(client.get_timelock_delay());
+    client.execute_upgrade_v2(&proposal_id);
+    corrupt(&env, &client, || {
+        env.storage().persistent().remove(&ArchiveKey::ArchiveIndex(proposal_id))
+    });
+
+    env.budget().reset_default();
+    assert!(check(&env, &client).is_empty(), "IDs 1 to 50 are consistent");
+    let cursor = env.as_contract(&client.address, || GrainlifyContract::get_consistency_cursor(env.clone()));
+    assert_eq!(cursor, 51);
+    assert_eq!(check(&env, &client), vec![&env, symbol_short!("archive")]);
+}
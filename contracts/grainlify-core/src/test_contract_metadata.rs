@@ -0,0 +1,116 @@
+//! # Contract Metadata Tests
+//!
+//! - `set_metadata` stores all four fields, emits `("meta", "updated")`, and
+//!   the name appears in `get_contract_info`
+//! - `set_metadata_field` updates one field and leaves the rest
+//! - Values over their limit fail with `MetadataTooLong`; unknown field
+//!   keys fail with `UnknownMetadataField`
+//! - Only governance can change metadata
+
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{testutils::Events as _, Env, String, Symbol, TryFromVal};
+
+use crate::testutils::setup_admin;
+use crate::{MetadataUpdatedEvent, MAX_METADATA_NAME_LEN, MAX_METADATA_URL_LEN};
+
+fn s(env: &Env, value: &str) -> String {
+    String::from_str(env, value)
+}
+
+fn repeated(env: &Env, len: u32) -> String {
+    String::from_str(env, &"x".repeat(len as usize))
+}
+
+fn last_metadata_event(env: &Env) -> Option<MetadataUpdatedEvent> {
+    let mut found = None;
+    for (_, topics, data) in env.events().all().iter() {
+        if topics.len() != 2 {
+            continue;
+        }
+        let t0 = Symbol::try_from_val(env, &topics.get(0).unwrap());
+        if t0 == Ok(Symbol::new(env, "meta")) {
+            found = MetadataUpdatedEvent::try_from_val(env, &data).ok();
+        }
+    }
+    found
+}
+
+#[test]
+fn test_set_metadata_and_contract_info() {
+    let env = Env::default();
+    let (client, _admin) = setup_admin(&env);
+    assert_eq!(client.get_metadata().name, s(&env, ""));
+
+    client.set_metadata(
+        &s(&env, "grainlify-core"),
+        &s(&env, "Upgrade governance for Grainlify"),
+        &s(&env, "https://grainlify.example"),
+        &s(&env, "ops@grainlify.example"),
+    );
+
+    let metadata = client.get_metadata();
+    assert_eq!(metadata.name, s(&env, "grainlify-core"));
+    assert_eq!(metadata.contact, s(&env, "ops@grainlify.example"));
+    assert_eq!(last_metadata_event(&env).unwrap().metadata, metadata);
+
+    let info = client.get_contract_info();
+    assert_eq!(info.name, s(&env, "grainlify-core"));
+    assert_eq!(info.version, client.get_version());
+}
+
+#[test]
+fn test_set_metadata_field_updates_one_field() {
+    let env = Env::default();
+    let (client, _admin) = setup_admin(&env);
+    client.set_metadata(&s(&env, "core"), &s(&env, "desc"), &s(&env, "https://a"), &s(&env, "a@b"));
+
+    client.set_metadata_field(&Symbol::new(&env, "url"), &s(&env, "https://b"));
+    let metadata = client.get_metadata();
+    assert_eq!(metadata.url, s(&env, "https://b"));
+    assert_eq!(metadata.name, s(&env, "core"));
+    assert_eq!(metadata.description, s(&env, "desc"));
+    assert_eq!(last_metadata_event(&env).unwrap().metadata.url, s(&env, "https://b"));
+}
+
+#[test]
+fn test_limits_enforced() {
+    let env = Env::default();
+    let (client, _admin) = setup_admin(&env);
+    let empty = s(&env, "");
+
+    client.set_metadata(&repeated(&env, MAX_METADATA_NAME_LEN), &empty, &empty, &empty);
+    let url = Symbol::new(&env, "url");
+    assert!(client.try_set_metadata_field(&url, &repeated(&env, MAX_METADATA_URL_LEN + 1)).is_err());
+    assert_eq!(client.get_metadata().url, empty);
+}
+
+#[test]
+#[should_panic(expected = "119")]
+fn test_name_over_limit_fails_with_typed_error() {
+    let env = Env::default();
+    let (client, _admin) = setup_admin(&env);
+    let empty = s(&env, "");
+    client.set_metadata(&repeated(&env, MAX_METADATA_NAME_LEN + 1), &empty, &empty, &empty);
+}
+
+#[test]
+fn test_unknown_field_rejected() {
+    let env = Env::default();
+    let (client, _admin) = setup_admin(&env);
+    assert!(client
+        .try_set_metadata_field(&Symbol::new(&env, "logo"), &s(&env, "x"))
+        .is_err());
+}
+
+#[test]
+fn test_metadata_requires_governance() {
+    let env = Env::default();
+    let (client, _admin) = setup_admin(&env);
+    env.set_auths(&[]);
+    assert!(client
+        .try_set_metadata_field(&Symbol::new(&env, "name"), &s(&env, "x"))
+        .is_err());
+}
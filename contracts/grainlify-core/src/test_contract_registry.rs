@@ -14,17 +14,10 @@
 
 #[cfg(test)]
 mod tests {
-    use crate::{ContractKind, GrainlifyContract, GrainlifyContractClient};
+    use crate::testutils::setup_admin;
+    use crate::ContractKind;
     use soroban_sdk::{testutils::Address as _, Address, Env, String};
 
-    fn setup(env: &Env) -> (GrainlifyContractClient, Address) {
-        let id = env.register_contract(None, GrainlifyContract);
-        let client = GrainlifyContractClient::new(env, &id);
-        let admin = Address::generate(env);
-        client.init_admin(&admin);
-        (client, admin)
-    }
-
     fn make_address(env: &Env) -> Address {
         Address::generate(env)
     }
@@ -40,8 +33,7 @@ mod tests {
     #[test]
     fn test_register_deployed_contract() {
         let env = Env::default();
-        env.mock_all_auths();
-        let (client, _admin) = setup(&env);
+        let (client, _admin) = setup_admin(&env);
 
         let addr = make_address(&env);
         let name = make_string(&env, "bounty-escrow-v1");
@@ -58,8 +50,7 @@ mod tests {
     #[test]
     fn test_register_updates_existing_entry() {
         let env = Env::default();
-        env.mock_all_auths();
-        let (client, _admin) = setup(&env);
+        let (client, _admin) = setup_admin(&env);
 
         let addr = make_address(&env);
         let name1 = make_string(&env, "escrow-v1");
@@ -82,7 +73,7 @@ mod tests {
         env.mock_all_auths();
         let mut budget = env.budget();
         budget.reset_unlimited();
-        let (client, _admin) = setup(&env);
+        let (client, _admin) = setup_admin(&env);
 
         for _ in 0..200 {
             let addr = make_address(&env);
@@ -99,8 +90,7 @@ mod tests {
     #[should_panic(expected = "Read-only mode")]
     fn test_register_blocked_in_read_only_mode() {
         let env = Env::default();
-        env.mock_all_auths();
-        let (client, _admin) = setup(&env);
+        let (client, _admin) = setup_admin(&env);
 
         client.set_read_only_mode(&true);
 
@@ -116,8 +106,7 @@ mod tests {
     #[test]
     fn test_deregister_removes_entry() {
         let env = Env::default();
-        env.mock_all_auths();
-        let (client, _admin) = setup(&env);
+        let (client, _admin) = setup_admin(&env);
 
         let addr = make_address(&env);
         let name = make_string(&env, "to-remove");
@@ -132,8 +121,7 @@ mod tests {
     #[test]
     fn test_deregister_unknown_address_is_noop() {
         let env = Env::default();
-        env.mock_all_auths();
-        let (client, _admin) = setup(&env);
+        let (client, _admin) = setup_admin(&env);
 
         let addr = make_address(&env);
         client.deregister_deployed_contract(&addr);
@@ -144,8 +132,7 @@ mod tests {
     #[should_panic(expected = "Read-only mode")]
     fn test_deregister_blocked_in_read_only_mode() {
         let env = Env::default();
-        env.mock_all_auths();
-        let (client, _admin) = setup(&env);
+        let (client, _admin) = setup_admin(&env);
 
         let addr = make_address(&env);
         let name = make_string(&env, "test");
@@ -162,8 +149,7 @@ mod tests {
     #[test]
     fn test_list_deployed_contracts_pagination() {
         let env = Env::default();
-        env.mock_all_auths();
-        let (client, _admin) = setup(&env);
+        let (client, _admin) = setup_admin(&env);
 
         for _ in 0..5 {
             let addr = make_address(&env);
@@ -171,34 +157,33 @@ mod tests {
             client.register_deployed_contract(&addr, &name, &ContractKind::Other, &1);
         }
 
-        let page = client.list_deployed_contracts(&Some(0), &Some(2));
+        let page = client.list_deployed_contracts(&Some(0), &Some(2)).items;
         assert_eq!(page.len(), 2);
 
-        let page2 = client.list_deployed_contracts(&Some(2), &Some(2));
+        let page2 = client.list_deployed_contracts(&Some(2), &Some(2)).items;
         assert_eq!(page2.len(), 2);
 
-        let page3 = client.list_deployed_contracts(&Some(4), &Some(10));
+        let page3 = client.list_deployed_contracts(&Some(4), &Some(10)).items;
         assert_eq!(page3.len(), 1);
     }
 
     #[test]
     fn test_list_deployed_contracts_empty() {
         let env = Env::default();
-        env.mock_all_auths();
-        let (client, _admin) = setup(&env);
+        let (client, _admin) = setup_admin(&env);
 
-        let list = client.list_deployed_contracts(&None, &None);
+        let list = client.list_deployed_contracts(&None, &None).items;
         assert_eq!(list.len(), 0);
     }
 
     #[test]
-    #[should_panic(expected = "Offset exceeds registry size")]
     fn test_list_deployed_contracts_offset_too_large() {
         let env = Env::default();
-        env.mock_all_auths();
-        let (client, _admin) = setup(&env);
+        let (client, _admin) = setup_admin(&env);
 
-        client.list_deployed_contracts(&Some(1), &Some(1));
+        let page = client.list_deployed_contracts(&Some(1), &Some(1));
+        assert_eq!(page.items.len(), 0);
+        assert!(!page.has_more);
     }
 
     // -----------------------------------------------------------------------
@@ -208,8 +193,7 @@ mod tests {
     #[test]
     fn test_get_deployed_contract_returns_none_for_unknown() {
         let env = Env::default();
-        env.mock_all_auths();
-        let (client, _admin) = setup(&env);
+        let (client, _admin) = setup_admin(&env);
 
         let addr = make_address(&env);
         assert!(client.get_deployed_contract(&addr).is_none());
@@ -222,8 +206,7 @@ mod tests {
     #[test]
     fn test_deployed_contract_count_increments_and_decrements() {
         let env = Env::default();
-        env.mock_all_auths();
-        let (client, _admin) = setup(&env);
+        let (client, _admin) = setup_admin(&env);
 
         assert_eq!(client.deployed_contract_count(), 0);
 
@@ -246,8 +229,7 @@ mod tests {
     #[test]
     fn test_deployed_at_timestamp_recorded() {
         let env = Env::default();
-        env.mock_all_auths();
-        let (client, _admin) = setup(&env);
+        let (client, _admin) = setup_admin(&env);
 
         let before = env.ledger().timestamp();
         let addr = make_address(&env);
@@ -265,8 +247,7 @@ mod tests {
     #[test]
     fn test_list_pagination_exact_boundary() {
         let env = Env::default();
-        env.mock_all_auths();
-        let (client, _admin) = setup(&env);
+        let (client, _admin) = setup_admin(&env);
 
         for _ in 0..3 {
             let addr = make_address(&env);
@@ -274,15 +255,14 @@ mod tests {
         }
 
         // offset=1, limit=2 should return exactly the last 2 items
-        let page = client.list_deployed_contracts(&Some(1), &Some(2));
+        let page = client.list_deployed_contracts(&Some(1), &Some(2)).items;
         assert_eq!(page.len(), 2);
     }
 
     #[test]
     fn test_get_deployed_contract_all_fields_correct() {
         let env = Env::default();
-        env.mock_all_auths();
-        let (client, _admin) = setup(&env);
+        let (client, _admin) = setup_admin(&env);
 
         let addr = make_address(&env);
         let name = make_string(&env, "full-check");
@@ -298,8 +278,7 @@ mod tests {
     #[test]
     fn test_multiple_deregisters_no_panic() {
         let env = Env::default();
-        env.mock_all_auths();
-        let (client, _admin) = setup(&env);
+        let (client, _admin) = setup_admin(&env);
 
         let addr = make_address(&env);
         client.deregister_deployed_contract(&addr);
@@ -315,11 +294,8 @@ mod tests {
     #[test]
     fn test_views_require_no_auth() {
         let env = Env::default();
-        env.mock_all_auths();
-        let id = env.register_contract(None, GrainlifyContract);
-        let client = GrainlifyContractClient::new(&env, &id);
-        let admin = Address::generate(&env);
-        client.init_admin(&admin);
+        let (client, _admin) = setup_admin(&env);
+        env.set_auths(&[]);
 
         // These should work without auth mock
         let _ = client.deployed_contract_count();
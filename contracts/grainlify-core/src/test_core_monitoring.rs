@@ -1,26 +1,19 @@
 #[cfg(test)]
 mod test {
     use crate::monitoring;
+    use crate::testutils::setup_admin;
     use crate::{DataKey, GrainlifyContract, GrainlifyContractClient};
     use soroban_sdk::{testutils::Address as _, Address, Env, Symbol};
 
-    fn setup_test(env: &Env) -> (GrainlifyContractClient, Address) {
-        let contract_id = env.register_contract(None, GrainlifyContract);
-        let client = GrainlifyContractClient::new(env, &contract_id);
-        let admin = Address::generate(env);
-        client.init_admin(&admin);
-        (client, admin)
-    }
-
     #[test]
     fn test_healthy_state_passes_verification() {
         let env = Env::default();
-        let (client, admin) = setup_test(&env);
+        let (client, admin) = setup_admin(&env);
 
         env.as_contract(&client.address, || {
             // Record some successful operations
-            monitoring::track_operation(&env, Symbol::new(&env, "op1"), admin.clone(), true);
-            monitoring::track_operation(&env, Symbol::new(&env, "op2"), admin.clone(), true);
+            monitoring::track(&env, Symbol::new(&env, "op1"), admin.clone(), true, None, true);
+            monitoring::track(&env, Symbol::new(&env, "op2"), admin.clone(), true, None, true);
 
             // Verify state is healthy
             assert!(monitoring::verify_invariants(&env));
@@ -30,11 +23,11 @@ mod test {
     #[test]
     fn test_tampered_state_fails_verification() {
         let env = Env::default();
-        let (client, admin) = setup_test(&env);
+        let (client, admin) = setup_admin(&env);
 
         env.as_contract(&client.address, || {
             // Record a single successful operation
-            monitoring::track_operation(&env, Symbol::new(&env, "op1"), admin.clone(), true);
+            monitoring::track(&env, Symbol::new(&env, "op1"), admin.clone(), true, None, true);
 
             // Verify healthy initially
             assert!(monitoring::verify_invariants(&env));
@@ -57,7 +50,7 @@ mod test {
     #[test]
     fn test_user_drift_tampering() {
         let env = Env::default();
-        let (client, admin) = setup_test(&env);
+        let (client, admin) = setup_admin(&env);
 
         env.as_contract(&client.address, || {
             let op_key = Symbol::new(&env, "op_count");
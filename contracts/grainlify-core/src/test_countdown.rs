@@ -0,0 +1,107 @@
+//! # Countdown View Tests
+//!
+//! - `time_until_executable` counts down the timelock and the window's
+//!   `not_before`, whichever ends later; an unstarted timelock counts as the
+//!   full delay
+//! - `time_until_expiry` counts down to the expiry and stays at zero after
+//!   it; proposals without an expiry report `u64::MAX`
+//! - Unknown, executed and cancelled proposals fail with a `CountdownError`
+
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{testutils::Ledger as _, vec, Env};
+
+use crate::testutils::{setup_multisig, upload_dummy_wasm};
+use crate::{ConfigUpdate, CountdownError, ProposalAction};
+
+const START: u64 = 1_000;
+
+#[test]
+fn test_timelock_countdown() {
+    let env = Env::default();
+    env.ledger().set_timestamp(START);
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    let delay = client.get_timelock_delay();
+    let proposal_id = client.propose_upgrade(&signers.get(0).unwrap(), &upload_dummy_wasm(&env, 1), &0);
+
+    assert_eq!(client.time_until_executable(&proposal_id), delay);
+    assert_eq!(client.time_until_expiry(&proposal_id), u64::MAX);
+
+    env.ledger().set_timestamp(START + 100);
+    assert_eq!(client.time_until_executable(&proposal_id), delay);
+    client.approve_upgrade(&proposal_id, &signers.get(0).unwrap());
+    client.approve_upgrade(&proposal_id, &signers.get(1).unwrap());
+
+    env.ledger().set_timestamp(START + 110);
+    assert_eq!(client.time_until_executable(&proposal_id), delay - 10);
+    env.ledger().set_timestamp(START + 100 + delay - 1);
+    assert_eq!(client.time_until_executable(&proposal_id), 1);
+    env.ledger().set_timestamp(START + 100 + delay);
+    assert_eq!(client.time_until_executable(&proposal_id), 0);
+    env.ledger().set_timestamp(START + 100 + delay + 500);
+    assert_eq!(client.time_until_executable(&proposal_id), 0);
+}
+
+#[test]
+fn test_window_and_expiry_countdown() {
+    let env = Env::default();
+    env.ledger().set_timestamp(START);
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    let delay = client.get_timelock_delay();
+    let not_before = START + delay + 500;
+    let not_after = START + delay + 2_000;
+    let proposal_id = client.propose_upgrade_in_window(
+        &signers.get(0).unwrap(),
+        &upload_dummy_wasm(&env, 1),
+        &0,
+        &Some(not_before),
+        &Some(not_after),
+    );
+
+    assert_eq!(client.time_until_executable(&proposal_id), delay + 500);
+    assert_eq!(client.time_until_expiry(&proposal_id), delay + 2_000);
+
+    client.approve_upgrade(&proposal_id, &signers.get(0).unwrap());
+    client.approve_upgrade(&proposal_id, &signers.get(1).unwrap());
+
+    // Timelock elapsed; the window still holds execution back.
+    env.ledger().set_timestamp(START + delay);
+    assert_eq!(client.time_until_executable(&proposal_id), 500);
+    assert_eq!(client.time_until_expiry(&proposal_id), 2_000);
+
+    env.ledger().set_timestamp(not_before);
+    assert_eq!(client.time_until_executable(&proposal_id), 0);
+    assert_eq!(client.time_until_expiry(&proposal_id), 1_500);
+
+    env.ledger().set_timestamp(not_after);
+    assert_eq!(client.time_until_expiry(&proposal_id), 0);
+    env.ledger().set_timestamp(not_after + 1_000);
+    assert_eq!(client.time_until_expiry(&proposal_id), 0);
+}
+
+#[test]
+fn test_closed_or_unknown_proposals_fail() {
+    let env = Env::default();
+    env.ledger().set_timestamp(START);
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    let proposer = signers.get(0).unwrap();
+
+    assert_eq!(client.try_time_until_executable(&99), Err(Ok(CountdownError::ProposalNotFound)));
+    assert_eq!(client.try_time_until_expiry(&99), Err(Ok(CountdownError::ProposalNotFound)));
+
+    let cancelled = client.propose_upgrade(&proposer, &upload_dummy_wasm(&env, 1), &0);
+    client.cancel_upgrade(&cancelled, &proposer);
+    assert_eq!(client.try_time_until_executable(&cancelled), Err(Ok(CountdownError::ProposalCancelled)));
+    assert_eq!(client.try_time_until_expiry(&cancelled), Err(Ok(CountdownError::ProposalCancelled)));
+
+    let update = ConfigUpdate { max_postponement: Some(600), ..Default::default() };
+    let executed = client.propose_actions(&proposer, &vec![&env, ProposalAction::UpdateConfig(update)], &0);
+    client.approve_actions(&executed, &proposer);
+    client.approve_actions(&executed, &signers.get(1).unwrap());
+    env.ledger().set_timestamp(START + client.get_timelock_delay());
+    client.execute_proposal(&executed);
+    assert_eq!(client.try_time_until_executable(&executed), Err(Ok(CountdownError::ProposalExecuted)));
+    assert_eq!(client.try_time_until_expiry(&executed), Err(Ok(CountdownError::ProposalExecuted)));
+}
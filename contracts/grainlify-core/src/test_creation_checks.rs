@@ -0,0 +1,109 @@
+//! # Creation-Time Proposal Check Tests
+//!
+//! - `propose_upgrade` refuses a blocked, unattested or already-installed
+//!   hash, and read-only mode, with the matching `ProposalCheckError`
+//! - The checks are the ones `validate_proposal` reports, so a proposal
+//!   that passes them at creation reports them passing
+//! - Time-gated conditions are left to execution: a proposal whose window
+//!   has not opened is still created
+
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger as _},
+    vec, Address, BytesN, Env, String, Vec,
+};
+
+use crate::testutils::upload_dummy_wasm;
+use crate::{
+    AttestationConfig, GrainlifyContract, GrainlifyContractClient, MultiSig, ProposalCheckError,
+    ValidationCheck,
+};
+
+/// Admin-initialized contract running `installed`, with a 2-of-3 multisig.
+fn setup<'a>(env: &'a Env, installed: &BytesN<32>) -> (GrainlifyContractClient<'a>, Vec<Address>) {
+    env.mock_all_auths();
+    let client = GrainlifyContractClient::new(env, &env.register_contract(None, GrainlifyContract));
+    client.init_admin_with_wasm(&Address::generate(env), installed, &None);
+    let signers = vec![env, Address::generate(env), Address::generate(env), Address::generate(env)];
+    env.as_contract(&client.address, || MultiSig::init(env, signers.clone(), 2));
+    (client, signers)
+}
+
+#[test]
+#[should_panic(expected = "151")]
+fn test_blocked_hash_rejected() {
+    let env = Env::default();
+    let (client, signers) = setup(&env, &upload_dummy_wasm(&env, 0));
+    let wasm_hash = upload_dummy_wasm(&env, 1);
+    client.block_wasm_hash(&wasm_hash, &String::from_str(&env, "known bad"));
+    client.propose_upgrade(&signers.get(0).unwrap(), &wasm_hash, &0);
+}
+
+#[test]
+#[should_panic(expected = "109")]
+fn test_unattested_hash_rejected() {
+    let env = Env::default();
+    let (client, signers) = setup(&env, &upload_dummy_wasm(&env, 0));
+    client.set_attestation_config(&AttestationConfig {
+        require_attestation: true,
+        auditors: vec![&env, Address::generate(&env)],
+        min_attestations: 1,
+    });
+    client.propose_upgrade(&signers.get(0).unwrap(), &upload_dummy_wasm(&env, 1), &0);
+}
+
+#[test]
+#[should_panic(expected = "152")]
+fn test_installed_hash_rejected() {
+    let env = Env::default();
+    let installed = upload_dummy_wasm(&env, 0);
+    let (client, signers) = setup(&env, &installed);
+    client.propose_upgrade(&signers.get(0).unwrap(), &installed, &0);
+}
+
+#[test]
+#[should_panic(expected = "149")]
+fn test_read_only_rejected() {
+    let env = Env::default();
+    let (client, signers) = setup(&env, &upload_dummy_wasm(&env, 0));
+    client.set_read_only_mode(&true);
+    client.propose_upgrade(&signers.get(0).unwrap(), &upload_dummy_wasm(&env, 1), &0);
+}
+
+#[test]
+fn test_error_codes_are_stable() {
+    assert_eq!(ProposalCheckError::Paused as u32, crate::ContractError::Paused as u32);
+    assert_eq!(ProposalCheckError::AttestationMissing as u32, crate::ContractError::AttestationMissing as u32);
+}
+
+#[test]
+fn test_time_gated_conditions_checked_at_execution_only() {
+    let env = Env::default();
+    let (client, signers) = setup(&env, &upload_dummy_wasm(&env, 0));
+    let not_before = 10 * client.get_timelock_delay();
+    let proposal_id = client.propose_upgrade_in_window(
+        &signers.get(0).unwrap(),
+        &upload_dummy_wasm(&env, 1),
+        &0,
+        &Some(not_before),
+        &None,
+    );
+    client.approve_upgrade(&proposal_id, &signers.get(0).unwrap());
+    client.approve_upgrade(&proposal_id, &signers.get(1).unwrap());
+
+    let failing: std::vec::Vec<ValidationCheck> = client
+        .validate_proposal(&proposal_id)
+        .results
+        .iter()
+        .filter(|result| !result.passed)
+        .map(|result| result.check)
+        .collect();
+    assert_eq!(failing, std::vec![ValidationCheck::Timelock, ValidationCheck::Window]);
+    assert!(client.try_execute_upgrade(&proposal_id).is_err());
+
+    env.ledger().set_timestamp(not_before);
+    client.execute_upgrade(&proposal_id);
+}
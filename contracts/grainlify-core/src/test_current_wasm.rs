@@ -0,0 +1,108 @@
+//! # Current WASM Hash Tests
+//!
+//! - `init_admin_with_wasm` / `init_with_wasm` record the initial hash; the
+//!   plain init paths leave it unset
+//! - `upgrade` and `execute_upgrade` record the newly installed hash
+//! - `attest_current_wasm` corrects a missing or stale record, emits
+//!   `("wasm", "corrected")`, and requires governance
+
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{
+    testutils::{Address as _, Events as _, Ledger as _},
+    vec, Address, BytesN, Env, Symbol, TryFromVal,
+};
+
+use crate::testutils::{setup_admin, setup_multisig, upload_dummy_wasm};
+use crate::{DataKey, GrainlifyContract, GrainlifyContractClient, WasmHashCorrectedEvent};
+
+fn digest(env: &Env, n: u8) -> BytesN<32> {
+    BytesN::from_array(env, &[n; 32])
+}
+
+/// Reads the tracked hash directly, for use after a swap to the dummy WASM
+/// leaves no callable entrypoints.
+fn tracked_hash(env: &Env, client: &GrainlifyContractClient) -> Option<BytesN<32>> {
+    env.as_contract(&client.address, || env.storage().instance().get(&DataKey::CurrentWasmHash))
+}
+
+fn last_correction(env: &Env) -> Option<WasmHashCorrectedEvent> {
+    let mut found = None;
+    for (_, topics, data) in env.events().all().iter() {
+        if topics.len() != 2 {
+            continue;
+        }
+        let t1 = Symbol::try_from_val(env, &topics.get(1).unwrap());
+        if t1 == Ok(Symbol::new(env, "corrected")) {
+            found = WasmHashCorrectedEvent::try_from_val(env, &data).ok();
+        }
+    }
+    found
+}
+
+#[test]
+fn test_init_records_initial_hash() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = GrainlifyContractClient::new(&env, &env.register_contract(None, GrainlifyContract));
+    client.init_admin_with_wasm(&Address::generate(&env), &digest(&env, 1), &Some(7));
+    assert_eq!(client.get_current_wasm_hash(), Some(digest(&env, 1)));
+    assert_eq!(client.get_version(), 7);
+
+    let client = GrainlifyContractClient::new(&env, &env.register_contract(None, GrainlifyContract));
+    let signers = vec![&env, Address::generate(&env), Address::generate(&env)];
+    client.init_with_wasm(&signers, &2, &digest(&env, 2), &None);
+    assert_eq!(client.get_current_wasm_hash(), Some(digest(&env, 2)));
+
+    let (client, _admin) = setup_admin(&env);
+    assert_eq!(client.get_current_wasm_hash(), None);
+}
+
+#[test]
+fn test_admin_upgrade_records_hash() {
+    let env = Env::default();
+    let (client, _admin) = setup_admin(&env);
+    let wasm = upload_dummy_wasm(&env, 1);
+    client.upgrade(&wasm);
+    assert_eq!(tracked_hash(&env, &client), Some(wasm));
+}
+
+#[test]
+fn test_execute_upgrade_records_hash() {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    let wasm = upload_dummy_wasm(&env, 1);
+    let proposal_id = client.propose_upgrade(&signers.get(0).unwrap(), &wasm, &0);
+    client.approve_upgrade(&proposal_id, &signers.get(0).unwrap());
+    client.approve_upgrade(&proposal_id, &signers.get(1).unwrap());
+    env.ledger().set_timestamp(client.get_timelock_delay());
+    client.execute_upgrade(&proposal_id);
+    assert_eq!(tracked_hash(&env, &client), Some(wasm));
+}
+
+#[test]
+fn test_attest_corrects_record() {
+    let env = Env::default();
+    let (client, _admin) = setup_admin(&env);
+
+    client.attest_current_wasm(&digest(&env, 1));
+    assert_eq!(client.get_current_wasm_hash(), Some(digest(&env, 1)));
+    let event = last_correction(&env).unwrap();
+    assert_eq!(event.previous_wasm_hash, digest(&env, 0));
+    assert_eq!(event.wasm_hash, digest(&env, 1));
+
+    client.attest_current_wasm(&digest(&env, 2));
+    assert_eq!(client.get_current_wasm_hash(), Some(digest(&env, 2)));
+    assert_eq!(last_correction(&env).unwrap().previous_wasm_hash, digest(&env, 1));
+}
+
+#[test]
+fn test_attest_requires_governance() {
+    let env = Env::default();
+    let (client, _admin) = setup_admin(&env);
+    env.set_auths(&[]);
+    assert!(client.try_attest_current_wasm(&digest(&env, 1)).is_err());
+    assert_eq!(client.get_current_wasm_hash(), None);
+}
@@ -0,0 +1,197 @@
+//! # Deployment Name Registry Tests
+//!
+//! - `register_deployment` / `resolve` round-trip
+//! - Name collisions rejected unless `overwrite` is set
+//! - `set_deployment_version` updates version and timestamp
+//! - `list_deployments` enumerates in registration order
+//! - `unregister_deployment` drops the name from resolution and listing
+//! - Entries live in persistent storage, not the contract instance
+//! - Writes are governance-only; reads need no auth
+//! - Multisig deployments manage the registry with action bundles
+
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Ledger as _},
+    vec, Address, Env,
+};
+
+use crate::testutils::{setup_admin, setup_multisig, GovernanceDriver};
+use crate::{DataKey, ProposalAction};
+
+#[test]
+fn test_register_and_resolve() {
+    let env = Env::default();
+    let (client, _admin) = setup_admin(&env);
+    let escrow = Address::generate(&env);
+    env.ledger().set_timestamp(500);
+
+    client.register_deployment(&symbol_short!("escrow"), &escrow, &false);
+
+    assert_eq!(client.resolve(&symbol_short!("escrow")), escrow);
+    let info = client.get_deployment_info(&symbol_short!("escrow")).unwrap();
+    assert_eq!(info.address, escrow);
+    assert_eq!(info.version, 0);
+    assert_eq!(info.updated_at, 500);
+}
+
+#[test]
+#[should_panic(expected = "Deployment not found")]
+fn test_resolve_unknown_name_panics() {
+    let env = Env::default();
+    let (client, _admin) = setup_admin(&env);
+    client.resolve(&symbol_short!("missing"));
+}
+
+#[test]
+#[should_panic(expected = "Deployment name already registered")]
+fn test_collision_rejected_without_overwrite() {
+    let env = Env::default();
+    let (client, _admin) = setup_admin(&env);
+    client.register_deployment(&symbol_short!("escrow"), &Address::generate(&env), &false);
+    client.register_deployment(&symbol_short!("escrow"), &Address::generate(&env), &false);
+}
+
+#[test]
+fn test_overwrite_replaces_address_and_resets_version() {
+    let env = Env::default();
+    let (client, _admin) = setup_admin(&env);
+    let old = Address::generate(&env);
+    let new = Address::generate(&env);
+
+    client.register_deployment(&symbol_short!("escrow"), &old, &false);
+    client.set_deployment_version(&symbol_short!("escrow"), &3);
+    client.register_deployment(&symbol_short!("escrow"), &new, &true);
+
+    let info = client.get_deployment_info(&symbol_short!("escrow")).unwrap();
+    assert_eq!(info.address, new);
+    assert_eq!(info.version, 0);
+    assert_eq!(client.list_deployments(&0, &10).items.len(), 1, "overwrite must not duplicate");
+}
+
+#[test]
+fn test_set_deployment_version_updates_timestamp() {
+    let env = Env::default();
+    let (client, _admin) = setup_admin(&env);
+    client.register_deployment(&symbol_short!("core"), &Address::generate(&env), &false);
+
+    env.ledger().set_timestamp(9_000);
+    client.set_deployment_version(&symbol_short!("core"), &4);
+
+    let info = client.get_deployment_info(&symbol_short!("core")).unwrap();
+    assert_eq!(info.version, 4);
+    assert_eq!(info.updated_at, 9_000);
+}
+
+#[test]
+#[should_panic(expected = "Deployment not found")]
+fn test_set_version_for_unknown_name_panics() {
+    let env = Env::default();
+    let (client, _admin) = setup_admin(&env);
+    client.set_deployment_version(&symbol_short!("missing"), &1);
+}
+
+#[test]
+fn test_list_deployments_in_registration_order() {
+    let env = Env::default();
+    let (client, _admin) = setup_admin(&env);
+    let names = [symbol_short!("core"), symbol_short!("escrow"), symbol_short!("facade")];
+    for name in names.iter() {
+        client.register_deployment(name, &Address::generate(&env), &false);
+    }
+
+    let page = client.list_deployments(&0, &2).items;
+    assert_eq!(page.len(), 2);
+    assert_eq!(page.get(0).unwrap().name, names[0]);
+    assert_eq!(page.get(1).unwrap().name, names[1]);
+
+    let rest = client.list_deployments(&2, &10).items;
+    assert_eq!(rest.len(), 1);
+    assert_eq!(rest.get(0).unwrap().name, names[2]);
+    assert_eq!(client.list_deployments(&5, &10).items.len(), 0);
+}
+
+#[test]
+fn test_unregister_removes_name() {
+    let env = Env::default();
+    let (client, _admin) = setup_admin(&env);
+    for name in [symbol_short!("core"), symbol_short!("escrow"), symbol_short!("facade")].iter() {
+        client.register_deployment(name, &Address::generate(&env), &false);
+    }
+
+    client.unregister_deployment(&symbol_short!("escrow"));
+    assert!(client.get_deployment_info(&symbol_short!("escrow")).is_none());
+    let page = client.list_deployments(&0, &10).items;
+    assert_eq!(page.len(), 2);
+    assert_eq!(page.get(0).unwrap().name, symbol_short!("core"));
+    assert_eq!(page.get(1).unwrap().name, symbol_short!("facade"));
+
+    // The name is free again without `overwrite`.
+    client.register_deployment(&symbol_short!("escrow"), &Address::generate(&env), &false);
+    assert_eq!(client.list_deployments(&0, &10).items.len(), 3);
+}
+
+#[test]
+#[should_panic(expected = "Deployment not found")]
+fn test_unregister_unknown_name_panics() {
+    let env = Env::default();
+    let (client, _admin) = setup_admin(&env);
+    client.unregister_deployment(&symbol_short!("missing"));
+}
+
+#[test]
+fn test_entries_stored_outside_instance() {
+    let env = Env::default();
+    let (client, _admin) = setup_admin(&env);
+    client.register_deployment(&symbol_short!("core"), &Address::generate(&env), &false);
+
+    env.as_contract(&client.address, || {
+        let key = DataKey::DeploymentByName(symbol_short!("core"));
+        assert!(env.storage().persistent().has(&key));
+        assert!(env.storage().persistent().has(&DataKey::DeploymentNames));
+        assert!(!env.storage().instance().has(&key));
+        assert!(!env.storage().instance().has(&DataKey::DeploymentNames));
+    });
+}
+
+#[test]
+fn test_writes_require_admin_auth() {
+    let env = Env::default();
+    let (client, _admin) = setup_admin(&env);
+    client.register_deployment(&symbol_short!("core"), &Address::generate(&env), &false);
+
+    env.set_auths(&[]);
+    assert!(client
+        .try_register_deployment(&symbol_short!("other"), &Address::generate(&env), &false)
+        .is_err());
+    assert!(client.try_set_deployment_version(&symbol_short!("core"), &2).is_err());
+    assert!(client.try_unregister_deployment(&symbol_short!("core")).is_err());
+
+    // Reads stay open.
+    assert_eq!(client.list_deployments(&0, &10).items.len(), 1);
+    assert!(client.get_deployment_info(&symbol_short!("core")).is_some());
+}
+
+#[test]
+fn test_multisig_manages_registry_through_actions() {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    let escrow = Address::generate(&env);
+    assert!(client.try_register_deployment(&symbol_short!("escrow"), &escrow, &false).is_err());
+
+    let actions = vec![
+        &env,
+        ProposalAction::RegisterDeployment(symbol_short!("escrow"), escrow.clone(), false),
+        ProposalAction::SetDeploymentVersion(symbol_short!("escrow"), 3),
+    ];
+    GovernanceDriver::apply_actions(&env, &client, &signers, actions);
+    assert_eq!(client.resolve(&symbol_short!("escrow")), escrow);
+    assert_eq!(client.get_deployment_info(&symbol_short!("escrow")).unwrap().version, 3);
+
+    let actions = vec![&env, ProposalAction::UnregisterDeployment(symbol_short!("escrow"))];
+    GovernanceDriver::apply_actions(&env, &client, &signers, actions);
+    assert_eq!(client.get_deployment_info(&symbol_short!("escrow")), None);
+}
@@ -0,0 +1,137 @@
+//! # Duplicate Approval Tests
+//!
+//! - `Strict` (default): a repeated approval fails with `AlreadyApproved`
+//! - `Idempotent`: the repeat succeeds, records nothing, publishes no
+//!   `approved` event, and `approve_upgrade_v2` returns the unchanged count
+//! - The count never double-increments in either mode
+
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{
+    symbol_short, testutils::Address as _, testutils::Events as _, vec, Address, BytesN, Env,
+    IntoVal, String, Vec,
+};
+
+use crate::testutils::{setup_admin, setup_multisig, upload_dummy_wasm, GovernanceDriver};
+use crate::{DuplicateApproval, GrainlifyContractClient, ProposalAction};
+
+struct Setup<'a> {
+    env: Env,
+    client: GrainlifyContractClient<'a>,
+    signers: Vec<Address>,
+    proposal_id: u64,
+    wasm_hash: BytesN<32>,
+}
+
+/// 2-of-3 multisig contract that set the mode through an action bundle,
+/// with one open proposal.
+fn setup<'a>(mode: DuplicateApproval) -> Setup<'a> {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    let client = GrainlifyContractClient::new(&env, &client.address);
+    GovernanceDriver::apply_actions(&env, &client, &signers, vec![&env, ProposalAction::SetDuplicateApproval(mode)]);
+
+    let wasm_hash = upload_dummy_wasm(&env, 1);
+    let proposal_id = client.propose_upgrade(&signers.get(0).unwrap(), &wasm_hash, &0);
+    Setup { env, client, signers, proposal_id, wasm_hash }
+}
+
+impl Setup<'_> {
+    fn approve_v2(&self, signer: &Address) -> u32 {
+        let description = self.client.get_approval_description(&self.proposal_id);
+        self.client.approve_upgrade_v2(&self.proposal_id, signer, &self.wasm_hash, &description, &None, &None)
+    }
+
+    fn try_approve_v2(&self, signer: &Address) -> bool {
+        let description: String = self.client.get_approval_description(&self.proposal_id);
+        self.client
+            .try_approve_upgrade_v2(&self.proposal_id, signer, &self.wasm_hash, &description, &None, &None)
+            .is_ok()
+    }
+
+    fn approved_events(&self) -> usize {
+        let topics = (symbol_short!("approved"),).into_val(&self.env);
+        self.env.events().all().iter().filter(|(_, t, _)| *t == topics).count()
+    }
+}
+
+#[test]
+fn test_strict_is_the_default() {
+    let env = Env::default();
+    let (client, _admin) = setup_admin(&env);
+    assert_eq!(client.get_duplicate_approval(), DuplicateApproval::Strict);
+}
+
+#[test]
+#[should_panic(expected = "146")]
+fn test_strict_rejects_duplicate_approve_upgrade() {
+    let s = setup(DuplicateApproval::Strict);
+    let signer = s.signers.get(0).unwrap();
+    s.client.approve_upgrade(&s.proposal_id, &signer);
+    s.client.approve_upgrade(&s.proposal_id, &signer);
+}
+
+#[test]
+fn test_strict_rejects_duplicate_v2() {
+    let s = setup(DuplicateApproval::Strict);
+    let signer = s.signers.get(0).unwrap();
+    assert_eq!(s.approve_v2(&signer), 1);
+    assert!(!s.try_approve_v2(&signer));
+    assert_eq!(s.client.get_valid_approvals(&s.proposal_id).len(), 1);
+}
+
+#[test]
+fn test_idempotent_duplicate_returns_unchanged_count() {
+    let s = setup(DuplicateApproval::Idempotent);
+    let signer = s.signers.get(0).unwrap();
+    assert_eq!(s.approve_v2(&signer), 1);
+    let events = s.approved_events();
+    assert_eq!(s.approve_v2(&signer), 1);
+    assert_eq!(s.approved_events(), events, "no-op must not publish");
+
+    assert_eq!(s.approve_v2(&s.signers.get(1).unwrap()), 2);
+    assert_eq!(s.approve_v2(&signer), 2);
+    assert_eq!(s.client.get_valid_approvals(&s.proposal_id).len(), 2);
+}
+
+#[test]
+fn test_idempotent_applies_to_approve_upgrade() {
+    let s = setup(DuplicateApproval::Idempotent);
+    let signer = s.signers.get(0).unwrap();
+    let given = s.client.get_signer_stats(&signer).unwrap().approvals_given;
+    s.client.approve_upgrade(&s.proposal_id, &signer);
+    s.client.approve_upgrade(&s.proposal_id, &signer);
+    assert_eq!(s.client.get_valid_approvals(&s.proposal_id), vec![&s.env, signer.clone()]);
+    assert_eq!(s.client.get_signer_stats(&signer).unwrap().approvals_given, given + 1);
+}
+
+#[test]
+fn test_idempotent_still_rejects_non_signers() {
+    let s = setup(DuplicateApproval::Idempotent);
+    assert!(!s.try_approve_v2(&Address::generate(&s.env)));
+}
+
+#[test]
+fn test_admin_sets_mode_directly() {
+    let env = Env::default();
+    let (client, _admin) = setup_admin(&env);
+    client.set_duplicate_approval(&DuplicateApproval::Idempotent);
+    assert_eq!(client.get_duplicate_approval(), DuplicateApproval::Idempotent);
+}
+
+#[test]
+fn test_set_duplicate_approval_requires_governance() {
+    let env = Env::default();
+    let (client, _admin) = setup_admin(&env);
+    env.set_auths(&[]);
+    assert!(client.try_set_duplicate_approval(&DuplicateApproval::Idempotent).is_err());
+}
+
+#[test]
+fn test_multisig_contract_has_no_direct_setter() {
+    let s = setup(DuplicateApproval::Idempotent);
+    assert_eq!(s.client.get_duplicate_approval(), DuplicateApproval::Idempotent);
+    assert!(s.client.try_set_duplicate_approval(&DuplicateApproval::Strict).is_err());
+}
@@ -0,0 +1,98 @@
+//! # Deployment Environment Tests
+//!
+//! - `init_admin_with_environment` labels the deployment and records the
+//!   network passphrase hash
+//! - `assert_environment` passes for the recorded label and fails with
+//!   `NetworkMismatch` for another label, an unlabelled deployment, or a
+//!   different network
+//! - Governance relabels through `set_network_label`, leaving the network
+//!   IDs given to `init_with_network` intact
+
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Ledger as _},
+    Address, Env, String,
+};
+
+use crate::testutils::setup_admin;
+use crate::{GrainlifyContract, GrainlifyContractClient};
+
+fn setup_mainnet(env: &Env) -> GrainlifyContractClient<'_> {
+    env.mock_all_auths();
+    let client = GrainlifyContractClient::new(env, &env.register_contract(None, GrainlifyContract));
+    client.init_admin_with_environment(&Address::generate(env), &symbol_short!("mainnet"));
+    client
+}
+
+#[test]
+fn test_init_records_label_and_network() {
+    let env = Env::default();
+    let client = setup_mainnet(&env);
+    assert_eq!(client.get_environment(), Some(symbol_short!("mainnet")));
+    assert_eq!(client.get_environment_network_id(), Some(env.ledger().network_id()));
+    client.assert_environment(&symbol_short!("mainnet"));
+}
+
+#[test]
+#[should_panic(expected = "128")]
+fn test_wrong_label_fails() {
+    let env = Env::default();
+    let client = setup_mainnet(&env);
+    client.assert_environment(&symbol_short!("testnet"));
+}
+
+#[test]
+#[should_panic(expected = "128")]
+fn test_unlabelled_deployment_fails() {
+    let env = Env::default();
+    let (client, _) = setup_admin(&env);
+    assert_eq!(client.get_environment(), None);
+    client.assert_environment(&symbol_short!("mainnet"));
+}
+
+#[test]
+#[should_panic(expected = "128")]
+fn test_other_network_fails() {
+    let env = Env::default();
+    let client = setup_mainnet(&env);
+    env.ledger().set_network_id([7; 32]);
+    client.assert_environment(&symbol_short!("mainnet"));
+}
+
+#[test]
+fn test_governance_relabels() {
+    let env = Env::default();
+    let client = setup_mainnet(&env);
+    env.ledger().set_network_id([7; 32]);
+    client.set_network_label(&Some(symbol_short!("testnet")));
+
+    assert_eq!(client.get_environment_network_id().unwrap().to_array(), [7; 32]);
+    client.assert_environment(&symbol_short!("testnet"));
+    assert!(client.try_assert_environment(&symbol_short!("mainnet")).is_err());
+
+    client.set_network_label(&None);
+    assert_eq!(client.get_environment_network_id(), None);
+    assert!(client.try_assert_environment(&symbol_short!("testnet")).is_err());
+}
+
+#[test]
+fn test_relabel_keeps_init_network_ids() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = GrainlifyContractClient::new(&env, &env.register_contract(None, GrainlifyContract));
+    let chain_id = String::from_str(&env, "stellar");
+    let network_id = String::from_str(&env, "mainnet");
+    client.init_with_network(&Address::generate(&env), &chain_id, &network_id);
+
+    client.set_network_label(&Some(symbol_short!("mainnet")));
+    assert_eq!(client.get_network_info(), (Some(chain_id.clone()), Some(network_id.clone())));
+    assert_eq!(client.get_network_id(), Some(network_id.clone()));
+    client.health_check();
+
+    client.set_network_label(&None);
+    assert_eq!(client.get_network_info(), (Some(chain_id), Some(network_id)));
+}
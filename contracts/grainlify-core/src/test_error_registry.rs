@@ -63,8 +63,8 @@ mod tests {
     fn test_registry_entry_count() {
         assert_eq!(
             registered_count(),
-            10,
-            "Expected exactly 10 entries in GRAINLIFY_CORE_REGISTRY (3 common + 7 governance)"
+            50,
+            "Expected exactly 50 entries in GRAINLIFY_CORE_REGISTRY (4 common + 46 governance)"
         );
     }
 
@@ -136,8 +136,8 @@ mod tests {
     }
 
     #[test]
-    fn test_lookup_unassigned_code_108() {
-        assert_eq!(lookup_name(108), None);
+    fn test_lookup_unassigned_code_199() {
+        assert_eq!(lookup_name(199), None);
     }
 
     #[test]
@@ -149,7 +149,7 @@ mod tests {
 
     #[test]
     fn test_is_registered_true_for_all_known_codes() {
-        let known = [1u32, 2, 3, 101, 102, 103, 104, 105, 106, 107];
+        let known = [1u32, 2, 3, 10, 101, 102, 103, 104, 105, 106, 107, 108, 109, 110, 111, 112, 113, 114, 115, 116, 117, 118, 119, 120, 121, 122, 123, 124, 125, 126, 127, 128, 129, 130, 131, 132, 133, 134, 135, 136, 137, 138, 139, 140, 141, 142, 143, 144, 145, 146];
         for code in known {
             assert!(is_registered(code), "code {code} must be registered");
         }
@@ -157,7 +157,7 @@ mod tests {
 
     #[test]
     fn test_is_registered_false_for_gaps() {
-        let gaps = [0u32, 4, 99, 100, 108, 200, 9999];
+        let gaps = [0u32, 4, 99, 100, 199, 200, 9999];
         for code in gaps {
             assert!(!is_registered(code), "code {code} must NOT be registered");
         }
@@ -171,6 +171,7 @@ mod tests {
             (ContractError::AlreadyInitialized as u32, "AlreadyInitialized"),
             (ContractError::NotInitialized as u32, "NotInitialized"),
             (ContractError::NotAdmin as u32, "NotAdmin"),
+            (ContractError::Paused as u32, "Paused"),
             (ContractError::ThresholdNotMet as u32, "ThresholdNotMet"),
             (ContractError::ProposalNotFound as u32, "ProposalNotFound"),
             (ContractError::MigrationCommitmentNotFound as u32, "MigrationCommitmentNotFound"),
@@ -178,6 +179,45 @@ mod tests {
             (ContractError::TimelockDelayTooHigh as u32, "TimelockDelayTooHigh"),
             (ContractError::SnapshotRestoreAdminPending as u32, "SnapshotRestoreAdminPending"),
             (ContractError::SnapshotPruned as u32, "SnapshotPruned"),
+            (ContractError::FunctionDisabled as u32, "FunctionDisabled"),
+            (ContractError::AttestationMissing as u32, "AttestationMissing"),
+            (ContractError::SchemaOutdated as u32, "SchemaOutdated"),
+            (ContractError::FeatureDisabled as u32, "FeatureDisabled"),
+            (ContractError::RevealMismatch as u32, "RevealMismatch"),
+            (ContractError::UpgradeNotRevealed as u32, "UpgradeNotRevealed"),
+            (ContractError::InvalidExecutionWindow as u32, "InvalidExecutionWindow"),
+            (ContractError::OutsideExecutionWindow as u32, "OutsideExecutionWindow"),
+            (ContractError::AmendmentLocked as u32, "AmendmentLocked"),
+            (ContractError::NotProposer as u32, "NotProposer"),
+            (ContractError::ProposalNotExpired as u32, "ProposalNotExpired"),
+            (ContractError::MetadataTooLong as u32, "MetadataTooLong"),
+            (ContractError::UnknownMetadataField as u32, "UnknownMetadataField"),
+            (ContractError::InvalidInitialVersion as u32, "InvalidInitialVersion"),
+            (ContractError::InvalidActionBundle as u32, "InvalidActionBundle"),
+            (ContractError::InvalidAdminNonce as u32, "InvalidAdminNonce"),
+            (ContractError::NonceRequired as u32, "NonceRequired"),
+            (ContractError::PostponementExceeded as u32, "PostponementExceeded"),
+            (ContractError::MaintenanceMode as u32, "MaintenanceMode"),
+            (ContractError::InvalidMaintenanceDeadline as u32, "InvalidMaintenanceDeadline"),
+            (ContractError::NetworkMismatch as u32, "NetworkMismatch"),
+            (ContractError::OutOfOrderUpgrade as u32, "OutOfOrderUpgrade"),
+            (ContractError::StagingRequired as u32, "StagingRequired"),
+            (ContractError::StagingDisabled as u32, "StagingDisabled"),
+            (ContractError::UpgradeAlreadyStaged as u32, "UpgradeAlreadyStaged"),
+            (ContractError::NoStagedUpgrade as u32, "NoStagedUpgrade"),
+            (ContractError::StagedHashMismatch as u32, "StagedHashMismatch"),
+            (ContractError::StagedUpgradeNotReady as u32, "StagedUpgradeNotReady"),
+            (ContractError::ApprovalMismatch as u32, "ApprovalMismatch"),
+            (ContractError::LegacyApprovalDisabled as u32, "LegacyApprovalDisabled"),
+            (ContractError::NotRelayer as u32, "NotRelayer"),
+            (ContractError::DescriptionTooLong as u32, "DescriptionTooLong"),
+            (ContractError::UrlTooLong as u32, "UrlTooLong"),
+            (ContractError::LabelTooLong as u32, "LabelTooLong"),
+            (ContractError::InvalidStringLimit as u32, "InvalidStringLimit"),
+            (ContractError::DuplicateExternalId as u32, "DuplicateExternalId"),
+            (ContractError::WrongGovernanceMode as u32, "WrongGovernanceMode"),
+            (ContractError::SelfReference as u32, "SelfReference"),
+            (ContractError::AlreadyApproved as u32, "AlreadyApproved"),
         ];
         for (code, name) in variants {
             assert!(
@@ -193,6 +233,7 @@ mod tests {
             (ContractError::AlreadyInitialized as u32, "AlreadyInitialized"),
             (ContractError::NotInitialized as u32, "NotInitialized"),
             (ContractError::NotAdmin as u32, "NotAdmin"),
+            (ContractError::Paused as u32, "Paused"),
             (ContractError::ThresholdNotMet as u32, "ThresholdNotMet"),
             (ContractError::ProposalNotFound as u32, "ProposalNotFound"),
             (ContractError::MigrationCommitmentNotFound as u32, "MigrationCommitmentNotFound"),
@@ -200,6 +241,45 @@ mod tests {
             (ContractError::TimelockDelayTooHigh as u32, "TimelockDelayTooHigh"),
             (ContractError::SnapshotRestoreAdminPending as u32, "SnapshotRestoreAdminPending"),
             (ContractError::SnapshotPruned as u32, "SnapshotPruned"),
+            (ContractError::FunctionDisabled as u32, "FunctionDisabled"),
+            (ContractError::AttestationMissing as u32, "AttestationMissing"),
+            (ContractError::SchemaOutdated as u32, "SchemaOutdated"),
+            (ContractError::FeatureDisabled as u32, "FeatureDisabled"),
+            (ContractError::RevealMismatch as u32, "RevealMismatch"),
+            (ContractError::UpgradeNotRevealed as u32, "UpgradeNotRevealed"),
+            (ContractError::InvalidExecutionWindow as u32, "InvalidExecutionWindow"),
+            (ContractError::OutsideExecutionWindow as u32, "OutsideExecutionWindow"),
+            (ContractError::AmendmentLocked as u32, "AmendmentLocked"),
+            (ContractError::NotProposer as u32, "NotProposer"),
+            (ContractError::ProposalNotExpired as u32, "ProposalNotExpired"),
+            (ContractError::MetadataTooLong as u32, "MetadataTooLong"),
+            (ContractError::UnknownMetadataField as u32, "UnknownMetadataField"),
+            (ContractError::InvalidInitialVersion as u32, "InvalidInitialVersion"),
+            (ContractError::InvalidActionBundle as u32, "InvalidActionBundle"),
+            (ContractError::InvalidAdminNonce as u32, "InvalidAdminNonce"),
+            (ContractError::NonceRequired as u32, "NonceRequired"),
+            (ContractError::PostponementExceeded as u32, "PostponementExceeded"),
+            (ContractError::MaintenanceMode as u32, "MaintenanceMode"),
+            (ContractError::InvalidMaintenanceDeadline as u32, "InvalidMaintenanceDeadline"),
+            (ContractError::NetworkMismatch as u32, "NetworkMismatch"),
+            (ContractError::OutOfOrderUpgrade as u32, "OutOfOrderUpgrade"),
+            (ContractError::StagingRequired as u32, "StagingRequired"),
+            (ContractError::StagingDisabled as u32, "StagingDisabled"),
+            (ContractError::UpgradeAlreadyStaged as u32, "UpgradeAlreadyStaged"),
+            (ContractError::NoStagedUpgrade as u32, "NoStagedUpgrade"),
+            (ContractError::StagedHashMismatch as u32, "StagedHashMismatch"),
+            (ContractError::StagedUpgradeNotReady as u32, "StagedUpgradeNotReady"),
+            (ContractError::ApprovalMismatch as u32, "ApprovalMismatch"),
+            (ContractError::LegacyApprovalDisabled as u32, "LegacyApprovalDisabled"),
+            (ContractError::NotRelayer as u32, "NotRelayer"),
+            (ContractError::DescriptionTooLong as u32, "DescriptionTooLong"),
+            (ContractError::UrlTooLong as u32, "UrlTooLong"),
+            (ContractError::LabelTooLong as u32, "LabelTooLong"),
+            (ContractError::InvalidStringLimit as u32, "InvalidStringLimit"),
+            (ContractError::DuplicateExternalId as u32, "DuplicateExternalId"),
+            (ContractError::WrongGovernanceMode as u32, "WrongGovernanceMode"),
+            (ContractError::SelfReference as u32, "SelfReference"),
+            (ContractError::AlreadyApproved as u32, "AlreadyApproved"),
         ];
         for (code, expected_name) in variants {
             assert_eq!(
@@ -216,6 +296,7 @@ mod tests {
             ContractError::AlreadyInitialized as u32,
             ContractError::NotInitialized as u32,
             ContractError::NotAdmin as u32,
+            ContractError::Paused as u32,
             ContractError::ThresholdNotMet as u32,
             ContractError::ProposalNotFound as u32,
             ContractError::MigrationCommitmentNotFound as u32,
@@ -223,6 +304,45 @@ mod tests {
             ContractError::TimelockDelayTooHigh as u32,
             ContractError::SnapshotRestoreAdminPending as u32,
             ContractError::SnapshotPruned as u32,
+            ContractError::FunctionDisabled as u32,
+            ContractError::AttestationMissing as u32,
+            ContractError::SchemaOutdated as u32,
+            ContractError::FeatureDisabled as u32,
+            ContractError::RevealMismatch as u32,
+            ContractError::UpgradeNotRevealed as u32,
+            ContractError::InvalidExecutionWindow as u32,
+            ContractError::OutsideExecutionWindow as u32,
+            ContractError::AmendmentLocked as u32,
+            ContractError::NotProposer as u32,
+            ContractError::ProposalNotExpired as u32,
+            ContractError::MetadataTooLong as u32,
+            ContractError::UnknownMetadataField as u32,
+            ContractError::InvalidInitialVersion as u32,
+            ContractError::InvalidActionBundle as u32,
+            ContractError::InvalidAdminNonce as u32,
+            ContractError::NonceRequired as u32,
+            ContractError::PostponementExceeded as u32,
+            ContractError::MaintenanceMode as u32,
+            ContractError::InvalidMaintenanceDeadline as u32,
+            ContractError::NetworkMismatch as u32,
+            ContractError::OutOfOrderUpgrade as u32,
+            ContractError::StagingRequired as u32,
+            ContractError::StagingDisabled as u32,
+            ContractError::UpgradeAlreadyStaged as u32,
+            ContractError::NoStagedUpgrade as u32,
+            ContractError::StagedHashMismatch as u32,
+            ContractError::StagedUpgradeNotReady as u32,
+            ContractError::ApprovalMismatch as u32,
+            ContractError::LegacyApprovalDisabled as u32,
+            ContractError::NotRelayer as u32,
+            ContractError::DescriptionTooLong as u32,
+            ContractError::UrlTooLong as u32,
+            ContractError::LabelTooLong as u32,
+            ContractError::InvalidStringLimit as u32,
+            ContractError::DuplicateExternalId as u32,
+            ContractError::WrongGovernanceMode as u32,
+            ContractError::SelfReference as u32,
+            ContractError::AlreadyApproved as u32,
         ];
         for i in 0..discriminants.len() {
             for j in (i + 1)..discriminants.len() {
@@ -240,7 +360,7 @@ mod tests {
         // The registry length must equal the number of ContractError variants.
         // If they diverge, a variant was added to the enum but not the registry
         // (or vice-versa).
-        let enum_count = 10; // update when ContractError grows
+        let enum_count = 50; // update when ContractError grows
         assert_eq!(
             registered_count(),
             enum_count,
@@ -488,6 +608,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::assertions_on_constants)]
     fn test_shared_constants_respect_range_boundaries() {
         // Spot-check that each constant falls inside its declared range.
         assert!(errors::ALREADY_INITIALIZED < 100, "common range: must be < 100");
@@ -623,6 +744,7 @@ mod tests {
             ContractError::AlreadyInitialized as u32,
             ContractError::NotInitialized as u32,
             ContractError::NotAdmin as u32,
+            ContractError::Paused as u32,
             ContractError::ThresholdNotMet as u32,
             ContractError::ProposalNotFound as u32,
             ContractError::MigrationCommitmentNotFound as u32,
@@ -630,6 +752,45 @@ mod tests {
             ContractError::TimelockDelayTooHigh as u32,
             ContractError::SnapshotRestoreAdminPending as u32,
             ContractError::SnapshotPruned as u32,
+            ContractError::FunctionDisabled as u32,
+            ContractError::AttestationMissing as u32,
+            ContractError::SchemaOutdated as u32,
+            ContractError::FeatureDisabled as u32,
+            ContractError::RevealMismatch as u32,
+            ContractError::UpgradeNotRevealed as u32,
+            ContractError::InvalidExecutionWindow as u32,
+            ContractError::OutsideExecutionWindow as u32,
+            ContractError::AmendmentLocked as u32,
+            ContractError::NotProposer as u32,
+            ContractError::ProposalNotExpired as u32,
+            ContractError::MetadataTooLong as u32,
+            ContractError::UnknownMetadataField as u32,
+            ContractError::InvalidInitialVersion as u32,
+            ContractError::InvalidActionBundle as u32,
+            ContractError::InvalidAdminNonce as u32,
+            ContractError::NonceRequired as u32,
+            ContractError::PostponementExceeded as u32,
+            ContractError::MaintenanceMode as u32,
+            ContractError::InvalidMaintenanceDeadline as u32,
+            ContractError::NetworkMismatch as u32,
+            ContractError::OutOfOrderUpgrade as u32,
+            ContractError::StagingRequired as u32,
+            ContractError::StagingDisabled as u32,
+            ContractError::UpgradeAlreadyStaged as u32,
+            ContractError::NoStagedUpgrade as u32,
+            ContractError::StagedHashMismatch as u32,
+            ContractError::StagedUpgradeNotReady as u32,
+            ContractError::ApprovalMismatch as u32,
+            ContractError::LegacyApprovalDisabled as u32,
+            ContractError::NotRelayer as u32,
+            ContractError::DescriptionTooLong as u32,
+            ContractError::UrlTooLong as u32,
+            ContractError::LabelTooLong as u32,
+            ContractError::InvalidStringLimit as u32,
+            ContractError::DuplicateExternalId as u32,
+            ContractError::WrongGovernanceMode as u32,
+            ContractError::SelfReference as u32,
+            ContractError::AlreadyApproved as u32,
         ];
         
         for code in contract_codes {
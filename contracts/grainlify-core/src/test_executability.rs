@@ -0,0 +1,113 @@
+//! # Executability Report Tests
+//!
+//! - `approvals_needed` counts down with each approval and clamps at 0
+//! - Approvals from removed signers stop counting
+//! - `can_execute_detailed` lists exactly the conditions blocking execution
+//!   and agrees with `execute_upgrade_v2`
+
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{testutils::Ledger as _, vec, Env, Symbol, Vec};
+
+use crate::testutils::{setup_multisig, upload_dummy_wasm};
+use crate::multisig::MultiSigConfig;
+use crate::{GrainlifyContractClient, MultiSig};
+
+fn blockers(env: &Env, names: &[&str]) -> Vec<Symbol> {
+    let mut out = Vec::new(env);
+    for name in names {
+        out.push_back(Symbol::new(env, name));
+    }
+    out
+}
+
+fn report_blockers(client: &GrainlifyContractClient, proposal_id: u64) -> Vec<Symbol> {
+    client.can_execute_detailed(&proposal_id).blockers
+}
+
+#[test]
+fn test_countdown_decreases_with_each_approval() {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 4, 3);
+    let proposal_id = client.propose_upgrade(&signers.get(0).unwrap(), &upload_dummy_wasm(&env, 1), &0);
+
+    assert_eq!(client.approvals_needed(&proposal_id), 3);
+    for (i, signer) in signers.iter().enumerate() {
+        client.approve_upgrade(&proposal_id, &signer);
+        assert_eq!(client.approvals_needed(&proposal_id), 2u32.saturating_sub(i as u32));
+    }
+}
+
+#[test]
+fn test_removed_signer_approval_stops_counting() {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    let proposal_id = client.propose_upgrade(&signers.get(0).unwrap(), &upload_dummy_wasm(&env, 1), &0);
+    client.approve_upgrade(&proposal_id, &signers.get(0).unwrap());
+    assert_eq!(client.approvals_needed(&proposal_id), 1);
+
+    let remaining = vec![&env, signers.get(1).unwrap(), signers.get(2).unwrap()];
+    env.as_contract(&client.address, || {
+        MultiSig::set_config(&env, MultiSigConfig { signers: remaining, threshold: 2 })
+    });
+    assert_eq!(client.approvals_needed(&proposal_id), 2);
+}
+
+#[test]
+fn test_report_tracks_blockers_through_lifecycle() {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    let proposal_id = client.propose_upgrade(&signers.get(0).unwrap(), &upload_dummy_wasm(&env, 1), &0);
+
+    assert_eq!(report_blockers(&client, proposal_id), blockers(&env, &["insufficient_approvals"]));
+
+    client.approve_upgrade(&proposal_id, &signers.get(0).unwrap());
+    client.approve_upgrade(&proposal_id, &signers.get(1).unwrap());
+    assert_eq!(report_blockers(&client, proposal_id), blockers(&env, &["timelock_pending"]));
+
+    client.pause(&signers.get(2).unwrap());
+    assert_eq!(
+        report_blockers(&client, proposal_id),
+        blockers(&env, &["timelock_pending", "paused"])
+    );
+    env.as_contract(&client.address, || MultiSig::set_paused(&env, false));
+
+    env.ledger().set_timestamp(client.get_timelock_delay());
+    let report = client.can_execute_detailed(&proposal_id);
+    assert!(report.executable);
+    assert_eq!(report.approvals_needed, 0);
+    assert!(client.try_execute_upgrade_v2(&proposal_id).is_ok());
+}
+
+#[test]
+fn test_closed_proposals_report_why() {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    let proposer = signers.get(0).unwrap();
+    let wasm_hash = upload_dummy_wasm(&env, 1);
+
+    let expiring = client.propose_upgrade(&proposer, &wasm_hash, &100);
+    let cancelled = client.propose_upgrade(&proposer, &wasm_hash, &0);
+    client.cancel_upgrade(&cancelled, &proposer);
+    env.ledger().set_timestamp(100);
+
+    assert_eq!(
+        report_blockers(&client, expiring),
+        blockers(&env, &["expired", "insufficient_approvals"])
+    );
+    assert_eq!(
+        report_blockers(&client, cancelled),
+        blockers(&env, &["cancelled", "insufficient_approvals"])
+    );
+    assert!(client.try_execute_upgrade_v2(&expiring).is_err());
+}
+
+#[test]
+fn test_unknown_proposal_rejected() {
+    let env = Env::default();
+    let (client, _) = setup_multisig(&env, 3, 2);
+    assert!(client.try_approvals_needed(&9).is_err());
+    assert!(client.try_can_execute_detailed(&9).is_err());
+}
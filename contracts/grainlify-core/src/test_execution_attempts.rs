@@ -0,0 +1,89 @@
+//! # Execution Attempt Tests
+//!
+//! - `attempt_execute_upgrade` records each failed attempt on the proposal:
+//!   the count, and the time, check and caller of the latest one
+//! - `get_upgrade_proposal` exposes the record; a plain `execute_upgrade`
+//!   failure reverts and leaves none
+//! - A passing attempt executes the upgrade
+
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events as _, Ledger as _},
+    Address, Env, IntoVal,
+};
+
+use crate::testutils::{setup_multisig, upload_dummy_wasm};
+use crate::ExecutionCheck;
+
+#[test]
+fn test_failed_attempts_are_recorded() {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    let prober = Address::generate(&env);
+    let proposal_id = client.propose_upgrade(&signers.get(0).unwrap(), &upload_dummy_wasm(&env, 1), &0);
+
+    env.ledger().set_timestamp(100);
+    assert_eq!(
+        client.attempt_execute_upgrade(&prober, &proposal_id),
+        Some(ExecutionCheck::TimelockNotStarted)
+    );
+
+    client.approve_upgrade(&proposal_id, &signers.get(0).unwrap());
+    client.approve_upgrade(&proposal_id, &signers.get(1).unwrap());
+    let caller = signers.get(2).unwrap();
+    env.ledger().set_timestamp(200);
+    assert_eq!(
+        client.attempt_execute_upgrade(&caller, &proposal_id),
+        Some(ExecutionCheck::TimelockPending)
+    );
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(topics, (symbol_short!("upgrade"), symbol_short!("exec_fail")).into_val(&env));
+    let data: (u64, Address, ExecutionCheck) = data.into_val(&env);
+    assert_eq!(data, (proposal_id, caller.clone(), ExecutionCheck::TimelockPending));
+
+    let attempts = client.get_upgrade_proposal(&proposal_id).unwrap().execution_attempts.get(0).unwrap();
+    assert_eq!(attempts.count, 2);
+    assert_eq!(attempts.last_failure, ExecutionCheck::TimelockPending);
+    assert_eq!(attempts.last_attempt_at, 200);
+    assert_eq!(attempts.last_caller, caller);
+}
+
+#[test]
+fn test_plain_execute_failure_leaves_no_record() {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    let proposal_id = client.propose_upgrade(&signers.get(0).unwrap(), &upload_dummy_wasm(&env, 1), &0);
+
+    assert!(client.try_execute_upgrade(&proposal_id).is_err());
+    assert_eq!(client.get_upgrade_proposal(&proposal_id).unwrap().execution_attempts.len(), 0);
+}
+
+#[test]
+#[should_panic(expected = "102")]
+fn test_unknown_proposal_is_not_recorded() {
+    let env = Env::default();
+    let (client, _) = setup_multisig(&env, 3, 2);
+    client.attempt_execute_upgrade(&Address::generate(&env), &7);
+}
+
+#[test]
+fn test_passing_attempt_executes() {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    let proposal_id = client.propose_upgrade(&signers.get(0).unwrap(), &upload_dummy_wasm(&env, 1), &0);
+    client.approve_upgrade(&proposal_id, &signers.get(0).unwrap());
+    client.approve_upgrade(&proposal_id, &signers.get(1).unwrap());
+    env.ledger().set_timestamp(client.get_timelock_delay());
+
+    assert_eq!(client.attempt_execute_upgrade(&signers.get(2).unwrap(), &proposal_id), None);
+    let executed = env
+        .events()
+        .all()
+        .iter()
+        .any(|(_, topics, _)| topics == (symbol_short!("upgrade"), symbol_short!("executed")).into_val(&env));
+    assert!(executed);
+}
@@ -0,0 +1,202 @@
+//! # Execution Preview Tests
+//!
+//! Every case compares `preview_execution` with what `execute_upgrade`
+//! actually does at the same moment:
+//! - Missing approvals, pending timelock, unopened window, expiry, pause,
+//!   missing attestations, and an unrevealed commitment each show up as the
+//!   matching `ExecutionCheck` and block execution
+//! - A proposal that previews clean executes, installing the previewed hash
+//! - Token-voting mode reports `VoteNotPassed` until the vote passes, then
+//!   `TimelockPending` until the timelock after the window elapses
+//! - Unknown proposals are rejected
+
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger as _},
+    vec, Address, Bytes, BytesN, Env, Vec,
+};
+
+use crate::test_token_voting::mint_holders;
+use crate::testutils::{setup_admin, setup_admin_and_multisig, setup_multisig, upload_dummy_wasm};
+use crate::{
+    AttestationConfig, ExecutionCheck, ExecutionPreview, GrainlifyContractClient, RevealDeadline,
+    TokenVotingConfig,
+};
+
+fn approve_all(client: &GrainlifyContractClient, proposal_id: u64, signers: &Vec<Address>) {
+    client.approve_upgrade(&proposal_id, &signers.get(0).unwrap());
+    client.approve_upgrade(&proposal_id, &signers.get(1).unwrap());
+}
+
+fn wait_timelock(env: &Env, client: &GrainlifyContractClient) {
+    env.ledger()
+        .set_timestamp(env.ledger().timestamp() + client.get_timelock_delay());
+}
+
+/// Asserts the preview lists exactly `expected` and agrees with the outcome
+/// of executing now. Execution failures roll back, so callers can keep going.
+fn assert_preview_matches(
+    client: &GrainlifyContractClient,
+    proposal_id: u64,
+    expected: &[ExecutionCheck],
+) -> ExecutionPreview {
+    let preview = client.preview_execution(&proposal_id);
+    let failing: std::vec::Vec<ExecutionCheck> = preview.failing.iter().collect();
+    assert_eq!(failing.as_slice(), expected);
+    assert_eq!(preview.executable, expected.is_empty());
+    if !preview.executable {
+        assert!(client.try_execute_upgrade(&proposal_id).is_err());
+    }
+    preview
+}
+
+#[test]
+fn test_preview_tracks_approval_and_timelock() {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    let wasm = upload_dummy_wasm(&env, 1);
+    let proposal_id = client.propose_upgrade(&signers.get(0).unwrap(), &wasm, &0);
+
+    let preview = assert_preview_matches(
+        &client,
+        proposal_id,
+        &[ExecutionCheck::TimelockNotStarted, ExecutionCheck::NotExecutable],
+    );
+    assert_eq!((preview.wasm_hash, preview.revealed), (wasm.clone(), true));
+    assert!(preview.attested);
+    assert_eq!(preview.timelock_remaining, None);
+
+    approve_all(&client, proposal_id, &signers);
+    let preview = assert_preview_matches(&client, proposal_id, &[ExecutionCheck::TimelockPending]);
+    assert_eq!(preview.timelock_remaining, Some(client.get_timelock_delay()));
+
+    wait_timelock(&env, &client);
+    let preview = assert_preview_matches(&client, proposal_id, &[]);
+    assert_eq!(preview.timelock_remaining, Some(0));
+    let receipt = client.execute_upgrade_v2(&proposal_id);
+    assert_eq!(receipt.wasm_hash, preview.wasm_hash);
+    assert_eq!(receipt.new_version, preview.new_version);
+}
+
+#[test]
+fn test_preview_reports_window_and_expiry() {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    let wasm = upload_dummy_wasm(&env, 1);
+    let delay = client.get_timelock_delay();
+    let proposer = signers.get(0).unwrap();
+
+    let windowed = client.propose_upgrade_in_window(&proposer, &wasm, &0, &Some(delay * 3), &None);
+    let expiring = client.propose_upgrade(&proposer, &wasm, &(delay * 2));
+    approve_all(&client, windowed, &signers);
+    approve_all(&client, expiring, &signers);
+    wait_timelock(&env, &client);
+
+    // `expiring` is newer and approved, so `windowed` is also out of order.
+    assert_preview_matches(
+        &client,
+        windowed,
+        &[ExecutionCheck::OutsideWindow, ExecutionCheck::OutOfOrder],
+    );
+    assert_preview_matches(&client, expiring, &[]);
+
+    env.ledger().set_timestamp(delay * 3);
+    assert_preview_matches(&client, expiring, &[ExecutionCheck::NotExecutable]);
+    assert_preview_matches(&client, windowed, &[]);
+    client.execute_upgrade(&windowed);
+}
+
+#[test]
+fn test_preview_reports_pause_and_attestations() {
+    let env = Env::default();
+    let (client, signers) = setup_admin_and_multisig(&env);
+    let wasm = upload_dummy_wasm(&env, 1);
+    let proposal_id = client.propose_upgrade(&signers.get(0).unwrap(), &wasm, &0);
+    let auditor = Address::generate(&env);
+    client.set_attestation_config(&AttestationConfig {
+        require_attestation: true,
+        auditors: vec![&env, auditor.clone()],
+        min_attestations: 1,
+    });
+    approve_all(&client, proposal_id, &signers);
+    wait_timelock(&env, &client);
+
+    let preview = assert_preview_matches(&client, proposal_id, &[ExecutionCheck::AttestationMissing]);
+    assert!(!preview.attested);
+
+    client.attest_wasm(&wasm, &auditor, &BytesN::from_array(&env, &[7; 32]));
+    client.pause(&signers.get(2).unwrap());
+    assert_preview_matches(
+        &client,
+        proposal_id,
+        &[ExecutionCheck::Paused, ExecutionCheck::NotExecutable],
+    );
+
+    client.set_read_only_mode(&true);
+    assert_preview_matches(
+        &client,
+        proposal_id,
+        &[ExecutionCheck::ReadOnly, ExecutionCheck::Paused, ExecutionCheck::NotExecutable],
+    );
+}
+
+#[test]
+fn test_preview_reports_unrevealed_commitment() {
+    let env = Env::default();
+    let (client, signers) = setup_admin_and_multisig(&env);
+    client.set_reveal_deadline(&RevealDeadline::BeforeExecution);
+    let wasm = upload_dummy_wasm(&env, 1);
+    let salt = Bytes::from_array(&env, &[7; 16]);
+    let mut data = Bytes::from(wasm.clone());
+    data.append(&salt);
+    let commitment: BytesN<32> = env.crypto().sha256(&data).into();
+
+    let proposal_id = client.propose_upgrade_commit(&signers.get(0).unwrap(), &commitment, &0);
+    approve_all(&client, proposal_id, &signers);
+    wait_timelock(&env, &client);
+
+    let preview = assert_preview_matches(&client, proposal_id, &[ExecutionCheck::NotRevealed]);
+    assert_eq!((preview.wasm_hash, preview.revealed), (commitment, false));
+    assert!(!preview.attested);
+
+    client.reveal_upgrade(&proposal_id, &wasm, &salt);
+    assert_preview_matches(&client, proposal_id, &[]);
+    client.execute_upgrade(&proposal_id);
+}
+
+#[test]
+fn test_preview_in_token_voting_mode() {
+    let env = Env::default();
+    let (client, _admin) = setup_admin(&env);
+    let holders = [Address::generate(&env), Address::generate(&env), Address::generate(&env)];
+    let token = mint_holders(&env, &holders);
+    client.set_token_voting_config(&Some(TokenVotingConfig {
+        token: token.address.clone(),
+        voting_period: 3_600,
+        quorum_bps: 4_000,
+        threshold_bps: 5_000,
+    }));
+    let holder = holders[0].clone();
+
+    let wasm = upload_dummy_wasm(&env, 1);
+    let proposal_id = client.propose_upgrade(&holder, &wasm, &0);
+    client.vote(&proposal_id, &holder, &true);
+    let preview = assert_preview_matches(&client, proposal_id, &[ExecutionCheck::VoteNotPassed]);
+    assert_eq!(preview.wasm_hash, wasm);
+
+    env.ledger().set_timestamp(3_600);
+    assert_preview_matches(&client, proposal_id, &[ExecutionCheck::TimelockPending]);
+    wait_timelock(&env, &client);
+    assert_preview_matches(&client, proposal_id, &[]);
+    client.execute_upgrade(&proposal_id);
+}
+
+#[test]
+fn test_preview_unknown_proposal_rejected() {
+    let env = Env::default();
+    let (client, _signers) = setup_multisig(&env, 3, 2);
+    assert!(client.try_preview_execution(&7).is_err());
+}
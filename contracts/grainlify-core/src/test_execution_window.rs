@@ -0,0 +1,106 @@
+//! # Execution Window Tests
+//!
+//! - `propose_upgrade_in_window` rejects bounds that are out of order, in
+//!   the past, or that open after the proposal's expiry
+//! - Execution fails before `not_before`, succeeds inside the window, and
+//!   fails once `not_after` passes, when the proposal reads as expired
+//! - `get_upgrade_proposal` surfaces the window; plain proposals have none
+
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{testutils::Ledger as _, Address, BytesN, Env, Vec};
+
+use crate::testutils::{setup_multisig, upload_dummy_wasm};
+use crate::{GrainlifyContractClient, MultiSig};
+
+/// Proposes `wasm_hash` with window `[start, end)` and approves it at the
+/// current time, starting the timelock.
+fn propose_and_approve(
+    client: &GrainlifyContractClient,
+    signers: &Vec<Address>,
+    wasm_hash: &BytesN<32>,
+    start: u64,
+    end: u64,
+) -> u64 {
+    let proposer = signers.get(0).unwrap();
+    let proposal_id =
+        client.propose_upgrade_in_window(&proposer, wasm_hash, &0, &Some(start), &Some(end));
+    client.approve_upgrade(&proposal_id, &proposer);
+    client.approve_upgrade(&proposal_id, &signers.get(1).unwrap());
+    proposal_id
+}
+
+#[test]
+fn test_invalid_windows_are_rejected() {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    let proposer = signers.get(0).unwrap();
+    let wasm_hash = upload_dummy_wasm(&env, 1);
+    env.ledger().set_timestamp(1_000);
+
+    let propose = |expiry: u64, not_before: Option<u64>, not_after: Option<u64>| {
+        client.try_propose_upgrade_in_window(&proposer, &wasm_hash, &expiry, &not_before, &not_after)
+    };
+    assert!(propose(0, Some(3_000), Some(2_000)).is_err());
+    assert!(propose(0, Some(2_000), Some(2_000)).is_err());
+    assert!(propose(0, Some(1_000), Some(2_000)).is_err());
+    assert!(propose(0, None, Some(500)).is_err());
+    assert!(propose(1_500, Some(2_000), None).is_err());
+
+    assert!(propose(0, Some(2_000), None).is_ok());
+    assert!(propose(0, None, Some(2_000)).is_ok());
+}
+
+#[test]
+fn test_execution_only_inside_window() {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    let delay = client.get_timelock_delay();
+    let wasm_hash = upload_dummy_wasm(&env, 1);
+    let proposal_id =
+        propose_and_approve(&client, &signers, &wasm_hash, delay + 100, delay + 200);
+
+    // Timelock elapsed, window not open yet.
+    env.ledger().set_timestamp(delay + 50);
+    assert!(client.try_execute_upgrade(&proposal_id).is_err());
+
+    env.ledger().set_timestamp(delay + 150);
+    assert_eq!(client.execute_upgrade_v2(&proposal_id).wasm_hash, wasm_hash);
+}
+
+#[test]
+fn test_window_passed_expires_proposal() {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    let delay = client.get_timelock_delay();
+    let wasm_hash = upload_dummy_wasm(&env, 1);
+    let proposal_id =
+        propose_and_approve(&client, &signers, &wasm_hash, delay + 100, delay + 200);
+
+    env.ledger().set_timestamp(delay + 200);
+    assert!(client.try_execute_upgrade(&proposal_id).is_err());
+    env.as_contract(&client.address, || assert!(MultiSig::is_expired(&env, proposal_id)));
+}
+
+#[test]
+fn test_record_surfaces_window() {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    let proposer = signers.get(0).unwrap();
+    let wasm_hash = upload_dummy_wasm(&env, 1);
+
+    let windowed = client.propose_upgrade_in_window(&proposer, &wasm_hash, &0, &Some(100), &Some(200));
+    let record = client.get_upgrade_proposal(&windowed).unwrap();
+    assert_eq!((record.not_before, record.not_after), (Some(100), Some(200)));
+    assert_eq!(record.expiry, 200);
+
+    // An earlier explicit expiry wins over not_after.
+    let early = client.propose_upgrade_in_window(&proposer, &wasm_hash, &150, &None, &Some(200));
+    assert_eq!(client.get_upgrade_proposal(&early).unwrap().expiry, 150);
+
+    let plain = client.propose_upgrade(&proposer, &wasm_hash, &0);
+    let record = client.get_upgrade_proposal(&plain).unwrap();
+    assert_eq!((record.not_before, record.not_after), (None, None));
+}
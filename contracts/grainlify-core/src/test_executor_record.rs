@@ -0,0 +1,96 @@
+//! # Executor Record Tests
+//!
+//! - `execute_upgrade_as` requires the executor's auth and records the
+//!   executor and ledger sequence in the history and the archive
+//! - Execution without an executor records `None`
+//! - The `("upgrade", "executed")` event carries the executor
+
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events as _, Ledger as _},
+    Address, Env, IntoVal, TryFromVal,
+};
+
+use crate::testutils::{setup_multisig, upload_dummy_wasm};
+use crate::{GrainlifyContract, GrainlifyContractClient, UpgradeExecutedEvent};
+
+fn approved_proposal(env: &Env, client: &GrainlifyContractClient, proposer: &Address, approver: &Address) -> u64 {
+    let proposal_id = client.propose_upgrade(proposer, &upload_dummy_wasm(env, 1), &0);
+    client.approve_upgrade(&proposal_id, proposer);
+    client.approve_upgrade(&proposal_id, approver);
+    env.ledger().set_timestamp(client.get_timelock_delay());
+    env.ledger().set_sequence_number(77);
+    proposal_id
+}
+
+#[test]
+fn test_executor_recorded_in_history_and_archive() {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    let proposer = signers.get(0).unwrap();
+    let proposal_id = approved_proposal(&env, &client, &proposer, &signers.get(1).unwrap());
+    let executor = Address::generate(&env);
+
+    client.execute_upgrade_as(&executor, &proposal_id, &false);
+    let (authorized, _) = env.auths().into_iter().next().unwrap();
+    assert_eq!(authorized, executor);
+
+    let (executed, archived) = env.as_contract(&client.address, || {
+        (
+            GrainlifyContract::get_executed_upgrade(env.clone(), proposal_id).unwrap(),
+            GrainlifyContract::get_archived_upgrade(env.clone(), 0).unwrap(),
+        )
+    });
+    assert_eq!(executed.executor, Some(executor.clone()));
+    assert_ne!(executed.executor, Some(proposer));
+    assert_eq!(executed.ledger_sequence, 77);
+    assert_eq!(executed.executed_at, Some(env.ledger().timestamp()));
+    assert_eq!(archived.executor, Some(executor));
+    assert_eq!(archived.ledger_sequence, 77);
+}
+
+#[test]
+fn test_execution_event_carries_executor() {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    let proposal_id = approved_proposal(&env, &client, &signers.get(0).unwrap(), &signers.get(1).unwrap());
+    let executor = Address::generate(&env);
+
+    client.execute_upgrade_as(&executor, &proposal_id, &false);
+    let topics = (symbol_short!("upgrade"), symbol_short!("executed")).into_val(&env);
+    let (_, _, data) = env.events().all().iter().find(|(_, t, _)| *t == topics).unwrap();
+    let event = UpgradeExecutedEvent::try_from_val(&env, &data).unwrap();
+    assert_eq!(event.proposal_id, proposal_id);
+    assert_eq!(event.executor, Some(executor));
+    assert_eq!(event.ledger_sequence, 77);
+}
+
+#[test]
+fn test_execution_without_executor_records_none() {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    let proposal_id = approved_proposal(&env, &client, &signers.get(0).unwrap(), &signers.get(1).unwrap());
+
+    client.execute_upgrade_v2(&proposal_id);
+    let executed = env.as_contract(&client.address, || {
+        GrainlifyContract::get_executed_upgrade(env.clone(), proposal_id).unwrap()
+    });
+    assert_eq!(executed.executor, None);
+    assert_eq!(executed.ledger_sequence, 0);
+}
+
+#[test]
+fn test_execute_as_requires_executor_auth() {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    let proposal_id = approved_proposal(&env, &client, &signers.get(0).unwrap(), &signers.get(1).unwrap());
+
+    env.set_auths(&[]);
+    assert!(client
+        .try_execute_upgrade_as(&Address::generate(&env), &proposal_id, &false)
+        .is_err());
+}
@@ -0,0 +1,81 @@
+//! # Expedited Execution Tests
+//!
+//! - A proposal approved by every signer executes through `expedite` before
+//!   its timelock, and the archive and `("upgrade", "executed")` event
+//!   record it as expedited
+//! - Threshold-only approval, or a pending signer invitation, fails with
+//!   `ExecutionError::NotUnanimous`
+//! - Executions after the timelock are not marked expedited
+
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events as _},
+    Address, Env, IntoVal, TryFromVal, Vec,
+};
+
+use crate::testutils::{setup_admin_and_multisig, upload_dummy_wasm, GovernanceDriver};
+use crate::{GrainlifyContract, GrainlifyContractClient, UpgradeExecutedEvent};
+
+/// Proposes an upgrade approved by the first `approvers` signers.
+fn approved_by(env: &Env, client: &GrainlifyContractClient, signers: &Vec<Address>, approvers: u32) -> u64 {
+    let proposal_id = GovernanceDriver::propose(env, client, signers, &upload_dummy_wasm(env, 1));
+    for signer in signers.iter().take(approvers as usize) {
+        client.approve_upgrade(&proposal_id, &signer);
+    }
+    proposal_id
+}
+
+fn archived_expedited(env: &Env, client: &GrainlifyContractClient) -> bool {
+    env.as_contract(&client.address, || {
+        GrainlifyContract::get_archived_upgrade(env.clone(), 0).unwrap().expedited
+    })
+}
+
+#[test]
+fn test_unanimous_approval_skips_timelock() {
+    let env = Env::default();
+    let (client, signers) = setup_admin_and_multisig(&env);
+    let proposal_id = approved_by(&env, &client, &signers, signers.len());
+    assert!(client.time_until_executable(&proposal_id) > 0);
+
+    let receipt = client.expedite(&proposal_id);
+    assert_eq!(receipt.proposal_id, proposal_id);
+
+    let topics = (symbol_short!("upgrade"), symbol_short!("executed")).into_val(&env);
+    let (_, _, data) = env.events().all().iter().find(|(_, t, _)| *t == topics).unwrap();
+    assert!(UpgradeExecutedEvent::try_from_val(&env, &data).unwrap().expedited);
+    assert!(archived_expedited(&env, &client));
+}
+
+#[test]
+#[should_panic(expected = "161")]
+fn test_threshold_approval_rejected() {
+    let env = Env::default();
+    let (client, signers) = setup_admin_and_multisig(&env);
+    let proposal_id = approved_by(&env, &client, &signers, 2);
+    client.expedite(&proposal_id);
+}
+
+#[test]
+#[should_panic(expected = "161")]
+fn test_pending_signer_blocks_unanimity() {
+    let env = Env::default();
+    let (client, signers) = setup_admin_and_multisig(&env);
+    let proposal_id = approved_by(&env, &client, &signers, signers.len());
+    client.add_signer(&Address::generate(&env));
+    client.expedite(&proposal_id);
+}
+
+#[test]
+fn test_timelocked_execution_not_expedited() {
+    let env = Env::default();
+    let (client, signers) = setup_admin_and_multisig(&env);
+    let proposal_id = approved_by(&env, &client, &signers, signers.len());
+    GovernanceDriver::skip_timelock(&env, &client);
+    client.execute_upgrade_v2(&proposal_id);
+    assert!(!archived_expedited(&env, &client));
+}
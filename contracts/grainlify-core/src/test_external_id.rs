@@ -0,0 +1,100 @@
+//! # External ID Tests
+//!
+//! - `propose_upgrade_with_external_id` links a proposal to a release ID that
+//!   `find_proposal_by_external_id` and `get_upgrade_proposal` return
+//! - A pending proposal blocks reuse of its ID with `DuplicateExternalId`;
+//!   once it is cancelled or executed the ID moves to the new proposal
+//! - The link and execution events carry the ID
+
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Events as _, Ledger as _},
+    Address, Env, IntoVal, Symbol, TryFromVal, Val, Vec,
+};
+
+use crate::testutils::{setup_multisig, upload_dummy_wasm};
+use crate::{GrainlifyContractClient, ProposalLinkedEvent, UpgradeExecutedEvent};
+
+const RELEASE: u64 = 20_261_016_001;
+
+fn last_event<T: TryFromVal<Env, Val>>(env: &Env, action: &str) -> Option<T> {
+    let topics = (symbol_short!("upgrade"), Symbol::new(env, action)).into_val(env);
+    let mut found = None;
+    for (_, t, data) in env.events().all().iter() {
+        if t == topics {
+            found = T::try_from_val(env, &data).ok();
+        }
+    }
+    found
+}
+
+fn propose(env: &Env, client: &GrainlifyContractClient, signers: &Vec<Address>, seed: u8) -> u64 {
+    client.propose_upgrade_with_external_id(
+        &signers.get(0).unwrap(),
+        &upload_dummy_wasm(env, seed),
+        &RELEASE,
+        &0,
+    )
+}
+
+#[test]
+fn test_lookup_by_external_id() {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    let proposal_id = propose(&env, &client, &signers, 1);
+
+    assert_eq!(client.find_proposal_by_external_id(&RELEASE), Some(proposal_id));
+    assert_eq!(client.get_upgrade_proposal(&proposal_id).unwrap().external_id, Some(RELEASE));
+
+    let linked: ProposalLinkedEvent = last_event(&env, "linked").unwrap();
+    assert_eq!((linked.proposal_id, linked.external_id), (proposal_id, RELEASE));
+}
+
+#[test]
+fn test_unlinked_and_unknown_ids_are_absent() {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    let plain = client.propose_upgrade(&signers.get(0).unwrap(), &upload_dummy_wasm(&env, 1), &0);
+
+    assert_eq!(client.get_upgrade_proposal(&plain).unwrap().external_id, None);
+    assert_eq!(client.find_proposal_by_external_id(&RELEASE), None);
+}
+
+#[test]
+#[should_panic(expected = "143")]
+fn test_duplicate_pending_external_id_rejected() {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    propose(&env, &client, &signers, 1);
+    propose(&env, &client, &signers, 2);
+}
+
+#[test]
+fn test_external_id_reusable_after_close() {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    let first = propose(&env, &client, &signers, 1);
+    client.cancel_upgrade(&first, &signers.get(0).unwrap());
+
+    let second = propose(&env, &client, &signers, 2);
+    assert_eq!(client.find_proposal_by_external_id(&RELEASE), Some(second));
+    assert_eq!(client.get_upgrade_proposal(&first).unwrap().external_id, Some(RELEASE));
+}
+
+#[test]
+fn test_execution_event_carries_external_id() {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    let proposal_id = propose(&env, &client, &signers, 1);
+    client.approve_upgrade(&proposal_id, &signers.get(0).unwrap());
+    client.approve_upgrade(&proposal_id, &signers.get(1).unwrap());
+    env.ledger().set_timestamp(client.get_timelock_delay());
+    client.execute_upgrade(&proposal_id);
+
+    let executed: UpgradeExecutedEvent = last_event(&env, "executed").unwrap();
+    assert_eq!(executed.external_id, Some(RELEASE));
+}
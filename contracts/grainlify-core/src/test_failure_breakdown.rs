@@ -0,0 +1,124 @@
+//! # Failure Breakdown Tests
+//!
+//! - Failures recorded with an error code are counted per operation and
+//!   per code; uncoded failures only reach the global error count
+//! - Fleet children that fail are counted under `fleet_upg` with the
+//!   child's contract error code, or 0 when it trapped without one
+//! - The global error count covers every failure
+
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{
+    symbol_short,
+    testutils::Ledger as _,
+    vec, Address, BytesN, Env, Symbol,
+};
+
+use crate::monitoring;
+use crate::testutils::setup_admin_and_multisig;
+use crate::GrainlifyContractClient;
+
+mod rejecting_child {
+    use soroban_sdk::{contract, contracterror, contractimpl, panic_with_error, BytesN, Env};
+
+    #[contracterror]
+    #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+    #[repr(u32)]
+    pub enum ChildError {
+        Rejected = 7,
+    }
+
+    #[contract]
+    pub struct RejectingChild;
+
+    #[contractimpl]
+    impl RejectingChild {
+        pub fn upgrade(env: Env, _new_wasm_hash: BytesN<32>) {
+            panic_with_error!(&env, ChildError::Rejected);
+        }
+    }
+}
+
+mod trapping_child {
+    use soroban_sdk::{contract, contractimpl, BytesN, Env};
+
+    #[contract]
+    pub struct TrappingChild;
+
+    #[contractimpl]
+    impl TrappingChild {
+        pub fn upgrade(_env: Env, _new_wasm_hash: BytesN<32>) {
+            panic!("upgrade rejected");
+        }
+    }
+}
+
+/// Admin-governed contract, so the admin can register fleet children,
+/// with the first two signers of its 2-of-3 multisig.
+fn setup(env: &Env) -> (GrainlifyContractClient<'_>, [Address; 2]) {
+    let (client, signers) = setup_admin_and_multisig(env);
+    (client, [signers.get(0).unwrap(), signers.get(1).unwrap()])
+}
+
+#[test]
+fn test_fleet_child_failures_are_broken_down() {
+    let env = Env::default();
+    let (client, signers) = setup(&env);
+    let rejecting = env.register_contract(None, rejecting_child::RejectingChild);
+    let trapping = env.register_contract(None, trapping_child::TrappingChild);
+    client.register_child(&rejecting);
+    client.register_child(&trapping);
+
+    let wasm = BytesN::from_array(&env, &[0xCD; 32]);
+    let proposal_id =
+        client.propose_fleet_upgrade(&signers[0], &wasm, &vec![&env, rejecting, trapping], &0u64);
+    client.approve_fleet_upgrade(&proposal_id, &signers[0]);
+    client.approve_fleet_upgrade(&proposal_id, &signers[1]);
+    env.ledger().set_timestamp(client.get_timelock_delay());
+    client.execute_fleet_upgrade(&proposal_id);
+
+    let breakdown = client.get_failure_breakdown();
+    assert_eq!(breakdown.by_function.get(symbol_short!("fleet_upg")), Some(2));
+    assert_eq!(breakdown.by_code.get(7), Some(1));
+    assert_eq!(breakdown.by_code.get(0), Some(1));
+    assert_eq!(breakdown.total_errors, 2);
+}
+
+#[test]
+fn test_uncoded_failures_only_count_globally() {
+    let env = Env::default();
+    let (client, signers) = setup(&env);
+    env.as_contract(&client.address, || {
+        monitoring::track(&env, symbol_short!("approve"), signers[0].clone(), false, None, true);
+        monitoring::track(&env, symbol_short!("approve"), signers[0].clone(), true, None, true);
+        monitoring::track_failure(&env, symbol_short!("execute"), signers[1].clone(), 129, false);
+    });
+
+    let breakdown = client.get_failure_breakdown();
+    assert_eq!(breakdown.by_function.get(symbol_short!("approve")), None);
+    assert_eq!(breakdown.by_function.get(symbol_short!("execute")), Some(1));
+    assert_eq!(breakdown.by_code.len(), 1);
+    assert_eq!(breakdown.by_code.get(129), Some(1));
+    assert_eq!(breakdown.total_errors, 2);
+}
+
+#[test]
+fn test_function_map_is_bounded() {
+    let env = Env::default();
+    let (client, signers) = setup(&env);
+    env.as_contract(&client.address, || {
+        for i in 0..=monitoring::MAX_TRACKED_FUNCTIONS {
+            let operation = Symbol::new(&env, &std::format!("op_{i}"));
+            monitoring::track_failure(&env, operation, signers[0].clone(), 1, false);
+        }
+    });
+
+    let breakdown = client.get_failure_breakdown();
+    assert_eq!(breakdown.by_function.len(), monitoring::MAX_TRACKED_FUNCTIONS);
+    assert_eq!(
+        breakdown.total_errors,
+        monitoring::MAX_TRACKED_FUNCTIONS as u64 + 1
+    );
+}
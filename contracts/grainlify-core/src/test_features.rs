@@ -0,0 +1,63 @@
+//! # Feature Discovery Tests
+//!
+//! - `get_abi_version` reports `ABI_VERSION`
+//! - `get_features` follows governance configuration as it changes
+//! - `get_contract_info` carries both
+
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{symbol_short, testutils::Address as _, Address, Env};
+
+use crate::testutils::{setup_admin, setup_multisig};
+use crate::ABI_VERSION;
+
+#[test]
+fn test_abi_version() {
+    let env = Env::default();
+    let (client, _) = setup_admin(&env);
+    assert_eq!(client.get_abi_version(), ABI_VERSION);
+}
+
+#[test]
+fn test_features_follow_configuration() {
+    let env = Env::default();
+    let (client, _) = setup_admin(&env);
+    let features = client.get_features();
+    assert!(!features.contains(symbol_short!("staging")));
+    assert!(!features.contains(symbol_short!("relayer")));
+
+    client.set_staging_delay(&Some(3_600));
+    client.set_relayer(&Address::generate(&env));
+    let features = client.get_features();
+    assert!(features.contains(symbol_short!("staging")));
+    assert!(features.contains(symbol_short!("relayer")));
+
+    client.set_staging_delay(&None);
+    client.clear_relayer();
+    let features = client.get_features();
+    assert!(!features.contains(symbol_short!("staging")));
+    assert!(!features.contains(symbol_short!("relayer")));
+}
+
+#[test]
+fn test_multisig_reports_timelock() {
+    let env = Env::default();
+    let (client, _) = setup_multisig(&env, 3, 2);
+    let features = client.get_features();
+    assert!(features.contains(symbol_short!("multisig")));
+    assert_eq!(features.contains(symbol_short!("timelock")), client.get_timelock_delay() > 0);
+}
+
+#[test]
+fn test_contract_info_includes_capabilities() {
+    let env = Env::default();
+    let (client, _) = setup_admin(&env);
+    client.set_require_nonce(&true);
+
+    let info = client.get_contract_info();
+    assert_eq!(info.abi_version, ABI_VERSION);
+    assert_eq!(info.features, client.get_features());
+    assert!(info.features.contains(symbol_short!("nonce")));
+}
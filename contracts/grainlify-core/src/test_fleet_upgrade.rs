@@ -0,0 +1,306 @@
+//! # Fleet Upgrade Tests
+//!
+//! - One threshold-approved proposal upgrades every listed child
+//! - Children authorize the call because this contract is their admin
+//! - A failing child is reported without aborting the rest of the fleet
+//! - Proposals may only target registered, distinct children
+//! - Multisig governance manages the child registry through
+//!   `RegisterChild`/`UnregisterChild` actions; no single signer can
+//! - A blocked hash cannot be proposed and an unattested one cannot be
+//!   sent to the children
+
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{
+    testutils::{Address as _, Events, Ledger as _},
+    vec, Address, BytesN, Env, String, Symbol, TryFromVal, Vec,
+};
+
+use crate::testutils::{setup_admin, setup_multisig, GovernanceDriver};
+use crate::{AttestationConfig, FleetChildResult, FleetUpgradeEvent, GrainlifyContractClient, ProposalAction};
+
+mod mock_child {
+    use soroban_sdk::{contract, contractimpl, symbol_short, Address, BytesN, Env};
+
+    /// Upgradeable child that records the hash instead of swapping its WASM.
+    #[contract]
+    pub struct MockChild;
+
+    #[contractimpl]
+    impl MockChild {
+        pub fn init(env: Env, admin: Address) {
+            env.storage().instance().set(&symbol_short!("admin"), &admin);
+        }
+
+        pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) {
+            let admin: Address = env.storage().instance().get(&symbol_short!("admin")).unwrap();
+            admin.require_auth();
+            env.storage().instance().set(&symbol_short!("wasm"), &new_wasm_hash);
+        }
+
+        pub fn installed(env: Env) -> Option<BytesN<32>> {
+            env.storage().instance().get(&symbol_short!("wasm"))
+        }
+    }
+}
+
+mod broken_child {
+    use soroban_sdk::{contract, contractimpl, BytesN, Env};
+
+    #[contract]
+    pub struct BrokenChild;
+
+    #[contractimpl]
+    impl BrokenChild {
+        pub fn upgrade(_env: Env, _new_wasm_hash: BytesN<32>) {
+            panic!("upgrade rejected");
+        }
+    }
+}
+
+use broken_child::BrokenChild;
+use mock_child::{MockChild, MockChildClient};
+
+/// Contract created by `init` with a 2-of-3 multisig; returns all signers.
+fn setup(env: &Env) -> (GrainlifyContractClient<'_>, Vec<Address>) {
+    setup_multisig(env, 3, 2)
+}
+
+/// Registers `children` through an approved `RegisterChild` bundle.
+fn register(env: &Env, client: &GrainlifyContractClient, signers: &Vec<Address>, children: &[&Address]) {
+    let mut actions = Vec::new(env);
+    for child in children {
+        actions.push_back(ProposalAction::RegisterChild((*child).clone()));
+    }
+    GovernanceDriver::apply_actions(env, client, signers, actions);
+}
+
+fn child_of<'a>(env: &'a Env, admin: &Address) -> MockChildClient<'a> {
+    let child = MockChildClient::new(env, &env.register_contract(None, MockChild));
+    child.init(admin);
+    child
+}
+
+fn wasm(env: &Env) -> BytesN<32> {
+    BytesN::from_array(env, &[0xCD; 32])
+}
+
+/// Proposes, approves with both signers, and waits out the timelock.
+fn approved_fleet_proposal(
+    env: &Env,
+    client: &GrainlifyContractClient,
+    signers: &Vec<Address>,
+    children: Vec<Address>,
+) -> u64 {
+    let (first, second) = (signers.get(0).unwrap(), signers.get(1).unwrap());
+    let proposal_id = client.propose_fleet_upgrade(&first, &wasm(env), &children, &0u64);
+    client.approve_fleet_upgrade(&proposal_id, &first);
+    client.approve_fleet_upgrade(&proposal_id, &second);
+    env.ledger().set_timestamp(env.ledger().timestamp() + 86_400);
+    proposal_id
+}
+
+fn fleet_done_event(env: &Env) -> Option<FleetUpgradeEvent> {
+    let mut found = None;
+    for (_, topics, data) in env.events().all().iter() {
+        if topics.len() != 2 {
+            continue;
+        }
+        let t1 = Symbol::try_from_val(env, &topics.get(1).unwrap());
+        if t1 == Ok(Symbol::new(env, "done")) {
+            found = FleetUpgradeEvent::try_from_val(env, &data).ok();
+        }
+    }
+    found
+}
+
+#[test]
+fn test_fleet_upgrade_upgrades_all_children() {
+    let env = Env::default();
+    let (client, signers) = setup(&env);
+    let a = child_of(&env, &client.address);
+    let b = child_of(&env, &client.address);
+    register(&env, &client, &signers, &[&a.address, &b.address]);
+
+    let proposal_id = approved_fleet_proposal(
+        &env,
+        &client,
+        &signers,
+        vec![&env, a.address.clone(), b.address.clone()],
+    );
+
+    // No mocked auths: children must accept this contract as invoking admin.
+    env.set_auths(&[]);
+    let results = client.execute_fleet_upgrade(&proposal_id);
+
+    assert_eq!(
+        results,
+        vec![
+            &env,
+            FleetChildResult { child: a.address.clone(), success: true },
+            FleetChildResult { child: b.address.clone(), success: true },
+        ]
+    );
+    assert_eq!(a.installed(), Some(wasm(&env)));
+    assert_eq!(b.installed(), Some(wasm(&env)));
+
+    let event = fleet_done_event(&env).expect("fleet done event");
+    assert_eq!(event.proposal_id, proposal_id);
+    assert_eq!(event.succeeded, 2);
+    assert_eq!(event.failed, 0);
+}
+
+#[test]
+fn test_failing_children_do_not_abort_fleet() {
+    let env = Env::default();
+    let (client, signers) = setup(&env);
+    let broken = env.register_contract(None, BrokenChild);
+    let foreign = child_of(&env, &Address::generate(&env));
+    let ok = child_of(&env, &client.address);
+    register(&env, &client, &signers, &[&broken, &foreign.address, &ok.address]);
+
+    let proposal_id = approved_fleet_proposal(
+        &env,
+        &client,
+        &signers,
+        vec![&env, broken.clone(), foreign.address.clone(), ok.address.clone()],
+    );
+    env.set_auths(&[]);
+    let results = client.execute_fleet_upgrade(&proposal_id);
+
+    assert_eq!(results.len(), 3);
+    assert!(!results.get(0).unwrap().success);
+    assert!(!results.get(1).unwrap().success, "child with another admin must reject");
+    assert!(results.get(2).unwrap().success);
+    assert_eq!(foreign.installed(), None);
+    assert_eq!(ok.installed(), Some(wasm(&env)));
+
+    let event = fleet_done_event(&env).expect("fleet done event");
+    assert_eq!(event.succeeded, 1);
+    assert_eq!(event.failed, 2);
+}
+
+#[test]
+fn test_fleet_upgrade_cannot_execute_twice() {
+    let env = Env::default();
+    let (client, signers) = setup(&env);
+    let a = child_of(&env, &client.address);
+    register(&env, &client, &signers, &[&a.address]);
+
+    let proposal_id = approved_fleet_proposal(&env, &client, &signers, vec![&env, a.address.clone()]);
+    client.execute_fleet_upgrade(&proposal_id);
+    assert!(client.try_execute_fleet_upgrade(&proposal_id).is_err());
+}
+
+#[test]
+#[should_panic(expected = "Timelock delay not met")]
+fn test_fleet_upgrade_respects_timelock() {
+    let env = Env::default();
+    let (client, signers) = setup(&env);
+    let a = child_of(&env, &client.address);
+    register(&env, &client, &signers, &[&a.address]);
+
+    let (first, second) = (signers.get(0).unwrap(), signers.get(1).unwrap());
+    let proposal_id = client.propose_fleet_upgrade(&first, &wasm(&env), &vec![&env, a.address.clone()], &0u64);
+    client.approve_fleet_upgrade(&proposal_id, &first);
+    client.approve_fleet_upgrade(&proposal_id, &second);
+    client.execute_fleet_upgrade(&proposal_id);
+}
+
+#[test]
+#[should_panic(expected = "Child not registered")]
+fn test_proposal_rejects_unregistered_child() {
+    let env = Env::default();
+    let (client, signers) = setup(&env);
+    client.propose_fleet_upgrade(&signers.get(0).unwrap(), &wasm(&env), &vec![&env, Address::generate(&env)], &0u64);
+}
+
+#[test]
+#[should_panic(expected = "Duplicate child in fleet upgrade")]
+fn test_proposal_rejects_duplicate_child() {
+    let env = Env::default();
+    let (client, signers) = setup(&env);
+    let child = Address::generate(&env);
+    register(&env, &client, &signers, &[&child]);
+    client.propose_fleet_upgrade(&signers.get(0).unwrap(), &wasm(&env), &vec![&env, child.clone(), child], &0u64);
+}
+
+#[test]
+fn test_child_registry_management() {
+    let env = Env::default();
+    let (client, signers) = setup(&env);
+    let child = Address::generate(&env);
+
+    register(&env, &client, &signers, &[&child, &child]);
+    assert_eq!(client.get_children(), vec![&env, child.clone()]);
+
+    let actions = vec![&env, ProposalAction::UnregisterChild(child)];
+    GovernanceDriver::apply_actions(&env, &client, &signers, actions);
+    assert_eq!(client.get_children().len(), 0);
+}
+
+#[test]
+fn test_child_registry_requires_governance() {
+    let env = Env::default();
+    let (client, signers) = setup(&env);
+    let child = Address::generate(&env);
+    register(&env, &client, &signers, &[&child]);
+
+    // A multisig contract has no admin to call the entrypoints directly,
+    // even with every auth mocked.
+    assert!(client.try_register_child(&Address::generate(&env)).is_err());
+    assert!(client.try_unregister_child(&child).is_err());
+    assert_eq!(client.get_children(), vec![&env, child.clone()]);
+
+    // An admin-mode contract manages the registry directly.
+    let (admin_client, _admin) = setup_admin(&env);
+    admin_client.register_child(&child);
+    env.set_auths(&[]);
+    assert!(admin_client.try_unregister_child(&child).is_err());
+    assert_eq!(admin_client.get_children(), vec![&env, child]);
+}
+
+#[test]
+#[should_panic(expected = "Wasm hash is blocked")]
+fn test_proposal_rejects_blocked_hash() {
+    let env = Env::default();
+    let (client, _admin) = setup_admin(&env);
+    let child = child_of(&env, &client.address);
+    client.register_child(&child.address);
+    client.block_wasm_hash(&wasm(&env), &String::from_str(&env, "known bad"));
+    let signers = vec![&env, Address::generate(&env), Address::generate(&env)];
+    client.migrate_to_multisig(&signers, &2, &false);
+
+    client.propose_fleet_upgrade(&signers.get(0).unwrap(), &wasm(&env), &vec![&env, child.address], &0u64);
+}
+
+#[test]
+#[should_panic(expected = "109")]
+fn test_execute_requires_attestation() {
+    let env = Env::default();
+    let (client, _admin) = setup_admin(&env);
+    let child = child_of(&env, &client.address);
+    client.register_child(&child.address);
+    let auditor = Address::generate(&env);
+    client.set_attestation_config(&AttestationConfig {
+        require_attestation: true,
+        auditors: vec![&env, auditor],
+        min_attestations: 1,
+    });
+    let signers = vec![&env, Address::generate(&env), Address::generate(&env)];
+    client.migrate_to_multisig(&signers, &2, &false);
+
+    let proposal_id = approved_fleet_proposal(&env, &client, &signers, vec![&env, child.address.clone()]);
+    client.execute_fleet_upgrade(&proposal_id);
+}
+
+#[test]
+fn test_approve_fleet_upgrade_rejects_plain_upgrade_proposal() {
+    let env = Env::default();
+    let (client, signers) = setup(&env);
+    let signer = signers.get(0).unwrap();
+    let proposal_id = client.propose_upgrade(&signer, &wasm(&env), &0u64);
+    assert!(client.try_approve_fleet_upgrade(&proposal_id, &signer).is_err());
+}
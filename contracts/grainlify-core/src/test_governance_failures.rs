@@ -0,0 +1,139 @@
+//! # Governance Failure Stage Tests
+//!
+//! - Refusals absorbed by `attempt_propose_upgrade`,
+//!   `attempt_approve_upgrade`, `attempt_execute_upgrade` and
+//!   `attempt_cancel_upgrade` count under their own stage
+//! - The `("metric", "gov_fail")` event carries the stage and error code
+//! - Fleet children that reject an upgrade count as execution failures
+
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events as _},
+    vec, Address, BytesN, Env, IntoVal, TryFromVal, Vec,
+};
+
+use crate::monitoring::{GovernanceFailureMetric, GovernanceFailureStats, GovernanceStage};
+use crate::testutils::{setup_multisig, upload_dummy_wasm, GovernanceDriver};
+use crate::{ContractError, ExecutionCheck, GrainlifyContractClient, ProposalAction, ProposalCheckError, ProposeAttempt};
+
+mod rejecting_child {
+    use soroban_sdk::{contract, contracterror, contractimpl, panic_with_error, BytesN, Env};
+
+    #[contracterror]
+    #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+    #[repr(u32)]
+    pub enum ChildError {
+        Rejected = 7,
+    }
+
+    #[contract]
+    pub struct RejectingChild;
+
+    #[contractimpl]
+    impl RejectingChild {
+        pub fn upgrade(env: Env, _new_wasm_hash: BytesN<32>) {
+            panic_with_error!(&env, ChildError::Rejected);
+        }
+    }
+}
+
+/// Contract created by `init` with a 2-of-3 multisig.
+fn setup(env: &Env) -> (GrainlifyContractClient<'_>, Vec<Address>) {
+    setup_multisig(env, 3, 2)
+}
+
+#[test]
+fn test_failed_attempt_is_an_execution_failure() {
+    let env = Env::default();
+    let (client, signers) = setup(&env);
+    let proposal_id = GovernanceDriver::propose(&env, &client, &signers, &upload_dummy_wasm(&env, 1));
+    GovernanceDriver::approve_to_threshold(&env, &client, &signers, proposal_id);
+
+    let caller = Address::generate(&env);
+    assert_eq!(client.attempt_execute_upgrade(&caller, &proposal_id), Some(ExecutionCheck::TimelockPending));
+    assert_eq!(client.get_governance_failure_stats(), GovernanceFailureStats { execute: 1, ..Default::default() });
+    assert_eq!(client.get_failure_breakdown().by_function.get(symbol_short!("exec_upg")), Some(1));
+}
+
+#[test]
+fn test_refused_proposal_is_a_propose_failure() {
+    let env = Env::default();
+    let (client, signers) = setup(&env);
+    let proposer = signers.get(0).unwrap();
+    client.pause(&proposer);
+
+    let wasm = upload_dummy_wasm(&env, 1);
+    let refused = ProposeAttempt { proposal_id: 0, refused: Some(ProposalCheckError::Paused as u32) };
+    assert_eq!(client.attempt_propose_upgrade(&proposer, &wasm, &0u64), refused);
+    assert_eq!(client.get_governance_failure_stats(), GovernanceFailureStats { propose: 1, ..Default::default() });
+    assert_eq!(client.get_failure_breakdown().by_function.get(symbol_short!("prop_upg")), Some(1));
+}
+
+#[test]
+fn test_refused_approval_is_an_approve_failure() {
+    let env = Env::default();
+    let (client, signers) = setup(&env);
+    let proposal_id = GovernanceDriver::propose(&env, &client, &signers, &upload_dummy_wasm(&env, 1));
+    let signer = signers.get(0).unwrap();
+
+    assert_eq!(client.attempt_approve_upgrade(&signer, &proposal_id), None);
+    assert_eq!(
+        client.attempt_approve_upgrade(&signer, &proposal_id),
+        Some(ContractError::AlreadyApproved as u32)
+    );
+    assert_eq!(client.get_governance_failure_stats(), GovernanceFailureStats { approve: 1, ..Default::default() });
+    assert_eq!(client.get_failure_breakdown().by_function.get(symbol_short!("appr_upg")), Some(1));
+}
+
+#[test]
+fn test_refused_cancel_is_a_cancel_failure() {
+    let env = Env::default();
+    let (client, signers) = setup(&env);
+    let proposal_id = GovernanceDriver::propose(&env, &client, &signers, &upload_dummy_wasm(&env, 1));
+
+    assert_eq!(client.attempt_cancel_upgrade(&Address::generate(&env), &proposal_id), Some(0));
+    assert_eq!(client.attempt_cancel_upgrade(&signers.get(0).unwrap(), &proposal_id), None);
+    assert_eq!(client.get_governance_failure_stats(), GovernanceFailureStats { cancel: 1, ..Default::default() });
+    assert_eq!(client.get_failure_breakdown().by_function.get(symbol_short!("canc_upg")), Some(1));
+}
+
+#[test]
+fn test_failure_event_carries_stage() {
+    let env = Env::default();
+    let (client, signers) = setup(&env);
+    let proposal_id = GovernanceDriver::propose(&env, &client, &signers, &upload_dummy_wasm(&env, 1));
+    GovernanceDriver::approve_to_threshold(&env, &client, &signers, proposal_id);
+    GovernanceDriver::skip_timelock(&env, &client);
+    client.pause(&signers.get(0).unwrap());
+
+    client.attempt_execute_upgrade(&Address::generate(&env), &proposal_id);
+    let topics = (symbol_short!("metric"), symbol_short!("gov_fail")).into_val(&env);
+    let (_, _, data) = env.events().all().iter().find(|(_, t, _)| *t == topics).unwrap();
+    let event = GovernanceFailureMetric::try_from_val(&env, &data).unwrap();
+    assert_eq!(event.stage, GovernanceStage::Execute);
+    assert_eq!(event.code, ContractError::Paused as u32);
+    assert_eq!(event.operation, symbol_short!("exec_upg"));
+}
+
+#[test]
+fn test_rejected_fleet_child_is_an_execution_failure() {
+    let env = Env::default();
+    let (client, signers) = setup(&env);
+    let rejecting = env.register_contract(None, rejecting_child::RejectingChild);
+    let actions = vec![&env, ProposalAction::RegisterChild(rejecting.clone())];
+    GovernanceDriver::apply_actions(&env, &client, &signers, actions);
+
+    let wasm = BytesN::from_array(&env, &[0xCD; 32]);
+    let (first, second) = (signers.get(0).unwrap(), signers.get(1).unwrap());
+    let proposal_id = client.propose_fleet_upgrade(&first, &wasm, &vec![&env, rejecting], &0u64);
+    client.approve_fleet_upgrade(&proposal_id, &first);
+    client.approve_fleet_upgrade(&proposal_id, &second);
+    GovernanceDriver::skip_timelock(&env, &client);
+    client.execute_fleet_upgrade(&proposal_id);
+
+    assert_eq!(client.get_governance_failure_stats(), GovernanceFailureStats { execute: 1, ..Default::default() });
+}
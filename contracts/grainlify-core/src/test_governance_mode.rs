@@ -0,0 +1,69 @@
+//! # Governance Mode Tests
+//!
+//! - Admin entrypoints (`upgrade`, `set_version`) on a multisig-only
+//!   contract fail with `WrongGovernanceMode`
+//! - Multisig entrypoints (`propose_upgrade`, `approve_upgrade`,
+//!   `execute_upgrade`) on an admin-only contract fail the same way
+//! - Uninitialized contracts keep failing with their existing errors
+
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{testutils::Address as _, Address, Env};
+
+use crate::testutils::{setup_admin, setup_multisig, upload_dummy_wasm};
+use crate::{GrainlifyContract, GrainlifyContractClient};
+
+#[test]
+#[should_panic(expected = "144")]
+fn test_upgrade_on_multisig_contract() {
+    let env = Env::default();
+    let (client, _signers) = setup_multisig(&env, 3, 2);
+    client.upgrade(&upload_dummy_wasm(&env, 1));
+}
+
+#[test]
+#[should_panic(expected = "144")]
+fn test_set_version_on_multisig_contract() {
+    let env = Env::default();
+    let (client, _signers) = setup_multisig(&env, 3, 2);
+    client.set_version(&5);
+}
+
+#[test]
+#[should_panic(expected = "144")]
+fn test_propose_upgrade_on_admin_contract() {
+    let env = Env::default();
+    let (client, admin) = setup_admin(&env);
+    client.propose_upgrade(&admin, &upload_dummy_wasm(&env, 1), &0);
+}
+
+#[test]
+#[should_panic(expected = "144")]
+fn test_approve_upgrade_on_admin_contract() {
+    let env = Env::default();
+    let (client, admin) = setup_admin(&env);
+    client.approve_upgrade(&1, &admin);
+}
+
+#[test]
+#[should_panic(expected = "144")]
+fn test_execute_upgrade_on_admin_contract() {
+    let env = Env::default();
+    let (client, _admin) = setup_admin(&env);
+    client.execute_upgrade(&1);
+}
+
+#[test]
+fn test_uninitialized_contract_is_not_a_mode_error() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = GrainlifyContractClient::new(&env, &env.register_contract(None, GrainlifyContract));
+    let wasm_hash = upload_dummy_wasm(&env, 1);
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| client.set_version(&5)));
+    let message = *result.unwrap_err().downcast::<std::string::String>().unwrap();
+    assert!(!message.contains("144"), "{message}");
+    assert!(client.try_propose_upgrade(&Address::generate(&env), &wasm_hash, &0).is_err());
+}
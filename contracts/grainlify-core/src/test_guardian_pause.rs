@@ -0,0 +1,193 @@
+//! # Guardian Emergency Pause Tests
+//!
+//! - `guardian_pause` stops upgrade execution and admin changes while
+//!   proposals, approvals and reads keep working
+//! - The pause lifts on its own after the configured duration; governance
+//!   can extend it or end it early
+//! - A second pause fails while one is active and within the cooldown
+//! - Health, contract info and `preview_execution` report the pause
+//! - Multisig governance appoints the guardian and manages pauses through
+//!   proposal actions, which an emergency pause does not hold up
+
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events as _, Ledger as _},
+    vec, Address, Env, IntoVal, Symbol, TryFromVal, Vec,
+};
+
+use crate::testutils::{setup_admin, setup_multisig, upload_dummy_wasm, GovernanceDriver};
+use crate::{
+    EmergencyPauseEvent, EmergencyPauseSettings, ExecutionCheck, GrainlifyContractClient, ProposalAction,
+    DEFAULT_EMERGENCY_PAUSE_COOLDOWN, DEFAULT_EMERGENCY_PAUSE_DURATION,
+};
+
+/// Contract created by `init` with a 2-of-3 multisig whose governance
+/// appointed a guardian.
+fn setup_guarded(env: &Env) -> (GrainlifyContractClient<'_>, Vec<Address>, Address) {
+    let (client, signers) = setup_multisig(env, 3, 2);
+    let guardian = Address::generate(env);
+    apply(env, &client, &signers, ProposalAction::SetGuardian(guardian.clone()));
+    (client, signers, guardian)
+}
+
+fn apply(env: &Env, client: &GrainlifyContractClient, signers: &Vec<Address>, action: ProposalAction) -> u64 {
+    GovernanceDriver::apply_actions(env, client, signers, vec![env, action])
+}
+
+fn emergency_events(env: &Env, action: &str) -> u32 {
+    let topics = (symbol_short!("emergency"), Symbol::new(env, action)).into_val(env);
+    env.events()
+        .all()
+        .iter()
+        .filter(|(_, t, data)| *t == topics && EmergencyPauseEvent::try_from_val(env, data).is_ok())
+        .count() as u32
+}
+
+#[test]
+fn test_pause_blocks_execution_but_not_approvals() {
+    let env = Env::default();
+    let (client, signers, guardian) = setup_guarded(&env);
+    let wasm_hash = upload_dummy_wasm(&env, 1);
+    let proposal_id = GovernanceDriver::propose(&env, &client, &signers, &wasm_hash);
+
+    let start = env.ledger().timestamp();
+    client.guardian_pause();
+    let pause = client.get_emergency_pause().unwrap();
+    assert_eq!(pause.guardian, guardian);
+    assert_eq!(pause.expires_at, start + DEFAULT_EMERGENCY_PAUSE_DURATION);
+    assert_eq!(emergency_events(&env, "paused"), 1);
+
+    GovernanceDriver::approve_to_threshold(&env, &client, &signers, proposal_id);
+    GovernanceDriver::skip_timelock(&env, &client);
+    assert!(client.preview_execution(&proposal_id).failing.contains(ExecutionCheck::EmergencyPaused));
+    assert!(client.try_execute_upgrade(&proposal_id).is_err());
+    assert!(!client.is_paused());
+
+    env.ledger().set_timestamp(start + DEFAULT_EMERGENCY_PAUSE_DURATION);
+    assert_eq!(client.get_emergency_pause(), None);
+    client.execute_upgrade(&proposal_id);
+}
+
+#[test]
+fn test_pause_blocks_admin_handover() {
+    let env = Env::default();
+    let (client, _admin) = setup_admin(&env);
+    client.set_guardian(&Address::generate(&env));
+    client.guardian_pause();
+
+    let signers = vec![&env, Address::generate(&env), Address::generate(&env)];
+    assert!(client.try_migrate_to_multisig(&signers, &2, &false).is_err());
+    client.end_emergency_pause();
+    client.migrate_to_multisig(&signers, &2, &false);
+}
+
+#[test]
+fn test_state_reported_in_health_and_info() {
+    let env = Env::default();
+    let (client, _, _) = setup_guarded(&env);
+    assert!(client.get_features().contains(symbol_short!("guardian")));
+    assert_eq!(client.health_check().emergency_paused_until, None);
+
+    let until = env.ledger().timestamp() + DEFAULT_EMERGENCY_PAUSE_DURATION;
+    client.guardian_pause();
+    assert_eq!(client.health_check().emergency_paused_until, Some(until));
+    assert_eq!(client.get_contract_info().emergency_paused_until, Some(until));
+}
+
+#[test]
+fn test_cannot_retrigger_during_incident_or_cooldown() {
+    let env = Env::default();
+    let (client, _, _) = setup_guarded(&env);
+    let start = env.ledger().timestamp();
+    client.guardian_pause();
+    assert!(client.try_guardian_pause().is_err());
+
+    env.ledger().set_timestamp(start + DEFAULT_EMERGENCY_PAUSE_DURATION);
+    assert!(client.try_guardian_pause().is_err());
+
+    env.ledger().set_timestamp(start + DEFAULT_EMERGENCY_PAUSE_COOLDOWN);
+    client.guardian_pause();
+    assert_eq!(emergency_events(&env, "paused"), 2);
+}
+
+#[test]
+fn test_governance_extends_and_ends_early() {
+    let env = Env::default();
+    let (client, signers, _) = setup_guarded(&env);
+    let start = env.ledger().timestamp();
+    client.guardian_pause();
+
+    // Each action waits out the timelock, which is shorter than the pause.
+    apply(&env, &client, &signers, ProposalAction::ExtendEmergencyPause(3_600));
+    let extended_until = start + DEFAULT_EMERGENCY_PAUSE_DURATION + 3_600;
+    assert_eq!(client.get_emergency_pause().unwrap().expires_at, extended_until);
+
+    apply(&env, &client, &signers, ProposalAction::EndEmergencyPause);
+    assert!(env.ledger().timestamp() < extended_until);
+    assert_eq!(client.get_emergency_pause(), None);
+    assert_eq!(emergency_events(&env, "ended"), 1);
+    assert!(client.try_guardian_pause().is_err());
+}
+
+#[test]
+fn test_admin_extends_and_ends_early() {
+    let env = Env::default();
+    let (client, _admin) = setup_admin(&env);
+    client.set_guardian(&Address::generate(&env));
+    client.guardian_pause();
+
+    client.extend_emergency_pause(&3_600);
+    assert_eq!(client.get_emergency_pause().unwrap().expires_at, DEFAULT_EMERGENCY_PAUSE_DURATION + 3_600);
+    env.ledger().set_timestamp(DEFAULT_EMERGENCY_PAUSE_DURATION);
+    assert!(client.get_emergency_pause().is_some());
+
+    client.end_emergency_pause();
+    assert_eq!(client.get_emergency_pause(), None);
+    env.set_auths(&[]);
+    assert!(client.try_set_guardian(&Address::generate(&env)).is_err());
+}
+
+#[test]
+fn test_settings_change_duration_and_cooldown() {
+    let env = Env::default();
+    let (client, signers, _) = setup_guarded(&env);
+    let settings = EmergencyPauseSettings { duration: 600, cooldown: 1_200 };
+    apply(&env, &client, &signers, ProposalAction::SetEmergencyPauseSettings(settings.clone()));
+    assert_eq!(client.get_emergency_pause_settings(), settings);
+    let invalid = ProposalAction::SetEmergencyPauseSettings(EmergencyPauseSettings { duration: 600, cooldown: 300 });
+    let proposal_id = client.propose_actions(&signers.get(0).unwrap(), &vec![&env, invalid], &0);
+    GovernanceDriver::approve_to_threshold(&env, &client, &signers, proposal_id);
+    GovernanceDriver::skip_timelock(&env, &client);
+    assert!(client.try_execute_proposal(&proposal_id).is_err());
+
+    let start = env.ledger().timestamp();
+    client.guardian_pause();
+    env.ledger().set_timestamp(start + 1_200);
+    client.guardian_pause();
+    assert_eq!(client.get_emergency_pause().unwrap().expires_at, start + 1_800);
+}
+
+#[test]
+fn test_multisig_clears_guardian() {
+    let env = Env::default();
+    let (client, signers, guardian) = setup_guarded(&env);
+    assert_eq!(client.get_guardian(), Some(guardian));
+    // No admin on an `init` contract, so only an action can change it.
+    assert!(client.try_clear_guardian().is_err());
+
+    apply(&env, &client, &signers, ProposalAction::ClearGuardian);
+    assert_eq!(client.get_guardian(), None);
+    assert!(client.try_guardian_pause().is_err());
+}
+
+#[test]
+#[should_panic(expected = "No guardian set")]
+fn test_pause_without_guardian_fails() {
+    let env = Env::default();
+    let (client, _) = setup_admin(&env);
+    client.guardian_pause();
+}
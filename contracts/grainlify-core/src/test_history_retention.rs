@@ -0,0 +1,104 @@
+//! # History Retention Tests
+//!
+//! - Past its retention a history deletes its oldest entries while the
+//!   lifetime count keeps growing
+//! - `get_history_range` reports the retained range; paging from index 0
+//!   starts at the oldest retained entry
+//! - Lowering the retention trims at most `MAX_HISTORY_PRUNE` entries per
+//!   append
+//! - The retention changes only through governance and cannot be zero;
+//!   multisig deployments change it with an action bundle
+
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{vec, Env};
+
+use crate::testutils::{setup_admin, setup_multisig, GovernanceDriver};
+use crate::{ArchiveKey, HistoryKind, ProposalAction, DEFAULT_HISTORY_RETENTION, MAX_HISTORY_PRUNE};
+
+#[test]
+fn test_oldest_entries_deleted_past_retention() {
+    let env = Env::default();
+    let (client, _) = setup_admin(&env);
+    let initial = client.get_history_range(&HistoryKind::Versions);
+    assert_eq!(initial.retention, DEFAULT_HISTORY_RETENTION);
+    assert_eq!(initial.first, 0);
+
+    client.set_history_retention(&HistoryKind::Versions, &3);
+    for version in 2..=7u32 {
+        client.set_version(&version);
+    }
+
+    let range = client.get_history_range(&HistoryKind::Versions);
+    assert_eq!(range.total_count, initial.total_count + 6);
+    assert_eq!(range.first, range.total_count - 3);
+    assert_eq!(range.retention, 3);
+    env.as_contract(&client.address, || {
+        assert!(!env.storage().persistent().has(&ArchiveKey::VersionHistory(range.first - 1)));
+        assert!(env.storage().persistent().has(&ArchiveKey::VersionHistory(range.first)));
+    });
+
+    let retained = client.get_version_history(&0, &10).items;
+    let versions: std::vec::Vec<u32> = retained.iter().map(|entry| entry.version).collect();
+    assert_eq!(versions, [5, 6, 7]);
+    assert_eq!(client.get_version_history(&(range.first + 1), &1).items.get(0).unwrap().version, 6);
+    assert_eq!(client.get_version_history(&0, &2).items.len(), 2);
+    assert!(client.check_consistency().is_empty());
+}
+
+#[test]
+fn test_lowered_retention_trims_gradually() {
+    let env = Env::default();
+    let (client, _) = setup_admin(&env);
+    for version in 2..=9u32 {
+        client.set_version(&version);
+    }
+    let total = client.get_history_range(&HistoryKind::Versions).total_count;
+
+    client.set_history_retention(&HistoryKind::Versions, &1);
+    assert_eq!(client.get_history_range(&HistoryKind::Versions).first, 0);
+    client.set_version(&10);
+    assert_eq!(client.get_history_range(&HistoryKind::Versions).first, MAX_HISTORY_PRUNE);
+
+    for version in 11..=20u32 {
+        client.set_version(&version);
+    }
+    let range = client.get_history_range(&HistoryKind::Versions);
+    assert_eq!(range.total_count, total + 11);
+    assert_eq!(range.first, range.total_count - 1);
+    assert_eq!(client.get_version_history(&0, &10).items.get(0).unwrap().version, 20);
+}
+
+#[test]
+fn test_histories_are_independent() {
+    let env = Env::default();
+    let (client, _) = setup_admin(&env);
+    client.set_history_retention(&HistoryKind::Thresholds, &1);
+    client.set_version(&2);
+    client.set_version(&3);
+    assert_eq!(client.get_history_range(&HistoryKind::Versions).first, 0);
+    assert_eq!(client.get_history_range(&HistoryKind::Upgrades).retention, DEFAULT_HISTORY_RETENTION);
+}
+
+#[test]
+fn test_retention_needs_governance_and_a_positive_value() {
+    let env = Env::default();
+    let (client, _) = setup_admin(&env);
+    assert!(client.try_set_history_retention(&HistoryKind::Versions, &0).is_err());
+    env.set_auths(&[]);
+    assert!(client.try_set_history_retention(&HistoryKind::Versions, &10).is_err());
+    assert_eq!(client.get_history_range(&HistoryKind::Versions).retention, DEFAULT_HISTORY_RETENTION);
+}
+
+#[test]
+fn test_multisig_sets_retention_through_actions() {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    assert!(client.try_set_history_retention(&HistoryKind::Upgrades, &5).is_err());
+
+    let actions = vec![&env, ProposalAction::SetHistoryRetention(HistoryKind::Upgrades, 5)];
+    GovernanceDriver::apply_actions(&env, &client, &signers, actions);
+    assert_eq!(client.get_history_range(&HistoryKind::Upgrades).retention, 5);
+}
@@ -0,0 +1,88 @@
+//! # Idempotent Proposal Tests
+//!
+//! - Resubmitting the same `(wasm_hash, nonce, proposer)` while the first
+//!   proposal is pending returns its ID and creates nothing
+//! - A different nonce, hash or proposer opens a separate proposal
+//! - Once the proposal is cancelled, the same content opens a new one and
+//!   `find_proposal_by_content` follows it
+
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{testutils::Address as _, Address, BytesN, Env};
+
+use crate::testutils::{setup_multisig, upload_dummy_wasm};
+use crate::{GrainlifyContractClient, MultiSig};
+
+fn proposal_count(env: &Env, client: &GrainlifyContractClient) -> u64 {
+    env.as_contract(&client.address, || MultiSig::get_proposal_count(env))
+}
+
+fn submit(client: &GrainlifyContractClient, proposer: &Address, wasm_hash: &BytesN<32>, nonce: u64) -> u64 {
+    client.propose_upgrade_idempotent(proposer, wasm_hash, &nonce)
+}
+
+#[test]
+fn test_retry_returns_existing_proposal() {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    let proposer = signers.get(0).unwrap();
+    let wasm_hash = upload_dummy_wasm(&env, 1);
+
+    let first = submit(&client, &proposer, &wasm_hash, 7);
+    let retry = submit(&client, &proposer, &wasm_hash, 7);
+    assert_eq!(retry, first);
+    assert_eq!(proposal_count(&env, &client), 1);
+    assert_eq!(client.find_proposal_by_content(&wasm_hash, &7, &proposer), Some(first));
+}
+
+#[test]
+fn test_different_content_opens_separate_proposals() {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    let proposer = signers.get(0).unwrap();
+    let wasm_hash = upload_dummy_wasm(&env, 1);
+
+    let base = submit(&client, &proposer, &wasm_hash, 7);
+    let other_nonce = submit(&client, &proposer, &wasm_hash, 8);
+    let other_hash = submit(&client, &proposer, &upload_dummy_wasm(&env, 2), 7);
+    let other_proposer = submit(&client, &signers.get(1).unwrap(), &wasm_hash, 7);
+
+    let mut ids = std::vec![base, other_nonce, other_hash, other_proposer];
+    ids.sort_unstable();
+    ids.dedup();
+    assert_eq!(ids.len(), 4);
+    assert_eq!(proposal_count(&env, &client), 4);
+}
+
+#[test]
+fn test_closed_proposal_is_not_reused() {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    let proposer = signers.get(0).unwrap();
+    let wasm_hash = upload_dummy_wasm(&env, 1);
+
+    let first = submit(&client, &proposer, &wasm_hash, 7);
+    client.cancel_upgrade(&first, &proposer);
+    let second = submit(&client, &proposer, &wasm_hash, 7);
+    assert_ne!(second, first);
+    assert_eq!(client.find_proposal_by_content(&wasm_hash, &7, &proposer), Some(second));
+}
+
+#[test]
+fn test_unknown_content_is_absent() {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    let wasm_hash = upload_dummy_wasm(&env, 1);
+    submit(&client, &signers.get(0).unwrap(), &wasm_hash, 7);
+    assert_eq!(client.find_proposal_by_content(&wasm_hash, &8, &signers.get(0).unwrap()), None);
+}
+
+#[test]
+fn test_non_signer_cannot_propose() {
+    let env = Env::default();
+    let (client, _signers) = setup_multisig(&env, 3, 2);
+    let outsider = Address::generate(&env);
+    assert!(client.try_propose_upgrade_idempotent(&outsider, &upload_dummy_wasm(&env, 1), &7).is_err());
+}
@@ -0,0 +1,63 @@
+//! # Init State Tests
+//!
+//! - `get_init_state` reports uninitialized, admin, or multisig mode
+//! - An admin contract that also configures a multisig stays in admin mode
+//! - Contracts initialized before the mode marker fall back to the stored
+//!   admin and multisig config
+
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{testutils::Address as _, vec, Address, Env, String};
+
+use crate::testutils::{setup_admin, setup_multisig};
+use crate::{DataKey, GrainlifyContract, GrainlifyContractClient, InitState, MultiSig};
+
+#[test]
+fn test_fresh_contract_is_uninitialized() {
+    let env = Env::default();
+    let client = GrainlifyContractClient::new(&env, &env.register_contract(None, GrainlifyContract));
+    assert_eq!(client.get_init_state(), InitState::Uninitialized);
+}
+
+#[test]
+fn test_admin_init_reports_admin_mode() {
+    let env = Env::default();
+    let (client, admin) = setup_admin(&env);
+    assert_eq!(client.get_init_state(), InitState::AdminMode(admin.clone()));
+
+    let signers = vec![&env, Address::generate(&env), Address::generate(&env)];
+    env.as_contract(&client.address, || MultiSig::init(&env, signers, 2));
+    assert_eq!(client.get_init_state(), InitState::AdminMode(admin));
+}
+
+#[test]
+fn test_multisig_init_reports_signers_and_threshold() {
+    let env = Env::default();
+    let (client, _) = setup_multisig(&env, 5, 3);
+    assert_eq!(client.get_init_state(), InitState::MultisigMode(5, 3));
+}
+
+#[test]
+fn test_network_init_reports_admin_mode() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = GrainlifyContractClient::new(&env, &env.register_contract(None, GrainlifyContract));
+    let admin = Address::generate(&env);
+    client.init_with_network(&admin, &String::from_str(&env, "stellar"), &String::from_str(&env, "testnet"));
+    assert_eq!(client.get_init_state(), InitState::AdminMode(admin));
+}
+
+#[test]
+fn test_contract_without_marker_derives_mode() {
+    let env = Env::default();
+    let (admin_client, admin) = setup_admin(&env);
+    let (multisig_client, _) = setup_multisig(&env, 3, 2);
+    for client in [&admin_client, &multisig_client] {
+        env.as_contract(&client.address, || env.storage().instance().remove(&DataKey::InitMode));
+    }
+
+    assert_eq!(admin_client.get_init_state(), InitState::AdminMode(admin));
+    assert_eq!(multisig_client.get_init_state(), InitState::MultisigMode(3, 2));
+}
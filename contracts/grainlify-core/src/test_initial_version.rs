@@ -0,0 +1,85 @@
+//! # Initial Version Tests
+//!
+//! - `init_admin` / `init` start at the built-in version
+//! - The `_with_version` variants start at the given version, and the init
+//!   events report it
+//! - `None` falls back to the built-in version; 0 is rejected
+//! - The variants still refuse a second initialization
+
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{testutils::Address as _, testutils::Events as _, vec, Address, Env, Symbol, TryFromVal};
+
+use crate::{BuildInfoEvent, GrainlifyContract, GrainlifyContractClient, InitializedEvent};
+
+fn register(env: &Env) -> GrainlifyContractClient<'_> {
+    env.mock_all_auths();
+    GrainlifyContractClient::new(env, &env.register_contract(None, GrainlifyContract))
+}
+
+/// Versions reported by the `("grainlify", "init")` and `("init", "build")`
+/// events.
+fn init_event_versions(env: &Env) -> (u32, u32) {
+    let (mut init, mut build) = (None, None);
+    for (_, topics, data) in env.events().all().iter() {
+        if topics.len() != 2 {
+            continue;
+        }
+        let t0 = Symbol::try_from_val(env, &topics.get(0).unwrap());
+        let t1 = Symbol::try_from_val(env, &topics.get(1).unwrap());
+        if t0 == Ok(Symbol::new(env, "grainlify")) && t1 == Ok(Symbol::new(env, "init")) {
+            init = InitializedEvent::try_from_val(env, &data).ok().map(|e| e.version);
+        } else if t0 == Ok(Symbol::new(env, "init")) && t1 == Ok(Symbol::new(env, "build")) {
+            build = BuildInfoEvent::try_from_val(env, &data).ok().map(|e| e.version);
+        }
+    }
+    (init.unwrap(), build.unwrap())
+}
+
+#[test]
+fn test_default_init_paths_use_builtin_version() {
+    let env = Env::default();
+    let client = register(&env);
+    client.init_admin(&Address::generate(&env));
+    assert_eq!(client.get_version(), 2);
+
+    let client = register(&env);
+    client.init_admin_with_version(&Address::generate(&env), &None);
+    assert_eq!(client.get_version(), 2);
+    assert_eq!(init_event_versions(&env), (2, 2));
+}
+
+#[test]
+fn test_init_admin_with_explicit_version() {
+    let env = Env::default();
+    let client = register(&env);
+    let admin = Address::generate(&env);
+    client.init_admin_with_version(&admin, &Some(7));
+
+    assert_eq!(client.get_version(), 7);
+    assert_eq!(client.get_admin(), Some(admin));
+    assert_eq!(init_event_versions(&env), (7, 7));
+    assert!(client.try_init_admin_with_version(&Address::generate(&env), &Some(8)).is_err());
+}
+
+#[test]
+fn test_multisig_init_with_explicit_version() {
+    let env = Env::default();
+    let client = register(&env);
+    let signers = vec![&env, Address::generate(&env), Address::generate(&env)];
+    client.init_with_version(&signers, &2, &Some(7));
+
+    assert_eq!(client.get_version(), 7);
+    assert_eq!(init_event_versions(&env), (7, 7));
+    assert!(client.try_init(&signers, &2).is_err());
+}
+
+#[test]
+#[should_panic(expected = "121")]
+fn test_zero_initial_version_rejected() {
+    let env = Env::default();
+    let client = register(&env);
+    client.init_admin_with_version(&Address::generate(&env), &Some(0));
+}
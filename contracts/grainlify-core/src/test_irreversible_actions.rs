@@ -0,0 +1,124 @@
+//! # Irreversible Action Confirmation Tests
+//!
+//! - `request_irreversible` followed by `confirm_irreversible` of the same
+//!   action inside the window finalizes the contract or renounces the admin
+//! - Confirming a different action, before the cool-off, or after the
+//!   expiry fails; `cancel_irreversible` drops the request
+//! - A finalized contract refuses upgrades
+
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events as _, Ledger as _},
+    Address, Env, IntoVal, TryFromVal,
+};
+
+use crate::confirmation::{COOL_OFF, EXPIRY};
+use crate::testutils::{setup_admin, upload_dummy_wasm};
+use crate::{GrainlifyContractClient, IrreversibleAction, IrreversibleActionEvent};
+
+fn setup(env: &Env) -> (GrainlifyContractClient<'_>, Address) {
+    let (client, admin) = setup_admin(env);
+    env.ledger().set_timestamp(1_000);
+    (client, admin)
+}
+
+#[test]
+fn test_finalize_after_cool_off() {
+    let env = Env::default();
+    let (client, _) = setup(&env);
+    let pending = client.request_irreversible(&IrreversibleAction::Finalize);
+    assert_eq!(pending.confirm_after, 1_000 + COOL_OFF);
+    assert_eq!(pending.expires_at, 1_000 + EXPIRY);
+    assert_eq!(client.get_pending_irreversible(), Some(pending.clone()));
+
+    env.ledger().set_timestamp(pending.confirm_after);
+    client.confirm_irreversible(&IrreversibleAction::Finalize);
+    assert!(client.is_finalized());
+    assert_eq!(client.get_pending_irreversible(), None);
+
+    let topics = (symbol_short!("irrev"), symbol_short!("confirmed")).into_val(&env);
+    let event = env
+        .events()
+        .all()
+        .iter()
+        .find(|(_, t, _)| *t == topics)
+        .map(|(_, _, data)| IrreversibleActionEvent::try_from_val(&env, &data).unwrap())
+        .unwrap();
+    assert_eq!(event.action_hash, pending.action_hash);
+}
+
+#[test]
+#[should_panic(expected = "157")]
+fn test_finalized_contract_refuses_upgrades() {
+    let env = Env::default();
+    let (client, _) = setup(&env);
+    client.request_irreversible(&IrreversibleAction::Finalize);
+    env.ledger().set_timestamp(1_000 + COOL_OFF);
+    client.confirm_irreversible(&IrreversibleAction::Finalize);
+    client.upgrade(&upload_dummy_wasm(&env, 1));
+}
+
+#[test]
+fn test_renounce_admin() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let action = IrreversibleAction::RenounceAdmin(admin);
+    client.request_irreversible(&action);
+    env.ledger().set_timestamp(1_000 + COOL_OFF);
+    client.confirm_irreversible(&action);
+    assert_eq!(client.get_admin(), None);
+}
+
+#[test]
+#[should_panic(expected = "3")]
+fn test_renounce_other_address_rejected() {
+    let env = Env::default();
+    let (client, _) = setup(&env);
+    client.request_irreversible(&IrreversibleAction::RenounceAdmin(Address::generate(&env)));
+}
+
+#[test]
+#[should_panic(expected = "154")]
+fn test_mismatched_action_rejected() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    client.request_irreversible(&IrreversibleAction::Finalize);
+    env.ledger().set_timestamp(1_000 + COOL_OFF);
+    client.confirm_irreversible(&IrreversibleAction::RenounceAdmin(admin));
+}
+
+#[test]
+#[should_panic(expected = "155")]
+fn test_confirm_too_early_rejected() {
+    let env = Env::default();
+    let (client, _) = setup(&env);
+    client.request_irreversible(&IrreversibleAction::Finalize);
+    env.ledger().set_timestamp(1_000 + COOL_OFF - 1);
+    client.confirm_irreversible(&IrreversibleAction::Finalize);
+}
+
+#[test]
+#[should_panic(expected = "156")]
+fn test_confirm_after_expiry_rejected() {
+    let env = Env::default();
+    let (client, _) = setup(&env);
+    client.request_irreversible(&IrreversibleAction::Finalize);
+    env.ledger().set_timestamp(1_000 + EXPIRY);
+    client.confirm_irreversible(&IrreversibleAction::Finalize);
+}
+
+#[test]
+#[should_panic(expected = "153")]
+fn test_cancelled_request_cannot_be_confirmed() {
+    let env = Env::default();
+    let (client, _) = setup(&env);
+    client.request_irreversible(&IrreversibleAction::Finalize);
+    client.cancel_irreversible();
+    assert_eq!(client.get_pending_irreversible(), None);
+    env.ledger().set_timestamp(1_000 + COOL_OFF);
+    client.confirm_irreversible(&IrreversibleAction::Finalize);
+}
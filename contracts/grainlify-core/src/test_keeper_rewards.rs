@@ -0,0 +1,216 @@
+//! # Keeper Reward Tests
+//!
+//! - `bump_metrics_ttl` / `record_snapshot` pay the caller only when they did
+//!   useful work; no-op calls return `false` and pay nothing
+//! - An exhausted pool skips the payout without failing the maintenance call
+//! - Funding and configuration validation
+//! - Governance can drain the pool with `withdraw_reward_pool`; rewards can
+//!   only be disabled once the pool is empty
+//! - Multisig deployments manage rewards with action bundles
+
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{
+    testutils::{storage::Persistent as _, Address as _, Ledger as _},
+    token::{StellarAssetClient, TokenClient},
+    vec, Address, Env,
+};
+
+use crate::monitoring::{MetricsKey, METRICS_TTL_EXTEND_TO, METRICS_TTL_THRESHOLD, SNAPSHOT_INTERVAL};
+use crate::testutils::{setup_multisig, GovernanceDriver};
+use crate::{GrainlifyContract, GrainlifyContractClient, KeeperRewardConfig, ProposalAction};
+
+const REWARD: i128 = 10;
+
+struct Setup<'a> {
+    env: Env,
+    client: GrainlifyContractClient<'a>,
+    token: TokenClient<'a>,
+    keeper: Address,
+}
+
+/// Admin-initialized contract with a funded pool of `funded` tokens and
+/// monitoring counters already written by one `set_version` call.
+fn setup<'a>(funded: i128) -> Setup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+    // Long default TTLs so jumping past the bump window does not archive
+    // unrelated entries such as the contract instance.
+    env.ledger().set_min_persistent_entry_ttl(2_000_000);
+
+    let id = env.register_contract(None, GrainlifyContract);
+    let client = GrainlifyContractClient::new(&env, &id);
+    client.init_admin(&Address::generate(&env));
+    client.set_version(&3);
+
+    let token_id = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    let funder = Address::generate(&env);
+    StellarAssetClient::new(&env, &token_id).mint(&funder, &1_000);
+    client.set_reward_config(&Some(KeeperRewardConfig {
+        token: token_id.clone(),
+        reward_per_call: REWARD,
+    }));
+    if funded > 0 {
+        client.fund_rewards(&funder, &funded);
+    }
+
+    let token = TokenClient::new(&env, &token_id);
+    let keeper = Address::generate(&env);
+    Setup { env, client, token, keeper }
+}
+
+fn advance_past_bump_window(env: &Env) {
+    let seq = env.ledger().sequence();
+    env.ledger()
+        .set_sequence_number(seq + METRICS_TTL_EXTEND_TO - METRICS_TTL_THRESHOLD + 1);
+}
+
+#[test]
+fn test_first_bump_pays_and_extends_ttl() {
+    let s = setup(100);
+    assert!(s.client.bump_metrics_ttl(&s.keeper));
+    assert_eq!(s.token.balance(&s.keeper), REWARD);
+    assert_eq!(s.client.get_reward_pool_balance(), 100 - REWARD);
+
+    let ttl = s.env.as_contract(&s.client.address, || {
+        s.env.storage().persistent().get_ttl(&MetricsKey::Totals)
+    });
+    assert!(ttl >= METRICS_TTL_THRESHOLD);
+}
+
+#[test]
+fn test_redundant_bump_is_not_rewarded() {
+    let s = setup(100);
+    s.client.bump_metrics_ttl(&s.keeper);
+
+    assert!(!s.client.bump_metrics_ttl(&s.keeper));
+    assert_eq!(s.token.balance(&s.keeper), REWARD, "no-op must not pay");
+
+    advance_past_bump_window(&s.env);
+    assert!(s.client.bump_metrics_ttl(&s.keeper));
+    assert_eq!(s.token.balance(&s.keeper), 2 * REWARD);
+}
+
+#[test]
+fn test_snapshot_rewarded_once_per_interval() {
+    let s = setup(100);
+    s.env.ledger().set_timestamp(10_000);
+
+    assert!(s.client.record_snapshot(&s.keeper));
+    assert_eq!(s.client.get_recorded_snapshot().unwrap().timestamp, 10_000);
+    assert!(!s.client.record_snapshot(&s.keeper));
+    assert_eq!(s.token.balance(&s.keeper), REWARD);
+
+    s.env.ledger().set_timestamp(10_000 + SNAPSHOT_INTERVAL);
+    assert!(s.client.record_snapshot(&s.keeper));
+    assert_eq!(s.token.balance(&s.keeper), 2 * REWARD);
+}
+
+#[test]
+fn test_exhausted_pool_skips_payout() {
+    let s = setup(REWARD + 5);
+    assert!(s.client.record_snapshot(&s.keeper));
+    assert!(s.client.bump_metrics_ttl(&s.keeper), "work still happens");
+
+    assert_eq!(s.token.balance(&s.keeper), REWARD);
+    assert_eq!(s.client.get_reward_pool_balance(), 5);
+}
+
+#[test]
+fn test_maintenance_without_reward_config() {
+    let s = setup(0);
+    s.client.set_reward_config(&None);
+    assert!(s.client.record_snapshot(&s.keeper));
+    assert_eq!(s.token.balance(&s.keeper), 0);
+}
+
+#[test]
+fn test_fund_rewards_moves_tokens_into_contract() {
+    let s = setup(250);
+    assert_eq!(s.client.get_reward_config().unwrap().reward_per_call, REWARD);
+    assert_eq!(s.token.balance(&s.client.address), 250);
+    assert_eq!(s.client.get_reward_pool_balance(), 250);
+}
+
+#[test]
+#[should_panic(expected = "Reward per call must be positive")]
+fn test_zero_reward_rejected() {
+    let s = setup(0);
+    s.client.set_reward_config(&Some(KeeperRewardConfig {
+        token: s.token.address.clone(),
+        reward_per_call: 0,
+    }));
+}
+
+#[test]
+#[should_panic(expected = "Reward pool must be empty to change token")]
+fn test_token_change_with_funded_pool_rejected() {
+    let s = setup(50);
+    s.client.set_reward_config(&Some(KeeperRewardConfig {
+        token: Address::generate(&s.env),
+        reward_per_call: REWARD,
+    }));
+}
+
+#[test]
+#[should_panic(expected = "Reward pool must be empty to disable rewards")]
+fn test_disable_with_funded_pool_rejected() {
+    let s = setup(50);
+    s.client.set_reward_config(&None);
+}
+
+#[test]
+fn test_withdraw_then_disable() {
+    let s = setup(50);
+    let treasury = Address::generate(&s.env);
+
+    s.client.withdraw_reward_pool(&treasury, &20);
+    assert_eq!(s.client.get_reward_pool_balance(), 30);
+    s.client.withdraw_reward_pool(&treasury, &30);
+    assert_eq!(s.token.balance(&treasury), 50);
+    assert_eq!(s.token.balance(&s.client.address), 0);
+
+    s.client.set_reward_config(&None);
+    assert_eq!(s.client.get_reward_config(), None);
+}
+
+#[test]
+#[should_panic(expected = "Withdrawal exceeds reward pool")]
+fn test_withdraw_more_than_pool_rejected() {
+    let s = setup(50);
+    s.client.withdraw_reward_pool(&Address::generate(&s.env), &51);
+}
+
+#[test]
+fn test_withdraw_requires_governance_auth() {
+    let s = setup(50);
+    s.env.set_auths(&[]);
+    assert!(s.client.try_withdraw_reward_pool(&Address::generate(&s.env), &10).is_err());
+    assert_eq!(s.client.get_reward_pool_balance(), 50);
+}
+
+#[test]
+fn test_multisig_manages_rewards_through_actions() {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    let token_id = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    let config = KeeperRewardConfig { token: token_id.clone(), reward_per_call: REWARD };
+    assert!(client.try_set_reward_config(&Some(config.clone())).is_err());
+    GovernanceDriver::apply_actions(&env, &client, &signers, vec![&env, ProposalAction::SetRewardConfig(config.clone())]);
+    assert_eq!(client.get_reward_config(), Some(config));
+
+    let funder = Address::generate(&env);
+    StellarAssetClient::new(&env, &token_id).mint(&funder, &50);
+    client.fund_rewards(&funder, &50);
+    let treasury = Address::generate(&env);
+    let actions = vec![&env, ProposalAction::WithdrawRewardPool(treasury.clone(), 50), ProposalAction::ClearRewardConfig];
+    GovernanceDriver::apply_actions(&env, &client, &signers, actions);
+    assert_eq!(TokenClient::new(&env, &token_id).balance(&treasury), 50);
+    assert_eq!(client.get_reward_config(), None);
+}
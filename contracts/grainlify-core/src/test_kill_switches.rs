@@ -0,0 +1,194 @@
+//! # Function Kill Switch Tests
+//!
+//! - Disabling an entrypoint blocks it with `FunctionDisabled` (108)
+//! - Other entrypoints stay operational
+//! - Re-enabling restores the entrypoint
+//! - `enable_function`, `execute_upgrade` and the action proposal
+//!   entrypoints cannot be disabled
+//! - Multisig governance flips switches through `DisableFunction`/
+//!   `EnableFunction` actions
+//! - Propose, approve and execute variants each have their own switch
+
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{testutils::Address as _, vec, Address, BytesN, Env, Map, Symbol, Vec};
+
+use crate::testutils::{
+    setup_admin, setup_multisig, upload_dummy_wasm, GovernanceDriver,
+};
+use crate::{GrainlifyContractClient, ProposalAction};
+
+#[test]
+fn test_no_functions_disabled_after_init() {
+    let env = Env::default();
+    let (client, _admin) = setup_admin(&env);
+    assert_eq!(client.list_disabled_functions().len(), 0);
+}
+
+#[test]
+#[should_panic(expected = "108")]
+fn test_disabled_function_is_blocked() {
+    let env = Env::default();
+    let (client, _admin) = setup_admin(&env);
+    client.disable_function(&Symbol::new(&env, "set_version"));
+    client.set_version(&5);
+}
+
+#[test]
+fn test_other_functions_keep_working() {
+    let env = Env::default();
+    let (client, _admin) = setup_admin(&env);
+    client.disable_function(&Symbol::new(&env, "upgrade"));
+
+    client.set_version(&5);
+    assert_eq!(client.get_version(), 5);
+    assert!(client.try_upgrade(&BytesN::from_array(&env, &[0xAB; 32])).is_err());
+}
+
+#[test]
+fn test_enable_restores_function() {
+    let env = Env::default();
+    let (client, _admin) = setup_admin(&env);
+    let name = Symbol::new(&env, "set_version");
+
+    client.disable_function(&name);
+    assert_eq!(client.list_disabled_functions(), vec![&env, name.clone()]);
+    assert!(client.try_set_version(&5).is_err());
+
+    client.enable_function(&name);
+    assert_eq!(client.list_disabled_functions().len(), 0);
+    client.set_version(&5);
+    assert_eq!(client.get_version(), 5);
+}
+
+#[test]
+fn test_disable_is_idempotent() {
+    let env = Env::default();
+    let (client, _admin) = setup_admin(&env);
+    let name = Symbol::new(&env, "ping_watchdog");
+    client.disable_function(&name);
+    client.disable_function(&name);
+    assert_eq!(client.list_disabled_functions().len(), 1);
+}
+
+#[test]
+#[should_panic(expected = "Function cannot be disabled")]
+fn test_enable_function_cannot_be_disabled() {
+    let env = Env::default();
+    let (client, _admin) = setup_admin(&env);
+    client.disable_function(&Symbol::new(&env, "enable_function"));
+}
+
+#[test]
+#[should_panic(expected = "Function cannot be disabled")]
+fn test_execute_upgrade_cannot_be_disabled() {
+    let env = Env::default();
+    let (client, _admin) = setup_admin(&env);
+    client.disable_function(&Symbol::new(&env, "execute_upgrade"));
+}
+
+#[test]
+fn test_disable_function_can_disable_itself_and_be_recovered() {
+    let env = Env::default();
+    let (client, _admin) = setup_admin(&env);
+    let name = Symbol::new(&env, "disable_function");
+
+    client.disable_function(&name);
+    assert!(client.try_disable_function(&Symbol::new(&env, "upgrade")).is_err());
+
+    client.enable_function(&name);
+    client.disable_function(&Symbol::new(&env, "upgrade"));
+    assert_eq!(client.list_disabled_functions().len(), 1);
+}
+
+#[test]
+fn test_multisig_switches_functions_through_actions() {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    let name = Symbol::new(&env, "propose_upgrade");
+    assert!(client.try_disable_function(&name).is_err());
+
+    GovernanceDriver::apply_actions(&env, &client, &signers, vec![&env, ProposalAction::DisableFunction(name.clone())]);
+    assert_eq!(client.list_disabled_functions(), vec![&env, name.clone()]);
+    assert!(client.try_propose_upgrade(&signers.get(0).unwrap(), &upload_dummy_wasm(&env, 1), &0).is_err());
+
+    GovernanceDriver::apply_actions(&env, &client, &signers, vec![&env, ProposalAction::EnableFunction(name)]);
+    GovernanceDriver::propose(&env, &client, &signers, &upload_dummy_wasm(&env, 1));
+}
+
+#[test]
+fn test_action_entrypoints_cannot_be_disabled() {
+    let env = Env::default();
+    let (client, _admin) = setup_admin(&env);
+    for name in ["propose_actions", "approve_actions", "execute_proposal"] {
+        assert!(client.try_disable_function(&Symbol::new(&env, name)).is_err(), "{name} was disabled");
+    }
+}
+
+/// Calls one variant entrypoint on a contract with a proposal that is
+/// approved and past its timelock; returns whether the call succeeded.
+type VariantCall = fn(&Env, &GrainlifyContractClient, &Vec<Address>, u64) -> bool;
+
+const VARIANTS: [(&str, VariantCall); 9] = [
+    ("propose_from_template", |env, client, signers, _| {
+        client.try_propose_from_template(&Symbol::new(env, "tmpl"), &signers.get(0).unwrap(), &Map::new(env)).is_ok()
+    }),
+    ("propose_upgrade_in_window", |env, client, signers, _| {
+        let wasm = upload_dummy_wasm(env, 2);
+        client.try_propose_upgrade_in_window(&signers.get(0).unwrap(), &wasm, &0, &None, &None).is_ok()
+    }),
+    ("propose_upgrade_with_external_id", |env, client, signers, _| {
+        let wasm = upload_dummy_wasm(env, 2);
+        client.try_propose_upgrade_with_external_id(&signers.get(0).unwrap(), &wasm, &7, &0).is_ok()
+    }),
+    ("propose_upgrade_min_protocol", |env, client, signers, _| {
+        let wasm = upload_dummy_wasm(env, 2);
+        client.try_propose_upgrade_min_protocol(&signers.get(0).unwrap(), &wasm, &0, &0).is_ok()
+    }),
+    ("propose_upgrade_idempotent", |env, client, signers, _| {
+        let wasm = upload_dummy_wasm(env, 2);
+        client.try_propose_upgrade_idempotent(&signers.get(0).unwrap(), &wasm, &1).is_ok()
+    }),
+    ("approve_upgrade_v2", |_, client, signers, proposal_id| {
+        let record = client.get_upgrade_proposal(&proposal_id).unwrap();
+        let description = client.get_approval_description(&proposal_id);
+        let signer = signers.get(2).unwrap();
+        client.try_approve_upgrade_v2(&proposal_id, &signer, &record.wasm_hash, &description, &None, &None).is_ok()
+    }),
+    ("execute_upgrade_as", |_, client, signers, proposal_id| {
+        client.try_execute_upgrade_as(&signers.get(0).unwrap(), &proposal_id, &false).is_ok()
+    }),
+    ("execute_upgrade_relayed", |_, client, _, proposal_id| {
+        client.try_execute_upgrade_relayed(&client.get_relayer().unwrap(), &proposal_id, &false).is_ok()
+    }),
+    ("attempt_execute_upgrade", |_, client, signers, proposal_id| {
+        client.try_attempt_execute_upgrade(&signers.get(0).unwrap(), &proposal_id) == Ok(Ok(None))
+    }),
+];
+
+#[test]
+fn test_variant_entrypoints_have_own_switch() {
+    for (name, call) in VARIANTS {
+        let env = Env::default();
+        let (client, signers) = setup_multisig(&env, 3, 2);
+        let setup = vec![
+            &env,
+            ProposalAction::CreateTemplate(Symbol::new(&env, "tmpl"), vec![&env, ProposalAction::SetVersion(3)]),
+            ProposalAction::SetRelayer(Address::generate(&env)),
+        ];
+        GovernanceDriver::apply_actions(&env, &client, &signers, setup);
+        let proposal_id = GovernanceDriver::propose(&env, &client, &signers, &upload_dummy_wasm(&env, 1));
+        GovernanceDriver::approve_to_threshold(&env, &client, &signers, proposal_id);
+        GovernanceDriver::skip_timelock(&env, &client);
+
+        let name_symbol = Symbol::new(&env, name);
+        let disable = vec![&env, ProposalAction::DisableFunction(name_symbol.clone())];
+        GovernanceDriver::apply_actions(&env, &client, &signers, disable);
+        assert!(!call(&env, &client, &signers, proposal_id), "{name} ran while disabled");
+        let enable = vec![&env, ProposalAction::EnableFunction(name_symbol)];
+        GovernanceDriver::apply_actions(&env, &client, &signers, enable);
+        assert!(call(&env, &client, &signers, proposal_id), "{name} failed once enabled");
+    }
+}
@@ -0,0 +1,151 @@
+//! # Lifecycle Event Tests
+//!
+//! Verifies the first-class `("grainlify", ...)` lifecycle events:
+//! - `init`     — emitted by every init path with admin/signers and version
+//! - `upgraded` — emitted by `upgrade` / `execute_upgrade` with old and new hash
+//! - `version`  — emitted by `set_version` with old/new values and the caller
+
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{
+    testutils::{Address as _, Events, Ledger as _},
+    vec, Address, BytesN, Env, Symbol, TryFromVal, Val,
+};
+
+use crate::testutils::{setup_admin, upload_dummy_wasm};
+use crate::{
+    GrainlifyContract, GrainlifyContractClient, InitializedEvent, UpgradedEvent,
+    VersionChangedEvent,
+};
+
+fn lifecycle_events(env: &Env, name: &str) -> std::vec::Vec<Val> {
+    let mut out = std::vec::Vec::new();
+    for (_, topics, data) in env.events().all().iter() {
+        if topics.len() != 2 {
+            continue;
+        }
+        let t0 = Symbol::try_from_val(env, &topics.get(0).unwrap());
+        let t1 = Symbol::try_from_val(env, &topics.get(1).unwrap());
+        if t0 == Ok(Symbol::new(env, "grainlify")) && t1 == Ok(Symbol::new(env, name)) {
+            out.push(data);
+        }
+    }
+    out
+}
+
+#[test]
+fn test_init_admin_emits_init_event() {
+    let env = Env::default();
+    let id = env.register_contract(None, GrainlifyContract);
+    let client = GrainlifyContractClient::new(&env, &id);
+    let admin = Address::generate(&env);
+    env.mock_all_auths();
+    client.init_admin(&admin);
+
+    let events = lifecycle_events(&env, "init");
+    assert_eq!(events.len(), 1);
+    let payload = InitializedEvent::try_from_val(&env, &events[0]).unwrap();
+    assert_eq!(payload.admin, Some(admin));
+    assert_eq!(payload.signers.len(), 0);
+    assert_eq!(payload.threshold, 0);
+    assert_eq!(payload.version, 2);
+}
+
+#[test]
+fn test_multisig_init_emits_init_event_with_signers() {
+    let env = Env::default();
+    let id = env.register_contract(None, GrainlifyContract);
+    let client = GrainlifyContractClient::new(&env, &id);
+    let s1 = Address::generate(&env);
+    let s2 = Address::generate(&env);
+    env.mock_all_auths();
+    client.init(&vec![&env, s1.clone(), s2.clone()], &2u32);
+
+    let events = lifecycle_events(&env, "init");
+    assert_eq!(events.len(), 1);
+    let payload = InitializedEvent::try_from_val(&env, &events[0]).unwrap();
+    assert_eq!(payload.admin, None);
+    assert_eq!(payload.signers, vec![&env, s1, s2]);
+    assert_eq!(payload.threshold, 2);
+}
+
+#[test]
+fn test_set_version_emits_version_event() {
+    let env = Env::default();
+    let (client, admin) = setup_admin(&env);
+    env.ledger().set_timestamp(1_000);
+    client.set_version(&7);
+
+    let events = lifecycle_events(&env, "version");
+    assert_eq!(events.len(), 1);
+    let payload = VersionChangedEvent::try_from_val(&env, &events[0]).unwrap();
+    assert_eq!(payload.old_version, 2);
+    assert_eq!(payload.new_version, 7);
+    assert_eq!(payload.caller, admin);
+    assert_eq!(payload.timestamp, 1_000);
+}
+
+#[test]
+fn test_upgrade_emits_upgraded_event() {
+    let env = Env::default();
+    let (client, _admin) = setup_admin(&env);
+    let first = upload_dummy_wasm(&env, 1);
+
+    client.upgrade(&first);
+    let events = lifecycle_events(&env, "upgraded");
+    assert_eq!(events.len(), 1);
+    let payload = UpgradedEvent::try_from_val(&env, &events[0]).unwrap();
+    assert_eq!(
+        payload.old_wasm_hash,
+        BytesN::from_array(&env, &[0; 32]),
+        "no hash is tracked before the first upgrade"
+    );
+    assert_eq!(payload.new_wasm_hash, first);
+    assert_eq!(payload.new_version, 2);
+}
+
+#[test]
+fn test_second_upgrade_reports_old_hash() {
+    let env = Env::default();
+    let (client, _admin) = setup_admin(&env);
+    let first = upload_dummy_wasm(&env, 1);
+    let second = upload_dummy_wasm(&env, 2);
+
+    // The uploaded module exports nothing, so a second real upgrade cannot be
+    // invoked after the first; seed the tracked hash as a prior upgrade would.
+    env.as_contract(&client.address, || {
+        env.storage()
+            .instance()
+            .set(&crate::DataKey::CurrentWasmHash, &first);
+    });
+    client.upgrade(&second);
+
+    let events = lifecycle_events(&env, "upgraded");
+    assert_eq!(events.len(), 1);
+    let payload = UpgradedEvent::try_from_val(&env, &events[0]).unwrap();
+    assert_eq!(payload.old_wasm_hash, first);
+    assert_eq!(payload.new_wasm_hash, second);
+}
+
+#[test]
+fn test_execute_upgrade_emits_upgraded_event() {
+    let env = Env::default();
+    let id = env.register_contract(None, GrainlifyContract);
+    let client = GrainlifyContractClient::new(&env, &id);
+    let signer = Address::generate(&env);
+    env.mock_all_auths();
+    client.init(&vec![&env, signer.clone()], &1u32);
+
+    let wasm = upload_dummy_wasm(&env, 3);
+    let proposal_id = client.propose_upgrade(&signer, &wasm, &0u64);
+    client.approve_upgrade(&proposal_id, &signer);
+    env.ledger().set_timestamp(env.ledger().timestamp() + 86_400);
+    client.execute_upgrade(&proposal_id);
+
+    let events = lifecycle_events(&env, "upgraded");
+    assert_eq!(events.len(), 1);
+    let payload = UpgradedEvent::try_from_val(&env, &events[0]).unwrap();
+    assert_eq!(payload.new_wasm_hash, wasm);
+}
@@ -0,0 +1,119 @@
+//! # Maintenance Mode Tests
+//!
+//! - While active, non-governance mutating calls fail with `MaintenanceMode`
+//! - Reads, governance calls, and executing the pending upgrade keep working
+//! - Maintenance ends on its own at the deadline, or early via
+//!   `exit_maintenance`
+//! - Only governance can enter it, only with a future deadline, and not in
+//!   read-only mode
+//! - Multisig deployments enter and exit it with action bundles
+
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{testutils::Ledger as _, vec, Address, Env, Vec};
+
+use crate::testutils::{setup_admin, setup_multisig, upload_dummy_wasm, GovernanceDriver};
+use crate::{GrainlifyContractClient, MaintenanceStatus, ProposalAction};
+
+fn setup(env: &Env) -> (GrainlifyContractClient<'_>, Vec<Address>) {
+    setup_multisig(env, 3, 2)
+}
+
+/// Enters maintenance through an action bundle, lasting `secs` past the
+/// moment the bundle executes. Returns the deadline.
+fn enter(env: &Env, client: &GrainlifyContractClient, signers: &Vec<Address>, secs: u64) -> u64 {
+    let until = env.ledger().timestamp() + client.get_timelock_delay() + secs;
+    GovernanceDriver::apply_actions(env, client, signers, vec![env, ProposalAction::EnterMaintenance(until)]);
+    until
+}
+
+#[test]
+fn test_non_governance_calls_blocked_until_deadline() {
+    let env = Env::default();
+    let (client, signers) = setup(&env);
+    let until = enter(&env, &client, &signers, 1_000);
+    assert_eq!(client.maintenance_status(), MaintenanceStatus { active: true, until });
+
+    assert!(client.try_sweep_proposals(&10).is_err());
+    assert!(client.try_prune_upgrade_proposal(&1).is_err());
+
+    env.ledger().set_timestamp(until);
+    assert!(!client.maintenance_status().active, "auto-exits at the deadline");
+    client.sweep_proposals(&10);
+}
+
+#[test]
+#[should_panic(expected = "126")]
+fn test_blocked_call_fails_with_typed_error() {
+    let env = Env::default();
+    let (client, signers) = setup(&env);
+    enter(&env, &client, &signers, 100);
+    client.sweep_proposals(&10);
+}
+
+#[test]
+fn test_governance_and_upgrade_execution_allowed() {
+    let env = Env::default();
+    let (client, signers) = setup(&env);
+    let wasm = upload_dummy_wasm(&env, 1);
+    let proposal_id = client.propose_upgrade(&signers.get(0).unwrap(), &wasm, &0);
+    enter(&env, &client, &signers, 10 * client.get_timelock_delay());
+
+    GovernanceDriver::apply_actions(&env, &client, &signers, vec![&env, ProposalAction::SetVersion(3)]);
+    assert_eq!(client.get_version(), 3);
+    client.approve_upgrade(&proposal_id, &signers.get(0).unwrap());
+    client.approve_upgrade(&proposal_id, &signers.get(1).unwrap());
+    assert_eq!(client.preview_execution(&proposal_id).failing.len(), 1, "only the timelock");
+
+    GovernanceDriver::skip_timelock(&env, &client);
+    assert!(client.maintenance_status().active);
+    assert_eq!(client.execute_upgrade_v2(&proposal_id).wasm_hash, wasm);
+}
+
+#[test]
+fn test_exit_maintenance_early() {
+    let env = Env::default();
+    let (client, signers) = setup(&env);
+    enter(&env, &client, &signers, 5 * client.get_timelock_delay());
+    GovernanceDriver::apply_actions(&env, &client, &signers, vec![&env, ProposalAction::ExitMaintenance]);
+    assert_eq!(client.maintenance_status(), MaintenanceStatus { active: false, until: 0 });
+    client.sweep_proposals(&10);
+}
+
+#[test]
+fn test_admin_enters_and_exits_directly() {
+    let env = Env::default();
+    let (client, _admin) = setup_admin(&env);
+    env.ledger().set_timestamp(1_000);
+    client.enter_maintenance(&5_000);
+    assert_eq!(client.maintenance_status(), MaintenanceStatus { active: true, until: 5_000 });
+    client.exit_maintenance();
+    assert!(!client.maintenance_status().active);
+}
+
+#[test]
+fn test_enter_requires_governance_and_future_deadline() {
+    let env = Env::default();
+    let (client, _admin) = setup_admin(&env);
+    env.ledger().set_timestamp(1_000);
+    assert!(client.try_enter_maintenance(&1_000).is_err());
+
+    env.set_auths(&[]);
+    assert!(client.try_enter_maintenance(&5_000).is_err());
+    assert!(!client.maintenance_status().active);
+
+    let (client, _signers) = setup(&env);
+    assert!(client.try_enter_maintenance(&5_000).is_err());
+    assert!(client.try_exit_maintenance().is_err());
+}
+
+#[test]
+fn test_enter_blocked_in_read_only_mode() {
+    let env = Env::default();
+    let (client, _admin) = setup_admin(&env);
+    client.set_read_only_mode(&true);
+    assert!(client.try_enter_maintenance(&5_000).is_err());
+    assert!(!client.maintenance_status().active);
+}
@@ -0,0 +1,153 @@
+//! # Metrics Export Tests
+//!
+//! - `export_metrics` pages through the totals and each tracked function's
+//!   call count, capping the page size
+//! - Exported rows imported after a reset, or into a fresh instance, export
+//!   back unchanged
+//! - `import_metrics` is governance only and refuses a store with any
+//!   recorded total, including one left by an earlier import
+//! - Rows with more errors than operations are rejected
+//! - Multisig deployments import with an action bundle
+
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{symbol_short, testutils::Address as _, testutils::Ledger as _, vec, Address, Env, Symbol, Vec};
+
+use crate::monitoring::{self, MetricsKey};
+use crate::paging::MAX_PAGE_SIZE;
+use crate::testutils::{setup_admin, setup_multisig, GovernanceDriver};
+use crate::{GrainlifyContractClient, ProposalAction};
+
+fn export_all(client: &GrainlifyContractClient) -> Vec<(Symbol, u64)> {
+    let mut rows = Vec::new(&client.env);
+    for page in 0.. {
+        let chunk = client.export_metrics(&page, &2);
+        rows.append(&chunk.items);
+        if !chunk.has_more {
+            return rows;
+        }
+    }
+    unreachable!()
+}
+
+/// Admin contract with a few tracked operations under two functions.
+fn tracked_client(env: &Env) -> GrainlifyContractClient<'_> {
+    let (client, _admin) = setup_admin(env);
+    env.ledger().set_timestamp(50);
+    client.set_version(&2);
+    client.set_version(&3);
+    env.as_contract(&client.address, || {
+        let operation = symbol_short!("audit");
+        monitoring::track(env, operation.clone(), Address::generate(env), false, None, true);
+        monitoring::emit_performance(env, operation, 4);
+    });
+    client
+}
+
+#[test]
+fn test_export_lists_totals_then_functions() {
+    let env = Env::default();
+    let client = tracked_client(&env);
+
+    let rows = export_all(&client);
+    let analytics = client.get_analytics();
+    assert_eq!(rows.get(0), Some((Symbol::new(&env, "op_count"), analytics.operation_count)));
+    assert_eq!(rows.get(1), Some((Symbol::new(&env, "err_count"), analytics.error_count)));
+    assert_eq!(rows.get(2), Some((Symbol::new(&env, "last_op_ts"), 50)));
+    for (function, count) in rows.slice(3..).iter() {
+        assert_eq!(client.get_performance_stats(&function).call_count, count);
+    }
+    assert!(rows.len() > 3);
+}
+
+#[test]
+fn test_page_size_is_capped() {
+    let env = Env::default();
+    let client = tracked_client(&env);
+    let total = export_all(&client).len();
+    assert_eq!(client.export_metrics(&0, &(MAX_PAGE_SIZE + 10)).items.len(), total.min(MAX_PAGE_SIZE));
+    assert!(client.export_metrics(&1, &MAX_PAGE_SIZE).items.is_empty());
+    assert!(client.export_metrics(&0, &0).items.is_empty());
+}
+
+#[test]
+fn test_reset_and_import_restores_export() {
+    let env = Env::default();
+    let client = tracked_client(&env);
+    let exported = export_all(&client);
+
+    env.as_contract(&client.address, || {
+        let storage = env.storage().persistent();
+        storage.remove(&MetricsKey::Totals);
+        for (function, _) in exported.slice(3..).iter() {
+            storage.remove(&MetricsKey::FunctionPerf(function));
+        }
+        storage.remove(&MetricsKey::PerfIndex);
+    });
+    assert_eq!(client.get_analytics().operation_count, 0);
+
+    client.import_metrics(&exported);
+    assert_eq!(export_all(&client), exported);
+}
+
+#[test]
+fn test_import_into_fresh_instance() {
+    let env = Env::default();
+    let source = tracked_client(&env);
+    let exported = export_all(&source);
+
+    let (target, _admin) = setup_admin(&env);
+    target.import_metrics(&exported);
+    assert_eq!(export_all(&target), exported);
+    assert_eq!(target.get_analytics().operation_count, source.get_analytics().operation_count);
+}
+
+#[test]
+#[should_panic(expected = "Metrics must be empty to import")]
+fn test_import_rejected_once_counters_exist() {
+    let env = Env::default();
+    let client = tracked_client(&env);
+    client.import_metrics(&vec![&env, (Symbol::new(&env, "op_count"), 1)]);
+}
+
+#[test]
+fn test_import_requires_governance() {
+    let env = Env::default();
+    let (client, _admin) = setup_admin(&env);
+    env.set_auths(&[]);
+    assert!(client.try_import_metrics(&vec![&env, (Symbol::new(&env, "op_count"), 1)]).is_err());
+}
+
+#[test]
+#[should_panic(expected = "Metrics must be empty to import")]
+fn test_second_import_rejected_without_operations() {
+    let env = Env::default();
+    let (client, _admin) = setup_admin(&env);
+    client.import_metrics(&vec![&env, (Symbol::new(&env, "last_op_ts"), 5)]);
+    client.import_metrics(&vec![&env, (Symbol::new(&env, "last_op_ts"), 9)]);
+}
+
+#[test]
+#[should_panic(expected = "Imported errors exceed operations")]
+fn test_import_rejects_more_errors_than_operations() {
+    let env = Env::default();
+    let (client, _admin) = setup_admin(&env);
+    client.import_metrics(&vec![
+        &env,
+        (Symbol::new(&env, "op_count"), 1),
+        (Symbol::new(&env, "err_count"), 2),
+    ]);
+}
+
+#[test]
+fn test_multisig_imports_through_actions() {
+    let env = Env::default();
+    let exported = export_all(&tracked_client(&env));
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    assert!(client.try_import_metrics(&exported).is_err());
+
+    GovernanceDriver::apply_actions(&env, &client, &signers, vec![&env, ProposalAction::ImportMetrics(exported.clone())]);
+    assert_eq!(export_all(&client), exported);
+}
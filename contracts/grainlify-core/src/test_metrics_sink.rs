@@ -0,0 +1,152 @@
+//! # Metrics Contract Tests
+//!
+//! - Detached (default): tracked calls update the internal counters
+//! - Attached: tracked calls are forwarded to the metrics contract and the
+//!   internal counters stay untouched; tracking overrides still apply
+//! - A trapping metrics contract never fails the tracked call, which is
+//!   then recorded internally and flagged by `("metric", "sink_err")`
+//! - Only governance can attach or detach a metrics contract; multisig
+//!   deployments do it with action bundles
+
+#![cfg(test)]
+
+extern crate std;
+
+use grainlify_metrics::{CallTotals, MetricsContract, MetricsContractClient};
+use soroban_sdk::{symbol_short, testutils::Address as _, testutils::Events as _, vec, Address, Env, IntoVal};
+
+use crate::testutils::{setup_admin, setup_multisig, GovernanceDriver};
+use crate::{GrainlifyContractClient, ProposalAction};
+
+mod trapping_sink {
+    use grainlify_upgradeable::MetricsSink;
+    use soroban_sdk::{contract, contractimpl, Address, Env, Symbol};
+
+    #[contract]
+    pub struct TrappingSink;
+
+    #[contractimpl]
+    impl MetricsSink for TrappingSink {
+        fn record_call(_env: Env, _operation: Symbol, _caller: Address, _success: bool, _duration: u64) {
+            panic!("metrics contract down");
+        }
+    }
+}
+
+use trapping_sink::TrappingSink;
+
+/// Metrics contract initialized for `core`.
+fn attach_metrics<'a>(env: &Env, core: &GrainlifyContractClient) -> MetricsContractClient<'a> {
+    let metrics = MetricsContractClient::new(env, &env.register_contract(None, MetricsContract));
+    metrics.init(&core.address);
+    core.set_metrics_contract(&Some(metrics.address.clone()));
+    metrics
+}
+
+fn sink_errors(env: &Env) -> usize {
+    let topics = (symbol_short!("metric"), symbol_short!("sink_err")).into_val(env);
+    env.events().all().iter().filter(|(_, t, _)| *t == topics).count()
+}
+
+#[test]
+fn test_detached_uses_internal_counters() {
+    let env = Env::default();
+    let (client, _admin) = setup_admin(&env);
+    assert_eq!(client.get_metrics_contract(), None);
+    client.set_version(&2);
+    assert_eq!(client.get_analytics().operation_count, 1);
+}
+
+#[test]
+fn test_attached_forwards_tracked_calls() {
+    let env = Env::default();
+    let (client, _admin) = setup_admin(&env);
+    let metrics = attach_metrics(&env, &client);
+    assert_eq!(client.get_metrics_contract(), Some(metrics.address.clone()));
+
+    client.set_version(&2);
+    client.set_version(&3);
+    assert_eq!(metrics.get_call_stats(&symbol_short!("set_ver")).call_count, 2);
+    assert_eq!(metrics.get_totals(), CallTotals { call_count: 2, error_count: 0 });
+    assert_eq!(client.get_analytics().operation_count, 0);
+}
+
+#[test]
+fn test_tracking_override_applies_to_forwarded_calls() {
+    let env = Env::default();
+    let (client, _admin) = setup_admin(&env);
+    let metrics = attach_metrics(&env, &client);
+    client.set_tracking(&symbol_short!("set_ver"), &false);
+    client.set_version(&2);
+    assert_eq!(metrics.get_totals().call_count, 0);
+}
+
+#[test]
+fn test_detaching_restores_internal_counters() {
+    let env = Env::default();
+    let (client, _admin) = setup_admin(&env);
+    let metrics = attach_metrics(&env, &client);
+    client.set_version(&2);
+
+    client.set_metrics_contract(&None);
+    client.set_version(&3);
+    assert_eq!(metrics.get_totals().call_count, 1);
+    assert_eq!(client.get_analytics().operation_count, 1);
+}
+
+#[test]
+fn test_trapping_metrics_contract_is_isolated() {
+    let env = Env::default();
+    let (client, _admin) = setup_admin(&env);
+    client.set_metrics_contract(&Some(env.register_contract(None, TrappingSink)));
+
+    client.set_version(&2);
+    assert_eq!(client.get_version(), 2);
+    assert_eq!(sink_errors(&env), 1);
+    assert_eq!(client.get_analytics().operation_count, 1, "falls back to internal counters");
+}
+
+#[test]
+fn test_uninitialized_metrics_contract_is_isolated() {
+    let env = Env::default();
+    let (client, _admin) = setup_admin(&env);
+    let metrics = MetricsContractClient::new(&env, &env.register_contract(None, MetricsContract));
+    metrics.init(&Address::generate(&env));
+    client.set_metrics_contract(&Some(metrics.address.clone()));
+
+    client.set_version(&2);
+    assert_eq!(client.get_version(), 2);
+    assert_eq!(metrics.get_totals().call_count, 0);
+}
+
+#[test]
+fn test_set_metrics_contract_requires_governance() {
+    let env = Env::default();
+    let (client, _admin) = setup_admin(&env);
+    env.set_auths(&[]);
+    assert!(client.try_set_metrics_contract(&Some(Address::generate(&env))).is_err());
+}
+
+#[test]
+fn test_multisig_attaches_and_detaches_through_actions() {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    let metrics = MetricsContractClient::new(&env, &env.register_contract(None, MetricsContract));
+    metrics.init(&client.address);
+    assert!(client.try_set_metrics_contract(&Some(metrics.address.clone())).is_err());
+
+    let attach = vec![&env, ProposalAction::SetMetricsContract(metrics.address.clone())];
+    GovernanceDriver::apply_actions(&env, &client, &signers, attach);
+    assert_eq!(client.get_metrics_contract(), Some(metrics.address.clone()));
+
+    GovernanceDriver::apply_actions(&env, &client, &signers, vec![&env, ProposalAction::ClearMetricsContract]);
+    assert_eq!(client.get_metrics_contract(), None);
+}
+
+#[test]
+#[should_panic(expected = "145")]
+fn test_self_as_metrics_contract_rejected() {
+    let env = Env::default();
+    let (client, _admin) = setup_admin(&env);
+    client.set_metrics_contract(&Some(client.address.clone()));
+}
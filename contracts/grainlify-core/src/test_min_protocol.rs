@@ -0,0 +1,67 @@
+//! # Minimum Protocol Tests
+//!
+//! - `propose_upgrade_min_protocol` records the requirement, which
+//!   `get_upgrade_proposal` reports; plain proposals have none
+//! - On an older network protocol `execute_upgrade` fails with
+//!   `ProtocolTooOld` and `preview_execution` lists the check; once the
+//!   network catches up the same proposal executes
+
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{testutils::Ledger as _, vec, Address, Env, Vec};
+
+use crate::testutils::{upload_dummy_wasm, GovernanceDriver};
+use crate::{ExecutionCheck, GrainlifyContractClient};
+
+const NETWORK_PROTOCOL: u32 = 21;
+
+/// Opens an approved proposal requiring `NETWORK_PROTOCOL`, with the
+/// timelock elapsed and the network one protocol behind.
+fn setup(env: &Env) -> (GrainlifyContractClient<'_>, Vec<Address>, u64) {
+    let (client, signers) = GovernanceDriver::bootstrap_multisig(env, 3, 2);
+    let proposal_id = client.propose_upgrade_min_protocol(
+        &signers.get(0).unwrap(),
+        &upload_dummy_wasm(env, 1),
+        &NETWORK_PROTOCOL,
+        &0,
+    );
+    GovernanceDriver::approve_to_threshold(env, &client, &signers, proposal_id);
+    GovernanceDriver::skip_timelock(env, &client);
+    env.ledger().set_protocol_version(NETWORK_PROTOCOL - 1);
+    (client, signers, proposal_id)
+}
+
+#[test]
+fn test_requirement_reported() {
+    let env = Env::default();
+    let (client, signers, proposal_id) = setup(&env);
+    let record = client.get_upgrade_proposal(&proposal_id).unwrap();
+    assert_eq!(record.min_protocol, Some(NETWORK_PROTOCOL));
+
+    env.ledger().set_protocol_version(NETWORK_PROTOCOL);
+    let plain = GovernanceDriver::propose(&env, &client, &signers, &upload_dummy_wasm(&env, 2));
+    assert_eq!(client.get_upgrade_proposal(&plain).unwrap().min_protocol, None);
+}
+
+#[test]
+#[should_panic(expected = "159")]
+fn test_execution_refused_on_older_protocol() {
+    let env = Env::default();
+    let (client, _, proposal_id) = setup(&env);
+    client.execute_upgrade(&proposal_id);
+}
+
+#[test]
+fn test_proposal_waits_for_network_upgrade() {
+    let env = Env::default();
+    let (client, _, proposal_id) = setup(&env);
+    let preview = client.preview_execution(&proposal_id);
+    assert_eq!(preview.failing, vec![&env, ExecutionCheck::ProtocolTooOld]);
+
+    env.ledger().set_protocol_version(NETWORK_PROTOCOL);
+    assert!(client.preview_execution(&proposal_id).executable);
+    let receipt = client.execute_upgrade_v2(&proposal_id);
+    assert_eq!(receipt.proposal_id, proposal_id);
+}
@@ -0,0 +1,70 @@
+//! # Governance Mode Stats Tests
+//!
+//! - Tracked operations are counted under the mode the contract was in
+//! - `migrate_to_multisig` records the switchover, so each mode reports the
+//!   time spent in it
+//! - Lifetime analytics keep counting across the switch
+
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{symbol_short, testutils::Address as _, testutils::Ledger as _, vec, Address, Env};
+
+use crate::monitoring::{ADMIN_MODE, MULTISIG_MODE};
+use crate::testutils::{setup_admin, upload_dummy_wasm, GovernanceDriver};
+use crate::{GrainlifyContract, InitState};
+
+#[test]
+fn test_operations_split_by_mode() {
+    let env = Env::default();
+    let (client, _admin) = setup_admin(&env);
+    env.ledger().set_timestamp(10);
+    client.set_version(&2);
+    client.set_version(&3);
+
+    let admin_stats = client.get_mode_stats(&ADMIN_MODE);
+    assert_eq!(admin_stats.operation_count, 2);
+    assert_eq!(admin_stats.by_operation.get(symbol_short!("set_ver")), Some(2));
+    assert_eq!(admin_stats.duration, 10);
+    assert_eq!(client.get_mode_stats(&MULTISIG_MODE).operation_count, 0);
+
+    env.ledger().set_timestamp(100);
+    let signers = vec![&env, Address::generate(&env), Address::generate(&env), Address::generate(&env)];
+    client.migrate_to_multisig(&signers, &2, &false);
+    assert_eq!(client.get_init_state(), InitState::MultisigMode(3, 2));
+    assert_eq!(client.get_admin(), None);
+    assert!(client.try_set_version(&4).is_err());
+
+    GovernanceDriver::full_upgrade(&env, &client, &signers, &upload_dummy_wasm(&env, 1));
+    let now = env.ledger().timestamp();
+
+    env.as_contract(&client.address, || {
+        let admin_stats = GrainlifyContract::get_mode_stats(env.clone(), ADMIN_MODE);
+        assert_eq!(admin_stats.operation_count, 2);
+        assert_eq!(admin_stats.duration, 100);
+
+        let multisig_stats = GrainlifyContract::get_mode_stats(env.clone(), MULTISIG_MODE);
+        assert_eq!(multisig_stats.operation_count, 1);
+        assert_eq!(multisig_stats.by_operation.get(symbol_short!("exec_upg")), Some(1));
+        assert_eq!(multisig_stats.duration, now - 100);
+
+        assert_eq!(GrainlifyContract::get_analytics(env.clone()).operation_count, 2);
+    });
+}
+
+#[test]
+fn test_migrate_to_multisig_requires_admin_mode() {
+    let env = Env::default();
+    let (client, signers) = GovernanceDriver::bootstrap_multisig(&env, 3, 2);
+    assert!(client.try_migrate_to_multisig(&signers, &2, &false).is_err());
+}
+
+#[test]
+fn test_migrate_to_multisig_validates_threshold() {
+    let env = Env::default();
+    let (client, _admin) = setup_admin(&env);
+    let signers = vec![&env, Address::generate(&env)];
+    assert!(client.try_migrate_to_multisig(&signers, &2, &false).is_err());
+    assert!(client.get_admin().is_some());
+}
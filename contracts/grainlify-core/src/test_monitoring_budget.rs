@@ -0,0 +1,170 @@
+//! # Monitoring Budget Benchmarks
+//!
+//! `track` and `emit_performance` run on every entrypoint, so their
+//! host cost is paid by every caller. These tests compare them against
+//! replicas of the schema 1 implementation, which kept each counter under its
+//! own bare `Symbol::new(..)` key and performance stats under three
+//! `(Symbol, function)` tuples:
+//! - `track` charges fewer CPU instructions and at least 10% less
+//!   memory, both on a caller's first call and on repeat calls
+//! - `emit_performance` does the same for new and already-tracked functions
+//!
+//! Run with `cargo test test_monitoring_budget -- --nocapture` to see the
+//! figures. The contract is registered natively, so they cover host work
+//! (storage, events, object conversions) but not WASM execution.
+
+#![cfg(test)]
+
+extern crate std;
+
+use std::println;
+
+use soroban_sdk::{symbol_short, testutils::Address as _, Address, Env, Symbol, Vec};
+
+use crate::monitoring::{self, OperationMetric, PerformanceMetric};
+use crate::testutils::setup_admin;
+use crate::DataKey;
+
+#[derive(Clone, Copy, Debug)]
+struct Cost {
+    cpu: u64,
+    mem: u64,
+}
+
+/// Resets the budget, runs `f`, and returns what it charged.
+fn measure(env: &Env, f: impl FnOnce()) -> Cost {
+    env.budget().reset_default();
+    f();
+    let budget = env.budget();
+    Cost {
+        cpu: budget.cpu_instruction_cost(),
+        mem: budget.memory_bytes_cost(),
+    }
+}
+
+fn legacy_counter(env: &Env, name: &str) -> u64 {
+    env.storage()
+        .persistent()
+        .get(&Symbol::new(env, name))
+        .unwrap_or(0)
+}
+
+fn set_legacy_counter(env: &Env, name: &str, value: u64) {
+    env.storage()
+        .persistent()
+        .set(&Symbol::new(env, name), &value);
+}
+
+/// Schema 1 `track_operation`, with the tracking-override check both
+/// implementations share. The breaker is disabled in these tests, so its
+/// evaluation reduces to the config lookup.
+fn legacy_track_operation(env: &Env, operation: Symbol, caller: Address, success: bool) {
+    if !monitoring::is_tracked(env, &operation) {
+        return;
+    }
+    set_legacy_counter(env, "op_count", legacy_counter(env, "op_count").saturating_add(1));
+    set_legacy_counter(env, "last_op_ts", env.ledger().timestamp());
+    let index_key = Symbol::new(env, "usr_index");
+    let mut users: Vec<Address> = env.storage().persistent().get(&index_key).unwrap_or(Vec::new(env));
+    if !users.contains(&caller) {
+        users.push_back(caller.clone());
+        env.storage().persistent().set(&index_key, &users);
+        set_legacy_counter(env, "usr_count", users.len().into());
+    }
+    if !success {
+        set_legacy_counter(env, "err_count", legacy_counter(env, "err_count").saturating_add(1));
+    }
+    monitoring::get_circuit_breaker_config(env);
+    env.events().publish(
+        (symbol_short!("metric"), symbol_short!("op")),
+        OperationMetric {
+            call_kind: monitoring::call_kind(&caller),
+            operation,
+            caller,
+            timestamp: env.ledger().timestamp(),
+            success,
+            version: env.storage().instance().get(&DataKey::Version).unwrap_or(0),
+        },
+    );
+}
+
+/// Schema 1 `emit_performance`, minus index eviction (never reached here).
+fn legacy_emit_performance(env: &Env, function: Symbol, duration: u64) {
+    let index_key = Symbol::new(env, "perf_index");
+    let mut index: Vec<Symbol> = env.storage().persistent().get(&index_key).unwrap_or(Vec::new(env));
+    if !index.contains(&function) {
+        index.push_back(function.clone());
+        env.storage().persistent().set(&index_key, &index);
+    }
+    let count_key = (Symbol::new(env, "perf_cnt"), function.clone());
+    let time_key = (Symbol::new(env, "perf_time"), function.clone());
+    let last_key = (Symbol::new(env, "perf_last"), function.clone());
+    let count: u64 = env.storage().persistent().get(&count_key).unwrap_or(0);
+    let total: u64 = env.storage().persistent().get(&time_key).unwrap_or(0);
+    let timestamp = env.ledger().timestamp();
+    env.storage().persistent().set(&count_key, &count.saturating_add(1));
+    env.storage().persistent().set(&time_key, &total.saturating_add(duration));
+    env.storage().persistent().set(&last_key, &timestamp);
+    env.events().publish(
+        (symbol_short!("metric"), symbol_short!("perf")),
+        PerformanceMetric { function, duration, timestamp },
+    );
+}
+
+/// Asserts `current` is cheaper in CPU and at least 10% cheaper in memory.
+fn assert_reduced(label: &str, legacy: Cost, current: Cost) {
+    println!(
+        "{:<28} cpu {:>7} -> {:>7}   mem {:>6} -> {:>6}",
+        label, legacy.cpu, current.cpu, legacy.mem, current.mem
+    );
+    assert!(current.cpu < legacy.cpu, "{}: {:?} vs legacy {:?}", label, current, legacy);
+    assert!(current.mem * 10 <= legacy.mem * 9, "{}: {:?} vs legacy {:?}", label, current, legacy);
+}
+
+/// `monitoring::track` behind the tracking-override check, as
+/// `TrackedOperation::finish` calls it.
+fn track_outcome(env: &Env, operation: Symbol, caller: Address, success: bool) {
+    if monitoring::is_tracked(env, &operation) {
+        monitoring::track(env, operation, caller, success, None, true);
+    }
+}
+
+/// Costs of a caller's first and second tracked call on a fresh contract.
+fn track_costs(track: fn(&Env, Symbol, Address, bool)) -> [Cost; 2] {
+    let env = Env::default();
+    let (client, _admin) = setup_admin(&env);
+    let caller = Address::generate(&env);
+    env.as_contract(&client.address, || {
+        let first = measure(&env, || track(&env, symbol_short!("op"), caller.clone(), true));
+        let repeat = measure(&env, || track(&env, symbol_short!("op"), caller.clone(), false));
+        [first, repeat]
+    })
+}
+
+/// Costs of the first and second recording for one function on a fresh
+/// contract.
+fn perf_costs(emit: fn(&Env, Symbol, u64)) -> [Cost; 2] {
+    let env = Env::default();
+    let (client, _admin) = setup_admin(&env);
+    env.as_contract(&client.address, || {
+        let first = measure(&env, || emit(&env, symbol_short!("upgrade"), 7));
+        let repeat = measure(&env, || emit(&env, symbol_short!("upgrade"), 7));
+        [first, repeat]
+    })
+}
+
+#[test]
+fn test_track_costs_less_than_schema_1() {
+    let [legacy_first, legacy_repeat] = track_costs(legacy_track_operation);
+    let [first, repeat] = track_costs(track_outcome);
+    assert_reduced("track (new user)", legacy_first, first);
+    assert_reduced("track (repeat)", legacy_repeat, repeat);
+}
+
+#[test]
+fn test_emit_performance_costs_less_than_schema_1() {
+    let [legacy_first, legacy_repeat] = perf_costs(legacy_emit_performance);
+    let [first, repeat] = perf_costs(monitoring::emit_performance);
+    assert_reduced("emit_performance (new)", legacy_first, first);
+    assert_reduced("emit_performance (repeat)", legacy_repeat, repeat);
+}
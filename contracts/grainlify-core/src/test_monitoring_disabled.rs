@@ -0,0 +1,51 @@
+//! # Monitoring Disabled Tests
+//!
+//! Built only without the `monitoring` feature:
+//! - Tracked entrypoints still succeed but write no metric entries
+//! - Metric views, keeper maintenance, and circuit-breaker configuration fail
+//!   with `FeatureDisabled`
+//! - `health_check` keeps working and reports zero operations
+
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{testutils::Address as _, Address, Env, Symbol};
+
+use crate::monitoring::{CircuitBreakerConfig, MetricsKey};
+use crate::testutils::setup_admin;
+
+#[test]
+fn test_tracked_calls_write_no_metrics() {
+    let env = Env::default();
+    let (client, _admin) = setup_admin(&env);
+    client.set_version(&3);
+    assert_eq!(client.get_version(), 3);
+
+    env.as_contract(&client.address, || {
+        assert!(!env.storage().persistent().has(&MetricsKey::Totals));
+        assert!(!env.storage().persistent().has(&MetricsKey::TrackedUsers));
+        assert!(!env.storage().persistent().has(&MetricsKey::PerfIndex));
+    });
+    assert_eq!(client.health_check().total_operations, 0);
+}
+
+#[test]
+fn test_metric_entrypoints_fail_with_feature_disabled() {
+    let env = Env::default();
+    let (client, _admin) = setup_admin(&env);
+    let keeper = Address::generate(&env);
+
+    assert!(client.try_get_analytics().is_err());
+    assert!(client.try_get_state_snapshot().is_err());
+    assert!(client.try_get_performance_stats(&Symbol::new(&env, "set_ver")).is_err());
+    assert!(client.try_get_recorded_snapshot().is_err());
+    assert!(client.try_bump_metrics_ttl(&keeper).is_err());
+    assert!(client.try_record_snapshot(&keeper).is_err());
+    assert!(client.try_set_circuit_breaker_config(&CircuitBreakerConfig {
+        enabled: true,
+        max_consecutive_failures: 1,
+        max_error_rate_bps: 0,
+        window_size: 0,
+    }).is_err());
+}
@@ -0,0 +1,127 @@
+//! # Network Label Tests
+//!
+//! - Upgrade proposals record the governance-set network label at creation
+//! - Approvals must name the recorded label; a wrong or missing label fails
+//!   with `NetworkMismatch`
+//! - Without a label the check is skipped
+//! - Creation and approval events carry the label
+
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{testutils::Events as _, vec, Address, Env, Symbol, TryFromVal, Vec};
+
+use crate::testutils::{setup_admin, setup_multisig, upload_dummy_wasm, GovernanceDriver};
+use crate::{ConfigSource, GrainlifyContractClient, NetworkLabelEvent, ProposalAction};
+
+fn setup(env: &Env) -> (GrainlifyContractClient<'_>, Vec<Address>) {
+    setup_multisig(env, 3, 2)
+}
+
+fn set_label(env: &Env, client: &GrainlifyContractClient, signers: &Vec<Address>, label: Option<Symbol>) -> u64 {
+    let action = match label {
+        Some(label) => ProposalAction::SetNetworkLabel(label),
+        None => ProposalAction::ClearNetworkLabel,
+    };
+    GovernanceDriver::apply_actions(env, client, signers, vec![env, action])
+}
+
+fn network_events(env: &Env, action: &str) -> std::vec::Vec<NetworkLabelEvent> {
+    env.events()
+        .all()
+        .iter()
+        .filter(|(_, topics, _)| {
+            topics.len() == 2
+                && Symbol::try_from_val(env, &topics.get(0).unwrap()).ok() == Some(Symbol::new(env, "network"))
+                && Symbol::try_from_val(env, &topics.get(1).unwrap()).ok() == Some(Symbol::new(env, action))
+        })
+        .map(|(_, _, data)| NetworkLabelEvent::try_from_val(env, &data).unwrap())
+        .collect()
+}
+
+#[test]
+fn test_matching_label_approves() {
+    let env = Env::default();
+    let (client, signers) = setup(&env);
+    let mainnet = Symbol::new(&env, "mainnet");
+    set_label(&env, &client, &signers, Some(mainnet.clone()));
+
+    let proposal_id = client.propose_upgrade(&signers.get(0).unwrap(), &upload_dummy_wasm(&env, 1), &0);
+    assert_eq!(client.get_proposal_network(&proposal_id), Some(mainnet.clone()));
+    assert_eq!(network_events(&env, "proposed").len(), 1);
+
+    client.approve_upgrade_on_network(&proposal_id, &signers.get(1).unwrap(), &mainnet);
+    let approved = network_events(&env, "approved");
+    assert_eq!(approved.len(), 1);
+    assert_eq!(approved[0].network, mainnet);
+    assert_eq!(approved[0].signer, signers.get(1).unwrap());
+}
+
+#[test]
+#[should_panic(expected = "128")]
+fn test_wrong_label_rejected() {
+    let env = Env::default();
+    let (client, signers) = setup(&env);
+    set_label(&env, &client, &signers, Some(Symbol::new(&env, "mainnet")));
+    let proposal_id = client.propose_upgrade(&signers.get(0).unwrap(), &upload_dummy_wasm(&env, 1), &0);
+
+    client.approve_upgrade_on_network(&proposal_id, &signers.get(1).unwrap(), &Symbol::new(&env, "testnet"));
+}
+
+#[test]
+fn test_labelled_proposal_needs_label_to_approve() {
+    let env = Env::default();
+    let (client, signers) = setup(&env);
+    set_label(&env, &client, &signers, Some(Symbol::new(&env, "mainnet")));
+    let proposal_id = client.propose_upgrade(&signers.get(0).unwrap(), &upload_dummy_wasm(&env, 1), &0);
+
+    assert!(client.try_approve_upgrade(&proposal_id, &signers.get(1).unwrap()).is_err());
+    assert_eq!(client.approvals_needed(&proposal_id), 2);
+}
+
+#[test]
+fn test_unconfigured_label_skips_check() {
+    let env = Env::default();
+    let (client, signers) = setup(&env);
+    let proposal_id = client.propose_upgrade(&signers.get(0).unwrap(), &upload_dummy_wasm(&env, 1), &0);
+    assert_eq!(client.get_proposal_network(&proposal_id), None);
+
+    client.approve_upgrade(&proposal_id, &signers.get(0).unwrap());
+    client.approve_upgrade_on_network(&proposal_id, &signers.get(1).unwrap(), &Symbol::new(&env, "testnet"));
+    assert_eq!(client.approvals_needed(&proposal_id), 0);
+    assert!(network_events(&env, "approved").is_empty());
+}
+
+#[test]
+fn test_proposal_keeps_label_it_was_created_with() {
+    let env = Env::default();
+    let (client, signers) = setup(&env);
+    let mainnet = Symbol::new(&env, "mainnet");
+    set_label(&env, &client, &signers, Some(mainnet.clone()));
+    let proposal_id = client.propose_upgrade(&signers.get(0).unwrap(), &upload_dummy_wasm(&env, 1), &0);
+
+    set_label(&env, &client, &signers, None);
+    assert_eq!(client.get_network_label(), None);
+    assert!(client.try_approve_upgrade(&proposal_id, &signers.get(1).unwrap()).is_err());
+    client.approve_upgrade_on_network(&proposal_id, &signers.get(1).unwrap(), &mainnet);
+}
+
+#[test]
+fn test_action_records_provenance() {
+    let env = Env::default();
+    let (client, signers) = setup(&env);
+    let bundle_id = set_label(&env, &client, &signers, Some(Symbol::new(&env, "mainnet")));
+    assert_eq!(client.get_network_label(), Some(Symbol::new(&env, "mainnet")));
+    let provenance = client.get_config_provenance(&Symbol::new(&env, "network_label")).unwrap();
+    assert_eq!(provenance.source, ConfigSource::Proposal(bundle_id));
+    assert!(client.try_set_network_label(&None).is_err());
+}
+
+#[test]
+fn test_admin_sets_label_directly() {
+    let env = Env::default();
+    let (client, _admin) = setup_admin(&env);
+    client.set_network_label(&Some(Symbol::new(&env, "testnet")));
+    assert_eq!(client.get_network_label(), Some(Symbol::new(&env, "testnet")));
+}
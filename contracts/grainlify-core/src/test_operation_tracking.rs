@@ -0,0 +1,81 @@
+//! # Operation Tracking Tests
+//!
+//! - `track_start` records nothing; `finish` records the outcome and duration
+//! - An entrypoint that fails after `track_start` leaves every counter
+//!   untouched, so it is never counted as a success
+//! - A double init through `attempt_init_admin` counts once as an error and
+//!   not as a success
+//! - A failure reported through `finish` counts once as an error
+
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{symbol_short, testutils::Address as _, testutils::Ledger as _, Address, Env};
+
+use crate::monitoring;
+use crate::testutils::{setup_admin, upload_dummy_wasm};
+
+fn counts(client: &crate::GrainlifyContractClient) -> (u64, u64) {
+    let analytics = client.get_analytics();
+    (analytics.operation_count, analytics.error_count)
+}
+
+#[test]
+fn test_failed_set_version_is_not_counted() {
+    let env = Env::default();
+    let (client, admin) = setup_admin(&env);
+    client.set_version(&3);
+    assert_eq!(counts(&client), (1, 0));
+
+    client.pause(&admin);
+    assert!(client.try_set_version(&4).is_err());
+    assert_eq!(counts(&client), (1, 0));
+    assert_eq!(client.get_performance_stats(&symbol_short!("set_ver")).call_count, 1);
+}
+
+#[test]
+fn test_failed_upgrade_is_not_counted() {
+    let env = Env::default();
+    let (client, _) = setup_admin(&env);
+    let wasm_hash = upload_dummy_wasm(&env, 1);
+
+    assert!(client.try_upgrade_with_nonce(&wasm_hash, &7).is_err());
+    assert_eq!(counts(&client), (0, 0));
+    assert_eq!(client.get_performance_stats(&symbol_short!("upgrade")).call_count, 0);
+}
+
+/// A plain double init reverts and counts as nothing (see
+/// `monitoring::track_start`); the absorbing attempt counts one error.
+#[test]
+fn test_double_init_counts_once_as_error() {
+    let env = Env::default();
+    let (client, admin) = setup_admin(&env);
+    client.set_version(&3);
+    assert!(client.try_init_admin(&Address::generate(&env)).is_err());
+    assert_eq!(counts(&client), (1, 0));
+
+    let code = client.attempt_init_admin(&Address::generate(&env));
+    assert_eq!(code, Some(crate::ContractError::AlreadyInitialized as u32));
+    assert_eq!(counts(&client), (2, 1));
+    assert_eq!(client.get_admin(), Some(admin));
+}
+
+#[test]
+fn test_reported_failure_counts_once_as_error() {
+    let env = Env::default();
+    let (client, admin) = setup_admin(&env);
+    client.set_version(&3);
+
+    env.as_contract(&client.address, || {
+        let tracked = monitoring::track_start(&env, symbol_short!("probe"));
+        env.ledger().set_timestamp(env.ledger().timestamp() + 40);
+        tracked.finish(&env, admin.clone(), false);
+    });
+
+    let (operations, errors) = counts(&client);
+    assert_eq!(errors, 1);
+    assert_eq!(operations - errors, 1, "successes unchanged");
+    let perf = client.get_performance_stats(&symbol_short!("probe"));
+    assert_eq!((perf.call_count, perf.total_time), (1, 40));
+}
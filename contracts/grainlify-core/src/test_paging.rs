@@ -0,0 +1,88 @@
+//! # Paged View Tests
+//!
+//! - Limits above `MAX_PAGE_SIZE` are clamped
+//! - A start at or past the end gives an empty page without `has_more`
+//! - `has_more` is set exactly while entries remain after the page, for
+//!   the version history, threshold history and deployment registry
+
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{testutils::Address as _, vec, Address, Env, Symbol};
+
+use crate::paging::MAX_PAGE_SIZE;
+use crate::testutils::setup_admin;
+use crate::{ConfigUpdate, GrainlifyContractClient, HistoryKind, MultiSig};
+
+/// Admin contract whose version history holds `count` entries.
+fn with_versions(env: &Env, count: u32) -> GrainlifyContractClient<'_> {
+    env.budget().reset_unlimited();
+    let (client, _) = setup_admin(env);
+    let recorded = client.get_history_range(&HistoryKind::Versions).total_count as u32;
+    for version in recorded..count {
+        client.set_version(&(version + 10));
+    }
+    client
+}
+
+#[test]
+fn test_limit_is_clamped() {
+    let env = Env::default();
+    let client = with_versions(&env, MAX_PAGE_SIZE + 5);
+
+    let page = client.get_version_history(&0, &u32::MAX);
+    assert_eq!(page.items.len(), MAX_PAGE_SIZE);
+    assert!(page.has_more);
+
+    let rest = client.get_version_history(&(MAX_PAGE_SIZE as u64), &u32::MAX);
+    assert_eq!(rest.items.len(), 5);
+    assert!(!rest.has_more);
+}
+
+#[test]
+fn test_start_past_end_is_empty() {
+    let env = Env::default();
+    let client = with_versions(&env, 3);
+
+    for start in [3, 4, u64::MAX] {
+        let page = client.get_version_history(&start, &10);
+        assert!(page.items.is_empty());
+        assert!(!page.has_more);
+    }
+    let page = client.list_deployments(&u32::MAX, &u32::MAX);
+    assert!(page.items.is_empty() && !page.has_more);
+}
+
+#[test]
+fn test_has_more_on_threshold_history() {
+    let env = Env::default();
+    let (client, _) = setup_admin(&env);
+    let signers = vec![&env, Address::generate(&env), Address::generate(&env), Address::generate(&env)];
+    env.as_contract(&client.address, || MultiSig::init(&env, signers, 2));
+    client.set_config(&ConfigUpdate { threshold: Some(2), ..Default::default() });
+    client.set_config(&ConfigUpdate { threshold: Some(3), ..Default::default() });
+    client.set_config(&ConfigUpdate { threshold: Some(1), ..Default::default() });
+
+    let first = client.get_threshold_history(&0, &2);
+    assert_eq!(first.items.len(), 2);
+    assert!(first.has_more);
+    let last = client.get_threshold_history(&2, &2);
+    assert_eq!(last.items.get(0).unwrap().threshold, 1);
+    assert!(!last.has_more);
+}
+
+#[test]
+fn test_has_more_on_deployment_registry() {
+    let env = Env::default();
+    let (client, _) = setup_admin(&env);
+    for name in ["a", "b", "c"] {
+        client.register_deployment(&Symbol::new(&env, name), &Address::generate(&env), &false);
+    }
+
+    assert!(client.list_deployments(&0, &2).has_more);
+    assert!(!client.list_deployments(&0, &3).has_more);
+    let tail = client.list_deployments(&2, &2);
+    assert_eq!(tail.items.len(), 1);
+    assert!(!tail.has_more);
+}
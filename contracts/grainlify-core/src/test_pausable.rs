@@ -0,0 +1,223 @@
+//! # Pausable Module Tests
+//!
+//! Covers the pause / unpause lifecycle:
+//! - Admin can pause and unpause directly
+//! - Any single multisig signer can pause, but unpausing requires a
+//!   threshold-approved `propose_unpause` proposal
+//! - `upgrade`, `propose_upgrade`, `approve_upgrade`, `execute_upgrade`, and
+//!   `set_version` are rejected with `Paused` while the flag is set
+//! - Read-only views and `health_check` keep working and report the flag
+//! - Both transitions publish a `PauseEvent`
+
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{
+    testutils::{Address as _, Events, Ledger as _},
+    Address, BytesN, Env, Symbol, TryFromVal,
+};
+
+use crate::testutils::{self, setup_admin};
+use crate::{GrainlifyContractClient, PauseEvent};
+
+fn setup_multisig(env: &Env) -> (GrainlifyContractClient<'_>, [Address; 3]) {
+    let (client, signers) = testutils::setup_multisig(env, 3, 2);
+    let signer = |i| signers.get(i).unwrap();
+    (client, [signer(0), signer(1), signer(2)])
+}
+
+fn fake_wasm(env: &Env) -> BytesN<32> {
+    BytesN::from_array(env, &[0xAB; 32])
+}
+
+fn last_pause_event(env: &Env, topic: &str) -> Option<PauseEvent> {
+    let mut found = None;
+    for (_, topics, data) in env.events().all().iter() {
+        if topics.len() != 1 {
+            continue;
+        }
+        let t0 = match Symbol::try_from_val(env, &topics.get(0).unwrap()) {
+            Ok(v) => v,
+            Err(_) => continue,
+        };
+        if t0 == Symbol::new(env, topic) {
+            found = PauseEvent::try_from_val(env, &data).ok();
+        }
+    }
+    found
+}
+
+// ── admin path ──────────────────────────────────────────────────────────────
+
+#[test]
+fn test_not_paused_after_init() {
+    let env = Env::default();
+    let (client, _admin) = setup_admin(&env);
+    assert!(!client.is_paused());
+    assert!(!client.health_check().is_paused);
+}
+
+#[test]
+fn test_admin_pause_and_unpause() {
+    let env = Env::default();
+    let (client, admin) = setup_admin(&env);
+
+    client.pause(&admin);
+    assert!(client.is_paused());
+    let event = last_pause_event(&env, "paused").expect("pause event");
+    assert!(event.paused);
+    assert_eq!(event.caller, admin);
+    assert_eq!(event.proposal_id, None);
+
+    client.unpause(&admin);
+    assert!(!client.is_paused());
+    let event = last_pause_event(&env, "unpause").expect("unpause event");
+    assert!(!event.paused);
+    assert_eq!(event.caller, admin);
+}
+
+#[test]
+fn test_non_admin_cannot_pause() {
+    let env = Env::default();
+    let (client, _admin) = setup_admin(&env);
+    let stranger = Address::generate(&env);
+    assert!(client.try_pause(&stranger).is_err());
+    assert!(!client.is_paused());
+}
+
+#[test]
+fn test_non_admin_cannot_unpause() {
+    let env = Env::default();
+    let (client, admin) = setup_admin(&env);
+    client.pause(&admin);
+    let stranger = Address::generate(&env);
+    assert!(client.try_unpause(&stranger).is_err());
+    assert!(client.is_paused());
+}
+
+#[test]
+#[should_panic(expected = "10")]
+fn test_upgrade_blocked_while_paused() {
+    let env = Env::default();
+    let (client, admin) = setup_admin(&env);
+    client.pause(&admin);
+    client.upgrade(&fake_wasm(&env));
+}
+
+#[test]
+#[should_panic(expected = "10")]
+fn test_set_version_blocked_while_paused() {
+    let env = Env::default();
+    let (client, admin) = setup_admin(&env);
+    client.pause(&admin);
+    client.set_version(&5);
+}
+
+#[test]
+fn test_set_version_allowed_after_unpause() {
+    let env = Env::default();
+    let (client, admin) = setup_admin(&env);
+    client.pause(&admin);
+    client.unpause(&admin);
+    client.set_version(&5);
+    assert_eq!(client.get_version(), 5);
+}
+
+#[test]
+fn test_views_keep_working_while_paused() {
+    let env = Env::default();
+    let (client, admin) = setup_admin(&env);
+    client.pause(&admin);
+
+    assert_eq!(client.get_version(), 2);
+    assert_eq!(client.get_admin(), Some(admin));
+    let health = client.health_check();
+    assert!(health.is_paused);
+    assert!(client.liveness_watchdog().paused);
+}
+
+// ── multisig path ───────────────────────────────────────────────────────────
+
+#[test]
+fn test_single_signer_can_pause() {
+    let env = Env::default();
+    let (client, [s1, _, _]) = setup_multisig(&env);
+    client.pause(&s1);
+    assert!(client.is_paused());
+}
+
+#[test]
+fn test_single_signer_cannot_unpause_directly() {
+    let env = Env::default();
+    let (client, [s1, _, _]) = setup_multisig(&env);
+    client.pause(&s1);
+    assert!(client.try_unpause(&s1).is_err());
+    assert!(client.is_paused());
+}
+
+#[test]
+fn test_unpause_proposal_requires_threshold() {
+    let env = Env::default();
+    let (client, [s1, s2, _]) = setup_multisig(&env);
+    client.pause(&s1);
+
+    let proposal_id = client.propose_unpause(&s1, &0u64);
+    client.approve_unpause(&proposal_id, &s1);
+    assert!(client.is_paused(), "one approval must not lift a 2-of-3 pause");
+
+    client.approve_unpause(&proposal_id, &s2);
+    assert!(!client.is_paused());
+    let event = last_pause_event(&env, "unpause").expect("unpause event");
+    assert_eq!(event.proposal_id, Some(proposal_id));
+    assert_eq!(event.caller, s2);
+}
+
+#[test]
+fn test_approve_unpause_rejects_upgrade_proposal() {
+    let env = Env::default();
+    let (client, [s1, _, _]) = setup_multisig(&env);
+    let proposal_id = client.propose_upgrade(&s1, &fake_wasm(&env), &0u64);
+    assert!(client.try_approve_unpause(&proposal_id, &s1).is_err());
+}
+
+#[test]
+#[should_panic(expected = "10")]
+fn test_propose_upgrade_blocked_while_paused() {
+    let env = Env::default();
+    let (client, [s1, _, _]) = setup_multisig(&env);
+    client.pause(&s1);
+    client.propose_upgrade(&s1, &fake_wasm(&env), &0u64);
+}
+
+#[test]
+#[should_panic(expected = "10")]
+fn test_approve_upgrade_blocked_while_paused() {
+    let env = Env::default();
+    let (client, [s1, _, _]) = setup_multisig(&env);
+    let proposal_id = client.propose_upgrade(&s1, &fake_wasm(&env), &0u64);
+    client.pause(&s1);
+    client.approve_upgrade(&proposal_id, &s1);
+}
+
+#[test]
+#[should_panic(expected = "10")]
+fn test_execute_upgrade_blocked_while_paused() {
+    let env = Env::default();
+    let (client, [s1, s2, _]) = setup_multisig(&env);
+    let proposal_id = client.propose_upgrade(&s1, &fake_wasm(&env), &0u64);
+    client.approve_upgrade(&proposal_id, &s1);
+    client.approve_upgrade(&proposal_id, &s2);
+    env.ledger().set_timestamp(env.ledger().timestamp() + 86_400);
+
+    client.pause(&s1);
+    client.execute_upgrade(&proposal_id);
+}
+
+#[test]
+fn test_non_signer_cannot_pause_multisig() {
+    let env = Env::default();
+    let (client, _) = setup_multisig(&env);
+    let stranger = Address::generate(&env);
+    assert!(client.try_pause(&stranger).is_err());
+}
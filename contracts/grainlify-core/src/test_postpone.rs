@@ -0,0 +1,111 @@
+//! # Postpone Tests
+//!
+//! - Once threshold is met, any signer can move the earliest execution time
+//!   later; execution before it fails, after it succeeds
+//! - Each call emits `("upgrade", "postpone")` with the new time and the
+//!   remaining budget; repeated calls hit `get_max_postponement` and fail
+//! - Moving earlier, past the expiry, before threshold, or as a non-signer
+//!   fails
+
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{
+    testutils::{Address as _, Events as _, Ledger as _},
+    Address, Env, Symbol, TryFromVal,
+};
+
+use crate::testutils::{setup_admin, setup_multisig, upload_dummy_wasm};
+use crate::{GrainlifyContractClient, MultiSig, ProposalPostponedEvent};
+
+const DAY: u64 = 86_400;
+
+fn last_postpone(env: &Env) -> Option<ProposalPostponedEvent> {
+    let mut found = None;
+    for (_, topics, data) in env.events().all().iter() {
+        if topics.len() != 2 {
+            continue;
+        }
+        let t1 = Symbol::try_from_val(env, &topics.get(1).unwrap());
+        if t1 == Ok(Symbol::new(env, "postpone")) {
+            found = ProposalPostponedEvent::try_from_val(env, &data).ok();
+        }
+    }
+    found
+}
+
+/// Approved proposal at time 0 whose timelock ends at `DAY`.
+fn approved(env: &Env, client: &GrainlifyContractClient, signers: &soroban_sdk::Vec<Address>, expiry: u64) -> u64 {
+    let proposal_id = client.propose_upgrade(&signers.get(0).unwrap(), &upload_dummy_wasm(env, 1), &expiry);
+    client.approve_upgrade(&proposal_id, &signers.get(0).unwrap());
+    client.approve_upgrade(&proposal_id, &signers.get(1).unwrap());
+    proposal_id
+}
+
+#[test]
+fn test_postpone_delays_execution() {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    let proposal_id = approved(&env, &client, &signers, 0);
+
+    client.postpone(&proposal_id, &(3 * DAY), &signers.get(2).unwrap());
+    let event = last_postpone(&env).unwrap();
+    assert_eq!(event.not_before, 3 * DAY);
+    assert_eq!(event.remaining_budget, 30 * DAY - 2 * DAY);
+    assert_eq!(client.get_upgrade_proposal(&proposal_id).unwrap().not_before, Some(3 * DAY));
+
+    env.ledger().set_timestamp(2 * DAY);
+    assert!(client.try_execute_upgrade(&proposal_id).is_err());
+    env.ledger().set_timestamp(3 * DAY);
+    client.execute_upgrade(&proposal_id);
+}
+
+#[test]
+fn test_repeated_postponements_hit_cap() {
+    let env = Env::default();
+    let (client, _admin) = setup_admin(&env);
+    let signers = soroban_sdk::vec![&env, Address::generate(&env), Address::generate(&env)];
+    env.as_contract(&client.address, || MultiSig::init(&env, signers.clone(), 2));
+    client.set_max_postponement(&(3 * DAY));
+    let proposal_id = approved(&env, &client, &signers, 0);
+    let signer = signers.get(0).unwrap();
+
+    client.postpone(&proposal_id, &(2 * DAY), &signer);
+    assert_eq!(last_postpone(&env).unwrap().remaining_budget, 2 * DAY);
+    client.postpone(&proposal_id, &(3 * DAY), &signer);
+    client.postpone(&proposal_id, &(4 * DAY), &signer);
+    assert_eq!(last_postpone(&env).unwrap().remaining_budget, 0);
+
+    assert!(client.try_postpone(&proposal_id, &(4 * DAY + 1), &signer).is_err());
+    assert_eq!(client.get_upgrade_proposal(&proposal_id).unwrap().not_before, Some(4 * DAY));
+}
+
+#[test]
+#[should_panic(expected = "125")]
+fn test_postpone_over_cap_fails_with_typed_error() {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    let proposal_id = approved(&env, &client, &signers, 0);
+    client.postpone(&proposal_id, &(32 * DAY), &signers.get(0).unwrap());
+}
+
+#[test]
+fn test_postpone_rejections() {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    let signer = signers.get(2).unwrap();
+
+    let unapproved = client.propose_upgrade(&signers.get(0).unwrap(), &upload_dummy_wasm(&env, 1), &0);
+    assert!(client.try_postpone(&unapproved, &(2 * DAY), &signer).is_err(), "threshold not met");
+
+    let proposal_id = approved(&env, &client, &signers, 5 * DAY);
+    assert!(client.try_postpone(&proposal_id, &DAY, &signer).is_err(), "not later than timelock end");
+    assert!(client.try_postpone(&proposal_id, &(5 * DAY), &signer).is_err(), "at expiry");
+    assert!(client
+        .try_postpone(&proposal_id, &(2 * DAY), &Address::generate(&env))
+        .is_err(), "not a signer");
+
+    client.postpone(&proposal_id, &(3 * DAY), &signer);
+    assert!(client.try_postpone(&proposal_id, &(2 * DAY), &signer).is_err(), "never earlier");
+}
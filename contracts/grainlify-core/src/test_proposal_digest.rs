@@ -0,0 +1,101 @@
+//! # Proposal Digest Tests
+//!
+//! - `proposal_digest` equals `sha256` of the XDR `ProposalDigestInput`
+//!   rebuilt from the proposal's own fields, and is stable across calls
+//! - The `("upgrade", "proposed")` event carries the digest
+//! - `approve_upgrade_v2` rejects a digest that does not match
+
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{
+    symbol_short, testutils::Events as _, xdr::ToXdr, Address, BytesN, Env, IntoVal, String, TryFromVal, Vec,
+};
+
+use crate::testutils::{setup_multisig, upload_dummy_wasm};
+use crate::{GrainlifyContractClient, ProposalDigestInput, UpgradeProposedEvent};
+
+const EXPIRY: u64 = 86_400;
+
+fn expected_digest(
+    env: &Env,
+    client: &GrainlifyContractClient,
+    proposal_id: u64,
+    wasm_hash: &BytesN<32>,
+    description: &str,
+) -> BytesN<32> {
+    let input = ProposalDigestInput {
+        contract: client.address.clone(),
+        proposal_id,
+        wasm_hash: wasm_hash.clone(),
+        metadata_hash: env.crypto().sha256(&String::from_str(env, description).to_xdr(env)).into(),
+        expiry: EXPIRY,
+        threshold: 2,
+    };
+    env.crypto().sha256(&input.to_xdr(env)).into()
+}
+
+fn propose(env: &Env, client: &GrainlifyContractClient, signers: &Vec<Address>, description: &str) -> u64 {
+    client.propose_upgrade_with_description(
+        &signers.get(0).unwrap(),
+        &upload_dummy_wasm(env, 1),
+        &String::from_str(env, description),
+        &EXPIRY,
+    )
+}
+
+#[test]
+fn test_digest_matches_recomputation() {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    let proposal_id = propose(&env, &client, &signers, "v2: fee fix");
+    let wasm_hash = upload_dummy_wasm(&env, 1);
+
+    let digest = client.proposal_digest(&proposal_id);
+    assert_eq!(digest, expected_digest(&env, &client, proposal_id, &wasm_hash, "v2: fee fix"));
+    assert_eq!(client.proposal_digest(&proposal_id), digest);
+    assert_ne!(digest, expected_digest(&env, &client, proposal_id, &wasm_hash, ""));
+}
+
+#[test]
+fn test_proposed_event_carries_digest() {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    let proposal_id = propose(&env, &client, &signers, "v2: fee fix");
+
+    let topics = (symbol_short!("upgrade"), symbol_short!("proposed")).into_val(&env);
+    let (_, _, data) = env.events().all().iter().find(|(_, t, _)| *t == topics).unwrap();
+    let event = UpgradeProposedEvent::try_from_val(&env, &data).unwrap();
+    assert_eq!(event.proposal_id, proposal_id);
+    assert_eq!(event.digest, client.proposal_digest(&proposal_id));
+}
+
+#[test]
+fn test_approval_checks_digest() {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    let proposal_id = propose(&env, &client, &signers, "");
+    let wasm_hash = upload_dummy_wasm(&env, 1);
+    let description = String::from_str(&env, "");
+    let signer = signers.get(0).unwrap();
+
+    let wrong = BytesN::from_array(&env, &[7; 32]);
+    assert!(client
+        .try_approve_upgrade_v2(&proposal_id, &signer, &wasm_hash, &description, &None, &Some(wrong))
+        .is_err());
+
+    let digest = client.proposal_digest(&proposal_id);
+    assert_eq!(
+        client.approve_upgrade_v2(&proposal_id, &signer, &wasm_hash, &description, &None, &Some(digest)),
+        1
+    );
+}
+
+#[test]
+#[should_panic(expected = "102")]
+fn test_unknown_proposal_has_no_digest() {
+    let env = Env::default();
+    let (client, _) = setup_multisig(&env, 3, 2);
+    client.proposal_digest(&9);
+}
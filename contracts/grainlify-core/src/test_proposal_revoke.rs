@@ -0,0 +1,69 @@
+//! # Approval Revocation and Proposal Pruning Tests
+//!
+//! - `revoke_approval` clears a running timelock once the proposal drops
+//!   below threshold, and re-approval restarts it
+//! - `prune_upgrade_proposal` deletes cancelled and expired proposals with
+//!   their stored hash, and refuses live or executed ones
+
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{testutils::Ledger as _, BytesN, Env};
+
+use crate::testutils::setup_multisig;
+
+#[test]
+fn test_revoke_below_threshold_clears_timelock() {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    let hash = BytesN::from_array(&env, &[7u8; 32]);
+    let id = client.propose_upgrade(&signers.get(0).unwrap(), &hash, &0u64);
+    client.approve_upgrade(&id, &signers.get(0).unwrap());
+    client.approve_upgrade(&id, &signers.get(1).unwrap());
+    assert!(client.get_timelock_status(&id).is_some());
+
+    client.revoke_approval(&id, &signers.get(1).unwrap());
+    assert!(client.get_timelock_status(&id).is_none());
+    assert!(client.try_execute_upgrade(&id).is_err());
+
+    client.approve_upgrade(&id, &signers.get(2).unwrap());
+    assert!(client.get_timelock_status(&id).is_some());
+}
+
+#[test]
+fn test_revoke_without_approval_fails() {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    let hash = BytesN::from_array(&env, &[7u8; 32]);
+    let id = client.propose_upgrade(&signers.get(0).unwrap(), &hash, &0u64);
+    assert!(client.try_revoke_approval(&id, &signers.get(1).unwrap()).is_err());
+}
+
+#[test]
+fn test_prune_cancelled_proposal() {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    let hash = BytesN::from_array(&env, &[7u8; 32]);
+    let id = client.propose_upgrade(&signers.get(0).unwrap(), &hash, &0u64);
+
+    assert!(client.try_prune_upgrade_proposal(&id).is_err());
+
+    client.cancel_upgrade(&id, &signers.get(0).unwrap());
+    client.prune_upgrade_proposal(&id);
+    assert!(client.get_upgrade_proposal(&id).is_none());
+    assert!(client.try_prune_upgrade_proposal(&id).is_err());
+}
+
+#[test]
+fn test_prune_expired_proposal() {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    let hash = BytesN::from_array(&env, &[7u8; 32]);
+    let id = client.propose_upgrade(&signers.get(0).unwrap(), &hash, &100u64);
+
+    assert!(client.try_prune_upgrade_proposal(&id).is_err());
+    env.ledger().set_timestamp(101);
+    client.prune_upgrade_proposal(&id);
+    assert!(client.get_upgrade_proposal(&id).is_none());
+}
@@ -0,0 +1,197 @@
+//! # Proposal Storage Layout Tests
+//!
+//! Per-proposal entries live in persistent storage so historical proposals
+//! do not grow the instance entry loaded by every call:
+//! - With 20 proposals, `get_version` is charged far less than under the
+//!   legacy instance layout
+//! - `relocate_upgrade_proposals` moves legacy entries once and proposals
+//!   stay readable afterwards, with their TTL extended
+//! - The first proposal entrypoint after an upgrade relocates on its own
+//! - A backlog above `RELOCATION_BATCH` is drained over several calls from
+//!   a stored cursor; proposal entrypoints fail with
+//!   `ProposalStorageError::RelocationPending` until it is
+//! - `get_executed_upgrade` reports hash, approvers, and execution time
+
+#![cfg(test)]
+
+extern crate std;
+
+use grainlify_upgradeable::multisig::Proposal;
+use grainlify_upgradeable::{UpgradeKey, RELOCATED_TTL, RELOCATION_BATCH};
+use soroban_sdk::{
+    contracttype,
+    testutils::{storage::Persistent as _, Ledger as _},
+    vec, Address, BytesN, Env, IntoVal, TryFromVal, Val,
+};
+
+use crate::testutils::{setup_multisig, upload_dummy_wasm};
+use crate::{DataKey, GrainlifyContractClient, MultiSig, MAX_PENDING_UPGRADES};
+
+const PROPOSALS: u64 = 20;
+/// Enough proposals that relocation takes three batches.
+const BACKLOG: u64 = RELOCATION_BATCH * 2 + 5;
+
+/// Encodes like the multisig module's private proposal keys.
+#[contracttype]
+enum MultiSigKey {
+    Proposal(u64),
+    ProposalMeta(u64),
+    ProposalApprovals(u64),
+}
+
+fn with_proposals(env: &Env) -> (GrainlifyContractClient<'_>, Address) {
+    with_n_proposals(env, PROPOSALS)
+}
+
+fn with_n_proposals(env: &Env, count: u64) -> (GrainlifyContractClient<'_>, Address) {
+    env.budget().reset_unlimited();
+    let (client, signers) = setup_multisig(env, 3, 2);
+    let proposer = signers.get(0).unwrap();
+    for seed in 0..count {
+        let hash = BytesN::from_array(env, &[seed as u8; 32]);
+        let id = client.propose_upgrade(&proposer, &hash, &0u64);
+        // Closes the tail so a backlog can exceed the open-proposal cap.
+        if id >= MAX_PENDING_UPGRADES as u64 {
+            client.cancel_upgrade(&id, &proposer);
+        }
+    }
+    (client, proposer)
+}
+
+/// Rewrites every proposal entry into instance storage, as versions before
+/// the persistent layout stored them.
+fn revert_to_legacy_layout(env: &Env, contract: &Address) {
+    revert_n_to_legacy_layout(env, contract, PROPOSALS);
+}
+
+fn revert_n_to_legacy_layout(env: &Env, contract: &Address, count: u64) {
+    env.as_contract(contract, || {
+        for id in 1..=count {
+            let proposal: Proposal = MultiSig::get_proposal_opt(env, id).unwrap();
+            env.storage().persistent().remove(&MultiSigKey::ProposalMeta(id));
+            env.storage().persistent().remove(&MultiSigKey::ProposalApprovals(id));
+            env.storage().instance().set(&MultiSigKey::Proposal(id), &proposal);
+            move_to_instance::<BytesN<32>>(env, DataKey::UpgradeProposal(id));
+            move_to_instance::<Address>(env, DataKey::UpgradeProposalProposer(id));
+        }
+        env.storage().instance().remove(&UpgradeKey::ProposalsRelocated);
+    });
+}
+
+fn move_to_instance<V>(env: &Env, key: impl IntoVal<Env, Val>)
+where
+    V: IntoVal<Env, Val> + TryFromVal<Env, Val>,
+{
+    let key: Val = key.into_val(env);
+    let value: V = env.storage().persistent().get(&key).unwrap();
+    env.storage().persistent().remove(&key);
+    env.storage().instance().set(&key, &value);
+}
+
+/// Memory bytes charged for a `get_version` call.
+fn get_version_cost(env: &Env, client: &GrainlifyContractClient) -> u64 {
+    env.budget().reset_default();
+    client.get_version();
+    env.budget().memory_bytes_cost()
+}
+
+#[test]
+fn test_proposals_do_not_bloat_instance_reads() {
+    let env = Env::default();
+    let (client, _) = with_proposals(&env);
+    let persistent_cost = get_version_cost(&env, &client);
+
+    revert_to_legacy_layout(&env, &client.address);
+    let legacy_cost = get_version_cost(&env, &client);
+
+    assert!(
+        legacy_cost > persistent_cost * 2,
+        "legacy {} vs persistent {}",
+        legacy_cost,
+        persistent_cost
+    );
+}
+
+#[test]
+fn test_relocate_moves_legacy_entries_once() {
+    let env = Env::default();
+    let (client, proposer) = with_proposals(&env);
+    let before = client.get_upgrade_proposal(&7).unwrap();
+    let baseline = get_version_cost(&env, &client);
+    revert_to_legacy_layout(&env, &client.address);
+
+    env.budget().reset_unlimited();
+    assert!(client.relocate_upgrade_proposals());
+    assert!(!client.relocate_upgrade_proposals());
+
+    env.as_contract(&client.address, || {
+        assert!(!env.storage().instance().has(&MultiSigKey::Proposal(7)));
+        assert!(!env.storage().instance().has(&DataKey::UpgradeProposal(7)));
+        let storage = env.storage().persistent();
+        assert_eq!(storage.get_ttl(&MultiSigKey::ProposalMeta(7)), RELOCATED_TTL);
+        assert_eq!(storage.get_ttl(&DataKey::UpgradeProposalProposer(7)), RELOCATED_TTL);
+    });
+    let after = client.get_upgrade_proposal(&7).unwrap();
+    assert_eq!(after, before);
+    assert_eq!(after.proposer, Some(proposer));
+    assert_eq!(get_version_cost(&env, &client), baseline);
+}
+
+#[test]
+fn test_first_proposal_call_relocates() {
+    let env = Env::default();
+    let (client, proposer) = with_proposals(&env);
+    revert_to_legacy_layout(&env, &client.address);
+
+    env.budget().reset_unlimited();
+    client.approve_upgrade(&3, &proposer);
+    assert_eq!(client.get_upgrade_proposal(&3).unwrap().proposal_id, 3);
+    assert!(!client.relocate_upgrade_proposals());
+}
+
+#[test]
+fn test_large_backlog_relocates_in_batches() {
+    let env = Env::default();
+    let (client, proposer) = with_n_proposals(&env, BACKLOG);
+    revert_n_to_legacy_layout(&env, &client.address, BACKLOG);
+    env.budget().reset_unlimited();
+    assert_eq!(client.get_relocation_remaining(), Some(BACKLOG));
+    assert!(client.try_approve_upgrade(&3, &proposer).is_err());
+
+    assert!(client.relocate_upgrade_proposals());
+    assert_eq!(client.get_relocation_remaining(), Some(BACKLOG - RELOCATION_BATCH));
+    env.as_contract(&client.address, || {
+        assert!(!env.storage().instance().has(&MultiSigKey::Proposal(RELOCATION_BATCH)));
+        assert!(env.storage().instance().has(&MultiSigKey::Proposal(RELOCATION_BATCH + 1)));
+    });
+
+    // The remainder now fits in one batch, so an entrypoint finishes it.
+    assert!(client.relocate_upgrade_proposals());
+    client.approve_upgrade(&3, &proposer);
+    assert_eq!(client.get_relocation_remaining(), None);
+    assert!(!client.relocate_upgrade_proposals());
+    assert_eq!(client.get_upgrade_proposal(&BACKLOG).unwrap().proposal_id, BACKLOG);
+}
+
+#[test]
+fn test_executed_upgrade_history() {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    let wasm = upload_dummy_wasm(&env, 1);
+    let id = client.propose_upgrade(&signers.get(0).unwrap(), &wasm, &0u64);
+    client.approve_upgrade(&id, &signers.get(0).unwrap());
+    client.approve_upgrade(&id, &signers.get(1).unwrap());
+    assert!(client.get_executed_upgrade(&id).is_none());
+
+    let executed_at = client.get_timelock_delay();
+    env.ledger().set_timestamp(executed_at);
+    client.execute_upgrade(&id);
+
+    // The dummy module exports nothing, so read storage directly.
+    let history = env.as_contract(&client.address, || {
+        grainlify_upgradeable::get_executed_upgrade(&env, id).unwrap()
+    });
+    assert_eq!(history.wasm_hash, wasm);
+    assert_eq!(history.approvals, vec![&env, signers.get(0).unwrap(), signers.get(1).unwrap()]);
+    assert_eq!(history.executed_at, Some(executed_at));
+}
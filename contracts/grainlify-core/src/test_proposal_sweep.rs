@@ -0,0 +1,122 @@
+//! # Proposal Sweep Tests
+//!
+//! Builds a mix of proposal states and checks `sweep_proposals` removes
+//! exactly the finished ones:
+//! - Cancelled and expired proposals lose every stored entry
+//! - Executed proposals are archived but keep their upgrade history
+//! - Pending and executable proposals are untouched
+//! - The cursor resumes between calls and wraps after the newest ID
+
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{testutils::Ledger as _, BytesN, Env};
+
+use crate::testutils::{setup_multisig, upload_dummy_wasm};
+use crate::{DataKey, GrainlifyContractClient};
+
+const PENDING: u64 = 1;
+const CANCELLED: u64 = 2;
+const EXPIRED: u64 = 3;
+const EXECUTABLE: u64 = 4;
+const EXECUTED: u64 = 5;
+const PENDING_EXPIRING: u64 = 6;
+
+/// Six proposals, one per state above, at ledger time `now` = 1_000.
+fn mixed_states(env: &Env) -> GrainlifyContractClient<'_> {
+    let (client, signers) = setup_multisig(env, 3, 2);
+    let (a, b) = (signers.get(0).unwrap(), signers.get(1).unwrap());
+    let hash = BytesN::from_array(env, &[3u8; 32]);
+    env.ledger().set_timestamp(1_000);
+
+    client.propose_upgrade(&a, &hash, &0u64);
+    client.propose_upgrade(&a, &hash, &0u64);
+    client.cancel_upgrade(&CANCELLED, &a);
+    client.propose_upgrade(&a, &hash, &1_500u64);
+    client.propose_upgrade(&a, &hash, &0u64);
+    client.approve_upgrade(&EXECUTABLE, &a);
+    client.approve_upgrade(&EXECUTABLE, &b);
+
+    // Installing the dummy module replaces the contract's code, so the
+    // tests execute this proposal last.
+    client.propose_upgrade(&a, &upload_dummy_wasm(env, 5), &0u64);
+    client.approve_upgrade(&EXECUTED, &a);
+    client.approve_upgrade(&EXECUTED, &b);
+    client.propose_upgrade(&a, &hash, &1_000_000u64);
+
+    env.ledger().set_timestamp(1_000 + client.get_timelock_delay());
+    client
+}
+
+fn stored(env: &Env, client: &GrainlifyContractClient, id: u64) -> (bool, bool, bool) {
+    env.as_contract(&client.address, || {
+        (
+            crate::MultiSig::get_proposal_opt(env, id).is_some(),
+            env.storage().persistent().has(&DataKey::UpgradeProposal(id)),
+            env.storage().persistent().has(&DataKey::UpgradeProposalProposer(id)),
+        )
+    })
+}
+
+/// Executes `EXECUTED`, after which only storage reads are possible.
+fn execute_and_sweep(env: &Env, client: &GrainlifyContractClient, max: u32) -> u32 {
+    client.execute_upgrade(&EXECUTED);
+    env.as_contract(&client.address, || {
+        crate::GrainlifyContract::sweep_proposals(env.clone(), max)
+    })
+}
+
+#[test]
+fn test_sweep_removes_exactly_finished_proposals() {
+    let env = Env::default();
+    let client = mixed_states(&env);
+
+    assert_eq!(execute_and_sweep(&env, &client, 10), 3);
+
+    for id in [PENDING, EXECUTABLE, PENDING_EXPIRING] {
+        assert_eq!(stored(&env, &client, id), (true, true, true), "proposal {}", id);
+    }
+    for id in [CANCELLED, EXPIRED] {
+        assert_eq!(stored(&env, &client, id), (false, false, false), "proposal {}", id);
+    }
+    assert_eq!(stored(&env, &client, EXECUTED), (false, true, false));
+    let history = env.as_contract(&client.address, || {
+        grainlify_upgradeable::get_executed_upgrade(&env, EXECUTED)
+    });
+    assert_eq!(history.unwrap().approvals.len(), 2);
+
+    let again = env.as_contract(&client.address, || {
+        crate::GrainlifyContract::sweep_proposals(env.clone(), 10)
+    });
+    assert_eq!(again, 0);
+}
+
+#[test]
+fn test_sweep_cursor_resumes_and_wraps() {
+    let env = Env::default();
+    let client = mixed_states(&env);
+    assert_eq!(client.get_sweep_cursor(), 1);
+
+    // Examines 1..=2, cleaning the cancelled proposal.
+    assert_eq!(client.sweep_proposals(&2), 1);
+    assert_eq!(client.get_sweep_cursor(), 3);
+    // Examines 3..=4, cleaning the expired one; 4 is executable.
+    assert_eq!(client.sweep_proposals(&2), 1);
+    assert_eq!(client.get_sweep_cursor(), 5);
+
+    // 5 is approved but not executed yet, 6 is pending; then wrap to 1..=2.
+    assert_eq!(client.sweep_proposals(&4), 0);
+    assert_eq!(client.get_sweep_cursor(), 3);
+
+    assert_eq!(execute_and_sweep(&env, &client, 6), 1);
+    assert_eq!(stored(&env, &client, EXECUTABLE), (true, true, true));
+}
+
+#[test]
+fn test_sweep_with_no_proposals() {
+    let env = Env::default();
+    let (client, _signers) = setup_multisig(&env, 3, 2);
+    assert_eq!(client.sweep_proposals(&10), 0);
+    assert_eq!(client.sweep_proposals(&0), 0);
+}
@@ -0,0 +1,128 @@
+//! # Proposal Template Tests
+//!
+//! - `create_template` stores an action bundle under a name that
+//!   `list_templates` reports; creating it again replaces the actions
+//! - `propose_from_template` fills placeholders from its parameters, so
+//!   one template yields proposals with different versions
+//! - Parameters must match the kind of the action they replace
+//! - Multisig governance manages templates through `CreateTemplate`/
+//!   `DeleteTemplate` actions; a template cannot hold template actions
+//! - Templates change only through governance
+
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{map, symbol_short, testutils::Ledger as _, vec, Address, Env, Map, Symbol, Vec};
+
+use crate::testutils::{setup_admin, setup_multisig, upload_dummy_wasm, GovernanceDriver};
+use crate::{ConfigUpdate, GrainlifyContractClient, ProposalAction};
+
+fn monthly(env: &Env) -> Vec<ProposalAction> {
+    let update = ConfigUpdate { ttl_warning_threshold: Some(50_000), ..Default::default() };
+    vec![env, ProposalAction::UpdateConfig(update), ProposalAction::SetVersion(0)]
+}
+
+/// Contract created by `init` with a 2-of-3 multisig.
+fn setup(env: &Env) -> (GrainlifyContractClient<'_>, Vec<Address>) {
+    setup_multisig(env, 3, 2)
+}
+
+fn create(env: &Env, client: &GrainlifyContractClient, signers: &Vec<Address>, name: &Symbol, actions: Vec<ProposalAction>) {
+    let bundle = vec![env, ProposalAction::CreateTemplate(name.clone(), actions)];
+    GovernanceDriver::apply_actions(env, client, signers, bundle);
+}
+
+fn pass(env: &Env, client: &GrainlifyContractClient, signers: &Vec<Address>, proposal_id: u64) {
+    client.approve_actions(&proposal_id, &signers.get(0).unwrap());
+    client.approve_actions(&proposal_id, &signers.get(1).unwrap());
+    env.ledger().set_timestamp(env.ledger().timestamp() + client.get_timelock_delay());
+    client.execute_proposal(&proposal_id);
+}
+
+#[test]
+fn test_template_instantiated_twice() {
+    let env = Env::default();
+    let (client, signers) = setup(&env);
+    let name = symbol_short!("monthly");
+    create(&env, &client, &signers, &name, monthly(&env));
+    assert_eq!(client.list_templates(), vec![&env, name.clone()]);
+
+    let proposer = signers.get(0).unwrap();
+    let version = |v: u32| map![&env, (1, ProposalAction::SetVersion(v))];
+    let first = client.propose_from_template(&name, &proposer, &version(7));
+    let second = client.propose_from_template(&name, &proposer, &version(8));
+    assert_eq!(client.get_proposal_actions(&first).unwrap().get(1), Some(ProposalAction::SetVersion(7)));
+    assert_eq!(client.get_proposal_actions(&second).unwrap().get(1), Some(ProposalAction::SetVersion(8)));
+
+    pass(&env, &client, &signers, first);
+    assert_eq!(client.get_version(), 7);
+    pass(&env, &client, &signers, second);
+    assert_eq!(client.get_version(), 8);
+    assert_eq!(client.get_config().ttl_warning_threshold, 50_000);
+    assert_eq!(client.get_template(&name), Some(monthly(&env)));
+}
+
+#[test]
+fn test_update_and_delete_template() {
+    let env = Env::default();
+    let (client, signers) = setup(&env);
+    let name = symbol_short!("monthly");
+    create(&env, &client, &signers, &name, monthly(&env));
+    create(&env, &client, &signers, &symbol_short!("other"), monthly(&env));
+
+    let replacement = vec![&env, ProposalAction::SetVersion(0)];
+    create(&env, &client, &signers, &name, replacement.clone());
+    assert_eq!(client.get_template(&name), Some(replacement));
+    assert_eq!(client.list_templates().len(), 2);
+
+    let delete = vec![&env, ProposalAction::DeleteTemplate(name.clone())];
+    GovernanceDriver::apply_actions(&env, &client, &signers, delete.clone());
+    assert_eq!(client.get_template(&name), None);
+    assert_eq!(client.list_templates(), vec![&env, symbol_short!("other")]);
+    let proposal_id = client.propose_actions(&signers.get(0).unwrap(), &delete, &0);
+    GovernanceDriver::approve_to_threshold(&env, &client, &signers, proposal_id);
+    GovernanceDriver::skip_timelock(&env, &client);
+    assert!(client.try_execute_proposal(&proposal_id).is_err());
+}
+
+#[test]
+fn test_mismatched_or_missing_parameters_rejected() {
+    let env = Env::default();
+    let (client, signers) = setup(&env);
+    let name = symbol_short!("monthly");
+    create(&env, &client, &signers, &name, monthly(&env));
+    let proposer = signers.get(0).unwrap();
+
+    let wrong_kind = map![&env, (1, ProposalAction::Upgrade(upload_dummy_wasm(&env, 1)))];
+    assert!(client.try_propose_from_template(&name, &proposer, &wrong_kind).is_err());
+    let past_end = map![&env, (2, ProposalAction::SetVersion(7))];
+    assert!(client.try_propose_from_template(&name, &proposer, &past_end).is_err());
+    assert!(client
+        .try_propose_from_template(&symbol_short!("missing"), &proposer, &Map::new(&env))
+        .is_err());
+}
+
+#[test]
+fn test_template_cannot_hold_template_actions() {
+    let env = Env::default();
+    let (client, _admin) = setup_admin(&env);
+    let nested = vec![&env, ProposalAction::DeleteTemplate(symbol_short!("monthly"))];
+    assert!(client.try_create_template(&symbol_short!("nested"), &nested).is_err());
+    assert!(client.list_templates().is_empty());
+}
+
+#[test]
+fn test_templates_need_governance() {
+    let env = Env::default();
+    let (client, _signers) = setup(&env);
+    // No admin on an `init` contract: only an action bundle can create one.
+    assert!(client.try_create_template(&symbol_short!("monthly"), &monthly(&env)).is_err());
+
+    let (admin_client, _admin) = setup_admin(&env);
+    admin_client.create_template(&symbol_short!("monthly"), &monthly(&env));
+    env.set_auths(&[]);
+    assert!(admin_client.try_delete_template(&symbol_short!("monthly")).is_err());
+    assert_eq!(admin_client.list_templates().len(), 1);
+    assert!(client.list_templates().is_empty());
+}
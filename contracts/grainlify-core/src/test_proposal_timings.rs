@@ -0,0 +1,134 @@
+//! # Proposal Timing Tests
+//!
+//! - Each multisig proposal records when it was created, first met its
+//!   threshold, and executed
+//! - `get_governance_stats` keeps running averages of time-to-threshold and
+//!   time-to-execution
+//! - Cancelled proposals are counted on cancel, expired ones when pruned
+
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{testutils::Ledger as _, vec, Env};
+
+use crate::testutils::{setup_multisig, upload_dummy_wasm};
+use crate::{GovernanceStats, GrainlifyContract, ProposalAction, ProposalTimings};
+
+#[test]
+fn test_timings_follow_proposal_lifecycle() {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    let wasm_hash = upload_dummy_wasm(&env, 1);
+    env.ledger().set_timestamp(1_000);
+
+    let proposal_id = client.propose_upgrade(&signers.get(0).unwrap(), &wasm_hash, &0);
+    client.approve_upgrade(&proposal_id, &signers.get(0).unwrap());
+    assert_eq!(
+        client.get_proposal_timings(&proposal_id),
+        Some(ProposalTimings { created_at: 1_000, threshold_met_at: None, executed_at: None })
+    );
+
+    env.ledger().set_timestamp(1_600);
+    client.approve_upgrade(&proposal_id, &signers.get(1).unwrap());
+    let execute_at = 1_600 + client.get_timelock_delay() + 400;
+    env.ledger().set_timestamp(execute_at);
+    client.execute_upgrade_v2(&proposal_id);
+
+    // The dummy WASM is installed now, so read through the native contract.
+    let timings = env.as_contract(&client.address, || {
+        GrainlifyContract::get_proposal_timings(env.clone(), proposal_id)
+    });
+    assert_eq!(
+        timings,
+        Some(ProposalTimings {
+            created_at: 1_000,
+            threshold_met_at: Some(1_600),
+            executed_at: Some(execute_at),
+        })
+    );
+}
+
+#[test]
+fn test_stats_average_over_proposals() {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    let (a, b) = (signers.get(0).unwrap(), signers.get(1).unwrap());
+    let delay = client.get_timelock_delay();
+
+    // First bundle: threshold after 100s, executed 200s after the timelock.
+    env.ledger().set_timestamp(1_000);
+    let first = client.propose_actions(&a, &vec![&env, ProposalAction::SetVersion(3)], &0);
+    client.approve_actions(&first, &a);
+    env.ledger().set_timestamp(1_100);
+    client.approve_actions(&first, &b);
+    env.ledger().set_timestamp(1_100 + delay + 200);
+    client.execute_proposal(&first);
+
+    // Second bundle: threshold after 300s, executed 600s after the timelock.
+    let start = env.ledger().timestamp();
+    let second = client.propose_actions(&a, &vec![&env, ProposalAction::SetVersion(4)], &0);
+    client.approve_actions(&second, &a);
+    env.ledger().set_timestamp(start + 300);
+    client.approve_actions(&second, &b);
+    env.ledger().set_timestamp(start + 300 + delay + 600);
+    client.execute_proposal(&second);
+
+    assert_eq!(
+        client.get_governance_stats(),
+        GovernanceStats {
+            created: 2,
+            reached_threshold: 2,
+            executed: 2,
+            expired: 0,
+            cancelled: 0,
+            avg_time_to_threshold: 200,
+            avg_time_to_execution: delay + 400,
+        }
+    );
+}
+
+#[test]
+fn test_cancelled_and_expired_counts() {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    let proposer = signers.get(0).unwrap();
+    let wasm_hash = upload_dummy_wasm(&env, 1);
+
+    let cancelled = client.propose_upgrade(&proposer, &wasm_hash, &0);
+    client.cancel_upgrade(&cancelled, &proposer);
+    let expiring = client.propose_upgrade(&proposer, &wasm_hash, &500);
+    let swept = client.propose_upgrade(&proposer, &wasm_hash, &500);
+
+    env.ledger().set_timestamp(501);
+    let stats = client.get_governance_stats();
+    assert_eq!((stats.cancelled, stats.expired), (1, 0));
+
+    client.prune_upgrade_proposal(&expiring);
+    client.prune_upgrade_proposal(&cancelled);
+    assert_eq!(client.sweep_proposals(&10), 1);
+    assert_eq!(client.get_proposal_timings(&swept), None);
+
+    let stats = client.get_governance_stats();
+    assert_eq!((stats.created, stats.cancelled, stats.expired), (3, 1, 2));
+    assert_eq!(stats.avg_time_to_threshold, 0);
+}
+
+#[test]
+fn test_threshold_time_kept_after_revoke() {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    let (a, b) = (signers.get(0).unwrap(), signers.get(1).unwrap());
+    let proposal_id = client.propose_upgrade(&a, &upload_dummy_wasm(&env, 1), &0);
+
+    client.approve_upgrade(&proposal_id, &a);
+    env.ledger().set_timestamp(50);
+    client.approve_upgrade(&proposal_id, &b);
+    client.revoke_approval(&proposal_id, &b);
+    env.ledger().set_timestamp(90);
+    client.approve_upgrade(&proposal_id, &b);
+
+    let timings = client.get_proposal_timings(&proposal_id).unwrap();
+    assert_eq!(timings.threshold_met_at, Some(50));
+    assert_eq!(client.get_governance_stats().reached_threshold, 1);
+}
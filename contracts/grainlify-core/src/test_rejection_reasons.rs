@@ -0,0 +1,128 @@
+//! # Rejection Reason Tests
+//!
+//! - `revoke_approval_with_reason` and `cancel_upgrade_with_reason` store
+//!   their reasons, which `get_rejections` returns oldest first
+//! - The `("upgrade", "rejected")` event carries the reason
+//! - Reasons are bounded by the `reason` string limit
+//! - With reasons required, empty reasons and the reasonless forms fail
+
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Events as _, Ledger as _},
+    vec, Address, Env, IntoVal, String, TryFromVal, Vec,
+};
+
+use crate::testutils::{setup_admin, setup_multisig, upload_dummy_wasm, GovernanceDriver};
+use crate::{limits, GrainlifyContractClient, ProposalAction, ProposalRejectedEvent};
+
+fn text(env: &Env, s: &str) -> String {
+    String::from_str(env, s)
+}
+
+#[test]
+fn test_reasons_stored_in_order() {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    let (first, second) = (signers.get(0).unwrap(), signers.get(1).unwrap());
+    let proposal_id = client.propose_upgrade(&first, &upload_dummy_wasm(&env, 1), &0);
+    client.approve_upgrade(&proposal_id, &first);
+
+    env.ledger().set_timestamp(10);
+    client.revoke_approval_with_reason(&proposal_id, &first, &text(&env, "hash mismatch"));
+    env.ledger().set_timestamp(20);
+    client.cancel_upgrade_with_reason(&proposal_id, &second, &text(&env, "superseded"));
+
+    assert_eq!(
+        client.get_rejections(&proposal_id),
+        vec![
+            &env,
+            (first, text(&env, "hash mismatch"), 10),
+            (second.clone(), text(&env, "superseded"), 20),
+        ]
+    );
+
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(topics, (symbol_short!("upgrade"), symbol_short!("rejected")).into_val(&env));
+    let event = ProposalRejectedEvent::try_from_val(&env, &data).unwrap();
+    assert_eq!((event.signer, event.action), (second, symbol_short!("cancel")));
+    assert_eq!(event.reason, text(&env, "superseded"));
+}
+
+#[test]
+fn test_reasonless_forms_record_empty_reason() {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    let signer = signers.get(0).unwrap();
+    let proposal_id = client.propose_upgrade(&signer, &upload_dummy_wasm(&env, 1), &0);
+    client.cancel_upgrade(&proposal_id, &signer);
+
+    assert_eq!(client.get_rejections(&proposal_id), vec![&env, (signer, text(&env, ""), 0)]);
+    assert!(client.get_rejections(&99).is_empty());
+}
+
+#[test]
+fn test_reason_length_enforced() {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    let signer = signers.get(0).unwrap();
+    let proposal_id = client.propose_upgrade(&signer, &upload_dummy_wasm(&env, 1), &0);
+
+    let too_long = "x".repeat(limits::MAX_REASON_LEN as usize + 1);
+    assert!(client.try_cancel_upgrade_with_reason(&proposal_id, &signer, &text(&env, &too_long)).is_err());
+    let at_limit = "x".repeat(limits::MAX_REASON_LEN as usize);
+    client.cancel_upgrade_with_reason(&proposal_id, &signer, &text(&env, &at_limit));
+    assert_eq!(client.get_rejections(&proposal_id).len(), 1);
+}
+
+#[test]
+#[should_panic(expected = "139")]
+fn test_oversized_reason_fails_with_description_too_long() {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    let signer = signers.get(0).unwrap();
+    let proposal_id = client.propose_upgrade(&signer, &upload_dummy_wasm(&env, 1), &0);
+    client.approve_upgrade(&proposal_id, &signer);
+    let too_long = "x".repeat(limits::MAX_REASON_LEN as usize + 1);
+    client.revoke_approval_with_reason(&proposal_id, &signer, &text(&env, &too_long));
+}
+
+fn require_reasons(env: &Env, client: &GrainlifyContractClient, signers: &Vec<Address>, required: bool) {
+    let actions = vec![env, ProposalAction::SetRejectionReasonRequired(required)];
+    GovernanceDriver::apply_actions(env, client, signers, actions);
+}
+
+#[test]
+fn test_required_reason_flag() {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    let signer = signers.get(0).unwrap();
+    let proposal_id = client.propose_upgrade(&signer, &upload_dummy_wasm(&env, 1), &0);
+    client.approve_upgrade(&proposal_id, &signer);
+    assert!(!client.is_rejection_reason_required());
+
+    require_reasons(&env, &client, &signers, true);
+    assert!(client.is_rejection_reason_required());
+    assert!(client.try_revoke_approval(&proposal_id, &signer).is_err());
+    assert!(client.try_revoke_approval_with_reason(&proposal_id, &signer, &text(&env, "")).is_err());
+    assert!(client.try_cancel_upgrade(&proposal_id, &signer).is_err());
+    client.revoke_approval_with_reason(&proposal_id, &signer, &text(&env, "needs audit"));
+
+    require_reasons(&env, &client, &signers, false);
+    client.cancel_upgrade(&proposal_id, &signer);
+    assert_eq!(client.get_rejections(&proposal_id).len(), 2);
+}
+
+#[test]
+fn test_flag_is_governance_only() {
+    let env = Env::default();
+    let (client, _signers) = setup_multisig(&env, 3, 2);
+    assert!(client.try_set_rejection_reason_required(&true).is_err());
+
+    let (client, _admin) = setup_admin(&env);
+    client.set_rejection_reason_required(&true);
+    assert!(client.is_rejection_reason_required());
+}
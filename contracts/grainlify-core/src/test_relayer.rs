@@ -0,0 +1,119 @@
+//! # Relayed Execution Tests
+//!
+//! - The configured relayer executes an approved proposal, and the archive
+//!   and `("upgrade", "executed")` event record it as relayed
+//! - Any other address fails with `NotRelayer`, as does the former relayer
+//!   once cleared
+//! - Multisig governance sets and clears the relayer through
+//!   `SetRelayer`/`ClearRelayer` actions; the admin entrypoints are for
+//!   admin-mode contracts
+
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events as _},
+    vec, Address, Env, IntoVal, TryFromVal, Vec,
+};
+
+use crate::testutils::{setup_admin, setup_multisig, upload_dummy_wasm, GovernanceDriver};
+use crate::{GrainlifyContract, GrainlifyContractClient, ProposalAction, UpgradeExecutedEvent};
+
+/// Contract created by `init` with a 2-of-3 multisig.
+fn setup(env: &Env) -> (GrainlifyContractClient<'_>, Vec<Address>) {
+    setup_multisig(env, 3, 2)
+}
+
+fn set_relayer(env: &Env, client: &GrainlifyContractClient, signers: &Vec<Address>, relayer: &Address) {
+    let actions = vec![env, ProposalAction::SetRelayer(relayer.clone())];
+    GovernanceDriver::apply_actions(env, client, signers, actions);
+}
+
+fn approved_proposal(env: &Env, client: &GrainlifyContractClient, signers: &Vec<Address>) -> u64 {
+    let proposal_id = GovernanceDriver::propose(env, client, signers, &upload_dummy_wasm(env, 1));
+    GovernanceDriver::approve_to_threshold(env, client, signers, proposal_id);
+    GovernanceDriver::skip_timelock(env, client);
+    proposal_id
+}
+
+#[test]
+fn test_relayer_executes_and_is_recorded() {
+    let env = Env::default();
+    let (client, signers) = setup(&env);
+    let relayer = Address::generate(&env);
+    set_relayer(&env, &client, &signers, &relayer);
+    assert_eq!(client.get_relayer(), Some(relayer.clone()));
+    let proposal_id = approved_proposal(&env, &client, &signers);
+
+    client.execute_upgrade_relayed(&relayer, &proposal_id, &false);
+    let (authorized, _) = env.auths().into_iter().next().unwrap();
+    assert_eq!(authorized, relayer);
+
+    let topics = (symbol_short!("upgrade"), symbol_short!("executed")).into_val(&env);
+    let (_, _, data) = env.events().all().iter().find(|(_, t, _)| *t == topics).unwrap();
+    let event = UpgradeExecutedEvent::try_from_val(&env, &data).unwrap();
+    assert!(event.relayed);
+    assert_eq!(event.executor, Some(relayer.clone()));
+
+    let archived = env.as_contract(&client.address, || {
+        GrainlifyContract::get_archived_upgrade(env.clone(), 0).unwrap()
+    });
+    assert!(archived.relayed);
+    assert_eq!(archived.executor, Some(relayer));
+}
+
+#[test]
+fn test_other_executor_is_not_relayed() {
+    let env = Env::default();
+    let (client, signers) = setup(&env);
+    set_relayer(&env, &client, &signers, &Address::generate(&env));
+    let proposal_id = approved_proposal(&env, &client, &signers);
+
+    client.execute_upgrade_as(&Address::generate(&env), &proposal_id, &false);
+    let archived = env.as_contract(&client.address, || {
+        GrainlifyContract::get_archived_upgrade(env.clone(), 0).unwrap()
+    });
+    assert!(!archived.relayed);
+}
+
+#[test]
+#[should_panic(expected = "138")]
+fn test_unlisted_relayer_is_rejected() {
+    let env = Env::default();
+    let (client, signers) = setup(&env);
+    set_relayer(&env, &client, &signers, &Address::generate(&env));
+    let proposal_id = approved_proposal(&env, &client, &signers);
+    client.execute_upgrade_relayed(&Address::generate(&env), &proposal_id, &false);
+}
+
+#[test]
+fn test_cleared_relayer_is_rejected() {
+    let env = Env::default();
+    let (client, signers) = setup(&env);
+    let relayer = Address::generate(&env);
+    set_relayer(&env, &client, &signers, &relayer);
+    GovernanceDriver::apply_actions(&env, &client, &signers, vec![&env, ProposalAction::ClearRelayer]);
+    assert_eq!(client.get_relayer(), None);
+
+    let proposal_id = approved_proposal(&env, &client, &signers);
+    assert!(client.try_execute_upgrade_relayed(&relayer, &proposal_id, &false).is_err());
+}
+
+#[test]
+fn test_set_relayer_requires_governance() {
+    let env = Env::default();
+    let (client, _signers) = setup(&env);
+    // No admin on an `init` contract, so the entrypoints fail outright.
+    assert!(client.try_set_relayer(&Address::generate(&env)).is_err());
+    assert!(client.try_clear_relayer().is_err());
+
+    let (admin_client, _admin) = setup_admin(&env);
+    let relayer = Address::generate(&env);
+    admin_client.set_relayer(&relayer);
+    assert_eq!(admin_client.get_relayer(), Some(relayer));
+    env.set_auths(&[]);
+    assert!(admin_client.try_clear_relayer().is_err());
+    assert!(admin_client.get_relayer().is_some());
+}
@@ -0,0 +1,100 @@
+//! # Repropose Tests
+//!
+//! - An expired proposal can be re-proposed under a new ID with a fresh
+//!   creation time and the same lifetime; the new one approves and executes
+//! - Approvals never carry over, the old record links to the new one, and a
+//!   `repropose` event carries both IDs
+//! - Open, cancelled, executed, and already-superseded proposals are rejected
+
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{testutils::Events as _, testutils::Ledger as _, Env, Symbol, TryFromVal};
+
+use crate::testutils::{setup_multisig, upload_dummy_wasm};
+
+fn repropose_event(env: &Env) -> Option<(u64, u64)> {
+    let mut found = None;
+    for (_, topics, data) in env.events().all().iter() {
+        if topics.len() != 2 {
+            continue;
+        }
+        let t1 = Symbol::try_from_val(env, &topics.get(1).unwrap());
+        if t1 == Ok(Symbol::new(env, "repropose")) {
+            found = <(u64, u64)>::try_from_val(env, &data).ok();
+        }
+    }
+    found
+}
+
+#[test]
+fn test_repropose_expired_then_execute() {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    let wasm = upload_dummy_wasm(&env, 1);
+    env.ledger().set_timestamp(100);
+    let old_id = client.propose_upgrade(&signers.get(0).unwrap(), &wasm, &200_100);
+    client.approve_upgrade(&old_id, &signers.get(0).unwrap());
+
+    env.ledger().set_timestamp(300_000);
+    let new_id = client.repropose(&old_id, &signers.get(1).unwrap());
+    assert_ne!(new_id, old_id);
+    assert_eq!(repropose_event(&env), Some((old_id, new_id)));
+
+    let old = client.get_upgrade_proposal(&old_id).unwrap();
+    assert_eq!(old.superseded_by, Some(new_id));
+    let new = client.get_upgrade_proposal(&new_id).unwrap();
+    assert_eq!(new.wasm_hash, wasm);
+    assert_eq!(new.proposed_at, 300_000);
+    assert_eq!(new.expiry, 500_000, "keeps the original lifetime");
+    assert_eq!(new.superseded_by, None);
+
+    // Signer 0's approval of the old proposal does not count toward the new one.
+    client.approve_upgrade(&new_id, &signers.get(0).unwrap());
+    env.ledger().set_timestamp(300_000 + client.get_timelock_delay());
+    assert!(client.try_execute_upgrade(&new_id).is_err());
+
+    env.ledger().set_timestamp(300_000);
+    client.approve_upgrade(&new_id, &signers.get(2).unwrap());
+    env.ledger().set_timestamp(300_000 + client.get_timelock_delay());
+    let receipt = client.execute_upgrade_v2(&new_id);
+    assert_eq!((receipt.proposal_id, receipt.wasm_hash), (new_id, wasm));
+}
+
+#[test]
+fn test_repropose_rejects_open_cancelled_and_superseded() {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    let proposer = signers.get(0).unwrap();
+    let wasm = upload_dummy_wasm(&env, 1);
+    env.ledger().set_timestamp(100);
+
+    let open_id = client.propose_upgrade(&proposer, &wasm, &500);
+    assert!(client.try_repropose(&open_id, &proposer).is_err());
+
+    let cancelled_id = client.propose_upgrade(&proposer, &wasm, &500);
+    client.cancel_upgrade(&cancelled_id, &proposer);
+    env.ledger().set_timestamp(600);
+    assert!(client.try_repropose(&cancelled_id, &proposer).is_err());
+
+    client.repropose(&open_id, &proposer);
+    assert!(client.try_repropose(&open_id, &proposer).is_err(), "already superseded");
+    assert!(client.try_repropose(&99, &proposer).is_err());
+}
+
+#[test]
+fn test_repropose_rejects_executed() {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    let wasm = upload_dummy_wasm(&env, 1);
+    env.ledger().set_timestamp(100);
+    let id = client.propose_upgrade(&signers.get(0).unwrap(), &wasm, &200_000);
+    client.approve_upgrade(&id, &signers.get(0).unwrap());
+    client.approve_upgrade(&id, &signers.get(1).unwrap());
+    env.ledger().set_timestamp(100 + client.get_timelock_delay());
+    client.execute_upgrade(&id);
+
+    env.ledger().set_timestamp(300_000);
+    assert!(client.try_repropose(&id, &signers.get(0).unwrap()).is_err());
+}
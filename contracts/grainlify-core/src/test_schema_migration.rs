@@ -0,0 +1,159 @@
+//! # Storage Schema Migration Tests
+//!
+//! - Fresh contracts start at `STORAGE_SCHEMA_VERSION` and `migrate_schema`
+//!   leaves them untouched
+//! - A schema 1 store (metrics under bare `Symbol` keys, performance stats
+//!   under per-function tuples) is refused by `require_schema` readers
+//!   until migrated, then reads the same numbers from `MetricsKey` entries
+//! - Running `migrate_schema` twice: the second call is a no-op
+//! - Metrics written by upgrade paths before the migration are merged
+//! - The admin or any signer may migrate, so a multisig contract created
+//!   by `init` can; anyone else cannot
+
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{symbol_short, testutils::{Address as _, Events as _}, Address, Env, Symbol, TryFromVal, Vec};
+
+use crate::monitoring::{Analytics, MetricTotals, MetricsKey, PerfCounters};
+use crate::testutils::{setup_admin, setup_multisig};
+use crate::{DataKey, GrainlifyContractClient, SchemaMigratedEvent, STORAGE_SCHEMA_VERSION};
+
+/// Rewrites the metrics in the schema 1 layout and removes the schema
+/// marker.
+fn revert_to_schema_1(env: &Env, client: &GrainlifyContractClient) {
+    env.as_contract(&client.address, || {
+        let storage = env.storage().persistent();
+        let legacy_key = |name: &str| Symbol::new(env, name);
+        if let Some(totals) = storage.get::<_, MetricTotals>(&MetricsKey::Totals) {
+            storage.remove(&MetricsKey::Totals);
+            storage.set(&legacy_key("op_count"), &totals.op_count);
+            storage.set(&legacy_key("usr_count"), &totals.usr_count);
+            storage.set(&legacy_key("err_count"), &totals.err_count);
+            storage.set(&legacy_key("last_op_ts"), &totals.last_op_ts);
+        }
+        if let Some(users) = storage.get::<_, Vec<Address>>(&MetricsKey::TrackedUsers) {
+            storage.remove(&MetricsKey::TrackedUsers);
+            storage.set(&legacy_key("usr_index"), &users);
+        }
+        let index: Vec<Symbol> = storage.get(&MetricsKey::PerfIndex).unwrap_or(Vec::new(env));
+        for function in index.iter() {
+            let key = MetricsKey::FunctionPerf(function.clone());
+            let perf: PerfCounters = storage.get(&key).unwrap();
+            storage.remove(&key);
+            storage.set(&(legacy_key("perf_cnt"), function.clone()), &perf.call_count);
+            storage.set(&(legacy_key("perf_time"), function.clone()), &perf.total_time);
+            storage.set(&(legacy_key("perf_last"), function), &perf.last_called);
+        }
+        storage.remove(&MetricsKey::PerfIndex);
+        storage.set(&legacy_key("perf_index"), &index);
+        env.storage().instance().remove(&DataKey::SchemaVersion);
+    });
+}
+
+const LEGACY_NAMES: [&str; 5] = ["op_count", "usr_count", "err_count", "last_op_ts", "usr_index"];
+
+fn schema_events(env: &Env) -> std::vec::Vec<SchemaMigratedEvent> {
+    env.events()
+        .all()
+        .iter()
+        .filter(|(_, topics, _)| {
+            topics.get(0).and_then(|t| Symbol::try_from_val(env, &t).ok())
+                == Some(Symbol::new(env, "schema"))
+        })
+        .map(|(_, _, data)| SchemaMigratedEvent::try_from_val(env, &data).unwrap())
+        .collect()
+}
+
+#[test]
+fn test_fresh_contract_is_current() {
+    let env = Env::default();
+    let (client, admin) = setup_admin(&env);
+    assert_eq!(client.get_schema_version(), STORAGE_SCHEMA_VERSION);
+    assert_eq!(client.migrate_schema(&admin), STORAGE_SCHEMA_VERSION);
+    assert!(schema_events(&env).is_empty());
+}
+
+#[test]
+fn test_migrate_consolidates_metrics_and_second_run_is_noop() {
+    let env = Env::default();
+    let (client, admin) = setup_admin(&env);
+    client.set_version(&3);
+    client.set_version(&4);
+    let before: Analytics = client.get_analytics();
+    assert_eq!(before.operation_count, 2);
+    let perf_before = client.get_performance_stats(&symbol_short!("set_ver"));
+    assert_eq!(perf_before.call_count, 2);
+
+    revert_to_schema_1(&env, &client);
+    assert_eq!(client.get_schema_version(), 1);
+    assert!(client.try_get_analytics().is_err());
+
+    assert_eq!(client.migrate_schema(&admin), 2);
+    let steps: std::vec::Vec<(u32, u32)> =
+        schema_events(&env).iter().map(|e| (e.from_version, e.to_version)).collect();
+    assert_eq!(steps, [(1, 2)]);
+    assert_eq!(client.get_analytics(), before);
+    let perf = client.get_performance_stats(&symbol_short!("set_ver"));
+    assert_eq!((perf.call_count, perf.total_time, perf.last_called), (2, perf_before.total_time, perf_before.last_called));
+    env.as_contract(&client.address, || {
+        for name in LEGACY_NAMES {
+            assert!(!env.storage().persistent().has(&Symbol::new(&env, name)));
+        }
+        assert!(!env.storage().persistent().has(&Symbol::new(&env, "perf_index")));
+    });
+
+    let emitted = schema_events(&env).len();
+    assert_eq!(client.migrate_schema(&admin), 2);
+    assert_eq!(schema_events(&env).len(), emitted);
+    assert_eq!(client.get_analytics(), before);
+}
+
+#[test]
+fn test_metrics_written_before_migration_are_merged() {
+    let env = Env::default();
+    let (client, admin) = setup_admin(&env);
+    client.set_version(&3);
+    client.set_version(&4);
+    revert_to_schema_1(&env, &client);
+
+    // set_version is not schema-gated, so it writes under the new keys.
+    client.set_version(&5);
+    client.migrate_schema(&admin);
+
+    let analytics = client.get_analytics();
+    assert_eq!(analytics.operation_count, 3);
+    assert_eq!(analytics.unique_users, 1);
+}
+
+#[test]
+fn test_signer_migrates_multisig_contract() {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    revert_to_schema_1(&env, &client);
+    assert!(client.try_get_analytics().is_err());
+
+    assert_eq!(client.migrate_schema(&signers.get(2).unwrap()), STORAGE_SCHEMA_VERSION);
+    assert_eq!(schema_events(&env).len(), 1);
+    assert_eq!(client.get_analytics().operation_count, 0);
+}
+
+#[test]
+fn test_outsider_cannot_migrate() {
+    let env = Env::default();
+    let (client, _signers) = setup_multisig(&env, 3, 2);
+    revert_to_schema_1(&env, &client);
+    assert!(client.try_migrate_schema(&Address::generate(&env)).is_err());
+    assert_eq!(client.get_schema_version(), 1);
+}
+
+#[test]
+fn test_migrate_requires_caller_auth() {
+    let env = Env::default();
+    let (client, admin) = setup_admin(&env);
+    revert_to_schema_1(&env, &client);
+    env.set_auths(&[]);
+    assert!(client.try_migrate_schema(&admin).is_err());
+    assert_eq!(client.get_schema_version(), 1);
+}
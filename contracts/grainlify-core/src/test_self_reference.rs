@@ -0,0 +1,136 @@
+//! # Self-Reference Tests
+//!
+//! - The contract's own address is rejected with `SelfReference` as the
+//!   admin of every admin init path, as a multisig signer, and as relayer
+//! - `migrate_to_multisig` rejects it among the new signers
+//! - An admin kept on as a signer, or a relayer that already holds a
+//!   governance role, is allowed but flagged via `("role", "overlap")`
+
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{
+    symbol_short, testutils::Address as _, testutils::Events as _, vec, Address, Env, IntoVal, String,
+    Symbol, TryFromVal,
+};
+
+use crate::governance::{GovernanceConfig, VotingScheme};
+use crate::testutils::setup_admin;
+use crate::{GrainlifyContract, GrainlifyContractClient, MultiSig};
+
+fn uninitialized(env: &Env) -> GrainlifyContractClient<'_> {
+    env.mock_all_auths();
+    GrainlifyContractClient::new(env, &env.register_contract(None, GrainlifyContract))
+}
+
+fn overlaps(env: &Env) -> std::vec::Vec<(Symbol, Symbol, Address)> {
+    let topics = (symbol_short!("role"), symbol_short!("overlap")).into_val(env);
+    env.events()
+        .all()
+        .iter()
+        .filter(|(_, t, _)| *t == topics)
+        .map(|(_, _, data)| <(Symbol, Symbol, Address)>::try_from_val(env, &data).unwrap())
+        .collect()
+}
+
+#[test]
+#[should_panic(expected = "145")]
+fn test_init_admin_rejects_self() {
+    let env = Env::default();
+    let client = uninitialized(&env);
+    client.init_admin(&client.address);
+}
+
+#[test]
+#[should_panic(expected = "145")]
+fn test_init_with_network_rejects_self() {
+    let env = Env::default();
+    let client = uninitialized(&env);
+    client.init_with_network(
+        &client.address,
+        &String::from_str(&env, "stellar"),
+        &String::from_str(&env, "testnet"),
+    );
+}
+
+#[test]
+#[should_panic(expected = "145")]
+fn test_init_governance_rejects_self() {
+    let env = Env::default();
+    let client = uninitialized(&env);
+    let config = GovernanceConfig {
+        voting_period: 86_400,
+        execution_delay: 3_600,
+        quorum_percentage: 5_000,
+        approval_threshold: 6_000,
+        min_proposal_stake: 1,
+        voting_scheme: VotingScheme::OnePersonOneVote,
+        governance_token: Address::generate(&env),
+    };
+    client.init_governance(&client.address, &config);
+}
+
+#[test]
+#[should_panic(expected = "145")]
+fn test_init_rejects_self_signer() {
+    let env = Env::default();
+    let client = uninitialized(&env);
+    client.init(&vec![&env, Address::generate(&env), client.address.clone()], &1);
+}
+
+#[test]
+fn test_rejected_init_leaves_contract_uninitialized() {
+    let env = Env::default();
+    let client = uninitialized(&env);
+    assert!(client.try_init(&vec![&env, client.address.clone()], &1).is_err());
+
+    let admin = Address::generate(&env);
+    client.init_admin(&admin);
+    assert_eq!(client.get_admin(), Some(admin));
+}
+
+#[test]
+#[should_panic(expected = "145")]
+fn test_migrate_to_multisig_rejects_self_signer() {
+    let env = Env::default();
+    let (client, _admin) = setup_admin(&env);
+    client.migrate_to_multisig(&vec![&env, client.address.clone(), Address::generate(&env)], &1, &false);
+}
+
+#[test]
+#[should_panic(expected = "145")]
+fn test_set_relayer_rejects_self() {
+    let env = Env::default();
+    let (client, _admin) = setup_admin(&env);
+    client.set_relayer(&client.address);
+}
+
+#[test]
+fn test_admin_kept_as_signer_is_flagged() {
+    let env = Env::default();
+    let (client, admin) = setup_admin(&env);
+    client.migrate_to_multisig(&vec![&env, admin.clone(), Address::generate(&env)], &2, &true);
+    assert_eq!(overlaps(&env), [(symbol_short!("admin"), symbol_short!("signer"), admin)]);
+}
+
+#[test]
+fn test_relayer_holding_governance_role_is_flagged() {
+    let env = Env::default();
+    let (client, admin) = setup_admin(&env);
+    client.set_relayer(&admin);
+    assert_eq!(overlaps(&env), [(symbol_short!("relayer"), symbol_short!("admin"), admin)]);
+
+    let signer = Address::generate(&env);
+    env.as_contract(&client.address, || MultiSig::init(&env, vec![&env, signer.clone()], 1));
+    client.set_relayer(&signer);
+    assert_eq!(overlaps(&env).last(), Some(&(symbol_short!("relayer"), symbol_short!("signer"), signer)));
+}
+
+#[test]
+fn test_distinct_roles_are_not_flagged() {
+    let env = Env::default();
+    let (client, _admin) = setup_admin(&env);
+    client.set_relayer(&Address::generate(&env));
+    assert!(overlaps(&env).is_empty());
+}
@@ -108,6 +108,8 @@ fn serialization_compatibility_public_types_and_events() {
         caller: caller.clone(),
         timestamp: 10,
         success: true,
+        call_kind: Symbol::new(&env, "direct"),
+        version: 2,
     };
 
     let perf_metric = PerformanceMetric {
@@ -121,6 +123,12 @@ fn serialization_compatibility_public_types_and_events() {
         last_operation: 12,
         total_operations: 34,
         contract_version: SdkString::from_str(&env, "2.0.0"),
+        version_label: None,
+        is_paused: false,
+        unhealthy_reason: None,
+        stale_proposal_id: None,
+        inactive_signers: Vec::new(&env),
+        emergency_paused_until: None,
     };
 
     let analytics = Analytics {
@@ -128,6 +136,7 @@ fn serialization_compatibility_public_types_and_events() {
         unique_users: 20,
         error_count: 3,
         error_rate: 150,
+        upgrade_count: 0,
     };
 
     let snapshot = StateSnapshot {
@@ -0,0 +1,61 @@
+//! # Signer Epoch Tests
+//!
+//! - Swapping a signer bumps `get_signer_epoch` and drops every earlier
+//!   approval from `get_valid_approvals`, while the raw record remains
+//! - `approvals_needed` and execution use the same filtered count
+//! - Multisig approval events carry the signer epoch
+
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events as _, Ledger as _},
+    vec, Address, Env, IntoVal, TryFromVal,
+};
+
+use crate::multisig::MultiSigConfig;
+use crate::testutils::{setup_multisig, upload_dummy_wasm};
+use crate::MultiSig;
+
+#[test]
+fn test_signer_swap_drops_valid_approvals() {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    let (a, b) = (signers.get(0).unwrap(), signers.get(1).unwrap());
+    let proposal_id = client.propose_upgrade(&a, &upload_dummy_wasm(&env, 1), &0);
+    client.approve_upgrade(&proposal_id, &a);
+    client.approve_upgrade(&proposal_id, &b);
+    assert_eq!(client.get_valid_approvals(&proposal_id), vec![&env, a.clone(), b.clone()]);
+    assert_eq!(client.approvals_needed(&proposal_id), 0);
+
+    let mut swapped = signers.clone();
+    swapped.set(2, Address::generate(&env));
+    env.as_contract(&client.address, || {
+        MultiSig::set_config(&env, MultiSigConfig { signers: swapped, threshold: 2 })
+    });
+
+    assert_eq!(client.get_signer_epoch(), 1);
+    assert!(client.get_valid_approvals(&proposal_id).is_empty());
+    assert_eq!(client.approvals_needed(&proposal_id), 2);
+    let raw = env.as_contract(&client.address, || MultiSig::get_proposal_opt(&env, proposal_id).unwrap());
+    assert_eq!(raw.approvals, vec![&env, a, b]);
+
+    env.ledger().set_timestamp(client.get_timelock_delay());
+    assert!(client.try_execute_upgrade(&proposal_id).is_err());
+}
+
+#[test]
+fn test_approval_event_carries_signer_epoch() {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    let signer = signers.get(0).unwrap();
+    let proposal_id = client.propose_upgrade(&signer, &upload_dummy_wasm(&env, 1), &0);
+    client.approve_upgrade(&proposal_id, &signer);
+
+    let topics = (symbol_short!("approved"),).into_val(&env);
+    let (_, _, data) = env.events().all().iter().find(|(_, t, _)| *t == topics).unwrap();
+    let payload = <(u64, Address, u64, u32, u32, bool)>::try_from_val(&env, &data).unwrap();
+    assert_eq!(payload, (proposal_id, signer, client.get_signer_epoch(), 1, 2, false));
+}
@@ -0,0 +1,119 @@
+//! # Signer Onboarding Tests
+//!
+//! - `add_signer` queues an invitation that `accept_signership` turns into
+//!   signer membership
+//! - A pending signer cannot approve and does not count toward the
+//!   threshold
+//! - Invitations lapse after the acceptance window, which multisig
+//!   deployments set with an `UpdateConfig` bundle
+//! - Multisig deployments invite through a `ProposalAction::AddSigner`
+//!   bundle
+
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger as _},
+    vec, Address, Env,
+};
+
+use crate::testutils::{setup_admin_and_multisig, setup_multisig, upload_dummy_wasm, GovernanceDriver};
+use crate::{
+    ConfigUpdate, GrainlifyContractClient, MultiSig, ProposalAction,
+    DEFAULT_SIGNER_ACCEPTANCE_WINDOW,
+};
+
+fn signer_count(env: &Env, client: &GrainlifyContractClient) -> u32 {
+    env.as_contract(&client.address, || MultiSig::get_config_opt(env).unwrap().signers.len())
+}
+
+#[test]
+fn test_accepted_invitation_joins_signer_set() {
+    let env = Env::default();
+    let (client, _) = setup_admin_and_multisig(&env);
+    let newcomer = Address::generate(&env);
+
+    client.add_signer(&newcomer);
+    let pending = client.get_pending_signers();
+    assert_eq!(pending.len(), 1);
+    assert_eq!(pending.get(0).unwrap().address, newcomer);
+    assert_eq!(pending.get(0).unwrap().expires_at, DEFAULT_SIGNER_ACCEPTANCE_WINDOW);
+    assert_eq!(signer_count(&env, &client), 3);
+
+    client.accept_signership(&newcomer);
+    assert!(client.get_pending_signers().is_empty());
+    assert_eq!(signer_count(&env, &client), 4);
+    assert!(env.as_contract(&client.address, || MultiSig::is_signer(&env, &newcomer)));
+}
+
+#[test]
+fn test_pending_signer_cannot_approve_or_raise_threshold() {
+    let env = Env::default();
+    let (client, signers) = setup_admin_and_multisig(&env);
+    let newcomer = Address::generate(&env);
+    client.add_signer(&newcomer);
+
+    let proposal_id = client.propose_upgrade(&signers.get(0).unwrap(), &upload_dummy_wasm(&env, 1), &0);
+    assert!(client.try_approve_upgrade(&proposal_id, &newcomer).is_err());
+    assert!(client.try_set_config(&ConfigUpdate { threshold: Some(4), ..Default::default() }).is_err());
+
+    client.accept_signership(&newcomer);
+    client.approve_upgrade(&proposal_id, &newcomer);
+    client.set_config(&ConfigUpdate { threshold: Some(4), ..Default::default() });
+}
+
+#[test]
+fn test_invitation_expires() {
+    let env = Env::default();
+    let (client, _) = setup_admin_and_multisig(&env);
+    client.set_signer_acceptance_window(&600);
+    let newcomer = Address::generate(&env);
+    client.add_signer(&newcomer);
+
+    env.ledger().set_timestamp(601);
+    assert!(client.get_pending_signers().is_empty());
+    assert!(client.try_accept_signership(&newcomer).is_err());
+    assert_eq!(signer_count(&env, &client), 3);
+
+    client.add_signer(&newcomer);
+    client.accept_signership(&newcomer);
+    assert_eq!(signer_count(&env, &client), 4);
+}
+
+#[test]
+fn test_multisig_sets_acceptance_window_through_config() {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    assert!(client.try_set_signer_acceptance_window(&600).is_err());
+
+    let update = ConfigUpdate { signer_acceptance_window: Some(600), ..Default::default() };
+    GovernanceDriver::apply_actions(&env, &client, &signers, vec![&env, ProposalAction::UpdateConfig(update)]);
+    assert_eq!(client.get_signer_acceptance_window(), 600);
+    assert_eq!(client.get_config().signer_acceptance_window, 600);
+}
+
+#[test]
+fn test_existing_signer_cannot_be_invited() {
+    let env = Env::default();
+    let (client, signers) = setup_admin_and_multisig(&env);
+    assert!(client.try_add_signer(&signers.get(0).unwrap()).is_err());
+    assert!(client.try_accept_signership(&Address::generate(&env)).is_err());
+}
+
+#[test]
+fn test_add_signer_action() {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    let newcomer = Address::generate(&env);
+    let proposal_id =
+        client.propose_actions(&signers.get(0).unwrap(), &vec![&env, ProposalAction::AddSigner(newcomer.clone())], &0);
+    client.approve_actions(&proposal_id, &signers.get(0).unwrap());
+    client.approve_actions(&proposal_id, &signers.get(1).unwrap());
+    env.ledger().set_timestamp(client.get_timelock_delay());
+    client.execute_proposal(&proposal_id);
+
+    assert_eq!(client.get_pending_signers().get(0).unwrap().proposal_id, proposal_id);
+    client.accept_signership(&newcomer);
+    assert_eq!(signer_count(&env, &client), 4);
+}
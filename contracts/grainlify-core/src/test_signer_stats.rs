@@ -0,0 +1,85 @@
+//! # Signer Statistics Tests
+//!
+//! - Proposing, approving, revoking and cancelling update the acting
+//!   signer's counters and last activity, and nobody else's
+//! - `list_signer_stats` covers the current signer set, including idle
+//!   signers
+//! - `health_check` lists signers idle past the inactivity period without
+//!   turning unhealthy
+//! - Multisig governance sets the inactivity period through a config update
+
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{testutils::Address as _, testutils::Ledger as _, vec, Address, Env};
+
+use crate::testutils::{setup_admin_and_multisig, setup_multisig, upload_dummy_wasm, GovernanceDriver};
+use crate::{ConfigUpdate, ProposalAction};
+
+#[test]
+fn test_counters_follow_each_signer() {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    let (alice, bob) = (signers.get(0).unwrap(), signers.get(1).unwrap());
+
+    env.ledger().set_timestamp(10);
+    let first = client.propose_upgrade(&alice, &upload_dummy_wasm(&env, 1), &0);
+    let second = client.propose_upgrade(&alice, &upload_dummy_wasm(&env, 2), &0);
+    client.approve_upgrade(&first, &alice);
+    env.ledger().set_timestamp(20);
+    client.approve_upgrade(&first, &bob);
+    client.revoke_approval(&first, &bob);
+    client.cancel_upgrade(&second, &bob);
+
+    let a = client.get_signer_stats(&alice).unwrap();
+    assert_eq!((a.proposals_created, a.approvals_given, a.rejections_given), (2, 1, 0));
+    assert_eq!(a.last_active_at, 10);
+    let b = client.get_signer_stats(&bob).unwrap();
+    assert_eq!((b.proposals_created, b.approvals_given, b.rejections_given), (0, 1, 2));
+    assert_eq!(b.last_active_at, 20);
+    assert_eq!(b.removed_at, None);
+}
+
+#[test]
+fn test_list_covers_idle_signers() {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    client.propose_upgrade(&signers.get(0).unwrap(), &upload_dummy_wasm(&env, 1), &0);
+
+    let stats = client.list_signer_stats();
+    assert_eq!(stats.len(), 3);
+    let idle = stats.get(2).unwrap();
+    assert_eq!(idle.signer, signers.get(2).unwrap());
+    assert_eq!((idle.proposals_created, idle.last_active_at), (0, 0));
+    assert_eq!(client.get_signer_stats(&Address::generate(&env)), None);
+}
+
+#[test]
+fn test_health_check_reports_inactive_signers() {
+    let env = Env::default();
+    let (client, signers) = setup_admin_and_multisig(&env);
+    assert!(client.health_check().inactive_signers.is_empty());
+
+    client.set_signer_inactivity_period(&1_000);
+    env.ledger().set_timestamp(5_000);
+    client.propose_upgrade(&signers.get(0).unwrap(), &upload_dummy_wasm(&env, 1), &0);
+    env.ledger().set_timestamp(5_500);
+
+    let health = client.health_check();
+    assert_eq!(health.inactive_signers, vec![&env, signers.get(1).unwrap(), signers.get(2).unwrap()]);
+    assert!(health.is_healthy);
+    assert_eq!(client.get_signer_inactivity_period(), 1_000);
+}
+
+#[test]
+fn test_multisig_sets_inactivity_period_through_config() {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    assert!(client.try_set_signer_inactivity_period(&1_000).is_err());
+
+    let update = ConfigUpdate { signer_inactivity_period: Some(1_000), ..Default::default() };
+    GovernanceDriver::apply_actions(&env, &client, &signers, vec![&env, ProposalAction::UpdateConfig(update)]);
+    assert_eq!(client.get_signer_inactivity_period(), 1_000);
+    assert_eq!(client.get_config().signer_inactivity_period, 1_000);
+}
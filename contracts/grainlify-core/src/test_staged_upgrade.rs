@@ -0,0 +1,109 @@
+//! # Two-Phase Admin Upgrade Tests
+//!
+//! - With a staging delay set, `stage_upgrade` records the hash and
+//!   `confirm_upgrade` installs it once the delay has passed
+//! - Confirming a different hash or too early fails; `unstage_upgrade`
+//!   cancels
+//! - The one-shot `upgrade` is rejected while staging is on and works
+//!   again once it is turned off
+//! - Each step publishes an event
+
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Events as _, Ledger as _},
+    Env, IntoVal, Symbol, TryFromVal,
+};
+
+use crate::testutils::{setup_admin, upload_dummy_wasm};
+use crate::{GrainlifyContract, GrainlifyContractClient, StagedUpgradeEvent};
+
+const DELAY: u64 = 3_600;
+
+fn setup(env: &Env) -> GrainlifyContractClient<'_> {
+    let (client, _) = setup_admin(env);
+    client.set_staging_delay(&Some(DELAY));
+    env.ledger().set_timestamp(1_000);
+    client
+}
+
+fn staging_event(env: &Env, step: Symbol) -> Option<StagedUpgradeEvent> {
+    let topics = (symbol_short!("upgrade"), step).into_val(env);
+    env.events()
+        .all()
+        .iter()
+        .find(|(_, t, _)| *t == topics)
+        .map(|(_, _, data)| StagedUpgradeEvent::try_from_val(env, &data).unwrap())
+}
+
+#[test]
+fn test_stage_then_confirm_installs_upgrade() {
+    let env = Env::default();
+    let client = setup(&env);
+    let wasm_hash = upload_dummy_wasm(&env, 1);
+
+    client.stage_upgrade(&wasm_hash);
+    assert_eq!(client.get_staged_upgrade_hash(), Some(wasm_hash.clone()));
+    assert_eq!(client.get_earliest_confirm_time(), Some(1_000 + DELAY));
+    assert_eq!(staging_event(&env, symbol_short!("staged")).unwrap().confirm_after, 1_000 + DELAY);
+
+    env.ledger().set_timestamp(1_000 + DELAY);
+    assert_eq!(client.confirm_upgrade(&wasm_hash).wasm_hash, wasm_hash);
+    assert_eq!(staging_event(&env, symbol_short!("confirmed")).unwrap().wasm_hash, wasm_hash);
+    let staged = env.as_contract(&client.address, || GrainlifyContract::get_staged_upgrade_hash(env.clone()));
+    assert_eq!(staged, None);
+}
+
+#[test]
+#[should_panic(expected = "134")]
+fn test_confirm_with_different_hash_fails() {
+    let env = Env::default();
+    let client = setup(&env);
+    client.stage_upgrade(&upload_dummy_wasm(&env, 1));
+    env.ledger().set_timestamp(1_000 + DELAY);
+    client.confirm_upgrade(&upload_dummy_wasm(&env, 2));
+}
+
+#[test]
+#[should_panic(expected = "135")]
+fn test_confirm_before_delay_fails() {
+    let env = Env::default();
+    let client = setup(&env);
+    let wasm_hash = upload_dummy_wasm(&env, 1);
+    client.stage_upgrade(&wasm_hash);
+    env.ledger().set_timestamp(1_000 + DELAY - 1);
+    client.confirm_upgrade(&wasm_hash);
+}
+
+#[test]
+fn test_unstage_cancels() {
+    let env = Env::default();
+    let client = setup(&env);
+    let wasm_hash = upload_dummy_wasm(&env, 1);
+    client.stage_upgrade(&wasm_hash);
+    assert!(client.try_stage_upgrade(&upload_dummy_wasm(&env, 2)).is_err());
+
+    client.unstage_upgrade();
+    assert!(staging_event(&env, symbol_short!("unstaged")).is_some());
+    assert_eq!(client.get_staged_upgrade_hash(), None);
+    assert_eq!(client.get_earliest_confirm_time(), None);
+
+    env.ledger().set_timestamp(1_000 + DELAY);
+    assert!(client.try_confirm_upgrade(&wasm_hash).is_err());
+    assert!(client.try_unstage_upgrade().is_err());
+}
+
+#[test]
+fn test_one_shot_upgrade_follows_mode() {
+    let env = Env::default();
+    let client = setup(&env);
+    let wasm_hash = upload_dummy_wasm(&env, 1);
+    assert!(client.try_upgrade(&wasm_hash).is_err());
+
+    client.set_staging_delay(&None);
+    assert!(client.try_stage_upgrade(&wasm_hash).is_err());
+    client.upgrade(&wasm_hash);
+}
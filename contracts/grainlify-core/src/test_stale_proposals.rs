@@ -0,0 +1,128 @@
+//! # Stale Proposal Health Tests
+//!
+//! - An open upgrade proposal older than the stale-proposal age makes
+//!   `health_check` unhealthy with `stale_proposal` and the proposal ID
+//! - The flag clears once the proposal is cancelled or executed; expired
+//!   proposals never count
+//! - The age is configurable and 0 turns the check off
+//! - At most `MAX_PENDING_UPGRADES` upgrade proposals are open at once;
+//!   closing one frees its slot
+
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{testutils::Ledger as _, Env, Symbol};
+
+use crate::testutils::{setup_admin_and_multisig, setup_multisig, upload_dummy_wasm};
+use crate::{GrainlifyContract, DEFAULT_STALE_PROPOSAL_AGE, MAX_PENDING_UPGRADES};
+
+#[test]
+fn test_old_pending_proposal_flags_health() {
+    let env = Env::default();
+    let (client, signers) = setup_admin_and_multisig(&env);
+    let proposer = signers.get(0).unwrap();
+    let proposal_id = client.propose_upgrade(&proposer, &upload_dummy_wasm(&env, 1), &0);
+    client.approve_upgrade(&proposal_id, &proposer);
+
+    env.ledger().set_timestamp(DEFAULT_STALE_PROPOSAL_AGE);
+    assert!(client.health_check().is_healthy, "exactly the age is not stale");
+
+    env.ledger().set_timestamp(DEFAULT_STALE_PROPOSAL_AGE + 1);
+    let health = client.health_check();
+    assert!(!health.is_healthy);
+    assert_eq!(health.unhealthy_reason, Some(Symbol::new(&env, "stale_proposal")));
+    assert_eq!(health.stale_proposal_id, Some(proposal_id));
+
+    client.cancel_upgrade(&proposal_id, &proposer);
+    let health = client.health_check();
+    assert!(health.is_healthy);
+    assert_eq!(health.unhealthy_reason, None);
+    assert_eq!(health.stale_proposal_id, None);
+}
+
+#[test]
+fn test_expired_proposal_is_not_stale() {
+    let env = Env::default();
+    let (client, signers) = setup_admin_and_multisig(&env);
+    client.propose_upgrade(&signers.get(0).unwrap(), &upload_dummy_wasm(&env, 1), &100);
+
+    env.ledger().set_timestamp(DEFAULT_STALE_PROPOSAL_AGE + 1);
+    assert_eq!(client.health_check().stale_proposal_id, None);
+}
+
+#[test]
+fn test_executed_proposal_clears_flag() {
+    let env = Env::default();
+    let (client, signers) = setup_admin_and_multisig(&env);
+    let proposal_id = client.propose_upgrade(&signers.get(0).unwrap(), &upload_dummy_wasm(&env, 1), &0);
+    client.approve_upgrade(&proposal_id, &signers.get(0).unwrap());
+    client.approve_upgrade(&proposal_id, &signers.get(1).unwrap());
+
+    env.ledger().set_timestamp(DEFAULT_STALE_PROPOSAL_AGE + 1);
+    client.execute_upgrade_v2(&proposal_id);
+    let health = env.as_contract(&client.address, || GrainlifyContract::health_check(env.clone()));
+    assert_eq!(health.stale_proposal_id, None);
+}
+
+#[test]
+fn test_configurable_age() {
+    let env = Env::default();
+    let (client, signers) = setup_admin_and_multisig(&env);
+    let proposal_id = client.propose_upgrade(&signers.get(0).unwrap(), &upload_dummy_wasm(&env, 1), &0);
+
+    client.set_stale_proposal_age(&60);
+    assert_eq!(client.get_stale_proposal_age(), 60);
+    env.ledger().set_timestamp(61);
+    assert_eq!(client.health_check().stale_proposal_id, Some(proposal_id));
+
+    client.set_stale_proposal_age(&0);
+    assert!(client.health_check().is_healthy);
+}
+
+#[test]
+fn test_reports_oldest_stale_proposal() {
+    let env = Env::default();
+    let (client, signers) = setup_admin_and_multisig(&env);
+    let proposer = signers.get(0).unwrap();
+    let first = client.propose_upgrade(&proposer, &upload_dummy_wasm(&env, 1), &0);
+    env.ledger().set_timestamp(10);
+    client.propose_upgrade(&proposer, &upload_dummy_wasm(&env, 2), &0);
+
+    env.ledger().set_timestamp(DEFAULT_STALE_PROPOSAL_AGE + 20);
+    assert_eq!(client.health_check().stale_proposal_id, Some(first));
+}
+
+#[test]
+#[should_panic(expected = "163")]
+fn test_open_proposals_are_capped() {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    env.budget().reset_unlimited();
+    let proposer = signers.get(0).unwrap();
+    let wasm_hash = upload_dummy_wasm(&env, 1);
+    for _ in 0..MAX_PENDING_UPGRADES {
+        client.propose_upgrade(&proposer, &wasm_hash, &0);
+    }
+    client.propose_upgrade(&proposer, &wasm_hash, &0);
+}
+
+#[test]
+fn test_closed_proposal_frees_its_slot() {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    env.budget().reset_unlimited();
+    let proposer = signers.get(0).unwrap();
+    let wasm_hash = upload_dummy_wasm(&env, 1);
+    let first = client.propose_upgrade(&proposer, &wasm_hash, &100);
+    let second = client.propose_upgrade(&proposer, &wasm_hash, &0);
+    for _ in 2..MAX_PENDING_UPGRADES {
+        client.propose_upgrade(&proposer, &wasm_hash, &0);
+    }
+
+    client.cancel_upgrade(&second, &proposer);
+    client.propose_upgrade(&proposer, &wasm_hash, &0);
+
+    env.ledger().set_timestamp(101);
+    assert!(client.try_propose_upgrade(&proposer, &wasm_hash, &0).is_ok(), "{first} expired");
+}
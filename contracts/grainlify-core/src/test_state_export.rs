@@ -0,0 +1,115 @@
+//! # State Export Tests
+//!
+//! - `export_manifest` lists every section with its page count
+//! - Each section decodes to its struct and matches the direct getters
+//! - `proposals` lists only pending proposals, with their approvals
+//! - `history` pages through the retained histories
+//! - An unknown section fails with `ExportError::UnknownSection`
+
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{xdr::FromXdr, Env, Symbol, Vec};
+
+use crate::export::{ConfigExport, ExportSection, GovernanceExport, HistoryExport, ProposalsExport};
+use crate::paging::{MetricsPage, MAX_PAGE_SIZE};
+use crate::testutils::{setup_admin, upload_dummy_wasm, GovernanceDriver};
+use crate::{monitoring, GrainlifyContractClient};
+
+fn export<T: FromXdr>(env: &Env, client: &GrainlifyContractClient, section: &str, page: u32) -> T {
+    let bytes = client.export_state(&Symbol::new(env, section), &page);
+    T::from_xdr(env, &bytes).ok().unwrap()
+}
+
+fn pages(env: &Env, manifest: &Vec<ExportSection>, section: &str) -> u32 {
+    manifest.iter().find(|s| s.name == Symbol::new(env, section)).unwrap().pages
+}
+
+#[test]
+fn test_governance_and_config_match_getters() {
+    let env = Env::default();
+    let (client, signers) = GovernanceDriver::bootstrap_multisig(&env, 3, 2);
+
+    let governance: GovernanceExport = export(&env, &client, "governance", 0);
+    assert_eq!(governance.admin, client.get_admin());
+    assert_eq!(governance.signers, signers);
+    assert_eq!(governance.threshold, 2);
+    assert_eq!(governance.signer_epoch, client.get_signer_epoch());
+    assert_eq!(governance.version, client.get_version());
+    assert_eq!(governance.previous_version, client.get_previous_version());
+    assert_eq!(governance.schema_version, client.get_schema_version());
+    assert_eq!(governance.paused, client.is_paused());
+    assert!(!governance.finalized);
+
+    let config: ConfigExport = export(&env, &client, "config", 0);
+    assert_eq!(config.config, client.get_config());
+    assert_eq!(config.disabled_functions, client.list_disabled_functions());
+    assert_eq!(config.guardian, client.get_guardian());
+    assert_eq!(config.relayer, client.get_relayer());
+}
+
+#[test]
+fn test_proposals_lists_pending_with_approvals() {
+    let env = Env::default();
+    let (client, signers) = GovernanceDriver::bootstrap_multisig(&env, 3, 2);
+    let cancelled = GovernanceDriver::propose(&env, &client, &signers, &upload_dummy_wasm(&env, 1));
+    client.cancel_upgrade(&cancelled, &signers.get(0).unwrap());
+    let pending = GovernanceDriver::propose(&env, &client, &signers, &upload_dummy_wasm(&env, 2));
+    client.approve_upgrade(&pending, &signers.get(1).unwrap());
+
+    let manifest = client.export_manifest();
+    assert_eq!(pages(&env, &manifest, "proposals"), 1);
+    let proposals: ProposalsExport = export(&env, &client, "proposals", 0);
+    assert!(!proposals.has_more);
+    assert_eq!(proposals.items.len(), 1);
+    let item = proposals.items.get(0).unwrap();
+    assert_eq!(Some(item.record), client.get_upgrade_proposal(&pending));
+    assert_eq!(item.approvals, client.get_approvers(&pending));
+
+    let past_end: ProposalsExport = export(&env, &client, "proposals", 1);
+    assert!(past_end.items.is_empty());
+}
+
+#[test]
+fn test_history_pages_match_getters() {
+    let env = Env::default();
+    let (client, _admin) = setup_admin(&env);
+    for version in 3..3 + MAX_PAGE_SIZE + 5 {
+        client.set_version(&version);
+    }
+    let manifest = client.export_manifest();
+    assert_eq!(pages(&env, &manifest, "governance"), 1);
+    assert_eq!(pages(&env, &manifest, "config"), 1);
+    assert_eq!(pages(&env, &manifest, "history"), 2);
+
+    let first: HistoryExport = export(&env, &client, "history", 0);
+    assert!(first.has_more);
+    assert_eq!(first.versions, client.get_version_history(&0, &MAX_PAGE_SIZE).items);
+    let second: HistoryExport = export(&env, &client, "history", 1);
+    assert!(!second.has_more);
+    assert_eq!(second.versions, client.get_version_history(&(MAX_PAGE_SIZE as u64), &MAX_PAGE_SIZE).items);
+}
+
+#[test]
+fn test_metrics_section_matches_export_metrics() {
+    let env = Env::default();
+    let (client, _admin) = setup_admin(&env);
+    client.set_version(&3);
+    let manifest = client.export_manifest();
+    if !monitoring::ENABLED {
+        assert!(manifest.iter().all(|s| s.name != Symbol::new(&env, "metrics")));
+        return;
+    }
+    assert_eq!(pages(&env, &manifest, "metrics"), 1);
+    let metrics: MetricsPage = export(&env, &client, "metrics", 0);
+    assert_eq!(metrics, client.export_metrics(&0, &MAX_PAGE_SIZE));
+}
+
+#[test]
+#[should_panic(expected = "160")]
+fn test_unknown_section_rejected() {
+    let env = Env::default();
+    let (client, _admin) = setup_admin(&env);
+    client.export_state(&Symbol::new(&env, "signers"), &0);
+}
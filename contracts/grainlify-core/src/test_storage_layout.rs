@@ -1,33 +1,25 @@
 #[cfg(test)]
 mod test {
-    use crate::{DataKey, GrainlifyContract, GrainlifyContractClient, LIVENESS_SCHEMA_VERSION, STORAGE_SCHEMA_VERSION};
-    use soroban_sdk::{testutils::Address as _, Address, Env};
-
-    fn setup_test(env: &Env) -> (GrainlifyContractClient, Address) {
-        env.mock_all_auths();
-        let contract_id = env.register_contract(None, GrainlifyContract);
-        let client = GrainlifyContractClient::new(env, &contract_id);
-        let admin = Address::generate(env);
-        client.init_admin(&admin);
-        (client, admin)
-    }
+    use crate::testutils::setup_admin;
+    use crate::{DataKey, LIVENESS_SCHEMA_VERSION, STORAGE_SCHEMA_VERSION};
+    use soroban_sdk::Env;
 
     #[test]
     fn test_storage_schema_version_constant() {
-        assert_eq!(STORAGE_SCHEMA_VERSION, 1);
+        assert_eq!(STORAGE_SCHEMA_VERSION, 2);
     }
 
     #[test]
     fn test_verify_storage_layout_after_init() {
         let env = Env::default();
-        let (client, _admin) = setup_test(&env);
+        let (client, _admin) = setup_admin(&env);
         assert!(client.verify_storage_layout());
     }
 
     #[test]
     fn test_all_instance_keys_readable_after_init() {
         let env = Env::default();
-        let (client, _admin) = setup_test(&env);
+        let (client, _admin) = setup_admin(&env);
 
         env.as_contract(&client.address, || {
             assert!(env.storage().instance().has(&DataKey::Admin));
@@ -44,7 +36,7 @@ mod test {
     #[test]
     fn test_liveness_watchdog_healthy_after_init() {
         let env = Env::default();
-        let (client, _admin) = setup_test(&env);
+        let (client, _admin) = setup_admin(&env);
 
         let status = client.liveness_watchdog();
         assert!(!status.paused, "should not be paused after init");
@@ -57,7 +49,7 @@ mod test {
     #[test]
     fn test_liveness_watchdog_reflects_read_only() {
         let env = Env::default();
-        let (client, _admin) = setup_test(&env);
+        let (client, _admin) = setup_admin(&env);
 
         client.set_read_only_mode(&true);
         let status = client.liveness_watchdog();
@@ -69,7 +61,7 @@ mod test {
     #[test]
     fn test_liveness_watchdog_read_only_restored() {
         let env = Env::default();
-        let (client, _admin) = setup_test(&env);
+        let (client, _admin) = setup_admin(&env);
 
         client.set_read_only_mode(&true);
         client.set_read_only_mode(&false);
@@ -83,7 +75,7 @@ mod test {
     #[test]
     fn test_liveness_watchdog_version_matches_get_version() {
         let env = Env::default();
-        let (client, _admin) = setup_test(&env);
+        let (client, _admin) = setup_admin(&env);
 
         let status = client.liveness_watchdog();
         assert_eq!(status.version, client.get_version());
@@ -93,7 +85,7 @@ mod test {
     #[test]
     fn test_get_liveness_schema_version_after_init() {
         let env = Env::default();
-        let (client, _admin) = setup_test(&env);
+        let (client, _admin) = setup_admin(&env);
 
         assert_eq!(client.get_liveness_schema_version(), LIVENESS_SCHEMA_VERSION);
     }
@@ -102,7 +94,7 @@ mod test {
     #[test]
     fn test_liveness_schema_version_key_written_at_init() {
         let env = Env::default();
-        let (client, _admin) = setup_test(&env);
+        let (client, _admin) = setup_admin(&env);
 
         env.as_contract(&client.address, || {
             assert!(
@@ -116,7 +108,7 @@ mod test {
     #[test]
     fn test_liveness_watchdog_last_ping_ts_defaults_to_zero() {
         let env = Env::default();
-        let (client, _admin) = setup_test(&env);
+        let (client, _admin) = setup_admin(&env);
 
         let status = client.liveness_watchdog();
         assert_eq!(status.last_ping_ts, 0, "last_ping_ts must be 0 before first ping");
@@ -126,7 +118,7 @@ mod test {
     #[test]
     fn test_liveness_watchdog_paused_and_read_only_independent() {
         let env = Env::default();
-        let (client, _admin) = setup_test(&env);
+        let (client, _admin) = setup_admin(&env);
 
         let s = client.liveness_watchdog();
         assert!(!s.paused);
@@ -0,0 +1,149 @@
+//! # String Limit Tests
+//!
+//! - Every string-accepting entrypoint rejects oversized input with the
+//!   field's own error code and writes nothing
+//! - The metadata fields are also bounded together by `meta_tot`
+//! - Governance can tune limits only within each field's floor and ceiling;
+//!   multisig deployments tune them with an action bundle
+
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{testutils::Address as _, vec, Address, Env, String, Symbol};
+
+use crate::limits::{self, DESCRIPTION_CEILING, MAX_DESCRIPTION_LEN, MAX_LABEL_LEN, MAX_URL_LEN};
+use crate::testutils::{setup_admin, setup_multisig, upload_dummy_wasm, GovernanceDriver};
+use crate::{ContractKind, GrainlifyContract, GrainlifyContractClient, ProposalAction};
+
+fn repeated(env: &Env, len: u32) -> String {
+    String::from_str(env, &"x".repeat(len as usize))
+}
+
+#[test]
+#[should_panic(expected = "139")]
+fn test_oversized_metadata_description() {
+    let env = Env::default();
+    let (client, _admin) = setup_admin(&env);
+    let empty = repeated(&env, 0);
+    client.set_metadata(&empty, &repeated(&env, MAX_DESCRIPTION_LEN + 1), &empty, &empty);
+}
+
+#[test]
+#[should_panic(expected = "140")]
+fn test_oversized_metadata_url() {
+    let env = Env::default();
+    let (client, _admin) = setup_admin(&env);
+    let url = Symbol::new(&env, "url");
+    client.set_metadata_field(&url, &repeated(&env, MAX_URL_LEN + 1));
+}
+
+#[test]
+#[should_panic(expected = "119")]
+fn test_metadata_total_limit() {
+    let env = Env::default();
+    let (client, _admin) = setup_admin(&env);
+    client.set_string_limit(&limits::DESCRIPTION, &DESCRIPTION_CEILING);
+    let empty = repeated(&env, 0);
+    // Each field fits on its own; together they exceed 1 KiB.
+    client.set_metadata(
+        &repeated(&env, 64),
+        &repeated(&env, 900),
+        &repeated(&env, 128),
+        &empty,
+    );
+}
+
+#[test]
+#[should_panic(expected = "139")]
+fn test_oversized_upgrade_description() {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    client.propose_upgrade_with_description(
+        &signers.get(0).unwrap(),
+        &upload_dummy_wasm(&env, 1),
+        &repeated(&env, MAX_DESCRIPTION_LEN + 1),
+        &0,
+    );
+}
+
+#[test]
+#[should_panic(expected = "141")]
+fn test_oversized_deployed_contract_name() {
+    let env = Env::default();
+    let (client, _admin) = setup_admin(&env);
+    client.register_deployed_contract(
+        &Address::generate(&env),
+        &repeated(&env, MAX_LABEL_LEN + 1),
+        &ContractKind::Other,
+        &1,
+    );
+}
+
+#[test]
+fn test_oversized_network_ids() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = GrainlifyContractClient::new(&env, &env.register_contract(None, GrainlifyContract));
+    let admin = Address::generate(&env);
+    let ok = repeated(&env, MAX_LABEL_LEN);
+    let long = repeated(&env, MAX_LABEL_LEN + 1);
+
+    assert!(client.try_init_with_network(&admin, &long, &ok).is_err());
+    assert!(client.try_init_with_network(&admin, &ok, &long).is_err());
+    assert_eq!(client.get_chain_id(), None);
+
+    client.init_with_network(&admin, &ok, &ok);
+    assert_eq!(client.get_chain_id(), Some(ok));
+}
+
+#[test]
+fn test_tuned_limit_applies() {
+    let env = Env::default();
+    let (client, _admin) = setup_admin(&env);
+    let url = Symbol::new(&env, "url");
+    assert_eq!(client.get_string_limit(&limits::URL), MAX_URL_LEN);
+
+    client.set_string_limit(&limits::URL, &64);
+    assert_eq!(client.get_string_limit(&limits::URL), 64);
+    assert!(client.try_set_metadata_field(&url, &repeated(&env, 65)).is_err());
+
+    client.set_string_limit(&limits::URL, &256);
+    client.set_metadata_field(&url, &repeated(&env, 200));
+    assert_eq!(client.get_metadata().url.len(), 200);
+}
+
+#[test]
+fn test_multisig_tunes_limit_through_actions() {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    assert!(client.try_set_string_limit(&limits::URL, &64).is_err());
+
+    let actions = vec![&env, ProposalAction::SetStringLimit(limits::URL, 64)];
+    GovernanceDriver::apply_actions(&env, &client, &signers, actions);
+    assert_eq!(client.get_string_limit(&limits::URL), 64);
+}
+
+#[test]
+#[should_panic(expected = "142")]
+fn test_limit_below_floor_rejected() {
+    let env = Env::default();
+    let (client, _admin) = setup_admin(&env);
+    client.set_string_limit(&limits::LABEL, &7);
+}
+
+#[test]
+#[should_panic(expected = "142")]
+fn test_limit_above_ceiling_rejected() {
+    let env = Env::default();
+    let (client, _admin) = setup_admin(&env);
+    client.set_string_limit(&limits::DESCRIPTION, &(DESCRIPTION_CEILING + 1));
+}
+
+#[test]
+#[should_panic(expected = "142")]
+fn test_unknown_field_rejected() {
+    let env = Env::default();
+    let (client, _admin) = setup_admin(&env);
+    client.get_string_limit(&Symbol::new(&env, "title"));
+}
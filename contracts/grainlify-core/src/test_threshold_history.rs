@@ -0,0 +1,84 @@
+//! # Threshold History Tests
+//!
+//! - Initialization records the first threshold history entry
+//! - Each `SetThreshold` action appends an entry naming its proposal
+//! - Proposals are stamped with the threshold in force at creation
+//! - `get_threshold_history` pages by start index and limit
+
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{testutils::Ledger as _, vec, Address, Env, Vec};
+
+use crate::testutils::{setup_multisig, upload_dummy_wasm};
+use crate::{GrainlifyContractClient, ProposalAction, ThresholdChange};
+
+fn change_threshold(env: &Env, client: &GrainlifyContractClient, signers: &Vec<Address>, threshold: u32) -> u64 {
+    let proposal_id =
+        client.propose_actions(&signers.get(0).unwrap(), &vec![env, ProposalAction::SetThreshold(threshold)], &0);
+    for signer in signers.iter() {
+        client.approve_actions(&proposal_id, &signer);
+    }
+    env.ledger().set_timestamp(env.ledger().timestamp() + client.get_timelock_delay());
+    client.execute_proposal(&proposal_id);
+    proposal_id
+}
+
+#[test]
+fn test_threshold_changes_are_recorded() {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    let proposer = signers.get(0).unwrap();
+
+    let before = client.propose_upgrade(&proposer, &upload_dummy_wasm(&env, 1), &0);
+    let first = change_threshold(&env, &client, &signers, 3);
+    let first_at = env.ledger().timestamp();
+    let during = client.propose_upgrade(&proposer, &upload_dummy_wasm(&env, 2), &0);
+    let second = change_threshold(&env, &client, &signers, 1);
+    let second_at = env.ledger().timestamp();
+    let after = client.propose_upgrade(&proposer, &upload_dummy_wasm(&env, 3), &0);
+
+    let history = client.get_threshold_history(&0, &10).items;
+    assert_eq!(
+        history,
+        vec![
+            &env,
+            ThresholdChange { threshold: 2, signer_count: 3, changed_at: 0, proposal_id: None },
+            ThresholdChange { threshold: 3, signer_count: 3, changed_at: first_at, proposal_id: Some(first) },
+            ThresholdChange { threshold: 1, signer_count: 3, changed_at: second_at, proposal_id: Some(second) },
+        ]
+    );
+
+    assert_eq!(client.get_upgrade_proposal(&before).unwrap().threshold, 2);
+    assert_eq!(client.get_upgrade_proposal(&during).unwrap().threshold, 3);
+    assert_eq!(client.get_upgrade_proposal(&after).unwrap().threshold, 1);
+}
+
+#[test]
+fn test_history_pagination() {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    change_threshold(&env, &client, &signers, 3);
+    change_threshold(&env, &client, &signers, 1);
+
+    let page = client.get_threshold_history(&1, &1).items;
+    assert_eq!(page.len(), 1);
+    assert_eq!(page.get(0).unwrap().threshold, 3);
+    assert_eq!(client.get_threshold_history(&2, &10).items.len(), 1);
+    assert!(client.get_threshold_history(&3, &10).items.is_empty());
+}
+
+#[test]
+fn test_invalid_threshold_reverts_without_history() {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    let proposal_id =
+        client.propose_actions(&signers.get(0).unwrap(), &vec![&env, ProposalAction::SetThreshold(4)], &0);
+    client.approve_actions(&proposal_id, &signers.get(0).unwrap());
+    client.approve_actions(&proposal_id, &signers.get(1).unwrap());
+    env.ledger().set_timestamp(client.get_timelock_delay());
+
+    assert!(client.try_execute_proposal(&proposal_id).is_err());
+    assert_eq!(client.get_threshold_history(&0, &10).items.len(), 1);
+}
@@ -32,10 +32,11 @@ extern crate std;
 
 use soroban_sdk::{
     testutils::{Address as _, Ledger},
-    Address, BytesN, Env, vec,
+    vec, Address, BytesN, Env,
 };
 
-use crate::{GrainlifyContract, GrainlifyContractClient, DataKey};
+use crate::testutils::setup_admin;
+use crate::{DataKey, GrainlifyContract, GrainlifyContractClient};
 
 // ── constants (mirror lib.rs) ─────────────────────────────────────────────
 const MIN_TIMELOCK: u64 = 3_600;       // 1 hour
@@ -44,15 +45,6 @@ const DEFAULT_TIMELOCK: u64 = 86_400;  // 24 hours
 
 // ── helpers ───────────────────────────────────────────────────────────────
 
-fn setup(env: &Env) -> (GrainlifyContractClient<'_>, Address) {
-    let id = env.register_contract(None, GrainlifyContract);
-    let client = GrainlifyContractClient::new(env, &id);
-    let admin = Address::generate(env);
-    env.mock_all_auths();
-    client.init_admin(&admin);
-    (client, admin)
-}
-
 fn setup_multisig_with_timelock(env: &Env) -> (GrainlifyContractClient<'_>, Address) {
     let id = env.register_contract(None, GrainlifyContract);
     let client = GrainlifyContractClient::new(env, &id);
@@ -87,7 +79,7 @@ fn propose_and_approve(
 #[test]
 fn test_default_timelock_is_24h() {
     let env = Env::default();
-    let (client, _) = setup(&env);
+    let (client, _) = setup_admin(&env);
     assert_eq!(client.get_timelock_delay(), DEFAULT_TIMELOCK,
         "default timelock must be 86 400 s (24 h)");
 }
@@ -99,7 +91,7 @@ fn test_default_timelock_is_24h() {
 #[test]
 fn test_set_timelock_exactly_1h_succeeds() {
     let env = Env::default();
-    let (client, _) = setup(&env);
+    let (client, _) = setup_admin(&env);
     client.set_timelock_delay(&MIN_TIMELOCK);
     assert_eq!(client.get_timelock_delay(), MIN_TIMELOCK,
         "exactly 1 h must be accepted");
@@ -109,7 +101,7 @@ fn test_set_timelock_exactly_1h_succeeds() {
 #[should_panic(expected = "Timelock delay must be at least 1 hour")]
 fn test_set_timelock_59min59s_panics() {
     let env = Env::default();
-    let (client, _) = setup(&env);
+    let (client, _) = setup_admin(&env);
     client.set_timelock_delay(&(MIN_TIMELOCK - 1)); // 3 599 s
 }
 
@@ -117,7 +109,7 @@ fn test_set_timelock_59min59s_panics() {
 #[should_panic(expected = "Timelock delay must be at least 1 hour")]
 fn test_set_timelock_zero_panics() {
     let env = Env::default();
-    let (client, _) = setup(&env);
+    let (client, _) = setup_admin(&env);
     client.set_timelock_delay(&0u64);
 }
 
@@ -125,14 +117,14 @@ fn test_set_timelock_zero_panics() {
 #[should_panic(expected = "Timelock delay must be at least 1 hour")]
 fn test_set_timelock_1s_panics() {
     let env = Env::default();
-    let (client, _) = setup(&env);
+    let (client, _) = setup_admin(&env);
     client.set_timelock_delay(&1u64);
 }
 
 #[test]
 fn test_set_timelock_1h_plus_1s_succeeds() {
     let env = Env::default();
-    let (client, _) = setup(&env);
+    let (client, _) = setup_admin(&env);
     client.set_timelock_delay(&(MIN_TIMELOCK + 1)); // 3 601 s
     assert_eq!(client.get_timelock_delay(), MIN_TIMELOCK + 1);
 }
@@ -144,7 +136,7 @@ fn test_set_timelock_1h_plus_1s_succeeds() {
 #[test]
 fn test_set_timelock_exactly_30d_succeeds() {
     let env = Env::default();
-    let (client, _) = setup(&env);
+    let (client, _) = setup_admin(&env);
     client.set_timelock_delay(&MAX_TIMELOCK);
     assert_eq!(client.get_timelock_delay(), MAX_TIMELOCK,
         "exactly 30 d must be accepted");
@@ -154,7 +146,7 @@ fn test_set_timelock_exactly_30d_succeeds() {
 #[should_panic(expected = "Timelock delay cannot exceed 30 days")]
 fn test_set_timelock_30d_plus_1s_panics() {
     let env = Env::default();
-    let (client, _) = setup(&env);
+    let (client, _) = setup_admin(&env);
     client.set_timelock_delay(&(MAX_TIMELOCK + 1)); // 2 592 001 s
 }
 
@@ -162,14 +154,14 @@ fn test_set_timelock_30d_plus_1s_panics() {
 #[should_panic(expected = "Timelock delay cannot exceed 30 days")]
 fn test_set_timelock_u64_max_panics() {
     let env = Env::default();
-    let (client, _) = setup(&env);
+    let (client, _) = setup_admin(&env);
     client.set_timelock_delay(&u64::MAX);
 }
 
 #[test]
 fn test_set_timelock_30d_minus_1s_succeeds() {
     let env = Env::default();
-    let (client, _) = setup(&env);
+    let (client, _) = setup_admin(&env);
     client.set_timelock_delay(&(MAX_TIMELOCK - 1)); // 2 591 999 s
     assert_eq!(client.get_timelock_delay(), MAX_TIMELOCK - 1);
 }
@@ -181,7 +173,7 @@ fn test_set_timelock_30d_minus_1s_succeeds() {
 #[test]
 fn test_set_timelock_2h_succeeds() {
     let env = Env::default();
-    let (client, _) = setup(&env);
+    let (client, _) = setup_admin(&env);
     client.set_timelock_delay(&7_200u64);
     assert_eq!(client.get_timelock_delay(), 7_200);
 }
@@ -189,7 +181,7 @@ fn test_set_timelock_2h_succeeds() {
 #[test]
 fn test_set_timelock_7d_succeeds() {
     let env = Env::default();
-    let (client, _) = setup(&env);
+    let (client, _) = setup_admin(&env);
     client.set_timelock_delay(&604_800u64); // 7 days
     assert_eq!(client.get_timelock_delay(), 604_800);
 }
@@ -197,7 +189,7 @@ fn test_set_timelock_7d_succeeds() {
 #[test]
 fn test_set_timelock_14d_succeeds() {
     let env = Env::default();
-    let (client, _) = setup(&env);
+    let (client, _) = setup_admin(&env);
     client.set_timelock_delay(&1_209_600u64); // 14 days
     assert_eq!(client.get_timelock_delay(), 1_209_600);
 }
@@ -350,7 +342,7 @@ fn test_updated_delay_applies_to_new_proposals() {
 #[test]
 fn test_timelock_status_returns_none_before_proposal() {
     let env = Env::default();
-    let (client, _) = setup(&env);
+    let (client, _) = setup_admin(&env);
     // No proposal exists — status must be None
     assert!(client.get_timelock_status(&999u64).is_none());
 }
@@ -363,7 +355,7 @@ fn test_timelock_status_returns_none_before_proposal() {
 #[should_panic(expected = "Timelock delay must be at least 1 hour")]
 fn test_security_cannot_bypass_minimum_by_1s() {
     let env = Env::default();
-    let (client, _) = setup(&env);
+    let (client, _) = setup_admin(&env);
     // 3 599 = 1 h - 1 s — must be rejected
     client.set_timelock_delay(&(MIN_TIMELOCK - 1));
 }
@@ -372,7 +364,7 @@ fn test_security_cannot_bypass_minimum_by_1s() {
 #[should_panic(expected = "Timelock delay cannot exceed 30 days")]
 fn test_security_cannot_set_delay_above_maximum_by_1s() {
     let env = Env::default();
-    let (client, _) = setup(&env);
+    let (client, _) = setup_admin(&env);
     // 2 592 001 = 30 d + 1 s — must be rejected
     client.set_timelock_delay(&(MAX_TIMELOCK + 1));
 }
@@ -380,7 +372,7 @@ fn test_security_cannot_set_delay_above_maximum_by_1s() {
 #[test]
 fn test_security_delay_persists_across_reads() {
     let env = Env::default();
-    let (client, _) = setup(&env);
+    let (client, _) = setup_admin(&env);
 
     client.set_timelock_delay(&MIN_TIMELOCK);
     // Read multiple times — must be stable
@@ -17,6 +17,7 @@ use soroban_sdk::{
     Address, BytesN, Env, Vec as SVec,
 };
 
+use crate::testutils::{self, setup_admin};
 use crate::{GrainlifyContract, GrainlifyContractClient};
 
 // ── shared helpers ────────────────────────────────────────────────────────────
@@ -33,28 +34,11 @@ fn migration_hash(env: &Env, seed: u8) -> BytesN<32> {
     BytesN::from_array(env, &[seed; 32])
 }
 
-/// Register + init with 2-of-3 multisig; returns (client, [s1, s2, s3]).
+/// 2-of-3 multisig via `testutils::setup_multisig`; returns (client, [s1, s2, s3]).
 fn setup_multisig(env: &Env) -> (GrainlifyContractClient, [Address; 3]) {
-    let id = env.register_contract(None, GrainlifyContract);
-    let client = GrainlifyContractClient::new(env, &id);
-    let s1 = Address::generate(env);
-    let s2 = Address::generate(env);
-    let s3 = Address::generate(env);
-    let mut signers = SVec::new(env);
-    signers.push_back(s1.clone());
-    signers.push_back(s2.clone());
-    signers.push_back(s3.clone());
-    client.init(&signers, &2);
-    (client, [s1, s2, s3])
-}
-
-/// Register + init with single admin; returns (client, admin).
-fn setup_admin(env: &Env) -> (GrainlifyContractClient, Address) {
-    let id = env.register_contract(None, GrainlifyContract);
-    let client = GrainlifyContractClient::new(env, &id);
-    let admin = Address::generate(env);
-    client.init_admin(&admin);
-    (client, admin)
+    let (client, signers) = testutils::setup_multisig(env, 3, 2);
+    let signer = |i| signers.get(i).unwrap();
+    (client, [signer(0), signer(1), signer(2)])
 }
 
 // ═══════════════════════════════════════════════════════════════════════════════
@@ -0,0 +1,379 @@
+//! # Token-Holder Governance Tests
+//!
+//! Uses a checkpointing test token that implements
+//! `SnapshotTokenInterface`; the SDK's Stellar Asset Contract does not, and
+//! is rejected. Covers:
+//! - `propose_upgrade` opens a voting window when token voting is configured
+//! - Token proposals take IDs from the multisig counter
+//! - Voting power is the balance at the proposal snapshot, not the current
+//!   balance, and transfers in the proposal's own ledger do not count
+//! - Double votes, votes after the window, and zero-power votes are rejected
+//! - `execute_upgrade` enforces the window, quorum, majority threshold and
+//!   the timelock, and records the execution like a multisig upgrade
+//! - Quorum and threshold are fixed when the proposal opens
+//! - Token and multisig upgrades share the execution order
+//! - Multisig deployments switch to token voting with an action bundle
+
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger as _},
+    vec, Address, BytesN, Env,
+};
+
+use crate::testutils::{setup_admin_and_multisig, setup_multisig, upload_dummy_wasm, GovernanceDriver};
+use crate::{ExecutionCheck, GrainlifyContract, GrainlifyContractClient, ProposalAction, TokenVotingConfig};
+
+use checkpoint_token::{CheckpointToken, CheckpointTokenClient};
+
+pub(crate) mod checkpoint_token {
+    use soroban_sdk::{contract, contractimpl, Address, Env, Vec};
+
+    /// Token keeping `(ledger, balance)` checkpoints per holder and for the
+    /// total supply (`None`), as `SnapshotTokenInterface` requires.
+    #[contract]
+    pub struct CheckpointToken;
+
+    #[contractimpl]
+    impl CheckpointToken {
+        pub fn mint(env: Env, to: Address, amount: i128) {
+            adjust(&env, Some(to), amount);
+            adjust(&env, None, amount);
+        }
+
+        pub fn transfer(env: Env, from: Address, to: Address, amount: i128) {
+            from.require_auth();
+            if balance_at(&env, Some(from.clone()), u32::MAX) < amount {
+                panic!("insufficient balance");
+            }
+            adjust(&env, Some(from), -amount);
+            adjust(&env, Some(to), amount);
+        }
+
+        pub fn balance_at(env: Env, id: Address, ledger: u32) -> i128 {
+            balance_at(&env, Some(id), ledger)
+        }
+
+        pub fn total_supply_at(env: Env, ledger: u32) -> i128 {
+            balance_at(&env, None, ledger)
+        }
+    }
+
+    fn history(env: &Env, holder: &Option<Address>) -> Vec<(u32, i128)> {
+        env.storage().persistent().get(holder).unwrap_or(Vec::new(env))
+    }
+
+    fn balance_at(env: &Env, holder: Option<Address>, ledger: u32) -> i128 {
+        history(env, &holder)
+            .iter()
+            .filter(|(at, _)| *at < ledger)
+            .last()
+            .map_or(0, |(_, balance)| balance)
+    }
+
+    fn adjust(env: &Env, holder: Option<Address>, delta: i128) {
+        let mut history = history(env, &holder);
+        let ledger = env.ledger().sequence();
+        let balance = history.last().map_or(0, |(_, balance)| balance) + delta;
+        if history.last().is_some_and(|(at, _)| at == ledger) {
+            history.pop_back();
+        }
+        history.push_back((ledger, balance));
+        env.storage().persistent().set(&holder, &history);
+    }
+}
+
+const VOTING_PERIOD: u64 = 3_600;
+
+struct Setup<'a> {
+    env: Env,
+    client: GrainlifyContractClient<'a>,
+    token: CheckpointTokenClient<'a>,
+    holders: [Address; 3],
+}
+
+/// Registers a checkpoint token with `holders` minted 50 / 30 / 20 in
+/// ledger 1, leaving the ledger at 2.
+pub(crate) fn mint_holders<'a>(env: &Env, holders: &[Address; 3]) -> CheckpointTokenClient<'a> {
+    let token = CheckpointTokenClient::new(env, &env.register_contract(None, CheckpointToken));
+    env.ledger().set_sequence_number(1);
+    for (holder, amount) in holders.iter().zip([50i128, 30, 20]) {
+        token.mint(holder, &amount);
+    }
+    env.ledger().set_sequence_number(2);
+    token
+}
+
+fn config_with_token(token: &Address) -> TokenVotingConfig {
+    TokenVotingConfig { token: token.clone(), voting_period: VOTING_PERIOD, quorum_bps: 4_000, threshold_bps: 5_000 }
+}
+
+/// Admin-initialized contract in token voting mode (quorum 40%, majority
+/// 50%) with three holders of 50 / 30 / 20 tokens.
+fn setup<'a>() -> Setup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+    let id = env.register_contract(None, GrainlifyContract);
+    let client = GrainlifyContractClient::new(&env, &id);
+    client.init_admin(&Address::generate(&env));
+
+    let holders = [Address::generate(&env), Address::generate(&env), Address::generate(&env)];
+    let token = mint_holders(&env, &holders);
+    client.set_token_voting_config(&Some(config_with_token(&token.address)));
+
+    Setup { env, client, token, holders }
+}
+
+/// Moves past the voting window and the timelock that follows it.
+fn close_window(s: &Setup) {
+    let delay = s.client.get_timelock_delay();
+    s.env.ledger().set_timestamp(s.env.ledger().timestamp() + VOTING_PERIOD + delay);
+}
+
+#[test]
+fn test_proposal_snapshots_supply_and_window() {
+    let s = setup();
+    s.env.ledger().set_timestamp(1_000);
+    let id = s.client.propose_upgrade(&s.holders[0], &upload_dummy_wasm(&s.env, 1), &0u64);
+
+    let proposal = s.client.get_token_proposal(&id).unwrap();
+    assert_eq!(proposal.total_supply, 100);
+    assert_eq!(proposal.snapshot_ledger, 2);
+    assert_eq!(proposal.token, s.token.address);
+    assert_eq!(proposal.voting_ends_at, 1_000 + VOTING_PERIOD);
+    assert_eq!(s.client.get_proposal_timings(&id).unwrap().created_at, 1_000);
+}
+
+#[test]
+fn test_passing_vote_executes_upgrade() {
+    let s = setup();
+    let wasm = upload_dummy_wasm(&s.env, 1);
+    let id = s.client.propose_upgrade(&s.holders[0], &wasm, &0u64);
+
+    s.client.vote(&id, &s.holders[0], &true);
+    s.client.vote(&id, &s.holders[1], &false);
+    assert!(s.client.has_voted(&id, &s.holders[0]));
+
+    close_window(&s);
+    s.client.execute_upgrade(&id);
+    // The contract now runs the uploaded module; read its state directly.
+    s.env.as_contract(&s.client.address, || {
+        assert!(crate::TokenVoting::get_proposal(&s.env, id).unwrap().executed);
+        let archived = GrainlifyContract::get_archived_upgrade(s.env.clone(), 0).unwrap();
+        assert_eq!((archived.proposal_id, archived.wasm_hash), (id, wasm));
+        let timings = GrainlifyContract::get_proposal_timings(s.env.clone(), id).unwrap();
+        assert_eq!(timings.executed_at, Some(s.env.ledger().timestamp()));
+    });
+}
+
+#[test]
+#[should_panic(expected = "Voting period still open")]
+fn test_execute_before_window_closes_fails() {
+    let s = setup();
+    let id = s.client.propose_upgrade(&s.holders[0], &upload_dummy_wasm(&s.env, 1), &0u64);
+    s.client.vote(&id, &s.holders[0], &true);
+    s.client.execute_upgrade(&id);
+}
+
+#[test]
+#[should_panic(expected = "Timelock delay not met")]
+fn test_execute_waits_for_timelock_after_window() {
+    let s = setup();
+    let id = s.client.propose_upgrade(&s.holders[0], &upload_dummy_wasm(&s.env, 1), &0u64);
+    s.client.vote(&id, &s.holders[0], &true);
+    s.env.ledger().set_timestamp(s.env.ledger().timestamp() + VOTING_PERIOD);
+    s.client.execute_upgrade(&id);
+}
+
+#[test]
+#[should_panic(expected = "Quorum not met")]
+fn test_quorum_not_met() {
+    let s = setup();
+    let id = s.client.propose_upgrade(&s.holders[0], &BytesN::from_array(&s.env, &[1; 32]), &0u64);
+    // 30 of 100 tokens is below the 40% quorum even though it is unanimous.
+    s.client.vote(&id, &s.holders[1], &true);
+    close_window(&s);
+    s.client.execute_upgrade(&id);
+}
+
+#[test]
+#[should_panic(expected = "Vote threshold not met")]
+fn test_majority_not_met() {
+    let s = setup();
+    let id = s.client.propose_upgrade(&s.holders[0], &BytesN::from_array(&s.env, &[1; 32]), &0u64);
+    // 50 yes vs 50 no: quorum met, but yes does not exceed 50% of votes cast.
+    s.client.vote(&id, &s.holders[0], &true);
+    s.client.vote(&id, &s.holders[1], &false);
+    s.client.vote(&id, &s.holders[2], &false);
+    close_window(&s);
+    s.client.execute_upgrade(&id);
+}
+
+#[test]
+#[should_panic(expected = "Already voted")]
+fn test_double_vote_rejected() {
+    let s = setup();
+    let id = s.client.propose_upgrade(&s.holders[0], &BytesN::from_array(&s.env, &[1; 32]), &0u64);
+    s.client.vote(&id, &s.holders[0], &true);
+    s.client.vote(&id, &s.holders[0], &true);
+}
+
+#[test]
+#[should_panic(expected = "Voting period has ended")]
+fn test_vote_after_window_rejected() {
+    let s = setup();
+    let id = s.client.propose_upgrade(&s.holders[0], &BytesN::from_array(&s.env, &[1; 32]), &0u64);
+    s.env.ledger().set_timestamp(s.env.ledger().timestamp() + VOTING_PERIOD);
+    s.client.vote(&id, &s.holders[0], &true);
+}
+
+#[test]
+fn test_power_is_fixed_at_snapshot() {
+    let s = setup();
+    let id = s.client.propose_upgrade(&s.holders[0], &BytesN::from_array(&s.env, &[1; 32]), &0u64);
+
+    // Tokens moved to a fresh account after the snapshot grant it no power.
+    s.env.ledger().set_sequence_number(3);
+    let newcomer = Address::generate(&s.env);
+    s.token.transfer(&s.holders[2], &newcomer, &20);
+    assert!(s.client.try_vote(&id, &newcomer, &true).is_err());
+
+    // The original holder keeps the weight it held at the snapshot.
+    s.client.vote(&id, &s.holders[2], &true);
+    assert_eq!(s.client.get_token_proposal(&id).unwrap().yes_votes, 20);
+}
+
+#[test]
+fn test_same_ledger_transfer_does_not_count() {
+    let s = setup();
+    let late = Address::generate(&s.env);
+    s.token.mint(&late, &500);
+    s.token.transfer(&s.holders[0], &late, &50);
+
+    let id = s.client.propose_upgrade(&s.holders[1], &BytesN::from_array(&s.env, &[1; 32]), &0u64);
+    assert_eq!(s.client.get_token_proposal(&id).unwrap().total_supply, 100);
+    assert!(s.client.try_vote(&id, &late, &true).is_err());
+    s.client.vote(&id, &s.holders[0], &true);
+    assert_eq!(s.client.get_token_proposal(&id).unwrap().yes_votes, 50);
+}
+
+#[test]
+fn test_multisig_approval_disabled_in_token_mode() {
+    let s = setup();
+    let id = s.client.propose_upgrade(&s.holders[0], &BytesN::from_array(&s.env, &[1; 32]), &0u64);
+    assert!(s.client.try_approve_upgrade(&id, &s.holders[0]).is_err());
+}
+
+#[test]
+fn test_proposer_without_tokens_rejected() {
+    let s = setup();
+    let outsider = Address::generate(&s.env);
+    assert!(s
+        .client
+        .try_propose_upgrade(&outsider, &BytesN::from_array(&s.env, &[1; 32]), &0u64)
+        .is_err());
+}
+
+#[test]
+#[should_panic(expected = "Quorum and threshold cannot exceed 10000 bps")]
+fn test_config_rejects_bps_above_100_percent() {
+    let s = setup();
+    s.client.set_token_voting_config(&Some(TokenVotingConfig {
+        token: s.token.address.clone(),
+        voting_period: VOTING_PERIOD,
+        quorum_bps: 10_001,
+        threshold_bps: 5_000,
+    }));
+}
+
+#[test]
+fn test_open_proposal_keeps_its_token() {
+    let s = setup();
+    let id = s.client.propose_upgrade(&s.holders[0], &BytesN::from_array(&s.env, &[1; 32]), &0u64);
+
+    let other = mint_holders(&s.env, &[Address::generate(&s.env), Address::generate(&s.env), Address::generate(&s.env)]);
+    s.client.set_token_voting_config(&Some(config_with_token(&other.address)));
+    s.client.vote(&id, &s.holders[0], &true);
+    assert_eq!(s.client.get_token_proposal(&id).unwrap().yes_votes, 50);
+}
+
+#[test]
+fn test_multisig_switches_to_token_voting_by_proposal() {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    let holders = [Address::generate(&env), Address::generate(&env), Address::generate(&env)];
+    let token = mint_holders(&env, &holders);
+    assert!(client
+        .try_set_token_voting_config(&Some(config_with_token(&token.address)))
+        .is_err());
+
+    let bundle_id = GovernanceDriver::apply_actions(
+        &env,
+        &client,
+        &signers,
+        vec![&env, ProposalAction::SetTokenVotingConfig(config_with_token(&token.address))],
+    );
+    assert_eq!(client.get_token_voting_config(), Some(config_with_token(&token.address)));
+
+    // The token proposal takes the next ID after the bundle.
+    env.ledger().set_sequence_number(env.ledger().sequence() + 1);
+    let id = client.propose_upgrade(&holders[0], &BytesN::from_array(&env, &[1; 32]), &0u64);
+    assert_eq!(id, bundle_id + 1);
+}
+
+#[test]
+#[should_panic(expected = "Voting token must implement SnapshotTokenInterface")]
+fn test_sdk_token_without_snapshots_rejected() {
+    let env = Env::default();
+    let (client, _signers) = setup_admin_and_multisig(&env);
+    let sac = env.register_stellar_asset_contract_v2(Address::generate(&env));
+    client.set_token_voting_config(&Some(config_with_token(&sac.address())));
+}
+
+#[test]
+#[should_panic(expected = "Vote threshold not met")]
+fn test_config_change_does_not_rescue_a_failed_vote() {
+    let s = setup();
+    let id = s.client.propose_upgrade(&s.holders[0], &BytesN::from_array(&s.env, &[1; 32]), &0u64);
+    s.client.vote(&id, &s.holders[0], &true);
+    s.client.vote(&id, &s.holders[1], &false);
+    s.client.vote(&id, &s.holders[2], &false);
+
+    let mut lenient = config_with_token(&s.token.address);
+    lenient.quorum_bps = 0;
+    lenient.threshold_bps = 0;
+    s.client.set_token_voting_config(&Some(lenient));
+    let proposal = s.client.get_token_proposal(&id).unwrap();
+    assert_eq!((proposal.quorum_bps, proposal.threshold_bps), (4_000, 5_000));
+
+    close_window(&s);
+    s.client.execute_upgrade(&id);
+}
+
+#[test]
+#[should_panic(expected = "129")]
+fn test_token_upgrade_orders_older_multisig_proposal() {
+    let env = Env::default();
+    let (client, signers) = setup_admin_and_multisig(&env);
+    let older = GovernanceDriver::propose(&env, &client, &signers, &upload_dummy_wasm(&env, 1));
+    GovernanceDriver::approve_to_threshold(&env, &client, &signers, older);
+
+    let holders = [Address::generate(&env), Address::generate(&env), Address::generate(&env)];
+    let token = mint_holders(&env, &holders);
+    client.set_token_voting_config(&Some(config_with_token(&token.address)));
+    let newer = client.propose_upgrade(&holders[0], &upload_dummy_wasm(&env, 2), &0u64);
+    client.vote(&newer, &holders[0], &true);
+    let delay = client.get_timelock_delay();
+    env.ledger().set_timestamp(env.ledger().timestamp() + VOTING_PERIOD + delay);
+    client.execute_upgrade(&newer);
+
+    // The token upgrade replaced the code; drive the multisig side directly.
+    env.as_contract(&client.address, || {
+        crate::TokenVoting::set_config(&env, None);
+        let preview = GrainlifyContract::preview_execution(env.clone(), older);
+        assert_eq!(preview.failing, vec![&env, ExecutionCheck::OutOfOrder]);
+        GrainlifyContract::execute_upgrade(env.clone(), older);
+    });
+}
@@ -0,0 +1,98 @@
+//! # Tracking Filter Tests
+//!
+//! - An operation with tracking turned off moves no counter and publishes
+//!   no metric event
+//! - Other operations stay tracked
+//! - `get_tracking_config` lists the overrides; turning tracking back on
+//!   resumes counting
+//! - Only governance can change the filter; multisig deployments use an
+//!   action bundle
+
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{symbol_short, testutils::Events as _, vec, Env, Symbol, TryFromVal};
+
+use crate::testutils::{setup_admin, setup_multisig, upload_dummy_wasm, GovernanceDriver};
+use crate::{GrainlifyContract, ProposalAction};
+
+fn metric_events(env: &Env) -> u32 {
+    let metric = symbol_short!("metric");
+    env.events()
+        .all()
+        .iter()
+        .filter(|(_, topics, _)| {
+            topics
+                .get(0)
+                .and_then(|t| Symbol::try_from_val(env, &t).ok())
+                .is_some_and(|t| t == metric)
+        })
+        .count() as u32
+}
+
+#[test]
+fn test_disabled_operation_is_not_tracked() {
+    let env = Env::default();
+    let (client, _) = setup_admin(&env);
+    client.set_tracking(&symbol_short!("set_ver"), &false);
+
+    let before = client.get_analytics();
+    let perf_before = client.get_performance_stats(&symbol_short!("set_ver"));
+    client.set_version(&5);
+    assert_eq!(metric_events(&env), 0);
+    assert_eq!(client.get_analytics().operation_count, before.operation_count);
+    assert_eq!(
+        client.get_performance_stats(&symbol_short!("set_ver")).call_count,
+        perf_before.call_count
+    );
+    assert_eq!(client.get_version(), 5);
+}
+
+#[test]
+fn test_other_operations_stay_tracked() {
+    let env = Env::default();
+    let (client, _) = setup_admin(&env);
+    client.set_tracking(&symbol_short!("set_ver"), &false);
+    let before = client.get_analytics().operation_count;
+
+    client.upgrade(&upload_dummy_wasm(&env, 1));
+    assert!(metric_events(&env) > 0);
+    let after = env.as_contract(&client.address, || GrainlifyContract::get_analytics(env.clone()));
+    assert_eq!(after.operation_count, before + 1);
+}
+
+#[test]
+fn test_reenabling_resumes_tracking() {
+    let env = Env::default();
+    let (client, _) = setup_admin(&env);
+    client.set_tracking(&symbol_short!("set_ver"), &false);
+    client.set_version(&4);
+    assert_eq!(client.get_tracking_config().get(symbol_short!("set_ver")), Some(false));
+
+    client.set_tracking(&symbol_short!("set_ver"), &true);
+    assert_eq!(client.get_tracking_config().get(symbol_short!("set_ver")), Some(true));
+    let before = client.get_analytics().operation_count;
+    client.set_version(&5);
+    assert_eq!(client.get_analytics().operation_count, before + 1);
+}
+
+#[test]
+fn test_set_tracking_requires_admin_auth() {
+    let env = Env::default();
+    let (client, _) = setup_admin(&env);
+    env.set_auths(&[]);
+    assert!(client.try_set_tracking(&symbol_short!("set_ver"), &false).is_err());
+    assert!(client.get_tracking_config().is_empty());
+}
+
+#[test]
+fn test_multisig_sets_tracking_through_actions() {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    assert!(client.try_set_tracking(&symbol_short!("set_ver"), &false).is_err());
+
+    let actions = vec![&env, ProposalAction::SetTracking(symbol_short!("set_ver"), false)];
+    GovernanceDriver::apply_actions(&env, &client, &signers, actions);
+    assert_eq!(client.get_tracking_config().get(symbol_short!("set_ver")), Some(false));
+}
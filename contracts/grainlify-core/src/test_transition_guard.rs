@@ -0,0 +1,88 @@
+//! # Transition Guard Tests
+//!
+//! - A subscriber notified by `execute_upgrade` that calls back into
+//!   `approve_upgrade` is rejected, and the execution still completes
+//! - Governance entrypoints fail with `TransitionError::InProgress` while
+//!   the guard is set
+
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{symbol_short, Env};
+
+use crate::testutils::{upload_dummy_wasm, GovernanceDriver};
+use crate::{MultiSig, TransitionKey};
+
+mod reentrant_subscriber {
+    use soroban_sdk::{contract, contractimpl, symbol_short, Address, Env};
+
+    use crate::GrainlifyContractClient;
+
+    /// On notification, tries to approve the proposal stored by `arm` on
+    /// the contract that upgraded, and records whether it was rejected.
+    #[contract]
+    pub struct ReentrantSubscriber;
+
+    #[contractimpl]
+    impl ReentrantSubscriber {
+        pub fn arm(env: Env, proposal_id: u64, signer: Address) {
+            env.storage().instance().set(&symbol_short!("target"), &(proposal_id, signer));
+        }
+
+        pub fn on_dependency_upgraded(env: Env, upgraded: Address, _new_version: u32) {
+            let (proposal_id, signer): (u64, Address) =
+                env.storage().instance().get(&symbol_short!("target")).unwrap();
+            let rejected = GrainlifyContractClient::new(&env, &upgraded)
+                .try_approve_upgrade(&proposal_id, &signer)
+                .is_err();
+            env.storage().instance().set(&symbol_short!("rejected"), &rejected);
+        }
+
+        pub fn was_rejected(env: Env) -> Option<bool> {
+            env.storage().instance().get(&symbol_short!("rejected"))
+        }
+    }
+}
+
+use reentrant_subscriber::{ReentrantSubscriber, ReentrantSubscriberClient};
+
+#[test]
+fn test_reentrant_approval_from_subscriber_rejected() {
+    let env = Env::default();
+    let (client, signers) = GovernanceDriver::bootstrap_multisig(&env, 3, 2);
+    let admin = signers.get(0).unwrap();
+    env.as_contract(&client.address, || {
+        env.storage().instance().set(&crate::DataKey::Admin, &admin);
+    });
+    let subscriber_id = env.register_contract(None, ReentrantSubscriber);
+    let subscriber = ReentrantSubscriberClient::new(&env, &subscriber_id);
+    client.register_subscriber(&subscriber_id);
+
+    let executed = GovernanceDriver::propose(&env, &client, &signers, &upload_dummy_wasm(&env, 1));
+    GovernanceDriver::approve_to_threshold(&env, &client, &signers, executed);
+    let pending = GovernanceDriver::propose(&env, &client, &signers, &upload_dummy_wasm(&env, 2));
+    subscriber.arm(&pending, &signers.get(1).unwrap());
+    GovernanceDriver::skip_timelock(&env, &client);
+
+    let receipt = client.execute_upgrade_v2(&executed);
+    assert_eq!(receipt.proposal_id, executed);
+    assert_eq!(subscriber.was_rejected(), Some(true));
+    // The contract now runs the dummy code, so read its storage directly.
+    env.as_contract(&client.address, || {
+        assert_eq!(MultiSig::get_valid_approvals(&env, pending).len(), 0);
+        assert!(!env.storage().instance().has(&TransitionKey::InProgress));
+    });
+}
+
+#[test]
+#[should_panic(expected = "158")]
+fn test_entrypoints_rejected_while_guard_set() {
+    let env = Env::default();
+    let (client, signers) = GovernanceDriver::bootstrap_multisig(&env, 3, 2);
+    let proposal_id = GovernanceDriver::propose(&env, &client, &signers, &upload_dummy_wasm(&env, 1));
+    env.as_contract(&client.address, || {
+        env.storage().instance().set(&TransitionKey::InProgress, &symbol_short!("exec_upg"));
+    });
+    client.approve_upgrade(&proposal_id, &signers.get(0).unwrap());
+}
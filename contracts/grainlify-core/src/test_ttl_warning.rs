@@ -0,0 +1,102 @@
+//! # Instance TTL Warning Tests
+//!
+//! - `extend_instance_ttl` records the ledger the instance lasts until
+//! - Calls made with fewer ledgers left than the warning threshold publish
+//!   `("ttl", "low")` with the estimate and still succeed
+//! - The threshold is configurable and 0 turns the warning off
+
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Events as _, Ledger as _},
+    Env, IntoVal, TryFromVal,
+};
+
+use crate::testutils::setup_admin;
+use crate::{GrainlifyContractClient, TtlLowEvent, DEFAULT_TTL_WARNING_LEDGERS, INSTANCE_TTL_EXTEND_TO};
+
+fn setup(env: &Env) -> GrainlifyContractClient<'_> {
+    // Long default TTLs so jumping ahead does not archive unrelated entries.
+    env.ledger().set_min_persistent_entry_ttl(2_000_000);
+    env.ledger().set_min_temp_entry_ttl(2_000_000);
+    let (client, _) = setup_admin(env);
+    client
+}
+
+fn ttl_low_events(env: &Env) -> usize {
+    let topics = (symbol_short!("ttl"), symbol_short!("low")).into_val(env);
+    env.events().all().iter().filter(|(_, t, _)| *t == topics).count()
+}
+
+fn ttl_low_event(env: &Env) -> Option<TtlLowEvent> {
+    let topics = (symbol_short!("ttl"), symbol_short!("low")).into_val(env);
+    env.events()
+        .all()
+        .iter()
+        .find(|(_, t, _)| *t == topics)
+        .map(|(_, _, data)| TtlLowEvent::try_from_val(env, &data).unwrap())
+}
+
+#[test]
+fn test_extension_is_recorded() {
+    let env = Env::default();
+    let client = setup(&env);
+    env.ledger().set_sequence_number(100);
+    assert_eq!(client.get_instance_extended_to(), None);
+
+    let extended_to = client.extend_instance_ttl();
+    assert_eq!(extended_to, 100 + INSTANCE_TTL_EXTEND_TO);
+    assert_eq!(client.get_instance_extended_to(), Some(extended_to));
+}
+
+#[test]
+fn test_low_ttl_publishes_warning_without_failing() {
+    let env = Env::default();
+    let client = setup(&env);
+    let extended_to = client.extend_instance_ttl();
+
+    env.ledger().set_sequence_number(extended_to - DEFAULT_TTL_WARNING_LEDGERS);
+    client.set_version(&2);
+    assert_eq!(ttl_low_event(&env), None, "exactly at the threshold is fine");
+
+    env.ledger().set_sequence_number(extended_to - DEFAULT_TTL_WARNING_LEDGERS + 1);
+    client.set_version(&3);
+    let event = ttl_low_event(&env).unwrap();
+    assert_eq!(event.ledgers_remaining, DEFAULT_TTL_WARNING_LEDGERS - 1);
+    assert_eq!(event.extended_to, extended_to);
+    assert_eq!(client.get_version(), 3);
+}
+
+#[test]
+fn test_extension_clears_warning() {
+    let env = Env::default();
+    let client = setup(&env);
+    let extended_to = client.extend_instance_ttl();
+    env.ledger().set_sequence_number(extended_to - 10);
+
+    // The extending call itself still warns: the check runs first.
+    client.extend_instance_ttl();
+    client.set_version(&2);
+    assert_eq!(ttl_low_events(&env), 1);
+    assert_eq!(ttl_low_event(&env).unwrap().extended_to, extended_to);
+}
+
+#[test]
+fn test_threshold_is_configurable() {
+    let env = Env::default();
+    let client = setup(&env);
+    client.set_ttl_warning_threshold(&0);
+    assert_eq!(client.get_ttl_warning_threshold(), 0);
+    let extended_to = client.extend_instance_ttl();
+
+    env.ledger().set_sequence_number(extended_to - 1);
+    client.set_version(&2);
+    assert_eq!(ttl_low_event(&env), None);
+
+    client.set_ttl_warning_threshold(&INSTANCE_TTL_EXTEND_TO);
+    client.set_version(&3);
+    assert_eq!(ttl_low_event(&env).unwrap().ledgers_remaining, 1);
+}
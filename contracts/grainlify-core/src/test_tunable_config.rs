@@ -0,0 +1,89 @@
+//! # Tunable Config Tests
+//!
+//! - `get_config` reports defaults for unset knobs
+//! - `set_config` changes only the fields it sets; an invalid field reverts
+//!   the whole update
+//! - An `UpdateConfig` action bundle applies an update in one proposal
+
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{testutils::Ledger as _, vec, Env};
+
+use crate::testutils::{setup_admin, setup_multisig};
+use crate::{
+    ConfigUpdate, ProposalAction, RevealDeadline, DEFAULT_STALE_PROPOSAL_AGE, DEFAULT_TTL_WARNING_LEDGERS,
+};
+
+#[test]
+fn test_defaults_filled_in() {
+    let env = Env::default();
+    let (client, _) = setup_admin(&env);
+    let config = client.get_config();
+    assert_eq!(config.timelock_delay, client.get_timelock_delay());
+    assert_eq!(config.stale_proposal_age, DEFAULT_STALE_PROPOSAL_AGE);
+    assert_eq!(config.ttl_warning_threshold, DEFAULT_TTL_WARNING_LEDGERS);
+    assert!(!config.require_nonce);
+    assert_eq!(config.reveal_deadline, RevealDeadline::BeforeApproval);
+    assert_eq!(config.threshold, 0);
+    assert_eq!(config.staging_delay, None);
+}
+
+#[test]
+fn test_partial_update_keeps_other_fields() {
+    let env = Env::default();
+    let (client, _) = setup_admin(&env);
+    let before = client.get_config();
+
+    client.set_config(&ConfigUpdate {
+        timelock_delay: Some(7_200),
+        require_nonce: Some(true),
+        ..Default::default()
+    });
+
+    let after = client.get_config();
+    assert_eq!(after.timelock_delay, 7_200);
+    assert!(after.require_nonce);
+    assert_eq!(after.config_change_delay, before.config_change_delay);
+    assert_eq!(after.max_postponement, before.max_postponement);
+    assert_eq!(after.stale_proposal_age, before.stale_proposal_age);
+    assert_eq!(after.ttl_warning_threshold, before.ttl_warning_threshold);
+}
+
+#[test]
+fn test_invalid_field_reverts_update() {
+    let env = Env::default();
+    let (client, _) = setup_admin(&env);
+    let before = client.get_config();
+
+    let result = client.try_set_config(&ConfigUpdate {
+        stale_proposal_age: Some(60),
+        timelock_delay: Some(1),
+        ..Default::default()
+    });
+    assert!(result.is_err());
+    assert_eq!(client.get_config(), before);
+}
+
+#[test]
+fn test_update_config_action() {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    let update = ConfigUpdate {
+        threshold: Some(3),
+        max_postponement: Some(600),
+        ..Default::default()
+    };
+    let proposal_id =
+        client.propose_actions(&signers.get(0).unwrap(), &vec![&env, ProposalAction::UpdateConfig(update)], &0);
+    client.approve_actions(&proposal_id, &signers.get(0).unwrap());
+    client.approve_actions(&proposal_id, &signers.get(1).unwrap());
+    env.ledger().set_timestamp(client.get_timelock_delay());
+    client.execute_proposal(&proposal_id);
+
+    let config = client.get_config();
+    assert_eq!(config.threshold, 3);
+    assert_eq!(config.max_postponement, 600);
+    assert_eq!(client.get_threshold_history(&1, &1).items.get(0).unwrap().proposal_id, Some(proposal_id));
+}
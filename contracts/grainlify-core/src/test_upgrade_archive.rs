@@ -0,0 +1,79 @@
+//! # Upgrade Archive Tests
+//!
+//! - Executing a multisig upgrade appends a compact `ArchivedUpgrade`
+//! - The approvers digest hashes the sorted approver set, so approval
+//!   order does not matter
+//! - Entries survive sweeping the original proposal
+
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{testutils::Ledger as _, xdr::ToXdr, Address, Bytes, BytesN, Env, Vec};
+
+use crate::testutils::{setup_multisig, upload_dummy_wasm};
+use crate::{GrainlifyContract, GrainlifyContractClient, MultiSig};
+
+fn digest(env: &Env, approvers: &[Address]) -> BytesN<32> {
+    let mut sorted = approvers.to_vec();
+    sorted.sort();
+    let mut data = Bytes::new(env);
+    for approver in sorted {
+        data.append(&approver.to_xdr(env));
+    }
+    env.crypto().sha256(&data).into()
+}
+
+fn approve_and_execute(env: &Env, client: &GrainlifyContractClient, approvers: &Vec<Address>, seed: u8) -> u64 {
+    let proposal_id = client.propose_upgrade(&approvers.get(0).unwrap(), &upload_dummy_wasm(env, seed), &0);
+    for approver in approvers.iter() {
+        client.approve_upgrade(&proposal_id, &approver);
+    }
+    env.ledger().set_timestamp(env.ledger().timestamp() + client.get_timelock_delay());
+    client.execute_upgrade_v2(&proposal_id);
+    proposal_id
+}
+
+#[test]
+fn test_execution_appends_archive_entry() {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    let approvers = Vec::from_array(&env, [signers.get(2).unwrap(), signers.get(0).unwrap()]);
+    let version = client.get_version();
+    let proposal_id = approve_and_execute(&env, &client, &approvers, 1);
+
+    let entry = env.as_contract(&client.address, || {
+        assert_eq!(GrainlifyContract::executed_upgrade_count(env.clone()), 1);
+        GrainlifyContract::get_archived_upgrade(env.clone(), 0).unwrap()
+    });
+    assert_eq!(entry.proposal_id, proposal_id);
+    assert_eq!(entry.wasm_hash, upload_dummy_wasm(&env, 1));
+    assert_eq!(entry.version, version);
+    assert_eq!(entry.executed_at, env.ledger().timestamp());
+    assert_eq!(entry.approver_count, 2);
+    assert_eq!(entry.approvers_digest, digest(&env, &[signers.get(0).unwrap(), signers.get(2).unwrap()]));
+}
+
+#[test]
+fn test_archive_survives_sweep() {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    let approvers = Vec::from_array(&env, [signers.get(0).unwrap(), signers.get(1).unwrap()]);
+    let proposal_id = approve_and_execute(&env, &client, &approvers, 1);
+
+    env.as_contract(&client.address, || {
+        let before = GrainlifyContract::get_archived_upgrade(env.clone(), 0);
+        GrainlifyContract::sweep_proposals(env.clone(), 10);
+        assert!(MultiSig::get_proposal_opt(&env, proposal_id).is_none());
+        assert_eq!(GrainlifyContract::get_archived_upgrade(env.clone(), 0), before);
+        assert!(before.is_some());
+    });
+}
+
+#[test]
+fn test_unknown_index_and_empty_archive() {
+    let env = Env::default();
+    let (client, _) = setup_multisig(&env, 3, 2);
+    assert_eq!(client.executed_upgrade_count(), 0);
+    assert_eq!(client.get_archived_upgrade(&0), None);
+}
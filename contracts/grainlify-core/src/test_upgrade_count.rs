@@ -0,0 +1,42 @@
+//! # Upgrade Count Tests
+//!
+//! - Every WASM swap increments `get_upgrade_count`, whichever path it took
+//! - The count appears in `get_analytics` and `get_contract_info`
+
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::Env;
+
+use crate::testutils::{setup_admin, upload_dummy_wasm, GovernanceDriver};
+use crate::GrainlifyContract;
+
+#[test]
+fn test_admin_upgrades_are_counted() {
+    let env = Env::default();
+    let (client, _) = setup_admin(&env);
+    assert_eq!(client.get_upgrade_count(), 0);
+
+    client.upgrade(&upload_dummy_wasm(&env, 1));
+    env.as_contract(&client.address, || {
+        assert_eq!(GrainlifyContract::get_upgrade_count(env.clone()), 1);
+        // The dummy WASM has no entrypoints, so the second upgrade runs
+        // against the native implementation.
+        GrainlifyContract::upgrade(env.clone(), upload_dummy_wasm(&env, 2));
+        assert_eq!(GrainlifyContract::get_upgrade_count(env.clone()), 2);
+        assert_eq!(GrainlifyContract::get_contract_info(env.clone()).upgrade_count, 2);
+        #[cfg(feature = "monitoring")]
+        assert_eq!(GrainlifyContract::get_analytics(env.clone()).upgrade_count, 2);
+    });
+}
+
+#[test]
+fn test_multisig_execution_is_counted() {
+    let env = Env::default();
+    let (client, signers) = GovernanceDriver::bootstrap_multisig(&env, 3, 2);
+    GovernanceDriver::full_upgrade(&env, &client, &signers, &upload_dummy_wasm(&env, 1));
+
+    let count = env.as_contract(&client.address, || GrainlifyContract::get_upgrade_count(env.clone()));
+    assert_eq!(count, 1);
+}
@@ -0,0 +1,122 @@
+//! # Upgrade Ordering Guard Tests
+//!
+//! - Executing an upgrade proposal while a newer one is approved fails with
+//!   `OutOfOrderUpgrade`
+//! - Same once the newer proposal has executed
+//! - `execute_upgrade_v3(.., true)` overrides the guard and records it
+//! - Cancelled or expired newer proposals and non-upgrade proposals do not
+//!   count
+//! - The guard does not walk the newer proposal IDs, so a long run of
+//!   unrelated proposals leaves the check within the default budget
+
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{testutils::Events as _, testutils::Ledger as _, vec, Address, Env, Symbol, TryFromVal, Vec};
+
+use crate::testutils::{setup_multisig, upload_dummy_wasm};
+use crate::{ExecutionCheck, GrainlifyContract, GrainlifyContractClient, ProposalAction};
+
+fn approve(client: &GrainlifyContractClient, proposal_id: u64, signers: &Vec<Address>) {
+    client.approve_upgrade(&proposal_id, &signers.get(0).unwrap());
+    client.approve_upgrade(&proposal_id, &signers.get(1).unwrap());
+}
+
+fn wait_timelock(env: &Env, client: &GrainlifyContractClient) {
+    env.ledger().set_timestamp(env.ledger().timestamp() + client.get_timelock_delay());
+}
+
+/// Proposal 1 installs the old build, proposal 2 the fix; both approved.
+fn race(env: &Env) -> (GrainlifyContractClient<'_>, Vec<Address>, u64, u64) {
+    let (client, signers) = setup_multisig(env, 3, 2);
+    let proposer = signers.get(0).unwrap();
+    let old = client.propose_upgrade(&proposer, &upload_dummy_wasm(env, 1), &0);
+    let fixed = client.propose_upgrade(&proposer, &upload_dummy_wasm(env, 2), &0);
+    approve(&client, old, &signers);
+    approve(&client, fixed, &signers);
+    wait_timelock(env, &client);
+    (client, signers, old, fixed)
+}
+
+#[test]
+#[should_panic(expected = "129")]
+fn test_older_proposal_rejected_while_newer_approved() {
+    let env = Env::default();
+    let (client, _, old, _) = race(&env);
+    client.execute_upgrade(&old);
+}
+
+#[test]
+fn test_older_proposal_rejected_after_newer_executed() {
+    let env = Env::default();
+    let (client, _, old, fixed) = race(&env);
+    client.execute_upgrade_v2(&fixed);
+
+    // The dummy WASM is installed now, so drive the native contract.
+    let (preview, report) = env.as_contract(&client.address, || {
+        (
+            GrainlifyContract::preview_execution(env.clone(), old),
+            GrainlifyContract::can_execute_detailed(env.clone(), old),
+        )
+    });
+    assert_eq!(preview.failing, vec![&env, ExecutionCheck::OutOfOrder]);
+    assert_eq!(report.blockers, vec![&env, Symbol::new(&env, "out_of_order")]);
+}
+
+#[test]
+fn test_override_executes_and_records_event() {
+    let env = Env::default();
+    let (client, _, old, fixed) = race(&env);
+    let receipt = client.execute_upgrade_v3(&old, &true);
+    assert_eq!(receipt.proposal_id, old);
+
+    let recorded = env.events().all().iter().any(|(_, topics, data)| {
+        topics.len() == 2
+            && Symbol::try_from_val(&env, &topics.get(1).unwrap()).ok() == Some(Symbol::new(&env, "out_order"))
+            && <(u64, u64)>::try_from_val(&env, &data).ok() == Some((old, fixed))
+    });
+    assert!(recorded);
+}
+
+#[test]
+fn test_newest_proposal_executes_without_flag() {
+    let env = Env::default();
+    let (client, _, _, fixed) = race(&env);
+    assert_eq!(client.execute_upgrade_v2(&fixed).proposal_id, fixed);
+}
+
+#[test]
+fn test_cancelled_newer_and_other_kinds_do_not_block() {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    let proposer = signers.get(0).unwrap();
+    let old = client.propose_upgrade(&proposer, &upload_dummy_wasm(&env, 1), &0);
+    let cancelled = client.propose_upgrade(&proposer, &upload_dummy_wasm(&env, 2), &0);
+    let bundle = client.propose_actions(&proposer, &vec![&env, ProposalAction::SetVersion(5)], &0);
+    approve(&client, old, &signers);
+    approve(&client, cancelled, &signers);
+    client.approve_actions(&bundle, &signers.get(0).unwrap());
+    client.approve_actions(&bundle, &signers.get(1).unwrap());
+    client.cancel_upgrade(&cancelled, &proposer);
+    wait_timelock(&env, &client);
+
+    client.execute_upgrade(&old);
+}
+
+#[test]
+fn test_guard_cost_ignores_unrelated_newer_proposals() {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    let proposer = signers.get(0).unwrap();
+    let old = client.propose_upgrade(&proposer, &upload_dummy_wasm(&env, 1), &0);
+    approve(&client, old, &signers);
+    env.budget().reset_unlimited();
+    for version in 0..300 {
+        client.propose_actions(&proposer, &vec![&env, ProposalAction::SetVersion(version)], &0);
+    }
+    wait_timelock(&env, &client);
+
+    env.budget().reset_default();
+    client.execute_upgrade(&old);
+}
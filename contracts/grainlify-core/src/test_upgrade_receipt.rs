@@ -0,0 +1,63 @@
+//! # Upgrade Receipt Tests
+//!
+//! - `execute_upgrade_v2` returns the installed hash, the stored version,
+//!   the proposal ID, and the execution time recorded in the upgrade history
+//! - `upgrade_v2` returns the same fields with proposal ID 0
+//! - `upgrade_v2` honours the `upgrade` kill switch
+
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{testutils::Ledger as _, Env, Symbol};
+
+use crate::testutils::{setup_admin, upload_dummy_wasm, GovernanceDriver};
+
+#[test]
+fn test_execute_upgrade_v2_receipt_matches_storage() {
+    let env = Env::default();
+    let (client, signers) = GovernanceDriver::bootstrap_multisig(&env, 3, 2);
+    env.as_contract(&client.address, || grainlify_upgradeable::set_version(&env, 7));
+    let wasm_hash = upload_dummy_wasm(&env, 1);
+
+    env.ledger().set_timestamp(5);
+    let receipt = GovernanceDriver::full_upgrade(&env, &client, &signers, &wasm_hash);
+    let proposal_id = receipt.proposal_id;
+
+    assert_eq!(receipt.wasm_hash, wasm_hash);
+    assert_eq!(proposal_id, 1);
+    assert_eq!(receipt.executed_at, env.ledger().timestamp());
+    env.as_contract(&client.address, || {
+        let history = grainlify_upgradeable::get_executed_upgrade(&env, proposal_id).unwrap();
+        assert_eq!(history.wasm_hash, receipt.wasm_hash);
+        assert_eq!(history.executed_at, Some(receipt.executed_at));
+        assert_eq!(grainlify_upgradeable::get_version(&env), receipt.new_version);
+    });
+    assert_eq!(receipt.new_version, 7);
+}
+
+#[test]
+fn test_upgrade_v2_receipt_has_no_proposal() {
+    let env = Env::default();
+    let (client, _admin) = setup_admin(&env);
+    env.ledger().set_timestamp(1_000);
+    let wasm_hash = upload_dummy_wasm(&env, 2);
+
+    let receipt = client.upgrade_v2(&wasm_hash);
+
+    assert_eq!(receipt.wasm_hash, wasm_hash);
+    assert_eq!(receipt.proposal_id, 0);
+    assert_eq!(receipt.executed_at, 1_000);
+    env.as_contract(&client.address, || {
+        assert_eq!(grainlify_upgradeable::get_version(&env), receipt.new_version);
+        assert_eq!(grainlify_upgradeable::get_previous_version(&env), Some(receipt.new_version));
+    });
+}
+
+#[test]
+fn test_upgrade_v2_respects_upgrade_kill_switch() {
+    let env = Env::default();
+    let (client, _admin) = setup_admin(&env);
+    client.disable_function(&Symbol::new(&env, "upgrade"));
+    assert!(client.try_upgrade_v2(&upload_dummy_wasm(&env, 3)).is_err());
+}
@@ -0,0 +1,84 @@
+//! # Upgrade Simulation Tests
+//!
+//! Drives [`simulate_upgrade`] against a contract carrying realistic state and
+//! proves instance storage survives the swap to the bundled v2 module:
+//! - Admin, signers, version, timelock delay, config snapshot history, and
+//!   monitoring metrics are unchanged after `execute_upgrade`
+//! - The v2-only entrypoint is callable and v2 reads the v1 version entry
+//! - v2's `migrate` rewrites the version in place
+
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{
+    testutils::Address as _, vec, Address, Env, IntoVal, Symbol, TryFromVal, Val, Vec,
+};
+
+use crate::testutils::{capture_state, install_test_wasm, simulate_upgrade, V2_ONLY_RESULT};
+use crate::{GrainlifyContract, GrainlifyContractClient, MultiSig};
+
+/// Admin-initialized contract with a 2-of-3 multisig, a custom timelock,
+/// two config snapshots, and metrics from two `set_version` calls.
+fn populated(env: &Env) -> (GrainlifyContractClient<'_>, Address) {
+    env.mock_all_auths();
+    let client = GrainlifyContractClient::new(env, &env.register_contract(None, GrainlifyContract));
+    let admin = Address::generate(env);
+    client.init_admin(&admin);
+    env.as_contract(&client.address, || {
+        let signers = vec![env, Address::generate(env), Address::generate(env), Address::generate(env)];
+        MultiSig::init(env, signers, 2);
+    });
+
+    client.set_timelock_delay(&7_200);
+    client.set_version(&3);
+    client.create_config_snapshot();
+    client.set_version(&4);
+    client.create_config_snapshot();
+    (client, admin)
+}
+
+/// Invokes an entrypoint the v1 client does not know about.
+fn call<T: TryFromVal<Env, Val>>(env: &Env, contract: &Address, function: &str, args: Vec<Val>) -> T {
+    env.invoke_contract(contract, &Symbol::new(env, function), args)
+}
+
+#[test]
+fn test_state_survives_upgrade() {
+    let env = Env::default();
+    let (client, admin) = populated(&env);
+
+    let state = simulate_upgrade(&env, &client, &install_test_wasm(&env));
+
+    assert_eq!(state.admin, Some(admin));
+    assert_eq!(state.multisig.unwrap().signers.len(), 3);
+    assert_eq!(state.version, 4);
+    assert_eq!(state.timelock_delay, 7_200);
+    assert_eq!(state.config_snapshots.len(), 2);
+    assert_eq!(state.config_snapshots.get(0).unwrap().version, 3);
+    #[cfg(feature = "monitoring")]
+    assert_eq!(state.analytics.operation_count, 2);
+}
+
+#[test]
+fn test_v2_code_reads_preserved_state() {
+    let env = Env::default();
+    let (client, _admin) = populated(&env);
+    simulate_upgrade(&env, &client, &install_test_wasm(&env));
+
+    assert_eq!(call::<u32>(&env, &client.address, "v2_only", Vec::new(&env)), V2_ONLY_RESULT);
+    assert_eq!(call::<u32>(&env, &client.address, "get_version", Vec::new(&env)), 4);
+}
+
+#[test]
+fn test_v2_migrate_updates_version() {
+    let env = Env::default();
+    let (client, _admin) = populated(&env);
+    simulate_upgrade(&env, &client, &install_test_wasm(&env));
+
+    let args = vec![&env, 5u32.into_val(&env)];
+    call::<()>(&env, &client.address, "migrate", args);
+
+    assert_eq!(call::<u32>(&env, &client.address, "get_version", Vec::new(&env)), 5);
+    assert_eq!(capture_state(&env, &client.address).version, 5);
+}
@@ -0,0 +1,138 @@
+//! # Upgrade Subscriber Tests
+//!
+//! - Registered subscribers receive `on_dependency_upgraded(upgraded, version)`
+//! - A failing subscriber does not block the upgrade or other subscribers
+//! - Registration is bounded, deduplicated, and reversible
+
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger as _},
+    vec, Address, Env,
+};
+
+use crate::testutils::{setup_admin, upload_dummy_wasm};
+use crate::{GrainlifyContract, GrainlifyContractClient};
+
+mod mock_subscriber {
+    use soroban_sdk::{contract, contractimpl, symbol_short, Address, Env};
+
+    #[contract]
+    pub struct MockSubscriber;
+
+    #[contractimpl]
+    impl MockSubscriber {
+        pub fn on_dependency_upgraded(env: Env, upgraded: Address, new_version: u32) {
+            env.storage()
+                .instance()
+                .set(&symbol_short!("notice"), &(upgraded, new_version));
+        }
+
+        pub fn last_notice(env: Env) -> Option<(Address, u32)> {
+            env.storage().instance().get(&symbol_short!("notice"))
+        }
+    }
+}
+
+mod failing_subscriber {
+    use soroban_sdk::{contract, contractimpl, Address, Env};
+
+    #[contract]
+    pub struct FailingSubscriber;
+
+    #[contractimpl]
+    impl FailingSubscriber {
+        pub fn on_dependency_upgraded(_env: Env, _upgraded: Address, _new_version: u32) {
+            panic!("subscriber failure");
+        }
+    }
+}
+
+use failing_subscriber::FailingSubscriber;
+use mock_subscriber::{MockSubscriber, MockSubscriberClient};
+
+fn setup(env: &Env) -> (GrainlifyContractClient<'_>, Address) {
+    let id = env.register_contract(None, GrainlifyContract);
+    let client = GrainlifyContractClient::new(env, &id);
+    let admin = Address::generate(env);
+    env.mock_all_auths();
+    client.init_admin(&admin);
+    (client, admin)
+}
+
+#[test]
+fn test_subscriber_receives_callback_on_upgrade() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let subscriber_id = env.register_contract(None, MockSubscriber);
+    let subscriber = MockSubscriberClient::new(&env, &subscriber_id);
+
+    client.register_subscriber(&subscriber_id);
+    client.upgrade(&upload_dummy_wasm(&env, 1));
+
+    assert_eq!(subscriber.last_notice(), Some((client.address.clone(), 2)));
+}
+
+#[test]
+fn test_subscriber_receives_callback_on_execute_upgrade() {
+    let env = Env::default();
+    let (client, _admin) = setup_admin(&env);
+    let signer = Address::generate(&env);
+
+    // Multisig config restored alongside the admin so both paths are available.
+    env.as_contract(&client.address, || {
+        crate::MultiSig::init(&env, vec![&env, signer.clone()], 1);
+    });
+
+    let subscriber_id = env.register_contract(None, MockSubscriber);
+    let subscriber = MockSubscriberClient::new(&env, &subscriber_id);
+    client.register_subscriber(&subscriber_id);
+
+    let proposal_id = client.propose_upgrade(&signer, &upload_dummy_wasm(&env, 2), &0u64);
+    client.approve_upgrade(&proposal_id, &signer);
+    env.ledger().set_timestamp(86_400);
+    client.execute_upgrade(&proposal_id);
+
+    assert_eq!(subscriber.last_notice(), Some((client.address.clone(), 2)));
+}
+
+#[test]
+fn test_failing_subscriber_does_not_block_upgrade() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let failing_id = env.register_contract(None, FailingSubscriber);
+    let ok_id = env.register_contract(None, MockSubscriber);
+    let ok = MockSubscriberClient::new(&env, &ok_id);
+
+    client.register_subscriber(&failing_id);
+    client.register_subscriber(&ok_id);
+    client.upgrade(&upload_dummy_wasm(&env, 3));
+
+    assert_eq!(ok.last_notice(), Some((client.address.clone(), 2)));
+}
+
+#[test]
+fn test_register_is_deduplicated_and_unregister_removes() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let subscriber = Address::generate(&env);
+
+    client.register_subscriber(&subscriber);
+    client.register_subscriber(&subscriber);
+    assert_eq!(client.get_subscribers(), vec![&env, subscriber.clone()]);
+
+    client.unregister_subscriber(&subscriber);
+    assert_eq!(client.get_subscribers().len(), 0);
+}
+
+#[test]
+#[should_panic(expected = "Subscriber list full")]
+fn test_subscriber_list_is_bounded() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    for _ in 0..11 {
+        client.register_subscriber(&Address::generate(&env));
+    }
+}
@@ -0,0 +1,114 @@
+//! # Upgrade Ticket Tests
+//!
+//! - `authorize_upgrade` issues a ticket that anyone can redeem with
+//!   `execute_authorized_upgrade` inside its window, without auth
+//! - Redeeming before or after the window, or with another hash, fails
+//! - A ticket is consumed by its execution and can be cancelled
+//! - Only one unexpired ticket exists at a time
+
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{testutils::Ledger as _, BytesN, Env};
+
+use crate::testutils::{setup_admin, upload_dummy_wasm};
+use crate::{GrainlifyContract, GrainlifyContractClient};
+
+const OPENS: u64 = 1_000;
+const CLOSES: u64 = 2_000;
+
+fn authorize(env: &Env) -> (GrainlifyContractClient<'_>, BytesN<32>) {
+    let (client, _) = setup_admin(env);
+    let wasm_hash = upload_dummy_wasm(env, 1);
+    client.authorize_upgrade(&wasm_hash, &OPENS, &CLOSES);
+    (client, wasm_hash)
+}
+
+#[test]
+fn test_anyone_executes_inside_window() {
+    let env = Env::default();
+    let (client, wasm_hash) = authorize(&env);
+    let ticket = client.get_upgrade_ticket().unwrap();
+    assert_eq!(ticket.wasm_hash, wasm_hash);
+    assert_eq!((ticket.not_before, ticket.not_after), (OPENS, CLOSES));
+
+    env.ledger().set_timestamp(OPENS);
+    env.set_auths(&[]);
+    let receipt = client.execute_authorized_upgrade(&wasm_hash);
+    assert_eq!(receipt.wasm_hash, wasm_hash);
+}
+
+#[test]
+fn test_outside_window_rejected() {
+    let env = Env::default();
+    let (client, wasm_hash) = authorize(&env);
+
+    env.ledger().set_timestamp(OPENS - 1);
+    assert!(client.try_execute_authorized_upgrade(&wasm_hash).is_err());
+    env.ledger().set_timestamp(CLOSES);
+    assert!(client.try_execute_authorized_upgrade(&wasm_hash).is_err());
+    assert!(client.get_upgrade_ticket().is_some());
+}
+
+#[test]
+#[should_panic(expected = "134")]
+fn test_ticket_bound_to_hash() {
+    let env = Env::default();
+    let (client, _) = authorize(&env);
+    env.ledger().set_timestamp(OPENS);
+    client.execute_authorized_upgrade(&upload_dummy_wasm(&env, 2));
+}
+
+#[test]
+#[should_panic(expected = "133")]
+fn test_ticket_consumed_by_execution() {
+    let env = Env::default();
+    let (client, wasm_hash) = authorize(&env);
+    env.ledger().set_timestamp(OPENS);
+    client.execute_authorized_upgrade(&wasm_hash);
+
+    // The contract now runs the installed code, so redeem again directly.
+    env.as_contract(&client.address, || {
+        GrainlifyContract::execute_authorized_upgrade(env.clone(), wasm_hash.clone())
+    });
+}
+
+#[test]
+fn test_cancelled_ticket_cannot_execute() {
+    let env = Env::default();
+    let (client, wasm_hash) = authorize(&env);
+    client.cancel_upgrade_authorization();
+    assert_eq!(client.get_upgrade_ticket(), None);
+
+    env.ledger().set_timestamp(OPENS);
+    assert!(client.try_execute_authorized_upgrade(&wasm_hash).is_err());
+    assert!(client.try_cancel_upgrade_authorization().is_err());
+}
+
+#[test]
+fn test_one_active_ticket_at_a_time() {
+    let env = Env::default();
+    let (client, _) = authorize(&env);
+    let other = upload_dummy_wasm(&env, 2);
+    assert!(client.try_authorize_upgrade(&other, &OPENS, &CLOSES).is_err());
+
+    // An expired ticket can be replaced.
+    env.ledger().set_timestamp(CLOSES);
+    client.authorize_upgrade(&other, &(CLOSES + OPENS), &(CLOSES * 2));
+    assert_eq!(client.get_upgrade_ticket().unwrap().wasm_hash, other);
+}
+
+#[test]
+fn test_invalid_windows_and_missing_auth_rejected() {
+    let env = Env::default();
+    let (client, _) = setup_admin(&env);
+    let wasm_hash = upload_dummy_wasm(&env, 1);
+    env.ledger().set_timestamp(OPENS);
+    assert!(client.try_authorize_upgrade(&wasm_hash, &CLOSES, &OPENS).is_err());
+    assert!(client.try_authorize_upgrade(&wasm_hash, &0, &OPENS).is_err());
+
+    env.set_auths(&[]);
+    assert!(client.try_authorize_upgrade(&wasm_hash, &OPENS, &CLOSES).is_err());
+    assert_eq!(client.get_upgrade_ticket(), None);
+}
@@ -0,0 +1,178 @@
+//! # Proposal Validation Tests
+//!
+//! - `validate_proposal` reports every check with its outcome
+//! - Across a matrix of configurations its verdict matches whether
+//!   `execute_upgrade` then succeeds, and names the failing checks
+//! - `HashChanged` is the one stricter check: it fails a proposal that
+//!   would reinstall the tracked current hash
+
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger as _},
+    vec, Address, Env, Vec,
+};
+
+use crate::testutils::{setup_admin_and_multisig, upload_dummy_wasm};
+use crate::{AttestationConfig, GrainlifyContractClient, ValidationCheck};
+
+fn approve(client: &GrainlifyContractClient, signers: &Vec<Address>, proposal_id: u64) {
+    client.approve_upgrade(&proposal_id, &signers.get(0).unwrap());
+    client.approve_upgrade(&proposal_id, &signers.get(1).unwrap());
+}
+
+/// Approved proposal with its timelock elapsed.
+fn ready(env: &Env, client: &GrainlifyContractClient, signers: &Vec<Address>) -> u64 {
+    let proposal_id = client.propose_upgrade(&signers.get(0).unwrap(), &upload_dummy_wasm(env, 1), &0);
+    approve(client, signers, proposal_id);
+    env.ledger().set_timestamp(client.get_timelock_delay());
+    proposal_id
+}
+
+type Scenario = fn(&Env, &GrainlifyContractClient, &Vec<Address>) -> u64;
+
+const MATRIX: [(&str, Scenario, &[ValidationCheck]); 10] = [
+    ("ready", |env, client, signers| ready(env, client, signers), &[]),
+    (
+        "unapproved",
+        |env, client, signers| {
+            let proposal_id = client.propose_upgrade(&signers.get(0).unwrap(), &upload_dummy_wasm(env, 1), &0);
+            env.ledger().set_timestamp(client.get_timelock_delay());
+            proposal_id
+        },
+        &[ValidationCheck::Threshold, ValidationCheck::Timelock],
+    ),
+    (
+        "timelock pending",
+        |env, client, signers| {
+            let proposal_id = client.propose_upgrade(&signers.get(0).unwrap(), &upload_dummy_wasm(env, 1), &0);
+            approve(client, signers, proposal_id);
+            proposal_id
+        },
+        &[ValidationCheck::Timelock],
+    ),
+    (
+        "paused",
+        |env, client, signers| {
+            let proposal_id = ready(env, client, signers);
+            client.pause(&signers.get(0).unwrap());
+            proposal_id
+        },
+        &[ValidationCheck::NotPaused],
+    ),
+    (
+        "read only",
+        |env, client, signers| {
+            let proposal_id = ready(env, client, signers);
+            client.set_read_only_mode(&true);
+            proposal_id
+        },
+        &[ValidationCheck::NotReadOnly],
+    ),
+    (
+        "unattested",
+        |env, client, signers| {
+            let proposal_id = ready(env, client, signers);
+            client.set_attestation_config(&AttestationConfig {
+                require_attestation: true,
+                auditors: vec![env, Address::generate(env)],
+                min_attestations: 1,
+            });
+            proposal_id
+        },
+        &[ValidationCheck::Attested],
+    ),
+    (
+        "cancelled",
+        |env, client, signers| {
+            let proposal_id = ready(env, client, signers);
+            client.cancel_upgrade(&proposal_id, &signers.get(2).unwrap());
+            proposal_id
+        },
+        &[ValidationCheck::Open, ValidationCheck::Timelock],
+    ),
+    (
+        "expired",
+        |env, client, signers| {
+            let proposal_id = client.propose_upgrade(&signers.get(0).unwrap(), &upload_dummy_wasm(env, 1), &100);
+            approve(client, signers, proposal_id);
+            env.ledger().set_timestamp(client.get_timelock_delay());
+            proposal_id
+        },
+        &[ValidationCheck::NotExpired],
+    ),
+    (
+        "window closed",
+        |env, client, signers| {
+            let not_before = 10 * client.get_timelock_delay();
+            let proposal_id = client.propose_upgrade_in_window(
+                &signers.get(0).unwrap(),
+                &upload_dummy_wasm(env, 1),
+                &0,
+                &Some(not_before),
+                &None,
+            );
+            approve(client, signers, proposal_id);
+            env.ledger().set_timestamp(client.get_timelock_delay());
+            proposal_id
+        },
+        &[ValidationCheck::Window],
+    ),
+    (
+        "out of order",
+        |env, client, signers| {
+            let proposal_id = ready(env, client, signers);
+            let newer = client.propose_upgrade(&signers.get(0).unwrap(), &upload_dummy_wasm(env, 2), &0);
+            approve(client, signers, newer);
+            proposal_id
+        },
+        &[ValidationCheck::InOrder],
+    ),
+];
+
+#[test]
+fn test_verdict_matches_execution() {
+    for (name, scenario, expected_failures) in MATRIX {
+        let env = Env::default();
+        let (client, signers) = setup_admin_and_multisig(&env);
+        let proposal_id = scenario(&env, &client, &signers);
+
+        let report = client.validate_proposal(&proposal_id);
+        let failing: std::vec::Vec<ValidationCheck> =
+            report.results.iter().filter(|result| !result.passed).map(|result| result.check).collect();
+        assert_eq!(failing, expected_failures, "{name}");
+        assert_eq!(report.valid, failing.is_empty(), "{name}");
+        assert_eq!(client.try_execute_upgrade(&proposal_id).is_ok(), report.valid, "{name}");
+    }
+}
+
+#[test]
+fn test_report_lists_every_check() {
+    let env = Env::default();
+    let (client, signers) = setup_admin_and_multisig(&env);
+    let wasm_hash = upload_dummy_wasm(&env, 1);
+    let proposal_id = client.propose_upgrade(&signers.get(0).unwrap(), &wasm_hash, &0);
+
+    let report = client.validate_proposal(&proposal_id);
+    assert_eq!(report.proposal_id, proposal_id);
+    assert_eq!(report.wasm_hash, wasm_hash);
+    assert_eq!(report.results.len(), 14);
+    assert_eq!(report.results.get(0).unwrap().check, ValidationCheck::GovernanceMode);
+    assert!(client.try_validate_proposal(&99).is_err());
+}
+
+#[test]
+fn test_reinstalling_current_hash_is_flagged() {
+    let env = Env::default();
+    let (client, signers) = setup_admin_and_multisig(&env);
+    let proposal_id = ready(&env, &client, &signers);
+    client.attest_current_wasm(&client.get_upgrade_proposal(&proposal_id).unwrap().wasm_hash);
+
+    let report = client.validate_proposal(&proposal_id);
+    assert!(!report.valid);
+    let failing: std::vec::Vec<ValidationCheck> =
+        report.results.iter().filter(|result| !result.passed).map(|result| result.check).collect();
+    assert_eq!(failing, [ValidationCheck::HashChanged]);
+}
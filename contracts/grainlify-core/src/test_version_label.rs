@@ -0,0 +1,70 @@
+//! # Version Label Tests
+//!
+//! - A label naming the stored version is kept and reported by
+//!   `get_version_label`, `health_check` and `get_contract_info`
+//! - Malformed labels are rejected: non-digits, empty parts, more than
+//!   three parts, or more than `MAX_VERSION_LABEL_LEN` bytes
+//! - A label for another version needs `force`
+
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{Env, String};
+
+use crate::testutils::setup_admin;
+use crate::MAX_VERSION_LABEL_LEN;
+
+#[test]
+fn test_matching_label_reported_everywhere() {
+    let env = Env::default();
+    let (client, _) = setup_admin(&env);
+    assert_eq!(client.get_version_label(), None);
+
+    let label = client.get_version_semver_string();
+    client.set_version_label(&label, &false);
+    assert_eq!(client.get_version_label(), Some(label.clone()));
+    assert_eq!(client.health_check().version_label, Some(label.clone()));
+    assert_eq!(client.get_contract_info().version_label, Some(label));
+}
+
+#[test]
+fn test_short_forms_fill_in_zeros() {
+    let env = Env::default();
+    let (client, _) = setup_admin(&env);
+    client.set_version(&20_100);
+    client.set_version_label(&String::from_str(&env, "2.1"), &false);
+    client.set_version(&30_000);
+    client.set_version_label(&String::from_str(&env, "3"), &false);
+}
+
+#[test]
+fn test_malformed_labels_rejected() {
+    let env = Env::default();
+    let (client, _) = setup_admin(&env);
+    let long = std::format!("2.0.{}", "0".repeat(MAX_VERSION_LABEL_LEN as usize - 3));
+    for label in ["", "v2.0.0", "2..0", ".2.0", "2.0.", "2.0.0.0", "2.0.0-rc1", long.as_str()] {
+        assert!(
+            client.try_set_version_label(&String::from_str(&env, label), &true).is_err(),
+            "{label:?} accepted"
+        );
+    }
+    assert_eq!(client.get_version_label(), None);
+}
+
+#[test]
+#[should_panic(expected = "Version label does not match version")]
+fn test_inconsistent_label_rejected() {
+    let env = Env::default();
+    let (client, _) = setup_admin(&env);
+    client.set_version_label(&String::from_str(&env, "9.9.9"), &false);
+}
+
+#[test]
+fn test_forced_label_kept() {
+    let env = Env::default();
+    let (client, _) = setup_admin(&env);
+    let label = String::from_str(&env, "9.9.9");
+    client.set_version_label(&label, &true);
+    assert_eq!(client.get_version_label(), Some(label));
+}
@@ -0,0 +1,60 @@
+//! # Version Metric Tests
+//!
+//! - `set_version` publishes `("metric", "version")` with the old and new
+//!   version next to the `("grainlify", "version")` event
+//! - A successful call counts one `set_ver` operation and no error
+//! - An unauthorized call reverts, leaving version, events and counters
+//!   untouched
+
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Events as _, Ledger as _},
+    Env, IntoVal, TryFromVal,
+};
+
+use crate::monitoring::VersionMetric;
+use crate::testutils::setup_admin;
+
+#[test]
+fn test_set_version_reports_transition() {
+    let env = Env::default();
+    let (client, admin) = setup_admin(&env);
+    env.ledger().set_timestamp(1_000);
+    let old_version = client.get_version();
+    let before = client.get_analytics();
+
+    client.set_version(&7);
+
+    let topics = (symbol_short!("metric"), symbol_short!("version")).into_val(&env);
+    let (_, _, data) = env.events().all().iter().find(|(_, t, _)| *t == topics).unwrap();
+    assert_eq!(
+        VersionMetric::try_from_val(&env, &data).unwrap(),
+        VersionMetric { old_version, new_version: 7, caller: admin, timestamp: 1_000 }
+    );
+
+    let after = client.get_analytics();
+    assert_eq!(after.operation_count, before.operation_count + 1);
+    assert_eq!(after.error_count, before.error_count);
+    assert_eq!(client.get_performance_stats(&symbol_short!("set_ver")).call_count, 1);
+}
+
+#[test]
+fn test_unauthorized_set_version_leaves_no_trace() {
+    let env = Env::default();
+    let (client, _) = setup_admin(&env);
+    let old_version = client.get_version();
+    let before = client.get_analytics();
+
+    env.set_auths(&[]);
+    assert!(client.try_set_version(&7).is_err());
+
+    assert_eq!(client.get_version(), old_version);
+    let after = client.get_analytics();
+    assert_eq!(after.operation_count, before.operation_count);
+    assert_eq!(after.error_count, before.error_count);
+    assert_eq!(client.get_performance_stats(&symbol_short!("set_ver")).call_count, 0);
+}
@@ -0,0 +1,70 @@
+//! # Per-Version Metric Tests
+//!
+//! - `OperationMetric` carries the version stored when it was recorded
+//! - Operations are counted under the stored version, and a version change
+//!   closes the old version's stats and opens the new one's
+//! - Returning to an earlier version keeps its counts
+
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Events as _, Ledger as _},
+    Env, IntoVal, TryFromVal,
+};
+
+use crate::monitoring::OperationMetric;
+use crate::testutils::setup_admin;
+
+fn last_operation(env: &Env) -> OperationMetric {
+    let topics = (symbol_short!("metric"), symbol_short!("op")).into_val(env);
+    let (_, _, data) = env.events().all().iter().filter(|(_, t, _)| *t == topics).last().unwrap();
+    OperationMetric::try_from_val(env, &data).unwrap()
+}
+
+#[test]
+fn test_operations_split_by_version() {
+    let env = Env::default();
+    env.ledger().set_timestamp(100);
+    let (client, _admin) = setup_admin(&env);
+    client.set_version(&5);
+    assert_eq!(last_operation(&env).version, 5);
+    let v5 = client.get_version_stats(&5);
+    assert_eq!(v5.operation_count, 1);
+    assert_eq!(v5.active_from, 100);
+
+    env.ledger().set_timestamp(500);
+    client.set_version(&6);
+    assert_eq!(last_operation(&env).version, 6);
+    client.set_version(&6);
+
+    let v5 = client.get_version_stats(&5);
+    assert_eq!(v5.operation_count, 1);
+    assert_eq!(v5.active_until, Some(500));
+
+    let v6 = client.get_version_stats(&6);
+    assert_eq!(v6.operation_count, 2);
+    assert_eq!(v6.error_count, 0);
+    assert_eq!(v6.active_from, 500);
+    assert_eq!(v6.active_until, None);
+    assert_eq!(client.get_version_stats(&7).operation_count, 0);
+}
+
+#[test]
+fn test_returning_version_keeps_counts() {
+    let env = Env::default();
+    env.ledger().set_timestamp(100);
+    let (client, _admin) = setup_admin(&env);
+    client.set_version(&5);
+    client.set_version(&6);
+    env.ledger().set_timestamp(200);
+    client.set_version(&5);
+
+    let v5 = client.get_version_stats(&5);
+    assert_eq!(v5.active_from, 100);
+    assert_eq!(v5.active_until, None);
+    assert_eq!(v5.operation_count, 2);
+    assert_eq!(client.get_version_stats(&6).active_until, Some(200));
+}
@@ -0,0 +1,117 @@
+//! # WASM Blocklist Tests
+//!
+//! - A hash blocked after its proposal was approved cannot be executed;
+//!   once unblocked it can
+//! - Blocked hashes are refused at proposal creation and by `upgrade`
+//! - `("wasm", "unblocked")` carries the reason the hash was blocked for
+//! - Multisig governance blocks and unblocks through `BlockWasm`/
+//!   `UnblockWasm` actions, and the event names the proposal
+
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Events as _, Ledger as _},
+    vec, Address, BytesN, Env, IntoVal, String, Symbol, TryFromVal, Vec,
+};
+
+use crate::testutils::{setup_admin, setup_multisig, upload_dummy_wasm, GovernanceDriver};
+use crate::{ExecutionCheck, GrainlifyContractClient, ProposalAction, ValidationCheck, WasmBlockEvent};
+
+fn reason(env: &Env) -> String {
+    String::from_str(env, "CVE-2026-0042: unchecked transfer")
+}
+
+fn block(env: &Env, client: &GrainlifyContractClient, signers: &Vec<Address>, wasm_hash: &BytesN<32>) -> u64 {
+    let actions = vec![env, ProposalAction::BlockWasm(wasm_hash.clone(), reason(env))];
+    GovernanceDriver::apply_actions(env, client, signers, actions)
+}
+
+fn wasm_events(env: &Env, action: &str) -> std::vec::Vec<WasmBlockEvent> {
+    let topics = (symbol_short!("wasm"), Symbol::new(env, action)).into_val(env);
+    env.events()
+        .all()
+        .iter()
+        .filter(|(_, t, _)| *t == topics)
+        .map(|(_, _, data)| WasmBlockEvent::try_from_val(env, &data).unwrap())
+        .collect()
+}
+
+#[test]
+fn test_block_mid_proposal_stops_execution_until_unblocked() {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    let wasm_hash = upload_dummy_wasm(&env, 1);
+    let proposal_id = GovernanceDriver::propose(&env, &client, &signers, &wasm_hash);
+    GovernanceDriver::approve_to_threshold(&env, &client, &signers, proposal_id);
+
+    block(&env, &client, &signers, &wasm_hash);
+    assert!(client.is_wasm_blocked(&wasm_hash));
+    assert!(client.try_execute_upgrade(&proposal_id).is_err());
+    assert!(client.preview_execution(&proposal_id).failing.contains(ExecutionCheck::WasmBlocked));
+    let report = client.validate_proposal(&proposal_id);
+    assert!(!report.valid);
+    assert!(report.results.iter().any(|r| r.check == ValidationCheck::NotBlocked && !r.passed));
+
+    let actions = vec![&env, ProposalAction::UnblockWasm(wasm_hash.clone())];
+    let unblock_id = GovernanceDriver::apply_actions(&env, &client, &signers, actions);
+    assert!(!client.is_wasm_blocked(&wasm_hash));
+    let unblocked = wasm_events(&env, "unblocked");
+    assert_eq!((unblocked[0].caller.clone(), unblocked[0].proposal_id), (None, Some(unblock_id)));
+    client.execute_upgrade(&proposal_id);
+}
+
+#[test]
+fn test_blocked_hash_refused_at_creation_and_by_upgrade() {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    let wasm_hash = upload_dummy_wasm(&env, 1);
+    let block_id = block(&env, &client, &signers, &wasm_hash);
+    assert_eq!(wasm_events(&env, "blocked")[0].proposal_id, Some(block_id));
+
+    assert!(client.try_propose_upgrade(&signers.get(0).unwrap(), &wasm_hash, &0).is_err());
+    let upgrade = vec![&env, ProposalAction::Upgrade(wasm_hash.clone())];
+    assert!(client.try_propose_actions(&signers.get(0).unwrap(), &upgrade, &0).is_err());
+    client.propose_upgrade(&signers.get(0).unwrap(), &upload_dummy_wasm(&env, 2), &0);
+
+    let (admin_client, _admin) = setup_admin(&env);
+    admin_client.block_wasm_hash(&wasm_hash, &reason(&env));
+    assert!(admin_client.try_upgrade(&wasm_hash).is_err());
+}
+
+#[test]
+fn test_multisig_contract_has_no_direct_block() {
+    let env = Env::default();
+    let (client, _signers) = setup_multisig(&env, 3, 2);
+    let wasm_hash = upload_dummy_wasm(&env, 1);
+    assert!(client.try_block_wasm_hash(&wasm_hash, &reason(&env)).is_err());
+    assert!(!client.is_wasm_blocked(&wasm_hash));
+}
+
+#[test]
+fn test_unblock_event_carries_reason() {
+    let env = Env::default();
+    let (client, admin) = setup_admin(&env);
+    let wasm_hash = upload_dummy_wasm(&env, 1);
+    env.ledger().set_timestamp(500);
+    client.block_wasm_hash(&wasm_hash, &reason(&env));
+    assert_eq!(client.get_wasm_block(&wasm_hash).unwrap().blocked_at, 500);
+
+    client.unblock_wasm_hash(&wasm_hash);
+    let topics = (symbol_short!("wasm"), symbol_short!("unblocked")).into_val(&env);
+    let (_, _, data) = env.events().all().iter().find(|(_, t, _)| *t == topics).unwrap();
+    let event = WasmBlockEvent::try_from_val(&env, &data).unwrap();
+    assert_eq!((event.wasm_hash, event.reason, event.caller), (wasm_hash.clone(), reason(&env), Some(admin)));
+    assert_eq!(event.proposal_id, None);
+    assert_eq!(client.get_wasm_block(&wasm_hash), None);
+}
+
+#[test]
+#[should_panic(expected = "Wasm hash is not blocked")]
+fn test_unblock_requires_block() {
+    let env = Env::default();
+    let (client, _) = setup_admin(&env);
+    client.unblock_wasm_hash(&upload_dummy_wasm(&env, 1));
+}
@@ -0,0 +1,52 @@
+//! # End-to-End WASM Swap Tests
+//!
+//! Installs the bundled v2 module from [`crate::testutils`] and proves the
+//! swap happened by calling `v2_only`, which only the new code exports.
+//! - Single-admin `upgrade`
+//! - Multisig propose → approve → timelock → `execute_upgrade`
+
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{testutils::Ledger as _, Address, Env, InvokeError, Symbol, Val, Vec};
+
+use crate::testutils::{install_test_wasm, setup_admin, setup_multisig, V2_ONLY_RESULT};
+
+fn call_v2_only(env: &Env, contract: &Address) -> u32 {
+    env.invoke_contract(contract, &Symbol::new(env, "v2_only"), Vec::new(env))
+}
+
+#[test]
+fn test_admin_upgrade_runs_v2_code() {
+    let env = Env::default();
+    let (client, _admin) = setup_admin(&env);
+    assert!(env
+        .try_invoke_contract::<u32, InvokeError>(
+            &client.address,
+            &Symbol::new(&env, "v2_only"),
+            Vec::<Val>::new(&env),
+        )
+        .is_err());
+
+    client.upgrade(&install_test_wasm(&env));
+    assert_eq!(call_v2_only(&env, &client.address), V2_ONLY_RESULT);
+
+    // v1 entrypoints are gone with the old code.
+    assert!(client.try_get_admin().is_err());
+}
+
+#[test]
+fn test_multisig_upgrade_runs_v2_code() {
+    let env = Env::default();
+    let (client, signers) = setup_multisig(&env, 3, 2);
+    let proposer = signers.get(0).unwrap();
+
+    let proposal_id = client.propose_upgrade(&proposer, &install_test_wasm(&env), &0u64);
+    client.approve_upgrade(&proposal_id, &proposer);
+    client.approve_upgrade(&proposal_id, &signers.get(1).unwrap());
+    env.ledger().set_timestamp(client.get_timelock_delay());
+    client.execute_upgrade(&proposal_id);
+
+    assert_eq!(call_v2_only(&env, &client.address), V2_ONLY_RESULT);
+}
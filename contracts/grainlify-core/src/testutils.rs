@@ -0,0 +1,226 @@
+//! Helpers for exercising real upgrades in tests.
+//!
+//! Available to this crate's own tests and, through the `testutils` cargo
+//! feature, to any crate that depends on `grainlify-core`:
+//!
+//! ```toml
+//! [dev-dependencies]
+//! grainlify-core = { path = "../grainlify-core", features = ["testutils"] }
+//! ```
+
+extern crate std;
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger as _},
+    Address, Bytes, BytesN, Env, Vec,
+};
+
+use crate::monitoring::{self, Analytics};
+use crate::multisig::{MultiSig, MultiSigConfig};
+use crate::{CoreConfigSnapshot, GrainlifyContract, GrainlifyContractClient, ProposalAction, UpgradeReceipt};
+
+/// Tiny "v2" contract. It drops every v1 entrypoint and exports
+/// `v2_only() -> u32` (returns [`V2_ONLY_RESULT`]), `get_version() -> u32`
+/// reading the v1 version entry, and `migrate(target_version: u32)`
+/// overwriting it. Source: `testdata/v2_test_contract.wat`.
+pub const V2_TEST_WASM: &[u8] = include_bytes!("../testdata/v2_test_contract.wasm");
+
+/// Value returned by the v2 module's `v2_only` entrypoint.
+pub const V2_ONLY_RESULT: u32 = 2;
+
+/// Uploads [`V2_TEST_WASM`] and returns its hash, ready for `upgrade` or
+/// `propose_upgrade`.
+pub fn install_test_wasm(env: &Env) -> BytesN<32> {
+    env.deployer()
+        .upload_contract_wasm(Bytes::from_slice(env, V2_TEST_WASM))
+}
+
+/// Uploads a minimal contract module and returns its hash.
+///
+/// The module carries only the `contractenvmetav0` section the host requires,
+/// so it can be installed with `update_current_contract_wasm` but exports no
+/// functions. The trailing custom section makes each `seed` hash differently,
+/// for tests that need several distinct installable hashes.
+pub fn upload_dummy_wasm(env: &Env, seed: u8) -> BytesN<32> {
+    let mut module: std::vec::Vec<u8> = std::vec![0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00];
+    // contractenvmetav0: SC_ENV_META_KIND_INTERFACE_VERSION, protocol 21.
+    module.extend_from_slice(&[0x00, 0x1e, 0x11]);
+    module.extend_from_slice(b"contractenvmetav0");
+    module.extend_from_slice(&[0, 0, 0, 0, 0, 0, 0, 21, 0, 0, 0, 0]);
+    module.extend_from_slice(&[0x00, 0x03, 0x01, b's', seed]);
+    env.deployer().upload_contract_wasm(Bytes::from_slice(env, &module))
+}
+
+/// Registers a contract initialized with a single generated admin.
+/// Mocks all auths.
+pub fn setup_admin(env: &Env) -> (GrainlifyContractClient<'_>, Address) {
+    env.mock_all_auths();
+    let client = GrainlifyContractClient::new(env, &env.register_contract(None, GrainlifyContract));
+    let admin = Address::generate(env);
+    client.init_admin(&admin);
+    (client, admin)
+}
+
+/// Registers a contract governed by `n_signers` generated signers with the
+/// given approval `threshold`. Mocks all auths.
+pub fn setup_multisig(
+    env: &Env,
+    n_signers: u32,
+    threshold: u32,
+) -> (GrainlifyContractClient<'_>, Vec<Address>) {
+    env.mock_all_auths();
+    let client = GrainlifyContractClient::new(env, &env.register_contract(None, GrainlifyContract));
+    let mut signers = Vec::new(env);
+    for _ in 0..n_signers {
+        signers.push_back(Address::generate(env));
+    }
+    client.init(&signers, &threshold);
+    (client, signers)
+}
+
+/// Registers a contract initialized with a generated admin that also has a
+/// 2-of-3 multisig of generated signers, for tests that need both the
+/// admin-only setters and signer flows. Mocks all auths.
+pub fn setup_admin_and_multisig(env: &Env) -> (GrainlifyContractClient<'_>, Vec<Address>) {
+    let (client, _admin) = setup_admin(env);
+    let mut signers = Vec::new(env);
+    for _ in 0..3 {
+        signers.push_back(Address::generate(env));
+    }
+    env.as_contract(&client.address, || MultiSig::init(env, signers.clone(), 2));
+    (client, signers)
+}
+
+/// Multisig governance flows as single calls on the generated client, for
+/// tests and scripts. Every method mocks all auths; approvals come from the
+/// first `threshold` entries of `signers`, which must be current signers.
+pub struct GovernanceDriver;
+
+impl GovernanceDriver {
+    /// Same as [`setup_multisig`]: `n` generated signers, threshold `k`.
+    pub fn bootstrap_multisig(env: &Env, n: u32, k: u32) -> (GrainlifyContractClient<'_>, Vec<Address>) {
+        setup_multisig(env, n, k)
+    }
+
+    /// Proposes `wasm_hash` as the first signer, without expiry, and
+    /// returns the proposal ID.
+    pub fn propose(env: &Env, client: &GrainlifyContractClient, signers: &Vec<Address>, wasm_hash: &BytesN<32>) -> u64 {
+        env.mock_all_auths();
+        client.propose_upgrade(&signers.get(0).expect("no signers"), wasm_hash, &0u64)
+    }
+
+    /// Approves `proposal_id` with as many signers as the current threshold
+    /// needs.
+    pub fn approve_to_threshold(
+        env: &Env,
+        client: &GrainlifyContractClient,
+        signers: &Vec<Address>,
+        proposal_id: u64,
+    ) {
+        env.mock_all_auths();
+        let threshold = env
+            .as_contract(&client.address, || MultiSig::get_config_opt(env))
+            .expect("GovernanceDriver requires multisig governance")
+            .threshold;
+        assert!(threshold <= signers.len(), "fewer signers than the threshold");
+        for signer in signers.iter().take(threshold as usize) {
+            client.approve_upgrade(&proposal_id, &signer);
+        }
+    }
+
+    /// Proposes `actions` as the first signer, approves them up to the
+    /// threshold, waits out the timelock, and executes them. Returns the
+    /// proposal ID. The multisig path to every governance setting.
+    pub fn apply_actions(
+        env: &Env,
+        client: &GrainlifyContractClient,
+        signers: &Vec<Address>,
+        actions: Vec<ProposalAction>,
+    ) -> u64 {
+        env.mock_all_auths();
+        let proposal_id = client.propose_actions(&signers.get(0).expect("no signers"), &actions, &0u64);
+        let threshold = env
+            .as_contract(&client.address, || MultiSig::get_config_opt(env))
+            .expect("GovernanceDriver requires multisig governance")
+            .threshold;
+        for signer in signers.iter().take(threshold as usize) {
+            client.approve_actions(&proposal_id, &signer);
+        }
+        Self::skip_timelock(env, client);
+        client.execute_proposal(&proposal_id);
+        proposal_id
+    }
+
+    /// Moves the ledger clock past the timelock delay.
+    pub fn skip_timelock(env: &Env, client: &GrainlifyContractClient) {
+        env.ledger()
+            .set_timestamp(env.ledger().timestamp() + client.get_timelock_delay());
+    }
+
+    /// Proposes `wasm_hash`, approves it up to the threshold, waits out the
+    /// timelock, and executes it. Returns the receipt.
+    pub fn full_upgrade(
+        env: &Env,
+        client: &GrainlifyContractClient,
+        signers: &Vec<Address>,
+        wasm_hash: &BytesN<32>,
+    ) -> UpgradeReceipt {
+        let proposal_id = Self::propose(env, client, signers, wasm_hash);
+        Self::approve_to_threshold(env, client, signers, proposal_id);
+        Self::skip_timelock(env, client);
+        client.execute_upgrade_v2(&proposal_id)
+    }
+}
+
+/// Durable state that must survive a WASM swap unchanged.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PreservedState {
+    pub admin: Option<Address>,
+    pub multisig: Option<MultiSigConfig>,
+    pub version: u32,
+    pub timelock_delay: u64,
+    pub config_snapshots: Vec<CoreConfigSnapshot>,
+    pub analytics: Analytics,
+}
+
+/// Reads [`PreservedState`] straight from `contract`'s storage, so it works
+/// whichever code the contract currently runs.
+pub fn capture_state(env: &Env, contract: &Address) -> PreservedState {
+    env.as_contract(contract, || PreservedState {
+        admin: GrainlifyContract::get_admin(env.clone()),
+        multisig: MultiSig::get_config_opt(env),
+        version: GrainlifyContract::get_version(env.clone()),
+        timelock_delay: GrainlifyContract::get_timelock_delay(env.clone()),
+        config_snapshots: GrainlifyContract::list_config_snapshots(env.clone()),
+        analytics: monitoring::get_analytics(env),
+    })
+}
+
+/// Upgrades a multisig-governed contract to `new_wasm` through the full
+/// propose → approve → timelock → execute flow and asserts that
+/// [`PreservedState`] is identical before and after the swap.
+///
+/// Approvals come from the first `threshold` signers. Mocks all auths and
+/// advances the ledger past the timelock. Returns the preserved state.
+pub fn simulate_upgrade(
+    env: &Env,
+    client: &GrainlifyContractClient,
+    new_wasm: &BytesN<32>,
+) -> PreservedState {
+    let signers = env
+        .as_contract(&client.address, || MultiSig::get_config_opt(env))
+        .expect("simulate_upgrade requires multisig governance")
+        .signers;
+
+    let proposal_id = GovernanceDriver::propose(env, client, &signers, new_wasm);
+    GovernanceDriver::approve_to_threshold(env, client, &signers, proposal_id);
+    GovernanceDriver::skip_timelock(env, client);
+
+    let mut before = capture_state(env, &client.address);
+    client.execute_upgrade(&proposal_id);
+    let after = capture_state(env, &client.address);
+    // The swap itself is the one change the upgrade is expected to make.
+    before.analytics.upgrade_count += 1;
+    assert_eq!(before, after, "state changed across the upgrade");
+    after
+}
@@ -0,0 +1,267 @@
+//! Token-holder voting engine for upgrade approval.
+//!
+//! When a [`TokenVotingConfig`] is stored, upgrade proposals are decided by
+//! holders of the configured token instead of the multisig signer set.
+//!
+//! Voting power is the holder's balance of the configured token at the
+//! proposal's snapshot ledger, read from the token's own history through
+//! [`SnapshotTokenInterface`]. Only balances held before the ledger the
+//! proposal was created in count, so tokens cannot be voted, moved to
+//! another account and voted again, nor borrowed for the proposal's own
+//! ledger. The quorum is measured against the token's total supply at the
+//! same snapshot.
+//!
+//! Implementing [`SnapshotTokenInterface`] is a hard requirement on the
+//! token. The Stellar Asset Contract and the SDK test token keep no balance
+//! history, so they cannot be configured: [`TokenVoting::set_config`]
+//! probes the token and rejects one without `total_supply_at`.
+//!
+//! Proposal IDs come from the multisig counter, so a token proposal never
+//! shares an ID with a multisig proposal or action bundle.
+
+use grainlify_upgradeable::multisig::MultiSig;
+use soroban_sdk::{contractclient, contracttype, symbol_short, Address, BytesN, Env};
+
+/// Basis-point denominator used for quorum and threshold checks.
+const BPS_DENOMINATOR: i128 = 10_000;
+
+/// =======================
+/// Storage Keys
+/// =======================
+/// Variant names must stay distinct from the multisig and contract keys
+/// that share instance storage.
+#[contracttype]
+enum DataKey {
+    VotingConfig,
+    /// Counter used before token proposals took multisig IDs; read once as
+    /// the floor for the next ID, then removed.
+    VoteCounter,
+    VoteProposal(u64),
+    Ballot(u64, Address),
+}
+
+/// =======================
+/// Voting Token Interface
+/// =======================
+/// Balance history a voting token must expose. Plain SEP-41 tokens do not
+/// implement it.
+#[contractclient(name = "SnapshotTokenClient")]
+pub trait SnapshotTokenInterface {
+    /// Balance of `id` at the start of `ledger`, before any of that
+    /// ledger's transfers.
+    fn balance_at(env: Env, id: Address, ledger: u32) -> i128;
+    /// Total supply at the start of `ledger`.
+    fn total_supply_at(env: Env, ledger: u32) -> i128;
+}
+
+/// =======================
+/// Configuration
+/// =======================
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenVotingConfig {
+    /// Token whose snapshot balances are the voting power; must implement
+    /// [`SnapshotTokenInterface`], which SEP-41 alone does not provide.
+    pub token: Address,
+    /// Seconds a proposal stays open for votes after creation.
+    pub voting_period: u64,
+    /// Minimum yes-votes as a share of the snapshot total supply, in bps.
+    pub quorum_bps: u32,
+    /// Yes-votes must exceed this share of votes cast, in bps.
+    pub threshold_bps: u32,
+}
+
+/// =======================
+/// Proposal Structure
+/// =======================
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenProposal {
+    pub proposal_id: u64,
+    pub proposer: Address,
+    /// Token the votes are counted in, fixed when the proposal opens.
+    pub token: Address,
+    /// WASM hash installed if the vote passes.
+    pub wasm_hash: BytesN<32>,
+    /// Balances held at the start of this ledger carry voting power.
+    pub snapshot_ledger: u32,
+    /// Ledger timestamp at which voting closes and execution opens.
+    pub voting_ends_at: u64,
+    pub yes_votes: i128,
+    pub no_votes: i128,
+    /// Total token supply at the snapshot, the quorum denominator.
+    pub total_supply: i128,
+    /// `quorum_bps` when the proposal opened; later config changes do not
+    /// move it.
+    pub quorum_bps: u32,
+    /// `threshold_bps` when the proposal opened.
+    pub threshold_bps: u32,
+    pub executed: bool,
+}
+
+/// =======================
+/// Public API
+/// =======================
+pub struct TokenVoting;
+
+impl TokenVoting {
+    /// Returns the active configuration; `None` means multisig governance.
+    pub fn get_config(env: &Env) -> Option<TokenVotingConfig> {
+        env.storage().instance().get(&DataKey::VotingConfig)
+    }
+
+    /// Stores or clears the configuration after validating its bounds and
+    /// that the token answers `total_supply_at`. Open proposals keep the
+    /// token, quorum and threshold they opened with.
+    pub fn set_config(env: &Env, config: Option<TokenVotingConfig>) {
+        match config {
+            Some(config) => {
+                if config.voting_period == 0 {
+                    panic!("Voting period must be non-zero");
+                }
+                if config.quorum_bps > 10_000 || config.threshold_bps > 10_000 {
+                    panic!("Quorum and threshold cannot exceed 10000 bps");
+                }
+                let probe = SnapshotTokenClient::new(env, &config.token).try_total_supply_at(&env.ledger().sequence());
+                if !matches!(probe, Ok(Ok(_))) {
+                    panic!("Voting token must implement SnapshotTokenInterface");
+                }
+                env.storage().instance().set(&DataKey::VotingConfig, &config);
+            }
+            None => env.storage().instance().remove(&DataKey::VotingConfig),
+        }
+    }
+
+    /// Opens a voting window for `wasm_hash` and returns the proposal ID.
+    /// The proposer must hold tokens at the snapshot.
+    pub fn propose(env: &Env, proposer: Address, wasm_hash: BytesN<32>) -> u64 {
+        proposer.require_auth();
+        let config = Self::require_config(env);
+        let token = SnapshotTokenClient::new(env, &config.token);
+        let snapshot_ledger = env.ledger().sequence();
+        if token.balance_at(&proposer, &snapshot_ledger) <= 0 {
+            panic!("No voting power");
+        }
+
+        let legacy_counter: u64 = env.storage().instance().get(&DataKey::VoteCounter).unwrap_or(0);
+        let proposal_id = MultiSig::reserve_proposal_id(env, legacy_counter);
+        env.storage().instance().remove(&DataKey::VoteCounter);
+        let proposal = TokenProposal {
+            proposal_id,
+            proposer: proposer.clone(),
+            token: config.token.clone(),
+            wasm_hash,
+            snapshot_ledger,
+            voting_ends_at: env.ledger().timestamp().saturating_add(config.voting_period),
+            yes_votes: 0,
+            no_votes: 0,
+            total_supply: token.total_supply_at(&snapshot_ledger),
+            quorum_bps: config.quorum_bps,
+            threshold_bps: config.threshold_bps,
+            executed: false,
+        };
+        env.storage()
+            .persistent()
+            .set(&DataKey::VoteProposal(proposal_id), &proposal);
+
+        env.events().publish(
+            (symbol_short!("tgov"), symbol_short!("propose")),
+            (proposal_id, proposer, proposal.voting_ends_at),
+        );
+        proposal_id
+    }
+
+    /// Records a vote weighted by the voter's balance at the snapshot.
+    pub fn vote(env: &Env, proposal_id: u64, voter: Address, support: bool) {
+        voter.require_auth();
+        let mut proposal = Self::require_proposal(env, proposal_id);
+        if env.ledger().timestamp() >= proposal.voting_ends_at {
+            panic!("Voting period has ended");
+        }
+        let vote_key = DataKey::Ballot(proposal_id, voter.clone());
+        if env.storage().persistent().has(&vote_key) {
+            panic!("Already voted");
+        }
+        let power = SnapshotTokenClient::new(env, &proposal.token).balance_at(&voter, &proposal.snapshot_ledger);
+        if power <= 0 {
+            panic!("No voting power");
+        }
+
+        if support {
+            proposal.yes_votes += power;
+        } else {
+            proposal.no_votes += power;
+        }
+        env.storage().persistent().set(&vote_key, &support);
+        env.storage()
+            .persistent()
+            .set(&DataKey::VoteProposal(proposal_id), &proposal);
+
+        env.events().publish(
+            (symbol_short!("tgov"), symbol_short!("vote")),
+            (proposal_id, voter, support, power),
+        );
+    }
+
+    /// Returns whether `voter` already voted on `proposal_id`.
+    pub fn has_voted(env: &Env, proposal_id: u64, voter: Address) -> bool {
+        env.storage()
+            .persistent()
+            .has(&DataKey::Ballot(proposal_id, voter))
+    }
+
+    /// Returns a proposal if present.
+    pub fn get_proposal(env: &Env, proposal_id: u64) -> Option<TokenProposal> {
+        env.storage().persistent().get(&DataKey::VoteProposal(proposal_id))
+    }
+
+    /// Verifies the closed vote passed, marks the proposal executed, and
+    /// returns the WASM hash to install.
+    pub fn finalize(env: &Env, proposal_id: u64) -> BytesN<32> {
+        Self::require_config(env);
+        let mut proposal = Self::require_proposal(env, proposal_id);
+        if let Err(reason) = Self::check_finalizable(env, &proposal) {
+            panic!("{}", reason);
+        }
+
+        proposal.executed = true;
+        env.storage()
+            .persistent()
+            .set(&DataKey::VoteProposal(proposal_id), &proposal);
+        proposal.wasm_hash
+    }
+
+    /// Checks `finalize` applies to `proposal`, returning the reason it
+    /// would panic otherwise. Quorum and threshold are the proposal's own,
+    /// fixed when it opened.
+    pub fn check_finalizable(env: &Env, proposal: &TokenProposal) -> Result<(), &'static str> {
+        Self::require_config(env);
+        if proposal.executed {
+            return Err("Proposal already executed");
+        }
+        if env.ledger().timestamp() < proposal.voting_ends_at {
+            return Err("Voting period still open");
+        }
+
+        let yes = proposal.yes_votes.saturating_mul(BPS_DENOMINATOR);
+        let quorum = proposal
+            .total_supply
+            .saturating_mul(proposal.quorum_bps as i128);
+        if proposal.yes_votes == 0 || yes < quorum {
+            return Err("Quorum not met");
+        }
+        let cast = proposal.yes_votes + proposal.no_votes;
+        if yes <= cast.saturating_mul(proposal.threshold_bps as i128) {
+            return Err("Vote threshold not met");
+        }
+        Ok(())
+    }
+
+    fn require_config(env: &Env) -> TokenVotingConfig {
+        Self::get_config(env).unwrap_or_else(|| panic!("Token voting not configured"))
+    }
+
+    fn require_proposal(env: &Env, proposal_id: u64) -> TokenProposal {
+        Self::get_proposal(env, proposal_id).unwrap_or_else(|| panic!("Proposal not found"))
+    }
+}
@@ -0,0 +1,45 @@
+;; Source of `v2_test_contract.wasm`, the "v2" module installed by
+;; `testutils::install_test_wasm`.
+;;
+;; Entrypoints:
+;;   v2_only() -> u32                  returns 2; absent from v1
+;;   get_version() -> u32              reads the v1 `DataKey::Version` entry
+;;   migrate(target_version: u32)      overwrites `DataKey::Version`
+;;
+;; Constants are raw Soroban `Val`s:
+;;   8589934596       U32Val(2)            (2 << 32 | tag 4)
+;;   592327041954574  SymbolSmall("Version") (6-bit chars << 8 | tag 14)
+;;   2                StorageType::Instance
+;;
+;; The binary additionally carries the `contractenvmetav0` custom section the
+;; host requires, pinning the interface version to protocol 21:
+;;   00 00 00 00  00 00 00 15  00 00 00 00
+(module
+  (import "v" "_" (func $vec_new (result i64)))
+  (import "v" "6" (func $vec_push_back (param i64 i64) (result i64)))
+  (import "l" "1" (func $get_contract_data (param i64 i64) (result i64)))
+  (import "l" "_" (func $put_contract_data (param i64 i64 i64) (result i64)))
+
+  ;; `DataKey::Version` encodes as the single-element vector [Symbol("Version")].
+  (func $version_key (result i64)
+    call $vec_new
+    i64.const 592327041954574
+    call $vec_push_back)
+
+  (func $v2_only (result i64)
+    i64.const 8589934596)
+
+  (func $get_version (result i64)
+    call $version_key
+    i64.const 2
+    call $get_contract_data)
+
+  (func $migrate (param $target_version i64) (result i64)
+    call $version_key
+    local.get $target_version
+    i64.const 2
+    call $put_contract_data)
+
+  (export "v2_only" (func $v2_only))
+  (export "get_version" (func $get_version))
+  (export "migrate" (func $migrate)))
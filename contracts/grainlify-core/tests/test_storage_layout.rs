@@ -1,3 +1,5 @@
+#![cfg(feature = "contract")]
+
 use grainlify_core::{GrainlifyContract, STORAGE_SCHEMA_VERSION};
 use soroban_sdk::{testutils::Address as _, Address, Env};
 
@@ -12,7 +14,7 @@ fn setup_test(env: &Env) -> Address {
 
 #[test]
 fn test_storage_schema_version_constant() {
-    assert_eq!(STORAGE_SCHEMA_VERSION, 1);
+    assert_eq!(STORAGE_SCHEMA_VERSION, 2);
 }
 
 #[test]
@@ -0,0 +1,263 @@
+#![no_std]
+//! # Grainlify Factory
+//!
+//! Deploys Grainlify-governed contracts and initializes them **in the same
+//! invocation**, closing the window between "deploy" and "init" during which
+//! anyone could call the public `init` entrypoint first and seize governance.
+//!
+//! ## Deployment Flow
+//!
+//! ```text
+//! 1. Upload the grainlify-core WASM and note its hash
+//! 2. Deploy this factory from an account or contract with a salt, and call
+//!    init(that deployer, that salt, owner) — owner is optional
+//! 3. Call deploy(deployer, wasm_hash, salt, signers, threshold)
+//!      ├─ deployer().with_current_contract(effective salt).deploy(wasm_hash)
+//!      ├─ <new contract>.init(signers, threshold)
+//!      └─ append the address to the registry, emit ("factory", "deployed")
+//! 4. Enumerate instances with deployment_count() / list_deployments(start, limit)
+//! ```
+//!
+//! The SDK version used by this workspace (21.x) has no constructor support,
+//! so `init` is invoked explicitly right after `deploy`. Both happen inside
+//! one contract call: if `init` fails the deployment is rolled back with it.
+//!
+//! ## Access Control
+//!
+//! - Only the address that deployed the factory may call `init`: it must
+//!   authorize, and the factory's own address must derive from it and the
+//!   salt it deployed with, so nobody can front-run `init` to take the
+//!   owner slot.
+//! - With an owner configured, only the owner may call `deploy`, and the
+//!   salt it passes is used as is.
+//! - Without an owner, deployment is permissionless. The salt is namespaced
+//!   per caller as `sha256(deployer || salt)`, so one caller cannot take, or
+//!   front-run, an address another caller derived from the same salt.
+//!
+//! ## Registry
+//!
+//! Deployed addresses are stored one per persistent slot, keyed by their
+//! zero-based deployment index, so pagination never loads the full list.
+//! Each slot's TTL is extended to [`DEPLOYMENT_TTL_EXTEND_TO`] when written.
+
+use soroban_sdk::{
+    contract, contracterror, contractimpl, contracttype, symbol_short, xdr::ToXdr, Address,
+    BytesN, Env, IntoVal, Symbol, Vec,
+};
+
+// ============================================================================
+// Error Type
+// ============================================================================
+
+/// Typed error codes returned by fallible factory entrypoints.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum FactoryError {
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+    InvalidThreshold = 3,
+    NotOwner = 4,
+    /// `init` was called by an address that did not deploy the factory.
+    NotDeployer = 5,
+}
+
+// ============================================================================
+// Storage Keys
+// ============================================================================
+
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    /// Marks the factory as initialized (instance storage).
+    Initialized,
+    /// Optional owner allowed to deploy (instance storage).
+    Owner,
+    /// Number of instances deployed so far (instance storage).
+    DeploymentCount,
+    /// Address of the deployment at the given index (persistent storage).
+    Deployment(u32),
+}
+
+/// Maximum page size accepted by [`GrainlifyFactory::list_deployments`].
+pub const MAX_PAGE_SIZE: u32 = 100;
+
+/// Ledgers (~30 days) a registry slot's TTL is extended to when written.
+pub const DEPLOYMENT_TTL_EXTEND_TO: u32 = 17_280 * 30;
+
+// ============================================================================
+// Events
+// ============================================================================
+
+/// Published under `("factory", "deployed")` after a deployment is
+/// initialized and recorded.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DeployedEvent {
+    pub address: Address,
+    pub index: u32,
+    pub deployer: Address,
+    pub wasm_hash: BytesN<32>,
+    /// Salt as passed to `deploy`, before any per-caller namespacing.
+    pub salt: BytesN<32>,
+    pub signers: Vec<Address>,
+    pub threshold: u32,
+    pub timestamp: u64,
+}
+
+// ============================================================================
+// Contract
+// ============================================================================
+
+#[contract]
+pub struct GrainlifyFactory;
+
+#[contractimpl]
+impl GrainlifyFactory {
+    /// Initializes the factory. `deployer` and `salt` are the address and
+    /// salt this factory was deployed with; `deployer` must authorize.
+    /// Pass `Some(owner)` to restrict `deploy` to that address, or `None`
+    /// for permissionless deployment.
+    pub fn init(
+        env: Env,
+        deployer: Address,
+        salt: BytesN<32>,
+        owner: Option<Address>,
+    ) -> Result<(), FactoryError> {
+        if env.storage().instance().has(&DataKey::Initialized) {
+            return Err(FactoryError::AlreadyInitialized);
+        }
+        let derived = env
+            .deployer()
+            .with_address(deployer.clone(), salt)
+            .deployed_address();
+        if derived != env.current_contract_address() {
+            return Err(FactoryError::NotDeployer);
+        }
+        deployer.require_auth();
+        if let Some(owner) = &owner {
+            owner.require_auth();
+            env.storage().instance().set(&DataKey::Owner, owner);
+        }
+        env.storage().instance().set(&DataKey::Initialized, &true);
+        env.storage().instance().set(&DataKey::DeploymentCount, &0u32);
+        Ok(())
+    }
+
+    /// Returns the owner restricting deployments, if any.
+    pub fn get_owner(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::Owner)
+    }
+
+    /// Deploys `wasm_hash` at the address derived from this factory and
+    /// `salt` (namespaced by `deployer` when permissionless), initializes it
+    /// with the multisig `signers` / `threshold`, and records it in the
+    /// registry. `deployer` must authorize, and must be the owner if one is
+    /// configured. Returns the new contract address.
+    pub fn deploy(
+        env: Env,
+        deployer: Address,
+        wasm_hash: BytesN<32>,
+        salt: BytesN<32>,
+        signers: Vec<Address>,
+        threshold: u32,
+    ) -> Result<Address, FactoryError> {
+        if !env.storage().instance().has(&DataKey::Initialized) {
+            return Err(FactoryError::NotInitialized);
+        }
+        if let Some(owner) = Self::get_owner(env.clone()) {
+            if deployer != owner {
+                return Err(FactoryError::NotOwner);
+            }
+        }
+        deployer.require_auth();
+        if threshold == 0 || threshold > signers.len() {
+            return Err(FactoryError::InvalidThreshold);
+        }
+
+        let address = env
+            .deployer()
+            .with_current_contract(Self::effective_salt(&env, &deployer, &salt))
+            .deploy(wasm_hash.clone());
+        env.invoke_contract::<()>(
+            &address,
+            &Symbol::new(&env, "init"),
+            (signers.clone(), threshold).into_val(&env),
+        );
+
+        let index = Self::deployment_count(env.clone());
+        let key = DataKey::Deployment(index);
+        env.storage().persistent().set(&key, &address);
+        env.storage().persistent().extend_ttl(
+            &key,
+            DEPLOYMENT_TTL_EXTEND_TO,
+            DEPLOYMENT_TTL_EXTEND_TO,
+        );
+        env.storage()
+            .instance()
+            .set(&DataKey::DeploymentCount, &(index + 1));
+
+        env.events().publish(
+            (symbol_short!("factory"), symbol_short!("deployed")),
+            DeployedEvent {
+                address: address.clone(),
+                index,
+                deployer,
+                wasm_hash,
+                salt,
+                signers,
+                threshold,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Ok(address)
+    }
+
+    /// Returns the address a `deploy` call by `deployer` with `salt` would
+    /// produce.
+    pub fn deployed_address(env: Env, deployer: Address, salt: BytesN<32>) -> Address {
+        let salt = Self::effective_salt(&env, &deployer, &salt);
+        env.deployer().with_current_contract(salt).deployed_address()
+    }
+
+    /// Number of contracts deployed through this factory.
+    pub fn deployment_count(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::DeploymentCount)
+            .unwrap_or(0)
+    }
+
+    /// Returns up to `limit` deployed addresses starting at index `start`, in
+    /// deployment order. `limit` is capped at [`MAX_PAGE_SIZE`].
+    pub fn list_deployments(env: Env, start: u32, limit: u32) -> Vec<Address> {
+        let count = Self::deployment_count(env.clone());
+        let end = start
+            .saturating_add(limit.min(MAX_PAGE_SIZE))
+            .min(count);
+        let mut page = Vec::new(&env);
+        for index in start..end {
+            if let Some(address) = env
+                .storage()
+                .persistent()
+                .get(&DataKey::Deployment(index))
+            {
+                page.push_back(address);
+            }
+        }
+        page
+    }
+
+    /// `salt` itself when an owner is configured, otherwise
+    /// `sha256(deployer XDR || salt)`.
+    fn effective_salt(env: &Env, deployer: &Address, salt: &BytesN<32>) -> BytesN<32> {
+        if env.storage().instance().has(&DataKey::Owner) {
+            return salt.clone();
+        }
+        let mut preimage = deployer.clone().to_xdr(env);
+        preimage.append(&salt.clone().into());
+        env.crypto().sha256(&preimage).into()
+    }
+}
+
+mod test;
@@ -0,0 +1,372 @@
+#![cfg(test)]
+
+extern crate std;
+
+use std::rc::Rc;
+
+use grainlify_core::{GrainlifyContract, GrainlifyContractClient};
+use soroban_sdk::{
+    testutils::{storage::Persistent as _, Address as _, Events},
+    vec,
+    xdr::{ContractDataDurability, LedgerKey, LedgerKeyContractData, ScVal},
+    Address, Bytes, BytesN, Env, Symbol, TryFromVal,
+};
+
+use crate::{
+    DataKey, DeployedEvent, FactoryError, GrainlifyFactory, GrainlifyFactoryClient,
+    DEPLOYMENT_TTL_EXTEND_TO,
+};
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// Registers the factory at the address `deployer` derives with `salt`,
+/// as deploying it from `deployer` would.
+fn register_factory<'a>(
+    env: &'a Env,
+    deployer: &Address,
+    salt: &BytesN<32>,
+) -> GrainlifyFactoryClient<'a> {
+    let id = env
+        .deployer()
+        .with_address(deployer.clone(), salt.clone())
+        .deployed_address();
+    env.register_contract(&id, GrainlifyFactory);
+    GrainlifyFactoryClient::new(env, &id)
+}
+
+fn setup(env: &Env, owner: Option<Address>) -> GrainlifyFactoryClient<'_> {
+    let factory_deployer = Address::generate(env);
+    let factory_salt = salt(env, 0);
+    let factory = register_factory(env, &factory_deployer, &factory_salt);
+    env.mock_all_auths();
+    factory.init(&factory_deployer, &factory_salt, &owner);
+    factory
+}
+
+fn salt(env: &Env, n: u8) -> BytesN<32> {
+    BytesN::from_array(env, &[n; 32])
+}
+
+/// Prepares the address the factory will deploy to for `deployer` and `salt`
+/// so that calls
+/// into it dispatch to the native `GrainlifyContract` implementation.
+///
+/// Host tests cannot build the grainlify-core WASM, so the native contract is
+/// registered at the predicted address and its instance entry is then dropped,
+/// leaving only the native dispatch table. The factory's `deploy` recreates the
+/// instance with the empty-WASM executable that registration uploaded, which
+/// the test host routes to the native implementation. Returns that hash.
+fn prepare_native_core(
+    env: &Env,
+    factory: &GrainlifyFactoryClient,
+    deployer: &Address,
+    salt: &BytesN<32>,
+) -> BytesN<32> {
+    let address = factory.deployed_address(deployer, salt);
+    env.register_contract(&address, GrainlifyContract);
+
+    let key = Rc::new(LedgerKey::ContractData(LedgerKeyContractData {
+        contract: (&address).into(),
+        key: ScVal::LedgerKeyContractInstance,
+        durability: ContractDataDurability::Persistent,
+    }));
+    let budget = env.host().budget_cloned();
+    env.host()
+        .with_mut_storage(|storage| storage.del(&key, &budget))
+        .unwrap();
+
+    env.crypto().sha256(&Bytes::new(env)).into()
+}
+
+fn deployed_events(env: &Env) -> std::vec::Vec<DeployedEvent> {
+    let mut out = std::vec::Vec::new();
+    for (_, topics, data) in env.events().all().iter() {
+        if topics.len() != 2 {
+            continue;
+        }
+        let t1 = Symbol::try_from_val(env, &topics.get(1).unwrap());
+        if t1 == Ok(Symbol::new(env, "deployed")) {
+            out.push(DeployedEvent::try_from_val(env, &data).unwrap());
+        }
+    }
+    out
+}
+
+// ---------------------------------------------------------------------------
+// Deployment
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_deploy_initializes_governance_in_same_call() {
+    let env = Env::default();
+    let factory = setup(&env, None);
+    let deployer = Address::generate(&env);
+    let s1 = Address::generate(&env);
+    let s2 = Address::generate(&env);
+    let salt = salt(&env, 1);
+    let wasm_hash = prepare_native_core(&env, &factory, &deployer, &salt);
+
+    let signers = vec![&env, s1.clone(), s2.clone()];
+    let address = factory.deploy(&deployer, &wasm_hash, &salt, &signers, &2);
+    assert_eq!(address, factory.deployed_address(&deployer, &salt));
+
+    let core = GrainlifyContractClient::new(&env, &address);
+    assert_eq!(core.get_version(), 2);
+    assert!(
+        core.try_init(&vec![&env, Address::generate(&env)], &1).is_err(),
+        "instance must already be initialized by the factory"
+    );
+
+    let event = deployed_events(&env).pop().expect("deployed event");
+    assert_eq!(event.address, address);
+    assert_eq!(event.index, 0);
+    assert_eq!(event.deployer, deployer);
+    assert_eq!(event.signers, vec![&env, s1, s2]);
+    assert_eq!(event.threshold, 2);
+}
+
+#[test]
+fn test_deployed_instance_governance_works() {
+    let env = Env::default();
+    let factory = setup(&env, None);
+    let deployer = Address::generate(&env);
+    let s1 = Address::generate(&env);
+    let s2 = Address::generate(&env);
+    let salt = salt(&env, 2);
+    let wasm_hash = prepare_native_core(&env, &factory, &deployer, &salt);
+    let signers = vec![&env, s1.clone(), s2.clone()];
+    let address = factory.deploy(&deployer, &wasm_hash, &salt, &signers, &2);
+    let core = GrainlifyContractClient::new(&env, &address);
+
+    // Signers can pause; lifting the pause needs both approvals.
+    core.pause(&s1);
+    assert!(core.is_paused());
+    let proposal_id = core.propose_unpause(&s1, &0u64);
+    core.approve_unpause(&proposal_id, &s1);
+    assert!(core.is_paused());
+    core.approve_unpause(&proposal_id, &s2);
+    assert!(!core.is_paused());
+
+    // Outsiders are not part of the configured multisig.
+    let stranger = Address::generate(&env);
+    assert!(core
+        .try_propose_upgrade(&stranger, &BytesN::from_array(&env, &[7; 32]), &0u64)
+        .is_err());
+}
+
+#[test]
+fn test_reusing_salt_fails() {
+    let env = Env::default();
+    let factory = setup(&env, None);
+    let deployer = Address::generate(&env);
+    let signers = vec![&env, Address::generate(&env)];
+    let salt = salt(&env, 3);
+    let wasm_hash = prepare_native_core(&env, &factory, &deployer, &salt);
+
+    factory.deploy(&deployer, &wasm_hash, &salt, &signers, &1);
+    assert!(factory.try_deploy(&deployer, &wasm_hash, &salt, &signers, &1).is_err());
+    assert_eq!(factory.deployment_count(), 1);
+}
+
+#[test]
+fn test_invalid_threshold_rejected() {
+    let env = Env::default();
+    let factory = setup(&env, None);
+    let deployer = Address::generate(&env);
+    let signers = vec![&env, Address::generate(&env)];
+    let wasm_hash = BytesN::from_array(&env, &[0; 32]);
+
+    assert_eq!(
+        factory.try_deploy(&deployer, &wasm_hash, &salt(&env, 4), &signers, &0),
+        Err(Ok(FactoryError::InvalidThreshold))
+    );
+    assert_eq!(
+        factory.try_deploy(&deployer, &wasm_hash, &salt(&env, 4), &signers, &2),
+        Err(Ok(FactoryError::InvalidThreshold))
+    );
+    assert_eq!(factory.deployment_count(), 0);
+}
+
+// ---------------------------------------------------------------------------
+// Initialization and access control
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_deploy_requires_init() {
+    let env = Env::default();
+    let id = env.register_contract(None, GrainlifyFactory);
+    let factory = GrainlifyFactoryClient::new(&env, &id);
+    let signers = vec![&env, Address::generate(&env)];
+
+    assert_eq!(
+        factory.try_deploy(
+            &Address::generate(&env),
+            &BytesN::from_array(&env, &[0; 32]),
+            &salt(&env, 5),
+            &signers,
+            &1,
+        ),
+        Err(Ok(FactoryError::NotInitialized))
+    );
+}
+
+#[test]
+fn test_double_init_rejected() {
+    let env = Env::default();
+    let factory = setup(&env, None);
+    assert_eq!(
+        factory.try_init(&Address::generate(&env), &salt(&env, 0), &Some(Address::generate(&env))),
+        Err(Ok(FactoryError::AlreadyInitialized))
+    );
+    assert_eq!(factory.get_owner(), None);
+}
+
+#[test]
+fn test_init_rejects_front_runner() {
+    let env = Env::default();
+    let deployer = Address::generate(&env);
+    let factory = register_factory(&env, &deployer, &salt(&env, 0));
+    env.mock_all_auths();
+
+    let attacker = Address::generate(&env);
+    assert_eq!(
+        factory.try_init(&attacker, &salt(&env, 0), &Some(attacker.clone())),
+        Err(Ok(FactoryError::NotDeployer))
+    );
+    assert_eq!(
+        factory.try_init(&deployer, &salt(&env, 1), &Some(attacker)),
+        Err(Ok(FactoryError::NotDeployer))
+    );
+
+    factory.init(&deployer, &salt(&env, 0), &None);
+    assert_eq!(factory.get_owner(), None);
+}
+
+#[test]
+fn test_init_requires_deployer_auth() {
+    let env = Env::default();
+    let deployer = Address::generate(&env);
+    let factory = register_factory(&env, &deployer, &salt(&env, 0));
+    assert!(factory.try_init(&deployer, &salt(&env, 0), &None).is_err());
+    assert_eq!(factory.deployment_count(), 0);
+}
+
+#[test]
+fn test_owner_must_authorize_deploy() {
+    let env = Env::default();
+    let owner = Address::generate(&env);
+    let factory = setup(&env, Some(owner.clone()));
+    assert_eq!(factory.get_owner(), Some(owner.clone()));
+
+    let signers = vec![&env, Address::generate(&env)];
+    let salt = salt(&env, 6);
+    let wasm_hash = prepare_native_core(&env, &factory, &owner, &salt);
+
+    env.set_auths(&[]);
+    assert!(factory.try_deploy(&owner, &wasm_hash, &salt, &signers, &1).is_err());
+    assert_eq!(factory.deployment_count(), 0);
+
+    env.mock_all_auths();
+    factory.deploy(&owner, &wasm_hash, &salt, &signers, &1);
+    assert_eq!(factory.deployment_count(), 1);
+}
+
+#[test]
+fn test_owner_mode_rejects_other_deployers() {
+    let env = Env::default();
+    let owner = Address::generate(&env);
+    let factory = setup(&env, Some(owner.clone()));
+    let signers = vec![&env, Address::generate(&env)];
+    let wasm_hash = BytesN::from_array(&env, &[0; 32]);
+
+    assert_eq!(
+        factory.try_deploy(&Address::generate(&env), &wasm_hash, &salt(&env, 7), &signers, &1),
+        Err(Ok(FactoryError::NotOwner))
+    );
+    // The owner's salt is used as is, so its addresses do not depend on the caller.
+    assert_eq!(
+        factory.deployed_address(&owner, &salt(&env, 7)),
+        factory.deployed_address(&Address::generate(&env), &salt(&env, 7))
+    );
+}
+
+#[test]
+fn test_permissionless_salt_is_namespaced_per_deployer() {
+    let env = Env::default();
+    let factory = setup(&env, None);
+    let alice = Address::generate(&env);
+    let mallory = Address::generate(&env);
+    let signers = vec![&env, Address::generate(&env)];
+    let salt = salt(&env, 8);
+    assert_ne!(
+        factory.deployed_address(&alice, &salt),
+        factory.deployed_address(&mallory, &salt)
+    );
+
+    // Mallory deploying with Alice's salt first does not take Alice's address.
+    let wasm_hash = prepare_native_core(&env, &factory, &mallory, &salt);
+    factory.deploy(&mallory, &wasm_hash, &salt, &signers, &1);
+    let wasm_hash = prepare_native_core(&env, &factory, &alice, &salt);
+    let address = factory.deploy(&alice, &wasm_hash, &salt, &signers, &1);
+    assert_eq!(address, factory.deployed_address(&alice, &salt));
+    assert_eq!(factory.deployment_count(), 2);
+}
+
+#[test]
+fn test_permissionless_deploy_requires_deployer_auth() {
+    let env = Env::default();
+    let factory = setup(&env, None);
+    let deployer = Address::generate(&env);
+    let signers = vec![&env, Address::generate(&env)];
+    let salt = salt(&env, 9);
+    let wasm_hash = prepare_native_core(&env, &factory, &deployer, &salt);
+
+    env.set_auths(&[]);
+    assert!(factory.try_deploy(&deployer, &wasm_hash, &salt, &signers, &1).is_err());
+    assert_eq!(factory.deployment_count(), 0);
+}
+
+// ---------------------------------------------------------------------------
+// Registry
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_list_deployments_paginates_in_order() {
+    let env = Env::default();
+    let factory = setup(&env, None);
+    let deployer = Address::generate(&env);
+    let signers = vec![&env, Address::generate(&env)];
+
+    let mut deployed = std::vec::Vec::new();
+    for n in 10..13u8 {
+        let salt = salt(&env, n);
+        let wasm_hash = prepare_native_core(&env, &factory, &deployer, &salt);
+        deployed.push(factory.deploy(&deployer, &wasm_hash, &salt, &signers, &1));
+    }
+
+    assert_eq!(factory.deployment_count(), 3);
+    assert_eq!(
+        factory.list_deployments(&0, &2),
+        vec![&env, deployed[0].clone(), deployed[1].clone()]
+    );
+    assert_eq!(factory.list_deployments(&2, &10), vec![&env, deployed[2].clone()]);
+    assert_eq!(factory.list_deployments(&3, &10).len(), 0);
+    assert_eq!(factory.list_deployments(&u32::MAX, &u32::MAX).len(), 0);
+}
+
+#[test]
+fn test_registry_slots_outlive_default_ttl() {
+    let env = Env::default();
+    let factory = setup(&env, None);
+    let deployer = Address::generate(&env);
+    let salt = salt(&env, 14);
+    let wasm_hash = prepare_native_core(&env, &factory, &deployer, &salt);
+    factory.deploy(&deployer, &wasm_hash, &salt, &vec![&env, Address::generate(&env)], &1);
+
+    let ttl = env.as_contract(&factory.address, || {
+        env.storage().persistent().get_ttl(&DataKey::Deployment(0))
+    });
+    assert_eq!(ttl, DEPLOYMENT_TTL_EXTEND_TO);
+}
@@ -0,0 +1,101 @@
+#![no_std]
+//! Reference companion metrics contract.
+//!
+//! `GrainlifyContract` forwards its tracked calls here once governance
+//! points `set_metrics_contract` at an instance, so metric changes ship by
+//! redeploying this contract instead of upgrading the core. Only the core
+//! the instance was initialized for may record.
+
+use grainlify_upgradeable::MetricsSink;
+use soroban_sdk::{contract, contractimpl, contracttype, Address, Env, Symbol};
+
+/// Storage keys.
+#[contracttype]
+#[derive(Clone)]
+pub enum MetricsKey {
+    /// Address of the contract allowed to record (instance)
+    Core,
+    /// `CallTotals` across every operation (persistent)
+    Totals,
+    /// `CallStats` of one operation (persistent)
+    Operation(Symbol),
+}
+
+/// Counters of one operation.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct CallStats {
+    pub call_count: u64,
+    pub error_count: u64,
+    pub total_time: u64,
+    pub last_called: u64,
+}
+
+/// Counters across every operation.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct CallTotals {
+    pub call_count: u64,
+    pub error_count: u64,
+}
+
+#[contract]
+pub struct MetricsContract;
+
+#[contractimpl]
+impl MetricsContract {
+    /// One-time initialization with the contract whose calls are recorded.
+    pub fn init(env: Env, core: Address) {
+        if env.storage().instance().has(&MetricsKey::Core) {
+            panic!("Already initialized");
+        }
+        env.storage().instance().set(&MetricsKey::Core, &core);
+    }
+
+    pub fn get_core(env: Env) -> Option<Address> {
+        env.storage().instance().get(&MetricsKey::Core)
+    }
+
+    pub fn get_totals(env: Env) -> CallTotals {
+        env.storage().persistent().get(&MetricsKey::Totals).unwrap_or_default()
+    }
+
+    /// Counters of `operation`, zeroed if it was never recorded.
+    pub fn get_call_stats(env: Env, operation: Symbol) -> CallStats {
+        env.storage()
+            .persistent()
+            .get(&MetricsKey::Operation(operation))
+            .unwrap_or_default()
+    }
+}
+
+#[contractimpl]
+impl MetricsSink for MetricsContract {
+    /// Requires the core's authorization, which a direct call from the core
+    /// carries implicitly.
+    fn record_call(env: Env, operation: Symbol, _caller: Address, success: bool, duration: u64) {
+        let core: Address = env
+            .storage()
+            .instance()
+            .get(&MetricsKey::Core)
+            .unwrap_or_else(|| panic!("Not initialized"));
+        core.require_auth();
+
+        let mut totals = Self::get_totals(env.clone());
+        totals.call_count = totals.call_count.saturating_add(1);
+        let key = MetricsKey::Operation(operation);
+        let mut stats: CallStats = env.storage().persistent().get(&key).unwrap_or_default();
+        stats.call_count = stats.call_count.saturating_add(1);
+        stats.total_time = stats.total_time.saturating_add(duration);
+        stats.last_called = env.ledger().timestamp();
+        if !success {
+            totals.error_count = totals.error_count.saturating_add(1);
+            stats.error_count = stats.error_count.saturating_add(1);
+        }
+        env.storage().persistent().set(&key, &stats);
+        env.storage().persistent().set(&MetricsKey::Totals, &totals);
+    }
+}
+
+#[cfg(test)]
+mod test;
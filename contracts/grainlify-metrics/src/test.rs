@@ -0,0 +1,48 @@
+extern crate std;
+
+use soroban_sdk::{symbol_short, testutils::Address as _, testutils::Ledger as _, Address, Env};
+
+use crate::{CallStats, CallTotals, MetricsContract, MetricsContractClient};
+
+fn setup(env: &Env) -> (MetricsContractClient<'_>, Address) {
+    let client = MetricsContractClient::new(env, &env.register_contract(None, MetricsContract));
+    let core = Address::generate(env);
+    client.init(&core);
+    (client, core)
+}
+
+#[test]
+fn test_record_call_accumulates() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _core) = setup(&env);
+    let caller = Address::generate(&env);
+    env.ledger().set_timestamp(40);
+
+    client.record_call(&symbol_short!("set_ver"), &caller, &true, &3);
+    client.record_call(&symbol_short!("set_ver"), &caller, &false, &5);
+    client.record_call(&symbol_short!("upgrade"), &caller, &true, &0);
+
+    assert_eq!(
+        client.get_call_stats(&symbol_short!("set_ver")),
+        CallStats { call_count: 2, error_count: 1, total_time: 8, last_called: 40 }
+    );
+    assert_eq!(client.get_totals(), CallTotals { call_count: 3, error_count: 1 });
+    assert_eq!(client.get_call_stats(&symbol_short!("other")), CallStats::default());
+}
+
+#[test]
+fn test_record_call_requires_core_auth() {
+    let env = Env::default();
+    let (client, _core) = setup(&env);
+    let result = client.try_record_call(&symbol_short!("set_ver"), &Address::generate(&env), &true, &0);
+    assert!(result.is_err());
+}
+
+#[test]
+#[should_panic(expected = "Already initialized")]
+fn test_init_once() {
+    let env = Env::default();
+    let (client, core) = setup(&env);
+    client.init(&core);
+}
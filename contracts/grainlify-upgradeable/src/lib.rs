@@ -0,0 +1,304 @@
+#![no_std]
+//! Reusable upgrade, version, and multisig governance surface.
+//!
+//! `GrainlifyContract` in `grainlify-core` is the reference consumer. Other
+//! contracts adopt the same pattern by calling these helpers from their own
+//! `#[contractimpl]` and implementing [`Upgradeable`]:
+//!
+//! ```ignore
+//! #[contractimpl]
+//! impl Upgradeable for MyContract {
+//!     fn upgrade(env: Env, new_wasm_hash: BytesN<32>) {
+//!         grainlify_upgradeable::require_governance(&env);
+//!         grainlify_upgradeable::perform_upgrade(&env, &new_wasm_hash);
+//!     }
+//!
+//!     fn get_version(env: Env) -> u32 {
+//!         grainlify_upgradeable::get_version(&env)
+//!     }
+//! }
+//! ```
+//!
+//! State lives under [`UpgradeKey`]: per-proposal entries in persistent
+//! storage, everything else in instance storage. A consuming
+//! contract must not reuse those variant names in its own `#[contracttype]`
+//! key enum for anything else: keys with the same variant name encode to the
+//! same storage entry regardless of the enum they belong to.
+
+use soroban_sdk::{contracterror, contracttype, symbol_short, Address, BytesN, Env, Vec};
+
+pub mod metrics;
+pub mod multisig;
+pub use metrics::{MetricsSink, MetricsSinkClient};
+pub use multisig::{MultiSig, MultiSigConfig, SignerStats};
+use multisig::MultiSigError;
+
+/// =======================
+/// Errors
+/// =======================
+/// Codes match the core contract's `ContractError` so callers see the same
+/// failures whichever contract they talk to.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum UpgradeError {
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+}
+
+/// =======================
+/// Storage Keys
+/// =======================
+#[contracttype]
+#[derive(Clone)]
+pub enum UpgradeKey {
+    /// Address allowed to perform single-admin upgrades.
+    Admin,
+    /// Current contract version; its presence marks the contract initialized.
+    Version,
+    /// Version recorded immediately before the last upgrade.
+    PreviousVersion,
+    /// WASM hash stored per multisig upgrade proposal (persistent).
+    UpgradeProposal(u64),
+    /// Set once proposal entries live in persistent storage.
+    ProposalsRelocated,
+    /// Next proposal ID [`relocate_legacy_proposals`] will scan while a
+    /// relocation is under way.
+    RelocationCursor,
+    /// `ExecutorRecord` of an upgrade proposal executed through
+    /// [`take_approved_upgrade_by`] (persistent).
+    UpgradeExecutor(u64),
+}
+
+/// Who executed an upgrade proposal and in which ledger.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ExecutorRecord {
+    pub executor: Address,
+    pub ledger_sequence: u32,
+}
+
+/// Upgrade history entry: the installed hash plus the approvals promoted
+/// when the proposal executed.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ExecutedUpgrade {
+    pub proposal_id: u64,
+    pub wasm_hash: BytesN<32>,
+    pub approvals: Vec<Address>,
+    /// `None` for a proposal executed before execution times were kept.
+    pub executed_at: Option<u64>,
+    /// Authenticated executor; `None` when executed without one.
+    pub executor: Option<Address>,
+    /// Ledger sequence of the execution; 0 when no executor was recorded.
+    pub ledger_sequence: u32,
+}
+
+/// =======================
+/// Interface
+/// =======================
+/// Minimal upgrade surface shared by Grainlify contracts.
+///
+/// Implement it inside a `#[contractimpl]` block to export the functions, or
+/// as a plain impl to assert conformance of existing entrypoints.
+pub trait Upgradeable {
+    /// Replaces the contract code with `new_wasm_hash`. Governance-gated.
+    fn upgrade(env: Env, new_wasm_hash: BytesN<32>);
+    /// Returns the stored version, or 0 before initialization.
+    fn get_version(env: Env) -> u32;
+}
+
+/// =======================
+/// Initialization
+/// =======================
+/// Stores `admin` and the initial `version`. Requires `admin` auth and
+/// panics with [`UpgradeError::AlreadyInitialized`] on a second call.
+pub fn initialize(env: &Env, admin: &Address, version: u32) {
+    if env.storage().instance().has(&UpgradeKey::Version) {
+        panic!("{}", UpgradeError::AlreadyInitialized as u32);
+    }
+    admin.require_auth();
+    env.storage().instance().set(&UpgradeKey::Admin, admin);
+    env.storage().instance().set(&UpgradeKey::Version, &version);
+    env.storage().instance().set(&UpgradeKey::ProposalsRelocated, &true);
+}
+
+/// Configures multisig governance instead of a single admin.
+pub fn initialize_multisig(env: &Env, signers: Vec<Address>, threshold: u32, version: u32) {
+    if env.storage().instance().has(&UpgradeKey::Version) {
+        panic!("Already initialized");
+    }
+    MultiSig::init(env, signers, threshold);
+    env.storage().instance().set(&UpgradeKey::Version, &version);
+    env.storage().instance().set(&UpgradeKey::ProposalsRelocated, &true);
+}
+
+/// =======================
+/// Governance
+/// =======================
+pub fn get_admin(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&UpgradeKey::Admin)
+}
+
+/// Requires the admin's authorization and returns the admin.
+///
+/// Panics with [`UpgradeError::NotInitialized`] when no admin is set.
+pub fn require_governance(env: &Env) -> Address {
+    let admin = get_admin(env).unwrap_or_else(|| panic!("{}", UpgradeError::NotInitialized as u32));
+    admin.require_auth();
+    admin
+}
+
+/// =======================
+/// Versioning
+/// =======================
+pub fn get_version(env: &Env) -> u32 {
+    env.storage().instance().get(&UpgradeKey::Version).unwrap_or(0)
+}
+
+/// Stores `new_version` and returns the version it replaced. The caller is
+/// responsible for authorization.
+pub fn set_version(env: &Env, new_version: u32) -> u32 {
+    let old_version = get_version(env);
+    env.storage().instance().set(&UpgradeKey::Version, &new_version);
+    old_version
+}
+
+pub fn get_previous_version(env: &Env) -> Option<u32> {
+    env.storage().instance().get(&UpgradeKey::PreviousVersion)
+}
+
+/// =======================
+/// Upgrades
+/// =======================
+/// Records the current version as the previous one and swaps in
+/// `new_wasm_hash`. Returns the recorded previous version.
+///
+/// Performs no authorization: call [`require_governance`] or
+/// [`take_approved_upgrade`] first.
+pub fn perform_upgrade(env: &Env, new_wasm_hash: &BytesN<32>) -> u32 {
+    let current_version: u32 = env.storage().instance().get(&UpgradeKey::Version).unwrap_or(1);
+    env.storage().instance().set(&UpgradeKey::PreviousVersion, &current_version);
+    env.deployer().update_current_contract_wasm(new_wasm_hash.clone());
+    current_version
+}
+
+/// Opens a multisig proposal to install `wasm_hash` and returns its ID.
+pub fn propose_upgrade(env: &Env, proposer: Address, wasm_hash: &BytesN<32>, expiry: u64) -> u64 {
+    let proposal_id = MultiSig::propose(env, proposer, expiry);
+    env.storage()
+        .persistent()
+        .set(&UpgradeKey::UpgradeProposal(proposal_id), wasm_hash);
+    proposal_id
+}
+
+/// Returns the WASM hash stored for `proposal_id`, if any.
+pub fn get_upgrade_hash(env: &Env, proposal_id: u64) -> Option<BytesN<32>> {
+    env.storage().persistent().get(&UpgradeKey::UpgradeProposal(proposal_id))
+}
+
+/// Returns the history entry for an executed upgrade proposal.
+pub fn get_executed_upgrade(env: &Env, proposal_id: u64) -> Option<ExecutedUpgrade> {
+    let record = MultiSig::get_execution(env, proposal_id)?;
+    let executor: Option<ExecutorRecord> =
+        env.storage().persistent().get(&UpgradeKey::UpgradeExecutor(proposal_id));
+    Some(ExecutedUpgrade {
+        proposal_id,
+        wasm_hash: get_upgrade_hash(env, proposal_id)?,
+        approvals: record.approvals,
+        executed_at: record.executed_at,
+        ledger_sequence: executor.as_ref().map_or(0, |e| e.ledger_sequence),
+        executor: executor.map(|e| e.executor),
+    })
+}
+
+/// Proposal IDs one [`relocate_legacy_proposals`] call scans at most.
+pub const RELOCATION_BATCH: u64 = 25;
+
+/// Ledgers a relocated persistent entry is kept alive for.
+pub const RELOCATED_TTL: u32 = 17_280 * 30;
+
+/// Proposal IDs [`relocate_legacy_proposals`] has yet to scan, or `None`
+/// once every proposal lives in persistent storage.
+pub fn legacy_proposals_remaining(env: &Env) -> Option<u64> {
+    if env.storage().instance().has(&UpgradeKey::ProposalsRelocated) {
+        return None;
+    }
+    let cursor: u64 = env.storage().instance().get(&UpgradeKey::RelocationCursor).unwrap_or(1);
+    Some((MultiSig::get_proposal_count(env) + 1).saturating_sub(cursor))
+}
+
+/// Moves proposals written by earlier versions, which kept them in instance
+/// storage, into persistent storage. Covers the multisig proposal record and
+/// the WASM hash, and extends the TTL of every entry it moves.
+///
+/// Each call scans at most `limit` IDs from a stored cursor and returns the
+/// range `(first, last)` it scanned, so the caller can move its own
+/// per-proposal entries; `first > last` when no IDs were left. Returns
+/// `None` once the relocation has finished. Contracts initialized by this
+/// version start with the new layout and never scan.
+pub fn relocate_legacy_proposals(env: &Env, limit: u64) -> Option<(u64, u64)> {
+    if env.storage().instance().has(&UpgradeKey::ProposalsRelocated) {
+        return None;
+    }
+    let count = MultiSig::get_proposal_count(env);
+    let first: u64 = env.storage().instance().get(&UpgradeKey::RelocationCursor).unwrap_or(1);
+    let last = count.min(first.saturating_add(limit).saturating_sub(1));
+    let mut moved = 0u32;
+    for proposal_id in first..=last {
+        if MultiSig::relocate_legacy_proposal(env, proposal_id) {
+            moved += 1;
+        }
+        let key = UpgradeKey::UpgradeProposal(proposal_id);
+        if let Some(hash) = env.storage().instance().get::<_, BytesN<32>>(&key) {
+            env.storage().instance().remove(&key);
+            env.storage().persistent().set(&key, &hash);
+            env.storage().persistent().extend_ttl(&key, RELOCATED_TTL, RELOCATED_TTL);
+        }
+    }
+    if last >= count {
+        env.storage().instance().remove(&UpgradeKey::RelocationCursor);
+        env.storage().instance().set(&UpgradeKey::ProposalsRelocated, &true);
+    } else {
+        env.storage().instance().set(&UpgradeKey::RelocationCursor, &(last + 1));
+    }
+    env.events().publish((symbol_short!("relocate"),), moved);
+    Some((first, last))
+}
+
+/// Consumes an approved proposal and returns the WASM hash to install.
+///
+/// Panics unless the proposal met its threshold and is still executable.
+pub fn take_approved_upgrade(env: &Env, proposal_id: u64) -> BytesN<32> {
+    // `try_mark_executed` runs the same checks as `MultiSig::can_execute`,
+    // so the approvals are scanned once.
+    match MultiSig::try_mark_executed(env, proposal_id) {
+        Ok(()) => {}
+        Err(MultiSigError::NotInitialized) => panic!("multisig not initialized"),
+        Err(MultiSigError::ProposalNotFound) => panic!("{:?}", MultiSigError::ProposalNotFound),
+        Err(_) => panic!("Threshold not met or proposal not executable"),
+    }
+    get_upgrade_hash(env, proposal_id).unwrap_or_else(|| panic!("Upgrade proposal not found"))
+}
+
+/// [`take_approved_upgrade`] that also records `executor` and the current
+/// ledger sequence for [`get_executed_upgrade`]. The caller is responsible
+/// for authenticating `executor`.
+pub fn take_approved_upgrade_by(env: &Env, proposal_id: u64, executor: &Address) -> BytesN<32> {
+    let wasm_hash = take_approved_upgrade(env, proposal_id);
+    let record = ExecutorRecord {
+        executor: executor.clone(),
+        ledger_sequence: env.ledger().sequence(),
+    };
+    env.storage()
+        .persistent()
+        .set(&UpgradeKey::UpgradeExecutor(proposal_id), &record);
+    wasm_hash
+}
+
+#[cfg(test)]
+mod test;
+#[cfg(test)]
+mod test_multisig_properties;
+#[cfg(test)]
+mod test_approval_ttl;
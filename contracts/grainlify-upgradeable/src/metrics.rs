@@ -0,0 +1,16 @@
+//! Interface between a contract and a companion metrics contract.
+//!
+//! A contract that moves its monitoring out of its own WASM forwards each
+//! tracked call through [`MetricsSinkClient`]. Calls are made with
+//! `try_record_call`, so a metrics contract that traps or runs out of
+//! budget never fails the caller.
+
+use soroban_sdk::{contractclient, Address, Env, Symbol};
+
+/// Entrypoints a metrics contract exposes to the contract it monitors.
+#[contractclient(name = "MetricsSinkClient")]
+pub trait MetricsSink {
+    /// Records one tracked call: `operation` invoked by `caller`, whether it
+    /// succeeded, and the ledger seconds it took.
+    fn record_call(env: Env, operation: Symbol, caller: Address, success: bool, duration: u64);
+}
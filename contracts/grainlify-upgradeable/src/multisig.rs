@@ -0,0 +1,1066 @@
+//! Multisig approval engine used by Grainlify upgrade flows.
+//!
+//! Proposal identifiers are allocated from a monotonic counter and are treated
+//! as stable handles for subsequent approval and execution steps.
+//!
+//! Each proposal is split across storage classes so the instance entry loaded
+//! on every call carries only the config, counters, and pause flag:
+//! - [`ProposalMeta`] (persistent): expiry, status, and the approval epoch
+//! - [`ApprovalSet`] (temporary, or persistent when the proposal never
+//!   expires): the approvals, with a TTL covering the proposal's expiry
+//! - [`ExecutionRecord`] (persistent): the approvals and execution time,
+//!   promoted when the proposal executes
+//! - [`ApprovalRecord`]s: when each signer approved, stored beside the
+//!   [`ApprovalSet`] and kept permanently once the proposal executes
+//!
+//! [`SignerStats`] (persistent) count each signer's activity across all
+//! proposals and outlive the signer's membership.
+//!
+//! The signer set has its own epoch, bumped whenever [`MultiSig::set_config`]
+//! or [`MultiSig::clear_config`] adds or removes a signer. Each approval
+//! remembers the signer epoch it was given under, and only approvals from
+//! current signers under the current signer epoch count toward a threshold
+//! (see [`MultiSig::get_valid_approvals`]); the raw approval list is kept,
+//! and a signer whose approval went stale may approve again to renew it.
+//! Approvals recorded before signer epochs existed count as epoch 0.
+//!
+//! Every proposal takes a fresh epoch from a monotonic nonce and its approval
+//! record carries the same epoch. A record that is missing or carries another
+//! epoch is never trusted: the proposal is *lapsed*, can no longer be approved
+//! or executed, and may only be pruned. TTL expiry therefore cannot reset a
+//! proposal's approvals for a second round.
+//!
+//! Proposals stored whole by earlier versions, in instance or persistent
+//! storage, are converted to this layout the first time they are read; see
+//! [`crate::relocate_legacy_proposals`].
+//!
+//! Each state transition has a `try_*` form that validates and applies the
+//! change without checking authorization and reports failures as
+//! [`MultiSigError`]. The public entry points add `require_auth` and panic on
+//! error; the `try_*` forms let tests drive the state machine step by step.
+
+use soroban_sdk::{contracttype, symbol_short, Address, Bytes, Env, IntoVal, Map, Val, Vec};
+
+/// Approximate ledger close time used to turn expiry timestamps into TTLs.
+const LEDGER_SECONDS: u64 = 5;
+/// Extra ledgers an approval record outlives its proposal's expiry by.
+const RECORD_TTL_MARGIN: u64 = 17_280;
+
+/// =======================
+/// Storage Keys
+/// =======================
+#[contracttype]
+enum DataKey {
+    Config,
+    /// Whole-proposal layout written by earlier versions; read-only now.
+    Proposal(u64),
+    ProposalCounter,
+    Paused,
+    ProposalMeta(u64),
+    ProposalApprovals(u64),
+    ProposalExecution(u64),
+    ApprovalEpoch,
+    /// `Vec<ApprovalRecord>` of an open proposal, stored like its approvals.
+    ProposalApprovalTimes(u64),
+    /// `Vec<ApprovalRecord>` promoted at execution.
+    ExecutionApprovalTimes(u64),
+    SignerStats(Address),
+    SignerEpoch,
+    /// `Map<Address, u64>` signer epoch of each approval of an open
+    /// proposal, stored like its approvals.
+    ApprovalSignerEpochs(u64),
+}
+
+/// =======================
+/// Multisig Configuration
+/// =======================
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MultiSigConfig {
+    /// Ordered signer set authorized to create and approve proposals.
+    pub signers: Vec<Address>,
+    /// Minimum number of distinct signer approvals required for execution.
+    pub threshold: u32,
+}
+
+/// =======================
+/// Proposal Structure
+/// =======================
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Proposal {
+    /// Signers that have approved this proposal.
+    pub approvals: Vec<Address>,
+    /// Whether the proposal has already been consumed by execution.
+    pub executed: bool,
+    /// Expiry ledger timestamp in seconds (`0` means no expiry).
+    pub expiry: u64,
+    /// Whether the proposal has been explicitly cancelled.
+    pub cancelled: bool,
+}
+
+/// Durable part of a proposal.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProposalMeta {
+    pub expiry: u64,
+    pub executed: bool,
+    pub cancelled: bool,
+    /// Nonce the approval record must carry to count.
+    pub epoch: u64,
+}
+
+/// Approvals collected for an open proposal.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ApprovalSet {
+    pub epoch: u64,
+    pub approvals: Vec<Address>,
+}
+
+/// When a signer approved a proposal.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ApprovalRecord {
+    pub signer: Address,
+    /// Ledger timestamp of the approval; 0 for approvals recorded before
+    /// times were kept.
+    pub at: u64,
+    /// Ledger sequence of the approval; 0 like `at`.
+    pub sequence: u32,
+}
+
+/// Activity counters of one signer.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SignerStats {
+    pub signer: Address,
+    pub proposals_created: u64,
+    pub approvals_given: u64,
+    /// Approvals revoked plus proposals cancelled.
+    pub rejections_given: u64,
+    /// Ledger timestamp of the latest of these; 0 before any.
+    pub last_active_at: u64,
+    /// When the signer left the signer set; `None` while a member.
+    pub removed_at: Option<u64>,
+}
+
+/// Persistent history entry written when a proposal executes.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ExecutionRecord {
+    pub proposal_id: u64,
+    /// Approvals recorded at execution, including any from signers that
+    /// were later removed.
+    pub approvals: Vec<Address>,
+    /// Ledger timestamp of the execution; `None` for a proposal executed
+    /// before execution times were kept.
+    pub executed_at: Option<u64>,
+}
+
+/// =======================
+/// Errors
+/// =======================
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum MultiSigError {
+    NotInitialized,
+    NotSigner,
+    AlreadyApproved,
+    ProposalNotFound,
+    ProposalAlreadyExists,
+    AlreadyExecuted,
+    AlreadyCancelled,
+    ThresholdNotMet,
+    InvalidThreshold,
+    ProposalCancelled,
+    ProposalExpired,
+    ContractPaused,
+    StateInconsistent,
+    NotApproved,
+    ProposalActive,
+    ApprovalsLapsed,
+}
+
+/// =======================
+/// Public API
+/// =======================
+pub struct MultiSig;
+
+impl MultiSig {
+    /// Initializes the signer set and execution threshold.
+    pub fn init(env: &Env, signers: Vec<Address>, threshold: u32) {
+        if threshold == 0 || threshold > signers.len() {
+            panic!("{:?}", MultiSigError::InvalidThreshold);
+        }
+
+        let config = MultiSigConfig { signers, threshold };
+        env.storage().instance().set(&DataKey::Config, &config);
+        env.storage()
+            .instance()
+            .set(&DataKey::ProposalCounter, &0u64);
+    }
+
+    /// Creates a new proposal and returns its stable identifier.
+    pub fn propose(env: &Env, proposer: Address, expiry: u64) -> u64 {
+        proposer.require_auth();
+        Self::try_propose(env, &proposer, expiry).unwrap_or_else(|e| panic!("{:?}", e))
+    }
+
+    /// [`Self::propose`] without the auth check.
+    pub fn try_propose(env: &Env, proposer: &Address, expiry: u64) -> Result<u64, MultiSigError> {
+        let config = Self::try_get_config(env)?;
+        Self::check_signer(&config, proposer)?;
+
+        let counter: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey::ProposalCounter)
+            .unwrap_or(0u64)
+            + 1;
+
+        if Self::load(env, counter).is_some() {
+            return Err(MultiSigError::ProposalAlreadyExists);
+        }
+
+        let meta = ProposalMeta {
+            expiry,
+            executed: false,
+            cancelled: false,
+            epoch: Self::next_epoch(env),
+        };
+        Self::save_meta(env, counter, &meta);
+        Self::save_approvals(env, counter, &meta, Vec::new(env));
+        env.storage()
+            .instance()
+            .set(&DataKey::ProposalCounter, &counter);
+        Self::record_activity(env, proposer, |stats| stats.proposals_created += 1);
+
+        env.events()
+            .publish((symbol_short!("proposal"),), (counter, Self::get_signer_epoch(env)));
+
+        Ok(counter)
+    }
+
+    /// Records a signer approval for an existing proposal. Publishes
+    /// `("approved",)` with `(proposal_id, signer, signer_epoch, tally,
+    /// threshold, threshold_met)`, where `tally` counts the valid approvals
+    /// after this one.
+    pub fn approve(env: &Env, proposal_id: u64, signer: Address) {
+        signer.require_auth();
+        Self::try_approve(env, proposal_id, &signer).unwrap_or_else(|e| panic!("{:?}", e));
+    }
+
+    /// [`Self::approve`] without the auth check.
+    pub fn try_approve(env: &Env, proposal_id: u64, signer: &Address) -> Result<(), MultiSigError> {
+        let config = Self::try_get_config(env)?;
+        Self::check_signer(&config, signer)?;
+
+        let (meta, approvals) = Self::try_load(env, proposal_id)?;
+        let mut approvals = Self::check_open(env, &meta, approvals)?;
+
+        // An approval from an earlier signer epoch no longer counts, so the
+        // signer may renew it in place.
+        let signer_epoch = Self::get_signer_epoch(env);
+        let mut epochs = Self::load_approval_epochs(env, proposal_id, &meta);
+        let mut times = Self::load_approval_times(env, proposal_id, &meta);
+        let record = ApprovalRecord {
+            signer: signer.clone(),
+            at: env.ledger().timestamp(),
+            sequence: env.ledger().sequence(),
+        };
+        if approvals.contains(signer) {
+            if epochs.get(signer.clone()).unwrap_or(0) == signer_epoch {
+                return Err(MultiSigError::AlreadyApproved);
+            }
+            match times.iter().position(|existing| existing.signer == *signer) {
+                Some(index) => times.set(index as u32, record),
+                None => times.push_back(record),
+            }
+        } else {
+            approvals.push_back(signer.clone());
+            Self::save_approvals(env, proposal_id, &meta, approvals.clone());
+            times.push_back(record);
+        }
+        Self::save_open(env, &DataKey::ProposalApprovalTimes(proposal_id), &meta, &times);
+        epochs.set(signer.clone(), signer_epoch);
+        Self::save_open(env, &DataKey::ApprovalSignerEpochs(proposal_id), &meta, &epochs);
+        Self::record_activity(env, signer, |stats| stats.approvals_given += 1);
+
+        // Valid approvals after this one, as `valid_approvals` counts them,
+        // so indexers can follow the tally from events alone.
+        let tally = approvals
+            .iter()
+            .filter(|approver| Self::counts(&config, &epochs, approver, signer_epoch))
+            .count() as u32;
+        env.events().publish(
+            (symbol_short!("approved"),),
+            (proposal_id, signer.clone(), signer_epoch, tally, config.threshold, tally >= config.threshold),
+        );
+        Ok(())
+    }
+
+    /// Withdraws a previously recorded approval before execution.
+    pub fn revoke(env: &Env, proposal_id: u64, signer: Address) {
+        signer.require_auth();
+        Self::try_revoke(env, proposal_id, &signer).unwrap_or_else(|e| panic!("{:?}", e));
+    }
+
+    /// [`Self::revoke`] without the auth check.
+    pub fn try_revoke(env: &Env, proposal_id: u64, signer: &Address) -> Result<(), MultiSigError> {
+        let (meta, approvals) = Self::try_load(env, proposal_id)?;
+        if meta.executed {
+            return Err(MultiSigError::AlreadyExecuted);
+        }
+        if meta.cancelled {
+            return Err(MultiSigError::ProposalCancelled);
+        }
+        let mut approvals = approvals.ok_or(MultiSigError::ApprovalsLapsed)?;
+
+        let index = approvals
+            .first_index_of(signer)
+            .ok_or(MultiSigError::NotApproved)?;
+        approvals.remove(index);
+        Self::save_approvals(env, proposal_id, &meta, approvals);
+        let mut times = Self::load_approval_times(env, proposal_id, &meta);
+        if let Some(index) = times.iter().position(|record| record.signer == *signer) {
+            times.remove(index as u32);
+            Self::save_open(env, &DataKey::ProposalApprovalTimes(proposal_id), &meta, &times);
+        }
+        let mut epochs = Self::load_approval_epochs(env, proposal_id, &meta);
+        if epochs.remove(signer.clone()).is_some() {
+            Self::save_open(env, &DataKey::ApprovalSignerEpochs(proposal_id), &meta, &epochs);
+        }
+        Self::record_activity(env, signer, |stats| stats.rejections_given += 1);
+
+        env.events().publish(
+            (symbol_short!("revoked"),),
+            (proposal_id, signer.clone(), Self::get_signer_epoch(env)),
+        );
+        Ok(())
+    }
+
+    /// Returns whether a proposal currently satisfies the execution threshold.
+    ///
+    /// Only [`Self::get_valid_approvals`] count.
+    pub fn can_execute(env: &Env, proposal_id: u64) -> bool {
+        Self::open_tally(env, proposal_id).is_some_and(|(tally, threshold)| tally >= threshold)
+    }
+
+    /// `(tally, threshold)` of an open proposal in a healthy contract, where
+    /// `tally` counts [`Self::get_valid_approvals`]; `None` whenever
+    /// [`Self::can_execute`] fails for a reason other than the threshold.
+    /// Lets a caller that needs both the verdict and the tally pay for one
+    /// approval scan.
+    pub fn open_tally(env: &Env, proposal_id: u64) -> Option<(u32, u32)> {
+        // First check if contract is in a healthy state
+        if Self::is_contract_paused(env) || Self::is_state_inconsistent(env) {
+            return None;
+        }
+
+        let config = Self::get_config(env);
+        let (meta, approvals) = Self::load(env, proposal_id)
+            .unwrap_or_else(|| panic!("{:?}", MultiSigError::ProposalNotFound));
+
+        let approvals = Self::check_open(env, &meta, approvals).ok()?;
+        let valid = Self::valid_approvals(env, &config, proposal_id, &meta, &approvals);
+        Some((valid.len(), config.threshold))
+    }
+
+    /// Returns how many more valid approvals `proposal_id` needs to meet the
+    /// threshold, 0 once it is met, or `None` for an unknown proposal. A
+    /// lapsed proposal needs the full threshold. Ignores whether the
+    /// proposal is still open; see [`Self::can_execute`].
+    pub fn approvals_needed(env: &Env, proposal_id: u64) -> Option<u32> {
+        let config = Self::get_config_opt(env)?;
+        let (meta, approvals) = Self::load(env, proposal_id)?;
+        let valid = match approvals {
+            Some(approvals) if !meta.executed => {
+                Self::valid_approvals(env, &config, proposal_id, &meta, &approvals).len()
+            }
+            Some(approvals) => Self::count_live(&config, &approvals),
+            None => 0,
+        };
+        Some(config.threshold.saturating_sub(valid))
+    }
+
+    /// Approvals of an unexecuted proposal that count toward its threshold:
+    /// those from current signers given under the current signer epoch, in
+    /// approval order. Empty for an executed, lapsed, or unknown proposal;
+    /// the raw list stays in [`Self::get_proposal_opt`].
+    pub fn get_valid_approvals(env: &Env, proposal_id: u64) -> Vec<Address> {
+        match (Self::get_config_opt(env), Self::load(env, proposal_id)) {
+            (Some(config), Some((meta, Some(approvals)))) if !meta.executed => {
+                Self::valid_approvals(env, &config, proposal_id, &meta, &approvals)
+            }
+            _ => Vec::new(env),
+        }
+    }
+
+    /// Current signer-set epoch; 0 until the set first changes.
+    pub fn get_signer_epoch(env: &Env) -> u64 {
+        env.storage().instance().get(&DataKey::SignerEpoch).unwrap_or(0)
+    }
+
+    /// Marks a proposal as executed after the guarded action succeeds and
+    /// promotes its approvals into persistent history.
+    pub fn mark_executed(env: &Env, proposal_id: u64) {
+        Self::try_mark_executed(env, proposal_id).unwrap_or_else(|e| panic!("{:?}", e));
+    }
+
+    /// [`Self::mark_executed`] reporting failures as errors.
+    pub fn try_mark_executed(env: &Env, proposal_id: u64) -> Result<(), MultiSigError> {
+        let config = Self::try_get_config(env)?;
+        let (mut meta, approvals) = Self::try_load(env, proposal_id)?;
+        let approvals = Self::check_open(env, &meta, approvals)?;
+        if Self::is_contract_paused(env) {
+            return Err(MultiSigError::ContractPaused);
+        }
+        if Self::is_state_inconsistent(env) {
+            return Err(MultiSigError::StateInconsistent);
+        }
+        if Self::valid_approvals(env, &config, proposal_id, &meta, &approvals).len() < config.threshold {
+            return Err(MultiSigError::ThresholdNotMet);
+        }
+
+        let times = Self::approval_records(env, &approvals, Self::load_approval_times(env, proposal_id, &meta));
+        env.storage()
+            .persistent()
+            .set(&DataKey::ExecutionApprovalTimes(proposal_id), &times);
+        let record = ExecutionRecord {
+            proposal_id,
+            approvals,
+            executed_at: Some(env.ledger().timestamp()),
+        };
+        env.storage()
+            .persistent()
+            .set(&DataKey::ProposalExecution(proposal_id), &record);
+        Self::remove_approvals(env, proposal_id, &meta);
+        meta.executed = true;
+        Self::save_meta(env, proposal_id, &meta);
+
+        env.events()
+            .publish((symbol_short!("executed"),), (proposal_id, Self::get_signer_epoch(env)));
+        Ok(())
+    }
+
+    /// Cancels a proposal so it can no longer be approved or executed.
+    pub fn cancel(env: &Env, proposal_id: u64, canceller: Address) {
+        canceller.require_auth();
+        Self::try_cancel(env, proposal_id, &canceller).unwrap_or_else(|e| panic!("{:?}", e));
+    }
+
+    /// [`Self::cancel`] without the auth check.
+    pub fn try_cancel(env: &Env, proposal_id: u64, canceller: &Address) -> Result<(), MultiSigError> {
+        let config = Self::try_get_config(env)?;
+        Self::check_signer(&config, canceller)?;
+
+        let (mut meta, _) = Self::try_load(env, proposal_id)?;
+
+        if meta.executed {
+            return Err(MultiSigError::AlreadyExecuted);
+        }
+        if meta.cancelled {
+            return Err(MultiSigError::AlreadyCancelled);
+        }
+
+        meta.cancelled = true;
+        Self::save_meta(env, proposal_id, &meta);
+        Self::record_activity(env, canceller, |stats| stats.rejections_given += 1);
+
+        env.events().publish(
+            (symbol_short!("cancelled"),),
+            (proposal_id, canceller.clone(), Self::get_signer_epoch(env)),
+        );
+        Ok(())
+    }
+
+    /// Deletes a cancelled, expired, or lapsed proposal that never executed
+    /// to reclaim its storage. Executed proposals are kept as history.
+    pub fn prune(env: &Env, proposal_id: u64) {
+        Self::try_prune(env, proposal_id).unwrap_or_else(|e| panic!("{:?}", e));
+    }
+
+    /// [`Self::prune`] reporting failures as errors.
+    pub fn try_prune(env: &Env, proposal_id: u64) -> Result<(), MultiSigError> {
+        let (meta, approvals) = Self::try_load(env, proposal_id)?;
+        if meta.executed {
+            return Err(MultiSigError::AlreadyExecuted);
+        }
+        if !meta.cancelled && !Self::meta_expired(env, &meta) && approvals.is_some() {
+            return Err(MultiSigError::ProposalActive);
+        }
+
+        Self::remove_approvals(env, proposal_id, &meta);
+        env.storage().persistent().remove(&DataKey::ProposalMeta(proposal_id));
+        env.events().publish((symbol_short!("pruned"),), proposal_id);
+        Ok(())
+    }
+
+    /// Drops an executed proposal's metadata, keeping only its
+    /// [`ExecutionRecord`]. The proposal then reads as absent.
+    pub fn archive(env: &Env, proposal_id: u64) {
+        Self::try_archive(env, proposal_id).unwrap_or_else(|e| panic!("{:?}", e));
+    }
+
+    /// [`Self::archive`] reporting failures as errors.
+    pub fn try_archive(env: &Env, proposal_id: u64) -> Result<(), MultiSigError> {
+        let (meta, _) = Self::try_load(env, proposal_id)?;
+        if !meta.executed {
+            return Err(MultiSigError::ProposalActive);
+        }
+        env.storage().persistent().remove(&DataKey::ProposalMeta(proposal_id));
+        env.events().publish((symbol_short!("archived"),), proposal_id);
+        Ok(())
+    }
+
+    /// Sets the pause flag for multisig-protected execution paths.
+    ///
+    /// Authorization is the caller's responsibility: the contract layer decides
+    /// whether an admin, a single signer, or an approved proposal may toggle it.
+    pub fn set_paused(env: &Env, paused: bool) {
+        env.storage().instance().set(&DataKey::Paused, &paused);
+    }
+
+    /// Returns `true` if `address` belongs to the configured signer set.
+    pub fn is_signer(env: &Env, address: &Address) -> bool {
+        Self::get_config_opt(env)
+            .map(|config| config.signers.contains(address))
+            .unwrap_or(false)
+    }
+
+    /// Position of `address` in the signer list: the bit
+    /// [`Self::approval_bitmap`] uses for it. Adding a signer appends it, so
+    /// existing positions only shift when a signer is removed.
+    pub fn signer_index(env: &Env, address: &Address) -> Option<u32> {
+        Self::get_config_opt(env)?.signers.first_index_of(address)
+    }
+
+    /// [`Self::get_valid_approvals`] as a bitmap over the signer list: bit
+    /// `i % 8` of byte `i / 8` is set when the signer at index `i`
+    /// approved. One bit per signer, rounded up to whole bytes. Valid
+    /// approvals are all from the current signer epoch, so the indexes are
+    /// those of the current list.
+    pub fn approval_bitmap(env: &Env, proposal_id: u64) -> Bytes {
+        let signers = Self::get_config_opt(env).map_or(Vec::new(env), |config| config.signers);
+        let mut bitmap = Bytes::new(env);
+        for _ in 0..signers.len().div_ceil(8) {
+            bitmap.push_back(0);
+        }
+        for approver in Self::get_valid_approvals(env, proposal_id).iter() {
+            if let Some(index) = signers.first_index_of(&approver) {
+                let byte = bitmap.get(index / 8).unwrap_or(0);
+                bitmap.set(index / 8, byte | (1 << (index % 8)));
+            }
+        }
+        bitmap
+    }
+
+    /// Returns whether multisig execution is paused.
+    pub fn is_contract_paused(env: &Env) -> bool {
+        env.storage()
+            .instance()
+            .get(&DataKey::Paused)
+            .unwrap_or(false)
+    }
+
+    /// Returns true if the stored multisig configuration is invalid.
+    pub fn is_state_inconsistent(env: &Env) -> bool {
+        match Self::get_config_opt(env) {
+            Some(config) => config.threshold == 0 || config.threshold > config.signers.len(),
+            None => false,
+        }
+    }
+
+    /// Returns the current multisig configuration, if initialized.
+    pub fn get_config_opt(env: &Env) -> Option<MultiSigConfig> {
+        env.storage().instance().get(&DataKey::Config)
+    }
+
+    /// Returns `true` if a proposal was cancelled.
+    #[allow(dead_code)]
+    pub fn is_cancelled(env: &Env, proposal_id: u64) -> bool {
+        Self::get_proposal_opt(env, proposal_id)
+            .map(|p| p.cancelled)
+            .unwrap_or(false)
+    }
+
+    /// Returns `true` if a proposal has expired at the current ledger timestamp.
+    pub fn is_expired(env: &Env, proposal_id: u64) -> bool {
+        Self::load(env, proposal_id)
+            .map(|(meta, _)| Self::meta_expired(env, &meta))
+            .unwrap_or(false)
+    }
+
+    /// Returns `true` if an unexecuted proposal lost its approval record,
+    /// through TTL expiry or an epoch mismatch.
+    pub fn is_lapsed(env: &Env, proposal_id: u64) -> bool {
+        matches!(Self::load(env, proposal_id), Some((meta, None)) if !meta.executed)
+    }
+
+    /// Returns the highest proposal ID allocated so far.
+    pub fn get_proposal_count(env: &Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&DataKey::ProposalCounter)
+            .unwrap_or(0)
+    }
+
+    /// Allocates the next proposal ID for a proposal stored outside this
+    /// engine, so it never collides with a multisig proposal. The new ID is
+    /// above both the counter and `floor`.
+    pub fn reserve_proposal_id(env: &Env, floor: u64) -> u64 {
+        let id = Self::get_proposal_count(env).max(floor) + 1;
+        env.storage().instance().set(&DataKey::ProposalCounter, &id);
+        id
+    }
+
+    /// Returns a proposal if present. A lapsed proposal reports no approvals;
+    /// an executed one reports the approvals promoted at execution.
+    pub fn get_proposal_opt(env: &Env, proposal_id: u64) -> Option<Proposal> {
+        let (meta, approvals) = Self::load(env, proposal_id)?;
+        Some(Proposal {
+            approvals: approvals.unwrap_or(Vec::new(env)),
+            executed: meta.executed,
+            expiry: meta.expiry,
+            cancelled: meta.cancelled,
+        })
+    }
+
+    /// Returns the history entry promoted when `proposal_id` executed.
+    ///
+    /// Survives [`Self::archive`].
+    pub fn get_execution(env: &Env, proposal_id: u64) -> Option<ExecutionRecord> {
+        let key = DataKey::ProposalExecution(proposal_id);
+        if let Some(record) = env.storage().persistent().get(&key) {
+            return Some(record);
+        }
+        // A legacy executed proposal has no record until a mutating path
+        // converts it.
+        match Self::load(env, proposal_id)? {
+            (meta, Some(approvals)) if meta.executed => Some(ExecutionRecord {
+                proposal_id,
+                approvals,
+                executed_at: None,
+            }),
+            _ => None,
+        }
+    }
+
+    /// Returns who approved `proposal_id` and when, in approval order: the
+    /// current approvals of an open proposal, or the approvals promoted at
+    /// execution, which survive [`Self::archive`]. Empty for an unknown or
+    /// lapsed proposal.
+    pub fn get_approval_records(env: &Env, proposal_id: u64) -> Vec<ApprovalRecord> {
+        if let Some(record) = Self::get_execution(env, proposal_id) {
+            let times = env
+                .storage()
+                .persistent()
+                .get(&DataKey::ExecutionApprovalTimes(proposal_id))
+                .unwrap_or(Vec::new(env));
+            return Self::approval_records(env, &record.approvals, times);
+        }
+        match Self::load(env, proposal_id) {
+            Some((meta, Some(approvals))) => {
+                let times = Self::load_approval_times(env, proposal_id, &meta);
+                Self::approval_records(env, &approvals, times)
+            }
+            _ => Vec::new(env),
+        }
+    }
+
+    /// Converts proposal `proposal_id` from the legacy instance layout if it
+    /// is still stored there, extending the TTL of the persistent entries it
+    /// writes. Returns whether it moved.
+    pub(crate) fn relocate_legacy_proposal(env: &Env, proposal_id: u64) -> bool {
+        let key = DataKey::Proposal(proposal_id);
+        match env.storage().instance().get::<_, Proposal>(&key) {
+            Some(proposal) => {
+                env.storage().instance().remove(&key);
+                // Approvals of an expiring proposal are temporary and get
+                // their own TTL in `save_open`.
+                let written = if proposal.executed {
+                    Some(DataKey::ProposalExecution(proposal_id))
+                } else if proposal.expiry == 0 {
+                    Some(DataKey::ProposalApprovals(proposal_id))
+                } else {
+                    None
+                };
+                Self::adopt_legacy(env, proposal_id, proposal);
+                let ttl = crate::RELOCATED_TTL;
+                let storage = env.storage().persistent();
+                storage.extend_ttl(&DataKey::ProposalMeta(proposal_id), ttl, ttl);
+                if let Some(key) = written {
+                    storage.extend_ttl(&key, ttl, ttl);
+                }
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Sets the multisig configuration directly for controlled restore flows.
+    /// Signers that leave the set keep their [`SignerStats`], marked removed.
+    pub fn set_config(env: &Env, config: MultiSigConfig) {
+        if config.threshold == 0 || config.threshold > config.signers.len() {
+            panic!("{:?}", MultiSigError::InvalidThreshold);
+        }
+        Self::update_membership(env, &config.signers);
+        env.storage().instance().set(&DataKey::Config, &config);
+    }
+
+    /// Clears the multisig configuration for controlled restore flows.
+    pub fn clear_config(env: &Env) {
+        Self::update_membership(env, &Vec::new(env));
+        env.storage().instance().remove(&DataKey::Config);
+    }
+
+    /// Activity counters of `signer`, current or removed. Zeroed stats for a
+    /// current signer with no activity yet; `None` for any other address
+    /// without history.
+    pub fn get_signer_stats(env: &Env, signer: &Address) -> Option<SignerStats> {
+        let stored = env
+            .storage()
+            .persistent()
+            .get(&DataKey::SignerStats(signer.clone()));
+        if stored.is_some() {
+            return stored;
+        }
+        let is_signer = Self::get_config_opt(env).is_some_and(|config| config.signers.contains(signer));
+        is_signer.then(|| Self::empty_stats(signer))
+    }
+
+    /// [`Self::get_signer_stats`] for each current signer, in signer order.
+    pub fn list_signer_stats(env: &Env) -> Vec<SignerStats> {
+        let mut out = Vec::new(env);
+        if let Some(config) = Self::get_config_opt(env) {
+            for signer in config.signers.iter() {
+                out.push_back(Self::get_signer_stats(env, &signer).unwrap_or_else(|| Self::empty_stats(&signer)));
+            }
+        }
+        out
+    }
+
+    /// =======================
+    /// Internal Helpers
+    /// =======================
+    fn empty_stats(signer: &Address) -> SignerStats {
+        SignerStats {
+            signer: signer.clone(),
+            proposals_created: 0,
+            approvals_given: 0,
+            rejections_given: 0,
+            last_active_at: 0,
+            removed_at: None,
+        }
+    }
+
+    fn record_activity(env: &Env, signer: &Address, update: impl FnOnce(&mut SignerStats)) {
+        let key = DataKey::SignerStats(signer.clone());
+        let mut stats = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or_else(|| Self::empty_stats(signer));
+        update(&mut stats);
+        stats.last_active_at = env.ledger().timestamp();
+        env.storage().persistent().set(&key, &stats);
+    }
+
+    /// Marks stats of signers leaving the set as removed and clears the mark
+    /// for signers rejoining it. Bumps the signer epoch if anyone left or
+    /// joined.
+    fn update_membership(env: &Env, signers: &Vec<Address>) {
+        let previous = Self::get_config_opt(env).map_or(Vec::new(env), |config| config.signers);
+        let changed = previous.iter().any(|signer| !signers.contains(&signer))
+            || signers.iter().any(|signer| !previous.contains(&signer));
+        if changed {
+            let epoch = Self::get_signer_epoch(env) + 1;
+            env.storage().instance().set(&DataKey::SignerEpoch, &epoch);
+            env.events().publish((symbol_short!("sgn_epoch"),), epoch);
+        }
+        let now = env.ledger().timestamp();
+        for signer in previous.iter().filter(|signer| !signers.contains(signer)) {
+            let mut stats = Self::get_signer_stats(env, &signer).unwrap_or_else(|| Self::empty_stats(&signer));
+            stats.removed_at = Some(now);
+            env.storage().persistent().set(&DataKey::SignerStats(signer), &stats);
+        }
+        for signer in signers.iter().filter(|signer| !previous.contains(signer)) {
+            let key = DataKey::SignerStats(signer.clone());
+            if let Some(mut stats) = env.storage().persistent().get::<_, SignerStats>(&key) {
+                if stats.removed_at.is_some() {
+                    stats.removed_at = None;
+                    env.storage().persistent().set(&key, &stats);
+                }
+            }
+        }
+    }
+
+    fn get_config(env: &Env) -> MultiSigConfig {
+        env.storage()
+            .instance()
+            .get(&DataKey::Config)
+            .expect("multisig not initialized")
+    }
+
+    fn try_get_config(env: &Env) -> Result<MultiSigConfig, MultiSigError> {
+        Self::get_config_opt(env).ok_or(MultiSigError::NotInitialized)
+    }
+
+    /// Reads a proposal's metadata and, unless the proposal lapsed, its
+    /// approvals. A whole-proposal legacy entry is converted in memory only,
+    /// so views never write; [`Self::try_load`] stores the conversion.
+    fn load(env: &Env, proposal_id: u64) -> Option<(ProposalMeta, Option<Vec<Address>>)> {
+        Self::load_with(env, proposal_id, false)
+    }
+
+    fn load_with(
+        env: &Env,
+        proposal_id: u64,
+        adopt: bool,
+    ) -> Option<(ProposalMeta, Option<Vec<Address>>)> {
+        let meta: ProposalMeta = match env
+            .storage()
+            .persistent()
+            .get(&DataKey::ProposalMeta(proposal_id))
+        {
+            Some(meta) => meta,
+            None => {
+                let key = DataKey::Proposal(proposal_id);
+                let legacy: Proposal = env.storage().persistent().get(&key)?;
+                if !adopt {
+                    // The real epoch is assigned on adoption; 0 is never
+                    // issued.
+                    let meta = ProposalMeta {
+                        expiry: legacy.expiry,
+                        executed: legacy.executed,
+                        cancelled: legacy.cancelled,
+                        epoch: 0,
+                    };
+                    return Some((meta, Some(legacy.approvals)));
+                }
+                env.storage().persistent().remove(&key);
+                return Some(Self::adopt_legacy(env, proposal_id, legacy));
+            }
+        };
+
+        let approvals = if meta.executed {
+            env.storage()
+                .persistent()
+                .get::<_, ExecutionRecord>(&DataKey::ProposalExecution(proposal_id))
+                .map(|record| record.approvals)
+        } else {
+            let key = DataKey::ProposalApprovals(proposal_id);
+            let record: Option<ApprovalSet> = if meta.expiry == 0 {
+                env.storage().persistent().get(&key)
+            } else {
+                env.storage().temporary().get(&key)
+            };
+            record
+                .filter(|record| record.epoch == meta.epoch)
+                .map(|record| record.approvals)
+        };
+        Some((meta, approvals))
+    }
+
+    /// [`Self::load`] for mutating paths: a whole-proposal legacy entry is
+    /// written in the split layout before it is returned.
+    fn try_load(
+        env: &Env,
+        proposal_id: u64,
+    ) -> Result<(ProposalMeta, Option<Vec<Address>>), MultiSigError> {
+        Self::load_with(env, proposal_id, true).ok_or(MultiSigError::ProposalNotFound)
+    }
+
+    /// Writes a legacy whole proposal in the split layout under a fresh epoch.
+    fn adopt_legacy(
+        env: &Env,
+        proposal_id: u64,
+        legacy: Proposal,
+    ) -> (ProposalMeta, Option<Vec<Address>>) {
+        let meta = ProposalMeta {
+            expiry: legacy.expiry,
+            executed: legacy.executed,
+            cancelled: legacy.cancelled,
+            epoch: Self::next_epoch(env),
+        };
+        Self::save_meta(env, proposal_id, &meta);
+        if legacy.executed {
+            let record = ExecutionRecord {
+                proposal_id,
+                approvals: legacy.approvals.clone(),
+                executed_at: None,
+            };
+            env.storage()
+                .persistent()
+                .set(&DataKey::ProposalExecution(proposal_id), &record);
+        } else {
+            Self::save_approvals(env, proposal_id, &meta, legacy.approvals.clone());
+        }
+        (meta, Some(legacy.approvals))
+    }
+
+    fn next_epoch(env: &Env) -> u64 {
+        let epoch: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey::ApprovalEpoch)
+            .unwrap_or(0)
+            + 1;
+        env.storage().instance().set(&DataKey::ApprovalEpoch, &epoch);
+        epoch
+    }
+
+    fn save_meta(env: &Env, proposal_id: u64, meta: &ProposalMeta) {
+        env.storage()
+            .persistent()
+            .set(&DataKey::ProposalMeta(proposal_id), meta);
+    }
+
+    /// Stores the approval record. Expiring proposals use temporary storage
+    /// with a TTL that outlasts the expiry, capped at the network maximum.
+    fn save_approvals(env: &Env, proposal_id: u64, meta: &ProposalMeta, approvals: Vec<Address>) {
+        let record = ApprovalSet {
+            epoch: meta.epoch,
+            approvals,
+        };
+        Self::save_open(env, &DataKey::ProposalApprovals(proposal_id), meta, &record);
+    }
+
+    /// Stores an entry that lives as long as an open proposal's approvals.
+    fn save_open<V: IntoVal<Env, Val>>(env: &Env, key: &DataKey, meta: &ProposalMeta, value: &V) {
+        if meta.expiry == 0 {
+            env.storage().persistent().set(key, value);
+            return;
+        }
+        env.storage().temporary().set(key, value);
+        let remaining = meta.expiry.saturating_sub(env.ledger().timestamp());
+        let ttl = (remaining.div_ceil(LEDGER_SECONDS) + RECORD_TTL_MARGIN)
+            .min(env.storage().max_ttl() as u64) as u32;
+        env.storage().temporary().extend_ttl(key, ttl, ttl);
+    }
+
+    fn remove_approvals(env: &Env, proposal_id: u64, meta: &ProposalMeta) {
+        for key in [
+            DataKey::ProposalApprovals(proposal_id),
+            DataKey::ProposalApprovalTimes(proposal_id),
+            DataKey::ApprovalSignerEpochs(proposal_id),
+        ] {
+            if meta.expiry == 0 {
+                env.storage().persistent().remove(&key);
+            } else {
+                env.storage().temporary().remove(&key);
+            }
+        }
+    }
+
+    fn load_approval_times(env: &Env, proposal_id: u64, meta: &ProposalMeta) -> Vec<ApprovalRecord> {
+        let key = DataKey::ProposalApprovalTimes(proposal_id);
+        let times = if meta.expiry == 0 {
+            env.storage().persistent().get(&key)
+        } else {
+            env.storage().temporary().get(&key)
+        };
+        times.unwrap_or(Vec::new(env))
+    }
+
+    fn load_approval_epochs(env: &Env, proposal_id: u64, meta: &ProposalMeta) -> Map<Address, u64> {
+        let key = DataKey::ApprovalSignerEpochs(proposal_id);
+        let epochs = if meta.expiry == 0 {
+            env.storage().persistent().get(&key)
+        } else {
+            env.storage().temporary().get(&key)
+        };
+        epochs.unwrap_or(Map::new(env))
+    }
+
+    /// `approvals` from current signers given under the current signer
+    /// epoch; approvals without a recorded epoch count as epoch 0.
+    fn valid_approvals(
+        env: &Env,
+        config: &MultiSigConfig,
+        proposal_id: u64,
+        meta: &ProposalMeta,
+        approvals: &Vec<Address>,
+    ) -> Vec<Address> {
+        let current = Self::get_signer_epoch(env);
+        let epochs = Self::load_approval_epochs(env, proposal_id, meta);
+        let mut valid = Vec::new(env);
+        for approver in approvals.iter() {
+            if Self::counts(config, &epochs, &approver, current) {
+                valid.push_back(approver);
+            }
+        }
+        valid
+    }
+
+    /// Whether `approver`'s approval counts under signer epoch `current`.
+    /// Every signer-set change bumps the epoch, so an approval recorded under
+    /// the current one came from a current signer; only approvals without a
+    /// recorded epoch need the membership check.
+    fn counts(config: &MultiSigConfig, epochs: &Map<Address, u64>, approver: &Address, current: u64) -> bool {
+        match epochs.get(approver.clone()) {
+            Some(epoch) => epoch == current,
+            None => current == 0 && config.signers.contains(approver),
+        }
+    }
+
+    /// One record per approver in `approvals` order, taking each time from
+    /// `times`; approvers without one report 0. `times` is normally already
+    /// in approval order, so the record at the same index is tried first.
+    fn approval_records(env: &Env, approvals: &Vec<Address>, times: Vec<ApprovalRecord>) -> Vec<ApprovalRecord> {
+        let mut records = Vec::new(env);
+        for (index, signer) in approvals.iter().enumerate() {
+            let record = times
+                .get(index as u32)
+                .filter(|record| record.signer == signer)
+                .or_else(|| times.iter().find(|record| record.signer == signer))
+                .unwrap_or(ApprovalRecord { signer, at: 0, sequence: 0 });
+            records.push_back(record);
+        }
+        records
+    }
+
+    fn meta_expired(env: &Env, meta: &ProposalMeta) -> bool {
+        meta.expiry != 0 && env.ledger().timestamp() >= meta.expiry
+    }
+
+    fn check_signer(config: &MultiSigConfig, signer: &Address) -> Result<(), MultiSigError> {
+        if config.signers.contains(signer) {
+            Ok(())
+        } else {
+            Err(MultiSigError::NotSigner)
+        }
+    }
+
+    /// Rejects proposals that were executed, cancelled, expired, or lapsed,
+    /// and returns the approvals of an open one.
+    fn check_open(
+        env: &Env,
+        meta: &ProposalMeta,
+        approvals: Option<Vec<Address>>,
+    ) -> Result<Vec<Address>, MultiSigError> {
+        if meta.executed {
+            return Err(MultiSigError::AlreadyExecuted);
+        }
+        if meta.cancelled {
+            return Err(MultiSigError::ProposalCancelled);
+        }
+        if Self::meta_expired(env, meta) {
+            return Err(MultiSigError::ProposalExpired);
+        }
+        approvals.ok_or(MultiSigError::ApprovalsLapsed)
+    }
+
+    /// Number of approvals from addresses still in the signer set, ignoring
+    /// the signer epoch; an upper bound on [`Self::get_valid_approvals`].
+    pub fn live_approvals(config: &MultiSigConfig, proposal: &Proposal) -> u32 {
+        Self::count_live(config, &proposal.approvals)
+    }
+
+    fn count_live(config: &MultiSigConfig, approvals: &Vec<Address>) -> u32 {
+        approvals
+            .iter()
+            .filter(|approver| config.signers.contains(approver))
+            .count() as u32
+    }
+}
@@ -0,0 +1,315 @@
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{contract, testutils::storage::Persistent as _, testutils::Address as _, testutils::Ledger as _, vec, Address, BytesN, Env};
+
+use crate::{
+    get_admin, get_upgrade_hash, get_version, initialize, initialize_multisig, legacy_proposals_remaining,
+    propose_upgrade, relocate_legacy_proposals, require_governance, set_version, take_approved_upgrade, MultiSig,
+    MultiSigConfig, UpgradeKey, RELOCATED_TTL, RELOCATION_BATCH,
+};
+use crate::multisig::Proposal;
+
+/// Empty contract whose instance storage hosts the helpers under test.
+#[contract]
+struct Host;
+
+fn host(env: &Env) -> Address {
+    env.mock_all_auths();
+    env.register_contract(None, Host)
+}
+
+#[test]
+fn test_initialize_stores_admin_and_version() {
+    let env = Env::default();
+    let id = host(&env);
+    let admin = Address::generate(&env);
+    env.as_contract(&id, || {
+        initialize(&env, &admin, 2);
+        assert_eq!(get_admin(&env), Some(admin.clone()));
+        assert_eq!(get_version(&env), 2);
+    });
+    // A fresh frame: an address can authorize only once per invocation.
+    env.as_contract(&id, || assert_eq!(require_governance(&env), admin));
+}
+
+#[test]
+#[should_panic(expected = "1")]
+fn test_initialize_twice_panics() {
+    let env = Env::default();
+    let id = host(&env);
+    let admin = Address::generate(&env);
+    env.as_contract(&id, || {
+        initialize(&env, &admin, 1);
+        initialize(&env, &admin, 1);
+    });
+}
+
+#[test]
+#[should_panic(expected = "2")]
+fn test_require_governance_without_admin_panics() {
+    let env = Env::default();
+    let id = host(&env);
+    env.as_contract(&id, || {
+        require_governance(&env);
+    });
+}
+
+#[test]
+fn test_set_version_returns_previous() {
+    let env = Env::default();
+    let id = host(&env);
+    env.as_contract(&id, || {
+        initialize(&env, &Address::generate(&env), 2);
+        assert_eq!(set_version(&env, 5), 2);
+        assert_eq!(get_version(&env), 5);
+    });
+}
+
+#[test]
+fn test_upgrade_proposal_consumed_once_approved() {
+    let env = Env::default();
+    let id = host(&env);
+    let signers = [Address::generate(&env), Address::generate(&env)];
+    let wasm = BytesN::from_array(&env, &[7; 32]);
+    env.as_contract(&id, || {
+        initialize_multisig(&env, vec![&env, signers[0].clone(), signers[1].clone()], 2, 1);
+    });
+    let proposal_id = env.as_contract(&id, || propose_upgrade(&env, signers[0].clone(), &wasm, 0));
+    env.as_contract(&id, || {
+        assert_eq!(get_upgrade_hash(&env, proposal_id), Some(wasm.clone()));
+        MultiSig::approve(&env, proposal_id, signers[0].clone());
+        MultiSig::approve(&env, proposal_id, signers[1].clone());
+        assert_eq!(take_approved_upgrade(&env, proposal_id), wasm);
+        assert!(!MultiSig::can_execute(&env, proposal_id));
+    });
+}
+
+#[test]
+#[should_panic(expected = "Threshold not met or proposal not executable")]
+fn test_take_unapproved_upgrade_panics() {
+    let env = Env::default();
+    let id = host(&env);
+    let signers = [Address::generate(&env), Address::generate(&env)];
+    env.as_contract(&id, || {
+        initialize_multisig(&env, vec![&env, signers[0].clone(), signers[1].clone()], 2, 1);
+    });
+    let proposal_id = env.as_contract(&id, || {
+        propose_upgrade(&env, signers[0].clone(), &BytesN::from_array(&env, &[1; 32]), 0)
+    });
+    env.as_contract(&id, || {
+        MultiSig::approve(&env, proposal_id, signers[0].clone());
+        take_approved_upgrade(&env, proposal_id);
+    });
+}
+
+#[test]
+fn test_keys_match_core_layout() {
+    // Same variant name, same encoding: this is what lets the core contract
+    // adopt the module without migrating state.
+    #[soroban_sdk::contracttype]
+    enum CoreKey {
+        Admin,
+        UpgradeProposal(u64),
+    }
+
+    let env = Env::default();
+    let id = host(&env);
+    let admin = Address::generate(&env);
+    env.as_contract(&id, || {
+        env.storage().instance().set(&CoreKey::Admin, &admin);
+        env.storage().persistent().set(&CoreKey::UpgradeProposal(3), &9u32);
+        assert_eq!(get_admin(&env), Some(admin.clone()));
+        assert_eq!(env.storage().persistent().get::<_, u32>(&UpgradeKey::UpgradeProposal(3)), Some(9));
+    });
+}
+
+#[test]
+fn test_relocate_legacy_proposals_moves_instance_entries() {
+    // Encodes like the multisig module's private keys.
+    #[soroban_sdk::contracttype]
+    enum LegacyKey {
+        Proposal(u64),
+        ProposalCounter,
+    }
+
+    let env = Env::default();
+    let id = host(&env);
+    let hash = BytesN::from_array(&env, &[4u8; 32]);
+    env.as_contract(&id, || {
+        let proposal = Proposal {
+            approvals: vec![&env],
+            executed: false,
+            expiry: 0,
+            cancelled: false,
+        };
+        for proposal_id in 1..=2u64 {
+            env.storage().instance().set(&LegacyKey::Proposal(proposal_id), &proposal);
+            env.storage()
+                .instance()
+                .set(&UpgradeKey::UpgradeProposal(proposal_id), &hash);
+        }
+        env.storage().instance().set(&LegacyKey::ProposalCounter, &2u64);
+
+        // One ID per call: the cursor carries the relocation over.
+        assert_eq!(relocate_legacy_proposals(&env, 1), Some((1, 1)));
+        assert!(env.storage().instance().has(&LegacyKey::Proposal(2)));
+        assert_eq!(legacy_proposals_remaining(&env), Some(1));
+        assert_eq!(relocate_legacy_proposals(&env, 1), Some((2, 2)));
+        assert_eq!(legacy_proposals_remaining(&env), None);
+        for proposal_id in 1..=2u64 {
+            assert!(!env.storage().instance().has(&LegacyKey::Proposal(proposal_id)));
+            assert!(!env.storage().instance().has(&UpgradeKey::UpgradeProposal(proposal_id)));
+            assert_eq!(MultiSig::get_proposal_opt(&env, proposal_id), Some(proposal.clone()));
+            assert_eq!(get_upgrade_hash(&env, proposal_id), Some(hash.clone()));
+            let key = UpgradeKey::UpgradeProposal(proposal_id);
+            assert_eq!(env.storage().persistent().get_ttl(&key), RELOCATED_TTL);
+        }
+        assert_eq!(relocate_legacy_proposals(&env, 1), None);
+    });
+}
+
+#[test]
+fn test_views_leave_persistent_legacy_proposals_in_place() {
+    // Encodes like the multisig module's private keys.
+    #[soroban_sdk::contracttype]
+    enum LegacyKey {
+        Proposal(u64),
+        ProposalMeta(u64),
+        ApprovalEpoch,
+    }
+
+    let env = Env::default();
+    let id = host(&env);
+    let signers = vec![&env, Address::generate(&env), Address::generate(&env)];
+    env.as_contract(&id, || {
+        MultiSig::init(&env, signers.clone(), 2);
+        let open = Proposal {
+            approvals: vec![&env, signers.get(0).unwrap()],
+            executed: false,
+            expiry: 0,
+            cancelled: false,
+        };
+        let executed = Proposal {
+            executed: true,
+            ..open.clone()
+        };
+        env.storage().persistent().set(&LegacyKey::Proposal(1), &open);
+        env.storage().persistent().set(&LegacyKey::Proposal(2), &executed);
+
+        assert_eq!(MultiSig::get_proposal_opt(&env, 1), Some(open.clone()));
+        assert!(!MultiSig::can_execute(&env, 1));
+        assert_eq!(MultiSig::get_valid_approvals(&env, 1), open.approvals);
+        let record = MultiSig::get_execution(&env, 2).unwrap();
+        assert_eq!(record.approvals, executed.approvals);
+        assert_eq!(record.executed_at, None);
+        for proposal_id in 1..=2u64 {
+            assert!(env.storage().persistent().has(&LegacyKey::Proposal(proposal_id)));
+            assert!(!env.storage().persistent().has(&LegacyKey::ProposalMeta(proposal_id)));
+        }
+        assert!(!env.storage().instance().has(&LegacyKey::ApprovalEpoch));
+    });
+    // A mutating path converts the entry.
+    env.as_contract(&id, || {
+        MultiSig::approve(&env, 1, signers.get(1).unwrap());
+        assert!(!env.storage().persistent().has(&LegacyKey::Proposal(1)));
+        assert!(env.storage().persistent().has(&LegacyKey::ProposalMeta(1)));
+        assert!(MultiSig::can_execute(&env, 1));
+    });
+}
+
+#[test]
+fn test_initialized_contract_skips_relocation() {
+    let env = Env::default();
+    let id = host(&env);
+    let admin = Address::generate(&env);
+    env.as_contract(&id, || {
+        initialize(&env, &admin, 1);
+        assert_eq!(relocate_legacy_proposals(&env, RELOCATION_BATCH), None);
+    });
+}
+
+#[test]
+fn test_removed_signer_keeps_stats() {
+    let env = Env::default();
+    let id = host(&env);
+    let signers = vec![&env, Address::generate(&env), Address::generate(&env)];
+    let leaving = signers.get(1).unwrap();
+    env.as_contract(&id, || {
+        MultiSig::init(&env, signers.clone(), 1);
+        let proposal_id = MultiSig::try_propose(&env, &leaving, 0).unwrap();
+        MultiSig::try_approve(&env, proposal_id, &leaving).unwrap();
+        env.ledger().set_timestamp(100);
+
+        MultiSig::set_config(&env, MultiSigConfig { signers: vec![&env, signers.get(0).unwrap()], threshold: 1 });
+        let stats = MultiSig::get_signer_stats(&env, &leaving).unwrap();
+        assert_eq!((stats.proposals_created, stats.approvals_given), (1, 1));
+        assert_eq!(stats.removed_at, Some(100));
+        assert_eq!(MultiSig::list_signer_stats(&env).len(), 1);
+
+        MultiSig::set_config(&env, MultiSigConfig { signers: signers.clone(), threshold: 1 });
+        assert_eq!(MultiSig::get_signer_stats(&env, &leaving).unwrap().removed_at, None);
+    });
+}
+
+#[test]
+fn test_signer_swap_invalidates_approvals() {
+    let env = Env::default();
+    let id = host(&env);
+    let signers = vec![&env, Address::generate(&env), Address::generate(&env), Address::generate(&env)];
+    let (a, b) = (signers.get(0).unwrap(), signers.get(1).unwrap());
+    env.as_contract(&id, || {
+        MultiSig::init(&env, signers.clone(), 2);
+        let proposal_id = MultiSig::try_propose(&env, &a, 0).unwrap();
+        MultiSig::try_approve(&env, proposal_id, &a).unwrap();
+        MultiSig::try_approve(&env, proposal_id, &b).unwrap();
+        assert!(MultiSig::can_execute(&env, proposal_id));
+        assert_eq!(MultiSig::get_signer_epoch(&env), 0);
+
+        let mut swapped = signers.clone();
+        swapped.set(2, Address::generate(&env));
+        MultiSig::set_config(&env, MultiSigConfig { signers: swapped, threshold: 2 });
+        assert_eq!(MultiSig::get_signer_epoch(&env), 1);
+        assert_eq!(MultiSig::get_valid_approvals(&env, proposal_id).len(), 0);
+        assert_eq!(MultiSig::get_proposal_opt(&env, proposal_id).unwrap().approvals, vec![&env, a.clone(), b.clone()]);
+        assert!(!MultiSig::can_execute(&env, proposal_id));
+
+        // Stale approvals renew in place.
+        MultiSig::try_approve(&env, proposal_id, &a).unwrap();
+        assert_eq!(MultiSig::get_valid_approvals(&env, proposal_id), vec![&env, a.clone()]);
+        MultiSig::try_approve(&env, proposal_id, &b).unwrap();
+        assert!(MultiSig::can_execute(&env, proposal_id));
+        assert_eq!(MultiSig::get_proposal_opt(&env, proposal_id).unwrap().approvals.len(), 2);
+    });
+}
+
+#[test]
+fn test_threshold_change_keeps_signer_epoch() {
+    let env = Env::default();
+    let id = host(&env);
+    let signers = vec![&env, Address::generate(&env), Address::generate(&env)];
+    env.as_contract(&id, || {
+        MultiSig::init(&env, signers.clone(), 2);
+        let proposal_id = MultiSig::try_propose(&env, &signers.get(0).unwrap(), 0).unwrap();
+        MultiSig::try_approve(&env, proposal_id, &signers.get(0).unwrap()).unwrap();
+        MultiSig::set_config(&env, MultiSigConfig { signers: signers.clone(), threshold: 1 });
+        assert_eq!(MultiSig::get_signer_epoch(&env), 0);
+        assert!(MultiSig::can_execute(&env, proposal_id));
+    });
+}
+
+#[test]
+fn test_reserved_ids_never_collide() {
+    let env = Env::default();
+    let id = host(&env);
+    let signers = vec![&env, Address::generate(&env)];
+    env.as_contract(&id, || {
+        MultiSig::init(&env, signers.clone(), 1);
+        assert_eq!(MultiSig::try_propose(&env, &signers.get(0).unwrap(), 0).unwrap(), 1);
+        assert_eq!(MultiSig::reserve_proposal_id(&env, 0), 2);
+        assert_eq!(MultiSig::reserve_proposal_id(&env, 7), 8);
+        assert_eq!(MultiSig::try_propose(&env, &signers.get(0).unwrap(), 0).unwrap(), 9);
+        assert_eq!(MultiSig::get_proposal_count(&env), 9);
+    });
+}
@@ -0,0 +1,161 @@
+//! Approval records in temporary storage and promotion at execution.
+//!
+//! - An expiring proposal's approval record gets a TTL that outlasts the
+//!   expiry, while a non-expiring one stays in persistent storage
+//! - A record lost to TTL expiry leaves the proposal lapsed: it cannot be
+//!   approved again or executed, only pruned
+//! - A record carrying a different epoch is ignored the same way
+//! - Execution promotes hash, approvers, and time into persistent history
+//!   and drops the temporary record
+
+#![cfg(test)]
+
+extern crate std;
+
+use soroban_sdk::{
+    contract, contracttype,
+    testutils::storage::Temporary as _,
+    testutils::{Address as _, Ledger as _},
+    vec, Address, BytesN, Env, Vec,
+};
+
+use crate::multisig::{ApprovalSet, MultiSig, MultiSigError};
+use crate::{get_executed_upgrade, initialize_multisig, propose_upgrade, take_approved_upgrade};
+
+/// Network TTL ceiling used by these tests, in ledgers.
+const MAX_TTL: u32 = 100_000;
+
+#[contract]
+struct Host;
+
+/// Encodes like the multisig module's private per-proposal keys.
+#[contracttype]
+enum MultiSigKey {
+    ProposalMeta(u64),
+    ProposalApprovals(u64),
+}
+
+fn setup(env: &Env) -> (Address, Vec<Address>) {
+    env.ledger().with_mut(|li| {
+        li.min_persistent_entry_ttl = MAX_TTL;
+        li.max_entry_ttl = MAX_TTL;
+    });
+    env.mock_all_auths();
+    let id = env.register_contract(None, Host);
+    let signers = vec![env, Address::generate(env), Address::generate(env), Address::generate(env)];
+    env.as_contract(&id, || initialize_multisig(env, signers.clone(), 2, 1));
+    (id, signers)
+}
+
+/// Moves the ledger forward and keeps instance and proposal metadata alive,
+/// so only the temporary approval record can run out.
+fn advance(env: &Env, id: &Address, proposal_id: u64, ledgers: u32) {
+    env.as_contract(id, || {
+        env.storage().instance().extend_ttl(MAX_TTL, MAX_TTL);
+        let key = MultiSigKey::ProposalMeta(proposal_id);
+        env.storage().persistent().extend_ttl(&key, MAX_TTL, MAX_TTL);
+    });
+    env.ledger().with_mut(|li| li.sequence_number += ledgers);
+    env.as_contract(id, || env.storage().instance().extend_ttl(MAX_TTL, MAX_TTL));
+}
+
+#[test]
+fn test_expiring_record_is_temporary_with_covering_ttl() {
+    let env = Env::default();
+    let (id, signers) = setup(&env);
+    env.as_contract(&id, || {
+        let proposal_id = MultiSig::try_propose(&env, &signers.get(0).unwrap(), 50_000).unwrap();
+        let key = MultiSigKey::ProposalApprovals(proposal_id);
+        assert!(env.storage().temporary().has(&key));
+        // 50_000 s is 10_000 ledgers at 5 s; the record must outlive it.
+        assert!(env.storage().temporary().get_ttl(&key) > 10_000);
+
+        let open_ended = MultiSig::try_propose(&env, &signers.get(0).unwrap(), 0).unwrap();
+        let key = MultiSigKey::ProposalApprovals(open_ended);
+        assert!(!env.storage().temporary().has(&key));
+        assert!(env.storage().persistent().has(&key));
+    });
+}
+
+#[test]
+fn test_ttl_expiry_cannot_reset_approvals() {
+    let env = Env::default();
+    let (id, signers) = setup(&env);
+    // Far-off expiry: the record TTL is capped at MAX_TTL and runs out first.
+    let proposal_id = env.as_contract(&id, || {
+        let proposal_id = MultiSig::try_propose(&env, &signers.get(0).unwrap(), u64::MAX).unwrap();
+        MultiSig::try_approve(&env, proposal_id, &signers.get(0).unwrap()).unwrap();
+        proposal_id
+    });
+
+    advance(&env, &id, proposal_id, MAX_TTL / 2);
+    advance(&env, &id, proposal_id, MAX_TTL / 2 + 1);
+
+    env.as_contract(&id, || {
+        assert!(!env.storage().temporary().has(&MultiSigKey::ProposalApprovals(proposal_id)));
+        assert!(MultiSig::is_lapsed(&env, proposal_id));
+        assert_eq!(
+            MultiSig::try_approve(&env, proposal_id, &signers.get(1).unwrap()),
+            Err(MultiSigError::ApprovalsLapsed)
+        );
+        assert_eq!(
+            MultiSig::try_mark_executed(&env, proposal_id),
+            Err(MultiSigError::ApprovalsLapsed)
+        );
+        MultiSig::try_prune(&env, proposal_id).unwrap();
+        assert!(MultiSig::get_proposal_opt(&env, proposal_id).is_none());
+    });
+}
+
+#[test]
+fn test_record_from_another_epoch_is_ignored() {
+    let env = Env::default();
+    let (id, signers) = setup(&env);
+    env.as_contract(&id, || {
+        let proposal_id = MultiSig::try_propose(&env, &signers.get(0).unwrap(), 0).unwrap();
+        let forged = ApprovalSet {
+            epoch: 999,
+            approvals: signers.clone(),
+        };
+        env.storage()
+            .persistent()
+            .set(&MultiSigKey::ProposalApprovals(proposal_id), &forged);
+
+        assert!(MultiSig::is_lapsed(&env, proposal_id));
+        assert!(!MultiSig::can_execute(&env, proposal_id));
+        assert!(MultiSig::get_proposal_opt(&env, proposal_id).unwrap().approvals.is_empty());
+    });
+}
+
+#[test]
+fn test_execution_promotes_complete_history() {
+    let env = Env::default();
+    let (id, signers) = setup(&env);
+    let hash = BytesN::from_array(&env, &[8u8; 32]);
+    env.ledger().set_timestamp(1_000);
+    let proposal_id = env.as_contract(&id, || {
+        let proposal_id = propose_upgrade(&env, signers.get(0).unwrap(), &hash, 5_000);
+        MultiSig::try_approve(&env, proposal_id, &signers.get(0).unwrap()).unwrap();
+        MultiSig::try_approve(&env, proposal_id, &signers.get(2).unwrap()).unwrap();
+        proposal_id
+    });
+    env.ledger().set_timestamp(1_500);
+    env.as_contract(&id, || {
+        assert_eq!(take_approved_upgrade(&env, proposal_id), hash);
+        assert!(!env.storage().temporary().has(&MultiSigKey::ProposalApprovals(proposal_id)));
+
+        let history = get_executed_upgrade(&env, proposal_id).unwrap();
+        assert_eq!(history.proposal_id, proposal_id);
+        assert_eq!(history.wasm_hash, hash);
+        assert_eq!(history.approvals, vec![&env, signers.get(0).unwrap(), signers.get(2).unwrap()]);
+        assert_eq!(history.executed_at, Some(1_500));
+    });
+
+    // History is persistent: it outlives the expiry and the record's TTL.
+    advance(&env, &id, proposal_id, MAX_TTL / 2);
+    env.ledger().set_timestamp(10_000);
+    env.as_contract(&id, || {
+        assert!(get_executed_upgrade(&env, proposal_id).is_some());
+        assert!(MultiSig::get_proposal_opt(&env, proposal_id).unwrap().executed);
+    });
+}
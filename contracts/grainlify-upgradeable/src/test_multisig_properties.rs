@@ -0,0 +1,302 @@
+//! Randomized state-machine tests for [`MultiSig`].
+//!
+//! Each case draws a threshold and a sequence of governance actions from a
+//! seeded xorshift generator, applies them through the `try_*` transitions,
+//! and checks these invariants after every step:
+//! - an execution succeeds only with at least `threshold` distinct approvals
+//!   from current signers
+//! - executed proposals never change again
+//! - live approvals never exceed the signer count and never repeat
+//! - cancelled proposals can always be pruned, leaving no storage behind
+//!
+//! A failing sequence is shrunk by deleting actions while it still fails,
+//! and the panic message reports the seed and the minimal sequence.
+
+#![cfg(test)]
+
+extern crate std;
+
+use std::collections::BTreeMap;
+use std::format;
+use std::string::String;
+use std::vec::Vec as StdVec;
+
+use soroban_sdk::{contract, testutils::Address as _, testutils::Ledger as _, Address, Env, Vec};
+
+use crate::multisig::{MultiSig, MultiSigConfig, Proposal};
+
+const CASES: u64 = 150;
+const STEPS: usize = 40;
+/// Addresses that may act; the first `INITIAL_SIGNERS` start in the set.
+const POOL: usize = 5;
+const INITIAL_SIGNERS: usize = 4;
+/// Proposal IDs actions may target, including ones that never exist.
+const MAX_TARGET_ID: u64 = 6;
+
+/// Empty contract whose instance storage holds the multisig state.
+#[contract]
+struct StateMachineHost;
+
+#[derive(Clone, Copy, Debug)]
+enum Action {
+    /// `ttl` seconds until expiry; 0 means no expiry.
+    Propose { who: usize, ttl: u64 },
+    Approve { id: u64, who: usize },
+    Revoke { id: u64, who: usize },
+    Cancel { id: u64, who: usize },
+    Execute { id: u64 },
+    Prune { id: u64 },
+    Advance { secs: u64 },
+    /// Adds `who` to the signer set, or removes it if that keeps the
+    /// threshold satisfiable.
+    Rotate { who: usize },
+}
+
+struct Rng(u64);
+
+impl Rng {
+    fn new(seed: u64) -> Self {
+        Rng(seed.wrapping_mul(0x9E37_79B9_7F4A_7C15) | 1)
+    }
+
+    fn next(&mut self) -> u64 {
+        let mut x = self.0;
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        self.0 = x;
+        x
+    }
+
+    fn below(&mut self, n: u64) -> u64 {
+        self.next() % n
+    }
+
+    fn action(&mut self) -> Action {
+        let who = self.below(POOL as u64) as usize;
+        let id = 1 + self.below(MAX_TARGET_ID);
+        match self.below(10) {
+            0 | 1 => Action::Propose { who, ttl: [0, 50, 200][self.below(3) as usize] },
+            2..=4 => Action::Approve { id, who },
+            5 => Action::Revoke { id, who },
+            6 => Action::Cancel { id, who },
+            7 => Action::Execute { id },
+            8 => match self.below(3) {
+                0 => Action::Prune { id },
+                1 => Action::Advance { secs: 1 + self.below(120) },
+                _ => Action::Rotate { who },
+            },
+            _ => Action::Execute { id },
+        }
+    }
+}
+
+/// Applies `actions` to a fresh contract and returns the first invariant
+/// violation, if any.
+fn run(threshold: u32, actions: &[Action]) -> Result<(), String> {
+    let env = Env::default();
+    env.budget().reset_unlimited();
+    let id = env.register_contract(None, StateMachineHost);
+    let pool: StdVec<Address> = (0..POOL).map(|_| Address::generate(&env)).collect();
+
+    env.as_contract(&id, || {
+        let mut initial = Vec::new(&env);
+        for address in pool.iter().take(INITIAL_SIGNERS) {
+            initial.push_back(address.clone());
+        }
+        MultiSig::init(&env, initial, threshold);
+
+        let mut executed: BTreeMap<u64, Proposal> = BTreeMap::new();
+        for (step, action) in actions.iter().enumerate() {
+            apply(&env, &pool, *action, &mut executed)
+                .and_then(|_| check_invariants(&env, &executed))
+                .map_err(|e| format!("step {} ({:?}): {}", step, action, e))?;
+        }
+
+        // Every cancelled proposal must still be reclaimable at the end.
+        for proposal_id in 1..=MultiSig::get_proposal_count(&env) {
+            if let Some(proposal) = MultiSig::get_proposal_opt(&env, proposal_id) {
+                if proposal.cancelled {
+                    MultiSig::try_prune(&env, proposal_id)
+                        .map_err(|e| format!("cancelled proposal {} not prunable: {:?}", proposal_id, e))?;
+                    if MultiSig::get_proposal_opt(&env, proposal_id).is_some() {
+                        return Err(format!("pruned proposal {} still stored", proposal_id));
+                    }
+                }
+            }
+        }
+        Ok(())
+    })
+}
+
+fn apply(
+    env: &Env,
+    pool: &[Address],
+    action: Action,
+    executed: &mut BTreeMap<u64, Proposal>,
+) -> Result<(), String> {
+    match action {
+        Action::Propose { who, ttl } => {
+            let expiry = if ttl == 0 { 0 } else { env.ledger().timestamp() + ttl };
+            let _ = MultiSig::try_propose(env, &pool[who], expiry);
+        }
+        Action::Approve { id, who } => {
+            let _ = MultiSig::try_approve(env, id, &pool[who]);
+        }
+        Action::Revoke { id, who } => {
+            let _ = MultiSig::try_revoke(env, id, &pool[who]);
+        }
+        Action::Cancel { id, who } => {
+            let _ = MultiSig::try_cancel(env, id, &pool[who]);
+        }
+        Action::Execute { id } => {
+            let config = MultiSig::get_config_opt(env).unwrap();
+            let before = MultiSig::get_proposal_opt(env, id);
+            if MultiSig::try_mark_executed(env, id).is_ok() {
+                let before = before.ok_or("executed a missing proposal")?;
+                let live = MultiSig::live_approvals(&config, &before);
+                if live < config.threshold {
+                    return Err(format!(
+                        "executed with {} live approvals, threshold {}",
+                        live, config.threshold
+                    ));
+                }
+                executed.insert(id, MultiSig::get_proposal_opt(env, id).unwrap());
+            }
+        }
+        Action::Prune { id } => {
+            let before = MultiSig::get_proposal_opt(env, id);
+            if MultiSig::try_prune(env, id).is_ok() {
+                let before = before.ok_or("pruned a missing proposal")?;
+                if before.executed {
+                    return Err(String::from("pruned an executed proposal"));
+                }
+                if MultiSig::get_proposal_opt(env, id).is_some() {
+                    return Err(String::from("pruned proposal still stored"));
+                }
+            }
+        }
+        Action::Advance { secs } => {
+            env.ledger().set_timestamp(env.ledger().timestamp() + secs);
+        }
+        Action::Rotate { who } => {
+            let mut config = MultiSig::get_config_opt(env).unwrap();
+            match config.signers.first_index_of(&pool[who]) {
+                Some(index) if config.signers.len() > config.threshold => {
+                    config.signers.remove(index);
+                }
+                Some(_) => return Ok(()),
+                None => config.signers.push_back(pool[who].clone()),
+            }
+            MultiSig::set_config(env, config);
+        }
+    }
+    Ok(())
+}
+
+fn check_invariants(env: &Env, executed: &BTreeMap<u64, Proposal>) -> Result<(), String> {
+    let config: MultiSigConfig = MultiSig::get_config_opt(env).unwrap();
+    for (id, snapshot) in executed.iter() {
+        if MultiSig::get_proposal_opt(env, *id).as_ref() != Some(snapshot) {
+            return Err(format!("executed proposal {} changed", id));
+        }
+    }
+    for id in 1..=MultiSig::get_proposal_count(env) {
+        let Some(proposal) = MultiSig::get_proposal_opt(env, id) else {
+            continue;
+        };
+        let approvals: StdVec<Address> = proposal.approvals.iter().collect();
+        for (i, approver) in approvals.iter().enumerate() {
+            if approvals[i + 1..].contains(approver) {
+                return Err(format!("proposal {} has a duplicate approval", id));
+            }
+        }
+        if MultiSig::live_approvals(&config, &proposal) > config.signers.len() {
+            return Err(format!("proposal {} has more live approvals than signers", id));
+        }
+    }
+    Ok(())
+}
+
+/// Removes actions one at a time for as long as `fails` keeps holding.
+fn shrink(actions: &[Action], fails: impl Fn(&[Action]) -> bool) -> StdVec<Action> {
+    let mut current: StdVec<Action> = actions.to_vec();
+    let mut index = 0;
+    while index < current.len() {
+        let mut candidate = current.clone();
+        candidate.remove(index);
+        if fails(&candidate) {
+            current = candidate;
+        } else {
+            index += 1;
+        }
+    }
+    current
+}
+
+#[test]
+fn test_random_sequences_preserve_invariants() {
+    for seed in 0..CASES {
+        let mut rng = Rng::new(seed);
+        let threshold = 1 + rng.below(3) as u32;
+        let actions: StdVec<Action> = (0..STEPS).map(|_| rng.action()).collect();
+
+        if let Err(error) = run(threshold, &actions) {
+            let minimal = shrink(&actions, |candidate| run(threshold, candidate).is_err());
+            panic!(
+                "seed {} (threshold {}): {}\nminimal failing sequence: {:#?}\nerror on minimal: {:?}",
+                seed,
+                threshold,
+                error,
+                minimal,
+                run(threshold, &minimal).unwrap_err()
+            );
+        }
+    }
+}
+
+#[test]
+fn test_shrink_reduces_to_minimal_sequence() {
+    let actions = [
+        Action::Advance { secs: 1 },
+        Action::Propose { who: 0, ttl: 0 },
+        Action::Approve { id: 1, who: 0 },
+        Action::Execute { id: 1 },
+        Action::Advance { secs: 2 },
+    ];
+    // Synthetic property: fails while a Propose is followed by an Execute.
+    let fails = |candidate: &[Action]| {
+        let propose = candidate.iter().position(|a| matches!(a, Action::Propose { .. }));
+        let execute = candidate.iter().rposition(|a| matches!(a, Action::Execute { .. }));
+        matches!((propose, execute), (Some(p), Some(e)) if p < e)
+    };
+    let minimal = shrink(&actions, fails);
+    assert_eq!(minimal.len(), 2);
+    assert!(matches!(minimal[0], Action::Propose { .. }));
+    assert!(matches!(minimal[1], Action::Execute { .. }));
+}
+
+#[test]
+fn test_removed_signer_approval_does_not_count() {
+    let env = Env::default();
+    let id = env.register_contract(None, StateMachineHost);
+    let signers = [Address::generate(&env), Address::generate(&env), Address::generate(&env)];
+    env.as_contract(&id, || {
+        MultiSig::init(&env, Vec::from_array(&env, signers.clone()), 2);
+        let proposal_id = MultiSig::try_propose(&env, &signers[0], 0).unwrap();
+        MultiSig::try_approve(&env, proposal_id, &signers[0]).unwrap();
+        MultiSig::try_approve(&env, proposal_id, &signers[1]).unwrap();
+        MultiSig::set_config(
+            &env,
+            MultiSigConfig {
+                signers: Vec::from_array(&env, [signers[0].clone(), signers[2].clone()]),
+                threshold: 2,
+            },
+        );
+        assert!(!MultiSig::can_execute(&env, proposal_id));
+        assert_eq!(
+            MultiSig::try_mark_executed(&env, proposal_id),
+            Err(crate::multisig::MultiSigError::ThresholdNotMet)
+        );
+    });
+}
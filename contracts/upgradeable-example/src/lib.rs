@@ -0,0 +1,70 @@
+#![no_std]
+//! Minimal contract wired to the shared `grainlify-upgradeable` module.
+//!
+//! It exposes the same upgrade, version, and multisig entrypoints as
+//! `GrainlifyContract` without any of the core contract's other features,
+//! and serves as the template for adopting the pattern elsewhere.
+
+use grainlify_upgradeable::{MultiSig, Upgradeable};
+use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, Vec};
+
+/// Version written at initialization.
+const VERSION: u32 = 1;
+
+#[contract]
+pub struct UpgradeableExample;
+
+#[contractimpl]
+impl UpgradeableExample {
+    /// One-time initialization with a single admin. Requires `admin` auth.
+    pub fn init_admin(env: Env, admin: Address) {
+        grainlify_upgradeable::initialize(&env, &admin, VERSION);
+    }
+
+    /// One-time initialization with multisig governance.
+    pub fn init(env: Env, signers: Vec<Address>, threshold: u32) {
+        grainlify_upgradeable::initialize_multisig(&env, signers, threshold, VERSION);
+    }
+
+    pub fn get_admin(env: Env) -> Option<Address> {
+        grainlify_upgradeable::get_admin(&env)
+    }
+
+    pub fn set_version(env: Env, new_version: u32) {
+        grainlify_upgradeable::require_governance(&env);
+        grainlify_upgradeable::set_version(&env, new_version);
+    }
+
+    pub fn get_previous_version(env: Env) -> Option<u32> {
+        grainlify_upgradeable::get_previous_version(&env)
+    }
+
+    pub fn propose_upgrade(env: Env, proposer: Address, wasm_hash: BytesN<32>, expiry: u64) -> u64 {
+        grainlify_upgradeable::propose_upgrade(&env, proposer, &wasm_hash, expiry)
+    }
+
+    pub fn approve_upgrade(env: Env, proposal_id: u64, signer: Address) {
+        MultiSig::approve(&env, proposal_id, signer);
+    }
+
+    /// Installs the WASM of a proposal that met its signer threshold.
+    pub fn execute_upgrade(env: Env, proposal_id: u64) {
+        let wasm_hash = grainlify_upgradeable::take_approved_upgrade(&env, proposal_id);
+        grainlify_upgradeable::perform_upgrade(&env, &wasm_hash);
+    }
+}
+
+#[contractimpl]
+impl Upgradeable for UpgradeableExample {
+    fn upgrade(env: Env, new_wasm_hash: BytesN<32>) {
+        grainlify_upgradeable::require_governance(&env);
+        grainlify_upgradeable::perform_upgrade(&env, &new_wasm_hash);
+    }
+
+    fn get_version(env: Env) -> u32 {
+        grainlify_upgradeable::get_version(&env)
+    }
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,131 @@
+//! Runs each scenario against both the example and `GrainlifyContract` to
+//! show the shared module behaves the same in either consumer.
+
+#![cfg(test)]
+
+extern crate std;
+
+use grainlify_core::testutils::upload_dummy_wasm;
+use grainlify_core::{GrainlifyContract, GrainlifyContractClient};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger as _},
+    vec, Address, Env,
+};
+
+use crate::{UpgradeableExample, UpgradeableExampleClient};
+
+fn clients(env: &Env) -> (UpgradeableExampleClient<'_>, GrainlifyContractClient<'_>) {
+    env.mock_all_auths();
+    let example = env.register_contract(None, UpgradeableExample);
+    let core = env.register_contract(None, GrainlifyContract);
+    (
+        UpgradeableExampleClient::new(env, &example),
+        GrainlifyContractClient::new(env, &core),
+    )
+}
+
+#[test]
+fn test_admin_init_and_version_match() {
+    let env = Env::default();
+    let (example, core) = clients(&env);
+    let admin = Address::generate(&env);
+    assert_eq!(example.get_version(), 0);
+    assert_eq!(core.get_version(), 0);
+
+    example.init_admin(&admin);
+    core.init_admin(&admin);
+    assert_eq!(example.get_admin(), Some(admin.clone()));
+    assert_eq!(core.get_admin(), Some(admin));
+
+    example.set_version(&7);
+    core.set_version(&7);
+    assert_eq!(example.get_version(), core.get_version());
+}
+
+#[test]
+fn test_double_init_fails_identically() {
+    let env = Env::default();
+    let (example, core) = clients(&env);
+    let admin = Address::generate(&env);
+    example.init_admin(&admin);
+    core.init_admin(&admin);
+
+    assert_eq!(
+        example.try_init_admin(&admin).unwrap_err(),
+        core.try_init_admin(&admin).unwrap_err()
+    );
+}
+
+#[test]
+fn test_admin_upgrade_records_previous_version() {
+    let env = Env::default();
+    let (example, core) = clients(&env);
+    let admin = Address::generate(&env);
+    example.init_admin(&admin);
+    core.init_admin(&admin);
+    example.set_version(&4);
+    core.set_version(&4);
+
+    example.upgrade(&upload_dummy_wasm(&env, 1));
+    core.upgrade(&upload_dummy_wasm(&env, 2));
+
+    // Both instances now run the uploaded module; read state directly.
+    for id in [&example.address, &core.address] {
+        env.as_contract(id, || {
+            assert_eq!(grainlify_upgradeable::get_previous_version(&env), Some(4));
+        });
+    }
+}
+
+#[test]
+fn test_upgrade_requires_admin_auth_in_both() {
+    let env = Env::default();
+    let (example, core) = clients(&env);
+    let admin = Address::generate(&env);
+    example.init_admin(&admin);
+    core.init_admin(&admin);
+
+    env.set_auths(&[]);
+    let wasm = upload_dummy_wasm(&env, 1);
+    assert!(example.try_upgrade(&wasm).is_err());
+    assert!(core.try_upgrade(&wasm).is_err());
+}
+
+#[test]
+fn test_upgrade_before_init_fails_identically() {
+    let env = Env::default();
+    let (example, core) = clients(&env);
+    let wasm = upload_dummy_wasm(&env, 1);
+    assert_eq!(
+        example.try_upgrade(&wasm).unwrap_err(),
+        core.try_upgrade(&wasm).unwrap_err()
+    );
+}
+
+#[test]
+fn test_multisig_upgrade_flow() {
+    let env = Env::default();
+    let (example, core) = clients(&env);
+    let signers = vec![&env, Address::generate(&env), Address::generate(&env)];
+    example.init(&signers, &2);
+    core.init(&signers, &2);
+
+    let wasm = upload_dummy_wasm(&env, 3);
+    let proposer = signers.get(0).unwrap();
+    let example_id = example.propose_upgrade(&proposer, &wasm, &0);
+    let core_id = core.propose_upgrade(&proposer, &wasm, &0);
+    assert_eq!(example_id, core_id);
+
+    example.approve_upgrade(&example_id, &proposer);
+    core.approve_upgrade(&core_id, &proposer);
+    assert!(example.try_execute_upgrade(&example_id).is_err());
+    assert!(core.try_execute_upgrade(&core_id).is_err());
+
+    let second = signers.get(1).unwrap();
+    example.approve_upgrade(&example_id, &second);
+    core.approve_upgrade(&core_id, &second);
+    // The core contract layers its timelock on top of the shared flow.
+    env.ledger().set_timestamp(86_400);
+    example.execute_upgrade(&example_id);
+    core.execute_upgrade(&core_id);
+}